@@ -4,6 +4,10 @@ mod cpal_backend;
 #[cfg(feature = "audio-cpal")]
 pub use cpal_backend::CpalBackend;
 
+mod wav_backend;
+
+pub use wav_backend::{seconds_to_frames, WavBackend, WavCapture, WAV_SAMPLE_RATE};
+
 /// Platform audio backend. Starts the audio output stream and calls `render_fn`
 /// each buffer to fill samples. Implementors live in bishop; engine_core never
 /// depends on the concrete type.
@@ -0,0 +1,459 @@
+use crate::audio::AudioBackend;
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Sample rate of the captured output. Matches the rate the `AudioManager`
+/// passes to oddio when rendering.
+pub const WAV_SAMPLE_RATE: u32 = 44_100;
+
+/// Number of frames pulled from the render function per block.
+const BLOCK_FRAMES: usize = 512;
+
+/// Size in bytes of the RIFF/WAVE header written by this backend.
+const WAV_HEADER_LEN: u64 = 58;
+
+type RenderFn = Box<dyn FnMut(&mut [[f32; 2]]) + Send>;
+
+/// Where rendered frames are written.
+enum WavSink {
+    /// Frames accumulate in memory until taken or written out.
+    Memory(Vec<[f32; 2]>),
+    /// Frames stream straight into a WAV file whose header is patched on finish.
+    File {
+        path: PathBuf,
+        writer: BufWriter<File>,
+        frames_written: u64,
+    },
+}
+
+struct WavCaptureState {
+    render_fn: RenderFn,
+    sink: WavSink,
+    scratch: Vec<[f32; 2]>,
+    frames_rendered: u64,
+}
+
+impl WavCaptureState {
+    fn render(&mut self, frame_count: usize) -> io::Result<()> {
+        let mut remaining = frame_count;
+        while remaining > 0 {
+            let block = remaining.min(BLOCK_FRAMES);
+            self.scratch.clear();
+            self.scratch.resize(block, [0.0, 0.0]);
+            (self.render_fn)(&mut self.scratch);
+
+            match &mut self.sink {
+                WavSink::Memory(frames) => frames.extend_from_slice(&self.scratch),
+                WavSink::File {
+                    writer,
+                    frames_written,
+                    ..
+                } => {
+                    write_frames(writer, &self.scratch)?;
+                    *frames_written += block as u64;
+                }
+            }
+
+            self.frames_rendered += block as u64;
+            remaining -= block;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for WavCaptureState {
+    fn drop(&mut self) {
+        // The last handle is gone, so patch the header of an unfinished file stream
+        if let Err(e) = finish_sink(&mut self.sink) {
+            log::error!("wav capture could not finalize its file: {e}");
+        }
+    }
+}
+
+/// Offline audio backend that mixes into a WAV file or an in-memory buffer
+/// instead of a sound card.
+///
+/// The render function is pulled on demand through [`WavCapture::render_frames`],
+/// which makes output deterministic for tests. [`WavCapture::start_realtime`]
+/// pulls at wall-clock rate on a worker thread for capturing live sessions.
+pub struct WavBackend {
+    capture: WavCapture,
+}
+
+impl WavBackend {
+    /// Returns a handle for driving and reading the captured output.
+    pub fn capture(&self) -> WavCapture {
+        self.capture.clone()
+    }
+}
+
+impl AudioBackend for WavBackend {
+    fn start<F: FnMut(&mut [[f32; 2]]) + Send + 'static>(render_fn: F) -> Self {
+        let state = WavCaptureState {
+            render_fn: Box::new(render_fn),
+            sink: WavSink::Memory(Vec::new()),
+            scratch: Vec::with_capacity(BLOCK_FRAMES),
+            frames_rendered: 0,
+        };
+        Self {
+            capture: WavCapture {
+                state: Arc::new(Mutex::new(state)),
+                realtime: Arc::new(Mutex::new(None)),
+            },
+        }
+    }
+}
+
+impl Drop for WavBackend {
+    fn drop(&mut self) {
+        self.capture.stop_realtime();
+    }
+}
+
+/// Running real-time pump thread.
+struct RealtimePump {
+    running: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+/// Shared handle to a [`WavBackend`]'s render function and output.
+///
+/// Cloning is cheap; all clones drive the same capture.
+#[derive(Clone)]
+pub struct WavCapture {
+    state: Arc<Mutex<WavCaptureState>>,
+    realtime: Arc<Mutex<Option<RealtimePump>>>,
+}
+
+impl WavCapture {
+    /// Pulls `frame_count` stereo frames from the mixer into the sink.
+    pub fn render_frames(&self, frame_count: usize) -> io::Result<()> {
+        self.lock_state().render(frame_count)
+    }
+
+    /// Pulls `seconds` worth of frames at [`WAV_SAMPLE_RATE`] into the sink.
+    pub fn render_seconds(&self, seconds: f32) -> io::Result<()> {
+        self.render_frames(seconds_to_frames(seconds))
+    }
+
+    /// Total frames pulled from the mixer since the backend started.
+    pub fn frames_rendered(&self) -> u64 {
+        self.lock_state().frames_rendered
+    }
+
+    /// Returns a copy of the frames captured in memory.
+    /// Empty while streaming to a file.
+    pub fn frames(&self) -> Vec<[f32; 2]> {
+        match &self.lock_state().sink {
+            WavSink::Memory(frames) => frames.clone(),
+            WavSink::File { .. } => Vec::new(),
+        }
+    }
+
+    /// Takes the frames captured in memory, leaving the buffer empty.
+    pub fn take_frames(&self) -> Vec<[f32; 2]> {
+        match &mut self.lock_state().sink {
+            WavSink::Memory(frames) => std::mem::take(frames),
+            WavSink::File { .. } => Vec::new(),
+        }
+    }
+
+    /// Encodes the in-memory capture as a 32-bit float WAV file.
+    pub fn wav_bytes(&self) -> Vec<u8> {
+        let frames = self.frames();
+        let mut bytes = Vec::with_capacity(WAV_HEADER_LEN as usize + frames.len() * 8);
+        // Writing into a Vec cannot fail.
+        let _ = write_header(&mut bytes, frames.len() as u64);
+        let _ = write_frames(&mut bytes, &frames);
+        bytes
+    }
+
+    /// Writes the in-memory capture to `path` as a 32-bit float WAV file.
+    pub fn write_wav(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.wav_bytes())
+    }
+
+    /// Streams all subsequently rendered frames into a WAV file at `path`.
+    ///
+    /// Frames already captured in memory are written first. Call
+    /// [`WavCapture::finish`] to finalize the header; a stream still open when
+    /// the backend and its last handle are dropped is finalized then.
+    pub fn stream_to_file(&self, path: &Path) -> io::Result<()> {
+        let mut state = self.lock_state();
+        finish_sink(&mut state.sink)?;
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        write_header(&mut writer, 0)?;
+
+        let buffered = match &mut state.sink {
+            WavSink::Memory(frames) => std::mem::take(frames),
+            WavSink::File { .. } => Vec::new(),
+        };
+        write_frames(&mut writer, &buffered)?;
+
+        state.sink = WavSink::File {
+            path: path.to_path_buf(),
+            writer,
+            frames_written: buffered.len() as u64,
+        };
+        Ok(())
+    }
+
+    /// Finalizes an active file stream and returns to in-memory capture.
+    /// Returns the path of the finished file, if one was being written.
+    pub fn finish(&self) -> io::Result<Option<PathBuf>> {
+        self.stop_realtime();
+        finish_sink(&mut self.lock_state().sink)
+    }
+
+    /// Starts pulling frames at wall-clock rate on a worker thread.
+    /// Does nothing if a real-time pump is already running.
+    pub fn start_realtime(&self) {
+        let mut realtime = self.lock_realtime();
+        if realtime.is_some() {
+            return;
+        }
+
+        let running = Arc::new(AtomicBool::new(true));
+        let thread_running = Arc::clone(&running);
+        let state = Arc::clone(&self.state);
+
+        let thread = std::thread::spawn(move || {
+            let started = Instant::now();
+            let mut pulled: u64 = 0;
+            while thread_running.load(Ordering::Relaxed) {
+                let due = (started.elapsed().as_secs_f64() * WAV_SAMPLE_RATE as f64) as u64;
+                if due > pulled {
+                    let count = (due - pulled) as usize;
+                    let result = match state.lock() {
+                        Ok(mut state) => state.render(count),
+                        Err(_) => return,
+                    };
+                    if let Err(e) = result {
+                        log::error!("wav capture write failed: {e} — stopping capture");
+                        return;
+                    }
+                    pulled = due;
+                }
+                std::thread::sleep(Duration::from_millis(5));
+            }
+        });
+
+        *realtime = Some(RealtimePump { running, thread });
+    }
+
+    /// Stops the real-time pump thread, if running.
+    pub fn stop_realtime(&self) {
+        let pump = self.lock_realtime().take();
+        if let Some(pump) = pump {
+            pump.running.store(false, Ordering::Relaxed);
+            let _ = pump.thread.join();
+        }
+    }
+
+    /// Returns whether the real-time pump thread is running.
+    pub fn is_realtime(&self) -> bool {
+        self.lock_realtime().is_some()
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, WavCaptureState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock_realtime(&self) -> std::sync::MutexGuard<'_, Option<RealtimePump>> {
+        self.realtime.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Converts a duration in seconds to a frame count at [`WAV_SAMPLE_RATE`].
+pub fn seconds_to_frames(seconds: f32) -> usize {
+    (seconds.max(0.0) as f64 * WAV_SAMPLE_RATE as f64).round() as usize
+}
+
+/// Flushes a file sink, patches its header and swaps back to memory capture.
+fn finish_sink(sink: &mut WavSink) -> io::Result<Option<PathBuf>> {
+    let previous = std::mem::replace(sink, WavSink::Memory(Vec::new()));
+    match previous {
+        WavSink::Memory(frames) => {
+            *sink = WavSink::Memory(frames);
+            Ok(None)
+        }
+        WavSink::File {
+            path,
+            mut writer,
+            frames_written,
+        } => {
+            writer.flush()?;
+            let mut file = writer.into_inner().map_err(|e| e.into_error())?;
+            file.seek(SeekFrom::Start(0))?;
+            write_header(&mut file, frames_written)?;
+            file.flush()?;
+            Ok(Some(path))
+        }
+    }
+}
+
+/// Writes a RIFF/WAVE header for stereo IEEE float samples.
+fn write_header<W: Write>(w: &mut W, frame_count: u64) -> io::Result<()> {
+    const CHANNELS: u16 = 2;
+    const BITS_PER_SAMPLE: u16 = 32;
+    const FORMAT_IEEE_FLOAT: u16 = 3;
+    let block_align = CHANNELS * BITS_PER_SAMPLE / 8;
+    let data_len = (frame_count * block_align as u64).min(u32::MAX as u64) as u32;
+    let riff_len = (WAV_HEADER_LEN as u32 - 8).saturating_add(data_len);
+
+    w.write_all(b"RIFF")?;
+    w.write_all(&riff_len.to_le_bytes())?;
+    w.write_all(b"WAVE")?;
+
+    // Non-PCM formats carry an extension size field in the fmt chunk.
+    w.write_all(b"fmt ")?;
+    w.write_all(&18u32.to_le_bytes())?;
+    w.write_all(&FORMAT_IEEE_FLOAT.to_le_bytes())?;
+    w.write_all(&CHANNELS.to_le_bytes())?;
+    w.write_all(&WAV_SAMPLE_RATE.to_le_bytes())?;
+    w.write_all(&(WAV_SAMPLE_RATE * block_align as u32).to_le_bytes())?;
+    w.write_all(&block_align.to_le_bytes())?;
+    w.write_all(&BITS_PER_SAMPLE.to_le_bytes())?;
+    w.write_all(&0u16.to_le_bytes())?;
+
+    // Float WAVs require a fact chunk with the per-channel sample count.
+    w.write_all(b"fact")?;
+    w.write_all(&4u32.to_le_bytes())?;
+    w.write_all(&(frame_count.min(u32::MAX as u64) as u32).to_le_bytes())?;
+
+    w.write_all(b"data")?;
+    w.write_all(&data_len.to_le_bytes())?;
+    Ok(())
+}
+
+fn write_frames<W: Write>(w: &mut W, frames: &[[f32; 2]]) -> io::Result<()> {
+    for [left, right] in frames {
+        w.write_all(&left.to_le_bytes())?;
+        w.write_all(&right.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counting_backend() -> WavBackend {
+        let mut next = 0.0f32;
+        WavBackend::start(move |frames: &mut [[f32; 2]]| {
+            for frame in frames {
+                *frame = [next, -next];
+                next += 1.0;
+            }
+        })
+    }
+
+    #[test]
+    fn render_frames_pulls_exact_frame_count_in_order() {
+        let backend = counting_backend();
+        let capture = backend.capture();
+
+        capture.render_frames(BLOCK_FRAMES + 3).unwrap();
+
+        let frames = capture.frames();
+        assert_eq!(frames.len(), BLOCK_FRAMES + 3);
+        assert_eq!(capture.frames_rendered(), (BLOCK_FRAMES + 3) as u64);
+        assert_eq!(frames[0], [0.0, -0.0]);
+        assert_eq!(frames[BLOCK_FRAMES + 2], [514.0, -514.0]);
+    }
+
+    #[test]
+    fn take_frames_empties_memory_buffer() {
+        let capture = counting_backend().capture();
+        capture.render_frames(10).unwrap();
+
+        assert_eq!(capture.take_frames().len(), 10);
+        assert!(capture.frames().is_empty());
+        assert_eq!(capture.frames_rendered(), 10);
+    }
+
+    #[test]
+    fn wav_bytes_has_float_header_and_payload() {
+        let capture = counting_backend().capture();
+        capture.render_frames(4).unwrap();
+
+        let bytes = capture.wav_bytes();
+
+        assert_eq!(bytes.len(), WAV_HEADER_LEN as usize + 4 * 8);
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(&bytes[8..12], b"WAVE");
+        assert_eq!(u16::from_le_bytes([bytes[20], bytes[21]]), 3);
+        assert_eq!(
+            u32::from_le_bytes([bytes[24], bytes[25], bytes[26], bytes[27]]),
+            WAV_SAMPLE_RATE
+        );
+        assert_eq!(&bytes[50..54], b"data");
+        assert_eq!(
+            u32::from_le_bytes([bytes[54], bytes[55], bytes[56], bytes[57]]),
+            32
+        );
+        let last = &bytes[bytes.len() - 4..];
+        assert_eq!(f32::from_le_bytes([last[0], last[1], last[2], last[3]]), -3.0);
+    }
+
+    #[test]
+    fn stream_to_file_patches_header_on_finish() {
+        let path = std::env::temp_dir().join(format!(
+            "bishop_wav_backend_{}.wav",
+            std::process::id()
+        ));
+        let capture = counting_backend().capture();
+        capture.render_frames(2).unwrap();
+
+        capture.stream_to_file(&path).unwrap();
+        capture.render_frames(6).unwrap();
+        let finished = capture.finish().unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(finished.as_deref(), Some(path.as_path()));
+        assert_eq!(bytes.len(), WAV_HEADER_LEN as usize + 8 * 8);
+        assert_eq!(
+            u32::from_le_bytes([bytes[54], bytes[55], bytes[56], bytes[57]]),
+            64
+        );
+        assert!(capture.frames().is_empty());
+    }
+
+    #[test]
+    fn dropping_the_backend_finalizes_an_open_stream() {
+        let path = std::env::temp_dir().join(format!(
+            "bishop_wav_backend_drop_{}.wav",
+            std::process::id()
+        ));
+        let backend = counting_backend();
+        let capture = backend.capture();
+        capture.stream_to_file(&path).unwrap();
+        capture.render_frames(3).unwrap();
+        drop(capture);
+        drop(backend);
+
+        let bytes = std::fs::read(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(bytes.len(), WAV_HEADER_LEN as usize + 3 * 8);
+        assert_eq!(
+            u32::from_le_bytes([bytes[54], bytes[55], bytes[56], bytes[57]]),
+            24
+        );
+    }
+
+    #[test]
+    fn seconds_to_frames_uses_sample_rate() {
+        assert_eq!(seconds_to_frames(1.0), WAV_SAMPLE_RATE as usize);
+        assert_eq!(seconds_to_frames(0.5), 22_050);
+        assert_eq!(seconds_to_frames(-1.0), 0);
+    }
+}
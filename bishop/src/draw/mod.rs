@@ -1,10 +1,12 @@
 //! Drawing primitives and textures.
 
 mod params;
+mod slice;
 
 pub use params::*;
+pub use slice::*;
 
-use crate::types::{Color, Texture2D, Vec2};
+use crate::types::{Color, Rect, Texture2D, Vec2};
 
 /// Core drawing operations for 2D primitives.
 pub trait Draw {
@@ -50,11 +52,44 @@ pub trait Draw {
         params: DrawTextureParams,
     );
 
+    /// Draws `texture` over `dest` cut into the corners, edges and centre of `slice`.
+    fn draw_nine_slice(
+        &mut self,
+        texture: &Texture2D,
+        dest: Rect,
+        slice: &NineSlice,
+        color: Color,
+    ) {
+        let size = Vec2::new(texture.width(), texture.height());
+        for patch in slice.patches(size, dest) {
+            self.draw_texture_ex(
+                texture,
+                patch.dest.x,
+                patch.dest.y,
+                color,
+                DrawTextureParams {
+                    dest_size: Some(patch.dest.size()),
+                    source: Some(patch.source),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
     /// Restricts subsequent rendering to the given rectangle.
     ///
     /// Must be paired with [`pop_clip_rect`](Self::pop_clip_rect).
-    fn push_clip_rect(&mut self, rect: crate::types::Rect);
+    fn push_clip_rect(&mut self, rect: Rect);
 
     /// Removes the active clip rectangle set by [`push_clip_rect`](Self::push_clip_rect).
     fn pop_clip_rect(&mut self);
+
+    /// Multiplies the alpha of everything drawn afterwards by `opacity`.
+    /// Nested calls combine, so a fading element inside a fading menu fades with both.
+    ///
+    /// Must be paired with [`pop_opacity`](Self::pop_opacity).
+    fn push_opacity(&mut self, opacity: f32);
+
+    /// Restores the opacity active before the last [`push_opacity`](Self::push_opacity).
+    fn pop_opacity(&mut self);
 }
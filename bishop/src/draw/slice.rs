@@ -0,0 +1,337 @@
+//! Nine-slice and three-slice sprite layout.
+
+use crate::types::{Rect, Vec2};
+use serde::{Deserialize, Serialize};
+
+/// Most copies a tiled part draws along one axis before it stretches instead.
+const MAX_SLICE_TILES: f32 = 64.0;
+
+/// How the stretchy parts of a sliced sprite cover their space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SliceFill {
+    /// Scale the part to fit.
+    #[default]
+    Stretch,
+    /// Repeat the part at its own size, cropping the last copy.
+    Tile,
+}
+
+impl SliceFill {
+    /// All fill modes.
+    pub fn all() -> &'static [SliceFill] {
+        &[SliceFill::Stretch, SliceFill::Tile]
+    }
+
+    /// Display label for UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SliceFill::Stretch => "Stretch",
+            SliceFill::Tile => "Tile",
+        }
+    }
+}
+
+impl std::fmt::Display for SliceFill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// Border widths of a sliced sprite, in source pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SliceInsets {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl SliceInsets {
+    /// Creates insets with the same width on every side.
+    pub const fn uniform(inset: f32) -> Self {
+        Self {
+            left: inset,
+            top: inset,
+            right: inset,
+            bottom: inset,
+        }
+    }
+}
+
+/// How a sprite is cut into corners, edges and a centre so it scales
+/// without stretching its frame.
+///
+/// Leaving the insets of one axis at zero gives a three-slice sprite.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NineSlice {
+    pub insets: SliceInsets,
+    /// How the centre covers the space between the borders.
+    pub center: SliceFill,
+    /// How the edges cover the space between the corners.
+    pub edges: SliceFill,
+    /// Screen pixels per source pixel for the borders and tiles.
+    pub scale: f32,
+}
+
+impl Default for NineSlice {
+    fn default() -> Self {
+        Self {
+            insets: SliceInsets::default(),
+            center: SliceFill::Stretch,
+            edges: SliceFill::Stretch,
+            scale: 1.0,
+        }
+    }
+}
+
+/// One piece of a sliced sprite: where it comes from and where it goes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SlicePatch {
+    /// Source rect in texture pixels.
+    pub source: Rect,
+    pub dest: Rect,
+}
+
+/// A span along one axis. `part` is 0 for the start border, 1 for the
+/// middle and 2 for the end border.
+#[derive(Clone, Copy, Debug)]
+struct Band {
+    part: usize,
+    source: (f32, f32),
+    dest: (f32, f32),
+}
+
+impl NineSlice {
+    /// Creates a nine-slice with the given insets, stretching its centre and edges.
+    pub fn new(insets: SliceInsets) -> Self {
+        Self {
+            insets,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a three-slice that keeps its left and right caps.
+    pub fn horizontal(left: f32, right: f32) -> Self {
+        Self::new(SliceInsets {
+            left,
+            right,
+            ..Default::default()
+        })
+    }
+
+    /// Creates a three-slice that keeps its top and bottom caps.
+    pub fn vertical(top: f32, bottom: f32) -> Self {
+        Self::new(SliceInsets {
+            top,
+            bottom,
+            ..Default::default()
+        })
+    }
+
+    /// Returns a copy drawn `factor` times larger.
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            scale: self.scale * factor,
+            ..*self
+        }
+    }
+
+    /// Cuts a texture of `texture_size` into the patches that cover `dest`.
+    ///
+    /// Borders shrink evenly when `dest` is too small to hold them.
+    pub fn patches(&self, texture_size: Vec2, dest: Rect) -> Vec<SlicePatch> {
+        let scale = if self.scale > 0.0 { self.scale } else { 1.0 };
+        let axis = |fill: SliceFill, horizontal: bool| {
+            let (size, start, end, pos, len) = if horizontal {
+                let insets = (self.insets.left, self.insets.right);
+                (texture_size.x, insets.0, insets.1, dest.x, dest.w)
+            } else {
+                let insets = (self.insets.top, self.insets.bottom);
+                (texture_size.y, insets.0, insets.1, dest.y, dest.h)
+            };
+            bands(size, (start, end), pos, len, scale, fill)
+        };
+
+        // Edges run along the borders, so each axis uses the centre fill only
+        // inside the centre column or row
+        let columns = [axis(self.edges, true), axis(self.center, true)];
+        let rows = [axis(self.edges, false), axis(self.center, false)];
+
+        let mut patches = Vec::new();
+        for y_part in 0..3 {
+            for x_part in 0..3 {
+                let row_bands = &rows[usize::from(x_part == 1)];
+                let column_bands = &columns[usize::from(y_part == 1)];
+                for row in row_bands.iter().filter(|b| b.part == y_part) {
+                    for column in column_bands.iter().filter(|b| b.part == x_part) {
+                        patches.push(SlicePatch {
+                            source: Rect::new(
+                                column.source.0,
+                                row.source.0,
+                                column.source.1,
+                                row.source.1,
+                            ),
+                            dest: Rect::new(column.dest.0, row.dest.0, column.dest.1, row.dest.1),
+                        });
+                    }
+                }
+            }
+        }
+        patches
+    }
+}
+
+/// Splits one axis of the source and destination into border and middle bands.
+fn bands(
+    size: f32,
+    insets: (f32, f32),
+    pos: f32,
+    len: f32,
+    scale: f32,
+    fill: SliceFill,
+) -> Vec<Band> {
+    if size <= 0.0 || len <= 0.0 {
+        return Vec::new();
+    }
+
+    // Keep the source borders inside the texture
+    let (mut start, mut end) = (insets.0.max(0.0), insets.1.max(0.0));
+    if start + end > size {
+        let shrink = size / (start + end);
+        start *= shrink;
+        end *= shrink;
+    }
+
+    // Keep the drawn borders inside the destination
+    let (mut dest_start, mut dest_end) = (start * scale, end * scale);
+    if dest_start + dest_end > len {
+        let shrink = len / (dest_start + dest_end);
+        dest_start *= shrink;
+        dest_end *= shrink;
+    }
+
+    let mut bands = Vec::new();
+    if dest_start > 0.0 {
+        bands.push(Band {
+            part: 0,
+            source: (0.0, start),
+            dest: (pos, dest_start),
+        });
+    }
+
+    let middle = size - start - end;
+    let dest_middle = len - dest_start - dest_end;
+    if middle > 0.0 && dest_middle > 0.0 {
+        let middle_pos = pos + dest_start;
+        let tile = middle * scale;
+        // A tiny middle would tile into thousands of draws
+        let fill = if tile <= 0.0 || dest_middle / tile > MAX_SLICE_TILES {
+            SliceFill::Stretch
+        } else {
+            fill
+        };
+        match fill {
+            SliceFill::Stretch => bands.push(Band {
+                part: 1,
+                source: (start, middle),
+                dest: (middle_pos, dest_middle),
+            }),
+            SliceFill::Tile => {
+                let mut offset = 0.0;
+                while offset < dest_middle {
+                    let dest_len = tile.min(dest_middle - offset);
+                    bands.push(Band {
+                        part: 1,
+                        source: (start, dest_len / scale),
+                        dest: (middle_pos + offset, dest_len),
+                    });
+                    offset += tile;
+                }
+            }
+        }
+    }
+
+    if dest_end > 0.0 {
+        bands.push(Band {
+            part: 2,
+            source: (size - end, end),
+            dest: (pos + len - dest_end, dest_end),
+        });
+    }
+    bands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corners_keep_their_size_while_the_middle_stretches() {
+        let slice = NineSlice::new(SliceInsets::uniform(4.0));
+        let patches = slice.patches(Vec2::new(16.0, 16.0), Rect::new(10.0, 20.0, 100.0, 40.0));
+
+        assert_eq!(patches.len(), 9);
+        assert_eq!(patches[0].source, Rect::new(0.0, 0.0, 4.0, 4.0));
+        assert_eq!(patches[0].dest, Rect::new(10.0, 20.0, 4.0, 4.0));
+        assert_eq!(patches[4].source, Rect::new(4.0, 4.0, 8.0, 8.0));
+        assert_eq!(patches[4].dest, Rect::new(14.0, 24.0, 92.0, 32.0));
+        assert_eq!(patches[8].dest, Rect::new(106.0, 56.0, 4.0, 4.0));
+
+        // Doubling the scale doubles the borders
+        let patches = slice
+            .scaled(2.0)
+            .patches(Vec2::new(16.0, 16.0), Rect::new(0.0, 0.0, 100.0, 40.0));
+        assert_eq!(patches[0].dest, Rect::new(0.0, 0.0, 8.0, 8.0));
+    }
+
+    #[test]
+    fn tiled_parts_repeat_and_crop_the_last_copy() {
+        let slice = NineSlice {
+            center: SliceFill::Tile,
+            ..NineSlice::horizontal(4.0, 4.0)
+        };
+        let patches = slice.patches(Vec2::new(16.0, 8.0), Rect::new(0.0, 0.0, 28.0, 8.0));
+
+        // Caps, then two full tiles of 8 and one of 4 between them
+        let dests: Vec<(f32, f32)> = patches.iter().map(|p| (p.dest.x, p.dest.w)).collect();
+        assert_eq!(
+            dests,
+            vec![
+                (0.0, 4.0),
+                (4.0, 8.0),
+                (12.0, 8.0),
+                (20.0, 4.0),
+                (24.0, 4.0)
+            ]
+        );
+        assert_eq!(patches[3].source, Rect::new(4.0, 0.0, 4.0, 8.0));
+    }
+
+    #[test]
+    fn a_one_pixel_middle_stretches_instead_of_tiling() {
+        let slice = NineSlice {
+            center: SliceFill::Tile,
+            ..NineSlice::horizontal(4.0, 4.0)
+        };
+        let patches = slice.patches(Vec2::new(9.0, 8.0), Rect::new(0.0, 0.0, 1000.0, 8.0));
+
+        assert_eq!(patches.len(), 3);
+        assert_eq!(patches[1].source, Rect::new(4.0, 0.0, 1.0, 8.0));
+        assert_eq!(patches[1].dest, Rect::new(4.0, 0.0, 992.0, 8.0));
+    }
+
+    #[test]
+    fn borders_shrink_to_fit_a_small_destination() {
+        let slice = NineSlice::new(SliceInsets::uniform(8.0));
+        let patches = slice.patches(Vec2::new(32.0, 32.0), Rect::new(0.0, 0.0, 8.0, 8.0));
+
+        assert_eq!(patches.len(), 4);
+        assert_eq!(patches[0].dest, Rect::new(0.0, 0.0, 4.0, 4.0));
+        assert_eq!(patches[3].dest, Rect::new(4.0, 4.0, 4.0, 4.0));
+        assert!(slice
+            .patches(Vec2::new(32.0, 32.0), Rect::new(0.0, 0.0, 0.0, 8.0))
+            .is_empty());
+    }
+}
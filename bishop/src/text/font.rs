@@ -0,0 +1,188 @@
+//! Font handles and font sources.
+
+use std::collections::HashMap;
+
+/// Handle to a font loaded into a text backend.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FontId(pub u32);
+
+impl FontId {
+    /// The font bundled with the backend.
+    pub const DEFAULT: FontId = FontId(0);
+}
+
+/// Font data passed to `Text::load_font`.
+pub enum FontSource<'a> {
+    /// TrueType or OpenType font data.
+    Vector(&'a [u8]),
+    /// A BMFont descriptor and the PNG data of its single texture page.
+    Bitmap {
+        descriptor: BitmapFontDescriptor,
+        page: &'a [u8],
+    },
+}
+
+/// A glyph inside a bitmap font page, in page pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BitmapGlyph {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub x_offset: f32,
+    /// Offset from the top of the line to the top of the glyph.
+    pub y_offset: f32,
+    pub x_advance: f32,
+    pub page: u32,
+}
+
+/// A parsed BMFont text descriptor (`.fnt`).
+#[derive(Clone, Debug, Default)]
+pub struct BitmapFontDescriptor {
+    /// Pixel size the glyphs were rendered at.
+    pub size: f32,
+    pub line_height: f32,
+    /// Distance from the top of a line to the baseline.
+    pub base: f32,
+    /// Texture page file names, indexed by page id.
+    pub pages: Vec<String>,
+    pub glyphs: HashMap<char, BitmapGlyph>,
+}
+
+impl BitmapFontDescriptor {
+    /// Returns true when the glyphs are spread over more than one texture page.
+    pub fn is_multi_page(&self) -> bool {
+        self.pages.len() > 1 || self.glyphs.values().any(|glyph| glyph.page != 0)
+    }
+
+    /// Parses the text variant of the BMFont descriptor format.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut descriptor = Self::default();
+
+        for (line_number, line) in source.lines().enumerate() {
+            let mut tokens = tokenize(line).into_iter();
+            let Some(tag) = tokens.next() else {
+                continue;
+            };
+            let attributes: HashMap<String, String> = tokens
+                .filter_map(|token| {
+                    let (key, value) = token.split_once('=')?;
+                    Some((key.to_string(), value.trim_matches('"').to_string()))
+                })
+                .collect();
+            let number = |key: &str| -> Result<f32, String> {
+                attributes
+                    .get(key)
+                    .ok_or_else(|| format!("line {}: missing '{key}'", line_number + 1))?
+                    .parse::<f32>()
+                    .map_err(|_| format!("line {}: invalid '{key}'", line_number + 1))
+            };
+
+            match tag.as_str() {
+                "info" => descriptor.size = number("size")?.abs(),
+                "common" => {
+                    descriptor.line_height = number("lineHeight")?;
+                    descriptor.base = number("base")?;
+                }
+                "page" => {
+                    let id = number("id")? as usize;
+                    let file = attributes
+                        .get("file")
+                        .ok_or_else(|| format!("line {}: missing 'file'", line_number + 1))?;
+                    if descriptor.pages.len() <= id {
+                        descriptor.pages.resize(id + 1, String::new());
+                    }
+                    descriptor.pages[id] = file.clone();
+                }
+                "char" => {
+                    let Some(character) = char::from_u32(number("id")? as u32) else {
+                        continue;
+                    };
+                    let glyph = BitmapGlyph {
+                        x: number("x")? as u32,
+                        y: number("y")? as u32,
+                        width: number("width")? as u32,
+                        height: number("height")? as u32,
+                        x_offset: number("xoffset")?,
+                        y_offset: number("yoffset")?,
+                        x_advance: number("xadvance")?,
+                        page: number("page").unwrap_or(0.0) as u32,
+                    };
+                    descriptor.glyphs.insert(character, glyph);
+                }
+                _ => {}
+            }
+        }
+
+        if descriptor.line_height <= 0.0 {
+            return Err("missing 'common' line".to_string());
+        }
+        if descriptor.size <= 0.0 {
+            descriptor.size = descriptor.line_height;
+        }
+        Ok(descriptor)
+    }
+}
+
+/// Splits a descriptor line on whitespace, keeping quoted values together.
+fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for ch in line.chars() {
+        match ch {
+            '"' => {
+                quoted = !quoted;
+                current.push(ch);
+            }
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESCRIPTOR: &str = r#"info face="Pixel Font" size=-8 bold=0
+common lineHeight=10 base=8 scaleW=64 scaleH=64 pages=1
+page id=0 file="pixel_0.png"
+chars count=2
+char id=65   x=0     y=0     width=5     height=7     xoffset=0     yoffset=1     xadvance=6     page=0  chnl=15
+char id=32   x=0     y=0     width=0     height=0     xoffset=0     yoffset=0     xadvance=3     page=0  chnl=15
+"#;
+
+    #[test]
+    fn parses_text_descriptor() {
+        let font = BitmapFontDescriptor::parse(DESCRIPTOR).unwrap();
+
+        assert_eq!(font.size, 8.0);
+        assert_eq!(font.line_height, 10.0);
+        assert_eq!(font.base, 8.0);
+        assert_eq!(font.pages, vec!["pixel_0.png".to_string()]);
+        assert_eq!(font.glyphs[&'A'].width, 5);
+        assert_eq!(font.glyphs[&'A'].y_offset, 1.0);
+        assert_eq!(font.glyphs[&' '].x_advance, 3.0);
+        assert!(!font.is_multi_page());
+
+        let two_pages = DESCRIPTOR.replace("chars count=2", "page id=1 file=\"pixel_1.png\"");
+        assert!(BitmapFontDescriptor::parse(&two_pages)
+            .unwrap()
+            .is_multi_page());
+    }
+
+    #[test]
+    fn rejects_descriptor_without_common_line() {
+        assert!(BitmapFontDescriptor::parse("info size=8").is_err());
+    }
+}
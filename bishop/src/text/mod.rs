@@ -1,9 +1,11 @@
 //! Text rendering and measurement.
 
 mod dimensions;
+mod font;
 mod params;
 
 pub use dimensions::*;
+pub use font::*;
 pub use params::*;
 
 use crate::types::Color;
@@ -25,4 +27,27 @@ pub trait Text {
 
     /// Measures text without drawing it.
     fn measure_text(&self, text: &str, font_size: f32) -> TextDimensions;
+
+    /// Draws text with a loaded font. Backends without custom fonts use the default font.
+    fn draw_text_with_font(
+        &mut self,
+        text: &str,
+        x: f32,
+        y: f32,
+        _font: FontId,
+        font_size: f32,
+        color: Color,
+    ) -> TextDimensions {
+        self.draw_text(text, x, y, font_size, color)
+    }
+
+    /// Measures text drawn with a loaded font without drawing it.
+    fn measure_text_with_font(&self, text: &str, _font: FontId, font_size: f32) -> TextDimensions {
+        self.measure_text(text, font_size)
+    }
+
+    /// Loads a font and returns the handle used to draw with it.
+    fn load_font(&mut self, _source: FontSource<'_>) -> Result<FontId, String> {
+        Err("Custom fonts are not supported by this backend".to_string())
+    }
 }
@@ -1,11 +1,13 @@
 //! Text rendering parameters.
 
+use super::FontId;
 use crate::types::Color;
 
 /// Arguments for "draw_text_ex" function such as font, font_size etc
 #[derive(Debug, Clone)]
 pub struct TextParams {
-    pub font: Option<()>,
+    /// Font to draw with. `None` uses the default font.
+    pub font: Option<FontId>,
     /// Base size for character height. The size in pixel used during font rasterizing.
     pub font_size: u16,
     /// The glyphs sizes actually drawn on the screen will be font_size * font_scale
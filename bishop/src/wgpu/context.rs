@@ -15,6 +15,7 @@ use super::render::{
 };
 use super::state::{GraphicsState, GraphicsStateError, InputState, TimeState};
 use crate::camera::Camera2D;
+use crate::text::FontId;
 use crate::types::Color;
 use crate::window::CursorIcon;
 
@@ -31,6 +32,7 @@ enum DrawSegment {
     Text {
         start: u32,
         count: u32,
+        font: FontId,
     },
 }
 
@@ -56,6 +58,8 @@ pub struct WgpuContext {
     saved_surface_view: Option<wgpu::TextureView>,
     render_target_dims: Option<(f32, f32)>,
     clip_rect: Option<[u32; 4]>,
+    /// Opacities pushed by callers; draws are faded by their product.
+    opacity_stack: Vec<f32>,
     draw_segments: Vec<DrawSegment>,
 }
 
@@ -96,6 +100,7 @@ impl WgpuContext {
             saved_surface_view: None,
             render_target_dims: None,
             clip_rect: None,
+            opacity_stack: Vec::new(),
             draw_segments: Vec::new(),
         })
     }
@@ -161,6 +166,7 @@ impl WgpuContext {
         self.current_camera = None;
         self.has_cleared_this_frame = false;
         self.clip_rect = None;
+        self.opacity_stack.clear();
     }
 
     /// Processes a winit WindowEvent and updates internal state.
@@ -290,6 +296,16 @@ impl WgpuContext {
         self.fullscreen
     }
 
+    /// Turns vertical sync on or off.
+    pub fn set_vsync(&mut self, enabled: bool) {
+        self.graphics.set_vsync(enabled);
+    }
+
+    /// Returns whether vertical sync is on.
+    pub fn is_vsync(&self) -> bool {
+        self.graphics.is_vsync()
+    }
+
     /// Returns the display scale factor (DPI scaling).
     pub fn scale_factor(&self) -> f32 {
         self.scale_factor
@@ -315,6 +331,25 @@ impl WgpuContext {
         self.clip_rect = None;
     }
 
+    /// Pushes an opacity that multiplies the alpha of later draws.
+    pub fn push_opacity(&mut self, opacity: f32) {
+        self.opacity_stack.push(opacity.clamp(0.0, 1.0));
+    }
+
+    /// Removes the most recently pushed opacity.
+    pub fn pop_opacity(&mut self) {
+        self.opacity_stack.pop();
+    }
+
+    /// Fades `color` by the pushed opacities.
+    pub(crate) fn faded(&self, color: Color) -> Color {
+        if self.opacity_stack.is_empty() {
+            return color;
+        }
+        let opacity: f32 = self.opacity_stack.iter().product();
+        Color::new(color.r, color.g, color.b, color.a * opacity)
+    }
+
     /// Returns the texture bind group layout for creating textures.
     pub fn texture_bind_group_layout(&self) -> &wgpu::BindGroupLayout {
         self.texture_renderer.texture_bind_group_layout()
@@ -447,9 +482,9 @@ impl WgpuContext {
         }
     }
 
-    /// Records a text draw segment, merging with the previous if it was also text.
+    /// Records a text draw segment, merging with the previous if it was also text in the same font.
     /// Also seals the texture batch so the next texture draw starts fresh.
-    pub(super) fn record_text_segment(&mut self, prev: u32) {
+    pub(super) fn record_text_segment(&mut self, prev: u32, font: FontId) {
         let current = self.text_renderer.vertex_count() as u32;
         let count = current - prev;
         if count == 0 {
@@ -457,10 +492,14 @@ impl WgpuContext {
         }
         self.texture_renderer.seal_batch();
         match self.draw_segments.last_mut() {
-            Some(DrawSegment::Text { count: c, .. }) => *c += count,
-            _ => self
-                .draw_segments
-                .push(DrawSegment::Text { start: prev, count }),
+            Some(DrawSegment::Text {
+                count: c, font: f, ..
+            }) if *f == font => *c += count,
+            _ => self.draw_segments.push(DrawSegment::Text {
+                start: prev,
+                count,
+                font,
+            }),
         }
     }
 
@@ -542,8 +581,8 @@ impl WgpuContext {
                             *batch_count,
                         );
                     }
-                    DrawSegment::Text { start, count } => {
-                        if self.text_renderer.setup_pipeline(&mut render_pass) {
+                    DrawSegment::Text { start, count, font } => {
+                        if self.text_renderer.setup_pipeline(&mut render_pass, *font) {
                             self.text_renderer
                                 .draw_range(&mut render_pass, *start, *count);
                         }
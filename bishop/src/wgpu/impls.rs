@@ -1,24 +1,17 @@
 //! Trait implementations for WgpuContext.
 
-use std::cell::RefCell;
-
 use super::context::WgpuContext;
-use super::render::FontAtlas;
 use super::render::WgpuTexture;
 use crate::camera::{Camera, Camera2D};
 use crate::draw::{Draw, DrawTextureParams};
 use crate::input::{Input, KeyCode, MouseButton};
 use crate::material::RenderOps;
-use crate::text::{Text, TextDimensions};
+use crate::text::{FontId, FontSource, Text, TextDimensions};
 use crate::time::Time;
 use crate::types::{Color, Texture2D, Vec2};
 use crate::window::Window;
 use crate::TextureLoader;
 
-thread_local! {
-    static MEASURE_ATLAS: RefCell<Option<FontAtlas>> = const { RefCell::new(None) };
-}
-
 impl Input for WgpuContext {
     fn is_key_down(&self, key: KeyCode) -> bool {
         self.input.is_key_down(key)
@@ -71,6 +64,7 @@ impl Input for WgpuContext {
 
 impl Draw for WgpuContext {
     fn draw_rectangle(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        let color = self.faded(color);
         let prev = self.primitive_renderer.vertex_count() as u32;
         self.primitive_renderer.draw_rectangle(x, y, w, h, color);
         self.record_primitive_segment(prev);
@@ -85,6 +79,7 @@ impl Draw for WgpuContext {
         thickness: f32,
         color: Color,
     ) {
+        let color = self.faded(color);
         let prev = self.primitive_renderer.vertex_count() as u32;
         self.primitive_renderer
             .draw_rectangle_lines(x, y, w, h, thickness, color);
@@ -92,6 +87,7 @@ impl Draw for WgpuContext {
     }
 
     fn draw_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color) {
+        let color = self.faded(color);
         let prev = self.primitive_renderer.vertex_count() as u32;
         self.primitive_renderer
             .draw_line(x1, y1, x2, y2, thickness, color);
@@ -99,12 +95,14 @@ impl Draw for WgpuContext {
     }
 
     fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color) {
+        let color = self.faded(color);
         let prev = self.primitive_renderer.vertex_count() as u32;
         self.primitive_renderer.draw_circle(x, y, radius, color);
         self.record_primitive_segment(prev);
     }
 
     fn draw_circle_lines(&mut self, x: f32, y: f32, radius: f32, thickness: f32, color: Color) {
+        let color = self.faded(color);
         let prev = self.primitive_renderer.vertex_count() as u32;
         self.primitive_renderer
             .draw_circle_lines(x, y, radius, thickness, color);
@@ -112,6 +110,7 @@ impl Draw for WgpuContext {
     }
 
     fn draw_triangle(&mut self, v1: Vec2, v2: Vec2, v3: Vec2, color: Color) {
+        let color = self.faded(color);
         let prev = self.primitive_renderer.vertex_count() as u32;
         self.primitive_renderer.draw_triangle(v1, v2, v3, color);
         self.record_primitive_segment(prev);
@@ -122,6 +121,7 @@ impl Draw for WgpuContext {
     }
 
     fn draw_texture(&mut self, texture: &Texture2D, x: f32, y: f32, color: Color) {
+        let color = self.faded(color);
         let prev = self.texture_renderer.batch_count();
         self.texture_renderer
             .draw_texture(texture.inner(), x, y, color);
@@ -136,6 +136,7 @@ impl Draw for WgpuContext {
         color: Color,
         params: DrawTextureParams,
     ) {
+        let color = self.faded(color);
         let prev = self.texture_renderer.batch_count();
         self.texture_renderer
             .draw_texture_ex(texture.inner(), x, y, color, params);
@@ -149,6 +150,14 @@ impl Draw for WgpuContext {
     fn pop_clip_rect(&mut self) {
         self.pop_clip_rect();
     }
+
+    fn push_opacity(&mut self, opacity: f32) {
+        self.push_opacity(opacity);
+    }
+
+    fn pop_opacity(&mut self) {
+        self.pop_opacity();
+    }
 }
 
 impl Text for WgpuContext {
@@ -160,10 +169,7 @@ impl Text for WgpuContext {
         font_size: f32,
         color: Color,
     ) -> TextDimensions {
-        let prev = self.text_renderer.vertex_count() as u32;
-        let dims = self.text_renderer.draw_text(text, x, y, font_size, color);
-        self.record_text_segment(prev);
-        dims
+        self.draw_text_with_font(text, x, y, FontId::DEFAULT, font_size, color)
     }
 
     fn draw_text_ex(
@@ -171,23 +177,44 @@ impl Text for WgpuContext {
         text: &str,
         x: f32,
         y: f32,
-        params: crate::text::TextParams,
+        mut params: crate::text::TextParams,
     ) -> TextDimensions {
+        params.color = self.faded(params.color);
         let prev = self.text_renderer.vertex_count() as u32;
         let dims = self.text_renderer.draw_text_ex(text, x, y, &params);
-        self.record_text_segment(prev);
+        self.record_text_segment(prev, params.font.unwrap_or_default());
         dims
     }
 
     fn measure_text(&self, text: &str, font_size: f32) -> TextDimensions {
-        MEASURE_ATLAS.with(|cell| {
-            let mut atlas_opt = cell.borrow_mut();
-            if atlas_opt.is_none() {
-                *atlas_opt =
-                    Some(FontAtlas::with_default_font().expect("Failed to create font atlas"));
-            }
-            atlas_opt.as_mut().unwrap().measure_text(text, font_size)
-        })
+        self.measure_text_with_font(text, FontId::DEFAULT, font_size)
+    }
+
+    fn draw_text_with_font(
+        &mut self,
+        text: &str,
+        x: f32,
+        y: f32,
+        font: FontId,
+        font_size: f32,
+        color: Color,
+    ) -> TextDimensions {
+        let color = self.faded(color);
+        let prev = self.text_renderer.vertex_count() as u32;
+        let dims = self
+            .text_renderer
+            .draw_text(text, x, y, font, font_size, color);
+        self.record_text_segment(prev, font);
+        dims
+    }
+
+    fn measure_text_with_font(&self, text: &str, font: FontId, font_size: f32) -> TextDimensions {
+        self.text_renderer.measure_text(text, font, font_size)
+    }
+
+    fn load_font(&mut self, source: FontSource<'_>) -> Result<FontId, String> {
+        self.text_renderer
+            .load_font(&self.graphics.device, &self.graphics.queue, source)
     }
 }
 
@@ -261,6 +288,14 @@ impl Window for WgpuContext {
         self.is_fullscreen()
     }
 
+    fn set_vsync(&mut self, enabled: bool) {
+        self.set_vsync(enabled);
+    }
+
+    fn is_vsync(&self) -> bool {
+        self.is_vsync()
+    }
+
     fn scale_factor(&self) -> f32 {
         self.scale_factor()
     }
@@ -7,7 +7,7 @@ use wgpu::util::DeviceExt;
 use super::sampler::create_nearest_sampler;
 use super::uniforms::CameraUniforms;
 use super::vertex::TexturedVertex;
-use crate::text::TextDimensions;
+use crate::text::{BitmapFontDescriptor, FontId, FontSource, TextDimensions};
 use crate::types::Color;
 
 const ATLAS_SIZE: u32 = 1024;
@@ -23,20 +23,33 @@ struct GlyphKey {
 }
 
 /// Information about a cached glyph in the atlas.
+/// `x`, `y`, `width` and `height` are atlas pixels; the rest is in screen pixels.
 #[derive(Clone, Copy, Debug)]
 struct GlyphInfo {
     x: u32,
     y: u32,
     width: u32,
     height: u32,
+    /// Screen pixels per atlas pixel.
+    scale: f32,
     advance_width: f32,
     offset_x: f32,
     offset_y: f32,
 }
 
+/// Where a font atlas gets its glyphs from.
+enum FontFace {
+    /// Glyphs are rasterized on demand at each requested size.
+    Vector(fontdue::Font),
+    /// Glyphs are fixed regions of a pre-rendered page, scaled to the requested size.
+    Bitmap(BitmapFontDescriptor),
+}
+
 /// Font atlas that caches rasterized glyphs on the GPU.
 pub struct FontAtlas {
-    font: fontdue::Font,
+    face: FontFace,
+    atlas_width: u32,
+    atlas_height: u32,
     atlas_data: Vec<u8>,
     atlas_texture: Option<wgpu::Texture>,
     atlas_view: Option<wgpu::TextureView>,
@@ -57,8 +70,43 @@ impl FontAtlas {
 
         let atlas_data = vec![0u8; (ATLAS_SIZE * ATLAS_SIZE * 4) as usize];
 
-        Ok(Self {
-            font,
+        Ok(Self::with_face(
+            FontFace::Vector(font),
+            ATLAS_SIZE,
+            ATLAS_SIZE,
+            atlas_data,
+        ))
+    }
+
+    /// Creates a font atlas from a BMFont descriptor and the PNG data of its page.
+    pub fn from_bitmap(descriptor: BitmapFontDescriptor, page: &[u8]) -> Result<Self, String> {
+        if descriptor.is_multi_page() {
+            return Err("Bitmap fonts with more than one page are not supported".to_string());
+        }
+
+        let image = image::load_from_memory(page)
+            .map_err(|e| format!("Failed to decode bitmap font page: {e}"))?
+            .to_rgba8();
+        let (width, height) = image.dimensions();
+
+        Ok(Self::with_face(
+            FontFace::Bitmap(descriptor),
+            width,
+            height,
+            image.into_raw(),
+        ))
+    }
+
+    /// Creates a font atlas with the embedded GNF font.
+    pub fn with_default_font() -> Result<Self, &'static str> {
+        Self::new(GNF_FONT_DATA)
+    }
+
+    fn with_face(face: FontFace, atlas_width: u32, atlas_height: u32, atlas_data: Vec<u8>) -> Self {
+        Self {
+            face,
+            atlas_width,
+            atlas_height,
             atlas_data,
             atlas_texture: None,
             atlas_view: None,
@@ -69,12 +117,7 @@ impl FontAtlas {
             cursor_y: 0,
             row_height: 0,
             dirty: true,
-        })
-    }
-
-    /// Creates a font atlas with the embedded GNF font.
-    pub fn with_default_font() -> Result<Self, &'static str> {
-        Self::new(GNF_FONT_DATA)
+        }
     }
 
     /// Initializes GPU resources for the atlas.
@@ -85,8 +128,8 @@ impl FontAtlas {
         texture_bind_group_layout: &wgpu::BindGroupLayout,
     ) {
         let size = wgpu::Extent3d {
-            width: ATLAS_SIZE,
-            height: ATLAS_SIZE,
+            width: self.atlas_width,
+            height: self.atlas_height,
             depth_or_array_layers: 1,
         };
 
@@ -130,6 +173,11 @@ impl FontAtlas {
 
     /// Gets or rasterizes a glyph, returning its info.
     fn get_glyph(&mut self, character: char, font_size: f32) -> Option<GlyphInfo> {
+        let font = match &self.face {
+            FontFace::Vector(font) => font,
+            FontFace::Bitmap(descriptor) => return bitmap_glyph(descriptor, character, font_size),
+        };
+
         let font_size_px = font_size as u32;
         let key = GlyphKey {
             character,
@@ -140,7 +188,7 @@ impl FontAtlas {
             return Some(*info);
         }
 
-        let (metrics, bitmap) = self.font.rasterize(character, font_size);
+        let (metrics, bitmap) = font.rasterize(character, font_size);
 
         if metrics.width == 0 || metrics.height == 0 {
             let info = GlyphInfo {
@@ -148,6 +196,7 @@ impl FontAtlas {
                 y: 0,
                 width: 0,
                 height: 0,
+                scale: 1.0,
                 advance_width: metrics.advance_width,
                 offset_x: metrics.xmin as f32,
                 offset_y: metrics.ymin as f32,
@@ -159,13 +208,13 @@ impl FontAtlas {
         let glyph_w = metrics.width as u32;
         let glyph_h = metrics.height as u32;
 
-        if self.cursor_x + glyph_w >= ATLAS_SIZE {
+        if self.cursor_x + glyph_w >= self.atlas_width {
             self.cursor_x = 0;
             self.cursor_y += self.row_height + 1;
             self.row_height = 0;
         }
 
-        if self.cursor_y + glyph_h >= ATLAS_SIZE {
+        if self.cursor_y + glyph_h >= self.atlas_height {
             return None;
         }
 
@@ -177,7 +226,7 @@ impl FontAtlas {
             let py = (i / metrics.width) as u32;
             let atlas_x = gx + px;
             let atlas_y = gy + py;
-            let idx = ((atlas_y * ATLAS_SIZE + atlas_x) * 4) as usize;
+            let idx = ((atlas_y * self.atlas_width + atlas_x) * 4) as usize;
             self.atlas_data[idx] = 255;
             self.atlas_data[idx + 1] = 255;
             self.atlas_data[idx + 2] = 255;
@@ -189,6 +238,7 @@ impl FontAtlas {
             y: gy,
             width: glyph_w,
             height: glyph_h,
+            scale: 1.0,
             advance_width: metrics.advance_width,
             offset_x: metrics.xmin as f32,
             offset_y: metrics.ymin as f32,
@@ -202,6 +252,18 @@ impl FontAtlas {
         Some(info)
     }
 
+    /// Returns the atlas UV rectangle of a glyph as `[u0, v0, u1, v1]`.
+    fn glyph_uvs(&self, info: &GlyphInfo) -> [f32; 4] {
+        let w = self.atlas_width as f32;
+        let h = self.atlas_height as f32;
+        [
+            info.x as f32 / w,
+            info.y as f32 / h,
+            (info.x + info.width) as f32 / w,
+            (info.y + info.height) as f32 / h,
+        ]
+    }
+
     /// Uploads the atlas texture to the GPU if dirty.
     pub fn upload(&mut self, queue: &wgpu::Queue) {
         if !self.dirty {
@@ -219,12 +281,12 @@ impl FontAtlas {
                 &self.atlas_data,
                 wgpu::TexelCopyBufferLayout {
                     offset: 0,
-                    bytes_per_row: Some(4 * ATLAS_SIZE),
-                    rows_per_image: Some(ATLAS_SIZE),
+                    bytes_per_row: Some(4 * self.atlas_width),
+                    rows_per_image: Some(self.atlas_height),
                 },
                 wgpu::Extent3d {
-                    width: ATLAS_SIZE,
-                    height: ATLAS_SIZE,
+                    width: self.atlas_width,
+                    height: self.atlas_height,
                     depth_or_array_layers: 1,
                 },
             );
@@ -234,20 +296,13 @@ impl FontAtlas {
     }
 
     /// Measures text without drawing it.
-    pub fn measure_text(&mut self, text: &str, font_size: f32) -> TextDimensions {
-        let mut width = 0.0f32;
-
-        for ch in text.chars() {
-            if let Some(info) = self.get_glyph(ch, font_size) {
-                width += info.advance_width;
-            }
-        }
+    pub fn measure_text(&self, text: &str, font_size: f32) -> TextDimensions {
+        let width = text
+            .chars()
+            .map(|ch| self.advance_width(ch, font_size))
+            .sum();
 
-        let line_metrics = self.font.horizontal_line_metrics(font_size);
-        let (ascent, descent) = match line_metrics {
-            Some(m) => (m.ascent, -m.descent),
-            None => (font_size, 0.0),
-        };
+        let (ascent, descent) = self.line_metrics(font_size);
 
         TextDimensions {
             width,
@@ -256,9 +311,30 @@ impl FontAtlas {
         }
     }
 
-    /// Returns the font's global line metrics for the given pixel size.
-    pub fn font_line_metrics(&self, font_size: f32) -> Option<fontdue::LineMetrics> {
-        self.font.horizontal_line_metrics(font_size)
+    /// Returns how far the cursor moves after a glyph, without rasterizing it.
+    fn advance_width(&self, character: char, font_size: f32) -> f32 {
+        match &self.face {
+            FontFace::Vector(font) => font.metrics(character, font_size).advance_width,
+            FontFace::Bitmap(descriptor) => bitmap_glyph(descriptor, character, font_size)
+                .map_or(0.0, |info| info.advance_width),
+        }
+    }
+
+    /// Returns the ascent and descent of a line at the given pixel size.
+    pub fn line_metrics(&self, font_size: f32) -> (f32, f32) {
+        match &self.face {
+            FontFace::Vector(font) => match font.horizontal_line_metrics(font_size) {
+                Some(m) => (m.ascent, -m.descent),
+                None => (font_size, 0.0),
+            },
+            FontFace::Bitmap(descriptor) => {
+                let scale = font_size / descriptor.size;
+                (
+                    descriptor.base * scale,
+                    (descriptor.line_height - descriptor.base) * scale,
+                )
+            }
+        }
     }
 
     /// Pre-caches common characters at multiple sizes.
@@ -284,15 +360,36 @@ impl FontAtlas {
     }
 }
 
-/// Text renderer using the font atlas.
+/// Looks up a bitmap glyph and scales it from the font's native size to `font_size`.
+fn bitmap_glyph(
+    descriptor: &BitmapFontDescriptor,
+    character: char,
+    font_size: f32,
+) -> Option<GlyphInfo> {
+    let glyph = descriptor.glyphs.get(&character)?;
+    let scale = font_size / descriptor.size;
+
+    Some(GlyphInfo {
+        x: glyph.x,
+        y: glyph.y,
+        width: glyph.width,
+        height: glyph.height,
+        scale,
+        advance_width: glyph.x_advance * scale,
+        offset_x: glyph.x_offset * scale,
+        offset_y: (descriptor.base - glyph.y_offset - glyph.height as f32) * scale,
+    })
+}
+
+/// Text renderer using one font atlas per loaded font.
 pub struct TextRenderer {
     pipeline: wgpu::RenderPipeline,
     vertex_buffer: wgpu::Buffer,
     uniform_buffer: wgpu::Buffer,
     camera_bind_group: wgpu::BindGroup,
-    #[allow(dead_code)]
     texture_bind_group_layout: wgpu::BindGroupLayout,
-    font_atlas: FontAtlas,
+    /// Font atlases indexed by `FontId`. The default font is always first.
+    fonts: Vec<FontAtlas>,
     vertices: Vec<TexturedVertex>,
 }
 
@@ -419,7 +516,7 @@ impl TextRenderer {
             uniform_buffer,
             camera_bind_group,
             texture_bind_group_layout,
-            font_atlas,
+            fonts: vec![font_atlas],
             vertices: Vec::with_capacity(MAX_VERTICES),
         }
     }
@@ -430,6 +527,37 @@ impl TextRenderer {
         &self.texture_bind_group_layout
     }
 
+    /// Creates an atlas for a font and returns its handle.
+    pub fn load_font(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        source: FontSource<'_>,
+    ) -> Result<FontId, String> {
+        let mut atlas = match source {
+            FontSource::Vector(data) => FontAtlas::new(data)?,
+            FontSource::Bitmap { descriptor, page } => FontAtlas::from_bitmap(descriptor, page)?,
+        };
+        atlas.init_gpu(device, queue, &self.texture_bind_group_layout);
+
+        self.fonts.push(atlas);
+        Ok(FontId(self.fonts.len() as u32 - 1))
+    }
+
+    /// Returns the atlas index for a font, falling back to the default font for unknown ids.
+    fn font_index(&self, font: FontId) -> usize {
+        let index = font.0 as usize;
+        if index < self.fonts.len() {
+            index
+        } else {
+            0
+        }
+    }
+
+    fn atlas(&self, font: FontId) -> &FontAtlas {
+        &self.fonts[self.font_index(font)]
+    }
+
     /// Clears all queued text for a new frame.
     pub fn clear(&mut self) {
         self.vertices.clear();
@@ -456,26 +584,26 @@ impl TextRenderer {
         text: &str,
         x: f32,
         y: f32,
+        font: FontId,
         font_size: f32,
         color: Color,
     ) -> TextDimensions {
         let c: [f32; 4] = color.into();
         let mut cursor_x = x;
         let baseline_y = y;
+        let index = self.font_index(font);
+        let atlas = &mut self.fonts[index];
 
         for ch in text.chars() {
-            if let Some(info) = self.font_atlas.get_glyph(ch, font_size) {
+            if let Some(info) = atlas.get_glyph(ch, font_size) {
                 if info.width > 0 && info.height > 0 {
-                    let gx = cursor_x + info.offset_x;
-                    let gy = baseline_y - info.height as f32 - info.offset_y;
+                    let gw = info.width as f32 * info.scale;
+                    let gh = info.height as f32 * info.scale;
 
-                    let u0 = info.x as f32 / ATLAS_SIZE as f32;
-                    let v0 = info.y as f32 / ATLAS_SIZE as f32;
-                    let u1 = (info.x + info.width) as f32 / ATLAS_SIZE as f32;
-                    let v1 = (info.y + info.height) as f32 / ATLAS_SIZE as f32;
+                    let gx = cursor_x + info.offset_x;
+                    let gy = baseline_y - gh - info.offset_y;
 
-                    let gw = info.width as f32;
-                    let gh = info.height as f32;
+                    let [u0, v0, u1, v1] = atlas.glyph_uvs(&info);
 
                     let v0_vert = TexturedVertex::new([gx, gy], [u0, v0], c);
                     let v1_vert = TexturedVertex::new([gx + gw, gy], [u1, v0], c);
@@ -490,11 +618,7 @@ impl TextRenderer {
             }
         }
 
-        let line_metrics = self.font_atlas.font_line_metrics(font_size);
-        let (ascent, descent) = match line_metrics {
-            Some(m) => (m.ascent, -m.descent),
-            None => (font_size, 0.0),
-        };
+        let (ascent, descent) = atlas.line_metrics(font_size);
 
         TextDimensions {
             width: cursor_x - x,
@@ -504,9 +628,8 @@ impl TextRenderer {
     }
 
     /// Measures text without drawing it.
-    #[allow(dead_code)]
-    pub fn measure_text(&mut self, text: &str, font_size: f32) -> TextDimensions {
-        self.font_atlas.measure_text(text, font_size)
+    pub fn measure_text(&self, text: &str, font: FontId, font_size: f32) -> TextDimensions {
+        self.atlas(font).measure_text(text, font_size)
     }
 
     /// Draws text with extended parameters including rotation support.
@@ -518,16 +641,19 @@ impl TextRenderer {
         params: &crate::text::TextParams,
     ) -> TextDimensions {
         let effective_font_size = params.font_size as f32 * params.font_scale;
+        let font = params.font.unwrap_or_default();
 
         if params.rotation == 0.0 {
-            return self.draw_text(text, x, y, effective_font_size, params.color);
+            return self.draw_text(text, x, y, font, effective_font_size, params.color);
         }
 
         let c: [f32; 4] = params.color.into();
         let font_scale_x = params.font_scale * params.font_scale_aspect;
         let font_scale_y = params.font_scale;
 
-        let dims = self.font_atlas.measure_text(text, params.font_size as f32);
+        let index = self.font_index(font);
+        let atlas = &mut self.fonts[index];
+        let dims = atlas.measure_text(text, params.font_size as f32);
         let scaled_width = dims.width * font_scale_x;
         let scaled_height = dims.height * font_scale_y;
         let scaled_ascent = dims.offset_y * font_scale_y;
@@ -544,20 +670,20 @@ impl TextRenderer {
         let mut cursor_x = 0.0f32;
 
         for ch in text.chars() {
-            if let Some(info) = self.font_atlas.get_glyph(ch, params.font_size as f32) {
+            if let Some(info) = atlas.get_glyph(ch, params.font_size as f32) {
                 if info.width > 0 && info.height > 0 {
+                    let glyph_w = info.width as f32 * info.scale;
+                    let glyph_h = info.height as f32 * info.scale;
+
                     let local_x = cursor_x * font_scale_x + info.offset_x * font_scale_x;
                     let local_y = dims.offset_y * font_scale_y
-                        - info.height as f32 * font_scale_y
+                        - glyph_h * font_scale_y
                         - info.offset_y * font_scale_y;
 
-                    let gw = info.width as f32 * font_scale_x;
-                    let gh = info.height as f32 * font_scale_y;
+                    let gw = glyph_w * font_scale_x;
+                    let gh = glyph_h * font_scale_y;
 
-                    let u0 = info.x as f32 / ATLAS_SIZE as f32;
-                    let v0 = info.y as f32 / ATLAS_SIZE as f32;
-                    let u1 = (info.x + info.width) as f32 / ATLAS_SIZE as f32;
-                    let v1 = (info.y + info.height) as f32 / ATLAS_SIZE as f32;
+                    let [u0, v0, u1, v1] = atlas.glyph_uvs(&info);
 
                     let corners = [
                         [local_x, local_y],
@@ -598,7 +724,9 @@ impl TextRenderer {
 
     /// Uploads any dirty atlas pixel data to the GPU. Must be called before the render pass.
     pub fn upload_atlas(&mut self, queue: &wgpu::Queue) {
-        self.font_atlas.upload(queue);
+        for atlas in &mut self.fonts {
+            atlas.upload(queue);
+        }
     }
 
     /// Uploads the vertex buffer to the GPU.
@@ -609,10 +737,14 @@ impl TextRenderer {
         queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(&self.vertices));
     }
 
-    /// Binds the pipeline, camera group, atlas group, and vertex buffer.
+    /// Binds the pipeline, camera group, the font's atlas group, and vertex buffer.
     /// Returns false if the atlas bind group is not yet ready.
-    pub fn setup_pipeline<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) -> bool {
-        let Some(bind_group) = self.font_atlas.bind_group() else {
+    pub fn setup_pipeline<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        font: FontId,
+    ) -> bool {
+        let Some(bind_group) = self.atlas(font).bind_group() else {
             return false;
         };
         render_pass.set_pipeline(&self.pipeline);
@@ -100,6 +100,25 @@ impl GraphicsState {
         }
     }
 
+    /// Switches between vsynced and uncapped presentation.
+    /// Falls back to whatever the surface supports.
+    pub fn set_vsync(&mut self, enabled: bool) {
+        self.config.present_mode = if enabled {
+            wgpu::PresentMode::AutoVsync
+        } else {
+            wgpu::PresentMode::AutoNoVsync
+        };
+        self.reconfigure();
+    }
+
+    /// Returns whether presentation waits for vertical sync.
+    pub fn is_vsync(&self) -> bool {
+        matches!(
+            self.config.present_mode,
+            wgpu::PresentMode::AutoVsync | wgpu::PresentMode::Fifo | wgpu::PresentMode::FifoRelaxed
+        )
+    }
+
     /// Reconfigures the surface with current settings without changing size.
     pub fn reconfigure(&self) {
         self.surface.configure(&self.device, &self.config);
@@ -23,6 +23,12 @@ pub trait Window {
     /// Returns whether the window is currently in fullscreen mode.
     fn is_fullscreen(&self) -> bool;
 
+    /// Turns vertical sync on or off.
+    fn set_vsync(&mut self, enabled: bool);
+
+    /// Returns whether vertical sync is on.
+    fn is_vsync(&self) -> bool;
+
     /// Returns the display scale factor (DPI scaling).
     fn scale_factor(&self) -> f32;
 }
@@ -17,6 +17,7 @@ struct EcsComponentArgs {
     deps: Vec<Type>,
     post_create: Option<Path>,
     post_remove: Option<Path>,
+    runtime: bool,
 }
 
 impl Parse for EcsComponentArgs {
@@ -24,6 +25,7 @@ impl Parse for EcsComponentArgs {
         let mut deps = Vec::new();
         let mut post_create = None;
         let mut post_remove = None;
+        let mut runtime = false;
 
         while !input.is_empty() {
             let ident: syn::Ident = input.parse()?;
@@ -39,10 +41,12 @@ impl Parse for EcsComponentArgs {
                 post_create = Some(input.parse()?);
             } else if ident == "post_remove" {
                 post_remove = Some(input.parse()?);
+            } else if ident == "runtime" {
+                runtime = input.parse::<syn::LitBool>()?.value;
             } else {
                 return Err(syn::Error::new_spanned(
                     ident,
-                    "Expected 'deps', 'post_create' or 'post_remove'",
+                    "Expected 'deps', 'post_create', 'post_remove' or 'runtime'",
                 ));
             }
 
@@ -55,6 +59,7 @@ impl Parse for EcsComponentArgs {
             deps,
             post_create,
             post_remove,
+            runtime,
         })
     }
 }
@@ -67,6 +72,7 @@ pub fn ecs_component(args: TokenStream, input: TokenStream) -> TokenStream {
             deps: Vec::new(),
             post_create: None,
             post_remove: None,
+            runtime: false,
         }
     } else {
         parse_macro_input!(args as EcsComponentArgs)
@@ -90,6 +96,14 @@ pub fn ecs_component(args: TokenStream, input: TokenStream) -> TokenStream {
 
     let fields = &struct_data.fields;
     let deps = &args.deps;
+    let runtime = args.runtime;
+
+    // Components deriving `Reflect` expose their fields to runtime systems
+    let reflect_fn = if derives_reflect(attrs) {
+        quote! { Some(crate::ecs::component_registry::reflect_component::<#name>) }
+    } else {
+        quote! { None }
+    };
 
     // Build the struct definition
     let struct_def = match fields {
@@ -275,6 +289,8 @@ pub fn ecs_component(args: TokenStream, input: TokenStream) -> TokenStream {
                 to_lua: <#name>::__to_lua,
                 from_lua: <#name>::__from_lua,
                 lua_schema: <#name as crate::ecs::component_registry::LuaSchema>::lua_schema,
+                reflect: #reflect_fn,
+                runtime: #runtime,
                 post_create: #post_create_fn,
                 post_remove: #post_remove_fn,
             }
@@ -284,6 +300,28 @@ pub fn ecs_component(args: TokenStream, input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Returns true if the struct carries a `#[derive(..., Reflect, ...)]` attribute.
+fn derives_reflect(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .any(|attr| {
+            let mut found = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|s| s.ident == "Reflect")
+                {
+                    found = true;
+                }
+                Ok(())
+            });
+            found
+        })
+}
+
 fn generate_lua_schema(fields: &Fields) -> proc_macro2::TokenStream {
     match fields {
         // Normal struct { a: T, b: U }
@@ -65,7 +65,7 @@ fn generate_lua_components() {
     );
 
     // Generate class definitions for each component with their schema
-    for reg in COMPONENTS.iter() {
+    for reg in COMPONENTS.iter().filter(|r| !r.runtime) {
         let schema = (reg.lua_schema)();
 
         // Check if this is an alias type (single-value tuple struct)
@@ -92,7 +92,7 @@ fn generate_lua_components() {
 
     // Generate the ComponentId class with all component names
     lua.push_str("---@class ComponentId\n");
-    for reg in COMPONENTS.iter() {
+    for reg in COMPONENTS.iter().filter(|r| !r.runtime) {
         lua.push_str(&format!("---@field {} string\n", reg.type_name));
     }
     lua.push('\n');
@@ -100,7 +100,7 @@ fn generate_lua_components() {
     lua.push_str("local C = {}\n\n");
 
     // Fill table assignments
-    for reg in COMPONENTS.iter() {
+    for reg in COMPONENTS.iter().filter(|r| !r.runtime) {
         lua.push_str(&format!("C.{} = \"{}\"\n", reg.type_name, reg.type_name));
     }
 
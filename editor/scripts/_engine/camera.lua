@@ -0,0 +1,41 @@
+-- Auto-generated. Do not edit.
+-- bishop-owner: shared-engine
+---@meta
+
+--- Camera effects module
+---@class CameraApi
+engine.camera = {}
+
+--- Shakes the camera. Shakes add up and fade out over `duration`.
+---@param intensity number From 0 to 1
+---@param duration number Seconds
+function engine.camera.shake(intensity, duration) end
+
+--- Zooms to `factor` times the camera's own zoom. Use 1 to zoom back.
+---@param factor number
+---@param duration number Seconds
+---@param easing? string e.g. "out_quad" (default "in_out_sine")
+function engine.camera.zoom(factor, duration, easing) end
+
+--- Pans to a world point, holds, then returns to the player on its own.
+---@param x number
+---@param y number
+---@param duration number Seconds to travel each way
+---@param hold? number Seconds to stay on the point (default 1)
+---@param easing? string (default "in_out_sine")
+function engine.camera.pan_to(x, y, duration, hold, easing) end
+
+--- Pans to an entity and follows it until `release` is called.
+---@param entity Entity|integer
+---@param duration? number Seconds to travel (default 0.5)
+---@param easing? string (default "in_out_sine")
+function engine.camera.focus(entity, duration, easing) end
+
+--- Returns a panned or focused camera to the player.
+---@param duration? number Seconds (default: the pan's duration)
+function engine.camera.release(duration) end
+
+--- Sets how long switches between room cameras blend. 0 cuts.
+---@param duration number Seconds
+function engine.camera.set_blend(duration) end
+
@@ -12,6 +12,11 @@
 ---@field sprite_cache table
 ---@field flip_x boolean
 ---@field speed_multiplier number
+---@field tint vec3
+---@field opacity number
+
+---@class AnimationStateMachine
+---@field machine string
 
 ---@class AudioSource
 ---@field groups table
@@ -33,6 +38,8 @@
 ---@field sprite_id number
 ---@field frame_size vec2
 ---@field flip_x boolean
+---@field tint table
+---@field pivot table
 
 ---@alias CurrentRoom number
 
@@ -75,6 +82,31 @@
 
 ---@alias Parent Entity
 
+---@class ParticleEmitter
+---@field emitting boolean
+---@field rate number
+---@field burst number
+---@field max_particles number
+---@field lifetime number
+---@field lifetime_variance number
+---@field angle number
+---@field spread number
+---@field speed number
+---@field speed_variance number
+---@field gravity vec2
+---@field spawn_area vec2
+---@field start_color vec3
+---@field end_color vec3
+---@field start_alpha number
+---@field end_alpha number
+---@field start_size number
+---@field end_size number
+---@field sprite number
+---@field sheet_columns number
+---@field sheet_rows number
+---@field sheet_fps number
+---@field local_space boolean
+
 ---@class PhysicsBody
 --- Marker component
 
@@ -89,6 +121,7 @@
 ---@field room_id number
 ---@field zoom_mode table
 ---@field camera_mode table
+---@field follow table
 
 ---@class Script
 ---@field script_id number
@@ -102,12 +135,29 @@
 ---@field color table
 ---@field offset table
 ---@field font_size table
+---@field font table
 ---@field max_width table
 ---@field show_background boolean
 ---@field background_color table
+---@field reveal_speed number
+---@field wait_for_input boolean
+---@field markup table
+---@field reveal_elapsed number
+---@field reveal_times table
+---@field revealed boolean
+---@field voiced_glyphs number
+---@field age number
+
+---@class SpeechVoice
+---@field blip_sound string
+---@field blip_interval number
+---@field pitch_variation number
+---@field volume number
 
 ---@class Sprite
 ---@field sprite number
+---@field tint vec3
+---@field opacity number
 
 ---@class SubPixel
 ---@field x number
@@ -117,6 +167,9 @@
 ---@field visible boolean
 ---@field position vec2
 ---@field pivot table
+---@field rotation number
+---@field scale vec2
+---@field flip_y boolean
 
 ---@class Velocity
 ---@field x number
@@ -126,6 +179,7 @@
 
 ---@class ComponentId
 ---@field Animation string
+---@field AnimationStateMachine string
 ---@field AudioSource string
 ---@field Children string
 ---@field Collider string
@@ -142,6 +196,7 @@
 ---@field Light string
 ---@field Name string
 ---@field Parent string
+---@field ParticleEmitter string
 ---@field PhysicsBody string
 ---@field Player string
 ---@field PlayerProxy string
@@ -149,6 +204,7 @@
 ---@field Script string
 ---@field Solid string
 ---@field SpeechBubble string
+---@field SpeechVoice string
 ---@field Sprite string
 ---@field SubPixel string
 ---@field Transform string
@@ -158,6 +214,7 @@
 local C = {}
 
 C.Animation = "Animation"
+C.AnimationStateMachine = "AnimationStateMachine"
 C.AudioSource = "AudioSource"
 C.Children = "Children"
 C.Collider = "Collider"
@@ -174,6 +231,7 @@ C.Layer = "Layer"
 C.Light = "Light"
 C.Name = "Name"
 C.Parent = "Parent"
+C.ParticleEmitter = "ParticleEmitter"
 C.PhysicsBody = "PhysicsBody"
 C.Player = "Player"
 C.PlayerProxy = "PlayerProxy"
@@ -181,6 +239,7 @@ C.RoomCamera = "RoomCamera"
 C.Script = "Script"
 C.Solid = "Solid"
 C.SpeechBubble = "SpeechBubble"
+C.SpeechVoice = "SpeechVoice"
 C.Sprite = "Sprite"
 C.SubPixel = "SubPixel"
 C.Transform = "Transform"
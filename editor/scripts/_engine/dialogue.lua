@@ -0,0 +1,36 @@
+-- Auto-generated. Do not edit.
+-- bishop-owner: shared-engine
+---@meta
+
+--- Branching dialogue module
+---@class DialogueApi
+engine.dialogue = {}
+
+--- Starts a dialogue graph from Resources/dialogue, replacing any running dialogue.
+--- Emits `dialogue:line` (role, text) per line and `dialogue:finished` (id) at the end.
+---@param id string The dialogue graph id (e.g. "merchant")
+---@param participants? table<string, Entity|integer> Maps speaker roles to entities
+---@param on_finished? fun(id: string) Called when the dialogue ends
+function engine.dialogue.start(id, participants, on_finished) end
+
+--- Stops the running dialogue without calling its callback.
+function engine.dialogue.stop() end
+
+--- Returns true if a dialogue is running.
+---@return boolean
+function engine.dialogue.is_active() end
+
+--- Picks an option on the current choice node.
+---@param index integer The 1-based option index
+function engine.dialogue.choose(index) end
+
+--- Returns the value of a dialogue variable.
+---@param name string
+---@return boolean|number|string|nil
+function engine.dialogue.get_var(name) end
+
+--- Sets a dialogue variable. Passing nil removes it.
+---@param name string
+---@param value boolean|number|string|nil
+function engine.dialogue.set_var(name, value) end
+
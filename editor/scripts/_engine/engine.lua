@@ -46,6 +46,11 @@ function engine.player() end
 function engine.call(name, method, ...) end
 
 --- Register an event handler
+--- `on_room_exit` and `on_room_enter` receive `{ from, to }` room ids when the player
+--- changes room. `on_room_enter` waits for the room transition to finish.
+--- `settings:changed` receives `{ key, value }` when a player setting changes.
+--- `text_input:<key>` receives the submitted text and `list:<key>` receives
+--- `{ index, item }` when a menu scroll list item is picked.
 --- @param event string The name of the event to listen for
 --- @param handler function The Lua function that will be called
 --- @return nil
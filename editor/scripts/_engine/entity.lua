@@ -8,6 +8,7 @@ local Entity = {}
 
 -- Component getters
 ---@overload fun(self: Entity, component: "Animation"): Animation
+---@overload fun(self: Entity, component: "AnimationStateMachine"): AnimationStateMachine
 ---@overload fun(self: Entity, component: "AudioSource"): AudioSource
 ---@overload fun(self: Entity, component: "Children"): Children
 ---@overload fun(self: Entity, component: "Collider"): Collider
@@ -24,6 +25,7 @@ local Entity = {}
 ---@overload fun(self: Entity, component: "Light"): Light
 ---@overload fun(self: Entity, component: "Name"): Name
 ---@overload fun(self: Entity, component: "Parent"): Parent
+---@overload fun(self: Entity, component: "ParticleEmitter"): ParticleEmitter
 ---@overload fun(self: Entity, component: "PhysicsBody"): PhysicsBody
 ---@overload fun(self: Entity, component: "Player"): Player
 ---@overload fun(self: Entity, component: "PlayerProxy"): PlayerProxy
@@ -31,6 +33,7 @@ local Entity = {}
 ---@overload fun(self: Entity, component: "Script"): Script
 ---@overload fun(self: Entity, component: "Solid"): Solid
 ---@overload fun(self: Entity, component: "SpeechBubble"): SpeechBubble
+---@overload fun(self: Entity, component: "SpeechVoice"): SpeechVoice
 ---@overload fun(self: Entity, component: "Sprite"): Sprite
 ---@overload fun(self: Entity, component: "SubPixel"): SubPixel
 ---@overload fun(self: Entity, component: "Transform"): Transform
@@ -51,6 +54,10 @@ function Entity:set(component, value) end
 ---@param v Animation
 function Entity:set_animation(v) end
 
+---@param self Entity
+---@param v AnimationStateMachine
+function Entity:set_animation_state_machine(v) end
+
 ---@param self Entity
 ---@param v AudioSource
 function Entity:set_audio_source(v) end
@@ -115,6 +122,10 @@ function Entity:set_name(v) end
 ---@param v Parent
 function Entity:set_parent(v) end
 
+---@param self Entity
+---@param v ParticleEmitter
+function Entity:set_particle_emitter(v) end
+
 ---@param self Entity
 ---@param v PhysicsBody
 function Entity:set_physics_body(v) end
@@ -143,6 +154,10 @@ function Entity:set_solid(v) end
 ---@param v SpeechBubble
 function Entity:set_speech_bubble(v) end
 
+---@param self Entity
+---@param v SpeechVoice
+function Entity:set_speech_voice(v) end
+
 ---@param self Entity
 ---@param v Sprite
 function Entity:set_sprite(v) end
@@ -186,6 +201,9 @@ function Entity:interact(...) end
 function Entity:find_best_interactable() end
 
 --- Sets the active animation clip.
+--- Frame events emit `animation:event` (entity, name, clip, frame) when their frame is entered,
+--- and non-looping clips emit `animation:finished` (entity, clip) after their last frame.
+--- The entity's script also receives `on_animation_event(self, name, clip, frame)` and `on_clip_finished(self, clip)`.
 ---@param clip_name string The name of the clip (e.g. "Walk", "Idle")
 function Entity:set_clip(clip_name) end
 
@@ -220,13 +238,64 @@ function Entity:get_current_frame() end
 ---@return boolean
 function Entity:is_clip_finished() end
 
+--- Gets a named Aseprite slice for the current animation frame.
+--- Bounds and pivot are in frame pixels.
+---@param name string
+---@return {x: number, y: number, w: number, h: number, pivot_x: number?, pivot_y: number?}|nil
+function Entity:get_slice(name) end
+
+--- Sets a parameter on the entity's animation state machine.
+--- Setting a trigger parameter to true fires it until a transition uses it.
+---@param name string
+---@param value boolean|number
+function Entity:set_anim_param(name, value) end
+
+--- Gets the current state of the entity's animation state machine.
+---@return string|nil
+function Entity:get_anim_state() end
+
+--- Sets the clockwise rotation in degrees. Children rotate with the entity.
+---@param degrees number
+function Entity:set_rotation(degrees) end
+
+--- Sets the scale. A negative axis mirrors the entity. Children scale with it.
+---@param x number
+---@param y number? Defaults to x
+function Entity:set_scale(x, y) end
+
+--- Sets vertical flip. Children are mirrored with the entity.
+---@param flip_y boolean Whether to flip vertically
+function Entity:set_flip_y(flip_y) end
+
+--- Sets the color multiplied with the sprite or animation (0-1 per channel).
+---@param r number
+---@param g number
+---@param b number
+function Entity:set_tint(r, g, b) end
+
+--- Sets the opacity of the sprite or animation (0-1).
+---@param alpha number
+function Entity:set_opacity(alpha) end
+
+--- Emits a burst of particles from this entity's ParticleEmitter.
+---@param count integer
+function Entity:emit_particles(count) end
+
+--- Starts continuous emission and fires the emitter's start burst.
+function Entity:start_particles() end
+
+--- Stops continuous emission. Live particles finish unless `clear` is true.
+---@param clear? boolean Remove live particles immediately
+function Entity:stop_particles(clear) end
+
 --- Shows a speech bubble with text from a dialogue file.
 ---@param dialogue_id string The dialogue file ID (e.g. "npc_merchant")
 ---@param key string The dialogue key (e.g. "greeting")
----@param opts? {vars?: table<string, string>, duration?: number, color?: number[], offset?: number[], font_size?: number, max_width?: number, show_background?: boolean, background_color?: number[]}
+---@param opts? {vars?: table<string, string|number|boolean>, duration?: number, color?: number[], offset?: number[], font_size?: number, font?: string, max_width?: number, show_background?: boolean, background_color?: number[], reveal_speed?: number, wait_for_input?: boolean}
+--- Emits `speech:revealed` (entity) when the text is fully shown and `speech:dismissed` (entity) when the bubble closes.
 function Entity:say(dialogue_id, key, opts) end
 
---- Removes any speech bubble from the entity.
+--- Removes any speech bubble from the entity, emitting `speech:dismissed` (entity).
 function Entity:clear_speech() end
 
 --- Checks if the entity currently has a speech bubble.
@@ -6,7 +6,7 @@
 ---@class MenuApi
 engine.menu = {}
 
---- Opens a menu by id.
+--- Opens a menu by id. HUD menus are shown as with `show_hud`.
 ---@param menu_id string The menu template id
 function engine.menu.open(menu_id) end
 
@@ -17,3 +17,34 @@ function engine.menu.close() end
 ---@return boolean
 function engine.menu.is_open() end
 
+--- Shows a HUD menu over gameplay. HUDs ignore input and stay up
+--- until hidden; several can be shown at once, later ones on top.
+--- Use `set_value` with the HUD's id to update what it shows.
+---@param hud_id string The id of a menu template in HUD mode
+function engine.menu.show_hud(hud_id) end
+
+--- Hides a shown HUD.
+---@param hud_id string The HUD menu id
+function engine.menu.hide_hud(hud_id) end
+
+--- Fills every scroll list with `key`. Picking an item emits
+--- `list:<key>` with `{ index, item }`, where `index` starts at 1.
+---@param key string The scroll list key
+---@param items string[] Item text or text keys, in order
+function engine.menu.set_list(key, items) end
+
+--- Sets the text of every text input with `key`. Submitting an input
+--- emits `text_input:<key>` with the entered text.
+---@param key string The text input key
+---@param text string
+function engine.menu.set_text(key, text) end
+
+--- Sets a value the elements of a menu bind to. Label text shows it
+--- through `{{key}}`, elements with a visible or enabled condition on
+--- `key` update, and lists fill the menu's scroll lists with that key.
+--- Passing nil clears the value.
+---@param menu_id string The menu template id
+---@param key string The value key, e.g. `"player.coins"`
+---@param value number|boolean|string|string[]|nil
+function engine.menu.set_value(menu_id, key, value) end
+
@@ -0,0 +1,26 @@
+-- Auto-generated. Do not edit.
+-- bishop-owner: shared-engine
+---@meta
+
+--- Player settings module. Settings are saved per game and shared by
+--- menu sliders with the same key.
+---
+--- The engine applies these keys itself:
+--- `master_volume`, `music_volume`, `sfx_volume` (0 to 1),
+--- `fullscreen`, `vsync` and `integer_scaling` (booleans).
+---
+--- Changes emit `settings:changed` with `{ key, value }`.
+---@class SettingsApi
+engine.settings = {}
+
+--- Returns the saved value for `key`, or `default` when it was never set.
+---@param key string
+---@param default? number|boolean|string
+---@return number|boolean|string|nil
+function engine.settings.get(key, default) end
+
+--- Stores a setting. Strings hold one option out of a set, e.g. "hard".
+---@param key string
+---@param value number|boolean|string
+function engine.settings.set(key, value) end
+
@@ -22,3 +22,18 @@ function engine.text.get_languages() end
 ---@return {default_duration: number, font_size: number, max_width: number, default_offset_y: number, padding: number, show_background: boolean, default_color: number[], default_background_color: number[]}
 function engine.text.get_config() end
 
+--- Formats a message with `{{name}}` placeholders and ICU-style arguments,
+--- e.g. `{count, plural, one {# coin} other {# coins}}`, in the current language.
+---@param message string
+---@param vars? table<string, string|number|boolean>
+---@return string
+function engine.text.format(message, vars) end
+
+--- Gets UI text by file and key in the current language, formatted with `vars`.
+--- Returns the key if the text is missing.
+---@param text_id string The text file id (e.g. "ui/hud")
+---@param key string
+---@param vars? table<string, string|number|boolean>
+---@return string
+function engine.text.get(text_id, key, vars) end
+
@@ -0,0 +1,70 @@
+-- Auto-generated. Do not edit.
+-- bishop-owner: shared-engine
+---@meta
+
+--- Handle to a running tween. Every method returns the handle for chaining.
+---@class Tween
+local Tween = {}
+
+--- Animates a property from its current value to `to` over `duration` seconds.
+--- Properties: "position", "rotation", "scale", "alpha", "camera_zoom",
+--- "light_intensity", "volume" or any reflected "Component.field".
+--- Tweens stop silently when their entity is removed.
+---@param entity Entity|integer
+---@param property string e.g. "Transform.position"
+---@param to number|{x: number, y: number}|{r: number, g: number, b: number}
+---@param duration number Seconds
+---@param easing? string e.g. "out_quad" (default "linear")
+---@return Tween
+function engine.tween(entity, property, to, duration, easing) end
+
+--- Stops every tween animating the entity, without calling callbacks.
+---@param entity Entity|integer
+function engine.cancel_tweens(entity) end
+
+--- Calls `fn` once every step of the tween has finished.
+---@param fn fun()
+---@return Tween
+function Tween:on_done(fn) end
+
+--- Waits before the last step starts.
+---@param seconds number
+---@return Tween
+function Tween:delay(seconds) end
+
+--- Sets the easing of the last step.
+---@param easing string e.g. "in_out_sine"
+---@return Tween
+function Tween:ease(easing) end
+
+--- Starts the last step from `value` instead of the current value.
+---@param value number|table
+---@return Tween
+function Tween:from(value) end
+
+--- Plays the last step back to its start after reaching the target.
+---@return Tween
+function Tween:yoyo() end
+
+--- Plays the last step `count` times, forever if omitted.
+--- With yoyo, every other pass runs backwards.
+---@param count? integer
+---@return Tween
+function Tween:loop(count) end
+
+--- Queues another step on the same entity that starts when the previous one ends.
+---@param property string
+---@param to number|table
+---@param duration number Seconds
+---@param easing? string
+---@return Tween
+function Tween:then_to(property, to, duration, easing) end
+
+--- Stops the tween without calling `on_done`.
+---@return boolean stopped False if the tween had already finished
+function Tween:cancel() end
+
+--- Returns true while the tween still has steps to play.
+---@return boolean
+function Tween:is_running() end
+
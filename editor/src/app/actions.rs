@@ -4,6 +4,7 @@ use crate::app::EditorCameraController;
 use crate::app::*;
 use crate::commands::game::*;
 use crate::commands::world::*;
+use crate::dialogue::DialogueEditor;
 use crate::editor_global::*;
 use crate::game::game_editor::GameEditor;
 use crate::gui::inspector::audio_source_module::clear_active_audio_preview;
@@ -31,6 +32,7 @@ impl Default for Editor {
             world_editor: WorldEditor::new(),
             room_editor: RoomEditor::new(),
             menu_editor: MenuEditor::new(),
+            dialogue_editor: DialogueEditor::new(),
             cur_world_id: None,
             cur_room_id: None,
             render_system: RenderSystem::with_default_grid_size(),
@@ -84,6 +86,7 @@ impl Editor {
                 .map(|room| room.name.clone())
                 .unwrap_or_else(|| "Room".to_string()),
             EditorMode::Menu => "Menu Editor".to_string(),
+            EditorMode::Dialogue => "Dialogue Editor".to_string(),
         };
 
         if let Some(action) = self.menu_bar.draw(ctx, &menu_title, self.mode) {
@@ -173,19 +176,31 @@ impl Editor {
                         panel_manager.toggle(DIAGNOSTICS_PANEL);
                     });
                 }
+                EditorAction::ViewLocalizationPanel => {
+                    with_panel_manager(|panel_manager| {
+                        panel_manager.toggle(LOCALIZATION_PANEL);
+                    });
+                }
                 EditorAction::WorldSettings => {
                     self.open_world_settings_modal(ctx);
                 }
                 EditorAction::OpenMenuEditor => {
                     clear_active_audio_preview();
-                    self.return_mode = Some(self.mode);
+                    self.leave_sub_editor();
                     self.mode = EditorMode::Menu;
                     self.load_menus();
                     MenuEditor::init_camera(ctx, &mut self.camera);
                 }
+                EditorAction::OpenDialogueEditor => {
+                    clear_active_audio_preview();
+                    self.leave_sub_editor();
+                    self.mode = EditorMode::Dialogue;
+                    self.load_dialogue_graphs();
+                    DialogueEditor::init_camera(ctx, &mut self.camera);
+                }
                 EditorAction::ReturnToGameEditor => {
-                    // Save menus before leaving menu mode
-                    self.save_menus();
+                    // Save menus or dialogue before leaving the sub-editor
+                    self.leave_sub_editor();
 
                     let return_mode = self.return_mode.unwrap_or(EditorMode::Game);
                     self.mode = return_mode;
@@ -214,8 +229,8 @@ impl Editor {
                                 );
                             }
                         }
-                        EditorMode::Menu => {
-                            // Should not return to Menu mode
+                        EditorMode::Menu | EditorMode::Dialogue => {
+                            // Should not return to a sub-editor
                         }
                     }
                 }
@@ -262,23 +277,63 @@ impl Editor {
             onscreen_error!("Could not save game: {}.", e)
         } else if palette_saved {
             self.save_menus();
+            self.save_dialogue_graphs();
             self.toast = Some(Toast::new("Saved", 2.5));
         }
     }
 
-    /// Saves all menu templates to disk.
+    /// Saves all menu templates and the menu theme to disk.
     pub fn save_menus(&self) {
         for template in &self.menu_editor.templates {
             if let Err(e) = save_menu(template) {
                 onscreen_error!("Could not save menu '{}': {}", template.id, e);
             }
         }
+        if let Err(e) = save_menu_theme(&self.menu_editor.theme) {
+            onscreen_error!("Could not save menu theme: {}", e);
+        }
     }
 
-    /// Loads all menu templates from disk.
+    /// Loads all menu templates and the menu theme from disk.
     pub fn load_menus(&mut self) {
         let templates = load_menus();
         self.menu_editor.set_templates(templates);
+        self.menu_editor.theme = load_menu_theme();
+        self.menu_editor
+            .adopt_sprite_refs(&mut self.game.asset_manager);
+    }
+
+    /// Saves all dialogue graphs and the dialogue settings to disk.
+    pub fn save_dialogue_graphs(&self) {
+        for graph in &self.dialogue_editor.graphs {
+            if let Err(e) = save_dialogue_graph(graph) {
+                onscreen_error!("Could not save dialogue '{}': {}", graph.id, e);
+            }
+        }
+        if let Err(e) = save_dialogue_config(&self.game.text_manager.config) {
+            onscreen_error!("Could not save dialogue settings: {e}");
+        }
+    }
+
+    /// Loads all dialogue graphs from disk.
+    pub fn load_dialogue_graphs(&mut self) {
+        let graphs = load_dialogue_graphs();
+        let text_manager = &self.game.text_manager;
+        self.dialogue_editor.set_graphs(
+            graphs,
+            text_manager.get_languages(),
+            text_manager.get_language(),
+        );
+    }
+
+    /// Saves the work of the menu or dialogue editor being left. When entering a
+    /// sub-editor from the game, world or room editor, remembers where to return to.
+    fn leave_sub_editor(&mut self) {
+        match self.mode {
+            EditorMode::Menu => self.save_menus(),
+            EditorMode::Dialogue => self.save_dialogue_graphs(),
+            mode => self.return_mode = Some(mode),
+        }
     }
 
     pub fn get_room_from_id(&self, room_id: &RoomId) -> &Room {
@@ -310,6 +365,7 @@ impl Editor {
             EditorMode::World(_) => "Rename world: ",
             EditorMode::Room(_) => "Rename room: ",
             EditorMode::Menu => "Rename menu: ",
+            EditorMode::Dialogue => "Rename dialogue: ",
         };
 
         let mut prompt = self.set_prompt_modal(ctx, prompt_message);
@@ -344,13 +400,19 @@ impl Editor {
     }
 
     fn open_world_settings_modal(&mut self, ctx: &mut WgpuContext) {
-        self.modal = Modal::new(ctx, 300.0, 150.0);
+        self.modal = Modal::new(ctx, 300.0, 260.0);
         let world = self.game.current_world();
         let world_id = world.id;
         let grid_size = world.grid_size;
+        let room_transition = world.room_transition;
 
-        let mut prompt =
-            WorldSettingsPrompt::new(world_id, self.modal.rect, WidgetId::default(), grid_size);
+        let mut prompt = WorldSettingsPrompt::new(
+            world_id,
+            self.modal.rect,
+            WidgetId::default(),
+            grid_size,
+            room_transition,
+        );
 
         let widgets: Vec<BoxedWidget> = vec![Box::new(move |ctx, _| {
             if let Some(result) = prompt.draw(ctx) {
@@ -475,7 +537,7 @@ impl Editor {
                                     room.name = name;
                                 }
                             }
-                            EditorMode::Menu => {}
+                            EditorMode::Menu | EditorMode::Dialogue => {}
                         }
                         self.modal.close();
                     }
@@ -523,6 +585,14 @@ impl Editor {
                         new_grid_size,
                     )));
                 }
+                if let Some(new_transition) = result.room_transition {
+                    let old_transition = self.game.get_world_mut(result.id).room_transition;
+                    push_command(Box::new(ChangeRoomTransitionCmd::new(
+                        result.id,
+                        old_transition,
+                        new_transition,
+                    )));
+                }
                 self.modal.close();
             }
 
@@ -8,6 +8,7 @@ pub use sub_editor::SubEditor;
 
 use crate::canvas::grid_shader::GridRenderer;
 use crate::game::game_editor::GameEditor;
+use crate::dialogue::DialogueEditor;
 use crate::gui::menu_bar::MenuBar;
 use crate::gui::modal::Modal;
 use crate::menu::MenuEditor;
@@ -33,6 +34,7 @@ pub enum EditorMode {
     World(WorldId),
     Room(RoomId),
     Menu,
+    Dialogue,
 }
 
 pub struct Editor {
@@ -43,6 +45,7 @@ pub struct Editor {
     pub world_editor: WorldEditor,
     pub room_editor: RoomEditor,
     pub menu_editor: MenuEditor,
+    pub dialogue_editor: DialogueEditor,
     pub camera: Camera2D,
     pub cur_world_id: Option<WorldId>,
     pub cur_room_id: Option<RoomId>,
@@ -143,6 +146,10 @@ impl Editor {
         match self.mode {
             EditorMode::Menu => {
                 self.menu_editor.update(ctx, &self.camera);
+                self.menu_editor.sync_sprite_refs(&mut self.game.asset_manager);
+            }
+            EditorMode::Dialogue => {
+                self.dialogue_editor.update(ctx, &self.camera);
             }
             EditorMode::Game => {
                 // Returns the id of the world that was clicked on or None
@@ -290,7 +297,22 @@ impl Editor {
 
     pub fn draw(&mut self, ctx: &mut WgpuContext) {
         match self.mode {
-            EditorMode::Menu => self.menu_editor.draw(ctx, &self.camera),
+            EditorMode::Menu => {
+                self.menu_editor
+                    .draw(ctx, &self.camera, &mut self.game.asset_manager)
+            }
+            EditorMode::Dialogue => {
+                if let Some(grid_renderer) = &self.grid_renderer {
+                    let game = &mut self.game;
+                    self.dialogue_editor.draw(
+                        ctx,
+                        &self.camera,
+                        grid_renderer,
+                        &mut game.text_manager.config,
+                        &mut game.asset_manager,
+                    );
+                }
+            }
             EditorMode::Game => {
                 self.game_editor.draw(ctx, &mut self.camera, &mut self.game);
             }
@@ -357,6 +379,7 @@ impl Editor {
     fn current_editor(&self) -> &dyn SubEditor {
         match self.mode {
             EditorMode::Menu => &self.menu_editor,
+            EditorMode::Dialogue => &self.dialogue_editor,
             EditorMode::Game => &self.game_editor,
             EditorMode::World(_) => &self.world_editor,
             EditorMode::Room(_) => &self.room_editor,
@@ -0,0 +1,66 @@
+// editor/src/commands/dialogue/add_node_cmd.rs
+use crate::app::EditorMode;
+use crate::commands::editor_command_manager::EditorCommand;
+use crate::with_editor;
+use engine_core::prelude::*;
+
+/// Undo-able command for adding a node to a dialogue graph.
+#[derive(Debug)]
+pub struct AddDialogueNodeCmd {
+    graph_index: usize,
+    kind: DialogueNodeKind,
+    position: (f32, f32),
+    added_id: Option<DialogueNodeId>,
+    set_start: bool,
+}
+
+impl AddDialogueNodeCmd {
+    pub fn new(graph_index: usize, kind: DialogueNodeKind, position: (f32, f32)) -> Self {
+        Self {
+            graph_index,
+            kind,
+            position,
+            added_id: None,
+            set_start: false,
+        }
+    }
+}
+
+impl EditorCommand for AddDialogueNodeCmd {
+    fn execute(&mut self) {
+        with_editor(|editor| {
+            let dialogue_editor = &mut editor.dialogue_editor;
+            let Some(graph) = dialogue_editor.graph_mut(self.graph_index) else {
+                return;
+            };
+
+            self.set_start = graph.start.is_none();
+            let id = graph.add_node(self.kind.clone(), self.position);
+            self.added_id = Some(id);
+            dialogue_editor.selected_node = Some(id);
+        });
+    }
+
+    fn undo(&mut self) {
+        with_editor(|editor| {
+            let dialogue_editor = &mut editor.dialogue_editor;
+            let Some(graph) = dialogue_editor.graph_mut(self.graph_index) else {
+                return;
+            };
+
+            if let Some(id) = self.added_id.take() {
+                graph.remove_node(id);
+                if self.set_start {
+                    graph.start = None;
+                }
+                if dialogue_editor.selected_node == Some(id) {
+                    dialogue_editor.selected_node = None;
+                }
+            }
+        });
+    }
+
+    fn mode(&self) -> EditorMode {
+        EditorMode::Dialogue
+    }
+}
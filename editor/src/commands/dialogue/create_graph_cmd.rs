@@ -0,0 +1,63 @@
+// editor/src/commands/dialogue/create_graph_cmd.rs
+use crate::app::EditorMode;
+use crate::commands::editor_command_manager::EditorCommand;
+use crate::storage::editor_storage::delete_dialogue_graph;
+use crate::with_editor;
+use engine_core::prelude::*;
+
+/// Undo-able command for creating a new dialogue graph.
+#[derive(Debug)]
+pub struct CreateDialogueGraphCmd {
+    id: String,
+    created_index: Option<usize>,
+    saved_graph: Option<DialogueGraph>,
+}
+
+impl CreateDialogueGraphCmd {
+    pub fn new(id: String) -> Self {
+        Self {
+            id,
+            created_index: None,
+            saved_graph: None,
+        }
+    }
+}
+
+impl EditorCommand for CreateDialogueGraphCmd {
+    fn execute(&mut self) {
+        with_editor(|editor| {
+            let dialogue_editor = &mut editor.dialogue_editor;
+
+            let graph = self.saved_graph.take().unwrap_or_else(|| {
+                DialogueGraph::new(&self.id, &format!("{DIALOGUE_FOLDER}/{}", self.id))
+            });
+            dialogue_editor.graphs.push(graph);
+
+            let index = dialogue_editor.graphs.len() - 1;
+            self.created_index = Some(index);
+            dialogue_editor.select_graph(index);
+        });
+    }
+
+    fn undo(&mut self) {
+        with_editor(|editor| {
+            let dialogue_editor = &mut editor.dialogue_editor;
+
+            if let Some(index) = self.created_index.take() {
+                if index < dialogue_editor.graphs.len() {
+                    let graph = dialogue_editor.graphs.remove(index);
+                    // The graph may have been saved since it was created
+                    if let Err(err) = delete_dialogue_graph(&graph.id) {
+                        onscreen_error!("Error deleting dialogue file: {err}");
+                    }
+                    self.saved_graph = Some(graph);
+                }
+                dialogue_editor.clamp_current_graph();
+            }
+        });
+    }
+
+    fn mode(&self) -> EditorMode {
+        EditorMode::Dialogue
+    }
+}
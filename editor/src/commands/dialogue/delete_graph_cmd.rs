@@ -0,0 +1,72 @@
+// editor/src/commands/dialogue/delete_graph_cmd.rs
+use crate::app::EditorMode;
+use crate::commands::editor_command_manager::EditorCommand;
+use crate::storage::editor_storage::{delete_dialogue_graph, save_dialogue_graph};
+use crate::with_editor;
+use engine_core::prelude::*;
+
+/// Undo-able command for deleting a dialogue graph.
+#[derive(Debug)]
+pub struct DeleteDialogueGraphCmd {
+    graph_index: usize,
+    saved_graph: Option<DialogueGraph>,
+    previous_graph_index: Option<usize>,
+}
+
+impl DeleteDialogueGraphCmd {
+    pub fn new(graph_index: usize) -> Self {
+        Self {
+            graph_index,
+            saved_graph: None,
+            previous_graph_index: None,
+        }
+    }
+}
+
+impl EditorCommand for DeleteDialogueGraphCmd {
+    fn execute(&mut self) {
+        with_editor(|editor| {
+            let dialogue_editor = &mut editor.dialogue_editor;
+
+            if self.graph_index >= dialogue_editor.graphs.len() {
+                return;
+            }
+
+            self.previous_graph_index = dialogue_editor.current_graph_index;
+
+            let graph = dialogue_editor.graphs.remove(self.graph_index);
+            if let Err(err) = delete_dialogue_graph(&graph.id) {
+                onscreen_error!("Error deleting dialogue file: {err}");
+            }
+            self.saved_graph = Some(graph);
+
+            if let Some(current) = dialogue_editor.current_graph_index {
+                if current > self.graph_index {
+                    dialogue_editor.current_graph_index = Some(current - 1);
+                }
+            }
+            dialogue_editor.clamp_current_graph();
+        });
+    }
+
+    fn undo(&mut self) {
+        with_editor(|editor| {
+            let dialogue_editor = &mut editor.dialogue_editor;
+
+            if let Some(graph) = self.saved_graph.take() {
+                if let Err(err) = save_dialogue_graph(&graph) {
+                    onscreen_error!("Error restoring dialogue file: {err}");
+                }
+                let index = self.graph_index.min(dialogue_editor.graphs.len());
+                dialogue_editor.graphs.insert(index, graph);
+                dialogue_editor.current_graph_index = self.previous_graph_index;
+                dialogue_editor.selected_node = None;
+                dialogue_editor.mark_issues_stale();
+            }
+        });
+    }
+
+    fn mode(&self) -> EditorMode {
+        EditorMode::Dialogue
+    }
+}
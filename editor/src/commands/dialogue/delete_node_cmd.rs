@@ -0,0 +1,60 @@
+// editor/src/commands/dialogue/delete_node_cmd.rs
+use crate::app::EditorMode;
+use crate::commands::editor_command_manager::EditorCommand;
+use crate::with_editor;
+use engine_core::prelude::*;
+
+/// Undo-able command for deleting a node and the edges leading into it.
+/// Stores the whole graph so every cleared edge comes back on undo.
+#[derive(Debug)]
+pub struct DeleteDialogueNodeCmd {
+    graph_index: usize,
+    node_id: DialogueNodeId,
+    saved_graph: Option<DialogueGraph>,
+}
+
+impl DeleteDialogueNodeCmd {
+    pub fn new(graph_index: usize, node_id: DialogueNodeId) -> Self {
+        Self {
+            graph_index,
+            node_id,
+            saved_graph: None,
+        }
+    }
+}
+
+impl EditorCommand for DeleteDialogueNodeCmd {
+    fn execute(&mut self) {
+        with_editor(|editor| {
+            let dialogue_editor = &mut editor.dialogue_editor;
+            let Some(graph) = dialogue_editor.graph_mut(self.graph_index) else {
+                return;
+            };
+
+            let before = graph.clone();
+            if graph.remove_node(self.node_id).is_some() {
+                self.saved_graph = Some(before);
+            }
+            if dialogue_editor.selected_node == Some(self.node_id) {
+                dialogue_editor.selected_node = None;
+            }
+        });
+    }
+
+    fn undo(&mut self) {
+        with_editor(|editor| {
+            let dialogue_editor = &mut editor.dialogue_editor;
+
+            if let Some(saved) = self.saved_graph.take() {
+                if let Some(graph) = dialogue_editor.graph_mut(self.graph_index) {
+                    *graph = saved;
+                    dialogue_editor.selected_node = Some(self.node_id);
+                }
+            }
+        });
+    }
+
+    fn mode(&self) -> EditorMode {
+        EditorMode::Dialogue
+    }
+}
@@ -0,0 +1,18 @@
+// editor/src/commands/dialogue/mod.rs
+mod add_node_cmd;
+mod create_graph_cmd;
+mod delete_graph_cmd;
+mod delete_node_cmd;
+mod update_config_cmd;
+mod update_graph_cmd;
+mod update_node_cmd;
+mod update_text_cmd;
+
+pub use add_node_cmd::*;
+pub use create_graph_cmd::*;
+pub use delete_graph_cmd::*;
+pub use delete_node_cmd::*;
+pub use update_config_cmd::*;
+pub use update_graph_cmd::*;
+pub use update_node_cmd::*;
+pub use update_text_cmd::*;
@@ -0,0 +1,41 @@
+// editor/src/commands/dialogue/update_config_cmd.rs
+use crate::app::EditorMode;
+use crate::commands::editor_command_manager::EditorCommand;
+use crate::with_editor;
+use engine_core::prelude::*;
+
+/// Undo-able command for editing the dialogue settings shared by every graph.
+#[derive(Debug)]
+pub struct UpdateDialogueConfigCmd {
+    old_config: DialogueConfig,
+    new_config: DialogueConfig,
+}
+
+impl UpdateDialogueConfigCmd {
+    pub fn new(old_config: DialogueConfig, new_config: DialogueConfig) -> Self {
+        Self {
+            old_config,
+            new_config,
+        }
+    }
+
+    fn apply(config: &DialogueConfig) {
+        with_editor(|editor| {
+            editor.game.text_manager.config = config.clone();
+        });
+    }
+}
+
+impl EditorCommand for UpdateDialogueConfigCmd {
+    fn execute(&mut self) {
+        Self::apply(&self.new_config);
+    }
+
+    fn undo(&mut self) {
+        Self::apply(&self.old_config);
+    }
+
+    fn mode(&self) -> EditorMode {
+        EditorMode::Dialogue
+    }
+}
@@ -0,0 +1,60 @@
+// editor/src/commands/dialogue/update_graph_cmd.rs
+use crate::app::EditorMode;
+use crate::commands::editor_command_manager::EditorCommand;
+use crate::with_editor;
+use engine_core::prelude::*;
+
+/// A graph-level property edited through [`UpdateDialogueGraphCmd`].
+#[derive(Debug, Clone)]
+pub enum DialogueGraphProperty {
+    TextId(String),
+    Start(Option<DialogueNodeId>),
+}
+
+/// Undo-able command for updating a property on a dialogue graph.
+#[derive(Debug)]
+pub struct UpdateDialogueGraphCmd {
+    graph_index: usize,
+    old_value: DialogueGraphProperty,
+    new_value: DialogueGraphProperty,
+}
+
+impl UpdateDialogueGraphCmd {
+    pub fn new(
+        graph_index: usize,
+        old_value: DialogueGraphProperty,
+        new_value: DialogueGraphProperty,
+    ) -> Self {
+        Self {
+            graph_index,
+            old_value,
+            new_value,
+        }
+    }
+
+    fn apply(&self, value: &DialogueGraphProperty) {
+        with_editor(|editor| {
+            let Some(graph) = editor.dialogue_editor.graph_mut(self.graph_index) else {
+                return;
+            };
+            match value {
+                DialogueGraphProperty::TextId(text_id) => graph.text_id = text_id.clone(),
+                DialogueGraphProperty::Start(start) => graph.start = *start,
+            }
+        });
+    }
+}
+
+impl EditorCommand for UpdateDialogueGraphCmd {
+    fn execute(&mut self) {
+        self.apply(&self.new_value.clone());
+    }
+
+    fn undo(&mut self) {
+        self.apply(&self.old_value.clone());
+    }
+
+    fn mode(&self) -> EditorMode {
+        EditorMode::Dialogue
+    }
+}
@@ -0,0 +1,49 @@
+// editor/src/commands/dialogue/update_node_cmd.rs
+use crate::app::EditorMode;
+use crate::commands::editor_command_manager::EditorCommand;
+use crate::with_editor;
+use engine_core::prelude::*;
+
+/// Undo-able command for moving, connecting or editing a dialogue node.
+/// Stores full before/after clones of the node.
+#[derive(Debug)]
+pub struct UpdateDialogueNodeCmd {
+    graph_index: usize,
+    old_node: DialogueNode,
+    new_node: DialogueNode,
+}
+
+impl UpdateDialogueNodeCmd {
+    pub fn new(graph_index: usize, old_node: DialogueNode, new_node: DialogueNode) -> Self {
+        Self {
+            graph_index,
+            old_node,
+            new_node,
+        }
+    }
+
+    fn apply_node(&self, node: &DialogueNode) {
+        with_editor(|editor| {
+            let Some(graph) = editor.dialogue_editor.graph_mut(self.graph_index) else {
+                return;
+            };
+            if let Some(target) = graph.node_mut(node.id) {
+                *target = node.clone();
+            }
+        });
+    }
+}
+
+impl EditorCommand for UpdateDialogueNodeCmd {
+    fn execute(&mut self) {
+        self.apply_node(&self.new_node);
+    }
+
+    fn undo(&mut self) {
+        self.apply_node(&self.old_node);
+    }
+
+    fn mode(&self) -> EditorMode {
+        EditorMode::Dialogue
+    }
+}
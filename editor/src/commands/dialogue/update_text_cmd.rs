@@ -0,0 +1,73 @@
+// editor/src/commands/dialogue/update_text_cmd.rs
+use crate::app::EditorMode;
+use crate::commands::editor_command_manager::EditorCommand;
+use crate::storage::editor_storage::save_text_file;
+use crate::with_editor;
+use engine_core::prelude::*;
+
+/// Undo-able command for editing a line of dialogue text. Writes straight
+/// through to `Resources/text/<lang>/<text_id>.toml`.
+#[derive(Debug)]
+pub struct UpdateDialogueTextCmd {
+    language: String,
+    text_id: String,
+    key: String,
+    old_entry: Option<TextEntry>,
+    new_entry: Option<TextEntry>,
+}
+
+impl UpdateDialogueTextCmd {
+    pub fn new(
+        language: String,
+        text_id: String,
+        key: String,
+        old_entry: Option<TextEntry>,
+        new_entry: Option<TextEntry>,
+    ) -> Self {
+        Self {
+            language,
+            text_id,
+            key,
+            old_entry,
+            new_entry,
+        }
+    }
+
+    fn apply(&self, entry: &Option<TextEntry>) {
+        with_editor(|editor| {
+            let Some(text) = editor
+                .dialogue_editor
+                .text_file_mut(&self.language, &self.text_id)
+            else {
+                return;
+            };
+
+            match entry {
+                Some(entry) => {
+                    text.entries.insert(self.key.clone(), entry.clone());
+                }
+                None => {
+                    text.entries.remove(&self.key);
+                }
+            }
+
+            if let Err(e) = save_text_file(&self.language, &self.text_id, text) {
+                onscreen_error!("Could not save text '{}': {}", self.text_id, e);
+            }
+        });
+    }
+}
+
+impl EditorCommand for UpdateDialogueTextCmd {
+    fn execute(&mut self) {
+        self.apply(&self.new_entry);
+    }
+
+    fn undo(&mut self) {
+        self.apply(&self.old_entry);
+    }
+
+    fn mode(&self) -> EditorMode {
+        EditorMode::Dialogue
+    }
+}
@@ -8,6 +8,7 @@ mod reorder_child_cmd;
 mod resize_element_cmd;
 mod update_element_cmd;
 mod update_template_cmd;
+mod update_theme_cmd;
 
 pub use add_element_cmd::*;
 pub use create_template_cmd::*;
@@ -18,3 +19,4 @@ pub use reorder_child_cmd::*;
 pub use resize_element_cmd::*;
 pub use update_element_cmd::*;
 pub use update_template_cmd::*;
+pub use update_theme_cmd::*;
@@ -19,6 +19,10 @@ pub enum TemplateProperty {
         old: MenuBackground,
         new: MenuBackground,
     },
+    Animation {
+        old: MenuAnimation,
+        new: MenuAnimation,
+    },
 }
 
 /// Undo-able command for changing a menu template property.
@@ -52,6 +56,9 @@ impl UpdateTemplateCmd {
             TemplateProperty::Background { old, new } => {
                 template.background = if use_new { *new } else { *old };
             }
+            TemplateProperty::Animation { old, new } => {
+                template.animation = if use_new { *new } else { *old };
+            }
         }
     }
 }
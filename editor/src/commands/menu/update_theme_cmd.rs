@@ -0,0 +1,62 @@
+// editor/src/commands/menu/update_theme_cmd.rs
+use crate::app::EditorMode;
+use crate::commands::editor_command_manager::EditorCommand;
+use crate::with_editor;
+use engine_core::prelude::*;
+
+/// Undo-able command for editing the menu theme shared by every template.
+/// Stores full before/after clones of the theme.
+#[derive(Debug)]
+pub struct UpdateThemeCmd {
+    old_theme: MenuTheme,
+    new_theme: MenuTheme,
+    /// Old and new name of a renamed style, so elements keep using it.
+    rename: Option<(String, String)>,
+}
+
+impl UpdateThemeCmd {
+    pub fn new(old_theme: MenuTheme, new_theme: MenuTheme) -> Self {
+        Self {
+            old_theme,
+            new_theme,
+            rename: None,
+        }
+    }
+
+    /// Also points elements using style `from` at style `to`.
+    pub fn renaming(mut self, from: String, to: String) -> Self {
+        self.rename = Some((from, to));
+        self
+    }
+
+    fn apply(&self, editor: &mut crate::app::Editor, use_new: bool) {
+        let menu_editor = &mut editor.menu_editor;
+        menu_editor.theme = if use_new {
+            &self.new_theme
+        } else {
+            &self.old_theme
+        }
+        .clone();
+
+        if let Some((from, to)) = &self.rename {
+            let (from, to) = if use_new { (from, to) } else { (to, from) };
+            for template in &mut menu_editor.templates {
+                template.rename_style(from, to);
+            }
+        }
+    }
+}
+
+impl EditorCommand for UpdateThemeCmd {
+    fn execute(&mut self) {
+        with_editor(|editor| self.apply(editor, true));
+    }
+
+    fn undo(&mut self) {
+        with_editor(|editor| self.apply(editor, false));
+    }
+
+    fn mode(&self) -> EditorMode {
+        EditorMode::Menu
+    }
+}
@@ -1,4 +1,5 @@
 // editor/src/commands/mod.rs
+pub mod dialogue;
 pub mod editor_command_manager;
 pub mod game;
 pub mod menu;
@@ -0,0 +1,61 @@
+// editor/src/commands/world/change_room_transition_cmd.rs
+use crate::app::EditorMode;
+use crate::commands::editor_command_manager::EditorCommand;
+use crate::with_editor;
+use engine_core::prelude::*;
+
+/// Undo-able command for changing a world's room transition.
+#[derive(Debug)]
+pub struct ChangeRoomTransitionCmd {
+    world_id: WorldId,
+    old_transition: RoomTransition,
+    new_transition: RoomTransition,
+}
+
+impl ChangeRoomTransitionCmd {
+    pub fn new(
+        world_id: WorldId,
+        old_transition: RoomTransition,
+        new_transition: RoomTransition,
+    ) -> Self {
+        Self {
+            world_id,
+            old_transition,
+            new_transition,
+        }
+    }
+}
+
+impl EditorCommand for ChangeRoomTransitionCmd {
+    fn execute(&mut self) {
+        with_editor(|editor| {
+            editor.game.get_world_mut(self.world_id).room_transition = self.new_transition;
+        });
+    }
+
+    fn undo(&mut self) {
+        with_editor(|editor| {
+            editor.game.get_world_mut(self.world_id).room_transition = self.old_transition;
+        });
+    }
+
+    fn mode(&self) -> EditorMode {
+        EditorMode::World(self.world_id)
+    }
+
+    fn applies_in_mode(&self, current_mode: EditorMode) -> bool {
+        match current_mode {
+            EditorMode::World(id) => id == self.world_id,
+            EditorMode::Room(room_id) => with_editor(|editor| {
+                editor
+                    .game
+                    .worlds
+                    .iter()
+                    .find(|w| w.id == self.world_id)
+                    .and_then(|w| w.get_room(room_id))
+                    .is_some()
+            }),
+            EditorMode::Menu | EditorMode::Dialogue | EditorMode::Game => false,
+        }
+    }
+}
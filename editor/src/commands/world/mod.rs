@@ -1,4 +1,5 @@
-// editor/src/commands/world/mod.rs
 mod change_grid_size_cmd;
+mod change_room_transition_cmd;
 
 pub use change_grid_size_cmd::*;
+pub use change_room_transition_cmd::*;
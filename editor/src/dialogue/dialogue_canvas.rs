@@ -0,0 +1,337 @@
+// editor/src/dialogue/dialogue_canvas.rs
+use crate::canvas::grid;
+use crate::canvas::grid_shader::GridRenderer;
+use crate::commands::dialogue::*;
+use crate::dialogue::dialogue_editor::*;
+use crate::dialogue::dialogue_properties_panel::{comparison_label, op_label};
+use crate::dialogue::DialogueEditor;
+use crate::editor_global::push_command;
+use bishop::prelude::*;
+use engine_core::prelude::*;
+
+pub(crate) const NODE_WIDTH: f32 = 180.0;
+const HEADER_HEIGHT: f32 = 20.0;
+const BODY_HEIGHT: f32 = 22.0;
+const SLOT_HEIGHT: f32 = 18.0;
+const PORT_RADIUS: f32 = 5.0;
+const GRID_SIZE: f32 = 16.0;
+const FONT_SIZE: f32 = 12.0;
+
+/// Returns the canvas rect of a node.
+pub(crate) fn node_rect(node: &DialogueNode) -> Rect {
+    let slots = node.kind.outputs().len() as f32;
+    Rect::new(
+        node.position.0,
+        node.position.1,
+        NODE_WIDTH,
+        HEADER_HEIGHT + BODY_HEIGHT + slots * SLOT_HEIGHT + 4.0,
+    )
+}
+
+/// Returns the point incoming edges attach to.
+fn input_port(node: &DialogueNode) -> Vec2 {
+    Vec2::new(node.position.0, node.position.1 + HEADER_HEIGHT / 2.0)
+}
+
+/// Returns the point an output slot's edge leaves from.
+fn output_port(node: &DialogueNode, slot: usize) -> Vec2 {
+    Vec2::new(
+        node.position.0 + NODE_WIDTH,
+        node.position.1 + HEADER_HEIGHT + BODY_HEIGHT + (slot as f32 + 0.5) * SLOT_HEIGHT,
+    )
+}
+
+fn header_color(kind: &DialogueNodeKind) -> Color {
+    match kind {
+        DialogueNodeKind::Line { .. } => Color::new(0.25, 0.4, 0.65, 1.0),
+        DialogueNodeKind::Choice { .. } => Color::new(0.55, 0.4, 0.15, 1.0),
+        DialogueNodeKind::Condition { .. } => Color::new(0.45, 0.25, 0.55, 1.0),
+        DialogueNodeKind::SetVariable { .. } => Color::new(0.2, 0.5, 0.45, 1.0),
+        DialogueNodeKind::Event { .. } => Color::new(0.6, 0.3, 0.3, 1.0),
+        DialogueNodeKind::End => Color::new(0.35, 0.35, 0.35, 1.0),
+    }
+}
+
+impl DialogueEditor {
+    /// Handles node selection, dragging and edge connection on the canvas.
+    pub fn update_canvas(&mut self, ctx: &mut WgpuContext, camera: &Camera2D, blocked: bool) {
+        let Some(graph_index) = self.current_graph_index else {
+            return;
+        };
+        let raw_mouse: Vec2 = ctx.mouse_position().into();
+        let mouse = camera.screen_to_world(raw_mouse, ctx.screen_width(), ctx.screen_height());
+
+        if !blocked
+            && !input_is_focused()
+            && (ctx.is_key_pressed(KeyCode::Delete) || ctx.is_key_pressed(KeyCode::Backspace))
+        {
+            if let Some(node_id) = self.selected_node {
+                push_command(Box::new(DeleteDialogueNodeCmd::new(graph_index, node_id)));
+                return;
+            }
+        }
+
+        if let Some(drag) = &self.node_drag {
+            let node_id = drag.node_id;
+            if ctx.is_mouse_button_down(MouseButton::Left) {
+                let position = mouse - drag.offset;
+                if let Some(node) = self
+                    .current_graph_mut()
+                    .and_then(|graph| graph.node_mut(node_id))
+                {
+                    node.position = (position.x.round(), position.y.round());
+                }
+            } else if let Some(drag) = self.node_drag.take() {
+                let moved = self
+                    .current_graph()
+                    .and_then(|graph| graph.node(node_id))
+                    .filter(|node| node.position != drag.original.position)
+                    .cloned();
+                if let Some(node) = moved {
+                    push_command(Box::new(UpdateDialogueNodeCmd::new(
+                        graph_index,
+                        drag.original,
+                        node,
+                    )));
+                }
+            }
+            return;
+        }
+
+        if self.connect_drag.is_some() {
+            if !ctx.is_mouse_button_down(MouseButton::Left) {
+                if let Some(connect) = self.connect_drag.take() {
+                    self.finish_connection(graph_index, connect, mouse);
+                }
+            }
+            return;
+        }
+
+        if blocked || !ctx.is_mouse_button_pressed(MouseButton::Left) {
+            return;
+        }
+
+        if let Some((node_id, slot)) = self.output_port_at(mouse) {
+            self.selected_node = Some(node_id);
+            self.connect_drag = Some(ConnectDragState { node_id, slot });
+            return;
+        }
+
+        let hit = self.node_at(mouse).and_then(|id| {
+            self.current_graph()
+                .and_then(|graph| graph.node(id))
+                .cloned()
+        });
+        match hit {
+            Some(node) => {
+                self.selected_node = Some(node.id);
+                self.node_drag = Some(NodeDragState {
+                    node_id: node.id,
+                    offset: mouse - Vec2::new(node.position.0, node.position.1),
+                    original: node,
+                });
+            }
+            None => self.selected_node = None,
+        }
+    }
+
+    /// Points the dragged slot at the node under the mouse, or clears it when dropped on empty canvas.
+    fn finish_connection(&mut self, graph_index: usize, connect: ConnectDragState, mouse: Vec2) {
+        let target = self.node_at(mouse);
+        let Some(old_node) = self
+            .current_graph()
+            .and_then(|graph| graph.node(connect.node_id))
+            .cloned()
+        else {
+            return;
+        };
+
+        let mut new_node = old_node.clone();
+        if new_node.kind.set_output(connect.slot, target) && new_node.kind != old_node.kind {
+            self.commit_node_update(graph_index, old_node, new_node);
+        }
+    }
+
+    /// Returns the top-most node under the given canvas position.
+    fn node_at(&self, point: Vec2) -> Option<DialogueNodeId> {
+        self.current_graph()?
+            .nodes
+            .iter()
+            .rev()
+            .find(|node| node_rect(node).contains(point))
+            .map(|node| node.id)
+    }
+
+    /// Returns the output slot under the given canvas position.
+    fn output_port_at(&self, point: Vec2) -> Option<(DialogueNodeId, usize)> {
+        let graph = self.current_graph()?;
+        graph.nodes.iter().rev().find_map(|node| {
+            (0..node.kind.outputs().len())
+                .find(|&slot| output_port(node, slot).distance(point) <= PORT_RADIUS * 1.5)
+                .map(|slot| (node.id, slot))
+        })
+    }
+
+    /// Draws the grid, edges and nodes of the current graph.
+    pub fn draw_canvas(
+        &mut self,
+        ctx: &mut WgpuContext,
+        camera: &Camera2D,
+        grid_renderer: &GridRenderer,
+    ) {
+        grid::draw_grid(ctx, grid_renderer, camera, GRID_SIZE);
+
+        let Some(graph) = self.current_graph().cloned() else {
+            return;
+        };
+
+        // Edges first so nodes sit on top
+        for node in &graph.nodes {
+            for (slot, (_, target)) in node.kind.outputs().into_iter().enumerate() {
+                if let Some(target) = target.and_then(|id| graph.node(id)) {
+                    draw_edge(
+                        ctx,
+                        output_port(node, slot),
+                        input_port(target),
+                        Color::new(0.8, 0.8, 0.8, 1.0),
+                    );
+                }
+            }
+        }
+
+        if let Some(connect) = &self.connect_drag {
+            if let Some(node) = graph.node(connect.node_id) {
+                let raw_mouse: Vec2 = ctx.mouse_position().into();
+                let mouse =
+                    camera.screen_to_world(raw_mouse, ctx.screen_width(), ctx.screen_height());
+                draw_edge(ctx, output_port(node, connect.slot), mouse, Color::YELLOW);
+            }
+        }
+
+        for node in &graph.nodes {
+            let preview = self.node_preview(&node.kind);
+            let has_issues = self.node_issues(node.id).next().is_some();
+            let outline = if self.selected_node == Some(node.id) {
+                Color::YELLOW
+            } else if has_issues {
+                Color::RED
+            } else {
+                Color::new(0.5, 0.5, 0.5, 1.0)
+            };
+            self.draw_node(ctx, node, graph.start == Some(node.id), &preview, outline);
+        }
+    }
+
+    fn draw_node(
+        &self,
+        ctx: &mut WgpuContext,
+        node: &DialogueNode,
+        is_start: bool,
+        preview: &str,
+        outline: Color,
+    ) {
+        let rect = node_rect(node);
+
+        ctx.draw_rectangle(
+            rect.x,
+            rect.y,
+            rect.w,
+            rect.h,
+            Color::new(0.18, 0.18, 0.22, 1.0),
+        );
+        ctx.draw_rectangle(
+            rect.x,
+            rect.y,
+            rect.w,
+            HEADER_HEIGHT,
+            header_color(&node.kind),
+        );
+        ctx.draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, outline);
+
+        let title = if is_start {
+            format!("#{} {} (start)", node.id.0, node.kind.ui_label())
+        } else {
+            format!("#{} {}", node.id.0, node.kind.ui_label())
+        };
+        ctx.draw_text(&title, rect.x + 6.0, rect.y + 14.0, FONT_SIZE, Color::WHITE);
+
+        let preview = truncate_to_width(ctx, preview, rect.w - 12.0, FONT_SIZE);
+        ctx.draw_text(
+            &preview,
+            rect.x + 6.0,
+            rect.y + HEADER_HEIGHT + 15.0,
+            FONT_SIZE,
+            Color::new(0.8, 0.8, 0.8, 1.0),
+        );
+
+        // Input port
+        let input = input_port(node);
+        ctx.draw_circle(
+            input.x,
+            input.y,
+            PORT_RADIUS,
+            Color::new(0.7, 0.7, 0.7, 1.0),
+        );
+
+        // Output slots
+        for (slot, (label, target)) in node.kind.outputs().into_iter().enumerate() {
+            let port = output_port(node, slot);
+            let label = truncate_to_width(ctx, &label, rect.w - 24.0, FONT_SIZE);
+            let dims = ctx.measure_text(&label, FONT_SIZE);
+            ctx.draw_text(
+                &label,
+                port.x - dims.width - 10.0,
+                port.y + 4.0,
+                FONT_SIZE,
+                Color::new(0.7, 0.7, 0.7, 1.0),
+            );
+
+            let color = if target.is_some() {
+                Color::GREEN
+            } else {
+                Color::new(0.5, 0.5, 0.5, 1.0)
+            };
+            ctx.draw_circle(port.x, port.y, PORT_RADIUS, color);
+        }
+    }
+
+    /// Returns the one-line summary shown in a node's body.
+    fn node_preview(&mut self, kind: &DialogueNodeKind) -> String {
+        match kind {
+            DialogueNodeKind::Line { speaker, key, .. } => {
+                let text = self.line_text(key).unwrap_or_else(|| format!("[{key}]"));
+                format!("{speaker}: {text}")
+            }
+            DialogueNodeKind::Choice { options } => format!("{} option(s)", options.len()),
+            DialogueNodeKind::Condition { condition, .. } => format!(
+                "{} {} {}",
+                condition.variable,
+                comparison_label(condition.comparison),
+                condition.value.to_display_string()
+            ),
+            DialogueNodeKind::SetVariable {
+                name, op, value, ..
+            } => format!("{} {name} {}", op_label(*op), value.to_display_string()),
+            DialogueNodeKind::Event { name, .. } => name.clone(),
+            DialogueNodeKind::End => String::new(),
+        }
+    }
+}
+
+/// Draws an edge as a horizontal bezier curve.
+fn draw_edge(ctx: &mut WgpuContext, from: Vec2, to: Vec2, color: Color) {
+    const SEGMENTS: usize = 16;
+    let bend = ((to.x - from.x).abs() / 2.0).max(40.0);
+    let c1 = from + Vec2::new(bend, 0.0);
+    let c2 = to - Vec2::new(bend, 0.0);
+
+    let mut previous = from;
+    for i in 1..=SEGMENTS {
+        let t = i as f32 / SEGMENTS as f32;
+        let u = 1.0 - t;
+        let point =
+            from * (u * u * u) + c1 * (3.0 * u * u * t) + c2 * (3.0 * u * t * t) + to * (t * t * t);
+        ctx.draw_line(previous.x, previous.y, point.x, point.y, 2.0, color);
+        previous = point;
+    }
+}
@@ -0,0 +1,298 @@
+// editor/src/dialogue/dialogue_editor.rs
+use crate::app::SubEditor;
+use crate::canvas::grid_shader::GridRenderer;
+use crate::commands::dialogue::UpdateDialogueNodeCmd;
+use crate::dialogue::*;
+use crate::editor_global::push_command;
+use crate::storage::editor_storage::load_text_file;
+use bishop::prelude::*;
+use engine_core::prelude::*;
+use std::collections::HashMap;
+
+/// Tracks a node being dragged around the canvas.
+pub(crate) struct NodeDragState {
+    pub node_id: DialogueNodeId,
+    pub offset: Vec2,
+    pub original: DialogueNode,
+}
+
+/// Tracks an edge being dragged out of a node's output slot.
+pub(crate) struct ConnectDragState {
+    pub node_id: DialogueNodeId,
+    pub slot: usize,
+}
+
+/// Main dialogue graph editor state.
+pub struct DialogueEditor {
+    pub(crate) list_panel: DialogueListPanel,
+    pub(crate) properties_panel: DialoguePropertiesPanel,
+    pub graphs: Vec<DialogueGraph>,
+    pub current_graph_index: Option<usize>,
+    pub selected_node: Option<DialogueNodeId>,
+    /// Language whose text is shown and edited.
+    pub language: String,
+    pub languages: Vec<String>,
+    /// Loaded text files by `(language, text_id)`. `None` marks a file that failed to parse
+    /// and must not be overwritten.
+    pub(crate) text_files: HashMap<(String, String), Option<TextFile>>,
+    pub(crate) issues: Vec<DialogueIssue>,
+    /// Whether the current graph or its text changed since the issues were found.
+    issues_stale: bool,
+    pub(crate) active_rects: Vec<Rect>,
+    pub(crate) node_drag: Option<NodeDragState>,
+    pub(crate) connect_drag: Option<ConnectDragState>,
+}
+
+impl DialogueEditor {
+    /// Creates a new dialogue editor.
+    pub fn new() -> Self {
+        Self {
+            list_panel: DialogueListPanel::new(),
+            properties_panel: DialoguePropertiesPanel::new(),
+            graphs: Vec::new(),
+            current_graph_index: None,
+            selected_node: None,
+            language: String::new(),
+            languages: Vec::new(),
+            text_files: HashMap::new(),
+            issues: Vec::new(),
+            issues_stale: true,
+            active_rects: Vec::new(),
+            node_drag: None,
+            connect_drag: None,
+        }
+    }
+
+    /// Updates the dialogue editor and handles input.
+    pub fn update(&mut self, ctx: &mut WgpuContext, camera: &Camera2D) {
+        let blocked = self.should_block_canvas(ctx);
+        self.update_canvas(ctx, camera, blocked);
+        self.refresh_issues();
+    }
+
+    /// Draws the canvas and ui. `config` and `asset_manager` back the speech bubble settings.
+    pub fn draw(
+        &mut self,
+        ctx: &mut WgpuContext,
+        camera: &Camera2D,
+        grid_renderer: &GridRenderer,
+        config: &mut DialogueConfig,
+        asset_manager: &mut AssetManager,
+    ) {
+        self.active_rects.clear();
+
+        ctx.set_camera(camera);
+        ctx.clear_background(Color::new(0.1, 0.1, 0.12, 1.0));
+
+        // Draw canvas under ui
+        self.draw_canvas(ctx, camera, grid_renderer);
+
+        // Draw ui after canvas
+        self.draw_ui(ctx, camera, config, asset_manager);
+    }
+
+    /// Sets all graphs and the editable languages, selecting the first graph if available.
+    pub fn set_graphs(&mut self, graphs: Vec<DialogueGraph>, languages: &[String], language: &str) {
+        self.graphs = graphs;
+        self.graphs.sort_by_key(|graph| graph.id.to_lowercase());
+        self.current_graph_index = if self.graphs.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.selected_node = None;
+        self.languages = languages.to_vec();
+        self.language = language.to_string();
+
+        // Text may have been edited outside the editor since the last visit
+        self.text_files.clear();
+        self.issues_stale = true;
+    }
+
+    /// Selects a graph by index.
+    pub fn select_graph(&mut self, index: usize) {
+        if index < self.graphs.len() {
+            self.current_graph_index = Some(index);
+            self.selected_node = None;
+            self.node_drag = None;
+            self.connect_drag = None;
+            self.issues_stale = true;
+        }
+    }
+
+    /// Keeps the current graph index valid after graphs were removed.
+    pub fn clamp_current_graph(&mut self) {
+        self.current_graph_index = match self.current_graph_index {
+            _ if self.graphs.is_empty() => None,
+            Some(current) => Some(current.min(self.graphs.len() - 1)),
+            None => None,
+        };
+        self.selected_node = None;
+        self.issues_stale = true;
+    }
+
+    /// Returns a reference to the current graph.
+    pub fn current_graph(&self) -> Option<&DialogueGraph> {
+        self.current_graph_index.and_then(|i| self.graphs.get(i))
+    }
+
+    /// Returns a mutable reference to the current graph.
+    pub fn current_graph_mut(&mut self) -> Option<&mut DialogueGraph> {
+        let index = self.current_graph_index?;
+        self.graph_mut(index)
+    }
+
+    /// Returns a mutable reference to a graph, marking its issues for another check.
+    pub fn graph_mut(&mut self, index: usize) -> Option<&mut DialogueGraph> {
+        self.issues_stale = true;
+        self.graphs.get_mut(index)
+    }
+
+    /// Sets the language whose text is shown and edited.
+    pub fn set_language(&mut self, language: String) {
+        self.language = language;
+        self.issues_stale = true;
+    }
+
+    /// Marks the issues for another check, e.g. after graphs were added or removed.
+    pub fn mark_issues_stale(&mut self) {
+        self.issues_stale = true;
+    }
+
+    /// Returns a reference to the selected node.
+    pub fn selected(&self) -> Option<&DialogueNode> {
+        let id = self.selected_node?;
+        self.current_graph()?.node(id)
+    }
+
+    /// Snapshots the selected node, applies `mutate` to produce the new state,
+    /// and pushes an `UpdateDialogueNodeCmd`. The mutation is applied immediately.
+    pub fn push_node_update<F>(&mut self, mutate: F)
+    where
+        F: FnOnce(&mut DialogueNode),
+    {
+        let Some(graph_index) = self.current_graph_index else {
+            return;
+        };
+        let Some(old_node) = self.selected().cloned() else {
+            return;
+        };
+        let mut new_node = old_node.clone();
+        mutate(&mut new_node);
+        self.commit_node_update(graph_index, old_node, new_node);
+    }
+
+    /// Applies `new_node` and records the change as a single undo-able command.
+    pub(crate) fn commit_node_update(
+        &mut self,
+        graph_index: usize,
+        old_node: DialogueNode,
+        new_node: DialogueNode,
+    ) {
+        if let Some(target) = self
+            .graph_mut(graph_index)
+            .and_then(|graph| graph.node_mut(new_node.id))
+        {
+            *target = new_node.clone();
+        }
+
+        push_command(Box::new(UpdateDialogueNodeCmd::new(
+            graph_index,
+            old_node,
+            new_node,
+        )));
+    }
+
+    /// Returns the text file for a language, loading it from disk on first use.
+    pub fn text_file(&mut self, language: &str, text_id: &str) -> Option<&TextFile> {
+        self.load_text_file(language, text_id).map(|text| &*text)
+    }
+
+    /// Returns the text file for a language mutably, loading it from disk on first use.
+    pub fn text_file_mut(&mut self, language: &str, text_id: &str) -> Option<&mut TextFile> {
+        self.issues_stale = true;
+        self.load_text_file(language, text_id)
+    }
+
+    fn load_text_file(&mut self, language: &str, text_id: &str) -> Option<&mut TextFile> {
+        if language.is_empty() || text_id.is_empty() {
+            return None;
+        }
+
+        self.text_files
+            .entry((language.to_string(), text_id.to_string()))
+            .or_insert_with(|| match load_text_file(language, text_id) {
+                Ok(text) => Some(text),
+                Err(e) => {
+                    onscreen_error!("Could not read text '{}' ({}): {}", text_id, language, e);
+                    None
+                }
+            })
+            .as_mut()
+    }
+
+    /// Returns the first variant of a key in the current language.
+    pub fn line_text(&mut self, key: &str) -> Option<String> {
+        let text_id = self.current_graph()?.text_id.clone();
+        let language = self.language.clone();
+        let entry = self.text_file(&language, &text_id)?.entries.get(key)?;
+        entry.variants.first().cloned()
+    }
+
+    /// Returns the issues attached to a node.
+    pub fn node_issues(&self, id: DialogueNodeId) -> impl Iterator<Item = &DialogueIssue> {
+        self.issues
+            .iter()
+            .filter(move |issue| issue.node() == Some(id))
+    }
+
+    fn refresh_issues(&mut self) {
+        if !std::mem::take(&mut self.issues_stale) {
+            return;
+        }
+        let Some(text_id) = self.current_graph().map(|graph| graph.text_id.clone()) else {
+            self.issues.clear();
+            return;
+        };
+        let language = self.language.clone();
+
+        // Make sure the text file is loaded before borrowing it alongside the graph
+        self.text_file(&language, &text_id);
+        let text = self
+            .text_files
+            .get(&(language, text_id))
+            .and_then(|text| text.as_ref());
+
+        if let Some(graph) = self.current_graph_index.and_then(|i| self.graphs.get(i)) {
+            self.issues = validate_dialogue_graph(graph, text);
+        }
+    }
+
+    #[inline]
+    pub fn register_rect(&mut self, rect: Rect) -> Rect {
+        self.active_rects.push(rect);
+        rect
+    }
+
+    /// Initializes the camera with a 1:1 screen-space mapping.
+    pub fn init_camera(ctx: &WgpuContext, camera: &mut Camera2D) {
+        let sw = ctx.screen_width();
+        let sh = ctx.screen_height();
+        camera.target = Vec2::new(sw / 2.0, sh / 2.0);
+        camera.zoom = Vec2::new(2.0 / sw, 2.0 / sh);
+        camera.rotation = 0.0;
+        camera.offset = Vec2::ZERO;
+    }
+}
+
+impl SubEditor for DialogueEditor {
+    fn active_rects(&self) -> &[Rect] {
+        &self.active_rects
+    }
+}
+
+impl Default for DialogueEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,794 @@
+// editor/src/dialogue/dialogue_properties_panel.rs
+use crate::commands::dialogue::*;
+use crate::dialogue::DialogueEditor;
+use crate::editor_global::push_command;
+use crate::menu::menu_properties_panel::control_properties::{checkbox_row, color_row, RowStyle};
+use crate::menu::menu_properties_panel::slice_properties::{slice_rows, SliceDrag, SliceWidgetIds};
+use bishop::prelude::*;
+use engine_core::prelude::*;
+
+const ROW_HEIGHT: f32 = 28.0;
+const LABEL_WIDTH: f32 = 80.0;
+const FIELD_HEIGHT: f32 = 24.0;
+
+const COMPARISON_OPTIONS: [&str; 7] = ["==", "!=", "<", "<=", ">", ">=", "is true"];
+const OP_OPTIONS: [&str; 3] = ["Set", "Add", "Toggle"];
+
+/// Widget IDs for the dialogue properties panel.
+#[derive(Default)]
+pub struct DialoguePropertiesWidgetIds {
+    pub(crate) language_id: WidgetId,
+    pub(crate) text_file_id: WidgetId,
+    pub(crate) speaker_id: WidgetId,
+    pub(crate) key_id: WidgetId,
+    pub(crate) line_text_id: WidgetId,
+    pub(crate) variable_id: WidgetId,
+    pub(crate) comparison_id: WidgetId,
+    pub(crate) value_id: WidgetId,
+    pub(crate) op_id: WidgetId,
+    pub(crate) event_name_id: WidgetId,
+    pub(crate) option_ids: Vec<ChoiceOptionWidgetIds>,
+    pub(crate) bubble_color_id: WidgetId,
+    pub(crate) bubble_slice_ids: SliceWidgetIds,
+}
+
+/// Widget IDs for a single choice option.
+#[derive(Default, Clone, Copy)]
+pub struct ChoiceOptionWidgetIds {
+    pub(crate) key: WidgetId,
+    pub(crate) text: WidgetId,
+}
+
+/// Groups dialogue property panel data.
+pub struct DialoguePropertiesPanel {
+    pub(crate) scroll_state: ScrollState,
+    pub(crate) widget_ids: DialoguePropertiesWidgetIds,
+    pub(crate) last_content_height: f32,
+    pub(crate) slice_drag: Option<SliceDrag>,
+}
+
+impl DialoguePropertiesPanel {
+    /// Creates a new properties panel.
+    pub fn new() -> Self {
+        Self {
+            scroll_state: ScrollState::new(),
+            widget_ids: DialoguePropertiesWidgetIds::default(),
+            last_content_height: 0.0,
+            slice_drag: None,
+        }
+    }
+
+    fn option_ids(&mut self, index: usize) -> ChoiceOptionWidgetIds {
+        while self.widget_ids.option_ids.len() <= index {
+            self.widget_ids
+                .option_ids
+                .push(ChoiceOptionWidgetIds::default());
+        }
+        self.widget_ids.option_ids[index]
+    }
+}
+
+impl Default for DialoguePropertiesPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DialogueEditor {
+    /// Renders the properties panel for the selected node or graph, followed by validation issues.
+    /// Without a selected node it also shows the speech bubble settings.
+    pub fn draw_properties_panel(
+        &mut self,
+        ctx: &mut WgpuContext,
+        rect: Rect,
+        blocked: bool,
+        config: &mut DialogueConfig,
+        asset_manager: &mut AssetManager,
+    ) {
+        let content_height = self.properties_panel.last_content_height;
+
+        let area = ScrollableArea::new(rect, content_height)
+            .scroll_speed(20.0)
+            .blocked(blocked)
+            .begin(ctx, &mut self.properties_panel.scroll_state);
+
+        let start_y = rect.y + self.properties_panel.scroll_state.scroll_y + 8.0;
+        let mut y = start_y;
+        let x = rect.x + 8.0;
+        let w = area.content_rect().w - 16.0;
+
+        if row_visible(y, 24.0, &rect) {
+            ctx.draw_text("Properties", x, y + 14.0, 14.0, Color::GREY);
+        }
+        y += 24.0;
+
+        if self.current_graph().is_none() {
+            if row_visible(y, ROW_HEIGHT, &rect) {
+                ctx.draw_text("No dialogue selected", x, y + 16.0, 12.0, Color::GREY);
+            }
+            y += ROW_HEIGHT;
+
+            let style = RowStyle {
+                x,
+                w,
+                blocked,
+                clip: &rect,
+            };
+            self.draw_bubble_properties(ctx, &mut y, style, config, asset_manager);
+        } else {
+            self.draw_language_row(ctx, &mut y, x, w, blocked, &rect);
+
+            match self.selected().map(|node| node.kind.clone()) {
+                Some(kind) => self.draw_node_properties(ctx, &kind, &mut y, x, w, blocked, &rect),
+                None => {
+                    self.draw_graph_properties(ctx, &mut y, x, w, blocked, &rect);
+                    let style = RowStyle {
+                        x,
+                        w,
+                        blocked,
+                        clip: &rect,
+                    };
+                    self.draw_bubble_properties(ctx, &mut y, style, config, asset_manager);
+                }
+            }
+
+            y += 8.0;
+            self.draw_issues(ctx, &mut y, x, w, blocked, &rect);
+        }
+
+        self.properties_panel.last_content_height = y - start_y + 16.0;
+        area.draw_scrollbar(ctx, self.properties_panel.scroll_state.scroll_y);
+    }
+
+    fn draw_language_row(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        x: f32,
+        w: f32,
+        blocked: bool,
+        clip: &Rect,
+    ) {
+        if row_visible(*y, ROW_HEIGHT, clip) {
+            ctx.draw_text("Language:", x, *y + 16.0, 12.0, Color::WHITE);
+            let dropdown_rect = Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
+            let languages = self.languages.clone();
+            if let Some(language) = Dropdown::new(
+                self.properties_panel.widget_ids.language_id,
+                dropdown_rect,
+                &self.language,
+                &languages,
+                |s| s.to_string(),
+            )
+            .blocked(blocked)
+            .fixed_width()
+            .show(ctx)
+            {
+                self.set_language(language);
+            }
+        }
+        *y += ROW_HEIGHT;
+    }
+
+    fn draw_graph_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        x: f32,
+        w: f32,
+        blocked: bool,
+        clip: &Rect,
+    ) {
+        let Some(graph_index) = self.current_graph_index else {
+            return;
+        };
+        let Some((id, text_id)) = self
+            .current_graph()
+            .map(|graph| (graph.id.clone(), graph.text_id.clone()))
+        else {
+            return;
+        };
+
+        if row_visible(*y, ROW_HEIGHT, clip) {
+            ctx.draw_text("Id:", x, *y + 16.0, 12.0, Color::WHITE);
+            ctx.draw_text(
+                &id,
+                x + LABEL_WIDTH,
+                *y + 16.0,
+                12.0,
+                Color::new(0.7, 0.7, 0.7, 1.0),
+            );
+        }
+        *y += ROW_HEIGHT;
+
+        if row_visible(*y, ROW_HEIGHT, clip) {
+            ctx.draw_text("Text file:", x, *y + 16.0, 12.0, Color::WHITE);
+            let field_rect = Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
+            let (new_text_id, _) = TextInput::new(
+                self.properties_panel.widget_ids.text_file_id,
+                field_rect,
+                &text_id,
+            )
+            .blocked(blocked)
+            .show(ctx);
+            if new_text_id != text_id {
+                if let Some(graph) = self.current_graph_mut() {
+                    graph.text_id = new_text_id.clone();
+                }
+                push_command(Box::new(UpdateDialogueGraphCmd::new(
+                    graph_index,
+                    DialogueGraphProperty::TextId(text_id),
+                    DialogueGraphProperty::TextId(new_text_id),
+                )));
+            }
+        }
+        *y += ROW_HEIGHT;
+
+        if row_visible(*y, ROW_HEIGHT, clip) {
+            ctx.draw_text("Select a node to edit it.", x, *y + 16.0, 12.0, Color::GREY);
+        }
+        *y += ROW_HEIGHT;
+    }
+
+    /// Draws the speech bubble background settings shared by every graph.
+    fn draw_bubble_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        style: RowStyle<'_>,
+        config: &mut DialogueConfig,
+        asset_manager: &mut AssetManager,
+    ) {
+        *y += 8.0;
+        if row_visible(*y, 24.0, style.clip) {
+            ctx.draw_text("Speech Bubbles", style.x, *y + 14.0, 14.0, Color::GREY);
+        }
+        *y += 24.0;
+
+        let ids = &self.properties_panel.widget_ids;
+        let (color_id, slice_ids) = (ids.bubble_color_id, ids.bubble_slice_ids);
+        let mut edited = config.clone();
+
+        if let Some(show) = checkbox_row(ctx, y, style, "Background:", config.show_background) {
+            edited.show_background = show;
+        }
+
+        let [r, g, b, a] = config.default_background_color;
+        if let Some(color) = color_row(ctx, y, style, "Color:", color_id, Color::new(r, g, b, a)) {
+            edited.default_background_color = [color.r, color.g, color.b, color.a];
+        }
+
+        let sprite = config
+            .background_sprite_id(ctx, asset_manager)
+            .unwrap_or(SpriteId(0));
+        if row_visible(*y, ROW_HEIGHT, style.clip) {
+            ctx.draw_text("Sprite:", style.x, *y + 16.0, 12.0, Color::WHITE);
+            let picker_rect = Rect::new(
+                style.x + LABEL_WIDTH,
+                *y,
+                style.w - LABEL_WIDTH,
+                FIELD_HEIGHT,
+            );
+            if let Some(picked) =
+                gui_sprite_field(ctx, picker_rect, sprite, asset_manager, style.blocked)
+            {
+                let path = asset_manager
+                    .sprite_id_to_path
+                    .get(&picked)
+                    .map(|path| path.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                edited.set_background_sprite(path);
+            }
+        }
+        *y += ROW_HEIGHT;
+
+        if let Some(slice) = slice_rows(
+            ctx,
+            y,
+            style,
+            slice_ids,
+            sprite,
+            config.background_slice,
+            &mut self.properties_panel.slice_drag,
+            asset_manager,
+        ) {
+            edited.background_slice = slice;
+        }
+
+        if edited.show_background != config.show_background
+            || edited.default_background_color != config.default_background_color
+            || edited.background_sprite != config.background_sprite
+            || edited.background_slice != config.background_slice
+        {
+            push_command(Box::new(UpdateDialogueConfigCmd::new(
+                config.clone(),
+                edited,
+            )));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_node_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        kind: &DialogueNodeKind,
+        y: &mut f32,
+        x: f32,
+        w: f32,
+        blocked: bool,
+        clip: &Rect,
+    ) {
+        let Some(graph_index) = self.current_graph_index else {
+            return;
+        };
+        let Some(node_id) = self.selected_node else {
+            return;
+        };
+        let start = self.current_graph().and_then(|graph| graph.start);
+
+        // Type (read-only)
+        if row_visible(*y, ROW_HEIGHT, clip) {
+            ctx.draw_text("Type:", x, *y + 16.0, 12.0, Color::WHITE);
+            ctx.draw_text(
+                &format!("{} #{}", kind.ui_label(), node_id.0),
+                x + LABEL_WIDTH,
+                *y + 16.0,
+                12.0,
+                Color::new(0.7, 0.7, 0.7, 1.0),
+            );
+        }
+        *y += ROW_HEIGHT;
+
+        if row_visible(*y, ROW_HEIGHT, clip) {
+            let button_rect = Rect::new(x, *y, w, FIELD_HEIGHT);
+            let is_start = start == Some(node_id);
+            let label = if is_start {
+                "Start node"
+            } else {
+                "Set as start"
+            };
+            if Button::new(button_rect, label)
+                .blocked(blocked || is_start)
+                .show(ctx)
+            {
+                push_command(Box::new(UpdateDialogueGraphCmd::new(
+                    graph_index,
+                    DialogueGraphProperty::Start(start),
+                    DialogueGraphProperty::Start(Some(node_id)),
+                )));
+            }
+        }
+        *y += ROW_HEIGHT;
+
+        match kind {
+            DialogueNodeKind::Line { speaker, key, .. } => {
+                let speaker_id = self.properties_panel.widget_ids.speaker_id;
+                if let Some(new) =
+                    text_row(ctx, "Speaker:", speaker_id, speaker, y, x, w, blocked, clip)
+                {
+                    self.push_node_update(|node| {
+                        if let DialogueNodeKind::Line { speaker, .. } = &mut node.kind {
+                            *speaker = new;
+                        }
+                    });
+                }
+
+                let key_id = self.properties_panel.widget_ids.key_id;
+                if let Some(new) = text_row(ctx, "Key:", key_id, key, y, x, w, blocked, clip) {
+                    self.push_node_update(|node| {
+                        if let DialogueNodeKind::Line { key, .. } = &mut node.kind {
+                            *key = new;
+                        }
+                    });
+                }
+
+                let text_id = self.properties_panel.widget_ids.line_text_id;
+                self.draw_line_text_row(ctx, "Text:", text_id, key, y, x, w, blocked, clip);
+            }
+            DialogueNodeKind::Choice { options } => {
+                let mut removed = None;
+                for (index, option) in options.iter().enumerate() {
+                    let ids = self.properties_panel.option_ids(index);
+
+                    if row_visible(*y, ROW_HEIGHT, clip) {
+                        ctx.draw_text(
+                            &format!("Option {}", index + 1),
+                            x,
+                            *y + 16.0,
+                            12.0,
+                            Color::GREY,
+                        );
+                        let remove_rect = Rect::new(x + w - 24.0, *y, 24.0, FIELD_HEIGHT);
+                        if Button::new(remove_rect, "×").blocked(blocked).show(ctx) {
+                            removed = Some(index);
+                        }
+                    }
+                    *y += ROW_HEIGHT;
+
+                    if let Some(new) =
+                        text_row(ctx, "Key:", ids.key, &option.key, y, x, w, blocked, clip)
+                    {
+                        self.push_node_update(|node| {
+                            if let DialogueNodeKind::Choice { options } = &mut node.kind {
+                                if let Some(option) = options.get_mut(index) {
+                                    option.key = new;
+                                }
+                            }
+                        });
+                    }
+
+                    self.draw_line_text_row(
+                        ctx,
+                        "Text:",
+                        ids.text,
+                        &option.key,
+                        y,
+                        x,
+                        w,
+                        blocked,
+                        clip,
+                    );
+                }
+
+                if let Some(index) = removed {
+                    self.push_node_update(|node| {
+                        if let DialogueNodeKind::Choice { options } = &mut node.kind {
+                            if index < options.len() {
+                                options.remove(index);
+                            }
+                        }
+                    });
+                }
+
+                if row_visible(*y, ROW_HEIGHT, clip) {
+                    let button_rect = Rect::new(x, *y, w, FIELD_HEIGHT);
+                    if Button::new(button_rect, "Add option")
+                        .blocked(blocked)
+                        .show(ctx)
+                    {
+                        let key = format!("choice_{}_{}", node_id.0, options.len() + 1);
+                        self.push_node_update(|node| {
+                            if let DialogueNodeKind::Choice { options } = &mut node.kind {
+                                options.push(DialogueChoice {
+                                    key,
+                                    next: None,
+                                    condition: None,
+                                });
+                            }
+                        });
+                    }
+                }
+                *y += ROW_HEIGHT;
+            }
+            DialogueNodeKind::Condition { condition, .. } => {
+                let variable_id = self.properties_panel.widget_ids.variable_id;
+                if let Some(new) = text_row(
+                    ctx,
+                    "Variable:",
+                    variable_id,
+                    &condition.variable,
+                    y,
+                    x,
+                    w,
+                    blocked,
+                    clip,
+                ) {
+                    self.push_node_update(|node| {
+                        if let DialogueNodeKind::Condition { condition, .. } = &mut node.kind {
+                            condition.variable = new;
+                        }
+                    });
+                }
+
+                if row_visible(*y, ROW_HEIGHT, clip) {
+                    ctx.draw_text("Compare:", x, *y + 16.0, 12.0, Color::WHITE);
+                    let dropdown_rect =
+                        Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
+                    if let Some(selected) = Dropdown::new(
+                        self.properties_panel.widget_ids.comparison_id,
+                        dropdown_rect,
+                        comparison_label(condition.comparison),
+                        &COMPARISON_OPTIONS,
+                        |s| s.to_string(),
+                    )
+                    .blocked(blocked)
+                    .fixed_width()
+                    .show(ctx)
+                    {
+                        let comparison = comparison_from_label(selected);
+                        self.push_node_update(|node| {
+                            if let DialogueNodeKind::Condition { condition, .. } = &mut node.kind {
+                                condition.comparison = comparison;
+                            }
+                        });
+                    }
+                }
+                *y += ROW_HEIGHT;
+
+                let value_id = self.properties_panel.widget_ids.value_id;
+                let current = condition.value.to_display_string();
+                if let Some(new) =
+                    text_row(ctx, "Value:", value_id, &current, y, x, w, blocked, clip)
+                {
+                    self.push_node_update(|node| {
+                        if let DialogueNodeKind::Condition { condition, .. } = &mut node.kind {
+                            condition.value = parse_dialogue_value(&new);
+                        }
+                    });
+                }
+            }
+            DialogueNodeKind::SetVariable {
+                name, op, value, ..
+            } => {
+                let variable_id = self.properties_panel.widget_ids.variable_id;
+                if let Some(new) =
+                    text_row(ctx, "Variable:", variable_id, name, y, x, w, blocked, clip)
+                {
+                    self.push_node_update(|node| {
+                        if let DialogueNodeKind::SetVariable { name, .. } = &mut node.kind {
+                            *name = new;
+                        }
+                    });
+                }
+
+                if row_visible(*y, ROW_HEIGHT, clip) {
+                    ctx.draw_text("Operation:", x, *y + 16.0, 12.0, Color::WHITE);
+                    let dropdown_rect =
+                        Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
+                    if let Some(selected) = Dropdown::new(
+                        self.properties_panel.widget_ids.op_id,
+                        dropdown_rect,
+                        op_label(*op),
+                        &OP_OPTIONS,
+                        |s| s.to_string(),
+                    )
+                    .blocked(blocked)
+                    .fixed_width()
+                    .show(ctx)
+                    {
+                        let new_op = match selected {
+                            "Add" => DialogueVariableOp::Add,
+                            "Toggle" => DialogueVariableOp::Toggle,
+                            _ => DialogueVariableOp::Set,
+                        };
+                        self.push_node_update(|node| {
+                            if let DialogueNodeKind::SetVariable { op, .. } = &mut node.kind {
+                                *op = new_op;
+                            }
+                        });
+                    }
+                }
+                *y += ROW_HEIGHT;
+
+                let value_id = self.properties_panel.widget_ids.value_id;
+                let current = value.to_display_string();
+                if let Some(new) =
+                    text_row(ctx, "Value:", value_id, &current, y, x, w, blocked, clip)
+                {
+                    self.push_node_update(|node| {
+                        if let DialogueNodeKind::SetVariable { value, .. } = &mut node.kind {
+                            *value = parse_dialogue_value(&new);
+                        }
+                    });
+                }
+            }
+            DialogueNodeKind::Event { name, .. } => {
+                let event_id = self.properties_panel.widget_ids.event_name_id;
+                if let Some(new) = text_row(ctx, "Event:", event_id, name, y, x, w, blocked, clip) {
+                    self.push_node_update(|node| {
+                        if let DialogueNodeKind::Event { name, .. } = &mut node.kind {
+                            *name = new;
+                        }
+                    });
+                }
+            }
+            DialogueNodeKind::End => {}
+        }
+    }
+
+    /// Draws an input for the first variant of `key` in the current language,
+    /// writing edits through to the language's text file.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_line_text_row(
+        &mut self,
+        ctx: &mut WgpuContext,
+        label: &str,
+        id: WidgetId,
+        key: &str,
+        y: &mut f32,
+        x: f32,
+        w: f32,
+        blocked: bool,
+        clip: &Rect,
+    ) {
+        let Some(text_id) = self.current_graph().map(|graph| graph.text_id.clone()) else {
+            return;
+        };
+        let language = self.language.clone();
+        let Some(old_entry) = self
+            .text_file(&language, &text_id)
+            .map(|text| text.entries.get(key).cloned())
+        else {
+            if row_visible(*y, ROW_HEIGHT, clip) {
+                ctx.draw_text(label, x, *y + 16.0, 12.0, Color::WHITE);
+                ctx.draw_text(
+                    "Text file unavailable",
+                    x + LABEL_WIDTH,
+                    *y + 16.0,
+                    12.0,
+                    Color::GREY,
+                );
+            }
+            *y += ROW_HEIGHT;
+            return;
+        };
+
+        let current = old_entry
+            .as_ref()
+            .and_then(|entry| entry.variants.first().cloned())
+            .unwrap_or_default();
+
+        if let Some(new) = text_row(ctx, label, id, &current, y, x, w, blocked, clip) {
+            let mut new_entry = old_entry.clone().unwrap_or_default();
+            match new_entry.variants.first_mut() {
+                Some(first) => *first = new,
+                None => new_entry.variants.push(new),
+            }
+
+            // Apply immediately so the input keeps its value until the command runs
+            if let Some(text) = self.text_file_mut(&language, &text_id) {
+                text.entries.insert(key.to_string(), new_entry.clone());
+            }
+            push_command(Box::new(UpdateDialogueTextCmd::new(
+                language,
+                text_id,
+                key.to_string(),
+                old_entry.clone(),
+                Some(new_entry),
+            )));
+        }
+
+        let extra_variants = old_entry.map_or(0, |entry| entry.variants.len().saturating_sub(1));
+        if extra_variants > 0 {
+            if row_visible(*y, ROW_HEIGHT, clip) {
+                ctx.draw_text(
+                    &format!("+{extra_variants} more variant(s) in the text file"),
+                    x + LABEL_WIDTH,
+                    *y + 16.0,
+                    12.0,
+                    Color::GREY,
+                );
+            }
+            *y += ROW_HEIGHT;
+        }
+    }
+
+    fn draw_issues(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        x: f32,
+        w: f32,
+        blocked: bool,
+        clip: &Rect,
+    ) {
+        if row_visible(*y, 24.0, clip) {
+            ctx.draw_text("Issues", x, *y + 14.0, 14.0, Color::GREY);
+        }
+        *y += 24.0;
+
+        if self.issues.is_empty() {
+            if row_visible(*y, ROW_HEIGHT, clip) {
+                ctx.draw_text("No issues found", x, *y + 16.0, 12.0, Color::GREEN);
+            }
+            *y += ROW_HEIGHT;
+            return;
+        }
+
+        let mouse: Vec2 = ctx.mouse_position().into();
+        let mut clicked = None;
+        for issue in &self.issues {
+            if row_visible(*y, ROW_HEIGHT, clip) {
+                let item_rect = Rect::new(x, *y, w, FIELD_HEIGHT);
+                let hover = item_rect.contains(mouse) && !blocked;
+                if hover {
+                    ctx.draw_rectangle(
+                        item_rect.x,
+                        item_rect.y,
+                        item_rect.w,
+                        item_rect.h,
+                        Color::new(0.25, 0.25, 0.3, 1.0),
+                    );
+                }
+
+                let message = truncate_to_width(ctx, &issue.message(), w - 8.0, 12.0);
+                ctx.draw_text(&message, x + 4.0, *y + 16.0, 12.0, Color::RED);
+
+                if hover && ctx.is_mouse_button_pressed(MouseButton::Left) {
+                    clicked = issue.node();
+                }
+            }
+            *y += ROW_HEIGHT;
+        }
+
+        if clicked.is_some() {
+            self.selected_node = clicked;
+        }
+    }
+}
+
+/// Draws a labelled text input and returns the new value when it changed.
+#[allow(clippy::too_many_arguments)]
+fn text_row(
+    ctx: &mut WgpuContext,
+    label: &str,
+    id: WidgetId,
+    current: &str,
+    y: &mut f32,
+    x: f32,
+    w: f32,
+    blocked: bool,
+    clip: &Rect,
+) -> Option<String> {
+    let mut changed = None;
+    if row_visible(*y, ROW_HEIGHT, clip) {
+        ctx.draw_text(label, x, *y + 16.0, 12.0, Color::WHITE);
+        let field_rect = Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
+        let (new, _) = TextInput::new(id, field_rect, current)
+            .blocked(blocked)
+            .show(ctx);
+        if new != current {
+            changed = Some(new);
+        }
+    }
+    *y += ROW_HEIGHT;
+    changed
+}
+
+fn row_visible(y: f32, h: f32, clip: &Rect) -> bool {
+    y >= clip.y && y + h <= clip.y + clip.h
+}
+
+/// Parses a typed value: `true`/`false` become bools, numbers become numbers, anything else is text.
+fn parse_dialogue_value(text: &str) -> DialogueValue {
+    match text.trim() {
+        "true" => DialogueValue::Bool(true),
+        "false" => DialogueValue::Bool(false),
+        trimmed => match trimmed.parse::<f64>() {
+            Ok(number) => DialogueValue::Number(number),
+            Err(_) => DialogueValue::Text(text.to_string()),
+        },
+    }
+}
+
+pub(crate) fn comparison_label(comparison: DialogueComparison) -> &'static str {
+    match comparison {
+        DialogueComparison::Equal => "==",
+        DialogueComparison::NotEqual => "!=",
+        DialogueComparison::Less => "<",
+        DialogueComparison::LessOrEqual => "<=",
+        DialogueComparison::Greater => ">",
+        DialogueComparison::GreaterOrEqual => ">=",
+        DialogueComparison::IsTrue => "is true",
+    }
+}
+
+fn comparison_from_label(label: &str) -> DialogueComparison {
+    match label {
+        "!=" => DialogueComparison::NotEqual,
+        "<" => DialogueComparison::Less,
+        "<=" => DialogueComparison::LessOrEqual,
+        ">" => DialogueComparison::Greater,
+        ">=" => DialogueComparison::GreaterOrEqual,
+        "is true" => DialogueComparison::IsTrue,
+        _ => DialogueComparison::Equal,
+    }
+}
+
+pub(crate) fn op_label(op: DialogueVariableOp) -> &'static str {
+    match op {
+        DialogueVariableOp::Set => "Set",
+        DialogueVariableOp::Add => "Add",
+        DialogueVariableOp::Toggle => "Toggle",
+    }
+}
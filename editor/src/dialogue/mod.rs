@@ -0,0 +1,12 @@
+// editor/src/dialogue/mod.rs
+pub mod dialogue_canvas;
+pub mod dialogue_editor;
+pub mod dialogue_list_panel;
+pub mod dialogue_properties_panel;
+pub mod node_palette;
+pub mod ui;
+
+pub use dialogue_editor::*;
+pub use dialogue_list_panel::*;
+pub use dialogue_properties_panel::*;
+pub use node_palette::*;
@@ -0,0 +1,113 @@
+// editor/src/dialogue/node_palette.rs
+use bishop::prelude::*;
+use engine_core::prelude::*;
+
+const PALETTE_ITEM_HEIGHT: f32 = 28.0;
+const PALETTE_SPACING: f32 = 4.0;
+const PALETTE_ITEMS: [&str; 6] = [
+    "Line",
+    "Choice",
+    "Condition",
+    "Set Variable",
+    "Event",
+    "End",
+];
+
+/// Palette of dialogue node kinds.
+pub struct NodePalette;
+
+impl NodePalette {
+    /// Renders the palette and returns the clicked node kind, with keys derived from `next_id`.
+    pub fn draw(
+        ctx: &mut WgpuContext,
+        rect: Rect,
+        next_id: DialogueNodeId,
+        blocked: bool,
+    ) -> Option<DialogueNodeKind> {
+        let mouse: Vec2 = ctx.mouse_position().into();
+        let mut y = rect.y + 8.0;
+
+        ctx.draw_text("Nodes", rect.x + 8.0, y + 14.0, 14.0, Color::GREY);
+        y += 24.0;
+
+        let mut clicked = None;
+        for name in PALETTE_ITEMS {
+            if y + PALETTE_ITEM_HEIGHT > rect.y + rect.h {
+                break;
+            }
+
+            let item_rect = Rect::new(rect.x + 8.0, y, rect.w - 16.0, PALETTE_ITEM_HEIGHT);
+            let hover = item_rect.contains(mouse) && !blocked;
+
+            let bg_color = if hover {
+                Color::new(0.3, 0.3, 0.35, 1.0)
+            } else {
+                Color::new(0.2, 0.2, 0.25, 1.0)
+            };
+            ctx.draw_rectangle(item_rect.x, item_rect.y, item_rect.w, item_rect.h, bg_color);
+            ctx.draw_rectangle_lines(
+                item_rect.x,
+                item_rect.y,
+                item_rect.w,
+                item_rect.h,
+                1.0,
+                Color::new(0.5, 0.5, 0.5, 1.0),
+            );
+            ctx.draw_text(
+                name,
+                item_rect.x + 8.0,
+                item_rect.y + 18.0,
+                14.0,
+                Color::WHITE,
+            );
+
+            if hover && ctx.is_mouse_button_pressed(MouseButton::Left) {
+                clicked = Some(default_node_kind(name, next_id));
+            }
+
+            y += PALETTE_ITEM_HEIGHT + PALETTE_SPACING;
+        }
+
+        clicked
+    }
+}
+
+/// Returns a new node of the named kind with placeholder fields.
+fn default_node_kind(name: &str, id: DialogueNodeId) -> DialogueNodeKind {
+    let flag_condition = DialogueCondition {
+        variable: "flag".to_string(),
+        comparison: DialogueComparison::IsTrue,
+        value: DialogueValue::Bool(true),
+    };
+
+    match name {
+        "Line" => DialogueNodeKind::Line {
+            speaker: "npc".to_string(),
+            key: format!("line_{}", id.0),
+            next: None,
+        },
+        "Choice" => DialogueNodeKind::Choice {
+            options: vec![DialogueChoice {
+                key: format!("choice_{}_1", id.0),
+                next: None,
+                condition: None,
+            }],
+        },
+        "Condition" => DialogueNodeKind::Condition {
+            condition: flag_condition,
+            if_true: None,
+            if_false: None,
+        },
+        "Set Variable" => DialogueNodeKind::SetVariable {
+            name: "flag".to_string(),
+            op: DialogueVariableOp::Set,
+            value: DialogueValue::Bool(true),
+            next: None,
+        },
+        "Event" => DialogueNodeKind::Event {
+            name: "dialogue:event".to_string(),
+            next: None,
+        },
+        _ => DialogueNodeKind::End,
+    }
+}
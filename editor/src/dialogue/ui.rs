@@ -0,0 +1,102 @@
+// editor/src/dialogue/ui.rs
+use crate::commands::dialogue::AddDialogueNodeCmd;
+use crate::dialogue::dialogue_canvas::NODE_WIDTH;
+use crate::dialogue::{DialogueEditor, NodePalette};
+use crate::editor_global::push_command;
+use crate::gui::menu_bar::{draw_top_panel_full, menu_panel_rect};
+use bishop::prelude::*;
+use engine_core::prelude::*;
+
+impl DialogueEditor {
+    /// Draws the dialogue editor ui.
+    pub fn draw_ui(
+        &mut self,
+        ctx: &mut WgpuContext,
+        camera: &Camera2D,
+        config: &mut DialogueConfig,
+        asset_manager: &mut AssetManager,
+    ) {
+        const LEFT_COLUMN_WIDTH: f32 = 200.0;
+        const PROPERTIES_WIDTH: f32 = 280.0;
+        const PALETTE_HEIGHT: f32 = 230.0;
+        const SPACING: f32 = 8.0;
+
+        let blocked = false;
+
+        // Reset to static camera
+        ctx.set_default_camera();
+
+        // Calculate top panel
+        let menu_panel = menu_panel_rect(ctx);
+
+        let screen_rect = Rect::new(
+            0.0,
+            menu_panel.h,
+            ctx.screen_width(),
+            ctx.screen_height() - menu_panel.h,
+        );
+
+        let list_rect = self.register_rect(Rect::new(
+            screen_rect.x + SPACING,
+            screen_rect.y + SPACING,
+            LEFT_COLUMN_WIDTH,
+            screen_rect.h - PALETTE_HEIGHT - SPACING * 3.0,
+        ));
+
+        let palette_rect = self.register_rect(Rect::new(
+            screen_rect.x + SPACING,
+            list_rect.bottom() + SPACING,
+            LEFT_COLUMN_WIDTH,
+            PALETTE_HEIGHT,
+        ));
+
+        let properties_rect = self.register_rect(Rect::new(
+            screen_rect.right() - PROPERTIES_WIDTH - SPACING,
+            screen_rect.y + SPACING,
+            PROPERTIES_WIDTH,
+            screen_rect.h - SPACING * 2.0,
+        ));
+
+        draw_panel_background(ctx, list_rect);
+        self.draw_dialogue_list_panel(ctx, list_rect, blocked);
+
+        draw_panel_background(ctx, palette_rect);
+        if let Some(graph_index) = self.current_graph_index {
+            let next_id = self.graphs[graph_index].next_node_id();
+            if let Some(kind) = NodePalette::draw(ctx, palette_rect, next_id, blocked) {
+                // Drop new nodes at the centre of the view
+                let position = camera.target - Vec2::new(NODE_WIDTH / 2.0, 20.0);
+                push_command(Box::new(AddDialogueNodeCmd::new(
+                    graph_index,
+                    kind,
+                    (position.x.round(), position.y.round()),
+                )));
+            }
+        }
+
+        draw_panel_background(ctx, properties_rect);
+        self.draw_properties_panel(ctx, properties_rect, blocked, config, asset_manager);
+
+        // Draw top menu
+        self.register_rect(draw_top_panel_full(ctx));
+    }
+}
+
+fn draw_panel_background(ctx: &mut WgpuContext, rect: Rect) {
+    ctx.draw_rectangle(
+        rect.x,
+        rect.y,
+        rect.w,
+        rect.h,
+        Color::new(0.15, 0.15, 0.18, 1.0),
+    );
+
+    ctx.draw_rectangle_lines(
+        rect.x,
+        rect.y,
+        rect.w,
+        rect.h,
+        1.0,
+        Color::new(0.4, 0.4, 0.4, 1.0),
+    );
+}
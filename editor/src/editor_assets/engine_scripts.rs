@@ -3,12 +3,16 @@
 /// Embedded _engine Lua scripts for new game projects.
 pub static ENGINE_SCRIPTS: &[(&str, &str)] = &[
     ("audio.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/audio.lua"))),
+    ("camera.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/camera.lua"))),
     ("components.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/components.lua"))),
+    ("dialogue.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/dialogue.lua"))),
     ("direction.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/direction.lua"))),
     ("engine.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/engine.lua"))),
     ("entity.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/entity.lua"))),
     ("input.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/input.lua"))),
     ("menu.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/menu.lua"))),
     ("script.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/script.lua"))),
+    ("settings.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/settings.lua"))),
     ("text.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/text.lua"))),
+    ("tween.lua", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/_engine/tween.lua"))),
 ];
@@ -35,6 +35,11 @@ pub struct AnimationModule {
     fps_id: WidgetId,
     offset_x_id: WidgetId,
     offset_y_id: WidgetId,
+    event_frame: usize,
+    /// Name and sound widget ids for each event row of the selected frame.
+    event_ids: Vec<(WidgetId, WidgetId)>,
+    /// Event rows drawn last frame, for the body height.
+    event_rows: usize,
 }
 
 impl InspectorModule for AnimationModule {
@@ -71,6 +76,7 @@ impl InspectorModule for AnimationModule {
         let mut clip_removed = false;
         let mut all_ids: Vec<ClipId> = vec![];
         fill_all_clip_ids(ecs, &mut all_ids);
+        let sound_groups = entity_sound_groups(ecs, entity);
 
         let animation = ecs
             .get_mut::<Animation>(entity)
@@ -228,6 +234,10 @@ impl InspectorModule for AnimationModule {
             draw_offset_fields(ctx, self, y, rect, clip, blocked);
             y += MARGIN + WIDGET_PADDING;
 
+            // Frame event timeline and the selected frame's events
+            self.event_rows = draw_frame_events(ctx, self, y, rect, clip, &sound_groups, blocked);
+            y += (self.event_rows + 1) as f32 * (MARGIN + WIDGET_PADDING);
+
             // Import buttons at the bottom: "Import: [JSON] [Variant]"
             const IMPORT_LABEL: &str = "Import:";
             const JSON_LABEL: &str = "JSON";
@@ -261,8 +271,7 @@ impl InspectorModule for AnimationModule {
                 .blocked(blocked || !has_variant)
                 .show(ctx)
             {
-                let json_path = resolve_json_path(&animation.variant, &current_clip_id);
-                match import_aseprite_metadata(&json_path) {
+                match import_clip_metadata(&animation.variant, &current_clip_id) {
                     JsonImportResult::Success(imported) => {
                         clip.frame_size = imported.frame_size;
                         clip.cols = imported.cols;
@@ -271,9 +280,12 @@ impl InspectorModule for AnimationModule {
                         clip.frame_durations = imported.frame_durations;
                         clip.offset = imported.offset;
                         clip.mirrored = imported.mirrored;
+                        clip.direction = imported.direction;
+                        clip.slices = imported.slices;
                         push_toast("Import successful", 2.0);
                     }
                     JsonImportResult::NotFound => {
+                        let json_path = resolve_json_path(&animation.variant, &current_clip_id);
                         push_toast(format!("JSON not found: {}", json_path.display()), 3.0);
                     }
                     JsonImportResult::Error(msg) => {
@@ -357,7 +369,7 @@ impl InspectorModule for AnimationModule {
         if self.has_clips {
             return InspectorBodyLayout::new()
                 .top_padding(WIDGET_SPACING)
-                .rows(7, SECTION_SPACING)
+                .rows(8 + self.event_rows.max(1), SECTION_SPACING)
                 .gap(SECTION_SPACING)
                 .block(IMPORT_ROW_HEIGHT);
         }
@@ -644,6 +656,165 @@ pub fn draw_offset_fields(
         .show(ctx);
 }
 
+/// Draws a row of frame cells for placing events and, below it, the name and
+/// sound group of each event on the selected frame plus an empty row for adding
+/// one. Returns the number of event rows drawn.
+pub fn draw_frame_events(
+    ctx: &mut WgpuContext,
+    module: &mut AnimationModule,
+    y: f32,
+    rect: Rect,
+    clip: &mut ClipDef,
+    sound_groups: &[SoundGroupId],
+    blocked: bool,
+) -> usize {
+    const EVENTS_LABEL: &str = "Events:";
+    const NAME_LABEL: &str = "Event:";
+    const MAX_CELL_W: f32 = 24.0;
+
+    let frame_count = clip.frame_count();
+    module.event_frame = module.event_frame.min(frame_count - 1);
+
+    // Timeline of frame cells; frames with events are highlighted
+    let label_w = measure_text(ctx, EVENTS_LABEL, LABEL_FONT_SIZE).width + COLON_GAP;
+    ctx.draw_text(
+        EVENTS_LABEL,
+        rect.x + WIDGET_PADDING,
+        y + LABEL_Y_OFFSET,
+        LABEL_FONT_SIZE,
+        FIELD_TEXT_COLOR,
+    );
+    let cells_x = rect.x + WIDGET_PADDING + label_w;
+    let cells_w = rect.x + rect.w - WIDGET_PADDING - cells_x;
+    let cell_w = (cells_w / frame_count as f32).min(MAX_CELL_W);
+    for frame in 0..frame_count {
+        let cell = Rect::new(cells_x + frame as f32 * cell_w, y, cell_w, INPUT_HEIGHT);
+        let label = if cell_w >= MAX_CELL_W {
+            frame.to_string()
+        } else {
+            String::new()
+        };
+        let color = if clip.events_at(frame).next().is_some() {
+            HIGHLIGHT_GREEN
+        } else {
+            FIELD_TEXT_COLOR
+        };
+        if Button::new(cell, &label)
+            .focused(frame == module.event_frame)
+            .text_color(color)
+            .blocked(blocked)
+            .show(ctx)
+        {
+            module.event_frame = frame;
+        }
+    }
+
+    // One row per event on the selected frame, then an empty row for a new event
+    let frame = module.event_frame;
+    let name_label_w = measure_text(ctx, NAME_LABEL, LABEL_FONT_SIZE).width + COLON_GAP;
+    let fields_x = rect.x + WIDGET_PADDING + name_label_w;
+    let field_w = (rect.x + rect.w - WIDGET_PADDING - fields_x - WIDGET_SPACING) / 2.0;
+
+    let indices: Vec<usize> = (0..clip.events.len())
+        .filter(|&i| clip.events[i].frame == frame)
+        .collect();
+    let rows = indices.len() + 1;
+    module
+        .event_ids
+        .resize_with(module.event_ids.len().max(rows), Default::default);
+
+    let mut removed = Vec::new();
+    let mut added = None;
+    for row in 0..rows {
+        let y = y + (row + 1) as f32 * (MARGIN + WIDGET_PADDING);
+        if row == 0 {
+            ctx.draw_text(
+                NAME_LABEL,
+                rect.x + WIDGET_PADDING,
+                y + LABEL_Y_OFFSET,
+                LABEL_FONT_SIZE,
+                FIELD_TEXT_COLOR,
+            );
+        }
+        let (name_id, sound_id) = module.event_ids[row];
+        let name_rect = Rect::new(fields_x, y, field_w, INPUT_HEIGHT);
+        let sound_rect = Rect::new(fields_x + field_w + WIDGET_SPACING, y, field_w, BTN_HEIGHT);
+
+        let index = indices.get(row).copied();
+        let current_name = index
+            .map(|i| clip.events[i].name.clone())
+            .unwrap_or_default();
+        let current_sound = index.and_then(|i| clip.events[i].sound.clone());
+
+        let (name, _) = TextInput::new(name_id, name_rect, &current_name)
+            .blocked(blocked)
+            .show(ctx);
+
+        let sound_label = current_sound
+            .as_ref()
+            .map(|g| g.ui_label())
+            .unwrap_or_else(|| "No Sound".to_string());
+        let chosen = Dropdown::new(
+            sound_id,
+            sound_rect,
+            &sound_label,
+            sound_groups,
+            |g| match g {
+                SoundGroupId::New => "No Sound".to_string(),
+                other => other.ui_label(),
+            },
+        )
+        .blocked(blocked)
+        .show(ctx);
+
+        let sound = match chosen {
+            Some(SoundGroupId::New) => None,
+            Some(group) => Some(group),
+            None => current_sound.clone(),
+        };
+        if name == current_name && sound == current_sound {
+            continue;
+        }
+
+        match index {
+            Some(i) if name.is_empty() && sound.is_none() => removed.push(i),
+            Some(i) => {
+                clip.events[i].name = name;
+                clip.events[i].sound = sound;
+            }
+            None => added = Some(FrameEvent { frame, name, sound }),
+        }
+    }
+
+    // Indices are ascending, so removing from the back keeps the rest valid
+    for i in removed.into_iter().rev() {
+        clip.events.remove(i);
+    }
+    if let Some(event) = added {
+        clip.events.push(event);
+        clip.events.sort_by_key(|e| e.frame);
+    }
+    rows
+}
+
+/// Returns "no sound" followed by the sound groups of the entity's `AudioSource`.
+fn entity_sound_groups(ecs: &Ecs, entity: Entity) -> Vec<SoundGroupId> {
+    let mut groups: Vec<SoundGroupId> = ecs
+        .get::<AudioSource>(entity)
+        .map(|source| {
+            source
+                .groups
+                .keys()
+                .filter(|id| !matches!(id, SoundGroupId::New))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+    groups.sort();
+    groups.insert(0, SoundGroupId::New);
+    groups
+}
+
 /// Returns every ClipId that has a concrete Clip stored in the map.
 fn existing_clip_ids(clips: &HashMap<ClipId, ClipDef>) -> Vec<ClipId> {
     clips.keys().cloned().collect()
@@ -759,6 +930,17 @@ mod tests {
             ..Default::default()
         };
 
-        assert_eq!(module.body_layout().height(), 330.0);
+        assert_eq!(module.body_layout().height(), 410.0);
+    }
+
+    #[test]
+    fn each_event_row_adds_a_row_of_height() {
+        let module = AnimationModule {
+            has_clips: true,
+            event_rows: 3,
+            ..Default::default()
+        };
+
+        assert_eq!(module.body_layout().height(), 490.0);
     }
 }
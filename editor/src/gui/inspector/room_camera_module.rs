@@ -11,7 +11,11 @@ pub struct RoomCameraModule {
     pub zoom_id: WidgetId,
     pub slider_id: WidgetId,
     pub cam_mode_id: WidgetId,
+    pub dead_zone_ids: [WidgetId; 2],
+    pub smooth_time_ids: [WidgetId; 2],
+    pub look_ahead_ids: [WidgetId; 2],
     current_zoom_mode: ZoomMode,
+    current_camera_mode: CameraMode,
 }
 
 const BODY_TOP_PADDING: f32 = WIDGET_SPACING;
@@ -20,6 +24,8 @@ const ROOM_CAMERA_VISUAL_GAP: f32 = WIDGET_SPACING;
 const MODE_ROW_HEIGHT: f32 = 30.0;
 const STEP_SECTION_HEIGHT: f32 = 40.0;
 const FREE_SECTION_HEIGHT: f32 = 35.0;
+/// Dead zone, smoothing, look-ahead, platform snap and room clamp.
+const FOLLOW_ROWS: usize = 5;
 
 impl InspectorModule for RoomCameraModule {
     fn undo_component_type(&self) -> Option<&'static str> {
@@ -39,6 +45,11 @@ impl InspectorModule for RoomCameraModule {
             .block(zoom_visual_height(self.current_zoom_mode))
             .gap(ROOM_CAMERA_VISUAL_GAP)
             .block(MODE_ROW_HEIGHT)
+            .gap(follow_section_gap(self.current_camera_mode))
+            .rows(
+                follow_rows(self.current_camera_mode),
+                ROOM_CAMERA_VISUAL_GAP,
+            )
     }
 
     fn draw(
@@ -60,6 +71,7 @@ impl InspectorModule for RoomCameraModule {
             .expect("Camera must exist");
 
         self.current_zoom_mode = cam.zoom_mode;
+        self.current_camera_mode = cam.camera_mode;
 
         let mut y = rect.y + BODY_TOP_PADDING;
 
@@ -142,6 +154,12 @@ impl InspectorModule for RoomCameraModule {
             CameraMode::Follow(FollowRestriction::ClampX),
         ];
 
+        if let CameraMode::Follow(_) = cam.camera_mode {
+            let follow_y = y + MODE_ROW_HEIGHT + ROOM_CAMERA_VISUAL_GAP;
+            let follow_rect = Rect::new(rect.x, follow_y, rect.w, rect.h);
+            self.draw_follow_settings(ctx, follow_rect, &mut cam.follow, blocked);
+        }
+
         // Render the dropdowns in reverse order
         if let Some(new_cam_mode) = Dropdown::new(
             self.cam_mode_id,
@@ -155,6 +173,7 @@ impl InspectorModule for RoomCameraModule {
         {
             if new_cam_mode != current_cam_mode {
                 cam.camera_mode = new_cam_mode;
+                self.current_camera_mode = new_cam_mode;
             }
         }
 
@@ -221,7 +240,105 @@ fn camera_mode_y(zoom_row_y: f32, mode: ZoomMode) -> f32 {
     zoom_row_y - zoom_top_bleed(mode) + zoom_visual_height(mode) + ROOM_CAMERA_VISUAL_GAP
 }
 
+fn follow_rows(mode: CameraMode) -> usize {
+    match mode {
+        CameraMode::Fixed => 0,
+        CameraMode::Follow(_) => FOLLOW_ROWS,
+    }
+}
+
+fn follow_section_gap(mode: CameraMode) -> f32 {
+    match mode {
+        CameraMode::Fixed => 0.0,
+        CameraMode::Follow(_) => ROOM_CAMERA_VISUAL_GAP,
+    }
+}
+
 impl RoomCameraModule {
+    /// Draw the follow settings, one row each.
+    fn draw_follow_settings(
+        &self,
+        ctx: &mut WgpuContext,
+        rect: Rect,
+        follow: &mut CameraFollow,
+        blocked: bool,
+    ) {
+        let label_width = measure_text(ctx, "Smoothing (s): ", FIELD_TEXT_SIZE_16).width;
+        let field_x = rect.x + label_width + WIDGET_SPACING;
+        let field_w = (rect.w - label_width - 2.0 * WIDGET_SPACING) / 2.0;
+        let row_step = DEFAULT_FIELD_HEIGHT + ROOM_CAMERA_VISUAL_GAP;
+        let mut y = rect.y;
+
+        for (label, ids, value, min) in [
+            (
+                "Dead Zone: ",
+                self.dead_zone_ids,
+                &mut follow.dead_zone,
+                0.0,
+            ),
+            (
+                "Smoothing (s): ",
+                self.smooth_time_ids,
+                &mut follow.smooth_time,
+                0.0,
+            ),
+            (
+                "Look Ahead (s): ",
+                self.look_ahead_ids,
+                &mut follow.look_ahead,
+                f32::MIN,
+            ),
+        ] {
+            ctx.draw_text(
+                label,
+                rect.x,
+                y + 20.0,
+                FIELD_TEXT_SIZE_16,
+                FIELD_TEXT_COLOR,
+            );
+            for (axis, id) in ids.into_iter().enumerate() {
+                let field_rect = Rect::new(
+                    field_x + axis as f32 * (field_w + WIDGET_SPACING),
+                    y,
+                    field_w,
+                    DEFAULT_FIELD_HEIGHT,
+                );
+                let new = NumberInput::new(id, field_rect, value[axis])
+                    .blocked(blocked)
+                    .min(min)
+                    .show(ctx);
+                if (new - value[axis]).abs() > f32::EPSILON {
+                    value[axis] = new;
+                }
+            }
+            y += row_step;
+        }
+
+        for (label, value) in [
+            ("Platform Snap: ", &mut follow.platform_snap),
+            ("Clamp To Room: ", &mut follow.clamp_to_room),
+        ] {
+            ctx.draw_text(
+                label,
+                rect.x,
+                y + 20.0,
+                FIELD_TEXT_SIZE_16,
+                FIELD_TEXT_COLOR,
+            );
+            let cb_rect = Rect::new(
+                field_x,
+                y + (DEFAULT_FIELD_HEIGHT - DEFAULT_CHECKBOX_DIMS) / 2.0,
+                DEFAULT_CHECKBOX_DIMS,
+                DEFAULT_CHECKBOX_DIMS,
+            );
+            let mut checked = *value;
+            if gui_checkbox(ctx, cb_rect, &mut checked) && !blocked {
+                *value = checked;
+            }
+            y += row_step;
+        }
+    }
+
     /// Draw a single numeric field that edits the scalar zoom.
     fn draw_freeform_mode(
         &self,
@@ -352,6 +469,20 @@ mod tests {
         assert_eq!(module.body_layout().height(), 135.0);
     }
 
+    #[test]
+    fn follow_settings_only_extend_follow_cameras() {
+        let fixed = RoomCameraModule::default().body_layout().height();
+        let follow = RoomCameraModule {
+            current_camera_mode: CameraMode::Follow(FollowRestriction::Free),
+            ..Default::default()
+        };
+
+        let expected = ROOM_CAMERA_VISUAL_GAP
+            + FOLLOW_ROWS as f32 * DEFAULT_FIELD_HEIGHT
+            + (FOLLOW_ROWS - 1) as f32 * ROOM_CAMERA_VISUAL_GAP;
+        assert!((follow.body_layout().height() - fixed - expected).abs() < 1e-3);
+    }
+
     #[test]
     fn zoom_section_layout_uses_even_visual_gaps() {
         assert_eq!(
@@ -39,10 +39,12 @@ pub enum EditorAction {
     ViewHierarchyPanel,
     ViewConsolePanel,
     ViewDiagnosticsPanel,
+    ViewLocalizationPanel,
     // Options actions
     WorldSettings,
     // Editors actions
     OpenMenuEditor,
+    OpenDialogueEditor,
     ReturnToGameEditor,
 }
 
@@ -63,8 +65,10 @@ impl EditorAction {
             EditorAction::ViewHierarchyPanel => "Hierarchy".to_string(),
             EditorAction::ViewConsolePanel => "Console".to_string(),
             EditorAction::ViewDiagnosticsPanel => "Diagnostics".to_string(),
+            EditorAction::ViewLocalizationPanel => "Localization".to_string(),
             EditorAction::WorldSettings => "World Settings".to_string(),
             EditorAction::OpenMenuEditor => "Menu Editor".to_string(),
+            EditorAction::OpenDialogueEditor => "Dialogue Editor".to_string(),
             EditorAction::ReturnToGameEditor => "Game Editor".to_string(),
             _ => format!("{self:?}"),
         }
@@ -245,9 +249,11 @@ impl MenuBar {
         // Console and Diagnostics panels available in all modes
         view_actions.push(EditorAction::ViewConsolePanel);
         view_actions.push(EditorAction::ViewDiagnosticsPanel);
+        view_actions.push(EditorAction::ViewLocalizationPanel);
 
         match editor_mode {
             EditorMode::Menu => {}
+            EditorMode::Dialogue => {}
             EditorMode::Game => {}
             EditorMode::World(_) => {}
             EditorMode::Room(_) => {
@@ -275,7 +281,7 @@ impl MenuBar {
             EditorMode::World(_) | EditorMode::Room(_) => {
                 options_actions.push(EditorAction::WorldSettings);
             }
-            EditorMode::Menu | EditorMode::Game => {}
+            EditorMode::Menu | EditorMode::Dialogue | EditorMode::Game => {}
         }
 
         if !options_actions.is_empty() {
@@ -314,8 +320,18 @@ impl MenuBar {
         );
 
         let editors_actions: Vec<EditorAction> = match editor_mode {
-            EditorMode::Menu => vec![EditorAction::ReturnToGameEditor],
-            _ => vec![EditorAction::OpenMenuEditor],
+            EditorMode::Menu => vec![
+                EditorAction::ReturnToGameEditor,
+                EditorAction::OpenDialogueEditor,
+            ],
+            EditorMode::Dialogue => vec![
+                EditorAction::ReturnToGameEditor,
+                EditorAction::OpenMenuEditor,
+            ],
+            _ => vec![
+                EditorAction::OpenMenuEditor,
+                EditorAction::OpenDialogueEditor,
+            ],
         };
 
         if let Some(selected) = menu_dropdown(
@@ -0,0 +1,232 @@
+// editor/src/gui/panels/localization_panel.rs
+use crate::gui::panels::generic_panel::PanelDefinition;
+use crate::Editor;
+use bishop::prelude::*;
+use engine_core::prelude::*;
+
+const ROW_HEIGHT: f32 = 16.0;
+const SECTION_SPACING: f32 = 8.0;
+const TOP_PADDING: f32 = 4.0;
+const LEFT_PADDING: f32 = 8.0;
+const FONT_SIZE: f32 = 13.0;
+const HEADER_FONT_SIZE: f32 = 14.0;
+const BUTTON_HEIGHT: f32 = 20.0;
+const HEADER_ROW_HEIGHT: f32 = 28.0;
+
+/// Shows translation coverage per language and exports or imports string tables.
+pub struct LocalizationPanel {
+    scroll_state: ScrollState,
+    report: Option<LocalizationReport>,
+}
+
+impl LocalizationPanel {
+    pub fn new() -> Self {
+        Self {
+            scroll_state: ScrollState::new(),
+            report: None,
+        }
+    }
+
+    fn coverage_color(report: &LanguageReport) -> Color {
+        if report.is_complete() {
+            Color::GREEN
+        } else if report.coverage() >= 0.5 {
+            Color::YELLOW
+        } else {
+            Color::RED
+        }
+    }
+
+    /// Returns the rows shown for a language, with their colors.
+    fn issue_rows(report: &LanguageReport) -> Vec<(String, Color)> {
+        let mut rows = Vec::new();
+        for entry in &report.missing {
+            rows.push((format!("Missing {entry}"), Color::RED));
+        }
+        for mismatch in &report.variant_mismatches {
+            rows.push((
+                format!(
+                    "{}: {} of {} variants",
+                    mismatch.entry, mismatch.found, mismatch.expected
+                ),
+                Color::YELLOW,
+            ));
+        }
+        for entry in &report.extra {
+            rows.push((format!("Extra {entry}"), Color::GREY));
+        }
+        rows
+    }
+
+    fn export(editor: &mut Editor) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(path) = rfd::FileDialog::new()
+                .add_filter("CSV", &["csv"])
+                .set_file_name("strings.csv")
+                .save_file()
+            else {
+                return;
+            };
+            match export_localization_csv(&text_folder(), &path) {
+                Ok(rows) => {
+                    editor.toast = Some(Toast::new(format!("Exported {rows} strings."), 2.5));
+                }
+                Err(e) => onscreen_error!("Could not export strings: {e}"),
+            }
+        }
+    }
+
+    fn import(&mut self, editor: &mut Editor) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(path) = rfd::FileDialog::new()
+                .add_filter("CSV", &["csv"])
+                .pick_file()
+            else {
+                return;
+            };
+            match import_localization_csv(&text_folder(), &path) {
+                Ok(count) => {
+                    editor.game.text_manager.clear_cache();
+                    editor.toast = Some(Toast::new(format!("Imported {count} strings."), 2.5));
+                    self.report = None;
+                }
+                Err(e) => onscreen_error!("Could not import strings: {e}"),
+            }
+        }
+    }
+}
+
+pub const LOCALIZATION_PANEL: &str = "Localization";
+
+impl PanelDefinition for LocalizationPanel {
+    fn title(&self) -> &'static str {
+        LOCALIZATION_PANEL
+    }
+
+    fn default_rect(&self, ctx: &WgpuContext) -> Rect {
+        Rect::new(ctx.screen_width() - 580., 60., 280., 320.)
+    }
+
+    fn draw(&mut self, ctx: &mut WgpuContext, rect: Rect, editor: &mut Editor, blocked: bool) {
+        // Header buttons
+        let btn_y = rect.y + TOP_PADDING + (HEADER_ROW_HEIGHT - BUTTON_HEIGHT) / 2.0;
+        let btn_w = (rect.w - LEFT_PADDING * 2.0 - 12.0) / 3.0;
+        let btn_rect = |i: f32| {
+            Rect::new(
+                rect.x + LEFT_PADDING + i * (btn_w + 6.0),
+                btn_y,
+                btn_w,
+                BUTTON_HEIGHT,
+            )
+        };
+
+        let refresh = Button::new(btn_rect(0.0), "Refresh")
+            .blocked(blocked)
+            .show(ctx);
+        let export = Button::new(btn_rect(1.0), "Export CSV")
+            .blocked(blocked)
+            .show(ctx);
+        let import = Button::new(btn_rect(2.0), "Import CSV")
+            .blocked(blocked)
+            .show(ctx);
+
+        if !blocked {
+            if refresh {
+                self.report = None;
+            }
+            if export {
+                Self::export(editor);
+            }
+            if import {
+                self.import(editor);
+            }
+        }
+
+        let report = self
+            .report
+            .take()
+            .unwrap_or_else(|| LocalizationReport::build(&text_folder()));
+
+        // Content area below the header row
+        let content_y = rect.y + TOP_PADDING + HEADER_ROW_HEIGHT;
+        let content_h = rect.h - TOP_PADDING - HEADER_ROW_HEIGHT;
+        let content_rect = Rect::new(rect.x, content_y, rect.w, content_h.max(0.0));
+
+        let sections: Vec<_> = report
+            .languages
+            .iter()
+            .map(|language| (language, Self::issue_rows(language)))
+            .collect();
+
+        let mut content_height = TOP_PADDING + ROW_HEIGHT + SECTION_SPACING;
+        for (_, rows) in &sections {
+            content_height += ROW_HEIGHT + 4.0 + rows.len() as f32 * ROW_HEIGHT + SECTION_SPACING;
+        }
+
+        let area = ScrollableArea::new(content_rect, content_height)
+            .blocked(blocked)
+            .begin(ctx, &mut self.scroll_state);
+
+        let mut y = content_rect.y + self.scroll_state.scroll_y + TOP_PADDING;
+        if area.is_fully_visible(y, ROW_HEIGHT) {
+            let label = if sections.is_empty() {
+                format!("Only '{}' is available.", report.default_language)
+            } else {
+                format!("Default language: {}", report.default_language)
+            };
+            ctx.draw_text(
+                &label,
+                rect.x + LEFT_PADDING,
+                y + FONT_SIZE,
+                FONT_SIZE,
+                Color::GREY,
+            );
+        }
+        y += ROW_HEIGHT + SECTION_SPACING;
+
+        for (language, rows) in &sections {
+            if area.is_fully_visible(y, ROW_HEIGHT) {
+                ctx.draw_text(
+                    &language.language,
+                    rect.x + LEFT_PADDING,
+                    y + HEADER_FONT_SIZE,
+                    HEADER_FONT_SIZE,
+                    Color::YELLOW,
+                );
+                let coverage = format!(
+                    "{:.0}% ({}/{})",
+                    language.coverage() * 100.0,
+                    language.translated,
+                    language.total
+                );
+                ctx.draw_text(
+                    &coverage,
+                    rect.x + rect.w * 0.45,
+                    y + HEADER_FONT_SIZE,
+                    HEADER_FONT_SIZE,
+                    Self::coverage_color(language),
+                );
+            }
+            y += ROW_HEIGHT + 4.0;
+
+            for (text, color) in rows {
+                if area.is_fully_visible(y, ROW_HEIGHT) {
+                    ctx.draw_text(
+                        text,
+                        rect.x + LEFT_PADDING + 8.0,
+                        y + FONT_SIZE,
+                        FONT_SIZE,
+                        *color,
+                    );
+                }
+                y += ROW_HEIGHT;
+            }
+            y += SECTION_SPACING;
+        }
+
+        area.draw_scrollbar(ctx, self.scroll_state.scroll_y);
+        self.report = Some(report);
+    }
+}
@@ -2,9 +2,11 @@ pub mod console_panel;
 pub mod diagnostics_panel;
 pub mod generic_panel;
 pub mod hierarchy_panel;
+pub mod localization_panel;
 pub mod panel_manager;
 
 pub use console_panel::*;
 pub use diagnostics_panel::*;
 pub use generic_panel::*;
 pub use hierarchy_panel::*;
+pub use localization_panel::*;
@@ -5,6 +5,7 @@ use crate::gui::panels::console_panel::ConsolePanel;
 use crate::gui::panels::diagnostics_panel::DiagnosticsPanel;
 use crate::gui::panels::generic_panel::*;
 use crate::gui::panels::hierarchy_panel::HierarchyPanel;
+use crate::gui::panels::localization_panel::LocalizationPanel;
 use crate::with_panel_manager;
 use bishop::prelude::*;
 use engine_core::storage::editor_config::{PanelPosition, get_panel_position, set_panel_position};
@@ -166,6 +167,16 @@ impl PanelManager {
                 PanelMode::Menu,
             ],
         );
+
+        self.register(
+            GenericPanel::new(LocalizationPanel::new(), ctx),
+            vec![
+                PanelMode::Game,
+                PanelMode::World,
+                PanelMode::Room,
+                PanelMode::Menu,
+            ],
+        );
     }
 }
 
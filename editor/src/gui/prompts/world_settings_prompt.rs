@@ -8,17 +8,24 @@ use engine_core::prelude::*;
 pub struct WorldSettingsResult {
     pub id: WorldId,
     pub grid_size: Option<f32>,
+    pub room_transition: Option<RoomTransition>,
 }
 
 /// Prompt that draws:
 ///   * Grid size number input,
+///   * Room transition style, duration and colour,
 ///   * Confirm / Cancel buttons.
 pub struct WorldSettingsPrompt {
     world_id: WorldId,
     grid_size_id: WidgetId,
+    style_id: WidgetId,
+    duration_id: WidgetId,
+    color_id: WidgetId,
     rect: Rect,
     og_grid_size: f32,
     current_grid_size: f32,
+    og_transition: RoomTransition,
+    current_transition: RoomTransition,
 }
 
 impl WorldSettingsPrompt {
@@ -28,6 +35,7 @@ impl WorldSettingsPrompt {
         modal_rect: Rect,
         grid_size_id: WidgetId,
         og_grid_size: f32,
+        og_transition: RoomTransition,
     ) -> Self {
         let inner_w = modal_rect.w * 0.8;
         let inner_x = modal_rect.x + (modal_rect.w - inner_w) / 2.0;
@@ -40,9 +48,14 @@ impl WorldSettingsPrompt {
         Self {
             world_id,
             grid_size_id,
+            style_id: WidgetId::default(),
+            duration_id: WidgetId::default(),
+            color_id: WidgetId::default(),
             rect,
             og_grid_size,
             current_grid_size: og_grid_size,
+            og_transition,
+            current_transition: og_transition,
         }
     }
 
@@ -75,6 +88,49 @@ impl WorldSettingsPrompt {
 
         y += grid_size_rect.h + FIELD_GAP;
 
+        // Room transition
+        let label_dims = ctx.draw_text(
+            "Room Transition:",
+            self.rect.x,
+            y,
+            DEFAULT_FONT_SIZE_16,
+            Color::WHITE,
+        );
+        y += label_dims.height + GAP;
+
+        let transition = &mut self.current_transition;
+        let style_rect = Rect::new(self.rect.x, y, self.rect.w, FIELD_H);
+        if let Some(style) = Dropdown::new(
+            self.style_id,
+            style_rect,
+            transition.style.label(),
+            TransitionStyle::all(),
+            |style| style.label().to_string(),
+        )
+        .fixed_width()
+        .show(ctx)
+        {
+            transition.style = style;
+        }
+        y += FIELD_H + GAP;
+
+        let half_w = (self.rect.w - GAP) / 2.0;
+        transition.duration = NumberInput::new(
+            self.duration_id,
+            Rect::new(self.rect.x, y, half_w, FIELD_H),
+            transition.duration,
+        )
+        .min(0.0)
+        .show(ctx);
+        transition.color = ColorInput::new(
+            self.color_id,
+            Rect::new(self.rect.x + half_w + GAP, y, half_w, FIELD_H),
+            transition.color,
+        )
+        .show(ctx);
+
+        y += FIELD_H + FIELD_GAP;
+
         // Buttons
         let (confirm_rect, cancel_rect) = confirm_cancel_rects(self.rect, y);
         let confirm_clicked = Button::new(confirm_rect, "Confirm").show(ctx);
@@ -87,9 +143,12 @@ impl WorldSettingsPrompt {
             } else {
                 None
             };
+            let room_transition =
+                (self.current_transition != self.og_transition).then_some(self.current_transition);
             return Some(WorldSettingsResult {
                 id: self.world_id,
                 grid_size,
+                room_transition,
             });
         }
 
@@ -97,6 +156,7 @@ impl WorldSettingsPrompt {
             return Some(WorldSettingsResult {
                 id: self.world_id,
                 grid_size: None,
+                room_transition: None,
             });
         }
 
@@ -13,6 +13,7 @@ use engine_core::prelude::*;
 mod app;
 mod canvas;
 mod commands;
+mod dialogue;
 mod editor_assets;
 mod editor_global;
 mod game;
@@ -71,6 +72,8 @@ impl BishopApp for EditorApp {
 
     fn on_exit(&mut self) {
         with_editor(|editor| {
+            // Count the last menu edits before unused sprites are dropped
+            editor.menu_editor.sync_sprite_refs(&mut editor.game.asset_manager);
             editor.game.asset_manager.flush_pending_removals();
             editor.game.script_manager.flush_pending_removals();
             editor.save();
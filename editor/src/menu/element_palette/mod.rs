@@ -90,6 +90,57 @@ impl ElementPalette {
             clicked_kind = Some(MenuElementKind::Panel(PanelElement::default()));
         }
 
+        if self
+            .draw_palette_item(ctx, rect, &mut y, "Toggle", blocked)
+            .is_some()
+        {
+            clicked_kind = Some(MenuElementKind::Toggle(ToggleElement {
+                text_key: "toggle".to_string(),
+                key: "toggle_key".to_string(),
+                ..Default::default()
+            }));
+        }
+
+        if self
+            .draw_palette_item(ctx, rect, &mut y, "Choice", blocked)
+            .is_some()
+        {
+            clicked_kind = Some(MenuElementKind::Choice(ChoiceElement {
+                text_key: "choice".to_string(),
+                key: "choice_key".to_string(),
+                options: vec!["option_a".to_string(), "option_b".to_string()],
+                ..Default::default()
+            }));
+        }
+
+        if self
+            .draw_palette_item(ctx, rect, &mut y, "Text Input", blocked)
+            .is_some()
+        {
+            clicked_kind = Some(MenuElementKind::TextInput(TextInputElement {
+                text_key: "text_input".to_string(),
+                key: "text_key".to_string(),
+                ..Default::default()
+            }));
+        }
+
+        if self
+            .draw_palette_item(ctx, rect, &mut y, "Image", blocked)
+            .is_some()
+        {
+            clicked_kind = Some(MenuElementKind::Image(ImageElement::default()));
+        }
+
+        if self
+            .draw_palette_item(ctx, rect, &mut y, "Scroll List", blocked)
+            .is_some()
+        {
+            clicked_kind = Some(MenuElementKind::ScrollList(ScrollListElement {
+                key: "list_key".to_string(),
+                ..Default::default()
+            }));
+        }
+
         clicked_kind
     }
 
@@ -148,7 +199,7 @@ impl ElementPalette {
 
     fn calculate_content_height(&self) -> f32 {
         let header_height = 24.0;
-        let item_count = 5;
+        let item_count = 10;
         let items_height = (PALETTE_ITEM_HEIGHT + PALETTE_SPACING) * item_count as f32;
         header_height + items_height + 16.0
     }
@@ -8,6 +8,8 @@ use engine_core::prelude::*;
 
 pub(crate) struct MenuCanvasFrame<'a> {
     pub(crate) ctx: &'a mut WgpuContext,
+    /// Resolves element font names to loaded fonts.
+    pub(crate) asset_manager: &'a mut AssetManager,
     pub(crate) canvas_origin: Vec2,
     pub(crate) canvas_size: Vec2,
     pub(crate) world_mouse: Vec2,
@@ -16,7 +18,13 @@ pub(crate) struct MenuCanvasFrame<'a> {
 
 impl MenuEditor {
     /// Renders the canvas.
-    pub fn draw_canvas(&self, ctx: &mut WgpuContext, camera: &Camera2D, rect: Rect) {
+    pub fn draw_canvas(
+        &self,
+        ctx: &mut WgpuContext,
+        camera: &Camera2D,
+        rect: Rect,
+        asset_manager: &mut AssetManager,
+    ) {
         ctx.draw_rectangle(
             rect.x,
             rect.y,
@@ -90,6 +98,7 @@ impl MenuEditor {
 
             let mut frame = MenuCanvasFrame {
                 ctx,
+                asset_manager,
                 canvas_origin,
                 canvas_size,
                 world_mouse,
@@ -137,7 +146,13 @@ impl MenuEditor {
     }
 
     /// Renders the menu fullscreen in preview mode without editor overlays.
-    pub fn draw_preview_canvas(&self, ctx: &mut WgpuContext, camera: &Camera2D, rect: Rect) {
+    pub fn draw_preview_canvas(
+        &self,
+        ctx: &mut WgpuContext,
+        camera: &Camera2D,
+        rect: Rect,
+        asset_manager: &mut AssetManager,
+    ) {
         let canvas_origin = Vec2::new(rect.x, rect.y);
         let canvas_size = Vec2::new(rect.w, rect.h);
 
@@ -167,6 +182,7 @@ impl MenuEditor {
             
         let mut frame = MenuCanvasFrame {
             ctx,
+            asset_manager,
             canvas_origin,
             canvas_size,
             world_mouse,
@@ -192,13 +208,39 @@ impl MenuEditor {
         let canvas_size = frame.canvas_size;
         let world_mouse = frame.world_mouse;
         let preview = frame.preview;
+        // Theme edits show straight away since styles are resolved every frame
+        let style = self.theme.resolve(element);
+        let style_state = if element.enabled {
+            StyleState::Normal
+        } else {
+            StyleState::Disabled
+        };
         match &element.kind {
             MenuElementKind::Button(button) => {
                 let display_text = button.text_key.to_string();
-                Button::new(element_rect, &display_text)
-                    .font_size(button.font_size)
-                    .mouse_position(world_mouse)
-                    .show(frame.ctx);
+                match &style {
+                    Some(style) => {
+                        let look = style.look(style_state);
+                        draw_style_look(frame.ctx, element_rect, look, frame.asset_manager, 1.0);
+                        let font = frame.asset_manager.font(frame.ctx, &style.font);
+                        Button::new(element_rect, &display_text)
+                            .plain()
+                            .hover_color(Color::TRANSPARENT)
+                            .text_color(look.text_color)
+                            .font(font)
+                            .font_size(style.font_size)
+                            .mouse_position(world_mouse)
+                            .show(frame.ctx);
+                    }
+                    None => {
+                        let font = frame.asset_manager.font(frame.ctx, &button.font);
+                        Button::new(element_rect, &display_text)
+                            .font(font)
+                            .font_size(button.font_size)
+                            .mouse_position(world_mouse)
+                            .show(frame.ctx);
+                    }
+                }
 
                 if is_selected {
                     frame.ctx.draw_rectangle_lines(
@@ -215,13 +257,7 @@ impl MenuEditor {
                 let has_child_selected = is_selected && self.selected_child_index.is_some();
 
                 if let Some(bg) = &group.background {
-                    frame.ctx.draw_rectangle(
-                        element_rect.x,
-                        element_rect.y,
-                        element_rect.w,
-                        element_rect.h,
-                        bg.render_color(),
-                    );
+                    bg.draw(frame.ctx, element_rect, frame.asset_manager, 1.0);
                 }
 
                 if !preview {
@@ -341,7 +377,17 @@ impl MenuEditor {
                 }
 
                 let text = &label.text_key;
-                let text_dims = frame.ctx.measure_text(text, label.font_size);
+                let (label, element_rect) = match &style {
+                    Some(style) => (
+                        style.label(label, style_state),
+                        style.content_rect(element_rect),
+                    ),
+                    None => (label.clone(), element_rect),
+                };
+                let font = frame.asset_manager.font(frame.ctx, &label.font);
+                let text_dims = frame
+                    .ctx
+                    .measure_text_with_font(text, font, label.font_size);
                 let text_x = match label.alignment {
                     HorizontalAlign::Left => element_rect.x,
                     HorizontalAlign::Center => {
@@ -351,9 +397,14 @@ impl MenuEditor {
                 };
                 let text_y =
                     element_rect.y + (element_rect.h - text_dims.height) / 2.0 + text_dims.offset_y;
-                frame
-                    .ctx
-                    .draw_text(text, text_x, text_y, label.font_size, label.color);
+                frame.ctx.draw_text_with_font(
+                    text,
+                    text_x,
+                    text_y,
+                    font,
+                    label.font_size,
+                    label.color,
+                );
             }
             MenuElementKind::Slider(slider) => {
                 // Draw label area (left 40%) and track area (right 60%)
@@ -410,13 +461,18 @@ impl MenuEditor {
                 }
             }
             MenuElementKind::Panel(panel) => {
-                frame.ctx.draw_rectangle(
-                    element_rect.x,
-                    element_rect.y,
-                    element_rect.w,
-                    element_rect.h,
-                    panel.background.render_color(),
-                );
+                match &style {
+                    Some(style) => draw_style_look(
+                        frame.ctx,
+                        element_rect,
+                        style.look(style_state),
+                        frame.asset_manager,
+                        1.0,
+                    ),
+                    None => panel
+                        .background
+                        .draw(frame.ctx, element_rect, frame.asset_manager, 1.0),
+                }
 
                 if !preview {
                     let outline_color = if is_selected {
@@ -449,6 +505,133 @@ impl MenuEditor {
                     );
                 }
             }
+            MenuElementKind::Toggle(toggle) => {
+                let value = if toggle.default_value { "[x]" } else { "[ ]" };
+                let styled = style.as_ref().map(|style| (style, style_state));
+                draw_control_preview(frame, element_rect, styled, &toggle.text_key, value);
+                if !preview {
+                    draw_element_outline(frame.ctx, element_rect, is_selected);
+                }
+            }
+            MenuElementKind::Choice(choice) => {
+                let option = choice
+                    .options
+                    .get(choice.index_of(None))
+                    .map(String::as_str)
+                    .unwrap_or_default();
+                let value = format!("< {option} >");
+                let styled = style.as_ref().map(|style| (style, style_state));
+                draw_control_preview(frame, element_rect, styled, &choice.text_key, &value);
+                if !preview {
+                    draw_element_outline(frame.ctx, element_rect, is_selected);
+                }
+            }
+            MenuElementKind::TextInput(input) => {
+                draw_control_preview(
+                    frame,
+                    element_rect,
+                    style.as_ref().map(|style| (style, style_state)),
+                    &input.text_key,
+                    &input.placeholder_key,
+                );
+                if !preview {
+                    draw_element_outline(frame.ctx, element_rect, is_selected);
+                }
+            }
+            MenuElementKind::Image(image) => {
+                if image.sprite.0 != 0 {
+                    let time = frame.ctx.get_time();
+                    let texture = frame
+                        .asset_manager
+                        .get_texture_from_id(frame.ctx, image.sprite);
+                    let source =
+                        image.frame_source(vec2(texture.width(), texture.height()), time);
+                    let dest = image.fit(element_rect, source.size());
+                    frame.ctx.draw_texture_ex(
+                        texture,
+                        dest.x,
+                        dest.y,
+                        image.tint,
+                        DrawTextureParams {
+                            dest_size: Some(dest.size()),
+                            source: Some(source),
+                            ..Default::default()
+                        },
+                    );
+                }
+
+                if !preview {
+                    draw_element_outline(frame.ctx, element_rect, is_selected);
+                    if image.sprite.0 == 0 {
+                        frame.ctx.draw_text(
+                            "[Image]",
+                            element_rect.x + 4.0,
+                            element_rect.y + 12.0,
+                            10.0,
+                            Color::new(0.5, 0.5, 0.5, 1.0),
+                        );
+                    }
+                }
+            }
+            MenuElementKind::ScrollList(list) => {
+                let row_label = LabelElement {
+                    font_size: list.font_size,
+                    font: list.font.clone(),
+                    color: Color::new(0.7, 0.7, 0.7, 1.0),
+                    ..Default::default()
+                };
+                let row_label = match &style {
+                    Some(style) => {
+                        let look = style.look(style_state);
+                        draw_style_look(frame.ctx, element_rect, look, frame.asset_manager, 1.0);
+                        style.label(&row_label, style_state)
+                    }
+                    None => {
+                        frame.ctx.draw_rectangle(
+                            element_rect.x,
+                            element_rect.y,
+                            element_rect.w,
+                            element_rect.h,
+                            Color::new(0.15, 0.15, 0.18, 1.0),
+                        );
+                        row_label
+                    }
+                };
+                let font = frame.asset_manager.font(frame.ctx, &row_label.font);
+
+                // Placeholder rows, since items only arrive from Lua at runtime
+                let rows = list.visible_rows.max(1);
+                let row_h = element_rect.h / rows as f32;
+                for row in 0..rows {
+                    let row_y = element_rect.y + row as f32 * row_h;
+                    if row == 0 {
+                        frame.ctx.draw_rectangle(
+                            element_rect.x,
+                            row_y,
+                            element_rect.w,
+                            row_h,
+                            Color::new(0.2, 0.2, 0.2, 0.8),
+                        );
+                    }
+                    let text = format!("{} {}", list.key, row + 1);
+                    let text_dims =
+                        frame
+                            .ctx
+                            .measure_text_with_font(&text, font, row_label.font_size);
+                    frame.ctx.draw_text_with_font(
+                        &text,
+                        element_rect.x + 4.0,
+                        row_y + (row_h - text_dims.height) * 0.5 + text_dims.offset_y,
+                        font,
+                        row_label.font_size,
+                        row_label.color,
+                    );
+                }
+
+                if !preview {
+                    draw_element_outline(frame.ctx, element_rect, is_selected);
+                }
+            }
         }
 
         if is_selected && allow_resize {
@@ -457,6 +640,82 @@ impl MenuEditor {
     }
 }
 
+/// Draws a control with its label key on the left and `value` centred on the right,
+/// in the look of `style` for its state when the element has one.
+fn draw_control_preview(
+    frame: &mut MenuCanvasFrame<'_>,
+    rect: Rect,
+    style: Option<(&MenuStyle, StyleState)>,
+    text_key: &str,
+    value: &str,
+) {
+    let (label, content) = match style {
+        Some((style, state)) => {
+            draw_style_look(frame.ctx, rect, style.look(state), frame.asset_manager, 1.0);
+            let label = style.label(&LabelElement::default(), state);
+            (label, style.content_rect(rect))
+        }
+        None => {
+            let split = rect.w * 0.4;
+            frame.ctx.draw_rectangle(
+                rect.x + split,
+                rect.y,
+                rect.w - split,
+                rect.h,
+                Color::new(0.15, 0.15, 0.18, 1.0),
+            );
+            let label = LabelElement {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..Default::default()
+            };
+            let content = Rect::new(rect.x + 4.0, rect.y, rect.w - 4.0, rect.h);
+            (label, content)
+        }
+    };
+
+    let font = frame.asset_manager.font(frame.ctx, &label.font);
+    let split = rect.x + rect.w * 0.4;
+    let text_dims = frame
+        .ctx
+        .measure_text_with_font(text_key, font, label.font_size);
+    let text_y = content.y + (content.h - text_dims.height) * 0.5 + text_dims.offset_y;
+    frame.ctx.draw_text_with_font(
+        text_key,
+        content.x,
+        text_y,
+        font,
+        label.font_size,
+        label.color,
+    );
+
+    let value_dims = frame
+        .ctx
+        .measure_text_with_font(value, font, label.font_size);
+    let value_right = content.x + content.w;
+    let value_x = split + (value_right - split - value_dims.width) * 0.5;
+    frame
+        .ctx
+        .draw_text_with_font(value, value_x, text_y, font, label.font_size, label.color);
+}
+
+/// Outlines an element on the editing canvas, highlighted while selected.
+fn draw_element_outline(ctx: &mut WgpuContext, rect: Rect, is_selected: bool) {
+    let outline_color = if is_selected {
+        Color::new(0.6, 0.8, 1.0, 1.0)
+    } else {
+        Color::new(0.5, 0.5, 0.5, 1.0)
+    };
+    ctx.draw_rectangle_lines(
+        rect.x,
+        rect.y,
+        rect.w,
+        rect.h,
+        if is_selected { 2.0 } else { 1.0 },
+        outline_color,
+    );
+}
+
 /// Draws a drop indicator line at the target managed slot position.
 pub(crate) fn draw_reorder_indicator(
     ctx: &mut WgpuContext,
@@ -121,6 +121,11 @@ impl MenuEditor {
                         MenuElementKind::Panel(_) => Vec2::new(0.16, 0.185),
                         MenuElementKind::LayoutGroup(_) => Vec2::new(0.25, 0.30),
                         MenuElementKind::Slider(_) => Vec2::new(0.20, 0.037),
+                        MenuElementKind::Toggle(_) => Vec2::new(0.20, 0.037),
+                        MenuElementKind::Choice(_) => Vec2::new(0.20, 0.037),
+                        MenuElementKind::TextInput(_) => Vec2::new(0.20, 0.037),
+                        MenuElementKind::Image(_) => Vec2::new(0.08, 0.14),
+                        MenuElementKind::ScrollList(_) => Vec2::new(0.20, 0.25),
                     };
 
                     // Check if a layout group is selected to add as child
@@ -6,7 +6,7 @@ use crate::menu::resize_handle::ResizeHandleState;
 use crate::menu::*;
 use bishop::prelude::*;
 use engine_core::prelude::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// Tracks an in-progress drag-to-reorder operation for managed layout children.
 pub(crate) struct ReorderDragState {
@@ -27,6 +27,8 @@ pub struct MenuEditor {
     pub(crate) element_palette: ElementPalette,
     pub(crate) properties_panel: MenuPropertiesPanel,
     pub templates: Vec<MenuTemplate>,
+    /// Named styles shared by every template.
+    pub theme: MenuTheme,
     pub current_template_index: Option<usize>,
     pub selected_element_indices: HashSet<usize>,
     pub selected_child_index: Option<usize>,
@@ -44,6 +46,8 @@ pub struct MenuEditor {
     pub(crate) last_norm_mouse: Option<Vec2>,
     pub(crate) view_preview: bool,
     pub(crate) drag_original_element: Option<MenuElement>,
+    /// Refs held on sprites used by the templates and theme, per sprite.
+    sprite_refs: HashMap<SpriteId, usize>,
 }
 
 impl MenuEditor {
@@ -54,6 +58,7 @@ impl MenuEditor {
             element_palette: ElementPalette::new(),
             properties_panel: MenuPropertiesPanel::new(),
             templates: Vec::new(),
+            theme: MenuTheme::default(),
             current_template_index: None,
             selected_element_indices: HashSet::new(),
             selected_child_index: None,
@@ -71,6 +76,7 @@ impl MenuEditor {
             last_norm_mouse: None,
             view_preview: false,
             drag_original_element: None,
+            sprite_refs: HashMap::new(),
         }
     }
 
@@ -110,7 +116,12 @@ impl MenuEditor {
         }
     }
 
-    pub fn draw(&mut self, ctx: &mut WgpuContext, camera: &Camera2D) {
+    pub fn draw(
+        &mut self,
+        ctx: &mut WgpuContext,
+        camera: &Camera2D,
+        asset_manager: &mut AssetManager,
+    ) {
         self.active_rects.clear();
 
         ctx.set_camera(camera);
@@ -118,17 +129,17 @@ impl MenuEditor {
 
         if self.view_preview {
             let preview_rect = compute_preview_rect(ctx.screen_width(), ctx.screen_height());
-            self.draw_preview_canvas(ctx, camera, preview_rect);
+            self.draw_preview_canvas(ctx, camera, preview_rect, asset_manager);
             return;
         }
 
         let canvas_rect = compute_canvas_rect(ctx.screen_width(), ctx.screen_height());
 
         // Draw canvas under ui
-        self.draw_canvas(ctx, camera, canvas_rect);
+        self.draw_canvas(ctx, camera, canvas_rect, asset_manager);
 
         // Draw ui after canvas
-        self.draw_ui(ctx);
+        self.draw_ui(ctx, asset_manager);
     }
 
     /// Returns a reference to the current template.
@@ -302,6 +313,47 @@ impl MenuEditor {
             .unwrap_or(false)
     }
 
+    /// Counts every use of a sprite in the templates and theme.
+    fn used_sprites(&self) -> HashMap<SpriteId, usize> {
+        let mut used = HashMap::new();
+        let template_sprites = self.templates.iter().flat_map(MenuTemplate::sprites);
+        for sprite in self.theme.sprites().into_iter().chain(template_sprites) {
+            *used.entry(sprite).or_insert(0) += 1;
+        }
+        used
+    }
+
+    /// Takes over the refs of templates and a theme just loaded from disk.
+    /// Saved ref counts already include them, so only missing refs are counted.
+    pub fn adopt_sprite_refs(&mut self, asset_manager: &mut AssetManager) {
+        let used = self.used_sprites();
+        for (&sprite, &uses) in &used {
+            for _ in asset_manager.get_ref_count(sprite)..uses {
+                asset_manager.increment_ref(sprite);
+            }
+        }
+        self.sprite_refs = used;
+    }
+
+    /// Counts sprites that entered the templates or theme since the last sync
+    /// and releases those that left, whether through an edit, a deletion or an undo.
+    pub fn sync_sprite_refs(&mut self, asset_manager: &mut AssetManager) {
+        let used = self.used_sprites();
+        for (&sprite, &uses) in &used {
+            let held = self.sprite_refs.get(&sprite).copied().unwrap_or(0);
+            for _ in held..uses {
+                asset_manager.increment_ref(sprite);
+            }
+        }
+        for (&sprite, &held) in &self.sprite_refs {
+            let uses = used.get(&sprite).copied().unwrap_or(0);
+            for _ in uses..held {
+                asset_manager.decrement_ref(sprite);
+            }
+        }
+        self.sprite_refs = used;
+    }
+
     #[inline]
     pub fn register_rect(&mut self, rect: Rect) -> Rect {
         self.active_rects.push(rect);
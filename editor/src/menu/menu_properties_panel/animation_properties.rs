@@ -0,0 +1,189 @@
+// editor/src/menu/menu_properties_panel/animation_properties.rs
+use super::control_properties::{checkbox_row, number_row, RowStyle};
+use super::{common_properties::row_visible, FIELD_HEIGHT, LABEL_WIDTH, ROW_HEIGHT};
+use crate::commands::menu::{TemplateProperty, UpdateTemplateCmd};
+use crate::editor_global::push_command;
+use crate::menu::MenuEditor;
+use bishop::prelude::*;
+use engine_core::prelude::*;
+
+/// Draws a transition style dropdown. Returns the new style when one was picked.
+fn transition_style_row(
+    ctx: &mut WgpuContext,
+    y: &mut f32,
+    style: RowStyle<'_>,
+    label: &str,
+    id: WidgetId,
+    current: MenuTransitionStyle,
+) -> Option<MenuTransitionStyle> {
+    let mut changed = None;
+    if row_visible(*y, ROW_HEIGHT, style.clip) {
+        ctx.draw_text(label, style.x, *y + 16.0, 12.0, Color::WHITE);
+        let dropdown_rect = Rect::new(
+            style.x + LABEL_WIDTH,
+            *y,
+            style.w - LABEL_WIDTH,
+            FIELD_HEIGHT,
+        );
+        if let Some(selected) = Dropdown::new(
+            id,
+            dropdown_rect,
+            current.label(),
+            MenuTransitionStyle::all(),
+            |s| s.label().to_string(),
+        )
+        .blocked(style.blocked)
+        .fixed_width()
+        .show(ctx)
+        {
+            if selected != current {
+                changed = Some(selected);
+            }
+        }
+    }
+    *y += ROW_HEIGHT;
+    changed
+}
+
+/// Draws the style and duration rows of a transition. Returns the edited transition.
+fn transition_rows(
+    ctx: &mut WgpuContext,
+    y: &mut f32,
+    style: RowStyle<'_>,
+    labels: (&str, &str),
+    ids: (WidgetId, WidgetId),
+    current: MenuTransition,
+) -> Option<MenuTransition> {
+    let mut transition = current;
+    if let Some(new_style) = transition_style_row(ctx, y, style, labels.0, ids.0, current.style) {
+        transition.style = new_style;
+    }
+    if current.style != MenuTransitionStyle::None {
+        if let Some(duration) = number_row(ctx, y, style, labels.1, ids.1, current.duration, 0.0) {
+            transition.duration = duration;
+        }
+    }
+    (transition != current).then_some(transition)
+}
+
+impl MenuEditor {
+    /// Draws the menu's open and close transitions and focus feedback.
+    pub(super) fn draw_menu_animation_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        style: RowStyle<'_>,
+        asset_manager: &mut AssetManager,
+    ) {
+        let Some(current) = self.current_template().map(|t| t.animation) else {
+            return;
+        };
+        let ids = &self.properties_panel.widget_ids;
+        let (open_ids, close_ids) = (
+            (ids.open_style_id, ids.open_duration_id),
+            (ids.close_style_id, ids.close_duration_id),
+        );
+        let (stagger_id, pulse_id) = (ids.stagger_id, ids.focus_pulse_id);
+
+        *y += 4.0;
+        if row_visible(*y, 20.0, style.clip) {
+            ctx.draw_text("Animation", style.x, *y + 14.0, 12.0, Color::GREY);
+        }
+        *y += 20.0;
+
+        let mut animation = current;
+        if let Some(open) = transition_rows(
+            ctx,
+            y,
+            style,
+            ("Open:", "Open Time:"),
+            open_ids,
+            current.open,
+        ) {
+            animation.open = open;
+        }
+        if let Some(close) = transition_rows(
+            ctx,
+            y,
+            style,
+            ("Close:", "Close Time:"),
+            close_ids,
+            current.close,
+        ) {
+            animation.close = close;
+        }
+        if let Some(stagger) =
+            number_row(ctx, y, style, "Stagger:", stagger_id, current.stagger, 0.0)
+        {
+            animation.stagger = stagger;
+        }
+        if let Some(pulse) = number_row(
+            ctx,
+            y,
+            style,
+            "Focus Pulse:",
+            pulse_id,
+            current.focus_pulse,
+            0.0,
+        ) {
+            animation.focus_pulse = pulse;
+        }
+
+        if row_visible(*y, ROW_HEIGHT, style.clip) {
+            ctx.draw_text("Cursor:", style.x, *y + 16.0, 12.0, Color::WHITE);
+            let picker_rect = Rect::new(
+                style.x + LABEL_WIDTH,
+                *y,
+                style.w - LABEL_WIDTH,
+                FIELD_HEIGHT,
+            );
+            if let Some(sprite) = gui_sprite_field(
+                ctx,
+                picker_rect,
+                current.cursor_sprite,
+                asset_manager,
+                style.blocked,
+            ) {
+                animation.cursor_sprite = sprite;
+            }
+        }
+        *y += ROW_HEIGHT;
+
+        if let Some(press) = checkbox_row(ctx, y, style, "Press Squash:", current.press_feedback) {
+            animation.press_feedback = press;
+        }
+
+        if animation != current {
+            if let Some(idx) = self.current_template_index {
+                push_command(Box::new(UpdateTemplateCmd::new(
+                    idx,
+                    TemplateProperty::Animation {
+                        old: current,
+                        new: animation,
+                    },
+                )));
+            }
+        }
+    }
+
+    /// Draws the enter animation of the selected top-level element.
+    pub(super) fn draw_element_enter_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        style: RowStyle<'_>,
+    ) {
+        let Some(current) = self.selected_element().map(|el| el.enter) else {
+            return;
+        };
+        let ids = (
+            self.properties_panel.widget_ids.enter_style_id,
+            self.properties_panel.widget_ids.enter_duration_id,
+        );
+        if let Some(enter) = transition_rows(ctx, y, style, ("Enter:", "Enter Time:"), ids, current)
+        {
+            self.push_element_update(|el| el.enter = enter);
+        }
+        *y += 8.0;
+    }
+}
@@ -14,7 +14,7 @@ impl MenuEditor {
         blocked: bool,
         clip: &Rect,
     ) {
-        let (current_name, rect_val, z_order, type_label) = {
+        let (current_name, rect_val, z_order, type_label, bindings, anchor) = {
             let Some(element) = self.selected_element() else {
                 return;
             };
@@ -24,12 +24,19 @@ impl MenuEditor {
                 MenuElementKind::Panel(_) => "Panel",
                 MenuElementKind::LayoutGroup(_) => "Layout Group",
                 MenuElementKind::Slider(_) => "Slider",
+                MenuElementKind::Toggle(_) => "Toggle",
+                MenuElementKind::Choice(_) => "Choice",
+                MenuElementKind::TextInput(_) => "Text Input",
+                MenuElementKind::Image(_) => "Image",
+                MenuElementKind::ScrollList(_) => "Scroll List",
             };
             (
                 element.name.clone(),
                 element.rect,
                 element.z_order,
                 type_label,
+                element.bindings.clone(),
+                element.anchor,
             )
         };
         let child_is_managed = self.is_selected_child_managed();
@@ -82,6 +89,35 @@ impl MenuEditor {
                 }
             }
             *y += ROW_HEIGHT;
+
+            // Anchor keeps HUD elements pinned to the safe area's edges
+            if row_visible(*y, ROW_HEIGHT, clip) {
+                ctx.draw_text("Anchor:", x, *y + 16.0, 12.0, Color::WHITE);
+                let anchor_options: Vec<&str> =
+                    ElementAnchor::ALL.iter().map(|a| a.label()).collect();
+                let dropdown_rect = Rect::new(x + LABEL_WIDTH, *y, _w - LABEL_WIDTH, FIELD_HEIGHT);
+                if let Some(selected) = Dropdown::new(
+                    self.properties_panel.widget_ids.anchor_id,
+                    dropdown_rect,
+                    anchor.label(),
+                    &anchor_options,
+                    |s| s.to_string(),
+                )
+                .blocked(blocked)
+                .fixed_width()
+                .show(ctx)
+                {
+                    if let Some(new_anchor) = ElementAnchor::ALL
+                        .into_iter()
+                        .find(|a| a.label() == selected)
+                    {
+                        if new_anchor != anchor {
+                            self.push_element_update(|el| el.anchor = new_anchor);
+                        }
+                    }
+                }
+            }
+            *y += ROW_HEIGHT;
         }
 
         if !child_is_managed {
@@ -171,6 +207,44 @@ impl MenuEditor {
             }
             *y += 20.0;
         }
+
+        // Bindings: conditions on values set from Lua with engine.menu.set_value
+        if row_visible(*y, 20.0, clip) {
+            ctx.draw_text("Bindings (key or !key)", x, *y + 14.0, 12.0, Color::GREY);
+        }
+        *y += 20.0;
+
+        if row_visible(*y, ROW_HEIGHT, clip) {
+            ctx.draw_text("Visible If:", x, *y + 16.0, 12.0, Color::WHITE);
+            let field_rect = Rect::new(x + LABEL_WIDTH, *y, _w - LABEL_WIDTH, FIELD_HEIGHT);
+            let (new_cond, _) = TextInput::new(
+                self.properties_panel.widget_ids.visible_if_id,
+                field_rect,
+                &bindings.visible_if,
+            )
+            .blocked(blocked)
+            .show(ctx);
+            if new_cond != bindings.visible_if {
+                self.push_element_update(|el| el.bindings.visible_if = new_cond);
+            }
+        }
+        *y += ROW_HEIGHT;
+
+        if row_visible(*y, ROW_HEIGHT, clip) {
+            ctx.draw_text("Enabled If:", x, *y + 16.0, 12.0, Color::WHITE);
+            let field_rect = Rect::new(x + LABEL_WIDTH, *y, _w - LABEL_WIDTH, FIELD_HEIGHT);
+            let (new_cond, _) = TextInput::new(
+                self.properties_panel.widget_ids.enabled_if_id,
+                field_rect,
+                &bindings.enabled_if,
+            )
+            .blocked(blocked)
+            .show(ctx);
+            if new_cond != bindings.enabled_if {
+                self.push_element_update(|el| el.bindings.enabled_if = new_cond);
+            }
+        }
+        *y += ROW_HEIGHT + 8.0;
     }
 }
 
@@ -0,0 +1,484 @@
+// editor/src/menu/menu_properties_panel/control_properties.rs
+use super::{
+    common_properties::row_visible, nav_section::NavSectionStyle, FIELD_HEIGHT, LABEL_WIDTH,
+    ROW_HEIGHT,
+};
+use crate::menu::MenuEditor;
+use bishop::prelude::*;
+use engine_core::prelude::*;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Position and state shared by every row of a properties section.
+#[derive(Clone, Copy)]
+pub(crate) struct RowStyle<'a> {
+    pub(crate) x: f32,
+    pub(crate) w: f32,
+    pub(crate) blocked: bool,
+    pub(crate) clip: &'a Rect,
+}
+
+/// Draws a labelled text field. Returns the new text when it changed.
+pub(super) fn text_row(
+    ctx: &mut WgpuContext,
+    y: &mut f32,
+    style: RowStyle<'_>,
+    label: &str,
+    id: WidgetId,
+    current: &str,
+) -> Option<String> {
+    let mut changed = None;
+    if row_visible(*y, ROW_HEIGHT, style.clip) {
+        ctx.draw_text(label, style.x, *y + 16.0, 12.0, Color::WHITE);
+        let field_rect = Rect::new(
+            style.x + LABEL_WIDTH,
+            *y,
+            style.w - LABEL_WIDTH,
+            FIELD_HEIGHT,
+        );
+        let (new_val, _) = TextInput::new(id, field_rect, current)
+            .blocked(style.blocked)
+            .show(ctx);
+        if new_val != current {
+            changed = Some(new_val);
+        }
+    }
+    *y += ROW_HEIGHT;
+    changed
+}
+
+/// Draws a labelled number field clamped to `min`. Returns the new value when it changed.
+pub(crate) fn number_row<T>(
+    ctx: &mut WgpuContext,
+    y: &mut f32,
+    style: RowStyle<'_>,
+    label: &str,
+    id: WidgetId,
+    current: T,
+    min: T,
+) -> Option<T>
+where
+    T: FromStr + Display + Default + Copy + PartialEq + PartialOrd,
+    <T as FromStr>::Err: std::fmt::Debug,
+{
+    let mut changed = None;
+    if row_visible(*y, ROW_HEIGHT, style.clip) {
+        ctx.draw_text(label, style.x, *y + 16.0, 12.0, Color::WHITE);
+        let field_rect = Rect::new(style.x + LABEL_WIDTH, *y, 80.0, FIELD_HEIGHT);
+        let new_val = NumberInput::new(id, field_rect, current)
+            .blocked(style.blocked)
+            .min(min)
+            .show(ctx);
+        if new_val != current {
+            changed = Some(new_val);
+        }
+    }
+    *y += ROW_HEIGHT;
+    changed
+}
+
+/// Draws a labelled colour field. Returns the new colour when it changed.
+pub(crate) fn color_row(
+    ctx: &mut WgpuContext,
+    y: &mut f32,
+    style: RowStyle<'_>,
+    label: &str,
+    id: WidgetId,
+    current: Color,
+) -> Option<Color> {
+    let mut changed = None;
+    if row_visible(*y, ROW_HEIGHT, style.clip) {
+        ctx.draw_text(label, style.x, *y + 16.0, 12.0, Color::WHITE);
+        let field_rect = Rect::new(
+            style.x + LABEL_WIDTH,
+            *y,
+            style.w - LABEL_WIDTH,
+            FIELD_HEIGHT,
+        );
+        let new_color = ColorInput::new(id, field_rect, current)
+            .blocked(style.blocked)
+            .show(ctx);
+        if new_color != current {
+            changed = Some(new_color);
+        }
+    }
+    *y += ROW_HEIGHT;
+    changed
+}
+
+/// Draws a labelled checkbox. Returns the new value when it was clicked.
+pub(crate) fn checkbox_row(
+    ctx: &mut WgpuContext,
+    y: &mut f32,
+    style: RowStyle<'_>,
+    label: &str,
+    current: bool,
+) -> Option<bool> {
+    let mut changed = None;
+    if row_visible(*y, ROW_HEIGHT, style.clip) {
+        ctx.draw_text(label, style.x, *y + 16.0, 12.0, Color::WHITE);
+        let checkbox_rect = Rect::new(style.x + LABEL_WIDTH, *y + 4.0, 16.0, 16.0);
+        let mut value = current;
+        if !style.blocked && gui_checkbox(ctx, checkbox_rect, &mut value) {
+            changed = Some(value);
+        }
+    }
+    *y += ROW_HEIGHT;
+    changed
+}
+
+impl MenuEditor {
+    /// Applies `edit` to the selected element when it is a `T`.
+    fn push_control_update<T>(&mut self, edit: impl FnOnce(&mut T))
+    where
+        T: Clone + Navigable,
+    {
+        self.push_element_update(|el| {
+            if let Some(mut control) = T::from_element(el).cloned() {
+                edit(&mut control);
+                el.kind = control.wrap_into_element();
+            }
+        });
+    }
+
+    /// Draws the navigation section of a top-level control.
+    fn draw_control_nav<T>(&mut self, ctx: &mut WgpuContext, y: &mut f32, style: RowStyle<'_>)
+    where
+        T: Clone + Navigable,
+    {
+        // Children of layout groups navigate through their group
+        if self.selected_child_index.is_some() {
+            return;
+        }
+        *y += 8.0;
+        if row_visible(*y, 20.0, style.clip) {
+            ctx.draw_text("Navigation", style.x, *y + 14.0, 12.0, Color::GREY);
+        }
+        *y += 20.0;
+
+        let nav_ids = self.properties_panel.widget_ids.control_nav_ids;
+        self.draw_nav_section::<T>(
+            ctx,
+            y,
+            NavSectionStyle {
+                x: style.x,
+                w: style.w,
+                blocked: style.blocked,
+                clip: style.clip,
+            },
+            &nav_ids,
+        );
+    }
+
+    pub(super) fn draw_toggle_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        style: RowStyle<'_>,
+    ) {
+        let Some(toggle) = self
+            .selected_element()
+            .and_then(ToggleElement::from_element)
+            .cloned()
+        else {
+            return;
+        };
+        let ids = &self.properties_panel.widget_ids;
+        let (text_id, key_id) = (ids.toggle_text_id, ids.toggle_key_id);
+
+        if let Some(text_key) = text_row(ctx, y, style, "Label:", text_id, &toggle.text_key) {
+            self.push_control_update(|t: &mut ToggleElement| t.text_key = text_key);
+        }
+        if let Some(key) = text_row(ctx, y, style, "Key:", key_id, &toggle.key) {
+            self.push_control_update(|t: &mut ToggleElement| t.key = key);
+        }
+        if let Some(value) = checkbox_row(ctx, y, style, "Default:", toggle.default_value) {
+            self.push_control_update(|t: &mut ToggleElement| t.default_value = value);
+        }
+
+        self.draw_control_nav::<ToggleElement>(ctx, y, style);
+    }
+
+    pub(super) fn draw_choice_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        style: RowStyle<'_>,
+    ) {
+        let Some(choice) = self
+            .selected_element()
+            .and_then(ChoiceElement::from_element)
+            .cloned()
+        else {
+            return;
+        };
+        let ids = &self.properties_panel.widget_ids;
+        let (text_id, key_id, options_id, default_id) = (
+            ids.choice_text_id,
+            ids.choice_key_id,
+            ids.choice_options_id,
+            ids.choice_default_id,
+        );
+
+        if let Some(text_key) = text_row(ctx, y, style, "Label:", text_id, &choice.text_key) {
+            self.push_control_update(|c: &mut ChoiceElement| c.text_key = text_key);
+        }
+        if let Some(key) = text_row(ctx, y, style, "Key:", key_id, &choice.key) {
+            self.push_control_update(|c: &mut ChoiceElement| c.key = key);
+        }
+
+        // Options are edited as one comma separated list
+        let options = choice.options.join(", ");
+        if let Some(options) = text_row(ctx, y, style, "Options:", options_id, &options) {
+            let options: Vec<String> = options
+                .split(',')
+                .map(str::trim)
+                .filter(|option| !option.is_empty())
+                .map(str::to_string)
+                .collect();
+            self.push_control_update(|c: &mut ChoiceElement| {
+                c.default_index = c.default_index.min(options.len().saturating_sub(1));
+                c.options = options;
+            });
+        }
+
+        let last = choice.options.len().saturating_sub(1);
+        if let Some(index) = number_row(
+            ctx,
+            y,
+            style,
+            "Default:",
+            default_id,
+            choice.default_index,
+            0,
+        ) {
+            self.push_control_update(|c: &mut ChoiceElement| c.default_index = index.min(last));
+        }
+
+        self.draw_control_nav::<ChoiceElement>(ctx, y, style);
+    }
+
+    pub(super) fn draw_text_input_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        style: RowStyle<'_>,
+    ) {
+        let Some(input) = self
+            .selected_element()
+            .and_then(TextInputElement::from_element)
+            .cloned()
+        else {
+            return;
+        };
+        let ids = &self.properties_panel.widget_ids;
+        let (text_id, key_id, placeholder_id, max_id) = (
+            ids.text_input_text_id,
+            ids.text_input_key_id,
+            ids.text_input_placeholder_id,
+            ids.text_input_max_id,
+        );
+
+        if let Some(text_key) = text_row(ctx, y, style, "Label:", text_id, &input.text_key) {
+            self.push_control_update(|t: &mut TextInputElement| t.text_key = text_key);
+        }
+        if let Some(key) = text_row(ctx, y, style, "Key:", key_id, &input.key) {
+            self.push_control_update(|t: &mut TextInputElement| t.key = key);
+        }
+        if let Some(placeholder) = text_row(
+            ctx,
+            y,
+            style,
+            "Placeholder:",
+            placeholder_id,
+            &input.placeholder_key,
+        ) {
+            self.push_control_update(|t: &mut TextInputElement| t.placeholder_key = placeholder);
+        }
+        if let Some(max_length) =
+            number_row(ctx, y, style, "Max Length:", max_id, input.max_length, 1)
+        {
+            self.push_control_update(|t: &mut TextInputElement| t.max_length = max_length);
+        }
+
+        self.draw_control_nav::<TextInputElement>(ctx, y, style);
+    }
+
+    pub(super) fn draw_image_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        style: RowStyle<'_>,
+        asset_manager: &mut AssetManager,
+    ) {
+        let Some(MenuElementKind::Image(image)) = self.selected_element().map(|el| el.kind.clone())
+        else {
+            return;
+        };
+        let ids = &self.properties_panel.widget_ids;
+        let (tint_id, frame_w_id, frame_h_id) = (
+            ids.image_tint_id,
+            ids.image_frame_w_id,
+            ids.image_frame_h_id,
+        );
+        let (columns_id, rows_id, fps_id) =
+            (ids.image_columns_id, ids.image_rows_id, ids.image_fps_id);
+        let update = |editor: &mut Self, edit: &dyn Fn(&mut ImageElement)| {
+            editor.push_element_update(|el| {
+                if let MenuElementKind::Image(image) = &mut el.kind {
+                    edit(image);
+                }
+            });
+        };
+
+        // Sprite
+        if row_visible(*y, ROW_HEIGHT, style.clip) {
+            ctx.draw_text("Sprite:", style.x, *y + 16.0, 12.0, Color::WHITE);
+            let picker_rect = Rect::new(
+                style.x + LABEL_WIDTH,
+                *y,
+                style.w - LABEL_WIDTH,
+                FIELD_HEIGHT,
+            );
+            if let Some(sprite) =
+                gui_sprite_field(ctx, picker_rect, image.sprite, asset_manager, style.blocked)
+            {
+                update(self, &|image| image.sprite = sprite);
+            }
+        }
+        *y += ROW_HEIGHT;
+
+        // Tint
+        if row_visible(*y, ROW_HEIGHT, style.clip) {
+            ctx.draw_text("Tint:", style.x, *y + 16.0, 12.0, Color::WHITE);
+            let field_rect = Rect::new(
+                style.x + LABEL_WIDTH,
+                *y,
+                style.w - LABEL_WIDTH,
+                FIELD_HEIGHT,
+            );
+            let tint = ColorInput::new(tint_id, field_rect, image.tint)
+                .blocked(style.blocked)
+                .show(ctx);
+            if tint != image.tint {
+                update(self, &|image| image.tint = tint);
+            }
+        }
+        *y += ROW_HEIGHT;
+
+        if let Some(preserve) = checkbox_row(ctx, y, style, "Keep Aspect:", image.preserve_aspect) {
+            update(self, &|image| image.preserve_aspect = preserve);
+        }
+
+        *y += 8.0;
+        if row_visible(*y, 20.0, style.clip) {
+            ctx.draw_text("Animation", style.x, *y + 14.0, 12.0, Color::GREY);
+        }
+        *y += 20.0;
+
+        if let Some(animated) = checkbox_row(ctx, y, style, "Animated:", image.clip.is_some()) {
+            // A new clip starts as a single frame covering the whole sprite
+            let whole_sprite = (image.sprite.0 != 0).then(|| {
+                let texture = asset_manager.get_texture_from_id(ctx, image.sprite);
+                vec2(texture.width(), texture.height())
+            });
+            update(self, &|image| {
+                image.clip = animated.then(|| ClipDef {
+                    frame_size: whole_sprite.unwrap_or(ClipDef::default().frame_size),
+                    cols: 1,
+                    ..Default::default()
+                });
+            });
+        }
+        let Some(clip) = image.clip else {
+            return;
+        };
+        let update_clip = |editor: &mut Self, edit: &dyn Fn(&mut ClipDef)| {
+            update(editor, &|image| {
+                if let Some(clip) = &mut image.clip {
+                    edit(clip);
+                }
+            });
+        };
+
+        if let Some(w) = number_row(
+            ctx,
+            y,
+            style,
+            "Frame W:",
+            frame_w_id,
+            clip.frame_size.x,
+            1.0,
+        ) {
+            update_clip(self, &|clip| clip.frame_size.x = w);
+        }
+        if let Some(h) = number_row(
+            ctx,
+            y,
+            style,
+            "Frame H:",
+            frame_h_id,
+            clip.frame_size.y,
+            1.0,
+        ) {
+            update_clip(self, &|clip| clip.frame_size.y = h);
+        }
+        if let Some(columns) = number_row(ctx, y, style, "Columns:", columns_id, clip.cols, 1) {
+            update_clip(self, &|clip| clip.cols = columns);
+        }
+        if let Some(rows) = number_row(ctx, y, style, "Rows:", rows_id, clip.rows, 1) {
+            update_clip(self, &|clip| clip.rows = rows);
+        }
+        if let Some(fps) = number_row(ctx, y, style, "FPS:", fps_id, clip.fps, 0.0) {
+            update_clip(self, &|clip| clip.fps = fps);
+        }
+        if let Some(looping) = checkbox_row(ctx, y, style, "Loop:", clip.looping) {
+            update_clip(self, &|clip| clip.looping = looping);
+        }
+    }
+
+    pub(super) fn draw_scroll_list_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        style: RowStyle<'_>,
+    ) {
+        let Some(list) = self
+            .selected_element()
+            .and_then(ScrollListElement::from_element)
+            .cloned()
+        else {
+            return;
+        };
+        let ids = &self.properties_panel.widget_ids;
+        let (key_id, rows_id, font_size_id, font_id) = (
+            ids.list_key_id,
+            ids.list_rows_id,
+            ids.list_font_size_id,
+            ids.list_font_id,
+        );
+
+        if let Some(key) = text_row(ctx, y, style, "Key:", key_id, &list.key) {
+            self.push_control_update(|l: &mut ScrollListElement| l.key = key);
+        }
+        if let Some(rows) = number_row(ctx, y, style, "Rows:", rows_id, list.visible_rows, 1) {
+            self.push_control_update(|l: &mut ScrollListElement| l.visible_rows = rows);
+        }
+        if let Some(size) = number_row(
+            ctx,
+            y,
+            style,
+            "Font Size:",
+            font_size_id,
+            list.font_size,
+            1.0,
+        ) {
+            self.push_control_update(|l: &mut ScrollListElement| l.font_size = size);
+        }
+        if let Some(font) = text_row(ctx, y, style, "Font:", font_id, &list.font) {
+            self.push_control_update(|l: &mut ScrollListElement| l.font = font);
+        }
+
+        self.draw_control_nav::<ScrollListElement>(ctx, y, style);
+    }
+}
@@ -1,5 +1,7 @@
 // editor/src/menu_editor/menu_properties_panel/element_properties.rs
 use super::{common_properties::row_visible, nav_section::NavSectionStyle, FIELD_HEIGHT, LABEL_WIDTH, ROW_HEIGHT};
+use super::control_properties::RowStyle;
+use super::slice_properties::slice_rows;
 use crate::menu::MenuEditor;
 use bishop::prelude::*;
 use engine_core::prelude::*;
@@ -14,14 +16,19 @@ impl MenuEditor {
         blocked: bool,
         clip: &Rect,
     ) {
-        let (current_text_key, current_font_size, current_alignment) = {
+        let (current_text_key, current_font_size, current_font, current_alignment) = {
             let Some(element) = self.selected_element() else {
                 return;
             };
             let MenuElementKind::Label(label) = &element.kind else {
                 return;
             };
-            (label.text_key.clone(), label.font_size, label.alignment)
+            (
+                label.text_key.clone(),
+                label.font_size,
+                label.font.clone(),
+                label.alignment,
+            )
         };
 
         // Text key field
@@ -73,6 +80,29 @@ impl MenuEditor {
         }
         *y += ROW_HEIGHT;
 
+        // Font
+        if row_visible(*y, ROW_HEIGHT, clip) {
+            ctx.draw_text("Font:", x, *y + 16.0, 12.0, Color::WHITE);
+            let field_rect = Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
+
+            let (new_font, _) = TextInput::new(
+                self.properties_panel.widget_ids.font_id,
+                field_rect,
+                &current_font,
+            )
+            .blocked(blocked)
+            .show(ctx);
+
+            if new_font != current_font {
+                self.push_element_update(|el| {
+                    if let MenuElementKind::Label(label) = &mut el.kind {
+                        label.font = new_font;
+                    }
+                });
+            }
+        }
+        *y += ROW_HEIGHT;
+
         // Horizontal alignment
         if row_visible(*y, ROW_HEIGHT, clip) {
             ctx.draw_text("Align:", x, *y + 16.0, 12.0, Color::WHITE);
@@ -119,7 +149,7 @@ impl MenuEditor {
         blocked: bool,
         clip: &Rect,
     ) {
-        let (current_text_key, current_font_size, current_action) = {
+        let (current_text_key, current_font_size, current_font, current_action) = {
             let Some(element) = self.selected_element() else {
                 return;
             };
@@ -129,6 +159,7 @@ impl MenuEditor {
             (
                 button.text_key.clone(),
                 button.font_size,
+                button.font.clone(),
                 button.action.clone(),
             )
         };
@@ -181,6 +212,29 @@ impl MenuEditor {
         }
         *y += ROW_HEIGHT;
 
+        // Font
+        if row_visible(*y, ROW_HEIGHT, clip) {
+            ctx.draw_text("Font:", x, *y + 16.0, 12.0, Color::WHITE);
+            let field_rect = Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
+
+            let (new_font, _) = TextInput::new(
+                self.properties_panel.widget_ids.font_id,
+                field_rect,
+                &current_font,
+            )
+            .blocked(blocked)
+            .show(ctx);
+
+            if new_font != current_font {
+                self.push_element_update(|el| {
+                    if let MenuElementKind::Button(button) = &mut el.kind {
+                        button.font = new_font;
+                    }
+                });
+            }
+        }
+        *y += ROW_HEIGHT;
+
         // Action dropdown
         if row_visible(*y, ROW_HEIGHT, clip) {
             ctx.draw_text("Action:", x, *y + 16.0, 12.0, Color::WHITE);
@@ -438,20 +492,21 @@ impl MenuEditor {
         &mut self,
         ctx: &mut WgpuContext,
         y: &mut f32,
-        x: f32,
-        w: f32,
-        blocked: bool,
-        clip: &Rect,
+        style: RowStyle<'_>,
+        asset_manager: &mut AssetManager,
     ) {
-        let (current_color, current_opacity) = {
-            let Some(element) = self.selected_element() else {
-                return;
-            };
-            let MenuElementKind::Panel(panel) = &element.kind else {
-                return;
-            };
-            let PanelFill::SolidColor(color) = panel.background.fill;
-            (color, panel.background.opacity)
+        let Some(MenuElementKind::Panel(panel)) = self.selected_element().map(|el| el.kind.clone())
+        else {
+            return;
+        };
+        let current_opacity = panel.background.opacity;
+        let (x, w, blocked, clip) = (style.x, style.w, style.blocked, style.clip);
+        let update_fill = |editor: &mut Self, fill: PanelFill| {
+            editor.push_element_update(|el| {
+                if let MenuElementKind::Panel(panel) = &mut el.kind {
+                    panel.background.fill = fill;
+                }
+            });
         };
 
         if row_visible(*y, 20.0, clip) {
@@ -459,27 +514,81 @@ impl MenuEditor {
         }
         *y += 20.0;
 
-        // Color
+        // Fill
         if row_visible(*y, ROW_HEIGHT, clip) {
-            ctx.draw_text("Color:", x, *y + 16.0, 12.0, Color::WHITE);
-            let field_rect = Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
-            let new_color = ColorInput::new(
-                self.properties_panel.widget_ids.panel_color_id,
-                field_rect,
-                current_color,
+            ctx.draw_text("Fill:", x, *y + 16.0, 12.0, Color::WHITE);
+            let dropdown_rect = Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
+            let fills = [
+                PanelFill::default(),
+                PanelFill::Sprite {
+                    sprite: SpriteId(0),
+                    slice: NineSlice::default(),
+                },
+            ];
+            let current = panel.background.fill;
+            if let Some(selected) = Dropdown::new(
+                self.properties_panel.widget_ids.panel_fill_id,
+                dropdown_rect,
+                current.label(),
+                &fills,
+                |f| f.label().to_string(),
             )
             .blocked(blocked)
-            .show(ctx);
-            if new_color != current_color {
-                self.push_element_update(|el| {
-                    if let MenuElementKind::Panel(panel) = &mut el.kind {
-                        panel.background.fill = PanelFill::SolidColor(new_color);
-                    }
-                });
+            .fixed_width()
+            .show(ctx)
+            {
+                if selected.label() != current.label() {
+                    update_fill(self, selected);
+                }
             }
         }
         *y += ROW_HEIGHT;
 
+        match panel.background.fill {
+            PanelFill::SolidColor(current_color) => {
+                if row_visible(*y, ROW_HEIGHT, clip) {
+                    ctx.draw_text("Color:", x, *y + 16.0, 12.0, Color::WHITE);
+                    let field_rect = Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
+                    let new_color = ColorInput::new(
+                        self.properties_panel.widget_ids.panel_color_id,
+                        field_rect,
+                        current_color,
+                    )
+                    .blocked(blocked)
+                    .show(ctx);
+                    if new_color != current_color {
+                        update_fill(self, PanelFill::SolidColor(new_color));
+                    }
+                }
+                *y += ROW_HEIGHT;
+            }
+            PanelFill::Sprite { sprite, slice } => {
+                if row_visible(*y, ROW_HEIGHT, clip) {
+                    ctx.draw_text("Sprite:", x, *y + 16.0, 12.0, Color::WHITE);
+                    let picker_rect = Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
+                    if let Some(picked) =
+                        gui_sprite_field(ctx, picker_rect, sprite, asset_manager, blocked)
+                    {
+                        update_fill(self, PanelFill::Sprite { sprite: picked, slice });
+                    }
+                }
+                *y += ROW_HEIGHT;
+
+                if let Some(slice) = slice_rows(
+                    ctx,
+                    y,
+                    style,
+                    self.properties_panel.widget_ids.panel_slice_ids,
+                    sprite,
+                    slice,
+                    &mut self.properties_panel.slice_drag,
+                    asset_manager,
+                ) {
+                    update_fill(self, PanelFill::Sprite { sprite, slice });
+                }
+            }
+        }
+
         // Opacity
         if row_visible(*y, ROW_HEIGHT, clip) {
             ctx.draw_text("Opacity:", x, *y + 16.0, 12.0, Color::WHITE);
@@ -40,7 +40,10 @@ impl MenuEditor {
             };
             let (has_bg, bg_color, bg_opacity) = match &group.background {
                 Some(bg) => {
-                    let PanelFill::SolidColor(color) = bg.fill;
+                    let color = match bg.fill {
+                        PanelFill::SolidColor(color) => color,
+                        PanelFill::Sprite { .. } => Color::WHITE,
+                    };
                     (true, color, bg.opacity)
                 }
                 None => (false, Color::new(0.3, 0.3, 0.35, 1.0), 1.0),
@@ -447,6 +450,11 @@ impl MenuEditor {
                         MenuElementKind::Panel(_) => "Panel".to_string(),
                         MenuElementKind::LayoutGroup(_) => "Layout Group".to_string(),
                         MenuElementKind::Slider(s) => format!("Slider: {}", s.text_key),
+                        MenuElementKind::Toggle(t) => format!("Toggle: {}", t.text_key),
+                        MenuElementKind::Choice(c) => format!("Choice: {}", c.text_key),
+                        MenuElementKind::TextInput(t) => format!("Text Input: {}", t.text_key),
+                        MenuElementKind::Image(_) => "Image".to_string(),
+                        MenuElementKind::ScrollList(l) => format!("Scroll List: {}", l.key),
                     }
                 };
                 (label, child.managed)
@@ -1,4 +1,5 @@
 // editor/src/menu_editor/menu_properties_panel/menu_properties.rs
+use super::control_properties::RowStyle;
 use super::{common_properties::row_visible, FIELD_HEIGHT, LABEL_WIDTH, ROW_HEIGHT};
 use crate::commands::menu::{TemplateProperty, UpdateTemplateCmd};
 use crate::editor_global::push_command;
@@ -11,11 +12,15 @@ impl MenuEditor {
         &mut self,
         ctx: &mut WgpuContext,
         y: &mut f32,
-        x: f32,
-        w: f32,
-        blocked: bool,
-        clip: &Rect,
+        style: RowStyle<'_>,
+        asset_manager: &mut AssetManager,
     ) {
+        let RowStyle {
+            x,
+            w,
+            blocked,
+            clip,
+        } = style;
         let Some(template) = self.current_template() else {
             return;
         };
@@ -60,11 +65,12 @@ impl MenuEditor {
         // Mode dropdown
         if row_visible(*y, ROW_HEIGHT, clip) {
             ctx.draw_text("Mode:", x, *y + 16.0, 12.0, Color::WHITE);
-            let mode_options = ["Paused", "Overlay", "FrontEnd"];
+            let mode_options = ["Paused", "Overlay", "FrontEnd", "Hud"];
             let current_mode_str = match current_mode {
                 MenuMode::Paused => "Paused",
                 MenuMode::Overlay => "Overlay",
                 MenuMode::FrontEnd => "FrontEnd",
+                MenuMode::Hud => "Hud",
             };
             let dropdown_rect = Rect::new(x + LABEL_WIDTH, *y, w - LABEL_WIDTH, FIELD_HEIGHT);
             if let Some(selected) = Dropdown::new(
@@ -82,6 +88,7 @@ impl MenuEditor {
                     "Paused" => MenuMode::Paused,
                     "Overlay" => MenuMode::Overlay,
                     "FrontEnd" => MenuMode::FrontEnd,
+                    "Hud" => MenuMode::Hud,
                     _ => current_mode,
                 };
                 if new_mode != current_mode {
@@ -214,6 +221,8 @@ impl MenuEditor {
             MenuBackground::None => {}
         }
 
+        self.draw_menu_animation_properties(ctx, y, style, asset_manager);
+
         // Elements list
         *y += 8.0;
         let element_labels: Vec<(usize, String)> = {
@@ -234,6 +243,11 @@ impl MenuEditor {
                             MenuElementKind::Panel(_) => "Panel".to_string(),
                             MenuElementKind::LayoutGroup(_) => "Layout Group".to_string(),
                             MenuElementKind::Slider(s) => format!("Slider: {}", s.text_key),
+                            MenuElementKind::Toggle(t) => format!("Toggle: {}", t.text_key),
+                            MenuElementKind::Choice(c) => format!("Choice: {}", c.text_key),
+                            MenuElementKind::TextInput(t) => format!("Text Input: {}", t.text_key),
+                            MenuElementKind::Image(_) => "Image".to_string(),
+                            MenuElementKind::ScrollList(l) => format!("Scroll List: {}", l.key),
                         }
                     };
                     (i, label)
@@ -291,5 +305,7 @@ impl MenuEditor {
             self.selected_element_indices.insert(index);
             self.selected_child_index = None;
         }
+
+        self.draw_theme_properties(ctx, y, style, asset_manager);
     }
 }
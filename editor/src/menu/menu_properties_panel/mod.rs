@@ -1,13 +1,19 @@
 // editor/src/menu_editor/menu_properties_panel/mod.rs
+mod animation_properties;
 mod common_properties;
+pub(crate) mod control_properties;
 mod element_properties;
 mod layout_properties;
 mod menu_properties;
 mod nav_section;
+pub(crate) mod slice_properties;
+mod theme_properties;
 
 use crate::menu::MenuEditor;
 use bishop::prelude::*;
+use control_properties::RowStyle;
 use engine_core::prelude::*;
+use slice_properties::{SliceDrag, SliceWidgetIds};
 
 pub(crate) const ROW_HEIGHT: f32 = 28.0;
 pub(crate) const LABEL_WIDTH: f32 = 80.0;
@@ -19,13 +25,17 @@ pub struct PropertiesWidgetIds {
     pub(crate) name_id: WidgetId,
     pub(crate) text_id: WidgetId,
     pub(crate) font_size_id: WidgetId,
+    pub(crate) font_id: WidgetId,
     pub(crate) action_id: WidgetId,
     pub(crate) action_param_id: WidgetId,
     pub(crate) z_order_id: WidgetId,
+    pub(crate) anchor_id: WidgetId,
     pub(crate) pos_x_id: WidgetId,
     pub(crate) pos_y_id: WidgetId,
     pub(crate) size_w_id: WidgetId,
     pub(crate) size_h_id: WidgetId,
+    pub(crate) visible_if_id: WidgetId,
+    pub(crate) enabled_if_id: WidgetId,
     pub(crate) button_nav_ids: NavWidgetIds,
     pub(crate) layout_direction_id: WidgetId,
     pub(crate) layout_grid_cols_id: WidgetId,
@@ -40,7 +50,9 @@ pub struct PropertiesWidgetIds {
     pub(crate) layout_item_h_id: WidgetId,
     pub(crate) layout_nav_ids: NavWidgetIds,
     pub(crate) label_h_align_id: WidgetId,
+    pub(crate) panel_fill_id: WidgetId,
     pub(crate) panel_color_id: WidgetId,
+    pub(crate) panel_slice_ids: SliceWidgetIds,
     pub(crate) panel_opacity_id: WidgetId,
     pub(crate) layout_bg_color_id: WidgetId,
     pub(crate) layout_bg_opacity_id: WidgetId,
@@ -55,6 +67,59 @@ pub struct PropertiesWidgetIds {
     pub(crate) slider_max_id: WidgetId,
     pub(crate) slider_step_id: WidgetId,
     pub(crate) slider_default_id: WidgetId,
+    pub(crate) toggle_text_id: WidgetId,
+    pub(crate) toggle_key_id: WidgetId,
+    pub(crate) choice_text_id: WidgetId,
+    pub(crate) choice_key_id: WidgetId,
+    pub(crate) choice_options_id: WidgetId,
+    pub(crate) choice_default_id: WidgetId,
+    pub(crate) text_input_text_id: WidgetId,
+    pub(crate) text_input_key_id: WidgetId,
+    pub(crate) text_input_placeholder_id: WidgetId,
+    pub(crate) text_input_max_id: WidgetId,
+    pub(crate) image_tint_id: WidgetId,
+    pub(crate) image_frame_w_id: WidgetId,
+    pub(crate) image_frame_h_id: WidgetId,
+    pub(crate) image_columns_id: WidgetId,
+    pub(crate) image_rows_id: WidgetId,
+    pub(crate) image_fps_id: WidgetId,
+    pub(crate) list_key_id: WidgetId,
+    pub(crate) list_rows_id: WidgetId,
+    pub(crate) list_font_size_id: WidgetId,
+    pub(crate) list_font_id: WidgetId,
+    pub(crate) control_nav_ids: NavWidgetIds,
+    pub(crate) open_style_id: WidgetId,
+    pub(crate) open_duration_id: WidgetId,
+    pub(crate) close_style_id: WidgetId,
+    pub(crate) close_duration_id: WidgetId,
+    pub(crate) stagger_id: WidgetId,
+    pub(crate) focus_pulse_id: WidgetId,
+    pub(crate) enter_style_id: WidgetId,
+    pub(crate) enter_duration_id: WidgetId,
+    pub(crate) theme_style_id: WidgetId,
+    pub(crate) theme_name_id: WidgetId,
+    pub(crate) theme_font_id: WidgetId,
+    pub(crate) theme_font_size_id: WidgetId,
+    pub(crate) theme_padding_id: WidgetId,
+    pub(crate) theme_focus_sound_id: WidgetId,
+    pub(crate) theme_activate_sound_id: WidgetId,
+    pub(crate) theme_state_id: WidgetId,
+    pub(crate) theme_background_id: WidgetId,
+    pub(crate) theme_text_color_id: WidgetId,
+    pub(crate) theme_outline_id: WidgetId,
+    pub(crate) theme_slice_ids: SliceWidgetIds,
+    pub(crate) theme_safe_top_id: WidgetId,
+    pub(crate) theme_safe_right_id: WidgetId,
+    pub(crate) theme_safe_bottom_id: WidgetId,
+    pub(crate) theme_safe_left_id: WidgetId,
+    pub(crate) element_style_id: WidgetId,
+    pub(crate) override_font_id: WidgetId,
+    pub(crate) override_font_size_id: WidgetId,
+    pub(crate) override_padding_id: WidgetId,
+    pub(crate) override_text_color_id: WidgetId,
+    pub(crate) override_background_id: WidgetId,
+    pub(crate) override_focus_sound_id: WidgetId,
+    pub(crate) override_activate_sound_id: WidgetId,
 }
 
 /// Widget IDs for nav dropdowns.
@@ -71,6 +136,12 @@ pub struct MenuPropertiesPanel {
     pub(crate) scroll_state: ScrollState,
     pub(crate) widget_ids: PropertiesWidgetIds,
     pub(crate) last_content_height: f32,
+    /// Index of the theme style being edited.
+    pub(crate) theme_style: usize,
+    /// State whose look the theme editor shows.
+    pub(crate) theme_state: StyleState,
+    /// Nine-slice inset being dragged on a sprite preview.
+    pub(crate) slice_drag: Option<SliceDrag>,
 }
 
 impl MenuPropertiesPanel {
@@ -80,6 +151,9 @@ impl MenuPropertiesPanel {
             scroll_state: ScrollState::new(),
             widget_ids: PropertiesWidgetIds::default(),
             last_content_height: 0.0,
+            theme_style: 0,
+            theme_state: StyleState::Normal,
+            slice_drag: None,
         }
     }
 }
@@ -92,7 +166,13 @@ impl Default for MenuPropertiesPanel {
 
 impl MenuEditor {
     /// Renders the properties panel and handles editing.
-    pub fn draw_properties_panel(&mut self, ctx: &mut WgpuContext, rect: Rect, blocked: bool) {
+    pub fn draw_properties_panel(
+        &mut self,
+        ctx: &mut WgpuContext,
+        rect: Rect,
+        blocked: bool,
+        asset_manager: &mut AssetManager,
+    ) {
         let content_height = self.properties_panel.last_content_height;
 
         let area = ScrollableArea::new(rect, content_height)
@@ -111,7 +191,13 @@ impl MenuEditor {
         y += 24.0;
 
         if self.primary_selected_index().is_none() {
-            self.draw_menu_properties(ctx, &mut y, content_x, content_w, blocked, &rect);
+            let style = RowStyle {
+                x: content_x,
+                w: content_w,
+                blocked,
+                clip: &rect,
+            };
+            self.draw_menu_properties(ctx, &mut y, style, asset_manager);
             self.properties_panel.last_content_height = y - start_y + 16.0;
             area.draw_scrollbar(ctx, self.properties_panel.scroll_state.scroll_y);
             return;
@@ -128,6 +214,17 @@ impl MenuEditor {
         self.draw_common_properties(ctx, &mut y, content_x, content_w, blocked, &rect);
         y += 8.0;
 
+        let style = RowStyle {
+            x: content_x,
+            w: content_w,
+            blocked,
+            clip: &rect,
+        };
+        if self.selected_child_index.is_none() {
+            self.draw_element_enter_properties(ctx, &mut y, style);
+        }
+        self.draw_element_style_properties(ctx, &mut y, style);
+
         match kind {
             MenuElementKind::Label(_) => {
                 self.draw_label_properties(ctx, &mut y, content_x, content_w, blocked, &rect);
@@ -136,7 +233,7 @@ impl MenuEditor {
                 self.draw_button_properties(ctx, &mut y, content_x, content_w, blocked, &rect);
             }
             MenuElementKind::Panel(_) => {
-                self.draw_panel_properties(ctx, &mut y, content_x, content_w, blocked, &rect);
+                self.draw_panel_properties(ctx, &mut y, style, asset_manager);
             }
             MenuElementKind::LayoutGroup(_) => {
                 self.draw_layout_group_properties(
@@ -146,6 +243,17 @@ impl MenuEditor {
             MenuElementKind::Slider(_) => {
                 self.draw_slider_properties(ctx, &mut y, content_x, content_w, blocked, &rect);
             }
+            MenuElementKind::Toggle(_) => self.draw_toggle_properties(ctx, &mut y, style),
+            MenuElementKind::Choice(_) => self.draw_choice_properties(ctx, &mut y, style),
+            MenuElementKind::TextInput(_) => {
+                self.draw_text_input_properties(ctx, &mut y, style);
+            }
+            MenuElementKind::Image(_) => {
+                self.draw_image_properties(ctx, &mut y, style, asset_manager);
+            }
+            MenuElementKind::ScrollList(_) => {
+                self.draw_scroll_list_properties(ctx, &mut y, style);
+            }
         }
 
         self.properties_panel.last_content_height = y - start_y + 16.0;
@@ -43,19 +43,12 @@ impl MenuEditor {
                 .and_then(|e| (meta.get)(e));
 
             let set_fn = meta.set;
-            self.draw_nav_dropdown(
-                ctx,
-                y,
-                style,
-                current,
-                meta,
-                |element, value| {
-                    if let Some(mut cloned_t) = T::from_element(element).cloned() {
-                        set_fn(&mut cloned_t, value);
-                        element.kind = cloned_t.wrap_into_element();
-                    }
-                },
-            );
+            self.draw_nav_dropdown(ctx, y, style, current, meta, |element, value| {
+                if let Some(mut cloned_t) = T::from_element(element).cloned() {
+                    set_fn(&mut cloned_t, value);
+                    element.kind = cloned_t.wrap_into_element();
+                }
+            });
         }
     }
 
@@ -162,23 +155,25 @@ impl MenuEditor {
                 } else {
                     match &element.kind {
                         MenuElementKind::Button(button) => button.text_key.clone(),
+                        MenuElementKind::Slider(slider) => slider.text_key.clone(),
+                        MenuElementKind::Toggle(toggle) => toggle.text_key.clone(),
+                        MenuElementKind::Choice(choice) => choice.text_key.clone(),
+                        MenuElementKind::TextInput(input) => input.text_key.clone(),
+                        MenuElementKind::ScrollList(list) => list.key.clone(),
                         MenuElementKind::LayoutGroup(group) => {
-                            let button_count = group
+                            let control_count = group
                                 .children
                                 .iter()
-                                .filter(|c| matches!(c.element.kind, MenuElementKind::Button(_)))
+                                .filter(|c| c.element.kind.is_focusable())
                                 .count();
-                            format!("Layout Group ({} buttons)", button_count)
+                            format!("Layout Group ({} controls)", control_count)
                         }
                         _ => return None,
                     }
                 };
-                match &element.kind {
-                    MenuElementKind::Button(_) | MenuElementKind::LayoutGroup(_) => {
-                        Some((idx, format!("{}: {}", idx, name)))
-                    }
-                    _ => None,
-                }
+                let navigable = element.kind.is_focusable()
+                    || matches!(element.kind, MenuElementKind::LayoutGroup(_));
+                navigable.then(|| (idx, format!("{}: {}", idx, name)))
             })
             .collect()
     }
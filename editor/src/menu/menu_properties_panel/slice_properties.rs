@@ -0,0 +1,269 @@
+// editor/src/menu/menu_properties_panel/slice_properties.rs
+use super::control_properties::{number_row, RowStyle};
+use super::{common_properties::row_visible, FIELD_HEIGHT, LABEL_WIDTH, ROW_HEIGHT};
+use bishop::prelude::*;
+use engine_core::prelude::*;
+
+/// Height of the sprite preview the insets are dragged on.
+const PREVIEW_HEIGHT: f32 = 96.0;
+/// How close to an inset line a press grabs it, in screen pixels.
+const GRAB_DISTANCE: f32 = 6.0;
+const INSET_LINE_COLOR: Color = Color::new(0.3, 0.9, 1.0, 1.0);
+
+/// Widget IDs for a nine-slice editor.
+#[derive(Default, Clone, Copy)]
+pub struct SliceWidgetIds {
+    pub(crate) preview: WidgetId,
+    pub(crate) left: WidgetId,
+    pub(crate) top: WidgetId,
+    pub(crate) right: WidgetId,
+    pub(crate) bottom: WidgetId,
+    pub(crate) center: WidgetId,
+    pub(crate) edges: WidgetId,
+    pub(crate) scale: WidgetId,
+}
+
+/// Side of a sliced sprite whose inset is being dragged.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SliceSide {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+/// Inset drag in progress on a sprite preview.
+#[derive(Clone, Copy)]
+pub(crate) struct SliceDrag {
+    preview: WidgetId,
+    side: SliceSide,
+    /// Slice with the dragged inset applied, committed on release.
+    slice: NineSlice,
+}
+
+/// Draws a labelled slice fill dropdown. Returns the new fill when one was picked.
+fn fill_row(
+    ctx: &mut WgpuContext,
+    y: &mut f32,
+    style: RowStyle<'_>,
+    label: &str,
+    id: WidgetId,
+    current: SliceFill,
+) -> Option<SliceFill> {
+    let mut changed = None;
+    if row_visible(*y, ROW_HEIGHT, style.clip) {
+        ctx.draw_text(label, style.x, *y + 16.0, 12.0, Color::WHITE);
+        let dropdown_rect = Rect::new(
+            style.x + LABEL_WIDTH,
+            *y,
+            style.w - LABEL_WIDTH,
+            FIELD_HEIGHT,
+        );
+        if let Some(selected) =
+            Dropdown::new(id, dropdown_rect, current.label(), SliceFill::all(), |f| {
+                f.label().to_string()
+            })
+            .blocked(style.blocked)
+            .fixed_width()
+            .show(ctx)
+        {
+            if selected != current {
+                changed = Some(selected);
+            }
+        }
+    }
+    *y += ROW_HEIGHT;
+    changed
+}
+
+/// Returns `value` kept between zero and `limit`, or zero when `limit` is negative.
+fn clamp_inset(value: f32, limit: f32) -> f32 {
+    value.max(0.0).min(limit.max(0.0))
+}
+
+/// Returns `slice` with the inset of `side` moved to `value` source pixels,
+/// kept clear of the opposite inset. Insets set for a larger sprite may
+/// already overlap, so the limit can be negative.
+fn with_inset(slice: NineSlice, side: SliceSide, value: f32, texture_size: Vec2) -> NineSlice {
+    let mut slice = slice;
+    let insets = &mut slice.insets;
+    match side {
+        SliceSide::Left => insets.left = clamp_inset(value, texture_size.x - insets.right),
+        SliceSide::Right => insets.right = clamp_inset(value, texture_size.x - insets.left),
+        SliceSide::Top => insets.top = clamp_inset(value, texture_size.y - insets.bottom),
+        SliceSide::Bottom => insets.bottom = clamp_inset(value, texture_size.y - insets.top),
+    }
+    slice
+}
+
+/// Draws `texture` with the inset lines of `slice` over it and lets them be dragged.
+/// Returns the edited slice once a drag is released.
+fn inset_preview(
+    ctx: &mut WgpuContext,
+    y: &mut f32,
+    style: RowStyle<'_>,
+    id: WidgetId,
+    texture: &Texture2D,
+    slice: NineSlice,
+    drag: &mut Option<SliceDrag>,
+) -> Option<NineSlice> {
+    let height = PREVIEW_HEIGHT + 4.0;
+    if !row_visible(*y, height, style.clip) {
+        *y += height;
+        return None;
+    }
+
+    let texture_size = Vec2::new(texture.width(), texture.height());
+    if texture_size.x <= 0.0 || texture_size.y <= 0.0 {
+        *y += height;
+        return None;
+    }
+
+    // Whole-pixel zoom keeps pixel art crisp
+    let fit = (style.w / texture_size.x).min(PREVIEW_HEIGHT / texture_size.y);
+    let zoom = if fit >= 1.0 { fit.floor() } else { fit };
+    let size = texture_size * zoom;
+    let origin = Vec2::new(style.x + (style.w - size.x) / 2.0, *y);
+    let frame = Rect::new(style.x, *y, style.w, PREVIEW_HEIGHT);
+
+    ctx.draw_rectangle(
+        frame.x,
+        frame.y,
+        frame.w,
+        frame.h,
+        Color::new(0.1, 0.1, 0.12, 1.0),
+    );
+    ctx.draw_texture_ex(
+        texture,
+        origin.x,
+        origin.y,
+        Color::WHITE,
+        DrawTextureParams {
+            dest_size: Some(size),
+            ..Default::default()
+        },
+    );
+
+    let shown = match drag {
+        Some(active) if active.preview == id => active.slice,
+        _ => slice,
+    };
+    let lines = [
+        (SliceSide::Left, origin.x + shown.insets.left * zoom),
+        (
+            SliceSide::Right,
+            origin.x + size.x - shown.insets.right * zoom,
+        ),
+        (SliceSide::Top, origin.y + shown.insets.top * zoom),
+        (
+            SliceSide::Bottom,
+            origin.y + size.y - shown.insets.bottom * zoom,
+        ),
+    ];
+    for (side, at) in lines {
+        match side {
+            SliceSide::Left | SliceSide::Right => {
+                ctx.draw_line(at, origin.y, at, origin.y + size.y, 1.0, INSET_LINE_COLOR);
+            }
+            SliceSide::Top | SliceSide::Bottom => {
+                ctx.draw_line(origin.x, at, origin.x + size.x, at, 1.0, INSET_LINE_COLOR);
+            }
+        }
+    }
+
+    let (mx, my) = ctx.mouse_position();
+    let mut committed = None;
+    if !style.blocked
+        && drag.is_none()
+        && ctx.is_mouse_button_pressed(MouseButton::Left)
+        && frame.contains(Vec2::new(mx, my))
+    {
+        let grabbed = lines
+            .iter()
+            .map(|&(side, at)| {
+                let distance = match side {
+                    SliceSide::Left | SliceSide::Right => (mx - at).abs(),
+                    SliceSide::Top | SliceSide::Bottom => (my - at).abs(),
+                };
+                (side, distance)
+            })
+            .filter(|&(_, distance)| distance <= GRAB_DISTANCE)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((side, _)) = grabbed {
+            *drag = Some(SliceDrag {
+                preview: id,
+                side,
+                slice,
+            });
+        }
+    }
+
+    if let Some(active) = drag.as_mut().filter(|active| active.preview == id) {
+        let local = (Vec2::new(mx, my) - origin) / zoom;
+        let value = match active.side {
+            SliceSide::Left => local.x,
+            SliceSide::Right => texture_size.x - local.x,
+            SliceSide::Top => local.y,
+            SliceSide::Bottom => texture_size.y - local.y,
+        };
+        active.slice = with_inset(active.slice, active.side, value.round(), texture_size);
+        if !ctx.is_mouse_button_down(MouseButton::Left) {
+            if active.slice != slice {
+                committed = Some(active.slice);
+            }
+            *drag = None;
+        }
+    }
+
+    *y += height;
+    committed
+}
+
+/// Draws the inset editor of a sliced sprite: a draggable preview over the
+/// source sprite, the inset numbers, fills and scale. Returns the edited slice.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn slice_rows(
+    ctx: &mut WgpuContext,
+    y: &mut f32,
+    style: RowStyle<'_>,
+    ids: SliceWidgetIds,
+    sprite: SpriteId,
+    current: NineSlice,
+    drag: &mut Option<SliceDrag>,
+    asset_manager: &mut AssetManager,
+) -> Option<NineSlice> {
+    if sprite.0 == 0 {
+        return None;
+    }
+    let texture = asset_manager.get_texture_from_id(ctx, sprite).clone();
+    let texture_size = Vec2::new(texture.width(), texture.height());
+    let mut slice = current;
+    if let Some(dragged) = inset_preview(ctx, y, style, ids.preview, &texture, current, drag) {
+        slice = dragged;
+    }
+
+    let insets = current.insets;
+    let rows = [
+        ("Left:", ids.left, SliceSide::Left, insets.left),
+        ("Top:", ids.top, SliceSide::Top, insets.top),
+        ("Right:", ids.right, SliceSide::Right, insets.right),
+        ("Bottom:", ids.bottom, SliceSide::Bottom, insets.bottom),
+    ];
+    for (label, id, side, value) in rows {
+        if let Some(value) = number_row(ctx, y, style, label, id, value, 0.0) {
+            slice = with_inset(slice, side, value, texture_size);
+        }
+    }
+
+    if let Some(fill) = fill_row(ctx, y, style, "Center:", ids.center, current.center) {
+        slice.center = fill;
+    }
+    if let Some(fill) = fill_row(ctx, y, style, "Edges:", ids.edges, current.edges) {
+        slice.edges = fill;
+    }
+    if let Some(scale) = number_row(ctx, y, style, "Scale:", ids.scale, current.scale, 0.1) {
+        slice.scale = scale;
+    }
+
+    (slice != current).then_some(slice)
+}
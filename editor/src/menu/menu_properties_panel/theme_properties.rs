@@ -0,0 +1,440 @@
+// editor/src/menu/menu_properties_panel/theme_properties.rs
+use super::control_properties::{color_row, number_row, text_row, RowStyle};
+use super::slice_properties::slice_rows;
+use super::{common_properties::row_visible, FIELD_HEIGHT, LABEL_WIDTH, ROW_HEIGHT};
+use crate::commands::menu::UpdateThemeCmd;
+use crate::editor_global::push_command;
+use crate::menu::MenuEditor;
+use bishop::prelude::*;
+use engine_core::prelude::*;
+
+/// Label of an override row, marked while the element overrides the field.
+fn override_label(label: &str, overridden: bool) -> String {
+    if overridden {
+        format!("{label}*:")
+    } else {
+        format!("{label}:")
+    }
+}
+
+/// Draws a labelled dropdown of names. Returns the picked name when it changed.
+fn name_dropdown_row(
+    ctx: &mut WgpuContext,
+    y: &mut f32,
+    style: RowStyle<'_>,
+    label: &str,
+    id: WidgetId,
+    current: &str,
+    names: &[String],
+) -> Option<String> {
+    let mut changed = None;
+    if row_visible(*y, ROW_HEIGHT, style.clip) {
+        ctx.draw_text(label, style.x, *y + 16.0, 12.0, Color::WHITE);
+        let dropdown_rect = Rect::new(
+            style.x + LABEL_WIDTH,
+            *y,
+            style.w - LABEL_WIDTH,
+            FIELD_HEIGHT,
+        );
+        if let Some(selected) = Dropdown::new(id, dropdown_rect, current, names, |s| s.clone())
+            .blocked(style.blocked)
+            .fixed_width()
+            .show(ctx)
+        {
+            if selected != current {
+                changed = Some(selected);
+            }
+        }
+    }
+    *y += ROW_HEIGHT;
+    changed
+}
+
+impl MenuEditor {
+    /// Draws the editor for the theme styles shared by every template.
+    pub(super) fn draw_theme_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        style: RowStyle<'_>,
+        asset_manager: &mut AssetManager,
+    ) {
+        let theme = self.theme.clone();
+        let ids = &self.properties_panel.widget_ids;
+        let (style_id, name_id, font_id, font_size_id, padding_id) = (
+            ids.theme_style_id,
+            ids.theme_name_id,
+            ids.theme_font_id,
+            ids.theme_font_size_id,
+            ids.theme_padding_id,
+        );
+        let (focus_sound_id, activate_sound_id, state_id) = (
+            ids.theme_focus_sound_id,
+            ids.theme_activate_sound_id,
+            ids.theme_state_id,
+        );
+        let (background_id, text_color_id, outline_id, slice_ids) = (
+            ids.theme_background_id,
+            ids.theme_text_color_id,
+            ids.theme_outline_id,
+            ids.theme_slice_ids,
+        );
+
+        *y += 8.0;
+        if row_visible(*y, 20.0, style.clip) {
+            ctx.draw_text(
+                "Theme (shared by all menus)",
+                style.x,
+                *y + 14.0,
+                12.0,
+                Color::GREY,
+            );
+        }
+        *y += 20.0;
+
+        // HUD safe area
+        if row_visible(*y, 20.0, style.clip) {
+            ctx.draw_text("HUD Safe Area", style.x, *y + 14.0, 12.0, Color::GREY);
+        }
+        *y += 20.0;
+        let inset = theme.hud_safe_area;
+        let safe_fields = [
+            ("Top:", ids.theme_safe_top_id, inset.top),
+            ("Right:", ids.theme_safe_right_id, inset.right),
+            ("Bottom:", ids.theme_safe_bottom_id, inset.bottom),
+            ("Left:", ids.theme_safe_left_id, inset.left),
+        ];
+        for (side, (label, id, current)) in safe_fields.into_iter().enumerate() {
+            if let Some(value) = number_row(ctx, y, style, label, id, current, 0.0) {
+                let mut new_theme = theme.clone();
+                let inset = &mut new_theme.hud_safe_area;
+                match side {
+                    0 => inset.top = value,
+                    1 => inset.right = value,
+                    2 => inset.bottom = value,
+                    _ => inset.left = value,
+                }
+                push_command(Box::new(UpdateThemeCmd::new(theme.clone(), new_theme)));
+            }
+        }
+
+        let index = self
+            .properties_panel
+            .theme_style
+            .min(theme.styles.len().saturating_sub(1));
+        let names: Vec<String> = theme.styles.iter().map(|s| s.name.clone()).collect();
+        if let Some(current) = theme.styles.get(index) {
+            if let Some(picked) =
+                name_dropdown_row(ctx, y, style, "Style:", style_id, &current.name, &names)
+            {
+                if let Some(picked_index) = names.iter().position(|name| *name == picked) {
+                    self.properties_panel.theme_style = picked_index;
+                }
+            }
+        }
+
+        // Add and remove buttons
+        if row_visible(*y, ROW_HEIGHT, style.clip) {
+            let half = (style.w - 4.0) / 2.0;
+            let add_rect = Rect::new(style.x, *y, half, FIELD_HEIGHT);
+            let remove_rect = Rect::new(style.x + half + 4.0, *y, half, FIELD_HEIGHT);
+            if Button::new(add_rect, "Add Style")
+                .blocked(style.blocked)
+                .show(ctx)
+            {
+                let mut new_theme = theme.clone();
+                new_theme
+                    .styles
+                    .push(MenuStyle::new(theme.unique_name("Style")));
+                self.properties_panel.theme_style = new_theme.styles.len() - 1;
+                push_command(Box::new(UpdateThemeCmd::new(theme.clone(), new_theme)));
+            }
+            if Button::new(remove_rect, "Remove Style")
+                .blocked(style.blocked || theme.styles.is_empty())
+                .show(ctx)
+            {
+                let mut new_theme = theme.clone();
+                new_theme.styles.remove(index);
+                self.properties_panel.theme_style = index.saturating_sub(1);
+                push_command(Box::new(UpdateThemeCmd::new(theme.clone(), new_theme)));
+            }
+        }
+        *y += ROW_HEIGHT;
+
+        let Some(current) = theme.styles.get(index) else {
+            return;
+        };
+        let mut edited = current.clone();
+        let mut rename = None;
+
+        if let Some(name) = text_row(ctx, y, style, "Name:", name_id, &current.name) {
+            let name = name.trim().to_string();
+            if !name.is_empty() && theme.style(&name).is_none() {
+                rename = Some((current.name.clone(), name.clone()));
+                edited.name = name;
+            }
+        }
+        if let Some(font) = text_row(ctx, y, style, "Font:", font_id, &current.font) {
+            edited.font = font;
+        }
+        if let Some(size) = number_row(
+            ctx,
+            y,
+            style,
+            "Font Size:",
+            font_size_id,
+            current.font_size,
+            1.0,
+        ) {
+            edited.font_size = size;
+        }
+        if let Some(padding) =
+            number_row(ctx, y, style, "Padding:", padding_id, current.padding, 0.0)
+        {
+            edited.padding = padding;
+        }
+        if let Some(sound) = text_row(
+            ctx,
+            y,
+            style,
+            "Focus Sfx:",
+            focus_sound_id,
+            &current.focus_sound,
+        ) {
+            edited.focus_sound = sound;
+        }
+        if let Some(sound) = text_row(
+            ctx,
+            y,
+            style,
+            "Activate Sfx:",
+            activate_sound_id,
+            &current.activate_sound,
+        ) {
+            edited.activate_sound = sound;
+        }
+
+        // One state's look is edited at a time
+        let state = self.properties_panel.theme_state;
+        if row_visible(*y, ROW_HEIGHT, style.clip) {
+            ctx.draw_text("State:", style.x, *y + 16.0, 12.0, Color::WHITE);
+            let dropdown_rect = Rect::new(
+                style.x + LABEL_WIDTH,
+                *y,
+                style.w - LABEL_WIDTH,
+                FIELD_HEIGHT,
+            );
+            if let Some(selected) = Dropdown::new(
+                state_id,
+                dropdown_rect,
+                state.label(),
+                StyleState::all(),
+                |s| s.label().to_string(),
+            )
+            .blocked(style.blocked)
+            .fixed_width()
+            .show(ctx)
+            {
+                self.properties_panel.theme_state = selected;
+            }
+        }
+        *y += ROW_HEIGHT;
+
+        let look = *current.look(state);
+        let edited_look = edited.look_mut(state);
+        if let Some(color) = color_row(ctx, y, style, "Background:", background_id, look.background)
+        {
+            edited_look.background = color;
+        }
+        if let Some(color) = color_row(ctx, y, style, "Text:", text_color_id, look.text_color) {
+            edited_look.text_color = color;
+        }
+        if let Some(color) = color_row(ctx, y, style, "Outline:", outline_id, look.outline) {
+            edited_look.outline = color;
+        }
+        if row_visible(*y, ROW_HEIGHT, style.clip) {
+            ctx.draw_text("Panel:", style.x, *y + 16.0, 12.0, Color::WHITE);
+            let picker_rect = Rect::new(
+                style.x + LABEL_WIDTH,
+                *y,
+                style.w - LABEL_WIDTH,
+                FIELD_HEIGHT,
+            );
+            if let Some(sprite) =
+                gui_sprite_field(ctx, picker_rect, look.sprite, asset_manager, style.blocked)
+            {
+                edited_look.sprite = sprite;
+            }
+        }
+        *y += ROW_HEIGHT;
+        if let Some(slice) = slice_rows(
+            ctx,
+            y,
+            style,
+            slice_ids,
+            look.sprite,
+            look.slice,
+            &mut self.properties_panel.slice_drag,
+            asset_manager,
+        ) {
+            edited_look.slice = slice;
+        }
+
+        if edited != *current {
+            let mut new_theme = theme.clone();
+            new_theme.styles[index] = edited;
+            let mut cmd = UpdateThemeCmd::new(theme, new_theme);
+            if let Some((from, to)) = rename {
+                cmd = cmd.renaming(from, to);
+            }
+            push_command(Box::new(cmd));
+        }
+    }
+
+    /// Draws the theme style of the selected element and the fields it overrides.
+    pub(super) fn draw_element_style_properties(
+        &mut self,
+        ctx: &mut WgpuContext,
+        y: &mut f32,
+        style: RowStyle<'_>,
+    ) {
+        let Some(element) = self.selected_element().cloned() else {
+            return;
+        };
+        if matches!(
+            element.kind,
+            MenuElementKind::LayoutGroup(_) | MenuElementKind::Image(_)
+        ) {
+            return;
+        }
+        let ids = &self.properties_panel.widget_ids;
+        let (style_id, font_id, font_size_id, padding_id) = (
+            ids.element_style_id,
+            ids.override_font_id,
+            ids.override_font_size_id,
+            ids.override_padding_id,
+        );
+        let (text_color_id, background_id, focus_sound_id, activate_sound_id) = (
+            ids.override_text_color_id,
+            ids.override_background_id,
+            ids.override_focus_sound_id,
+            ids.override_activate_sound_id,
+        );
+
+        *y += 4.0;
+        if row_visible(*y, 20.0, style.clip) {
+            ctx.draw_text("Style", style.x, *y + 14.0, 12.0, Color::GREY);
+        }
+        *y += 20.0;
+
+        let mut names = vec!["None".to_string()];
+        names.extend(self.theme.styles.iter().map(|s| s.name.clone()));
+        let current_name = if element.style.is_empty() {
+            "None"
+        } else {
+            element.style.as_str()
+        };
+        if let Some(picked) =
+            name_dropdown_row(ctx, y, style, "Style:", style_id, current_name, &names)
+        {
+            let picked = if picked == "None" {
+                String::new()
+            } else {
+                picked
+            };
+            self.push_element_update(|el| el.style = picked);
+        }
+
+        if element.style.is_empty() {
+            return;
+        }
+        let Some(base) = self.theme.style(&element.style).cloned() else {
+            if row_visible(*y, ROW_HEIGHT, style.clip) {
+                ctx.draw_text(
+                    "Style is missing from the theme",
+                    style.x,
+                    *y + 16.0,
+                    12.0,
+                    Color::GREY,
+                );
+            }
+            *y += ROW_HEIGHT;
+            return;
+        };
+
+        // Rows show the effective value. Editing one overrides it for this element.
+        let overrides = element.style_overrides.clone();
+        let resolved = base.with_overrides(&overrides);
+        let mut edited = overrides.clone();
+
+        let label = override_label("Font", overrides.font.is_some());
+        if let Some(font) = text_row(ctx, y, style, &label, font_id, &resolved.font) {
+            edited.font = Some(font);
+        }
+        let label = override_label("Font Size", overrides.font_size.is_some());
+        if let Some(size) = number_row(ctx, y, style, &label, font_size_id, resolved.font_size, 1.0)
+        {
+            edited.font_size = Some(size);
+        }
+        let label = override_label("Padding", overrides.padding.is_some());
+        if let Some(padding) = number_row(ctx, y, style, &label, padding_id, resolved.padding, 0.0)
+        {
+            edited.padding = Some(padding);
+        }
+        let label = override_label("Text", overrides.text_color.is_some());
+        if let Some(color) = color_row(
+            ctx,
+            y,
+            style,
+            &label,
+            text_color_id,
+            resolved.normal.text_color,
+        ) {
+            edited.text_color = Some(color);
+        }
+        let label = override_label("Background", overrides.background.is_some());
+        if let Some(color) = color_row(
+            ctx,
+            y,
+            style,
+            &label,
+            background_id,
+            resolved.normal.background,
+        ) {
+            edited.background = Some(color);
+        }
+        let label = override_label("Focus Sfx", overrides.focus_sound.is_some());
+        if let Some(sound) = text_row(ctx, y, style, &label, focus_sound_id, &resolved.focus_sound)
+        {
+            edited.focus_sound = Some(sound);
+        }
+        let label = override_label("Activate Sfx", overrides.activate_sound.is_some());
+        if let Some(sound) = text_row(
+            ctx,
+            y,
+            style,
+            &label,
+            activate_sound_id,
+            &resolved.activate_sound,
+        ) {
+            edited.activate_sound = Some(sound);
+        }
+
+        if !overrides.is_empty() {
+            if row_visible(*y, ROW_HEIGHT, style.clip) {
+                let reset_rect = Rect::new(style.x, *y, style.w, FIELD_HEIGHT);
+                if Button::new(reset_rect, "Clear Overrides")
+                    .blocked(style.blocked)
+                    .show(ctx)
+                {
+                    edited = StyleOverrides::default();
+                }
+            }
+            *y += ROW_HEIGHT;
+        }
+
+        if edited != overrides {
+            self.push_element_update(|el| el.style_overrides = edited);
+        }
+    }
+}
@@ -2,10 +2,11 @@
 use crate::gui::menu_bar::{draw_top_panel_full, menu_panel_rect};
 use crate::menu::MenuEditor;
 use bishop::prelude::*;
+use engine_core::prelude::*;
 
 impl MenuEditor {
     /// Draws the menu editor ui.
-    pub fn draw_ui(&mut self, ctx: &mut WgpuContext) {
+    pub fn draw_ui(&mut self, ctx: &mut WgpuContext, asset_manager: &mut AssetManager) {
         const LEFT_COLUMN_WIDTH: f32 = 200.0;
         const PROPERTIES_WIDTH: f32 = 250.0;
         const SPACING: f32 = 8.0;
@@ -109,7 +110,7 @@ impl MenuEditor {
             Color::new(0.4, 0.4, 0.4, 1.0),
         );
 
-        self.draw_properties_panel(ctx, properties_rect, blocked);
+        self.draw_properties_panel(ctx, properties_rect, blocked, asset_manager);
 
         // Draw top menu
         self.register_rect(draw_top_panel_full(ctx));
@@ -298,16 +298,21 @@ pub fn highlight_selected_entity<C: BishopContext>(
     };
 
     let size = entity_dimensions(ecs, asset_manager, entity, grid_size);
-    let draw_pos = pivot_adjusted_position(transform.position, size, transform.pivot);
-
-    ctx.draw_rectangle_lines(
-        draw_pos.x,
-        draw_pos.y,
-        size.x,
-        size.y,
-        thickness(grid_size) * 0.25,
-        color,
-    );
+    let corners = transform.world_corners(size);
+
+    // Outline the scaled and rotated rectangle edge by edge
+    for i in 0..corners.len() {
+        let from = corners[i];
+        let to = corners[(i + 1) % corners.len()];
+        ctx.draw_line(
+            from.x,
+            from.y,
+            to.x,
+            to.y,
+            thickness(grid_size) * 0.25,
+            color,
+        );
+    }
 }
 
 /// Draw the outline of the collider for an entity if it has one.
@@ -409,6 +414,53 @@ pub fn draw_light_placeholders(ctx: &mut WgpuContext, ecs: &Ecs, room_id: RoomId
     }
 }
 
+/// Draw an icon for a `ParticleEmitter` that has no other visual component.
+pub fn draw_particle_placeholders(
+    ctx: &mut WgpuContext,
+    ecs: &Ecs,
+    room_id: RoomId,
+    grid_size: f32,
+) {
+    let room_store = ecs.get_store::<CurrentRoom>();
+    for (entity, _emitter) in ecs.get_store::<ParticleEmitter>().data.iter() {
+        // Only draw placeholders in this room
+        if let Some(CurrentRoom(id)) = room_store.get(*entity) {
+            if *id != room_id {
+                continue;
+            }
+        }
+
+        // Don't draw if there is a Sprite or Animation component
+        if ecs.has_any::<(Sprite, Animation)>(*entity) {
+            continue;
+        }
+
+        if let Some(position) = ecs.get_store::<Transform>().get(*entity) {
+            let pos = position.position;
+
+            let half_tile = grid_size * 0.5;
+            let body = Rect::new(pos.x - half_tile, pos.y - half_tile, grid_size, grid_size);
+
+            let cyan = Color::new(0.0, 0.78, 0.78, PLACEHOLDER_OPACITY);
+            let orange = Color::new(1.0, 0.6, 0.1, PLACEHOLDER_OPACITY);
+
+            // Outer square
+            ctx.draw_rectangle_lines(body.x, body.y, body.w, body.h, thickness(grid_size), cyan);
+
+            // Sparks
+            let spark_radius = grid_size * 0.08;
+            for offset in [vec2(0.3, 0.65), vec2(0.5, 0.35), vec2(0.7, 0.6)] {
+                ctx.draw_circle(
+                    body.x + body.w * offset.x,
+                    body.y + body.h * offset.y,
+                    spark_radius,
+                    orange,
+                );
+            }
+        }
+    }
+}
+
 /// Draw a placeholder for a `Glow` that has no other visual component.
 pub fn draw_glow_placeholders(
     ctx: &mut WgpuContext,
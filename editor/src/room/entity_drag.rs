@@ -20,7 +20,6 @@ impl RoomEditor {
         asset_manager: &mut AssetManager,
         grid_size: f32,
     ) -> bool {
-        let mouse_screen: Vec2 = ctx.mouse_position().into();
         let ui_was_clicked = self.ui_was_clicked(ctx);
         let shift_held =
             ctx.is_key_down(KeyCode::LeftShift) || ctx.is_key_down(KeyCode::RightShift);
@@ -42,16 +41,14 @@ impl RoomEditor {
                 if !can_select_entity_in_room(ecs, *entity, room_id) {
                     continue;
                 }
-                let hitbox = entity_hitbox(
-                    ctx,
+                if entity_contains_point(
                     *entity,
                     pos.position,
-                    camera,
+                    mouse_world,
                     ecs,
                     asset_manager,
                     grid_size,
-                );
-                if hitbox.contains(mouse_screen) {
+                ) {
                     let z = layer_store.get(*entity).map_or(0, |l| l.z);
                     let is_camera = camera_store.get(*entity).is_some();
                     candidates.push((*entity, z, is_camera));
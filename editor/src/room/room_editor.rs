@@ -147,6 +147,7 @@ impl RoomEditor {
         let delta_time = ctx.get_frame_time();
 
         update_animation_sytem(ctx, ecs, asset_manager, delta_time, room.id);
+        update_particles(ecs, room.id, delta_time);
 
         match self.mode {
             RoomEditorMode::Tilemap => {
@@ -305,6 +306,7 @@ impl RoomEditor {
                         draw_exit_placeholders(ctx, &room.exits, room.position, grid_size);
                         draw_camera_placeholders(ctx, ecs, room_id, grid_size);
                         draw_light_placeholders(ctx, ecs, room_id, grid_size);
+                        draw_particle_placeholders(ctx, ecs, room_id, grid_size);
                         draw_glow_placeholders(ctx, ecs, asset_manager, room_id, grid_size);
                         draw_interactable_ranges(ctx, ecs, room_id, grid_size);
 
@@ -1,7 +1,6 @@
 // editor/src/room/selection.rs
 use crate::app::SubEditor;
 use crate::room::room_editor::*;
-use crate::world::coord;
 use bishop::prelude::*;
 use engine_core::prelude::*;
 use std::collections::HashSet;
@@ -120,46 +119,33 @@ impl RoomEditor {
     }
 }
 
-/// Returns a `Rect` hitbox for an entity based on its sprite if it has one,
-/// otherwise it returns a hitbox based on the default sprite dimensions.
-pub fn entity_hitbox(
-    ctx: &WgpuContext,
+/// Returns true if the world-space `point` hits the entity's visual, following
+/// its pivot, scale and rotation. Entities without a visual use the default
+/// sprite dimensions.
+pub fn entity_contains_point(
     entity: Entity,
     position: Vec2,
-    camera: &Camera2D,
+    point: Vec2,
     ecs: &Ecs,
     asset_manager: &mut AssetManager,
     grid_size: f32,
-) -> Rect {
+) -> bool {
     let size = entity_dimensions(ecs, asset_manager, entity, grid_size);
 
     // Only use the center-offset for pure placeholder entities (Camera/Light without sprites)
     let is_pure_placeholder = ecs.has::<RoomCamera>(entity)
         || (ecs.has::<Light>(entity) && !ecs.has_any::<(Sprite, Animation, CurrentFrame)>(entity));
 
-    let corrected_pos = if is_pure_placeholder {
-        position - vec2(grid_size * 0.5, grid_size * 0.5)
-    } else {
-        // Apply pivot offset for regular entities
-        let pivot = ecs
-            .get_store::<Transform>()
-            .get(entity)
-            .map(|t| t.pivot)
-            .unwrap_or(Pivot::TopLeft);
-        pivot_adjusted_position(position, size, pivot)
-    };
-
-    // Convert the two opposite corners of the entity to screen coords
-    let top_left = coord::world_to_screen(ctx, camera, corrected_pos);
-    let bottom_right = coord::world_to_screen(ctx, camera, corrected_pos + size);
-
-    // Build the rectangle from those screen‑space points
-    let rect_x = top_left.x.min(bottom_right.x);
-    let rect_y = top_left.y.min(bottom_right.y);
-    let rect_w = (bottom_right.x - top_left.x).abs();
-    let rect_h = (bottom_right.y - top_left.y).abs();
-
-    Rect::new(rect_x, rect_y, rect_w, rect_h)
+    if is_pure_placeholder {
+        let corrected_pos = position - vec2(grid_size * 0.5, grid_size * 0.5);
+        return Rect::new(corrected_pos.x, corrected_pos.y, size.x, size.y).contains(point);
+    }
+
+    Transform {
+        position,
+        ..entity_transform(ecs, entity)
+    }
+    .contains_point(size, point)
 }
 
 /// Returns a world-space Rect for an entity based on its sprite or placeholder size.
@@ -175,18 +161,33 @@ pub fn entity_world_rect(
     let is_placeholder = ecs.has::<RoomCamera>(entity)
         || (ecs.has::<Light>(entity) && !ecs.has_any::<(Sprite, Animation, CurrentFrame)>(entity));
 
-    let corrected_pos = if is_placeholder {
-        position - vec2(grid_size * 0.5, grid_size * 0.5)
+    if is_placeholder {
+        let corrected_pos = position - vec2(grid_size * 0.5, grid_size * 0.5);
+        Rect::new(corrected_pos.x, corrected_pos.y, size.x, size.y)
     } else {
-        let pivot = ecs
-            .get_store::<Transform>()
-            .get(entity)
-            .map(|t| t.pivot)
-            .unwrap_or(Pivot::TopLeft);
-        pivot_adjusted_position(position, size, pivot)
-    };
-
-    Rect::new(corrected_pos.x, corrected_pos.y, size.x, size.y)
+        transformed_world_rect(ecs, entity, position, size)
+    }
+}
+
+/// Returns the world bounds of an entity's visual after its pivot, scale
+/// and rotation, placed at `position`.
+fn transformed_world_rect(ecs: &Ecs, entity: Entity, position: Vec2, size: Vec2) -> Rect {
+    Transform {
+        position,
+        ..entity_transform(ecs, entity)
+    }
+    .world_bounds(size)
+}
+
+/// Returns the entity's transform, or a top-left pivoted one if it has none.
+fn entity_transform(ecs: &Ecs, entity: Entity) -> Transform {
+    ecs.get_store::<Transform>()
+        .get(entity)
+        .copied()
+        .unwrap_or(Transform {
+            pivot: Pivot::TopLeft,
+            ..Default::default()
+        })
 }
 
 /// Returns true if an entity can be selected in a room (is in the room).
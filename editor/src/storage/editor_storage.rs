@@ -45,6 +45,7 @@ pub fn create_new_game(name: String) -> Game {
         asset_manager,
         script_manager,
         text_manager: TextManager::default(),
+        tweens: TweenManager::default(),
         current_world_id: WorldId(Uuid::nil()),
         game_map: GameMap::default(),
         next_room_id: 0,
@@ -340,6 +341,7 @@ pub fn create_new_world(game: &mut Game) -> World {
         starting_position: Some(room_origin),
         meta: WorldMeta::default(),
         grid_size: DEFAULT_GRID_SIZE,
+        room_transition: RoomTransition::default(),
     };
 
     let _spawn_point = game
@@ -480,6 +482,27 @@ pub fn save_menu(template: &MenuTemplate) -> io::Result<()> {
     fs::write(path, ron)
 }
 
+/// Saves the menu theme shared by every menu to disk.
+pub fn save_menu_theme(theme: &MenuTheme) -> io::Result<()> {
+    let path = menu_theme_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let pretty = ron::ser::PrettyConfig::new()
+        .separate_tuple_members(true)
+        .enumerate_arrays(true);
+
+    let ron = ron::ser::to_string_pretty(theme, pretty).map_err(Error::other)?;
+
+    fs::write(path, ron)
+}
+
+/// Loads the menu theme from disk. A missing file gives an empty theme.
+pub fn load_menu_theme() -> MenuTheme {
+    MenuTheme::load_from_path(&menu_theme_path())
+}
+
 /// Loads all menu templates from disk.
 pub fn load_menus() -> Vec<MenuTemplate> {
     let dir = menus_folder();
@@ -509,3 +532,108 @@ pub fn delete_menu(id: &str) -> io::Result<()> {
     }
     Ok(())
 }
+
+/// Saves a dialogue graph to disk.
+pub fn save_dialogue_graph(graph: &DialogueGraph) -> io::Result<()> {
+    let path = dialogue_path(&graph.id).map_err(Error::other)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let pretty = ron::ser::PrettyConfig::new()
+        .separate_tuple_members(true)
+        .enumerate_arrays(true);
+
+    let ron = ron::ser::to_string_pretty(graph, pretty).map_err(Error::other)?;
+
+    fs::write(path, ron)
+}
+
+/// Saves the speech bubble and dialogue settings to disk.
+pub fn save_dialogue_config(config: &DialogueConfig) -> io::Result<()> {
+    let path = dialogue_config_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let pretty = ron::ser::PrettyConfig::new()
+        .separate_tuple_members(true)
+        .enumerate_arrays(true);
+
+    let ron = ron::ser::to_string_pretty(config, pretty).map_err(Error::other)?;
+
+    fs::write(path, ron)
+}
+
+/// Loads all dialogue graphs from disk, including those in subfolders.
+pub fn load_dialogue_graphs() -> Vec<DialogueGraph> {
+    let mut ids = Vec::new();
+    collect_dialogue_ids(&dialogue_folder(), "", &mut ids);
+
+    ids.iter()
+        .filter_map(|id| match load_dialogue_graph(id) {
+            Ok(graph) => Some(graph),
+            Err(e) => {
+                onscreen_error!("{e}");
+                None
+            }
+        })
+        .collect()
+}
+
+fn collect_dialogue_ids(dir: &std::path::Path, prefix: &str, ids: &mut Vec<String>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        let Some(name) = path.file_stem().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let id = if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{prefix}/{name}")
+        };
+
+        if path.is_dir() {
+            collect_dialogue_ids(&path, &id, ids);
+        } else if path.extension().is_some_and(|ext| ext == "ron") {
+            ids.push(id);
+        }
+    }
+}
+
+/// Deletes a dialogue graph from disk.
+pub fn delete_dialogue_graph(id: &str) -> io::Result<()> {
+    let path = dialogue_path(id).map_err(Error::other)?;
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Loads a language's text file. Returns an empty file if it does not exist yet.
+pub fn load_text_file(language: &str, text_id: &str) -> io::Result<TextFile> {
+    let path = text_file_path(&text_folder(), language, text_id);
+    if !path.exists() {
+        return Ok(TextFile::default());
+    }
+
+    let content = fs::read_to_string(path)?;
+    toml::from_str(&content).map_err(Error::other)
+}
+
+/// Saves a language's text file to disk.
+pub fn save_text_file(language: &str, text_id: &str, text: &TextFile) -> io::Result<()> {
+    let path = text_file_path(&text_folder(), language, text_id);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Sort keys so saves produce stable diffs
+    let sorted: std::collections::BTreeMap<&String, &TextEntry> = text.entries.iter().collect();
+    let content = toml::to_string_pretty(&sorted).map_err(Error::other)?;
+    fs::write(path, content)
+}
@@ -1,14 +1,23 @@
 // editor/src/tilemap/background_module.rs
 use bishop::prelude::*;
 use engine_core::prelude::*;
+use std::collections::HashMap;
+
+const FIELD_H: f32 = 30.0;
+const ROW_H: f32 = 35.0;
+const LABEL_W: f32 = 90.0;
+const SPACING: f32 = 5.0;
 
-// TODO: Add more complex backgrounds
 /// Responsible for editing the background of a tilemap.
 pub struct BackgroundModule {
     pub r_id: WidgetId,
     pub g_id: WidgetId,
     pub b_id: WidgetId,
     pub a_id: WidgetId,
+    /// Index of the layer shown in the layer editor.
+    pub selected_layer: usize,
+    /// Widget ids of the layer fields, keyed by field name.
+    field_ids: HashMap<&'static str, WidgetId>,
 }
 
 impl BackgroundModule {
@@ -18,10 +27,19 @@ impl BackgroundModule {
             g_id: WidgetId::default(),
             b_id: WidgetId::default(),
             a_id: WidgetId::default(),
+            selected_layer: 0,
+            field_ids: HashMap::new(),
         }
     }
 
-    pub fn draw(&mut self, ctx: &mut WgpuContext, rect: Rect, map: &mut TileMap, blocked: bool) {
+    pub fn draw(
+        &mut self,
+        ctx: &mut WgpuContext,
+        rect: Rect,
+        map: &mut TileMap,
+        asset_manager: &mut AssetManager,
+        blocked: bool,
+    ) {
         // Title
         ctx.draw_text(
             "Background",
@@ -93,5 +111,226 @@ impl BackgroundModule {
             2.0,
             Color::WHITE,
         );
+
+        let layers_rect = Rect::new(rect.x, y + field_h + 20.0, rect.w - 20.0, rect.h);
+        self.draw_layers(ctx, layers_rect, map, asset_manager, blocked);
+    }
+
+    /// Layer list controls followed by the fields of the selected layer.
+    fn draw_layers(
+        &mut self,
+        ctx: &mut WgpuContext,
+        rect: Rect,
+        map: &mut TileMap,
+        asset_manager: &mut AssetManager,
+        blocked: bool,
+    ) {
+        let layers = &mut map.background_layers;
+        self.selected_layer = self.selected_layer.min(layers.len().saturating_sub(1));
+
+        let summary = if layers.is_empty() {
+            "Layers (none)".to_string()
+        } else {
+            format!("Layers {}/{}", self.selected_layer + 1, layers.len())
+        };
+        ctx.draw_text(
+            &summary,
+            rect.x,
+            rect.y + 18.0,
+            DEFAULT_FONT_SIZE_16,
+            Color::WHITE,
+        );
+
+        // Back to front: "<" ">" select, "^" "v" reorder, "+" add, "x" remove
+        let btn = FIELD_H;
+        let mut x = rect.x + rect.w - 6.0 * (btn + SPACING);
+        let y = rect.y + 28.0;
+        let mut button = |label: &str| {
+            let pressed = Button::new(Rect::new(x, y, btn, btn), label)
+                .blocked(blocked)
+                .show(ctx);
+            x += btn + SPACING;
+            pressed
+        };
+
+        let prev = button("<");
+        let next = button(">");
+        let back = button("^");
+        let front = button("v");
+        let add = button("+");
+        let remove = button("x");
+
+        let selected = self.selected_layer;
+        if prev {
+            self.selected_layer = selected.saturating_sub(1);
+        }
+        if next && selected + 1 < layers.len() {
+            self.selected_layer = selected + 1;
+        }
+        if back && selected > 0 && selected < layers.len() {
+            layers.swap(selected, selected - 1);
+            self.selected_layer = selected - 1;
+        }
+        if front && selected + 1 < layers.len() {
+            layers.swap(selected, selected + 1);
+            self.selected_layer = selected + 1;
+        }
+        if add {
+            layers.push(BackgroundLayer {
+                name: format!("Layer {}", layers.len() + 1),
+                ..Default::default()
+            });
+            self.selected_layer = layers.len() - 1;
+        }
+        if remove && selected < layers.len() {
+            let layer = layers.remove(selected);
+            asset_manager.decrement_ref(layer.sprite);
+            self.selected_layer = selected.saturating_sub(1);
+        }
+
+        let Some(layer) = layers.get_mut(self.selected_layer) else {
+            return;
+        };
+        let fields_rect = Rect::new(rect.x, y + btn + 10.0, rect.w, rect.h);
+        self.draw_layer_fields(ctx, fields_rect, layer, asset_manager, blocked);
+    }
+
+    /// One labelled row per layer setting.
+    fn draw_layer_fields(
+        &mut self,
+        ctx: &mut WgpuContext,
+        rect: Rect,
+        layer: &mut BackgroundLayer,
+        asset_manager: &mut AssetManager,
+        blocked: bool,
+    ) {
+        let mut y = rect.y;
+        let widget_x = rect.x + LABEL_W;
+        let widget_w = rect.w - LABEL_W;
+        let full = |y: f32| Rect::new(widget_x, y, widget_w, FIELD_H);
+        let half = |y: f32, i: f32| {
+            let w = (widget_w - SPACING) / 2.0;
+            Rect::new(widget_x + i * (w + SPACING), y, w, FIELD_H)
+        };
+
+        // Name
+        label(ctx, "Name", rect.x, y);
+        let (name, _) = TextInput::new(self.id("name"), full(y), &layer.name)
+            .blocked(blocked)
+            .show(ctx);
+        layer.name = name;
+        y += ROW_H;
+
+        // Visible
+        label(ctx, "Visible", rect.x, y);
+        checkbox(ctx, widget_x, y, &mut layer.visible, blocked);
+        y += ROW_H;
+
+        // Image
+        label(ctx, "Image", rect.x, y);
+        gui_sprite_picker(ctx, full(y), &mut layer.sprite, asset_manager, blocked);
+        y += ROW_H;
+
+        for (name, keys, value) in [
+            (
+                "Parallax",
+                ["parallax.x", "parallax.y"],
+                &mut layer.parallax,
+            ),
+            ("Offset", ["offset.x", "offset.y"], &mut layer.offset),
+            (
+                "Scroll",
+                ["scroll.x", "scroll.y"],
+                &mut layer.scroll_velocity,
+            ),
+        ] {
+            label(ctx, name, rect.x, y);
+            value.x = NumberInput::new(self.id(keys[0]), half(y, 0.0), value.x)
+                .blocked(blocked)
+                .show(ctx);
+            value.y = NumberInput::new(self.id(keys[1]), half(y, 1.0), value.y)
+                .blocked(blocked)
+                .show(ctx);
+            y += ROW_H;
+        }
+
+        // Scale
+        label(ctx, "Scale", rect.x, y);
+        layer.scale = NumberInput::new(self.id("scale"), full(y), layer.scale)
+            .blocked(blocked)
+            .min(0.0)
+            .show(ctx);
+        y += ROW_H;
+
+        // Repeat
+        label(ctx, "Repeat X/Y", rect.x, y);
+        checkbox(ctx, widget_x, y, &mut layer.repeat_x, blocked);
+        checkbox(ctx, half(y, 1.0).x, y, &mut layer.repeat_y, blocked);
+        y += ROW_H;
+
+        // Sprite sheet
+        label(ctx, "Sheet", rect.x, y);
+        layer.sheet_columns =
+            NumberInput::new(self.id("columns"), half(y, 0.0), layer.sheet_columns)
+                .blocked(blocked)
+                .min(1)
+                .show(ctx);
+        layer.sheet_rows = NumberInput::new(self.id("rows"), half(y, 1.0), layer.sheet_rows)
+            .blocked(blocked)
+            .min(1)
+            .show(ctx);
+        y += ROW_H;
+
+        label(ctx, "Sheet FPS", rect.x, y);
+        layer.sheet_fps = NumberInput::new(self.id("fps"), full(y), layer.sheet_fps)
+            .blocked(blocked)
+            .min(0.0)
+            .show(ctx);
+        y += ROW_H;
+
+        // Gradient
+        label(ctx, "Gradient", rect.x, y);
+        let mut has_gradient = layer.gradient.is_some();
+        if checkbox(ctx, widget_x, y, &mut has_gradient, blocked) {
+            layer.gradient = has_gradient.then(BackgroundGradient::default);
+        }
+        y += ROW_H;
+
+        if let Some(gradient) = &mut layer.gradient {
+            label(ctx, "Top", rect.x, y);
+            gradient.top = ColorInput::new(self.id("top"), full(y), gradient.top)
+                .blocked(blocked)
+                .show(ctx);
+            y += ROW_H;
+
+            label(ctx, "Bottom", rect.x, y);
+            gradient.bottom = ColorInput::new(self.id("bottom"), full(y), gradient.bottom)
+                .blocked(blocked)
+                .show(ctx);
+        }
+    }
+
+    fn id(&mut self, key: &'static str) -> WidgetId {
+        *self.field_ids.entry(key).or_default()
+    }
+}
+
+fn label(ctx: &mut WgpuContext, text: &str, x: f32, y: f32) {
+    ctx.draw_text(text, x, y + 20.0, DEFAULT_FONT_SIZE_16, FIELD_TEXT_COLOR);
+}
+
+/// Draws a checkbox at the start of a row, returning true when toggled.
+fn checkbox(ctx: &mut WgpuContext, x: f32, y: f32, value: &mut bool, blocked: bool) -> bool {
+    let rect = Rect::new(
+        x,
+        y + (FIELD_H - DEFAULT_CHECKBOX_DIMS) / 2.0,
+        DEFAULT_CHECKBOX_DIMS,
+        DEFAULT_CHECKBOX_DIMS,
+    );
+    let mut toggled = *value;
+    if gui_checkbox(ctx, rect, &mut toggled) && !blocked {
+        *value = toggled;
+        return true;
     }
+    false
 }
@@ -0,0 +1,111 @@
+// editor/src/tilemap/exit_module.rs
+use bishop::prelude::*;
+use engine_core::prelude::*;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+const FIELD_H: f32 = 30.0;
+const SPACING: f32 = 5.0;
+
+/// Choice in the exit transition dropdown.
+#[derive(Clone, Copy, PartialEq)]
+enum TransitionOption {
+    /// Play the world's room transition.
+    WorldDefault,
+    Override(TransitionStyle),
+}
+
+impl Display for TransitionOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::WorldDefault => f.write_str("World Default"),
+            Self::Override(style) => f.write_str(style.label()),
+        }
+    }
+}
+
+/// Responsible for editing the room transition override of the selected exit.
+pub struct ExitModule {
+    style_id: WidgetId,
+    duration_id: WidgetId,
+    color_id: WidgetId,
+}
+
+impl ExitModule {
+    pub fn new() -> Self {
+        Self {
+            style_id: WidgetId::default(),
+            duration_id: WidgetId::default(),
+            color_id: WidgetId::default(),
+        }
+    }
+
+    /// Draws the transition fields of `exit`. Without an override the exit
+    /// plays the world's room transition.
+    pub fn draw(&mut self, ctx: &mut WgpuContext, rect: Rect, exit: &mut Exit, blocked: bool) {
+        // Title
+        ctx.draw_text(
+            "Exit Transition",
+            rect.x,
+            rect.y + 18.0,
+            DEFAULT_FONT_SIZE_16,
+            Color::WHITE,
+        );
+
+        let width = rect.w - 20.0;
+        let mut y = rect.y + 30.0;
+
+        let options: Vec<TransitionOption> = std::iter::once(TransitionOption::WorldDefault)
+            .chain(
+                TransitionStyle::all()
+                    .iter()
+                    .map(|style| TransitionOption::Override(*style)),
+            )
+            .collect();
+        let current = exit
+            .transition
+            .map_or(TransitionOption::WorldDefault, |transition| {
+                TransitionOption::Override(transition.style)
+            });
+        if let Some(choice) = Dropdown::new(
+            self.style_id,
+            Rect::new(rect.x, y, width, FIELD_H),
+            &current.to_string(),
+            &options,
+            |option| option.to_string(),
+        )
+        .fixed_width()
+        .blocked(blocked)
+        .show(ctx)
+        {
+            exit.transition = match choice {
+                TransitionOption::WorldDefault => None,
+                TransitionOption::Override(style) => Some(RoomTransition {
+                    style,
+                    ..exit.transition.unwrap_or_default()
+                }),
+            };
+        }
+
+        let Some(transition) = &mut exit.transition else {
+            return;
+        };
+        y += FIELD_H + SPACING;
+
+        let half_w = (width - SPACING) / 2.0;
+        transition.duration = NumberInput::new(
+            self.duration_id,
+            Rect::new(rect.x, y, half_w, FIELD_H),
+            transition.duration,
+        )
+        .min(0.0)
+        .blocked(blocked)
+        .show(ctx);
+        transition.color = ColorInput::new(
+            self.color_id,
+            Rect::new(rect.x + half_w + SPACING, y, half_w, FIELD_H),
+            transition.color,
+        )
+        .blocked(blocked)
+        .show(ctx);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod background_module;
+pub mod exit_module;
 pub mod resize_handle;
 pub mod tile_palette;
 pub mod tilemap_editor;
@@ -59,6 +59,8 @@ pub struct TileMapEditor {
     ui_was_clicked: bool,
     initialized: bool,
     adjacent_exits: Vec<(Vec2, ExitDirection)>,
+    /// Position of the exit shown in the panel while in Exits mode.
+    selected_exit: Option<Vec2>,
     /// Rect of the sub-mode strip for UI blocking.
     pub sub_mode_rect: Option<Rect>,
 }
@@ -74,6 +76,7 @@ impl TileMapEditor {
             ui_was_clicked: false,
             initialized: false,
             adjacent_exits: Vec::new(),
+            selected_exit: None,
             sub_mode_rect: None,
         }
     }
@@ -290,16 +293,24 @@ impl TileMapEditor {
             let exit_direction = self.exit_direction_from_position(tile_pos, map);
             let exit_vec = vec2(tile_pos.x() as f32, tile_pos.y() as f32);
 
+            // Clicking an existing exit selects it for the panel
             if ctx.is_mouse_button_pressed(MouseButton::Left) {
-                exits.push(Exit {
-                    position: exit_vec,
-                    direction: exit_direction,
-                    target_room_id: None,
-                });
+                if !exits.iter().any(|exit| exit.position == exit_vec) {
+                    exits.push(Exit {
+                        position: exit_vec,
+                        direction: exit_direction,
+                        target_room_id: None,
+                        transition: None,
+                    });
+                }
+                self.selected_exit = Some(exit_vec);
             }
 
             if ctx.is_mouse_button_pressed(MouseButton::Right) {
                 exits.retain(|exit| exit.position != exit_vec);
+                if self.selected_exit == Some(exit_vec) {
+                    self.selected_exit = None;
+                }
             }
         }
     }
@@ -317,27 +328,21 @@ impl TileMapEditor {
         let tilemap = &mut room.variants[variant_index].tilemap;
         let room_position = room.position;
         let room_id = room.id;
-        let room_size = room.size;
 
         ctx.clear_background(Color::BLACK);
         ctx.set_camera(camera);
+        tilemap.draw_background(ctx, asset_manager, room_position, grid_size, camera);
         tilemap.draw(ctx, asset_manager, room_position, grid_size);
         draw_exit_placeholders(ctx, &room.exits, room_position, grid_size);
         self.draw_adjacent_exits(ctx, grid_size);
         self.draw_hover_highlight(ctx, camera, tilemap, room_position, grid_size);
+        self.draw_selected_exit(ctx, room_position, grid_size);
 
         if self.active_handle_index.is_some() {
             draw_all_camera_viewports(ctx, camera, ecs, room_id);
         }
 
-        self.draw_ui(
-            ctx,
-            camera,
-            asset_manager,
-            tilemap,
-            Rect::new(room_position.x, room_position.y, room_size.x, room_size.y),
-            grid_size,
-        );
+        self.draw_ui(ctx, camera, asset_manager, room, grid_size);
     }
 
     /// Draws exits from adjacent rooms that face toward this room (only in Exits mode).
@@ -391,15 +396,36 @@ impl TileMapEditor {
         }
     }
 
+    /// Outlines the exit shown in the panel (only in Exits mode).
+    fn draw_selected_exit(&self, ctx: &mut WgpuContext, room_position: Vec2, grid_size: f32) {
+        let Some(position) = self.selected_exit else {
+            return;
+        };
+        if !matches!(self.mode, TilemapEditorMode::Exits) {
+            return;
+        }
+
+        let top_left = room_position + position * grid_size;
+        ctx.draw_rectangle_lines(
+            top_left.x,
+            top_left.y,
+            grid_size,
+            grid_size,
+            thickness(grid_size),
+            Color::YELLOW,
+        );
+    }
+
     fn draw_ui(
         &mut self,
         ctx: &mut WgpuContext,
         camera: &Camera2D,
         asset_manager: &mut AssetManager,
-        tilemap: &mut TileMap,
-        room_rect: Rect,
+        room: &mut Room,
         grid_size: f32,
     ) {
+        let room_rect = Rect::new(room.position.x, room.position.y, room.size.x, room.size.y);
+
         // Draw resize handles and preview
         for (i, handle) in self.resize_handles.iter().enumerate() {
             let is_active = self.active_handle_index == Some(i);
@@ -423,8 +449,17 @@ impl TileMapEditor {
         // Top menu background
         draw_top_panel_full(ctx);
 
-        // Draw inspector panel
-        self.tilemap_panel.draw(ctx, asset_manager, tilemap);
+        // Draw inspector panel, with the selected exit while in Exits mode
+        let variant_index = room.current_variant_index();
+        let tilemap = &mut room.variants[variant_index].tilemap;
+        let selected_exit = match self.mode {
+            TilemapEditorMode::Exits => self
+                .selected_exit
+                .and_then(|pos| room.exits.iter_mut().find(|exit| exit.position == pos)),
+            TilemapEditorMode::Tiles => None,
+        };
+        self.tilemap_panel
+            .draw(ctx, asset_manager, tilemap, selected_exit);
     }
 
     fn get_hovered_tile(
@@ -516,6 +551,7 @@ impl TileMapEditor {
         self.active_handle_index = None;
         self.resize_handles.clear();
         self.adjacent_exits.clear();
+        self.selected_exit = None;
         self.sub_mode_rect = None;
     }
 }
@@ -2,6 +2,7 @@
 use crate::gui::gui_constants::*;
 use crate::gui::panels::panel_manager::is_mouse_over_panel;
 use crate::tilemap::background_module::BackgroundModule;
+use crate::tilemap::exit_module::ExitModule;
 use crate::tilemap::tile_palette::TilePalette;
 use crate::tilemap::tile_palette::*;
 use bishop::prelude::*;
@@ -18,6 +19,8 @@ pub struct TilemapPanel {
     pub palette: TilePalette,
     /// Module responsible for editing the map background.
     pub background: BackgroundModule,
+    /// Module responsible for editing the selected exit.
+    pub exit: ExitModule,
     /// Rectangles that were drawn this frame and are therefore active.
     active_rects: Vec<Rect>,
 }
@@ -27,6 +30,7 @@ impl TilemapPanel {
     pub fn new() -> Self {
         let palette = TilePalette::new();
         let background = BackgroundModule::new();
+        let exit = ExitModule::new();
 
         // TODO: Add other modules
 
@@ -34,6 +38,7 @@ impl TilemapPanel {
             rect: Rect::new(0., 0., 0., 0.),
             palette,
             background,
+            exit,
             active_rects: Vec::new(),
         }
     }
@@ -47,12 +52,14 @@ impl TilemapPanel {
         self.rect = rect;
     }
 
-    /// Render the panel and any visible sub‑modules
+    /// Render the panel and any visible sub‑modules. A selected `exit` replaces
+    /// the palette and background modules.
     pub fn draw(
         &mut self,
         ctx: &mut WgpuContext,
         asset_manager: &mut AssetManager,
         tilemap: &mut TileMap,
+        exit: Option<&mut Exit>,
     ) {
         self.active_rects.clear();
 
@@ -97,17 +104,23 @@ impl TilemapPanel {
 
         let blocked = is_mouse_over_panel(ctx);
 
-        // Palette
-        self.palette.set_columns_for_width(inner.w - 20.0);
-        let height = self.palette.height();
-        let palette_rect = Rect::new(inner.x + 10.0, y, inner.w, height);
-        self.palette.draw(ctx, palette_rect, asset_manager);
-
-        y += height + 20.0; // Create gap for next module
-
-        // Background module
-        let background_rect = Rect::new(inner.x + 10.0, y, inner.w, height);
-        self.background.draw(ctx, background_rect, tilemap, blocked);
+        if let Some(exit) = exit {
+            let exit_rect = Rect::new(inner.x + 10.0, y, inner.w, inner.h);
+            self.exit.draw(ctx, exit_rect, exit, blocked);
+        } else {
+            // Palette
+            self.palette.set_columns_for_width(inner.w - 20.0);
+            let height = self.palette.height();
+            let palette_rect = Rect::new(inner.x + 10.0, y, inner.w, height);
+            self.palette.draw(ctx, palette_rect, asset_manager);
+
+            y += height + 20.0; // Create gap for next module
+
+            // Background module
+            let background_rect = Rect::new(inner.x + 10.0, y, inner.w, height);
+            self.background
+                .draw(ctx, background_rect, tilemap, asset_manager, blocked);
+        }
 
         // Draw create button
         if Button::new(create_rect, create_label)
@@ -21,11 +21,6 @@ pub fn mouse_world_grid(ctx: &WgpuContext, camera: &Camera2D, grid_size: f32) ->
     (world / grid_size).floor()
 }
 
-/// Turn a world‑space `Vec2` into screen coordinates using the current camera.
-pub fn world_to_screen(ctx: &WgpuContext, camera: &Camera2D, world_pos: Vec2) -> Vec2 {
-    camera.world_to_screen(world_pos, ctx.screen_width(), ctx.screen_height())
-}
-
 /// Check if a room overlaps with existing rooms.
 /// pos and size should be in tile coordinates.
 /// other_bounds contains (position in pixels, size in tiles).
@@ -1,6 +1,7 @@
 // engine_core/src/animation/animation_clip.rs
 use crate::assets::asset_manager::AssetManager;
 use crate::assets::sprite::SpriteId;
+use crate::audio::audio_source::SoundGroupId;
 use crate::constants::DEFAULT_GRID_SIZE;
 use crate::ecs::entity::Entity;
 use crate::game::*;
@@ -18,7 +19,8 @@ use strum_macros::EnumIter;
 
 /// The animation component for an entity.
 #[ecs_component(post_create = post_create, post_remove = post_remove)]
-#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde_as]
+#[derive(Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Animation {
     /// Defines the animations that belong to the entity.
@@ -44,6 +46,26 @@ pub struct Animation {
     /// Playback speed multiplier (runtime state, defaults to 1.0).
     #[serde(skip)]
     pub speed_multiplier: f32,
+    /// Color multiplied with every frame.
+    #[serde_as(as = "FromInto<[f32; 3]>")]
+    pub tint: Vec3,
+    pub opacity: f32,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self {
+            clips: HashMap::new(),
+            variant: VariantFolder::default(),
+            current: None,
+            states: HashMap::new(),
+            sprite_cache: HashMap::new(),
+            flip_x: false,
+            speed_multiplier: 1.0,
+            tint: Vec3::ONE,
+            opacity: 1.0,
+        }
+    }
 }
 
 impl Animation {
@@ -208,6 +230,20 @@ impl ClipId {
             _ => format!("{self:?}"),
         }
     }
+
+    /// Maps a clip name (an Aseprite tag or file stem) to a `ClipId`.
+    /// Built-in names match case-insensitively; anything else is `Custom`.
+    pub fn from_name(name: &str) -> ClipId {
+        match name.to_ascii_lowercase().as_str() {
+            "idle" => ClipId::Idle,
+            "walk" => ClipId::Walk,
+            "run" => ClipId::Run,
+            "attack" => ClipId::Attack,
+            "jump" => ClipId::Jump,
+            "fall" => ClipId::Fall,
+            _ => ClipId::Custom(name.to_string()),
+        }
+    }
 }
 
 impl fmt::Display for ClipId {
@@ -218,7 +254,7 @@ impl fmt::Display for ClipId {
 
 /// Definition for an animation set.
 #[serde_as]
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ClipDef {
     /// Width and height of a single cell.
@@ -239,6 +275,88 @@ pub struct ClipDef {
     pub offset: Vec2,
     /// Whether to auto-flip based on FacingDirection component.
     pub mirrored: bool,
+    /// Order in which the frames are played.
+    pub direction: PlaybackDirection,
+    /// Named regions (pivots, hitboxes) imported from Aseprite slices.
+    pub slices: Vec<ClipSlice>,
+    /// Events raised when playback enters a frame.
+    pub events: Vec<FrameEvent>,
+}
+
+impl ClipDef {
+    /// Number of frames in the clip.
+    pub fn frame_count(&self) -> usize {
+        (self.cols * self.rows).max(1)
+    }
+
+    /// Returns the key of the named slice that applies to `frame`.
+    pub fn slice(&self, name: &str, frame: usize) -> Option<&SliceKey> {
+        self.slices
+            .iter()
+            .find(|s| s.name == name)
+            .and_then(|s| s.key_at(frame))
+    }
+
+    /// Duration of `frame` in seconds.
+    pub fn frame_time(&self, frame: usize) -> f32 {
+        let uniform = 1.0 / self.fps.max(0.001);
+        if self.frame_durations.is_empty() {
+            uniform
+        } else {
+            self.frame_durations.get(frame).copied().unwrap_or(uniform)
+        }
+    }
+
+    /// Frame shown `time` seconds after playback started from the first step.
+    /// Non-looping clips hold their last frame.
+    pub fn frame_at(&self, time: f32) -> usize {
+        let frame_count = self.frame_count();
+        let cycle_len = self.direction.cycle_len(frame_count);
+        let step_frame = |step| self.direction.frame_index(step, frame_count);
+        let cycle_time: f32 = (0..cycle_len).map(|s| self.frame_time(step_frame(s))).sum();
+        if cycle_time <= 0.0 {
+            return step_frame(0);
+        }
+
+        let mut remaining = if self.looping {
+            time.max(0.0) % cycle_time
+        } else {
+            time.max(0.0)
+        };
+        for step in 0..cycle_len {
+            let frame = step_frame(step);
+            remaining -= self.frame_time(frame);
+            if remaining < 0.0 {
+                return frame;
+            }
+        }
+        step_frame(cycle_len - 1)
+    }
+
+    /// Playback cycles that have elapsed, counting completed loops, 1.0 once finished.
+    pub fn progress(&self, state: &ClipState) -> f32 {
+        if state.finished {
+            return 1.0;
+        }
+        let frame_count = self.frame_count();
+        let frame = self.direction.frame_index(state.step, frame_count);
+        let partial = (state.timer / self.frame_time(frame)).min(1.0);
+        let cycle = (state.step as f32 + partial) / self.direction.cycle_len(frame_count) as f32;
+        state.loops as f32 + cycle
+    }
+
+    /// Returns the events placed on `frame`.
+    pub fn events_at(&self, frame: usize) -> impl Iterator<Item = &FrameEvent> {
+        self.events.iter().filter(move |e| e.frame == frame)
+    }
+
+    /// Returns the first slice pivot that applies to `frame`.
+    pub fn pivot(&self, frame: usize) -> Option<Vec2> {
+        self.slices
+            .iter()
+            .filter_map(|s| s.key_at(frame))
+            .find_map(|k| k.pivot)
+    }
 }
 
 impl Default for ClipDef {
@@ -252,10 +370,107 @@ impl Default for ClipDef {
             looping: true,
             offset: Vec2::ZERO,
             mirrored: false,
+            direction: PlaybackDirection::Forward,
+            slices: Vec::new(),
+            events: Vec::new(),
         }
     }
 }
 
+/// A named event raised when playback enters a clip frame.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FrameEvent {
+    /// Frame index within the clip.
+    pub frame: usize,
+    /// Event name passed to scripts.
+    pub name: String,
+    /// Sound group of the entity's `AudioSource` played with the event.
+    pub sound: Option<SoundGroupId>,
+}
+
+/// Order in which the frames of a clip are played.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlaybackDirection {
+    #[default]
+    Forward,
+    Reverse,
+    /// Forward then back, without repeating the end frames.
+    PingPong,
+    /// Backward then forward, without repeating the end frames.
+    PingPongReverse,
+}
+
+impl PlaybackDirection {
+    /// Parses an Aseprite tag direction.
+    pub fn from_aseprite(direction: &str) -> Self {
+        match direction {
+            "reverse" => PlaybackDirection::Reverse,
+            "pingpong" => PlaybackDirection::PingPong,
+            "pingpong_reverse" => PlaybackDirection::PingPongReverse,
+            _ => PlaybackDirection::Forward,
+        }
+    }
+
+    /// Number of steps in one playback cycle of `frame_count` frames.
+    pub fn cycle_len(self, frame_count: usize) -> usize {
+        match self {
+            PlaybackDirection::PingPong | PlaybackDirection::PingPongReverse if frame_count > 1 => {
+                2 * frame_count - 2
+            }
+            _ => frame_count.max(1),
+        }
+    }
+
+    /// Maps a playback step to the frame index shown at that step.
+    pub fn frame_index(self, step: usize, frame_count: usize) -> usize {
+        let last = frame_count.max(1) - 1;
+        let step = step % self.cycle_len(frame_count);
+        match self {
+            PlaybackDirection::Forward => step,
+            PlaybackDirection::Reverse => last - step,
+            PlaybackDirection::PingPong => {
+                if step <= last {
+                    step
+                } else {
+                    2 * last - step
+                }
+            }
+            PlaybackDirection::PingPongReverse => last.abs_diff(step),
+        }
+    }
+}
+
+/// A named region of a clip whose bounds and pivot may change per frame.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClipSlice {
+    pub name: String,
+    /// Keys sorted by frame. A key applies until the next one.
+    pub keys: Vec<SliceKey>,
+}
+
+impl ClipSlice {
+    /// Returns the key that applies to `frame`.
+    pub fn key_at(&self, frame: usize) -> Option<&SliceKey> {
+        self.keys.iter().rev().find(|k| k.frame <= frame)
+    }
+}
+
+/// Slice bounds and pivot starting at a clip frame.
+#[serde_as]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SliceKey {
+    /// Clip-relative frame index.
+    pub frame: usize,
+    /// Bounds in frame pixels.
+    pub bounds: Rect,
+    /// Pivot in frame pixels.
+    #[serde_as(as = "Option<FromInto<[f32; 2]>>")]
+    pub pivot: Option<Vec2>,
+}
+
 /// A full set of clip definitions that can be reused.
 #[derive(Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
@@ -281,8 +496,14 @@ pub struct ClipState {
     pub col: usize,
     /// Current row index (0-based, relative to the clip's `rows`).
     pub row: usize,
+    /// Current step within the playback cycle of the clip's direction.
+    pub step: usize,
     /// Whether the clip has finished playing yet.
     pub finished: bool,
+    /// Whether the events of the first frame have been raised.
+    pub started: bool,
+    /// Playback cycles a looping clip has completed.
+    pub loops: usize,
 }
 
 /// Returns the `SpriteId` for the current variant clip.
@@ -303,20 +524,18 @@ pub fn resolve_sprite_id(
 }
 
 fn sprite_path(variant_folder: &VariantFolder, clip_id: &ClipId) -> Option<PathBuf> {
-    let filename = match clip_id {
-        ClipId::Idle => "Idle.png".to_string(),
-        ClipId::Walk => "Walk.png".to_string(),
-        ClipId::Run => "Run.png".to_string(),
-        ClipId::Attack => "Attack.png".to_string(),
-        ClipId::Jump => "Jump.png".to_string(),
-        ClipId::Fall => "Fall.png".to_string(),
-        ClipId::Custom(name) => format!("{name}.png"),
-        ClipId::New => return None,
-    };
-
+    let filename = sprite_file_name(clip_id)?;
     Some(Path::new(&variant_folder.0).join(filename))
 }
 
+/// Returns the spritesheet file name of a clip inside its variant folder.
+pub fn sprite_file_name(clip_id: &ClipId) -> Option<String> {
+    match clip_id {
+        ClipId::New => None,
+        _ => Some(format!("{}.png", clip_id.ui_label())),
+    }
+}
+
 /// Initializes the component when an entity is instantiated into the world.
 pub fn post_create(anim: &mut Animation, _entity: &Entity, ctx: &mut GameCtxMut) {
     anim.init_runtime();
@@ -414,6 +633,42 @@ mod tests {
     use std::collections::HashMap;
     use std::path::Path;
 
+    #[test]
+    fn playback_directions_map_steps_to_frames() {
+        let frames = |direction: PlaybackDirection| -> Vec<usize> {
+            (0..direction.cycle_len(4))
+                .map(|step| direction.frame_index(step, 4))
+                .collect()
+        };
+        assert_eq!(frames(PlaybackDirection::Forward), vec![0, 1, 2, 3]);
+        assert_eq!(frames(PlaybackDirection::Reverse), vec![3, 2, 1, 0]);
+        assert_eq!(frames(PlaybackDirection::PingPong), vec![0, 1, 2, 3, 2, 1]);
+        assert_eq!(
+            frames(PlaybackDirection::PingPongReverse),
+            vec![3, 2, 1, 0, 1, 2]
+        );
+        assert_eq!(PlaybackDirection::PingPong.cycle_len(1), 1);
+    }
+
+    #[test]
+    fn slice_keys_apply_until_the_next_key() {
+        let key = |frame: usize, x: f32| SliceKey {
+            frame,
+            bounds: Rect::new(x, 0.0, 4.0, 4.0),
+            pivot: None,
+        };
+        let clip = ClipDef {
+            slices: vec![ClipSlice {
+                name: "hitbox".to_string(),
+                keys: vec![key(0, 1.0), key(2, 5.0)],
+            }],
+            ..Default::default()
+        };
+        assert_eq!(clip.slice("hitbox", 1).map(|k| k.bounds.x), Some(1.0));
+        assert_eq!(clip.slice("hitbox", 3).map(|k| k.bounds.x), Some(5.0));
+        assert!(clip.slice("missing", 0).is_none());
+    }
+
     #[test]
     fn generate_animations_lua_marks_file_as_game_generated() {
         let lua = generate_animations_lua(&[]);
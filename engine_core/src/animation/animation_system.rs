@@ -2,11 +2,12 @@
 use crate::animation::animation_clip::*;
 use crate::assets::asset_manager::AssetManager;
 use crate::assets::sprite::SpriteId;
+use crate::audio::audio_source::AudioSource;
 use crate::ecs::component::PlayerProxy;
 use crate::ecs::ecs::Ecs;
 use crate::ecs::entity::Entity;
 use crate::rendering::render_room::pivot_adjusted_position;
-use crate::rendering::renderable::{EntityDrawParams, Renderable};
+use crate::rendering::renderable::{EntityDrawParams, Renderable, tint_color};
 use crate::worlds::room::RoomId;
 use crate::worlds::room::entities_in_room;
 use bishop::prelude::*;
@@ -15,7 +16,7 @@ use serde::{Deserialize, Serialize};
 
 /// Current frame data for rendering animated entities.
 #[ecs_component]
-#[derive(Clone, Default, Deserialize, Serialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct CurrentFrame {
     #[serde(skip)]
     pub clip_id: ClipId,
@@ -32,15 +33,52 @@ pub struct CurrentFrame {
     /// Whether to flip the sprite horizontally when rendering.
     #[serde(skip)]
     pub flip_x: bool,
+    /// Color the frame is drawn with, from the animation's tint and opacity.
+    #[serde(skip)]
+    pub tint: Color,
+    /// Slice pivot of the frame in frame pixels, drawn at the entity position.
+    #[serde(skip)]
+    pub pivot: Option<Vec2>,
+}
+
+impl Default for CurrentFrame {
+    fn default() -> Self {
+        Self {
+            clip_id: ClipId::default(),
+            col: 0,
+            row: 0,
+            offset: Vec2::ZERO,
+            sprite_id: SpriteId::default(),
+            frame_size: Vec2::ZERO,
+            flip_x: false,
+            pivot: None,
+            tint: Color::WHITE,
+        }
+    }
+}
+
+/// Animation playback events reported by `update_animation_sytem`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimationEvent {
+    /// Playback entered a frame that carries a frame event.
+    Frame {
+        entity: Entity,
+        clip_id: ClipId,
+        event: FrameEvent,
+    },
+    /// A non-looping clip played past its last frame.
+    ClipFinished { entity: Entity, clip_id: ClipId },
 }
 
+/// Advances the active clip of every animated entity in the room and returns
+/// the frame events entered this update, including frames skipped over by a long `dt`.
 pub fn update_animation_sytem(
     loader: &impl TextureLoader,
     ecs: &mut Ecs,
     asset_manager: &mut AssetManager,
     dt: f32,
     room_id: RoomId,
-) {
+) -> Vec<AnimationEvent> {
     // Gather the ids of all entities that are in the current room
     let mut entities = entities_in_room(ecs, room_id);
 
@@ -54,6 +92,7 @@ pub fn update_animation_sytem(
 
     let mut frames: Vec<(Entity, CurrentFrame)> = vec![];
     let mut to_remove: Vec<Entity> = vec![];
+    let mut events: Vec<AnimationEvent> = vec![];
 
     for (entity, animation) in anim_store.data.iter_mut() {
         if !entities.contains(entity) {
@@ -86,38 +125,23 @@ pub fn update_animation_sytem(
         };
         clip_state.timer += dt * speed;
 
-        loop {
-            let frame_index = clip_state.row * clip.cols + clip_state.col;
-            let frame_time = if !clip.frame_durations.is_empty() {
-                clip.frame_durations
-                    .get(frame_index)
-                    .copied()
-                    .unwrap_or(1.0 / clip.fps.max(0.001))
-            } else {
-                1.0 / clip.fps.max(0.001)
-            };
-
-            if clip_state.timer < frame_time {
-                break;
-            }
-
-            clip_state.timer -= frame_time;
-            clip_state.col += 1;
-            if clip_state.col >= clip.cols {
-                clip_state.col = 0;
-                clip_state.row += 1;
-                if clip_state.row >= clip.rows {
-                    if clip.looping {
-                        clip_state.row = 0;
-                    } else {
-                        clip_state.finished = true;
-                        clip_state.row = clip.rows - 1;
-                        clip_state.col = clip.cols - 1;
-                        break;
-                    }
-                }
+        let mut entered = Vec::new();
+        let just_finished = advance_clip(clip, clip_state, &mut entered);
+        for frame_index in entered {
+            for event in clip.events_at(frame_index) {
+                events.push(AnimationEvent::Frame {
+                    entity: *entity,
+                    clip_id: current_id.clone(),
+                    event: event.clone(),
+                });
             }
         }
+        if just_finished {
+            events.push(AnimationEvent::ClipFinished {
+                entity: *entity,
+                clip_id: current_id.clone(),
+            });
+        }
 
         let frame = CurrentFrame {
             clip_id: animation.current.clone().unwrap(),
@@ -127,6 +151,8 @@ pub fn update_animation_sytem(
             sprite_id,
             frame_size: clip.frame_size,
             flip_x: animation.flip_x,
+            tint: tint_color(animation.tint, animation.opacity),
+            pivot: clip.pivot(clip_state.row * clip.cols.max(1) + clip_state.col),
         };
 
         frames.push((*entity, frame));
@@ -141,6 +167,73 @@ pub fn update_animation_sytem(
     for entity in to_remove {
         frame_store.remove(entity);
     }
+
+    events
+}
+
+/// Steps `state` through the frames whose time has elapsed in `state.timer` and
+/// updates its column and row. Every frame entered is pushed to `entered`, the
+/// first frame included on a fresh state. Returns true when a non-looping clip
+/// finishes during this call.
+pub fn advance_clip(clip: &ClipDef, state: &mut ClipState, entered: &mut Vec<usize>) -> bool {
+    let frame_count = clip.frame_count();
+    let cycle_len = clip.direction.cycle_len(frame_count);
+    let mut frame_index = clip.direction.frame_index(state.step, frame_count);
+    let mut just_finished = false;
+
+    if !state.started {
+        state.started = true;
+        entered.push(frame_index);
+    }
+
+    loop {
+        let frame_time = clip.frame_time(frame_index);
+
+        if state.timer < frame_time {
+            break;
+        }
+
+        state.timer -= frame_time;
+        if state.step + 1 < cycle_len {
+            state.step += 1;
+        } else if clip.looping {
+            state.step = 0;
+            state.loops += 1;
+        } else {
+            just_finished = !state.finished;
+            state.finished = true;
+            break;
+        }
+        frame_index = clip.direction.frame_index(state.step, frame_count);
+        entered.push(frame_index);
+    }
+
+    let cols = clip.cols.max(1);
+    state.col = frame_index % cols;
+    state.row = frame_index / cols;
+    just_finished
+}
+
+/// Plays the `AudioSource` sound groups attached to frame events.
+pub fn play_animation_event_sounds(ecs: &Ecs, events: &[AnimationEvent]) {
+    let sources = ecs.get_store::<AudioSource>();
+    for event in events {
+        let AnimationEvent::Frame { entity, event, .. } = event else {
+            continue;
+        };
+        let Some(group_id) = &event.sound else {
+            continue;
+        };
+        match sources.get(*entity) {
+            Some(source) if source.play_group(group_id, **entity as u64) => {}
+            _ => log::warn!(
+                "Animation event '{}' on {:?} references missing sound group '{}'",
+                event.name,
+                entity,
+                group_id
+            ),
+        }
+    }
 }
 
 impl Renderable for CurrentFrame {
@@ -166,25 +259,35 @@ impl Renderable for CurrentFrame {
             frame_w,
             frame_h,
         );
-        let draw_base = pivot_adjusted_position(params.pos, self.frame_size, params.pivot);
-        let draw_x = (draw_base.x + self.offset.x).floor();
-        let draw_y = (draw_base.y + self.offset.y).floor();
-        ctx.draw_texture_ex(
-            tex,
-            draw_x,
-            draw_y,
-            Color::WHITE,
-            DrawTextureParams {
-                dest_size: Some(Vec2::new(frame_w, frame_h)),
-                source: Some(src),
-                flip_x: self.flip_x,
-                ..Default::default()
-            },
-        );
+        let (draw_base, mut texture_params) =
+            params.placement(self.frame_size, self.draw_offset(params), self.flip_x);
+        let draw_base = draw_base.floor();
+        texture_params.source = Some(src);
+        texture_params.pivot = Some(params.pos - draw_base);
+        ctx.draw_texture_ex(tex, draw_base.x, draw_base.y, self.tint, texture_params);
         true
     }
 }
 
+impl CurrentFrame {
+    /// Returns the draw offset in frame pixels. A slice pivot replaces the
+    /// transform pivot as the point placed on the entity position.
+    fn draw_offset(&self, params: &EntityDrawParams) -> Vec2 {
+        let Some(mut pivot) = self.pivot else {
+            return self.offset;
+        };
+        if self.flip_x != (params.scale.x < 0.0) {
+            pivot.x = self.frame_size.x - pivot.x;
+        }
+        if params.flip_y != (params.scale.y < 0.0) {
+            pivot.y = self.frame_size.y - pivot.y;
+        }
+        let anchor =
+            params.pos - pivot_adjusted_position(params.pos, self.frame_size, params.pivot);
+        self.offset + anchor - pivot
+    }
+}
+
 /// Return the SpriteId for for the current animation clip.
 fn get_sprite_id(
     loader: &impl TextureLoader,
@@ -202,3 +305,78 @@ fn get_sprite_id(
 
     (resolved, true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::transform::Pivot;
+
+    fn clip(cols: usize, looping: bool) -> ClipDef {
+        ClipDef {
+            cols,
+            rows: 1,
+            fps: 10.0,
+            looping,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn long_update_enters_every_skipped_frame() {
+        let clip = clip(4, true);
+        let mut state = ClipState::default();
+        let mut entered = Vec::new();
+
+        state.timer = 0.55;
+        assert!(!advance_clip(&clip, &mut state, &mut entered));
+        assert_eq!(entered, vec![0, 1, 2, 3, 0, 1]);
+        assert_eq!(state.col, 1);
+    }
+
+    #[test]
+    fn non_looping_clip_finishes_once_on_last_frame() {
+        let clip = ClipDef {
+            direction: PlaybackDirection::PingPong,
+            ..clip(3, false)
+        };
+        let mut state = ClipState::default();
+        let mut entered = Vec::new();
+
+        state.timer = 1.0;
+        assert!(advance_clip(&clip, &mut state, &mut entered));
+        assert_eq!(entered, vec![0, 1, 2, 1]);
+        assert_eq!(state.col, 1);
+
+        entered.clear();
+        state.timer += 1.0;
+        assert!(!advance_clip(&clip, &mut state, &mut entered));
+        assert!(entered.is_empty());
+    }
+
+    #[test]
+    fn slice_pivot_is_drawn_at_the_entity_position() {
+        let frame = CurrentFrame {
+            frame_size: vec2(16.0, 16.0),
+            pivot: Some(vec2(4.0, 12.0)),
+            ..Default::default()
+        };
+        let params = EntityDrawParams {
+            pos: vec2(100.0, 50.0),
+            pivot: Pivot::BottomCenter,
+            grid_size: 16.0,
+            rotation: 0.0,
+            scale: vec2(2.0, 2.0),
+            flip_y: false,
+        };
+
+        let (base, _) = params.placement(frame.frame_size, frame.draw_offset(&params), false);
+        assert_eq!(base, vec2(92.0, 26.0));
+
+        let flipped = CurrentFrame {
+            flip_x: true,
+            ..frame
+        };
+        let (base, _) = params.placement(flipped.frame_size, flipped.draw_offset(&params), true);
+        assert_eq!(base, vec2(76.0, 26.0));
+    }
+}
@@ -34,6 +34,22 @@ struct AseSize {
     h: i32,
 }
 
+/// Aseprite rectangle.
+#[derive(Deserialize)]
+struct AseRect {
+    x: i32,
+    y: i32,
+    w: i32,
+    h: i32,
+}
+
+/// Aseprite point.
+#[derive(Deserialize)]
+struct AsePoint {
+    x: i32,
+    y: i32,
+}
+
 /// Aseprite frame data.
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -44,22 +60,77 @@ struct AseFrame {
     duration: i32,
 }
 
+/// Aseprite frames, written as an array (json-array) or keyed by filename (json-hash).
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AseFrames {
+    Array(Vec<AseFrame>),
+    Hash(HashMap<String, AseFrame>),
+}
+
+impl AseFrames {
+    /// Returns the frames in playback order.
+    fn into_ordered(self) -> Vec<AseFrame> {
+        match self {
+            AseFrames::Array(frames) => frames,
+            AseFrames::Hash(frames) => {
+                let mut frames: Vec<(String, AseFrame)> = frames.into_iter().collect();
+                frames.sort_by_key(|(name, _)| frame_sort_key(name));
+                frames.into_iter().map(|(_, frame)| frame).collect()
+            }
+        }
+    }
+}
+
+/// Aseprite frame tag (`--list-tags`).
+#[derive(Deserialize)]
+struct AseFrameTag {
+    name: String,
+    from: usize,
+    to: usize,
+    #[serde(default)]
+    direction: String,
+}
+
+/// Aseprite slice key (`--list-slices`). The pivot is relative to the bounds.
+#[derive(Deserialize)]
+struct AseSliceKey {
+    frame: usize,
+    bounds: AseRect,
+    #[serde(default)]
+    pivot: Option<AsePoint>,
+}
+
+/// Aseprite slice.
+#[derive(Deserialize)]
+struct AseSlice {
+    name: String,
+    #[serde(default)]
+    keys: Vec<AseSliceKey>,
+}
+
 /// Aseprite meta section.
 #[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct AseMeta {
     #[allow(dead_code)]
     image: String,
     size: AseSize,
+    #[serde(default)]
+    frame_tags: Vec<AseFrameTag>,
+    #[serde(default)]
+    slices: Vec<AseSlice>,
 }
 
-/// Aseprite JSON root (json-hash format).
+/// Aseprite JSON root.
 #[derive(Deserialize)]
 struct AsepriteJson {
-    frames: HashMap<String, AseFrame>,
+    frames: AseFrames,
     meta: AseMeta,
 }
 
 /// Parses an Aseprite JSON file and returns a ClipDef with the extracted metadata.
+/// For files with frame tags, the tag named like the file is used, or else the first tag.
 pub fn import_aseprite_metadata(json_path: &Path) -> JsonImportResult {
     if !json_path.exists() {
         return JsonImportResult::NotFound;
@@ -70,35 +141,112 @@ pub fn import_aseprite_metadata(json_path: &Path) -> JsonImportResult {
         Err(e) => return JsonImportResult::Error(format!("Failed to read file: {}", e)),
     };
 
-    let ase_json: AsepriteJson = match serde_json::from_str(&content) {
-        Ok(j) => j,
-        Err(e) => return JsonImportResult::Error(format!("Failed to parse JSON: {}", e)),
+    let clip_id = filename_to_clip_id(json_path);
+    let clips = match parse_aseprite_json(&content, &clip_id) {
+        Ok(clips) => clips,
+        Err(e) => return JsonImportResult::Error(e),
     };
 
-    if ase_json.frames.is_empty() {
-        return JsonImportResult::Error("No frames found in JSON".to_string());
+    let tag_id = ClipId::from_name(&clip_id.ui_label());
+    let index = clips
+        .iter()
+        .position(|(id, _)| *id == clip_id || *id == tag_id)
+        .unwrap_or(0);
+    match clips.into_iter().nth(index) {
+        Some((_, clip_def)) => JsonImportResult::Success(clip_def),
+        None => JsonImportResult::Error("No clips found in JSON".to_string()),
+    }
+}
+
+/// Imports the metadata of one clip of a variant. Reads `<Clip>.json` when it
+/// exists, otherwise looks for a frame tag with the clip's name in the folder's JSON files.
+pub fn import_clip_metadata(variant_folder: &VariantFolder, clip_id: &ClipId) -> JsonImportResult {
+    let json_path = resolve_json_path(variant_folder, clip_id);
+    if json_path.exists() {
+        return import_aseprite_metadata(&json_path);
     }
 
-    let mut sorted_frames: Vec<(&String, &AseFrame)> = ase_json.frames.iter().collect();
-    sorted_frames.sort_by(|a, b| a.0.cmp(b.0));
+    let folder = assets_folder().join(&variant_folder.0);
+    let Ok(entries) = fs::read_dir(&folder) else {
+        return JsonImportResult::NotFound;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let Ok(clips) = parse_aseprite_json(&content, &filename_to_clip_id(&path)) else {
+            continue;
+        };
+        if let Some((_, clip_def)) = clips.into_iter().find(|(id, _)| id == clip_id) {
+            return JsonImportResult::Success(clip_def);
+        }
+    }
+    JsonImportResult::NotFound
+}
 
-    let first_frame = sorted_frames[0].1;
-    let frame_w = first_frame.source_size.w as f32;
-    let frame_h = first_frame.source_size.h as f32;
+/// Parses Aseprite JSON (json-array or json-hash) into clip definitions.
+///
+/// A file with frame tags gives one clip per tag, laid out as the horizontal
+/// strip written by `--split-tags --sheet-type horizontal`. A file without tags
+/// gives a single `default_clip` covering the whole sheet.
+pub fn parse_aseprite_json(
+    content: &str,
+    default_clip: &ClipId,
+) -> Result<Vec<(ClipId, ClipDef)>, String> {
+    let ase_json: AsepriteJson =
+        serde_json::from_str(content).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    let frames = ase_json.frames.into_ordered();
+    let meta = ase_json.meta;
+
+    if frames.is_empty() {
+        return Err("No frames found in JSON".to_string());
+    }
 
-    let sheet_w = ase_json.meta.size.w as f32;
-    let sheet_h = ase_json.meta.size.h as f32;
+    if meta.frame_tags.is_empty() {
+        let frame_w = frames[0].source_size.w as f32;
+        let frame_h = frames[0].source_size.h as f32;
+        let cols = (meta.size.w as f32 / frame_w).round() as usize;
+        let rows = (meta.size.h as f32 / frame_h).round() as usize;
 
-    let cols = (sheet_w / frame_w).round() as usize;
-    let rows = (sheet_h / frame_h).round() as usize;
+        let mut clip_def = clip_from_frames(&frames, cols, rows);
+        clip_def.slices = clip_slices(&meta.slices, 0, frames.len() - 1);
+        return Ok(vec![(default_clip.clone(), clip_def)]);
+    }
 
-    let raw_durations: Vec<i32> = sorted_frames.iter().map(|(_, f)| f.duration).collect();
+    let mut clips = Vec::with_capacity(meta.frame_tags.len());
+    for tag in &meta.frame_tags {
+        if tag.from > tag.to || tag.to >= frames.len() {
+            return Err(format!(
+                "Tag '{}' covers frames {}..{} but the file has {} frames",
+                tag.name,
+                tag.from,
+                tag.to,
+                frames.len()
+            ));
+        }
+
+        let tag_frames = &frames[tag.from..=tag.to];
+        let mut clip_def = clip_from_frames(tag_frames, tag_frames.len(), 1);
+        clip_def.direction = PlaybackDirection::from_aseprite(&tag.direction);
+        clip_def.slices = clip_slices(&meta.slices, tag.from, tag.to);
+        clips.push((ClipId::from_name(&tag.name), clip_def));
+    }
+    Ok(clips)
+}
+
+/// Builds a clip from its frames laid out in `cols` x `rows` cells.
+fn clip_from_frames(frames: &[AseFrame], cols: usize, rows: usize) -> ClipDef {
+    let first_frame = &frames[0];
+    let raw_durations: Vec<i32> = frames.iter().map(|f| f.duration).collect();
 
     // Check if all frames have the same duration
     let all_same = raw_durations.windows(2).all(|w| w[0] == w[1]);
 
     // Only use per-frame durations if timing varies; otherwise use FPS
-    let (frame_durations, fps) = if all_same && !raw_durations.is_empty() {
+    let (frame_durations, fps) = if all_same {
         let duration_ms = raw_durations[0] as f32;
         let fps = (1000.0 / duration_ms).round();
         (Vec::new(), fps)
@@ -119,20 +267,68 @@ pub fn import_aseprite_metadata(json_path: &Path) -> JsonImportResult {
         Vec2::ZERO
     };
 
-    let mirrored = rows == 1;
-
-    let clip_def = ClipDef {
-        frame_size: Vec2::new(frame_w, frame_h),
+    ClipDef {
+        frame_size: Vec2::new(
+            first_frame.source_size.w as f32,
+            first_frame.source_size.h as f32,
+        ),
         cols,
         rows,
         fps,
         frame_durations,
         looping: true,
         offset,
-        mirrored,
-    };
+        mirrored: rows == 1,
+        ..Default::default()
+    }
+}
+
+/// Converts the slice keys covering frames `from..=to` to clip-relative keys.
+fn clip_slices(slices: &[AseSlice], from: usize, to: usize) -> Vec<ClipSlice> {
+    slices
+        .iter()
+        .filter_map(|slice| {
+            let mut keys: Vec<&AseSliceKey> = slice.keys.iter().collect();
+            keys.sort_by_key(|k| k.frame);
+
+            // The last key at or before `from` still applies to the clip's first frame
+            let start = keys.iter().rposition(|k| k.frame <= from).unwrap_or(0);
+            let keys: Vec<SliceKey> = keys[start..]
+                .iter()
+                .filter(|k| k.frame <= to)
+                .map(|k| {
+                    let bounds = Rect::new(
+                        k.bounds.x as f32,
+                        k.bounds.y as f32,
+                        k.bounds.w as f32,
+                        k.bounds.h as f32,
+                    );
+                    SliceKey {
+                        frame: k.frame.saturating_sub(from),
+                        bounds,
+                        pivot: k
+                            .pivot
+                            .as_ref()
+                            .map(|p| Vec2::new(bounds.x + p.x as f32, bounds.y + p.y as f32)),
+                    }
+                })
+                .collect();
+
+            (!keys.is_empty()).then(|| ClipSlice {
+                name: slice.name.clone(),
+                keys,
+            })
+        })
+        .collect()
+}
 
-    JsonImportResult::Success(clip_def)
+/// Sort key for json-hash frame names such as `player 10.aseprite`,
+/// so that frame 10 comes after frame 9.
+fn frame_sort_key(name: &str) -> (String, u64) {
+    let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+    let prefix = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+    let number = stem[prefix.len()..].parse().unwrap_or(0);
+    (prefix.to_string(), number)
 }
 
 /// Resolves the path to the Aseprite JSON file for a given clip.
@@ -161,6 +357,10 @@ pub enum AseExportResult {
 }
 
 /// Export all .ase/.aseprite files in the folder to PNG + JSON using Aseprite CLI.
+///
+/// Each file is exported to `<file>.json` with its frame tags and slices. Files
+/// with tags also get one horizontal strip per tag, named after the tag's clip.
+/// Layers whose name starts with `_` are left out of the sheets.
 pub fn export_aseprite_folder(folder: &Path) -> AseExportResult {
     let entries = match fs::read_dir(folder) {
         Ok(e) => e,
@@ -184,41 +384,72 @@ pub fn export_aseprite_folder(folder: &Path) -> AseExportResult {
             Some(s) => s.to_string(),
             None => continue,
         };
+        let file = path.to_string_lossy().to_string();
+
+        let layers = match run_aseprite(&aseprite_path, &["--list-layers", &file], folder, &stem) {
+            Ok(stdout) => stdout,
+            Err(result) => return result,
+        };
+        let mut layer_args: Vec<String> = Vec::new();
+        for layer in layers.lines().map(str::trim) {
+            if layer.starts_with(HIDDEN_LAYER_PREFIX) {
+                layer_args.push("--ignore-layer".to_string());
+                layer_args.push(layer.to_string());
+            }
+        }
 
         let png_name = format!("{}.png", stem);
         let json_name = format!("{}.json", stem);
 
-        let output = Command::new(&aseprite_path)
-            .args([
-                "-b",
-                path.to_string_lossy().as_ref(),
-                "--sheet",
-                &png_name,
-                "--format",
-                "json-hash",
-                "--data",
-                &json_name,
-            ])
-            .current_dir(folder)
-            .output();
-
-        match output {
-            Ok(result) => {
-                if !result.status.success() {
-                    let stderr = String::from_utf8_lossy(&result.stderr);
-                    return AseExportResult::ExportFailed {
-                        file: stem,
-                        error: stderr.to_string(),
-                    };
-                }
-            }
-            Err(e) => {
-                if e.kind() == std::io::ErrorKind::NotFound {
-                    return AseExportResult::AsepriteNotFound;
-                }
+        let mut args: Vec<&str> = layer_args.iter().map(String::as_str).collect();
+        args.extend([
+            file.as_str(),
+            "--sheet",
+            &png_name,
+            "--sheet-type",
+            "horizontal",
+            "--format",
+            "json-array",
+            "--data",
+            &json_name,
+            "--list-tags",
+            "--list-slices",
+        ]);
+        if let Err(result) = run_aseprite(&aseprite_path, &args, folder, &stem) {
+            return result;
+        }
+
+        let tags = read_tag_names(&folder.join(&json_name));
+        if tags.is_empty() {
+            continue;
+        }
+
+        let mut args: Vec<&str> = layer_args.iter().map(String::as_str).collect();
+        args.extend([
+            "--split-tags",
+            file.as_str(),
+            "--sheet-type",
+            "horizontal",
+            "--sheet",
+            "{tag}.png",
+        ]);
+        if let Err(result) = run_aseprite(&aseprite_path, &args, folder, &stem) {
+            return result;
+        }
+
+        // Rename tag sheets to the file names clips load from (e.g. `walk` -> `Walk.png`)
+        for tag in tags {
+            let Some(file_name) = sprite_file_name(&ClipId::from_name(&tag)) else {
+                continue;
+            };
+            let tag_png = folder.join(format!("{}.png", tag));
+            let clip_png = folder.join(file_name);
+            if tag_png != clip_png
+                && let Err(e) = fs::rename(&tag_png, &clip_png)
+            {
                 return AseExportResult::ExportFailed {
                     file: stem,
-                    error: e.to_string(),
+                    error: format!("Failed to rename {}: {}", tag_png.display(), e),
                 };
             }
         }
@@ -227,6 +458,54 @@ pub fn export_aseprite_folder(folder: &Path) -> AseExportResult {
     AseExportResult::Success
 }
 
+/// Layers whose name starts with this prefix are not exported.
+const HIDDEN_LAYER_PREFIX: char = '_';
+
+/// Runs the Aseprite CLI in batch mode and returns its standard output.
+fn run_aseprite(
+    aseprite_path: &str,
+    args: &[&str],
+    folder: &Path,
+    stem: &str,
+) -> Result<String, AseExportResult> {
+    let output = Command::new(aseprite_path)
+        .arg("-b")
+        .args(args)
+        .current_dir(folder)
+        .output();
+
+    match output {
+        Ok(result) => {
+            if !result.status.success() {
+                let stderr = String::from_utf8_lossy(&result.stderr);
+                return Err(AseExportResult::ExportFailed {
+                    file: stem.to_string(),
+                    error: stderr.to_string(),
+                });
+            }
+            Ok(String::from_utf8_lossy(&result.stdout).to_string())
+        }
+        Err(e) => {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                return Err(AseExportResult::AsepriteNotFound);
+            }
+            Err(AseExportResult::ExportFailed {
+                file: stem.to_string(),
+                error: e.to_string(),
+            })
+        }
+    }
+}
+
+/// Returns the frame tag names listed in an exported JSON file.
+fn read_tag_names(json_path: &Path) -> Vec<String> {
+    fs::read_to_string(json_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<AsepriteJson>(&content).ok())
+        .map(|json| json.meta.frame_tags.into_iter().map(|t| t.name).collect())
+        .unwrap_or_default()
+}
+
 /// Find the Aseprite executable, checking common installation paths.
 fn find_aseprite_executable() -> String {
     #[cfg(target_os = "macos")]
@@ -271,7 +550,7 @@ pub struct FolderImportResult {
 }
 
 /// Import all JSON files in a folder and create clips.
-/// Missing or malformed JSON files are skipped.
+/// Files with frame tags add one clip per tag. Malformed JSON files are skipped.
 pub fn import_variant_folder(folder: &Path) -> Result<FolderImportResult, String> {
     let entries = fs::read_dir(folder).map_err(|e| format!("Failed to read directory: {}", e))?;
 
@@ -287,15 +566,13 @@ pub fn import_variant_folder(folder: &Path) -> Result<FolderImportResult, String
         }
 
         let clip_id = filename_to_clip_id(&path);
+        let parsed = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read file: {}", e))
+            .and_then(|content| parse_aseprite_json(&content, &clip_id));
 
-        match import_aseprite_metadata(&path) {
-            JsonImportResult::Success(clip_def) => {
-                clips.insert(clip_id, clip_def);
-            }
-            JsonImportResult::Error(e) => {
-                skipped.push(format!("{}: {}", path.display(), e));
-            }
-            JsonImportResult::NotFound => {}
+        match parsed {
+            Ok(parsed) => clips.extend(parsed),
+            Err(e) => skipped.push(format!("{}: {}", path.display(), e)),
         }
     }
 
@@ -315,3 +592,99 @@ fn filename_to_clip_id(path: &Path) -> ClipId {
         other => ClipId::Custom(other.to_string()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame_json(duration: i32) -> String {
+        format!(
+            r#"{{"frame": {{"x": 0, "y": 0, "w": 16, "h": 16}}, "trimmed": false,
+            "spriteSourceSize": {{"x": 0, "y": 0, "w": 16, "h": 16}},
+            "sourceSize": {{"w": 16, "h": 16}}, "duration": {duration}}}"#
+        )
+    }
+
+    #[test]
+    fn tagged_json_array_gives_one_clip_per_tag() {
+        let frames: Vec<String> = [100, 100, 100, 50, 150].map(frame_json).to_vec();
+        let json = format!(
+            r#"{{"frames": [{}], "meta": {{"image": "player.png", "size": {{"w": 80, "h": 16}},
+            "frameTags": [
+                {{"name": "idle", "from": 0, "to": 2, "direction": "pingpong"}},
+                {{"name": "Dash", "from": 3, "to": 4, "direction": "reverse"}}
+            ]}}}}"#,
+            frames.join(",")
+        );
+
+        let clips = parse_aseprite_json(&json, &ClipId::New).unwrap();
+        assert_eq!(clips.len(), 2);
+
+        let (idle_id, idle) = &clips[0];
+        assert_eq!(*idle_id, ClipId::Idle);
+        assert_eq!((idle.cols, idle.rows), (3, 1));
+        assert_eq!(idle.fps, 10.0);
+        assert!(idle.frame_durations.is_empty());
+        assert_eq!(idle.direction, PlaybackDirection::PingPong);
+
+        let (dash_id, dash) = &clips[1];
+        assert_eq!(*dash_id, ClipId::Custom("Dash".to_string()));
+        assert_eq!(dash.cols, 2);
+        assert_eq!(dash.frame_durations, vec![0.05, 0.15]);
+        assert_eq!(dash.direction, PlaybackDirection::Reverse);
+    }
+
+    #[test]
+    fn untagged_json_hash_orders_frames_numerically() {
+        let durations = [100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 300];
+        let frames: Vec<String> = durations
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| format!(r#""walk {i}.aseprite": {}"#, frame_json(d)))
+            .collect();
+        let json = format!(
+            r#"{{"frames": {{{}}}, "meta": {{"image": "Walk.png", "size": {{"w": 176, "h": 16}}}}}}"#,
+            frames.join(",")
+        );
+
+        let clips = parse_aseprite_json(&json, &ClipId::Walk).unwrap();
+        assert_eq!(clips.len(), 1);
+        let (id, walk) = &clips[0];
+        assert_eq!(*id, ClipId::Walk);
+        assert_eq!((walk.cols, walk.rows), (11, 1));
+        assert_eq!(walk.frame_durations.last(), Some(&0.3));
+    }
+
+    #[test]
+    fn slices_become_clip_relative() {
+        let frames: Vec<String> = [100, 100, 100, 100].map(frame_json).to_vec();
+        let json = format!(
+            r#"{{"frames": [{}], "meta": {{"image": "player.png", "size": {{"w": 64, "h": 16}},
+            "frameTags": [{{"name": "Attack", "from": 2, "to": 3, "direction": "forward"}}],
+            "slices": [{{"name": "hitbox", "keys": [
+                {{"frame": 0, "bounds": {{"x": 1, "y": 2, "w": 4, "h": 4}}, "pivot": {{"x": 2, "y": 3}}}},
+                {{"frame": 3, "bounds": {{"x": 8, "y": 2, "w": 6, "h": 4}}}}
+            ]}}]}}}}"#,
+            frames.join(",")
+        );
+
+        let clips = parse_aseprite_json(&json, &ClipId::New).unwrap();
+        let (_, attack) = &clips[0];
+        let hitbox = &attack.slices[0];
+        assert_eq!(hitbox.keys.len(), 2);
+        assert_eq!(hitbox.keys[0].frame, 0);
+        assert_eq!(hitbox.keys[1].frame, 1);
+        assert_eq!(attack.pivot(0), Some(Vec2::new(3.0, 5.0)));
+        assert_eq!(attack.slice("hitbox", 1).map(|k| k.bounds.w), Some(6.0));
+    }
+
+    #[test]
+    fn out_of_range_tags_are_rejected() {
+        let json = format!(
+            r#"{{"frames": [{}], "meta": {{"image": "a.png", "size": {{"w": 16, "h": 16}},
+            "frameTags": [{{"name": "Run", "from": 0, "to": 4}}]}}}}"#,
+            frame_json(100)
+        );
+        assert!(parse_aseprite_json(&json, &ClipId::New).is_err());
+    }
+}
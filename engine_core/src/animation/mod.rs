@@ -1,7 +1,9 @@
 pub mod animation_clip;
 pub mod animation_system;
 pub mod aseprite_import;
+pub mod state_machine;
 
 pub use animation_clip::*;
 pub use animation_system::*;
 pub use aseprite_import::*;
+pub use state_machine::*;
@@ -0,0 +1,538 @@
+// engine_core/src/animation/state_machine.rs
+use crate::animation::animation_clip::*;
+use crate::ecs::component::{Grounded, PlayerProxy, Velocity};
+use crate::ecs::ecs::Ecs;
+use crate::ecs::entity::Entity;
+use crate::ecs::facing_direction::FacingDirection;
+use crate::inspector_module;
+use crate::onscreen_error;
+use crate::storage::path_utils::state_machines_folder;
+use crate::worlds::room::{RoomId, entities_in_room};
+use ecs_component::ecs_component;
+use reflect_derive::Reflect;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Built-in parameter holding the horizontal velocity.
+pub const PARAM_VELOCITY_X: &str = "velocity_x";
+/// Built-in parameter holding the vertical velocity.
+pub const PARAM_VELOCITY_Y: &str = "velocity_y";
+/// Built-in parameter holding the length of the velocity.
+pub const PARAM_SPEED: &str = "speed";
+/// Built-in parameter holding the `Grounded` flag.
+pub const PARAM_GROUNDED: &str = "grounded";
+/// Built-in parameter that is true while the entity faces left.
+pub const PARAM_FACING_LEFT: &str = "facing_left";
+
+/// Drives the entity's animation clip from a state machine asset.
+#[ecs_component]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Reflect)]
+#[serde(default)]
+pub struct AnimationStateMachine {
+    /// Id of the state machine in the state machines folder.
+    pub machine: String,
+}
+inspector_module!(AnimationStateMachine);
+
+/// Runtime state of an entity's animation state machine.
+#[ecs_component(runtime = true)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AnimatorState {
+    /// The loaded state machine.
+    #[serde(skip)]
+    pub def: Option<Arc<AnimationStateMachineDef>>,
+    /// Name of the active state.
+    #[serde(skip)]
+    pub current: Option<String>,
+    /// Parameter values, including the built-in ones.
+    #[serde(skip)]
+    pub params: HashMap<String, AnimParam>,
+    /// Id of the machine that failed to load, so the error is only reported once.
+    #[serde(skip)]
+    pub load_failed: Option<String>,
+}
+
+impl AnimatorState {
+    /// Sets a parameter. A truthy value fires a declared trigger.
+    pub fn set_param(&mut self, name: &str, value: AnimParam) {
+        let value = match (self.params.get(name), value) {
+            (Some(AnimParam::Trigger(_)), value) => AnimParam::Trigger(value.is_truthy()),
+            (_, value) => value,
+        };
+        self.params.insert(name.to_string(), value);
+    }
+
+    /// Declares the parameters of `def`. Values set before it loaded keep
+    /// their value but take the declared type.
+    fn declare_params(&mut self, def: &AnimationStateMachineDef) {
+        for param in &def.params {
+            let value = match self.params.get(&param.name) {
+                Some(value) => value.coerced_to(param.default),
+                None => param.default,
+            };
+            self.params.insert(param.name.clone(), value);
+        }
+    }
+}
+
+/// Value of a state machine parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AnimParam {
+    Float(f32),
+    Bool(bool),
+    /// A flag that stays set until a transition that tests it is taken.
+    Trigger(bool),
+}
+
+impl AnimParam {
+    /// Returns the value as a number; booleans are 0 or 1.
+    pub fn as_f32(self) -> f32 {
+        match self {
+            AnimParam::Float(v) => v,
+            AnimParam::Bool(b) | AnimParam::Trigger(b) => b as u8 as f32,
+        }
+    }
+
+    /// Returns true for set flags and non-zero numbers.
+    pub fn is_truthy(self) -> bool {
+        match self {
+            AnimParam::Float(v) => v != 0.0,
+            AnimParam::Bool(b) | AnimParam::Trigger(b) => b,
+        }
+    }
+
+    /// Returns this value converted to the type of `declared`.
+    pub fn coerced_to(self, declared: AnimParam) -> AnimParam {
+        match declared {
+            AnimParam::Float(_) => AnimParam::Float(self.as_f32()),
+            AnimParam::Bool(_) => AnimParam::Bool(self.is_truthy()),
+            AnimParam::Trigger(_) => AnimParam::Trigger(self.is_truthy()),
+        }
+    }
+}
+
+/// A declared parameter and its initial value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimParamDef {
+    pub name: String,
+    pub default: AnimParam,
+}
+
+/// A state and the clip it plays.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnimStateDef {
+    pub name: String,
+    pub clip: ClipId,
+}
+
+/// Where a transition can be taken from.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum TransitionFrom {
+    /// Any state other than the target.
+    #[default]
+    Any,
+    State(String),
+}
+
+/// A condition on a parameter. Missing parameters never pass.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AnimCondition {
+    Greater(String, f32),
+    Less(String, f32),
+    IsTrue(String),
+    IsFalse(String),
+    /// The trigger is set; taking the transition clears it.
+    Triggered(String),
+}
+
+impl AnimCondition {
+    /// Returns true if the condition holds for `params`.
+    pub fn passes(&self, params: &HashMap<String, AnimParam>) -> bool {
+        match self {
+            AnimCondition::Greater(name, value) => {
+                params.get(name).is_some_and(|p| p.as_f32() > *value)
+            }
+            AnimCondition::Less(name, value) => {
+                params.get(name).is_some_and(|p| p.as_f32() < *value)
+            }
+            AnimCondition::IsTrue(name) => params.get(name).is_some_and(|p| p.is_truthy()),
+            AnimCondition::IsFalse(name) => params.get(name).is_some_and(|p| !p.is_truthy()),
+            AnimCondition::Triggered(name) => {
+                matches!(params.get(name), Some(AnimParam::Trigger(true)))
+            }
+        }
+    }
+}
+
+/// A transition taken when all its conditions hold.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnimTransition {
+    pub from: TransitionFrom,
+    pub to: String,
+    pub conditions: Vec<AnimCondition>,
+    /// Clip cycles that must have played first. Looping clips count their
+    /// completed loops, so 1.0 waits for one full cycle.
+    pub exit_time: Option<f32>,
+    /// Transitions with a higher priority are tested first.
+    pub priority: i32,
+}
+
+/// A state machine asset that picks animation clips from parameters.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnimationStateMachineDef {
+    #[serde(skip)]
+    pub id: String,
+    /// State entered when the machine starts.
+    pub initial: String,
+    pub params: Vec<AnimParamDef>,
+    pub states: Vec<AnimStateDef>,
+    pub transitions: Vec<AnimTransition>,
+}
+
+impl AnimationStateMachineDef {
+    /// Returns the state with the given name.
+    pub fn state(&self, name: &str) -> Option<&AnimStateDef> {
+        self.states.iter().find(|s| s.name == name)
+    }
+
+    /// Returns the highest priority transition out of `current` whose
+    /// conditions hold. `progress` is the number of cycles the current clip has played.
+    pub fn next_transition(
+        &self,
+        current: &str,
+        params: &HashMap<String, AnimParam>,
+        progress: f32,
+    ) -> Option<&AnimTransition> {
+        let mut candidates: Vec<&AnimTransition> = self
+            .transitions
+            .iter()
+            .filter(|t| match &t.from {
+                TransitionFrom::Any => t.to != current,
+                TransitionFrom::State(from) => from == current,
+            })
+            .collect();
+        candidates.sort_by_key(|t| std::cmp::Reverse(t.priority));
+
+        candidates.into_iter().find(|t| {
+            t.exit_time.is_none_or(|exit| progress >= exit)
+                && t.conditions.iter().all(|c| c.passes(params))
+        })
+    }
+
+    /// Returns a message for every reference to an undeclared state.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.state(&self.initial).is_none() {
+            errors.push(format!("Initial state '{}' does not exist", self.initial));
+        }
+        for transition in &self.transitions {
+            if let TransitionFrom::State(from) = &transition.from
+                && self.state(from).is_none()
+            {
+                errors.push(format!("Transition from unknown state '{from}'"));
+            }
+            if self.state(&transition.to).is_none() {
+                errors.push(format!("Transition to unknown state '{}'", transition.to));
+            }
+        }
+        errors
+    }
+}
+
+/// Returns the on-disk path for a state machine id.
+pub fn state_machine_path(id: &str) -> PathBuf {
+    let mut path = state_machines_folder();
+    for component in id.replace('\\', "/").split('/') {
+        path = path.join(component);
+    }
+    path.set_extension("ron");
+    path
+}
+
+/// Loads and validates a state machine by id from the state machines folder.
+pub fn load_state_machine(id: &str) -> Result<AnimationStateMachineDef, String> {
+    let path = state_machine_path(id);
+    let ron_str = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read state machine '{}': {e}", path.display()))?;
+    let mut def: AnimationStateMachineDef = ron::de::from_str(&ron_str)
+        .map_err(|e| format!("Failed to parse state machine '{}': {e}", path.display()))?;
+    def.id = id.to_string();
+
+    let errors = def.validate();
+    if !errors.is_empty() {
+        return Err(format!("State machine '{id}': {}", errors.join(", ")));
+    }
+    Ok(def)
+}
+
+/// Advances the state machines of the entities in the room and switches their
+/// clips. Runs before `update_animation_sytem`.
+pub fn update_state_machines(ecs: &mut Ecs, room_id: RoomId) {
+    let mut entities = entities_in_room(ecs, room_id);
+    let has_spawn_point = entities.iter().any(|e| ecs.has::<PlayerProxy>(*e));
+    if has_spawn_point && let Some(player) = ecs.get_player_entity() {
+        entities.insert(player);
+    }
+
+    let machines: Vec<(Entity, String)> = ecs
+        .get_store::<AnimationStateMachine>()
+        .data
+        .iter()
+        .filter(|(entity, machine)| entities.contains(entity) && !machine.machine.is_empty())
+        .map(|(entity, machine)| (*entity, machine.machine.clone()))
+        .collect();
+
+    for (entity, machine) in machines {
+        let velocity = ecs.get::<Velocity>(entity).copied();
+        let grounded = ecs.get::<Grounded>(entity).map(|g| g.0);
+        let facing_left = ecs
+            .get::<FacingDirection>(entity)
+            .map(|f| f.0.has_leftward_component());
+        let progress = ecs.get::<Animation>(entity).and_then(|animation| {
+            let current = animation.current.as_ref()?;
+            let clip = animation.clips.get(current)?;
+            Some(clip.progress(animation.states.get(current)?))
+        });
+
+        if !ecs.has::<AnimatorState>(entity) {
+            ecs.add_component_to_entity(entity, AnimatorState::default());
+        }
+        let Some(animator) = ecs.get_mut::<AnimatorState>(entity) else {
+            continue;
+        };
+
+        // Load the asset on first use, or again after the component points elsewhere
+        if animator.def.as_ref().is_none_or(|def| def.id != machine) {
+            if animator.load_failed.as_ref() == Some(&machine) {
+                continue;
+            }
+            match load_state_machine(&machine) {
+                Ok(def) => {
+                    animator.declare_params(&def);
+                    animator.current = None;
+                    animator.def = Some(Arc::new(def));
+                    animator.load_failed = None;
+                }
+                Err(e) => {
+                    animator.load_failed = Some(machine);
+                    onscreen_error!("{e}");
+                    continue;
+                }
+            }
+        }
+
+        if let Some(velocity) = velocity {
+            let params = &mut animator.params;
+            params.insert(PARAM_VELOCITY_X.to_string(), AnimParam::Float(velocity.x));
+            params.insert(PARAM_VELOCITY_Y.to_string(), AnimParam::Float(velocity.y));
+            let speed = (velocity.x * velocity.x + velocity.y * velocity.y).sqrt();
+            params.insert(PARAM_SPEED.to_string(), AnimParam::Float(speed));
+        }
+        if let Some(grounded) = grounded {
+            let value = AnimParam::Bool(grounded);
+            animator.params.insert(PARAM_GROUNDED.to_string(), value);
+        }
+        if let Some(facing_left) = facing_left {
+            let value = AnimParam::Bool(facing_left);
+            animator.params.insert(PARAM_FACING_LEFT.to_string(), value);
+        }
+
+        let Some(next_state) = step_state_machine(animator, progress.unwrap_or(0.0)) else {
+            continue;
+        };
+        let Some(def) = animator.def.clone() else {
+            continue;
+        };
+        let Some(state) = def.state(&next_state) else {
+            continue;
+        };
+
+        if let Some(animation) = ecs.get_mut::<Animation>(entity) {
+            animation.set_clip(&state.clip);
+            if let Some(clip) = animation.clips.get(&state.clip) {
+                animation.flip_x = clip.mirrored && facing_left.unwrap_or(false);
+            }
+        }
+    }
+}
+
+/// Enters the initial state or takes the next transition, clearing the
+/// triggers it tested. Returns the state that was entered.
+pub fn step_state_machine(animator: &mut AnimatorState, progress: f32) -> Option<String> {
+    let def = animator.def.clone()?;
+
+    let Some(current) = animator.current.clone() else {
+        animator.current = Some(def.initial.clone());
+        return Some(def.initial.clone());
+    };
+
+    let transition = def.next_transition(&current, &animator.params, progress)?;
+    for condition in &transition.conditions {
+        if let AnimCondition::Triggered(name) = condition {
+            animator
+                .params
+                .insert(name.clone(), AnimParam::Trigger(false));
+        }
+    }
+    animator.current = Some(transition.to.clone());
+    Some(transition.to.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MACHINE: &str = r#"(
+        initial: "idle",
+        params: [(name: "attack", default: Trigger(false))],
+        states: [
+            (name: "idle", clip: Idle),
+            (name: "walk", clip: Walk),
+            (name: "fall", clip: Fall),
+            (name: "attack", clip: Attack),
+        ],
+        transitions: [
+            (from: State("idle"), to: "walk", conditions: [Greater("speed", 1.0)]),
+            (from: State("walk"), to: "idle", conditions: [Less("speed", 1.0)]),
+            (from: Any, to: "fall", conditions: [IsFalse("grounded")], priority: 10),
+            (from: Any, to: "attack", conditions: [Triggered("attack")], priority: 5),
+            (from: State("attack"), to: "idle", exit_time: Some(1.0)),
+        ],
+    )"#;
+
+    fn animator() -> AnimatorState {
+        let def: AnimationStateMachineDef = ron::de::from_str(MACHINE).unwrap();
+        assert!(def.validate().is_empty());
+        let mut animator = AnimatorState {
+            params: def
+                .params
+                .iter()
+                .map(|p| (p.name.clone(), p.default))
+                .collect(),
+            def: Some(Arc::new(def)),
+            ..Default::default()
+        };
+        animator.set_param(PARAM_GROUNDED, AnimParam::Bool(true));
+        animator.set_param(PARAM_SPEED, AnimParam::Float(0.0));
+        animator
+    }
+
+    #[test]
+    fn enters_initial_state_then_follows_conditions() {
+        let mut animator = animator();
+        assert_eq!(
+            step_state_machine(&mut animator, 0.0).as_deref(),
+            Some("idle")
+        );
+        assert_eq!(step_state_machine(&mut animator, 0.0), None);
+
+        animator.set_param(PARAM_SPEED, AnimParam::Float(5.0));
+        assert_eq!(
+            step_state_machine(&mut animator, 0.0).as_deref(),
+            Some("walk")
+        );
+
+        animator.set_param(PARAM_GROUNDED, AnimParam::Bool(false));
+        assert_eq!(
+            step_state_machine(&mut animator, 0.0).as_deref(),
+            Some("fall")
+        );
+        // Any-state transitions do not re-enter their own target
+        assert_eq!(step_state_machine(&mut animator, 0.0), None);
+    }
+
+    #[test]
+    fn triggers_are_consumed_and_exit_time_is_honoured() {
+        let mut animator = animator();
+        step_state_machine(&mut animator, 0.0);
+
+        animator.set_param("attack", AnimParam::Bool(true));
+        assert_eq!(
+            step_state_machine(&mut animator, 0.0).as_deref(),
+            Some("attack")
+        );
+        assert_eq!(animator.params["attack"], AnimParam::Trigger(false));
+
+        assert_eq!(step_state_machine(&mut animator, 0.5), None);
+        assert_eq!(
+            step_state_machine(&mut animator, 1.0).as_deref(),
+            Some("idle")
+        );
+    }
+
+    #[test]
+    fn params_set_before_loading_take_the_declared_type() {
+        let def: AnimationStateMachineDef = ron::de::from_str(MACHINE).unwrap();
+        let mut animator = AnimatorState::default();
+        animator.set_param("attack", AnimParam::Bool(true));
+        animator.set_param("custom", AnimParam::Float(2.0));
+
+        animator.declare_params(&def);
+        assert_eq!(animator.params["attack"], AnimParam::Trigger(true));
+        assert_eq!(animator.params["custom"], AnimParam::Float(2.0));
+    }
+
+    #[test]
+    fn higher_priority_transition_wins() {
+        let mut animator = animator();
+        step_state_machine(&mut animator, 0.0);
+
+        animator.set_param("attack", AnimParam::Bool(true));
+        animator.set_param(PARAM_GROUNDED, AnimParam::Bool(false));
+        assert_eq!(
+            step_state_machine(&mut animator, 0.0).as_deref(),
+            Some("fall")
+        );
+        assert_eq!(animator.params["attack"], AnimParam::Trigger(true));
+    }
+
+    #[test]
+    fn animator_state_is_left_out_of_saves() {
+        let mut ecs = Ecs::default();
+        ecs.create_entity().with(AnimatorState::default()).finish();
+
+        let saved = ron::ser::to_string(&ecs).unwrap();
+        assert!(!saved.contains(AnimatorState::TYPE_NAME));
+    }
+
+    #[test]
+    fn exit_time_of_one_fires_after_a_looping_clip_wraps() {
+        use crate::animation::animation_system::advance_clip;
+
+        let mut animator = animator();
+        step_state_machine(&mut animator, 0.0);
+        animator.set_param("attack", AnimParam::Bool(true));
+        step_state_machine(&mut animator, 0.0);
+
+        let clip = ClipDef {
+            cols: 4,
+            rows: 1,
+            fps: 10.0,
+            looping: true,
+            ..Default::default()
+        };
+        let mut state = ClipState {
+            timer: 0.35,
+            ..Default::default()
+        };
+        advance_clip(&clip, &mut state, &mut Vec::new());
+        assert_eq!(
+            step_state_machine(&mut animator, clip.progress(&state)),
+            None
+        );
+
+        state.timer += 0.1;
+        advance_clip(&clip, &mut state, &mut Vec::new());
+        assert_eq!(state.step, 0);
+        assert_eq!(
+            step_state_machine(&mut animator, clip.progress(&state)).as_deref(),
+            Some("idle")
+        );
+    }
+}
@@ -1,6 +1,8 @@
 // engine_core/src/assets/asset_manager.rs
 use crate::animation::animation_clip::Animation;
+use crate::assets::font_loader::load_game_font;
 use crate::assets::sprite::*;
+use crate::constants::ICONS_FOLDER;
 use crate::game::Game;
 use crate::storage::path_utils::assets_folder;
 use crate::task::FileReadPool;
@@ -56,6 +58,9 @@ pub struct AssetManager {
     /// Placeholder texture returned for unset or missing sprite ids.
     #[serde(skip)]
     empty_texture: Option<Texture2D>,
+    /// Fonts loaded by name. Fonts that failed to load map to the default font.
+    #[serde(skip)]
+    fonts: HashMap<String, FontId>,
 }
 
 impl AssetManager {
@@ -332,6 +337,44 @@ impl AssetManager {
             .to_path_buf()
     }
 
+    /// Returns the texture for a rich text `[icon=name]` tag, loading
+    /// `icons/<name>.png` from the assets folder on first use.
+    pub fn icon_texture(&mut self, loader: &impl TextureLoader, name: &str) -> Option<Texture2D> {
+        let path = Path::new(ICONS_FOLDER).join(format!("{name}.png"));
+        if let Some(texture) = self
+            .path_to_sprite_id
+            .get(&path)
+            .and_then(|id| self.textures.get(id))
+        {
+            return Some(texture.clone());
+        }
+
+        // Missing icons are skipped quietly rather than reported every frame
+        if !assets_folder().join(&path).is_file() {
+            return None;
+        }
+        let id = self.init_texture(loader, &path).ok()?;
+        self.textures.get(&id).cloned()
+    }
+
+    /// Returns the font for a name from the fonts folder, loading it on first use.
+    /// An empty name, or a font that fails to load, gives the default font.
+    pub fn font(&mut self, ctx: &mut impl Text, name: &str) -> FontId {
+        if name.is_empty() {
+            return FontId::DEFAULT;
+        }
+        if let Some(font) = self.fonts.get(name) {
+            return *font;
+        }
+
+        let font = load_game_font(ctx, name).unwrap_or_else(|e| {
+            onscreen_error!("{}", e);
+            FontId::DEFAULT
+        });
+        self.fonts.insert(name.to_string(), font);
+        font
+    }
+
     /// Returns true if the texture for `id` is already present.
     #[inline]
     pub fn contains(&self, id: SpriteId) -> bool {
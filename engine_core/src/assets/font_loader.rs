@@ -0,0 +1,57 @@
+// engine_core/src/assets/font_loader.rs
+use crate::storage::path_utils::fonts_folder;
+use bishop::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Font file extensions tried, in order, for a font name without an extension.
+const FONT_EXTENSIONS: [&str; 3] = ["ttf", "otf", "fnt"];
+
+/// Loads `<name>.ttf`, `<name>.otf` or `<name>.fnt` from the fonts folder into `ctx`.
+/// Names may include subfolders or an explicit extension.
+pub fn load_game_font(ctx: &mut impl Text, name: &str) -> Result<FontId, String> {
+    let path = find_font_file(&fonts_folder(), name)
+        .ok_or_else(|| format!("Font '{name}' not found in the fonts folder"))?;
+
+    let result = if path.extension().is_some_and(|ext| ext == "fnt") {
+        load_bitmap_font(ctx, &path)
+    } else {
+        let data = fs::read(&path).map_err(|e| e.to_string())?;
+        ctx.load_font(FontSource::Vector(&data))
+    };
+    result.map_err(|e| format!("Failed to load font '{name}': {e}"))
+}
+
+/// Returns the font file for `name` inside `folder`, if one exists.
+fn find_font_file(folder: &Path, name: &str) -> Option<PathBuf> {
+    let path = folder.join(name);
+    if path.extension().is_some() {
+        return path.is_file().then_some(path);
+    }
+    FONT_EXTENSIONS
+        .iter()
+        .map(|ext| path.with_extension(ext))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Loads a BMFont descriptor and its page image, which sits next to the descriptor.
+/// Only single-page fonts are supported.
+fn load_bitmap_font(ctx: &mut impl Text, path: &Path) -> Result<FontId, String> {
+    let source = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let descriptor = BitmapFontDescriptor::parse(&source)?;
+    if descriptor.is_multi_page() {
+        return Err("bitmap fonts with more than one texture page are not supported".to_string());
+    }
+    let page_file = descriptor
+        .pages
+        .first()
+        .ok_or("descriptor has no texture page")?;
+    let page_path = path.parent().unwrap_or(Path::new("")).join(page_file);
+    let page = fs::read(&page_path)
+        .map_err(|e| format!("could not read page '{}': {e}", page_path.display()))?;
+
+    ctx.load_font(FontSource::Bitmap {
+        descriptor,
+        page: &page,
+    })
+}
@@ -1,7 +1,9 @@
 pub mod asset_manager;
 pub mod core_assets;
+pub mod font_loader;
 pub mod sprite;
 
 pub use asset_manager::*;
 pub use core_assets::*;
+pub use font_loader::*;
 pub use sprite::*;
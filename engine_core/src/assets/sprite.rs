@@ -3,12 +3,12 @@ use crate::assets::asset_manager::AssetManager;
 use crate::ecs::entity::Entity;
 use crate::game::GameCtxMut;
 use crate::inspector_module;
-use crate::rendering::render_room::pivot_adjusted_position;
-use crate::rendering::renderable::{EntityDrawParams, Renderable};
+use crate::rendering::renderable::{EntityDrawParams, Renderable, tint_color};
 use bishop::prelude::*;
 use ecs_component::ecs_component;
 use reflect_derive::Reflect;
 use serde::{Deserialize, Serialize};
+use serde_with::{FromInto, serde_as};
 
 /// Opaque handle that the asset manager gives out. Default/Unset is 0.
 #[derive(
@@ -17,16 +17,24 @@ use serde::{Deserialize, Serialize};
 pub struct SpriteId(pub usize);
 
 #[ecs_component(post_create = post_create, post_remove = post_remove)]
+#[serde_as]
 #[derive(Clone, Serialize, Deserialize, Reflect)]
+#[serde(default)]
 pub struct Sprite {
     /// Reference to the texture stored by the AssetManager.
     pub sprite: SpriteId,
+    /// Color multiplied with the texture.
+    #[serde_as(as = "FromInto<[f32; 3]>")]
+    pub tint: Vec3,
+    pub opacity: f32,
 }
 
 impl Default for Sprite {
     fn default() -> Self {
         Self {
             sprite: SpriteId(0),
+            tint: Vec3::ONE,
+            opacity: 1.0,
         }
     }
 }
@@ -60,16 +68,13 @@ impl Renderable for Sprite {
 
         let tex = asset_manager.get_texture_from_id(ctx, self.sprite);
         let size = vec2(tex.width(), tex.height());
-        let draw_base = pivot_adjusted_position(params.pos, size, params.pivot);
+        let (draw_base, texture_params) = params.placement(size, Vec2::ZERO, false);
         ctx.draw_texture_ex(
             tex,
             draw_base.x,
             draw_base.y,
-            Color::WHITE,
-            DrawTextureParams {
-                dest_size: Some(size),
-                ..Default::default()
-            },
+            tint_color(self.tint, self.opacity),
+            texture_params,
         );
         true
     }
@@ -166,6 +166,33 @@ impl AudioSource {
         ids.sort();
         ids
     }
+
+    /// Plays a group, as a loop tracked by `handle` or as a varied one-shot.
+    /// Returns false if the group does not exist.
+    pub fn play_group(&self, group_id: &SoundGroupId, handle: u64) -> bool {
+        let Some(group) = self.groups.get(group_id) else {
+            return false;
+        };
+        let volume = (group.volume * self.runtime_volume).clamp(0.0, 1.0);
+
+        if group.looping {
+            push_audio_command(AudioCommand::PlayLoop {
+                handle,
+                sounds: group.sounds.clone(),
+                volume,
+                pitch_variation: group.pitch_variation,
+                volume_variation: group.volume_variation,
+            });
+        } else {
+            push_audio_command(AudioCommand::PlayVariedSfx {
+                sounds: group.sounds.clone(),
+                volume,
+                pitch_variation: group.pitch_variation,
+                volume_variation: group.volume_variation,
+            });
+        }
+        true
+    }
 }
 
 impl Default for AudioSource {
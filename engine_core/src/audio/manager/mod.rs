@@ -29,6 +29,8 @@ use std::sync::Arc;
 type LoopMusicHandle = Handle<Stop<Gain<Cycle<[f32; 2]>>>>;
 /// Handle type for active one-shot music signals.
 type OneShotMusicHandle = Handle<Stop<Gain<FramesSignal<[f32; 2]>>>>;
+/// Boxed mixer render function handed to an [`AudioBackend`].
+pub type AudioRenderFn = Box<dyn FnMut(&mut [[f32; 2]]) + Send>;
 /// Handle type for active looping SFX signals.
 type LoopHandle = Handle<Stop<Gain<Speed<Cycle<[f32; 2]>>>>>;
 #[cfg(feature = "editor")]
@@ -176,6 +178,14 @@ impl AudioManager {
     /// Builds the signal graph: root mixer → music group (Gain<Mixer>) +
     /// sfx group (Gain<Mixer>). The rendered signal is driven by the backend.
     pub fn new<B: AudioBackend>() -> Self {
+        Self::with_backend(B::start)
+    }
+
+    /// Constructs an `AudioManager`, handing the mixer's render function to `start`.
+    ///
+    /// Use this when the caller needs to keep a handle to the backend it creates,
+    /// e.g. a [`bishop::audio::WavBackend`] capture for offline rendering.
+    pub fn with_backend<B: AudioBackend>(start: impl FnOnce(AudioRenderFn) -> B) -> Self {
         let (mut root_handle, root_signal) = oddio::split(Mixer::<[f32; 2]>::new());
 
         let music_group_handle = root_handle
@@ -188,9 +198,9 @@ impl AudioManager {
 
         const SAMPLE_RATE: u32 = 44_100;
         let keep_alive: Box<dyn Send + 'static> =
-            Box::new(B::start(move |frames: &mut [[f32; 2]]| {
+            Box::new(start(Box::new(move |frames: &mut [[f32; 2]]| {
                 oddio::run(&root_signal, SAMPLE_RATE, frames);
-            }));
+            })));
         // `root_signal` is owned by the backend render closure, so only the subgroup
         // handles need to be retained after graph construction.
 
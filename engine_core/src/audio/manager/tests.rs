@@ -3,7 +3,7 @@ use crate::audio::command_queue::{drain_audio_commands, push_audio_command};
 use crate::audio::runtime;
 use crate::audio::{AudioCommand, AudioDiagnosticsEntry, PlayMusicRequest};
 use crate::task::BackgroundService;
-use bishop::audio::AudioBackend;
+use bishop::audio::{AudioBackend, WavBackend, WavCapture};
 use oddio::Frames;
 
 struct TestBackend;
@@ -37,6 +37,30 @@ fn seeded_manager() -> AudioManager {
     manager
 }
 
+fn wav_manager() -> (AudioManager, WavCapture) {
+    runtime::reset_for_tests();
+    let _ = drain_audio_commands();
+
+    let mut capture = None;
+    let mut manager = AudioManager::with_backend(|render_fn| {
+        let backend = WavBackend::start(render_fn);
+        capture = Some(backend.capture());
+        backend
+    });
+    manager.sound_cache.insert(
+        "sfx/tone".to_string(),
+        Frames::from_slice(44_100, &[[0.5, 0.5]; 256]),
+    );
+    (manager, capture.expect("backend started"))
+}
+
+fn peak(frames: &[[f32; 2]]) -> f32 {
+    frames
+        .iter()
+        .flat_map(|frame| frame.iter())
+        .fold(0.0f32, |acc, sample| acc.max(sample.abs()))
+}
+
 fn assert_approx_eq(actual: f32, expected: f32) {
     assert!(
         (actual - expected).abs() < 0.001,
@@ -1273,3 +1297,55 @@ fn stop_tracked_preview_removes_preview_handle() {
     manager.poll(0.0);
     assert!(!manager.tracked_previews.contains_key(&23));
 }
+
+#[test]
+fn wav_capture_records_one_shot_then_silence() {
+    let (mut manager, capture) = wav_manager();
+
+    push_audio_command(AudioCommand::PlaySfx("sfx/tone".to_string()));
+    manager.poll(0.0);
+    capture.render_frames(1024).unwrap();
+
+    let frames = capture.take_frames();
+    assert_approx_eq(peak(&frames[16..200]), 0.5);
+    assert_eq!(peak(&frames[512..]), 0.0);
+}
+
+#[test]
+fn wav_capture_applies_sfx_volume_to_mixed_output() {
+    let (mut manager, capture) = wav_manager();
+
+    push_audio_command(AudioCommand::SetSfxVolume(0.5));
+    manager.poll(0.0);
+    // Let the smoothed group gain settle before the sound starts.
+    capture.render_seconds(0.25).unwrap();
+    let _ = capture.take_frames();
+
+    push_audio_command(AudioCommand::PlaySfx("sfx/tone".to_string()));
+    manager.poll(0.0);
+    capture.render_frames(256).unwrap();
+
+    assert_approx_eq(peak(&capture.take_frames()[16..200]), 0.25);
+}
+
+#[test]
+fn wav_capture_falls_silent_after_loop_stops() {
+    let (mut manager, capture) = wav_manager();
+
+    push_audio_command(AudioCommand::PlayLoop {
+        handle: 7,
+        sounds: vec!["sfx/tone".to_string()],
+        volume: 1.0,
+        pitch_variation: 0.0,
+        volume_variation: 0.0,
+    });
+    manager.poll(0.0);
+    capture.render_frames(2048).unwrap();
+    assert_approx_eq(peak(&capture.take_frames()[1024..]), 0.5);
+
+    push_audio_command(AudioCommand::StopLoop(7));
+    manager.poll(0.0);
+    capture.render_frames(1024).unwrap();
+
+    assert_eq!(peak(&capture.take_frames()[512..]), 0.0);
+}
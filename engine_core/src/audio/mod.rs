@@ -11,5 +11,5 @@ pub use audio_source::{AudioGroup, AudioSource, SoundGroupId, SoundPresetLink};
 pub use command_queue::{AudioCommand, PlayMusicRequest, push_audio_command};
 pub use diagnostics::{AudioDiagnosticsEntry, AudioDiagnosticsSnapshot};
 pub use loader::load_wav;
-pub use manager::AudioManager;
+pub use manager::{AudioManager, AudioRenderFn};
 pub use runtime::{MusicStopReason, MusicStoppedEvent};
@@ -0,0 +1,361 @@
+// engine_core/src/camera/camera_effects.rs
+use crate::ecs::ecs::Ecs;
+use crate::ecs::entity::Entity;
+use crate::ecs::transform::Transform;
+use crate::tween::easing::Easing;
+use bishop::prelude::*;
+
+/// Offset in pixels of a shake at full trauma.
+const MAX_SHAKE_OFFSET: f32 = 8.0;
+
+/// Oscillations per second of the shake noise.
+const SHAKE_FREQUENCY: f32 = 25.0;
+
+/// Seconds a `pan_to` holds on its point before returning by default.
+pub const DEFAULT_PAN_HOLD: f32 = 1.0;
+
+/// What a pan moves the camera to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PanTarget {
+    Point(Vec2),
+    /// Tracks the entity's position every step.
+    Entity(Entity),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PanPhase {
+    /// Travelling from `from` to the target.
+    Moving { from: Vec2 },
+    /// Sitting on the target. `None` holds until released.
+    Holding { left: Option<f32> },
+    /// Travelling from `from` back to the camera's own target.
+    Returning { from: Vec2 },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pan {
+    target: PanTarget,
+    duration: f32,
+    easing: Easing,
+    hold: Option<f32>,
+    phase: PanPhase,
+    elapsed: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ZoomEffect {
+    from: f32,
+    to: f32,
+    duration: f32,
+    elapsed: f32,
+    easing: Easing,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Blend {
+    from_target: Vec2,
+    from_zoom: Vec2,
+    duration: f32,
+    elapsed: f32,
+}
+
+/// Shake, zoom, pan and blend effects layered over the active camera.
+#[derive(Debug, Clone)]
+pub struct CameraEffects {
+    /// Seconds a switch between room cameras blends over. `0` cuts.
+    pub blend_duration: f32,
+    /// Shake strength in `0..=1`. The offset grows with its square.
+    trauma: f32,
+    /// Trauma lost per second.
+    trauma_decay: f32,
+    /// Running clock that drives the shake noise.
+    shake_time: f32,
+    /// Multiplier applied to the camera zoom.
+    zoom_factor: f32,
+    zoom: Option<ZoomEffect>,
+    pan: Option<Pan>,
+    blend: Option<Blend>,
+    /// Last target and zoom before shake, where new pans and blends start.
+    last_view: Option<(Vec2, Vec2)>,
+}
+
+impl Default for CameraEffects {
+    fn default() -> Self {
+        Self {
+            blend_duration: 0.5,
+            trauma: 0.0,
+            trauma_decay: 0.0,
+            shake_time: 0.0,
+            zoom_factor: 1.0,
+            zoom: None,
+            pan: None,
+            blend: None,
+            last_view: None,
+        }
+    }
+}
+
+impl CameraEffects {
+    /// Adds `intensity` trauma, fading out over `duration` seconds.
+    pub fn shake(&mut self, intensity: f32, duration: f32) {
+        self.trauma = (self.trauma + intensity.max(0.0)).min(1.0);
+        self.trauma_decay = if duration > 0.0 {
+            self.trauma / duration
+        } else {
+            f32::INFINITY
+        };
+    }
+
+    /// Changes the zoom multiplier to `factor` over `duration` seconds.
+    pub fn zoom_to(&mut self, factor: f32, duration: f32, easing: Easing) {
+        self.zoom = Some(ZoomEffect {
+            from: self.zoom_factor,
+            to: factor.max(f32::EPSILON),
+            duration: duration.max(0.0),
+            elapsed: 0.0,
+            easing,
+        });
+    }
+
+    /// Pans to `target` over `duration` seconds. After holding for `hold`
+    /// seconds the camera returns on its own; `None` holds until `release`.
+    pub fn pan_to(&mut self, target: PanTarget, duration: f32, hold: Option<f32>, easing: Easing) {
+        let from = self.last_view.map(|(target, _)| target).unwrap_or_default();
+        self.pan = Some(Pan {
+            target,
+            duration: duration.max(0.0),
+            easing,
+            hold: hold.map(|h| h.max(0.0)),
+            phase: PanPhase::Moving { from },
+            elapsed: 0.0,
+        });
+    }
+
+    /// Sends a pan back to the camera's own target over `duration` seconds.
+    pub fn release(&mut self, duration: Option<f32>) {
+        let Some(pan) = &mut self.pan else {
+            return;
+        };
+        if let PanPhase::Returning { .. } = pan.phase {
+            return;
+        }
+        let from = self.last_view.map(|(target, _)| target).unwrap_or_default();
+        pan.duration = duration.map_or(pan.duration, |d| d.max(0.0));
+        pan.phase = PanPhase::Returning { from };
+        pan.elapsed = 0.0;
+    }
+
+    /// Blends from the current view into the next camera.
+    /// Returns false when blending is disabled and the camera should cut.
+    pub fn start_blend(&mut self) -> bool {
+        let Some((from_target, from_zoom)) = self.last_view else {
+            return false;
+        };
+        if self.blend_duration <= 0.0 {
+            return false;
+        }
+        self.blend = Some(Blend {
+            from_target,
+            from_zoom,
+            duration: self.blend_duration,
+            elapsed: 0.0,
+        });
+        true
+    }
+
+    /// Drops a running blend so the view cuts to the active camera.
+    pub fn cancel_blend(&mut self) {
+        self.blend = None;
+    }
+
+    /// Multiplier the zoom effect currently applies to the camera zoom.
+    pub fn zoom_factor(&self) -> f32 {
+        self.zoom_factor
+    }
+
+    /// Returns true while a pan is active.
+    pub fn is_panning(&self) -> bool {
+        self.pan.is_some()
+    }
+
+    /// Stops every effect.
+    pub fn clear(&mut self) {
+        *self = Self {
+            blend_duration: self.blend_duration,
+            ..Self::default()
+        };
+    }
+
+    /// Advances the effects by `dt` and applies them to the camera's own
+    /// `target` and `zoom`. Returns the target and zoom to render with.
+    pub fn update(&mut self, ecs: &Ecs, target: Vec2, zoom: Vec2, dt: f32) -> (Vec2, Vec2) {
+        let mut view = target;
+        let mut view_zoom = zoom;
+
+        if let Some(blend) = &mut self.blend {
+            blend.elapsed += dt;
+            let t = Easing::InOutSine.apply(progress(blend.elapsed, blend.duration));
+            view = blend.from_target.lerp(view, t);
+            view_zoom = blend.from_zoom.lerp(view_zoom, t);
+            if t >= 1.0 {
+                self.blend = None;
+            }
+        }
+
+        if let Some(pan) = &mut self.pan {
+            match advance_pan(pan, ecs, view, dt) {
+                Some(position) => view = position,
+                None => self.pan = None,
+            }
+        }
+
+        if let Some(zoom) = &mut self.zoom {
+            zoom.elapsed += dt;
+            let t = progress(zoom.elapsed, zoom.duration);
+            self.zoom_factor = zoom.from + (zoom.to - zoom.from) * zoom.easing.apply(t);
+            if t >= 1.0 {
+                self.zoom = None;
+            }
+        }
+        view_zoom *= self.zoom_factor;
+
+        self.last_view = Some((view, view_zoom));
+
+        if self.trauma > 0.0 {
+            self.shake_time += dt;
+            let strength = self.trauma * self.trauma * MAX_SHAKE_OFFSET;
+            view += vec2(
+                shake_noise(self.shake_time, 0.0),
+                shake_noise(self.shake_time, 17.3),
+            ) * strength;
+            self.trauma = (self.trauma - self.trauma_decay * dt).max(0.0);
+        }
+
+        (view, view_zoom)
+    }
+}
+
+/// Moves a pan one step. Returns its position, or `None` once it has returned.
+fn advance_pan(pan: &mut Pan, ecs: &Ecs, camera_target: Vec2, dt: f32) -> Option<Vec2> {
+    let point = match pan.target {
+        PanTarget::Point(point) => Some(point),
+        PanTarget::Entity(entity) => ecs.get::<Transform>(entity).map(|t| t.position),
+    };
+
+    // A removed entity sends the camera home
+    let Some(point) = point else {
+        if !matches!(pan.phase, PanPhase::Returning { .. }) {
+            pan.phase = PanPhase::Returning {
+                from: camera_target,
+            };
+            pan.elapsed = 0.0;
+        }
+        return Some(camera_target);
+    };
+
+    pan.elapsed += dt;
+    match pan.phase {
+        PanPhase::Moving { from } => {
+            let t = progress(pan.elapsed, pan.duration);
+            if t >= 1.0 {
+                pan.phase = PanPhase::Holding { left: pan.hold };
+                pan.elapsed = 0.0;
+            }
+            Some(from.lerp(point, pan.easing.apply(t)))
+        }
+        PanPhase::Holding { left } => {
+            if let Some(left) = left {
+                let left = left - dt;
+                pan.phase = if left <= 0.0 {
+                    pan.elapsed = 0.0;
+                    PanPhase::Returning { from: point }
+                } else {
+                    PanPhase::Holding { left: Some(left) }
+                };
+            }
+            Some(point)
+        }
+        PanPhase::Returning { from } => {
+            let t = progress(pan.elapsed, pan.duration);
+            (t < 1.0).then(|| from.lerp(camera_target, pan.easing.apply(t)))
+        }
+    }
+}
+
+/// Normalised progress through `duration`, finishing at once for zero durations.
+fn progress(elapsed: f32, duration: f32) -> f32 {
+    if duration > 0.0 {
+        (elapsed / duration).min(1.0)
+    } else {
+        1.0
+    }
+}
+
+/// Smooth pseudo random value in `-1..=1`. `seed` decorrelates the axes.
+fn shake_noise(time: f32, seed: f32) -> f32 {
+    let t = time * SHAKE_FREQUENCY;
+    ((t + seed).sin() * 0.6 + (t * 2.3 + seed * 1.7).sin() * 0.4).clamp(-1.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shake_fades_out_over_its_duration() {
+        let ecs = Ecs::default();
+        let mut effects = CameraEffects::default();
+        effects.shake(1.0, 0.5);
+
+        let (shaken, _) = effects.update(&ecs, Vec2::ZERO, Vec2::ONE, 0.1);
+        assert!(shaken != Vec2::ZERO);
+
+        for _ in 0..5 {
+            effects.update(&ecs, Vec2::ZERO, Vec2::ONE, 0.1);
+        }
+        let (settled, _) = effects.update(&ecs, Vec2::ZERO, Vec2::ONE, 0.1);
+        assert_eq!(settled, Vec2::ZERO);
+    }
+
+    #[test]
+    fn pan_moves_holds_and_returns() {
+        let ecs = Ecs::default();
+        let mut effects = CameraEffects::default();
+        let home = vec2(10.0, 10.0);
+        effects.update(&ecs, home, Vec2::ONE, 0.0);
+
+        effects.pan_to(
+            PanTarget::Point(vec2(110.0, 10.0)),
+            1.0,
+            Some(0.5),
+            Easing::Linear,
+        );
+        let (halfway, _) = effects.update(&ecs, home, Vec2::ONE, 0.5);
+        assert_eq!(halfway, vec2(60.0, 10.0));
+
+        let (arrived, _) = effects.update(&ecs, home, Vec2::ONE, 0.5);
+        assert_eq!(arrived, vec2(110.0, 10.0));
+        let (held, _) = effects.update(&ecs, home, Vec2::ONE, 0.5);
+        assert_eq!(held, vec2(110.0, 10.0));
+
+        effects.update(&ecs, home, Vec2::ONE, 0.5);
+        let (back, _) = effects.update(&ecs, home, Vec2::ONE, 0.5);
+        assert_eq!(back, home);
+        assert!(!effects.is_panning());
+    }
+
+    #[test]
+    fn blend_eases_between_cameras() {
+        let ecs = Ecs::default();
+        let mut effects = CameraEffects::default();
+        effects.update(&ecs, Vec2::ZERO, Vec2::ONE, 0.0);
+        assert!(effects.start_blend());
+
+        let (mid, zoom) = effects.update(&ecs, vec2(100.0, 0.0), vec2(3.0, 3.0), 0.25);
+        assert!((mid.x - 50.0).abs() < 1e-3);
+        assert!((zoom.x - 2.0).abs() < 1e-3);
+
+        let (done, _) = effects.update(&ecs, vec2(100.0, 0.0), vec2(3.0, 3.0), 0.25);
+        assert_eq!(done, vec2(100.0, 0.0));
+    }
+}
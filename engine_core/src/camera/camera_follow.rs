@@ -0,0 +1,294 @@
+// engine_core/src/camera/camera_follow.rs
+use crate::camera::game_camera::FollowRestriction;
+use bishop::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_with::{FromInto, serde_as};
+
+/// Share of the view height the player may move through while airborne
+/// before a platform snapping camera follows vertically.
+const AIRBORNE_ZONE: f32 = 0.75;
+
+/// Longest look-ahead as a share of the half view.
+const MAX_LOOK_AHEAD: f32 = 0.5;
+
+/// How a following camera tracks the player.
+#[serde_as]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct CameraFollow {
+    /// Size in pixels of the box around the camera focus the player can
+    /// move in without moving the camera.
+    #[serde_as(as = "FromInto<[f32; 2]>")]
+    pub dead_zone: Vec2,
+    /// Roughly the seconds the camera takes to catch up, per axis. `0` snaps.
+    #[serde_as(as = "FromInto<[f32; 2]>")]
+    pub smooth_time: Vec2,
+    /// Seconds of player velocity the camera leads by, per axis.
+    #[serde_as(as = "FromInto<[f32; 2]>")]
+    pub look_ahead: Vec2,
+    /// Only re-centre vertically while the player is grounded.
+    pub platform_snap: bool,
+    /// Keep the view inside the room so the void around it never shows.
+    pub clamp_to_room: bool,
+}
+
+impl Default for CameraFollow {
+    fn default() -> Self {
+        Self {
+            dead_zone: Vec2::ZERO,
+            smooth_time: Vec2::ZERO,
+            look_ahead: Vec2::ZERO,
+            platform_snap: false,
+            clamp_to_room: true,
+        }
+    }
+}
+
+/// Runtime state of the active following camera.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FollowState {
+    /// Point kept within the dead zone around the player, before look-ahead.
+    pub focus: Vec2,
+    /// Smoothing velocity per axis.
+    pub velocity: Vec2,
+}
+
+impl FollowState {
+    /// Starts tracking with the focus on `player_pos`.
+    pub fn new(player_pos: Vec2) -> Self {
+        Self {
+            focus: player_pos,
+            velocity: Vec2::ZERO,
+        }
+    }
+}
+
+/// Everything a follow step needs to know about the current frame.
+#[derive(Debug, Clone, Copy)]
+pub struct FollowFrame {
+    pub player_pos: Vec2,
+    pub player_velocity: Vec2,
+    pub grounded: bool,
+    /// The camera entity's position, used by the clamped restrictions.
+    pub origin: Vec2,
+    /// Half the size of the view in world pixels.
+    pub half_view: Vec2,
+    /// Top left and bottom right corners of the room.
+    pub room_bounds: (Vec2, Vec2),
+    pub dt: f32,
+}
+
+/// Moves a camera currently looking at `current` one step towards the player.
+/// Returns the new camera target.
+pub fn follow_target(
+    settings: &CameraFollow,
+    restriction: FollowRestriction,
+    current: Vec2,
+    state: &mut FollowState,
+    frame: &FollowFrame,
+) -> Vec2 {
+    // Drag the focus along once the player leaves the dead zone
+    let mut zone = settings.dead_zone.max(Vec2::ZERO);
+    if settings.platform_snap && !frame.grounded {
+        zone.y = zone.y.max(frame.half_view.y * 2.0 * AIRBORNE_ZONE);
+    }
+    state.focus = vec2(
+        drag_into_zone(state.focus.x, frame.player_pos.x, zone.x / 2.0),
+        drag_into_zone(state.focus.y, frame.player_pos.y, zone.y / 2.0),
+    );
+
+    let max_lead = frame.half_view * MAX_LOOK_AHEAD;
+    let lead = (frame.player_velocity * settings.look_ahead).clamp(-max_lead, max_lead);
+    let mut desired = state.focus + lead;
+
+    match restriction {
+        FollowRestriction::Free => {}
+        FollowRestriction::ClampX => desired.x = frame.origin.x,
+        FollowRestriction::ClampY => desired.y = frame.origin.y,
+    }
+    if settings.clamp_to_room {
+        desired = clamp_to_bounds(desired, frame.half_view, frame.room_bounds);
+    }
+
+    let target = vec2(
+        smooth_damp(
+            current.x,
+            desired.x,
+            &mut state.velocity.x,
+            settings.smooth_time.x,
+            frame.dt,
+        ),
+        smooth_damp(
+            current.y,
+            desired.y,
+            &mut state.velocity.y,
+            settings.smooth_time.y,
+            frame.dt,
+        ),
+    );
+
+    if settings.clamp_to_room {
+        clamp_to_bounds(target, frame.half_view, frame.room_bounds)
+    } else {
+        target
+    }
+}
+
+/// Moves `focus` the least distance that puts `target` within `half_zone` of it.
+fn drag_into_zone(focus: f32, target: f32, half_zone: f32) -> f32 {
+    if target > focus + half_zone {
+        target - half_zone
+    } else if target < focus - half_zone {
+        target + half_zone
+    } else {
+        focus
+    }
+}
+
+/// Keeps a view of `half_view` centred on `target` inside `bounds`.
+/// Views larger than the room are centred on it.
+pub fn clamp_to_bounds(target: Vec2, half_view: Vec2, bounds: (Vec2, Vec2)) -> Vec2 {
+    let (min, max) = bounds;
+    let clamp_axis = |value: f32, half: f32, min: f32, max: f32| {
+        if max - min <= half * 2.0 {
+            (min + max) / 2.0
+        } else {
+            value.clamp(min + half, max - half)
+        }
+    };
+    vec2(
+        clamp_axis(target.x, half_view.x, min.x, max.x),
+        clamp_axis(target.y, half_view.y, min.y, max.y),
+    )
+}
+
+/// Critically damped spring that moves `current` towards `target`
+/// without overshooting. `smooth_time` of zero snaps straight to the target.
+pub fn smooth_damp(
+    current: f32,
+    target: f32,
+    velocity: &mut f32,
+    smooth_time: f32,
+    dt: f32,
+) -> f32 {
+    if smooth_time <= 0.0 || dt <= 0.0 {
+        *velocity = 0.0;
+        return target;
+    }
+
+    let omega = 2.0 / smooth_time;
+    let x = omega * dt;
+    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = current - target;
+    let temp = (*velocity + omega * change) * dt;
+    *velocity = (*velocity - omega * temp) * decay;
+    let output = target + (change + temp) * decay;
+
+    // Stop at the target instead of overshooting it
+    if (target > current) == (output > target) && output != target {
+        *velocity = 0.0;
+        return target;
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(player_pos: Vec2) -> FollowFrame {
+        FollowFrame {
+            player_pos,
+            player_velocity: Vec2::ZERO,
+            grounded: true,
+            origin: Vec2::ZERO,
+            half_view: vec2(50.0, 30.0),
+            room_bounds: (Vec2::ZERO, vec2(400.0, 200.0)),
+            dt: 1.0 / 60.0,
+        }
+    }
+
+    #[test]
+    fn dead_zone_holds_the_camera_until_the_player_leaves_it() {
+        let settings = CameraFollow {
+            dead_zone: vec2(20.0, 20.0),
+            ..Default::default()
+        };
+        let mut state = FollowState::new(vec2(100.0, 100.0));
+
+        let held = follow_target(
+            &settings,
+            FollowRestriction::Free,
+            vec2(100.0, 100.0),
+            &mut state,
+            &frame(vec2(108.0, 100.0)),
+        );
+        assert_eq!(held, vec2(100.0, 100.0));
+
+        let dragged = follow_target(
+            &settings,
+            FollowRestriction::Free,
+            held,
+            &mut state,
+            &frame(vec2(125.0, 100.0)),
+        );
+        assert_eq!(dragged, vec2(115.0, 100.0));
+    }
+
+    #[test]
+    fn smoothing_approaches_without_overshooting() {
+        let mut velocity = 0.0;
+        let mut value = 0.0;
+        let mut last = value;
+        for _ in 0..120 {
+            value = smooth_damp(value, 10.0, &mut velocity, 0.3, 1.0 / 60.0);
+            assert!(value >= last && value <= 10.0);
+            last = value;
+        }
+        assert!((value - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn view_is_clamped_to_the_room() {
+        let bounds = (Vec2::ZERO, vec2(400.0, 40.0));
+        let half_view = vec2(50.0, 30.0);
+
+        assert_eq!(
+            clamp_to_bounds(vec2(10.0, 0.0), half_view, bounds),
+            vec2(50.0, 20.0)
+        );
+        assert_eq!(
+            clamp_to_bounds(vec2(390.0, 35.0), half_view, bounds),
+            vec2(350.0, 20.0)
+        );
+    }
+
+    #[test]
+    fn airborne_platform_camera_keeps_its_height() {
+        let settings = CameraFollow {
+            platform_snap: true,
+            ..Default::default()
+        };
+        let mut state = FollowState::new(vec2(100.0, 100.0));
+        let mut jumping = frame(vec2(100.0, 80.0));
+        jumping.grounded = false;
+
+        let target = follow_target(
+            &settings,
+            FollowRestriction::Free,
+            vec2(100.0, 100.0),
+            &mut state,
+            &jumping,
+        );
+        assert_eq!(target.y, 100.0);
+
+        let landed = follow_target(
+            &settings,
+            FollowRestriction::Free,
+            target,
+            &mut state,
+            &frame(vec2(100.0, 80.0)),
+        );
+        assert_eq!(landed.y, 80.0);
+    }
+}
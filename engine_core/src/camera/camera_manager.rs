@@ -11,8 +11,18 @@ pub struct CameraManager {
     room_cameras: Vec<(Entity, RoomCamera)>,
     /// The id of the room we are currently tracking or `None`.
     current_room: Option<RoomId>,
-    /// The stored previous position of the active game camera.
+    /// The stored previous view target, interpolated from when rendering.
     pub previous_position: Option<Vec2>,
+    /// Shake, zoom, pans and blends applied on top of the active camera.
+    pub effects: CameraEffects,
+    /// Target of the active camera after effects.
+    view_target: Vec2,
+    /// Zoom of the active camera after effects.
+    view_zoom: Vec2,
+    /// Dead zone focus and smoothing velocity of the active follow camera.
+    follow_state: FollowState,
+    /// Set when the next follow step should jump straight onto its target.
+    snap_follow: bool,
 }
 
 impl CameraManager {
@@ -30,22 +40,31 @@ impl CameraManager {
                 .expect("Room must contain at least one camera.");
 
         active_camera.camera.render_target = Some(game_render_target(ctx, grid_size));
+        let view_target = active_camera.camera.target;
+        let view_zoom = active_camera.camera.zoom;
 
         Self {
             active: active_camera,
             room_cameras,
             current_room: Some(room_id),
             previous_position: None,
+            effects: CameraEffects::default(),
+            view_target,
+            view_zoom,
+            follow_state: FollowState::new(player_pos),
+            snap_follow: true,
         }
     }
 
-    /// Picks the best camera and update it if necessary.
+    /// Picks the best camera and update it if necessary, then applies effects.
+    /// `dt` is the fixed step used to smooth follow cameras and run effects.
     pub fn update_active<C: BishopContext>(
         &mut self,
         ctx: &mut C,
         ecs: &Ecs,
         room: &Room,
         grid_size: f32,
+        dt: f32,
     ) {
         // If the player moved to another room get the new cameras
         if self.current_room != Some(room.id) {
@@ -59,22 +78,70 @@ impl CameraManager {
             .map(|t| t.position)
             .unwrap_or_default();
 
-        if let Some((mut best_cam, mode)) =
+        let mut cut = false;
+        if let Some((mut best_cam, room_cam)) =
             Self::find_best_camera_for_room(ecs, &self.room_cameras, player_pos)
         {
-            // Prevent interpolation with the previous camera.
             // Only create a render target when the active camera actually changes.
-            if best_cam.id != self.active.id {
+            let changed = best_cam.id != self.active.id;
+            if changed {
                 best_cam.camera.render_target = Some(game_render_target(ctx, grid_size));
                 self.active = best_cam;
-                self.previous_position = Some(self.active.camera.target);
+                self.follow_state = FollowState::new(player_pos);
+            } else {
+                self.active.camera.zoom = best_cam.camera.zoom;
             }
 
             // Apply follow if needed
-            if let CameraMode::Follow(restriction) = mode {
-                self.apply_follow(&restriction, player_pos);
+            if let CameraMode::Follow(restriction) = room_cam.camera_mode {
+                let frame = self.follow_frame(ecs, room, grid_size, player_pos, dt);
+                // A new camera starts on its target instead of sliding in
+                let settings = if std::mem::take(&mut self.snap_follow) || changed {
+                    CameraFollow {
+                        smooth_time: Vec2::ZERO,
+                        ..room_cam.follow
+                    }
+                } else {
+                    room_cam.follow
+                };
+                self.active.camera.target = follow_target(
+                    &settings,
+                    restriction,
+                    self.active.camera.target,
+                    &mut self.follow_state,
+                    &frame,
+                );
             }
+
+            // Blend into the new camera, or cut without interpolating from the old one
+            cut = changed && !self.effects.start_blend();
         }
+
+        (self.view_target, self.view_zoom) =
+            self.effects
+                .update(ecs, self.active.camera.target, self.active.camera.zoom, dt);
+        if cut {
+            self.previous_position = Some(self.view_target);
+        }
+    }
+
+    /// Cuts the view to the active camera, dropping any blend and interpolation.
+    pub fn cut(&mut self, ecs: &Ecs) {
+        self.effects.cancel_blend();
+        (self.view_target, self.view_zoom) =
+            self.effects
+                .update(ecs, self.active.camera.target, self.active.camera.zoom, 0.0);
+        self.previous_position = Some(self.view_target);
+    }
+
+    /// Target of the active camera with effects applied.
+    pub fn view_target(&self) -> Vec2 {
+        self.view_target
+    }
+
+    /// Zoom of the active camera with effects applied.
+    pub fn view_zoom(&self) -> Vec2 {
+        self.view_zoom
     }
 
     /// Finds the most suitable camera for a given room and player position.
@@ -82,20 +149,22 @@ impl CameraManager {
         ecs: &Ecs,
         room_cameras: &[(Entity, RoomCamera)],
         player_pos: Vec2,
-    ) -> Option<(GameCamera, CameraMode)> {
+    ) -> Option<(GameCamera, RoomCamera)> {
         // Keep track of the camera with the smallest distance to the player
-        let mut closest: Option<(f32, GameCamera, CameraMode)> = None;
+        let mut closest: Option<(f32, GameCamera, RoomCamera)> = None;
 
         for &(entity, ref cam) in room_cameras.iter() {
+            // Prefer the live component so runtime zoom changes (e.g. tweens) apply
+            let cam = ecs.get::<RoomCamera>(entity).unwrap_or(cam);
             let game_cam = room_to_game_camera(ecs, &entity, cam, player_pos);
             match cam.camera_mode {
                 CameraMode::Fixed => {
                     if Self::point_in_camera_view(&game_cam, player_pos) {
-                        return Some((game_cam, cam.camera_mode));
+                        return Some((game_cam, *cam));
                     }
                 }
                 CameraMode::Follow(_) => {
-                    return Some((game_cam, cam.camera_mode));
+                    return Some((game_cam, *cam));
                 }
             }
             // Squared distance between the camera centre and the player
@@ -106,11 +175,11 @@ impl CameraManager {
             // Update the closest so far
             match closest {
                 Some((best_dist, _, _)) if dist_sq >= best_dist => {}
-                _ => closest = Some((dist_sq, game_cam, cam.camera_mode)),
+                _ => closest = Some((dist_sq, game_cam, *cam)),
             }
         }
         // Return the closest as a fallback
-        closest.map(|(_, cam, mode)| (cam, mode))
+        closest.map(|(_, game_cam, cam)| (game_cam, cam))
     }
 
     /// Checks whether `point` lies inside the rectangular view of `cam`.
@@ -124,24 +193,42 @@ impl CameraManager {
         point.x >= left && point.x <= right && point.y >= top && point.y <= bottom
     }
 
-    /// Moves the active camera according to the follow restriction.
-    fn apply_follow(&mut self, restriction: &FollowRestriction, player_pos: Vec2) {
-        match restriction {
-            FollowRestriction::Free => {
-                self.active.camera.target = player_pos;
-            }
-            FollowRestriction::ClampX => {
-                self.active.camera.target.y = player_pos.y;
-            }
-            FollowRestriction::ClampY => {
-                self.active.camera.target.x = player_pos.x;
-            }
+    /// Gathers the player and room state a follow step needs.
+    fn follow_frame(
+        &self,
+        ecs: &Ecs,
+        room: &Room,
+        grid_size: f32,
+        player_pos: Vec2,
+        dt: f32,
+    ) -> FollowFrame {
+        let player = ecs.get_player_entity();
+        let player_velocity = player
+            .and_then(|p| ecs.get::<Velocity>(p))
+            .map(|v| vec2(v.x, v.y))
+            .unwrap_or_default();
+        let grounded = player
+            .and_then(|p| ecs.get::<Grounded>(p))
+            .is_none_or(|g| g.0);
+        // The zoom effect narrows or widens the view the follow keeps inside the room
+        let zoom = (self.active.camera.zoom * self.effects.zoom_factor())
+            .abs()
+            .max(Vec2::splat(f32::EPSILON));
+
+        FollowFrame {
+            player_pos,
+            player_velocity,
+            grounded,
+            origin: self.active.origin,
+            half_view: Vec2::ONE / zoom,
+            room_bounds: room.room_bounds(grid_size),
+            dt,
         }
     }
 
     /// Returns the interpolated camera target for rendering.
     pub fn interpolated_target(&self, alpha: f32) -> Vec2 {
-        let prev = self.previous_position.unwrap_or(self.active.camera.target);
-        lerp_rounded(prev, self.active.camera.target, alpha)
+        let prev = self.previous_position.unwrap_or(self.view_target);
+        lerp_rounded(prev, self.view_target, alpha)
     }
 }
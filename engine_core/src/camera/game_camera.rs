@@ -1,4 +1,5 @@
 // engine_core/src/camera/game_camera.rs
+use crate::camera::camera_follow::CameraFollow;
 use crate::ecs::component::CurrentRoom;
 use crate::ecs::ecs::Ecs;
 use crate::ecs::entity::Entity;
@@ -58,6 +59,8 @@ pub struct RoomCamera {
     pub room_id: RoomId,
     pub zoom_mode: ZoomMode,
     pub camera_mode: CameraMode,
+    /// Smoothing and framing used in follow mode.
+    pub follow: CameraFollow,
 }
 
 impl RoomCamera {
@@ -72,6 +75,7 @@ impl RoomCamera {
             room_id,
             zoom_mode: ZoomMode::Step,
             camera_mode: CameraMode::Fixed,
+            follow: CameraFollow::default(),
         }
     }
 
@@ -86,6 +90,7 @@ impl RoomCamera {
             room_id,
             zoom_mode: ZoomMode::Step,
             camera_mode: CameraMode::Fixed,
+            follow: CameraFollow::default(),
         }
     }
 }
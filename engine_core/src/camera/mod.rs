@@ -1,5 +1,9 @@
+pub mod camera_effects;
+pub mod camera_follow;
 pub mod camera_manager;
 pub mod game_camera;
 
+pub use camera_effects::*;
+pub use camera_follow::*;
 pub use camera_manager::*;
 pub use game_camera::*;
@@ -40,6 +40,24 @@ pub const TEXT_FOLDER: &str = "text";
 /// Name of the folder that contains menu templates.
 pub const MENUS_FOLDER: &str = "menus";
 
+/// Name of the file holding the menu theme shared by every menu.
+pub const MENU_THEME_FILE: &str = "menu_theme.ron";
+
+/// Name of the folder that contains dialogue graphs.
+pub const DIALOGUE_FOLDER: &str = "dialogue";
+
+/// Name of the file holding the speech bubble and dialogue settings.
+pub const DIALOGUE_CONFIG_FILE: &str = "dialogue_config.ron";
+
+/// Name of the folder that contains animation state machines.
+pub const STATE_MACHINES_FOLDER: &str = "state_machines";
+
+/// Name of the assets subfolder that contains rich text `[icon=...]` images.
+pub const ICONS_FOLDER: &str = "icons";
+
+/// Name of the folder that contains font files (TTF, OTF and BMFont).
+pub const FONTS_FOLDER: &str = "fonts";
+
 /// Name of the audio folder.
 pub const AUDIO_FOLDER: &str = "audio";
 
@@ -1,5 +1,6 @@
 // engine_core/src/ecs/component_registry.rs
 use crate::ecs::component::Component;
+use crate::ecs::reflect_field::Reflect;
 use crate::ecs::{ecs::Ecs, entity::Entity};
 use crate::game::GameCtxMut;
 use mlua::Lua;
@@ -22,6 +23,9 @@ pub trait LuaSchema {
     fn lua_schema() -> &'static [(&'static str, &'static str)];
 }
 
+/// Borrows a component mutably as `dyn Reflect`.
+pub type ReflectFn = fn(&mut Ecs, Entity) -> Option<&mut dyn Reflect>;
+
 /// One entry for a concrete component type.
 pub struct ComponentRegistry {
     /// Human‑readable identifier that will appear in the save file.
@@ -56,6 +60,10 @@ pub struct ComponentRegistry {
     pub from_lua: fn(&Lua, Value) -> mlua::Result<Box<dyn Any>>,
     /// Returns the Lua schema for this component (field names and types).
     pub lua_schema: fn() -> &'static [(&'static str, &'static str)],
+    /// Borrows the component as `dyn Reflect`. `None` if the type does not derive `Reflect`.
+    pub reflect: Option<ReflectFn>,
+    /// Runtime-only components are left out of save files and the Lua API.
+    pub runtime: bool,
 }
 
 /// Factory that works for any component that implements `Component + Default`.
@@ -93,6 +101,14 @@ where
     ecs.get_store_mut::<T>().insert(entity, concrete);
 }
 
+/// Borrows a concrete component mutably as a `dyn Reflect`.
+pub fn reflect_component<T>(ecs: &mut Ecs, entity: Entity) -> Option<&mut dyn Reflect>
+where
+    T: Component + Reflect + 'static,
+{
+    ecs.get_mut::<T>(entity).map(|c| c as &mut dyn Reflect)
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct StoredComponent {
     pub type_name: String,
@@ -267,6 +267,9 @@ impl Serialize for Ecs {
                 .into_iter()
                 .find(|r| r.type_name == *type_name)
                 .expect("registry entry missing");
+            if reg.runtime {
+                continue;
+            }
 
             // Convert the concrete store (`any_box`) into a RON value
             let ron_string = (reg.to_ron)(&**any_box);
@@ -101,6 +101,13 @@ pub struct Transform {
     pub position: Vec2,
     /// Pivot point for rendering. Defaults to BottomCenter.
     pub pivot: Pivot,
+    /// Clockwise rotation in degrees around the position.
+    pub rotation: f32,
+    /// Per-axis scale. A negative axis mirrors the entity.
+    #[serde_as(as = "FromInto<[f32; 2]>")]
+    pub scale: Vec2,
+    /// Whether the entity is flipped vertically.
+    pub flip_y: bool,
 }
 
 impl Default for Transform {
@@ -109,11 +116,68 @@ impl Default for Transform {
             visible: true,
             position: Vec2::ZERO,
             pivot: Pivot::default(),
+            rotation: 0.0,
+            scale: Vec2::ONE,
+            flip_y: false,
         }
     }
 }
 inspector_module!(Transform, removable = false);
 
+impl Transform {
+    /// Returns the size of a rectangle of `size` after scaling.
+    pub fn scaled_size(&self, size: Vec2) -> Vec2 {
+        size * self.scale.abs()
+    }
+
+    /// Returns whether drawing should mirror horizontally and vertically.
+    pub fn mirrored(&self) -> (bool, bool) {
+        (self.scale.x < 0.0, self.flip_y != (self.scale.y < 0.0))
+    }
+
+    /// Returns the world corners of a rectangle of `size` placed at the
+    /// position, in clockwise order starting at the unrotated top-left.
+    pub fn world_corners(&self, size: Vec2) -> [Vec2; 4] {
+        let size = self.scaled_size(size);
+        let top_left = pivot_offset(self.position, size, self.pivot);
+        [
+            top_left,
+            top_left + vec2(size.x, 0.0),
+            top_left + size,
+            top_left + vec2(0.0, size.y),
+        ]
+        .map(|corner| rotate_around(corner, self.position, self.rotation))
+    }
+
+    /// Returns the axis-aligned bounds of a rectangle of `size` after
+    /// scaling and rotation.
+    pub fn world_bounds(&self, size: Vec2) -> Rect {
+        let corners = self.world_corners(size);
+        let min = corners.iter().fold(corners[0], |acc, c| acc.min(*c));
+        let max = corners.iter().fold(corners[0], |acc, c| acc.max(*c));
+        Rect::new(min.x, min.y, max.x - min.x, max.y - min.y)
+    }
+
+    /// Returns true if `point` lies inside the scaled and rotated rectangle.
+    pub fn contains_point(&self, size: Vec2, point: Vec2) -> bool {
+        let size = self.scaled_size(size);
+        let top_left = pivot_offset(self.position, size, self.pivot);
+        let local = rotate_around(point, self.position, -self.rotation);
+        Rect::new(top_left.x, top_left.y, size.x, size.y).contains(local)
+    }
+}
+
+/// Rotates `point` clockwise by `degrees` around `origin`.
+#[inline]
+pub fn rotate_around(point: Vec2, origin: Vec2, degrees: f32) -> Vec2 {
+    if degrees == 0.0 {
+        return point;
+    }
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let d = point - origin;
+    origin + vec2(d.x * cos - d.y * sin, d.x * sin + d.y * cos)
+}
+
 /// Update the position of an entity and any children it may have.
 pub fn update_entity_position(ecs: &mut Ecs, entity: Entity, new_pos: Vec2) {
     // Determine the old position
@@ -131,18 +195,171 @@ pub fn update_entity_position(ecs: &mut Ecs, entity: Entity, new_pos: Vec2) {
         return;
     }
 
-    // Propagate the translation to every child recursively
-    let children = get_children(ecs, entity);
-    for child in children {
-        let child_new_pos = if let Some(child_pos) = ecs.get_store_mut::<Transform>().get_mut(child)
-        {
-            let new = child_pos.position + delta;
-            child_pos.position = new;
-            new
-        } else {
-            return;
+    // Propagate the translation to every descendant
+    for child in descendants(ecs, entity) {
+        if let Some(child_transform) = ecs.get_mut::<Transform>(child) {
+            child_transform.position += delta;
+        }
+    }
+}
+
+/// Update the rotation of an entity. Children orbit the entity's position
+/// and rotate by the same amount.
+pub fn update_entity_rotation(ecs: &mut Ecs, entity: Entity, rotation: f32) {
+    let Some(transform) = ecs.get_mut::<Transform>(entity) else {
+        return;
+    };
+    let delta = rotation - transform.rotation;
+    transform.rotation = rotation;
+    let origin = transform.position;
+    if delta == 0.0 {
+        return;
+    }
+
+    for child in descendants(ecs, entity) {
+        if let Some(child_transform) = ecs.get_mut::<Transform>(child) {
+            child_transform.position = rotate_around(child_transform.position, origin, delta);
+            child_transform.rotation += delta;
+        }
+    }
+}
+
+/// Update the scale of an entity. Children keep their relative placement and
+/// are scaled by the same ratio.
+pub fn update_entity_scale(ecs: &mut Ecs, entity: Entity, scale: Vec2) {
+    let Some(transform) = ecs.get_mut::<Transform>(entity) else {
+        return;
+    };
+    let old_scale = transform.scale;
+    transform.scale = scale;
+    let origin = transform.position;
+    let rotation = transform.rotation;
+
+    // Children cannot follow a collapsed axis back out of zero
+    if old_scale.x == 0.0 || old_scale.y == 0.0 || old_scale == scale {
+        return;
+    }
+    let ratio = scale / old_scale;
+
+    for child in descendants(ecs, entity) {
+        if let Some(child_transform) = ecs.get_mut::<Transform>(child) {
+            // Scale along the parent's axes
+            let local = rotate_around(child_transform.position, origin, -rotation) - origin;
+            let scaled = origin + local * ratio;
+            child_transform.position = rotate_around(scaled, origin, rotation);
+            child_transform.scale *= ratio;
+        }
+    }
+}
+
+/// Update the vertical flip of an entity. Children are mirrored across the
+/// entity's position and flipped with it.
+pub fn update_entity_flip_y(ecs: &mut Ecs, entity: Entity, flip_y: bool) {
+    let Some(transform) = ecs.get_mut::<Transform>(entity) else {
+        return;
+    };
+    if transform.flip_y == flip_y {
+        return;
+    }
+    transform.flip_y = flip_y;
+    let origin = transform.position;
+    let rotation = transform.rotation;
+
+    for child in descendants(ecs, entity) {
+        if let Some(child_transform) = ecs.get_mut::<Transform>(child) {
+            let local = rotate_around(child_transform.position, origin, -rotation) - origin;
+            let mirrored = origin + vec2(local.x, -local.y);
+            child_transform.position = rotate_around(mirrored, origin, rotation);
+            child_transform.flip_y = !child_transform.flip_y;
+        }
+    }
+}
+
+/// Returns every descendant of an entity, depth first.
+fn descendants(ecs: &Ecs, entity: Entity) -> Vec<Entity> {
+    let mut result = Vec::new();
+    let mut stack = get_children(ecs, entity);
+    while let Some(child) = stack.pop() {
+        stack.extend(get_children(ecs, child));
+        result.push(child);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent_with_child(ecs: &mut Ecs, child_pos: Vec2) -> (Entity, Entity) {
+        let parent = ecs.create_entity().with(Transform::default()).finish();
+        let child = ecs
+            .create_entity()
+            .with(Transform {
+                position: child_pos,
+                ..Default::default()
+            })
+            .finish();
+        set_parent(ecs, child, parent);
+        (parent, child)
+    }
+
+    fn assert_near(a: Vec2, b: Vec2) {
+        assert!((a - b).length() < 1e-4, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn bounds_include_scale_and_rotation() {
+        let transform = Transform {
+            position: vec2(10.0, 10.0),
+            pivot: Pivot::Center,
+            scale: vec2(2.0, -1.0),
+            rotation: 90.0,
+            ..Default::default()
         };
+        let bounds = transform.world_bounds(vec2(8.0, 4.0));
+        assert_near(vec2(bounds.x, bounds.y), vec2(8.0, 2.0));
+        assert_near(vec2(bounds.w, bounds.h), vec2(4.0, 16.0));
+        assert!(transform.contains_point(vec2(8.0, 4.0), vec2(10.0, 17.0)));
+        assert!(!transform.contains_point(vec2(8.0, 4.0), vec2(17.0, 10.0)));
+        assert_eq!(transform.mirrored(), (false, true));
+    }
+
+    #[test]
+    fn children_inherit_rotation_scale_and_flip() {
+        let mut ecs = Ecs::default();
+        let (parent, child) = parent_with_child(&mut ecs, vec2(4.0, 0.0));
+        let grandchild = ecs
+            .create_entity()
+            .with(Transform {
+                position: vec2(8.0, 0.0),
+                ..Default::default()
+            })
+            .finish();
+        set_parent(&mut ecs, grandchild, child);
+
+        update_entity_rotation(&mut ecs, parent, 90.0);
+        let child_transform = *ecs.get::<Transform>(child).unwrap();
+        assert_near(child_transform.position, vec2(0.0, 4.0));
+        assert_eq!(child_transform.rotation, 90.0);
+
+        update_entity_scale(&mut ecs, parent, vec2(2.0, 1.0));
+        assert_near(
+            ecs.get::<Transform>(child).unwrap().position,
+            vec2(0.0, 8.0),
+        );
+        assert_eq!(ecs.get::<Transform>(child).unwrap().scale, vec2(2.0, 1.0));
+
+        update_entity_flip_y(&mut ecs, parent, true);
+        assert_near(
+            ecs.get::<Transform>(child).unwrap().position,
+            vec2(0.0, 8.0),
+        );
+        assert!(ecs.get::<Transform>(grandchild).unwrap().flip_y);
 
-        update_entity_position(ecs, child, child_new_pos);
+        update_entity_position(&mut ecs, parent, vec2(1.0, 1.0));
+        assert_near(
+            ecs.get::<Transform>(grandchild).unwrap().position,
+            vec2(1.0, 17.0),
+        );
     }
 }
@@ -10,9 +10,11 @@ use crate::assets::asset_manager::AssetManager;
 use crate::ecs::ecs::Ecs;
 use crate::engine_global::set_game_name;
 use crate::scripting::script_manager::ScriptManager;
+use crate::tween::TweenManager;
 use crate::worlds::room::RoomId;
 use crate::worlds::world::*;
-use crate::{storage::text_folder, text::TextManager};
+use crate::storage::{dialogue_config_path, text_folder};
+use crate::text::{DialogueConfig, TextManager};
 use bishop::prelude::TextureLoader;
 use mlua::Lua;
 use serde::{Deserialize, Serialize};
@@ -39,6 +41,9 @@ pub struct Game {
     /// Text manager for the game.
     #[serde(skip)]
     pub text_manager: TextManager,
+    /// Running tweens.
+    #[serde(skip)]
+    pub tweens: TweenManager,
     /// Id of the currently active world.
     pub current_world_id: WorldId, // TODO: Change this to an option
     /// Top level map of the whole game.
@@ -168,6 +173,7 @@ impl Game {
     pub fn init_text_manager(&mut self) {
         let text_root = text_folder();
         self.text_manager.set_text_root(text_root);
+        self.text_manager.config = DialogueConfig::load_from_path(&dialogue_config_path());
     }
 
     /// Allocates a globally unique room ID.
@@ -12,6 +12,7 @@ pub mod input;
 pub mod lighting;
 pub mod logging;
 pub mod menu;
+pub mod particles;
 pub mod physics;
 pub mod rendering;
 pub mod scripting;
@@ -20,6 +21,7 @@ pub mod storage;
 pub mod task;
 pub mod text;
 pub mod tiles;
+pub mod tween;
 pub mod ui;
 pub mod worlds;
 
@@ -42,6 +44,7 @@ pub mod prelude {
     pub use crate::logging::*;
     #[allow(ambiguous_glob_reexports)]
     pub use crate::menu::*;
+    pub use crate::particles::*;
     pub use crate::physics::*;
     pub use crate::register_lua_api;
     pub use crate::register_lua_module;
@@ -52,6 +55,7 @@ pub mod prelude {
     pub use crate::task::*;
     pub use crate::text::*;
     pub use crate::tiles::*;
+    pub use crate::tween::*;
     pub use crate::ui::*;
     pub use crate::worlds::*;
 }
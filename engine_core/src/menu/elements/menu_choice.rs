@@ -0,0 +1,79 @@
+use crate::menu::*;
+use serde::{Deserialize, Serialize};
+
+/// Choice element that cycles left and right through a fixed set of options.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ChoiceElement {
+    /// Label text key resolved via TextManager.
+    pub text_key: String,
+    /// Player setting the choice reads and writes (e.g. `"difficulty"`).
+    pub key: String,
+    /// Stored option values. Each is also resolved via TextManager for display.
+    pub options: Vec<String>,
+    /// Index of the option used when no saved setting is present.
+    pub default_index: usize,
+    /// Navigation targets for each direction.
+    pub nav_targets: NavTargets,
+}
+
+impl ChoiceElement {
+    /// Returns the index of `value` in the options, or the default index.
+    pub fn index_of(&self, value: Option<&str>) -> usize {
+        value
+            .and_then(|value| self.options.iter().position(|option| option == value))
+            .unwrap_or(self.default_index)
+            .min(self.options.len().saturating_sub(1))
+    }
+
+    /// Returns the option `offset` steps away from `index`, wrapping at both ends.
+    pub fn cycle(&self, index: usize, offset: isize) -> Option<&str> {
+        let count = self.options.len() as isize;
+        if count == 0 {
+            return None;
+        }
+        let next = (index as isize + offset).rem_euclid(count) as usize;
+        self.options.get(next).map(String::as_str)
+    }
+}
+
+impl Navigable for ChoiceElement {
+    fn nav_targets(&self) -> &NavTargets {
+        &self.nav_targets
+    }
+
+    fn nav_targets_mut(&mut self) -> &mut NavTargets {
+        &mut self.nav_targets
+    }
+
+    fn from_element(el: &MenuElement) -> Option<&Self> {
+        match &el.kind {
+            MenuElementKind::Choice(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    fn wrap_into_element(self) -> MenuElementKind {
+        MenuElementKind::Choice(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycling_wraps_and_unknown_values_use_the_default() {
+        let choice = ChoiceElement {
+            options: vec!["easy".into(), "normal".into(), "hard".into()],
+            default_index: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(choice.index_of(None), 1);
+        assert_eq!(choice.index_of(Some("removed")), 1);
+        assert_eq!(choice.index_of(Some("hard")), 2);
+        assert_eq!(choice.cycle(2, 1), Some("easy"));
+        assert_eq!(choice.cycle(0, -1), Some("hard"));
+        assert_eq!(ChoiceElement::default().cycle(0, 1), None);
+    }
+}
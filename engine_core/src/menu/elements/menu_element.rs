@@ -1,8 +1,17 @@
 use super::layout_group::LayoutGroupElement;
+use super::menu_choice::ChoiceElement;
+use super::menu_image::ImageElement;
 use super::menu_panel::PanelBackground;
+use super::menu_scroll_list::ScrollListElement;
 use super::menu_slider::SliderElement;
-use crate::menu::layout::HorizontalAlign;
+use super::menu_text_input::TextInputElement;
+use super::menu_toggle::ToggleElement;
+use crate::assets::sprite::SpriteId;
+use crate::menu::layout::{ElementAnchor, HorizontalAlign};
+use crate::menu::menu_animation::MenuTransition;
+use crate::menu::menu_binding::ElementBindings;
 use crate::menu::menu_builder::MenuAction;
+use crate::menu::menu_theme::StyleOverrides;
 use crate::menu::{NavTargets, Navigable};
 use bishop::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -15,6 +24,35 @@ pub enum MenuElementKind {
     Panel(PanelElement),
     LayoutGroup(LayoutGroupElement),
     Slider(SliderElement),
+    Toggle(ToggleElement),
+    Choice(ChoiceElement),
+    TextInput(TextInputElement),
+    Image(ImageElement),
+    ScrollList(ScrollListElement),
+}
+
+impl MenuElementKind {
+    /// Returns true for controls that take focus on their own.
+    /// Layout groups are not included; their children take focus instead.
+    pub fn is_focusable(&self) -> bool {
+        self.control_nav_targets().is_some()
+    }
+
+    /// Returns the navigation targets of a focusable control.
+    pub fn control_nav_targets(&self) -> Option<&NavTargets> {
+        match self {
+            MenuElementKind::Button(button) => Some(&button.nav_targets),
+            MenuElementKind::Slider(slider) => Some(&slider.nav_targets),
+            MenuElementKind::Toggle(toggle) => Some(&toggle.nav_targets),
+            MenuElementKind::Choice(choice) => Some(&choice.nav_targets),
+            MenuElementKind::TextInput(input) => Some(&input.nav_targets),
+            MenuElementKind::ScrollList(list) => Some(&list.nav_targets),
+            MenuElementKind::Label(_)
+            | MenuElementKind::Panel(_)
+            | MenuElementKind::LayoutGroup(_)
+            | MenuElementKind::Image(_) => None,
+        }
+    }
 }
 
 /// Label element displaying text resolved from a text key.
@@ -26,6 +64,9 @@ pub struct LabelElement {
     pub color: Color,
     #[serde(default)]
     pub alignment: HorizontalAlign,
+    /// Font name from the fonts folder. Empty uses the default font.
+    #[serde(default)]
+    pub font: String,
 }
 
 impl Default for LabelElement {
@@ -35,6 +76,7 @@ impl Default for LabelElement {
             font_size: 20.0,
             color: Color::WHITE,
             alignment: HorizontalAlign::default(),
+            font: String::new(),
         }
     }
 }
@@ -47,6 +89,9 @@ pub struct ButtonElement {
     pub action: MenuAction,
     pub font_size: f32,
     pub nav_targets: NavTargets,
+    /// Font name from the fonts folder. Empty uses the default font.
+    #[serde(default)]
+    pub font: String,
 }
 
 impl Default for ButtonElement {
@@ -56,6 +101,7 @@ impl Default for ButtonElement {
             action: MenuAction::CloseMenu,
             font_size: 20.0,
             nav_targets: NavTargets::default(),
+            font: String::new(),
         }
     }
 }
@@ -96,6 +142,21 @@ pub struct MenuElement {
     pub enabled: bool,
     pub visible: bool,
     pub z_order: i32,
+    /// Conditions on values pushed from Lua that hide or disable the element.
+    #[serde(default, skip_serializing_if = "ElementBindings::is_empty")]
+    pub bindings: ElementBindings,
+    /// Safe-area anchor for top-level elements. Layout children ignore it.
+    #[serde(default, skip_serializing_if = "ElementAnchor::is_stretch")]
+    pub anchor: ElementAnchor,
+    /// Animation played when the menu opens, delayed by the menu's stagger.
+    #[serde(default, skip_serializing_if = "MenuTransition::is_default")]
+    pub enter: MenuTransition,
+    /// Name of the theme style the element is drawn with. Empty keeps its own look.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub style: String,
+    /// Style fields this element sets for itself.
+    #[serde(default, skip_serializing_if = "StyleOverrides::is_empty")]
+    pub style_overrides: StyleOverrides,
 }
 
 impl MenuElement {
@@ -108,6 +169,26 @@ impl MenuElement {
             enabled: true,
             visible: true,
             z_order: 0,
+            bindings: ElementBindings::default(),
+            anchor: ElementAnchor::default(),
+            enter: MenuTransition::default(),
+            style: String::new(),
+            style_overrides: StyleOverrides::default(),
+        }
+    }
+
+    /// Pushes every sprite the element and its layout children draw onto `sprites`.
+    pub fn collect_sprites(&self, sprites: &mut Vec<SpriteId>) {
+        match &self.kind {
+            MenuElementKind::Image(image) if image.sprite.0 != 0 => sprites.push(image.sprite),
+            MenuElementKind::Panel(panel) => sprites.extend(panel.background.sprite()),
+            MenuElementKind::LayoutGroup(group) => {
+                sprites.extend(group.background.and_then(|background| background.sprite()));
+                for child in &group.children {
+                    child.element.collect_sprites(sprites);
+                }
+            }
+            _ => {}
         }
     }
 
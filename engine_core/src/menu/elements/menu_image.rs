@@ -0,0 +1,103 @@
+use crate::animation::ClipDef;
+use crate::assets::sprite::SpriteId;
+use bishop::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_with::{FromInto, serde_as};
+
+/// Image element that draws a sprite, optionally playing an animation clip from it.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImageElement {
+    pub sprite: SpriteId,
+    /// Colour the sprite is multiplied by.
+    #[serde_as(as = "FromInto<[f32; 4]>")]
+    pub tint: Color,
+    /// Fit the sprite inside the element instead of stretching it.
+    pub preserve_aspect: bool,
+    /// Clip played from the sprite as a sheet. `None` draws the whole sprite.
+    pub clip: Option<ClipDef>,
+}
+
+impl Default for ImageElement {
+    fn default() -> Self {
+        Self {
+            sprite: SpriteId(0),
+            tint: Color::WHITE,
+            preserve_aspect: true,
+            clip: None,
+        }
+    }
+}
+
+impl ImageElement {
+    /// Source rect of the clip frame shown at `time` seconds in a texture of `size`.
+    pub fn frame_source(&self, size: Vec2, time: f64) -> Rect {
+        let Some(clip) = &self.clip else {
+            return Rect::new(0.0, 0.0, size.x, size.y);
+        };
+        let frame = clip.frame_at(time as f32);
+        let columns = clip.cols.max(1);
+        Rect::new(
+            (frame % columns) as f32 * clip.frame_size.x,
+            (frame / columns) as f32 * clip.frame_size.y,
+            clip.frame_size.x,
+            clip.frame_size.y,
+        )
+    }
+
+    /// Rect the sprite is drawn in for an element at `rect` and a frame of `frame_size`.
+    pub fn fit(&self, rect: Rect, frame_size: Vec2) -> Rect {
+        if !self.preserve_aspect || frame_size.x <= 0.0 || frame_size.y <= 0.0 {
+            return rect;
+        }
+        let scale = (rect.w / frame_size.x).min(rect.h / frame_size.y);
+        let size = frame_size * scale;
+        Rect::new(
+            rect.x + (rect.w - size.x) / 2.0,
+            rect.y + (rect.h - size.y) / 2.0,
+            size.x,
+            size.y,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clips_step_through_frames_and_fit_keeps_the_aspect() {
+        let mut image = ImageElement {
+            clip: Some(ClipDef {
+                frame_size: vec2(32.0, 16.0),
+                cols: 2,
+                rows: 2,
+                fps: 4.0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let size = vec2(64.0, 32.0);
+        assert_eq!(
+            image.frame_source(size, 0.0),
+            Rect::new(0.0, 0.0, 32.0, 16.0)
+        );
+        assert_eq!(
+            image.frame_source(size, 0.5),
+            Rect::new(0.0, 16.0, 32.0, 16.0)
+        );
+        // Looping wraps back to the first frame, otherwise the last one holds
+        assert_eq!(image.frame_source(size, 1.1).x, 0.0);
+        if let Some(clip) = &mut image.clip {
+            clip.looping = false;
+        }
+        assert_eq!(
+            image.frame_source(size, 5.0),
+            Rect::new(32.0, 16.0, 32.0, 16.0)
+        );
+
+        let fitted = image.fit(Rect::new(0.0, 0.0, 100.0, 100.0), vec2(32.0, 16.0));
+        assert_eq!(fitted, Rect::new(0.0, 25.0, 100.0, 50.0));
+    }
+}
@@ -1,11 +1,31 @@
+use crate::assets::asset_manager::AssetManager;
+use crate::assets::sprite::SpriteId;
 use bishop::prelude::*;
 use serde::{Deserialize, Serialize};
 
 /// Fill type for a panel background.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum PanelFill {
     /// Solid color fill.
     SolidColor(Color),
+    /// Sprite cut into a frame that scales without stretching its borders.
+    Sprite { sprite: SpriteId, slice: NineSlice },
+}
+
+impl PanelFill {
+    /// Display label for UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PanelFill::SolidColor(_) => "Color",
+            PanelFill::Sprite { .. } => "Sprite",
+        }
+    }
+}
+
+impl std::fmt::Display for PanelFill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
 }
 
 impl Default for PanelFill {
@@ -25,11 +45,41 @@ pub struct PanelBackground {
 
 impl PanelBackground {
     /// Returns the final color to use when rendering, with opacity applied.
+    /// Sprite fills give the tint of the sprite.
     pub fn render_color(&self) -> Color {
         match self.fill {
             PanelFill::SolidColor(color) => {
                 Color::new(color.r, color.g, color.b, color.a * self.opacity)
             }
+            PanelFill::Sprite { .. } => Color::new(1.0, 1.0, 1.0, self.opacity),
+        }
+    }
+
+    /// Returns the sprite of a sprite fill.
+    pub fn sprite(&self) -> Option<SpriteId> {
+        match self.fill {
+            PanelFill::Sprite { sprite, .. } if sprite.0 != 0 => Some(sprite),
+            _ => None,
+        }
+    }
+
+    /// Draws the background over `rect`. `scale` sizes the borders of a sprite fill.
+    pub fn draw<C: BishopContext>(
+        &self,
+        ctx: &mut C,
+        rect: Rect,
+        asset_manager: &mut AssetManager,
+        scale: f32,
+    ) {
+        match self.fill {
+            PanelFill::Sprite { sprite, slice } if sprite.0 != 0 => {
+                let texture = asset_manager.get_texture_from_id(ctx, sprite);
+                ctx.draw_nine_slice(texture, rect, &slice.scaled(scale), self.render_color());
+            }
+            PanelFill::Sprite { .. } => {}
+            PanelFill::SolidColor(_) => {
+                ctx.draw_rectangle(rect.x, rect.y, rect.w, rect.h, self.render_color());
+            }
         }
     }
 }
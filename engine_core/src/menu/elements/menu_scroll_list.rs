@@ -0,0 +1,50 @@
+use crate::menu::*;
+use serde::{Deserialize, Serialize};
+
+/// Scrolling list whose items are filled in from Lua.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrollListElement {
+    /// Identifier scripts fill the list by and that is reported with picked items.
+    pub key: String,
+    /// Number of rows that fit in the list at once.
+    pub visible_rows: usize,
+    pub font_size: f32,
+    /// Font name from the fonts folder. Empty uses the default font.
+    #[serde(default)]
+    pub font: String,
+    /// Navigation targets for each direction.
+    pub nav_targets: NavTargets,
+}
+
+impl Default for ScrollListElement {
+    fn default() -> Self {
+        Self {
+            key: String::new(),
+            visible_rows: 5,
+            font_size: 20.0,
+            font: String::new(),
+            nav_targets: NavTargets::default(),
+        }
+    }
+}
+
+impl Navigable for ScrollListElement {
+    fn nav_targets(&self) -> &NavTargets {
+        &self.nav_targets
+    }
+
+    fn nav_targets_mut(&mut self) -> &mut NavTargets {
+        &mut self.nav_targets
+    }
+
+    fn from_element(el: &MenuElement) -> Option<&Self> {
+        match &el.kind {
+            MenuElementKind::ScrollList(l) => Some(l),
+            _ => None,
+        }
+    }
+
+    fn wrap_into_element(self) -> MenuElementKind {
+        MenuElementKind::ScrollList(self)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::menu::*;
+use serde::{Deserialize, Serialize};
+
+/// Text entry element for names and other short player input.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextInputElement {
+    /// Label text key resolved via TextManager.
+    pub text_key: String,
+    /// Identifier reported with the submitted text (e.g. `"player_name"`).
+    pub key: String,
+    /// Text key shown while the field is empty.
+    #[serde(default)]
+    pub placeholder_key: String,
+    /// Longest text the field accepts, in characters.
+    pub max_length: usize,
+    /// Navigation targets for each direction.
+    pub nav_targets: NavTargets,
+}
+
+impl Default for TextInputElement {
+    fn default() -> Self {
+        Self {
+            text_key: String::new(),
+            key: String::new(),
+            placeholder_key: String::new(),
+            max_length: 12,
+            nav_targets: NavTargets::default(),
+        }
+    }
+}
+
+impl Navigable for TextInputElement {
+    fn nav_targets(&self) -> &NavTargets {
+        &self.nav_targets
+    }
+
+    fn nav_targets_mut(&mut self) -> &mut NavTargets {
+        &mut self.nav_targets
+    }
+
+    fn from_element(el: &MenuElement) -> Option<&Self> {
+        match &el.kind {
+            MenuElementKind::TextInput(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn wrap_into_element(self) -> MenuElementKind {
+        MenuElementKind::TextInput(self)
+    }
+}
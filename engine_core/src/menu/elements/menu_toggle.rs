@@ -0,0 +1,36 @@
+use crate::menu::*;
+use serde::{Deserialize, Serialize};
+
+/// Toggle element that flips a boolean setting on and off.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ToggleElement {
+    /// Label text key resolved via TextManager.
+    pub text_key: String,
+    /// Player setting the toggle reads and writes (e.g. `"fullscreen"`).
+    pub key: String,
+    /// Value used when no saved setting is present.
+    pub default_value: bool,
+    /// Navigation targets for each direction.
+    pub nav_targets: NavTargets,
+}
+
+impl Navigable for ToggleElement {
+    fn nav_targets(&self) -> &NavTargets {
+        &self.nav_targets
+    }
+
+    fn nav_targets_mut(&mut self) -> &mut NavTargets {
+        &mut self.nav_targets
+    }
+
+    fn from_element(el: &MenuElement) -> Option<&Self> {
+        match &el.kind {
+            MenuElementKind::Toggle(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn wrap_into_element(self) -> MenuElementKind {
+        MenuElementKind::Toggle(self)
+    }
+}
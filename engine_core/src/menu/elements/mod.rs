@@ -1,17 +1,27 @@
 pub mod layout_group;
 pub mod menu_background;
 pub mod menu_button;
+pub mod menu_choice;
 pub mod menu_element;
 pub mod menu_group;
+pub mod menu_image;
 pub mod menu_label;
 pub mod menu_panel;
+pub mod menu_scroll_list;
 pub mod menu_slider;
+pub mod menu_text_input;
+pub mod menu_toggle;
 
 pub use layout_group::*;
 pub use menu_background::*;
 pub use menu_button::*;
+pub use menu_choice::*;
 pub use menu_element::*;
 pub use menu_group::*;
+pub use menu_image::*;
 pub use menu_label::*;
 pub use menu_panel::*;
+pub use menu_scroll_list::*;
 pub use menu_slider::*;
+pub use menu_text_input::*;
+pub use menu_toggle::*;
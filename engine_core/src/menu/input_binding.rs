@@ -59,4 +59,15 @@ impl InputBinding {
         }
         false
     }
+
+    /// Returns the keyboard keys of this binding.
+    pub fn keys(&self) -> impl Iterator<Item = KeyCode> {
+        self.keyboard.into_iter().chain(self.keyboard_alt)
+    }
+
+    /// Checks if the primary key was pressed this frame, ignoring the alternate.
+    /// Used while typing, where alternates such as letter keys are text.
+    pub fn is_primary_pressed<C: BishopContext>(&self, ctx: &C) -> bool {
+        self.keyboard.is_some_and(|key| ctx.is_key_pressed(key))
+    }
 }
@@ -0,0 +1,78 @@
+use super::{Alignment, HorizontalAlign, VerticalAlign};
+use serde::{Deserialize, Serialize};
+
+/// Where a top-level menu element is pinned within the safe area.
+///
+/// `Stretch` elements scale with the area on both axes. Anchored elements keep
+/// their design aspect and hold their authored distance from the anchored edges,
+/// so HUD corners stay in the corners whatever the window's aspect ratio.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ElementAnchor {
+    #[default]
+    Stretch,
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl ElementAnchor {
+    /// All anchors, in the order editors list them.
+    pub const ALL: [ElementAnchor; 10] = [
+        ElementAnchor::Stretch,
+        ElementAnchor::TopLeft,
+        ElementAnchor::Top,
+        ElementAnchor::TopRight,
+        ElementAnchor::Left,
+        ElementAnchor::Center,
+        ElementAnchor::Right,
+        ElementAnchor::BottomLeft,
+        ElementAnchor::Bottom,
+        ElementAnchor::BottomRight,
+    ];
+
+    /// Returns true for the default stretching behaviour.
+    pub fn is_stretch(&self) -> bool {
+        *self == ElementAnchor::Stretch
+    }
+
+    /// Returns the edges this anchor pins to, or `None` when stretching.
+    pub fn alignment(&self) -> Option<Alignment> {
+        use HorizontalAlign::*;
+        use VerticalAlign::*;
+        let (horizontal, vertical) = match self {
+            ElementAnchor::Stretch => return None,
+            ElementAnchor::TopLeft => (Left, Top),
+            ElementAnchor::Top => (Center, Top),
+            ElementAnchor::TopRight => (Right, Top),
+            ElementAnchor::Left => (Left, Middle),
+            ElementAnchor::Center => (Center, Middle),
+            ElementAnchor::Right => (Right, Middle),
+            ElementAnchor::BottomLeft => (Left, Bottom),
+            ElementAnchor::Bottom => (Center, Bottom),
+            ElementAnchor::BottomRight => (Right, Bottom),
+        };
+        Some(Alignment::new(horizontal, vertical))
+    }
+
+    /// Returns a display label for editors.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ElementAnchor::Stretch => "Stretch",
+            ElementAnchor::TopLeft => "Top Left",
+            ElementAnchor::Top => "Top",
+            ElementAnchor::TopRight => "Top Right",
+            ElementAnchor::Left => "Left",
+            ElementAnchor::Center => "Center",
+            ElementAnchor::Right => "Right",
+            ElementAnchor::BottomLeft => "Bottom Left",
+            ElementAnchor::Bottom => "Bottom",
+            ElementAnchor::BottomRight => "Bottom Right",
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use super::{HorizontalAlign, LayoutConfig, LayoutDirection, VerticalAlign};
+use super::{ElementAnchor, HorizontalAlign, LayoutConfig, LayoutDirection, VerticalAlign};
+use crate::constants::{DESIGN_RESOLUTION_HEIGHT, DESIGN_RESOLUTION_WIDTH};
 use crate::menu::elements::layout_group::LayoutGroupElement;
+use crate::menu::normalized_rect_to_screen;
 use bishop::prelude::{Rect, Vec2};
 
 /// Computes absolute rects for all children in a layout group.
@@ -56,6 +58,56 @@ pub fn resolve_layout(group: &LayoutGroupElement, group_rect: Rect) -> Vec<Rect>
         .collect()
 }
 
+/// Resolves a top-level element's normalized rect into screen space within the safe `area`.
+///
+/// Stretched elements map straight onto the area. Anchored elements are scaled
+/// uniformly and keep the distance they were authored at from their anchored
+/// edges, measured on the design resolution.
+pub fn resolve_anchored_rect(rect: Rect, anchor: ElementAnchor, area: Rect) -> Rect {
+    let Some(alignment) = anchor.alignment() else {
+        return normalized_rect_to_screen(rect, Vec2::new(area.x, area.y), area.size());
+    };
+
+    let scale = (area.w / DESIGN_RESOLUTION_WIDTH).min(area.h / DESIGN_RESOLUTION_HEIGHT);
+    let unit_w = DESIGN_RESOLUTION_WIDTH * scale;
+    let unit_h = DESIGN_RESOLUTION_HEIGHT * scale;
+    let w = rect.w * unit_w;
+    let h = rect.h * unit_h;
+
+    let x = match alignment.horizontal {
+        HorizontalAlign::Left => area.x + rect.x * unit_w,
+        HorizontalAlign::Center => {
+            area.x + (area.w - w) / 2.0 + (rect.x + rect.w / 2.0 - 0.5) * unit_w
+        }
+        HorizontalAlign::Right => area.x + area.w - w - (1.0 - rect.x - rect.w) * unit_w,
+    };
+    let y = match alignment.vertical {
+        VerticalAlign::Top => area.y + rect.y * unit_h,
+        VerticalAlign::Middle => {
+            area.y + (area.h - h) / 2.0 + (rect.y + rect.h / 2.0 - 0.5) * unit_h
+        }
+        VerticalAlign::Bottom => area.y + area.h - h - (1.0 - rect.y - rect.h) * unit_h,
+    };
+    Rect::new(x, y, w, h)
+}
+
+/// Maps a rect resolved by [`resolve_layout`] into the screen rect of its group.
+///
+/// `group_rect` is the group's normalized rect and `group_screen` where it was drawn.
+pub fn child_screen_rect(child: Rect, group_rect: Rect, group_screen: Rect) -> Rect {
+    if group_rect.w <= 0.0 || group_rect.h <= 0.0 {
+        return Rect::new(group_screen.x, group_screen.y, 0.0, 0.0);
+    }
+    let scale_x = group_screen.w / group_rect.w;
+    let scale_y = group_screen.h / group_rect.h;
+    Rect::new(
+        group_screen.x + (child.x - group_rect.x) * scale_x,
+        group_screen.y + (child.y - group_rect.y) * scale_y,
+        child.w * scale_x,
+        child.h * scale_y,
+    )
+}
+
 /// Computes positions for managed children relative to inner area origin (0,0).
 fn compute_managed_positions(layout: LayoutConfig, count: usize, inner_size: Vec2) -> Vec<Rect> {
     if count == 0 {
@@ -128,3 +180,52 @@ fn align_offset_h(h_align: HorizontalAlign, container: f32, content: f32) -> f32
         HorizontalAlign::Right => (container - content).max(0.0),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rect_eq(actual: Rect, expected: Rect) {
+        let close = |a: f32, b: f32| (a - b).abs() < 0.01;
+        assert!(
+            close(actual.x, expected.x)
+                && close(actual.y, expected.y)
+                && close(actual.w, expected.w)
+                && close(actual.h, expected.h),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn anchored_rects_hold_their_edge_distance_on_wide_areas() {
+        // A 10% square authored in the bottom-right corner, 5% in from each edge
+        let rect = Rect::new(0.85, 0.85, 0.1, 0.1);
+        let design = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+        assert_rect_eq(
+            resolve_anchored_rect(rect, ElementAnchor::BottomRight, design),
+            normalized_rect_to_screen(rect, Vec2::ZERO, design.size()),
+        );
+
+        // An ultrawide area keeps the design scale and slides the element to the right edge
+        let wide = Rect::new(0.0, 0.0, 2560.0, 1080.0);
+        assert_rect_eq(
+            resolve_anchored_rect(rect, ElementAnchor::BottomRight, wide),
+            Rect::new(2560.0 - 96.0 - 192.0, 1080.0 - 54.0 - 108.0, 192.0, 108.0),
+        );
+        assert_rect_eq(
+            resolve_anchored_rect(rect, ElementAnchor::TopLeft, wide),
+            Rect::new(1632.0, 918.0, 192.0, 108.0),
+        );
+        assert_rect_eq(
+            resolve_anchored_rect(rect, ElementAnchor::Stretch, wide),
+            Rect::new(2176.0, 918.0, 256.0, 108.0),
+        );
+
+        let group = Rect::new(0.5, 0.5, 0.2, 0.2);
+        let group_screen = Rect::new(100.0, 100.0, 400.0, 200.0);
+        assert_rect_eq(
+            child_screen_rect(Rect::new(0.6, 0.55, 0.1, 0.1), group, group_screen),
+            Rect::new(300.0, 150.0, 200.0, 100.0),
+        );
+    }
+}
@@ -1,10 +1,12 @@
 mod alignment;
+mod anchor;
 mod layout_config;
 mod layout_direction;
 mod layout_resolver;
 mod padding;
 
 pub use alignment::*;
+pub use anchor::*;
 pub use layout_config::*;
 pub use layout_direction::*;
 pub use layout_resolver::*;
@@ -0,0 +1,251 @@
+use crate::assets::sprite::SpriteId;
+use crate::tween::Easing;
+use bishop::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+
+/// Pulses per second of the focus pulse.
+const FOCUS_PULSE_RATE: f32 = 1.5;
+/// Seconds a pressed control stays squashed.
+pub const PRESS_DURATION: f32 = 0.15;
+/// How far a pressed control shrinks at the bottom of the press.
+const PRESS_DEPTH: f32 = 0.08;
+/// Size scaled animations start from.
+const SCALE_FROM: f32 = 0.8;
+
+/// How a menu or element moves while it is shown or hidden.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MenuTransitionStyle {
+    /// Appear and disappear at once.
+    #[default]
+    None,
+    /// Fade in and out.
+    Fade,
+    /// Slide in from the left and back out to it.
+    SlideFromLeft,
+    /// Slide in from the right and back out to it.
+    SlideFromRight,
+    /// Slide in from the top and back out to it.
+    SlideFromTop,
+    /// Slide in from the bottom and back out to it.
+    SlideFromBottom,
+    /// Grow from slightly smaller while fading in.
+    Scale,
+}
+
+impl MenuTransitionStyle {
+    /// All transition styles.
+    pub fn all() -> &'static [MenuTransitionStyle] {
+        &[
+            MenuTransitionStyle::None,
+            MenuTransitionStyle::Fade,
+            MenuTransitionStyle::SlideFromLeft,
+            MenuTransitionStyle::SlideFromRight,
+            MenuTransitionStyle::SlideFromTop,
+            MenuTransitionStyle::SlideFromBottom,
+            MenuTransitionStyle::Scale,
+        ]
+    }
+
+    /// Display label for UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            MenuTransitionStyle::None => "None",
+            MenuTransitionStyle::Fade => "Fade",
+            MenuTransitionStyle::SlideFromLeft => "Slide From Left",
+            MenuTransitionStyle::SlideFromRight => "Slide From Right",
+            MenuTransitionStyle::SlideFromTop => "Slide From Top",
+            MenuTransitionStyle::SlideFromBottom => "Slide From Bottom",
+            MenuTransitionStyle::Scale => "Scale",
+        }
+    }
+
+    /// Places `rect` when it is `shown` of the way in, where 1 is fully shown.
+    /// Slides travel the rect's own size. Returns the rect and its opacity.
+    pub fn apply(&self, rect: Rect, shown: f32) -> (Rect, f32) {
+        let hidden = 1.0 - shown;
+        match self {
+            MenuTransitionStyle::None => (rect, 1.0),
+            MenuTransitionStyle::Fade => (rect, shown),
+            MenuTransitionStyle::SlideFromLeft => (offset_rect(rect, -rect.w * hidden, 0.0), 1.0),
+            MenuTransitionStyle::SlideFromRight => (offset_rect(rect, rect.w * hidden, 0.0), 1.0),
+            MenuTransitionStyle::SlideFromTop => (offset_rect(rect, 0.0, -rect.h * hidden), 1.0),
+            MenuTransitionStyle::SlideFromBottom => (offset_rect(rect, 0.0, rect.h * hidden), 1.0),
+            MenuTransitionStyle::Scale => (
+                scale_rect(rect, SCALE_FROM + (1.0 - SCALE_FROM) * shown),
+                shown,
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for MenuTransitionStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// Transition played when a menu opens or closes, or an element enters.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct MenuTransition {
+    pub style: MenuTransitionStyle,
+    /// Length of the transition in seconds.
+    pub duration: f32,
+}
+
+impl Default for MenuTransition {
+    fn default() -> Self {
+        Self {
+            style: MenuTransitionStyle::None,
+            duration: 0.25,
+        }
+    }
+}
+
+impl MenuTransition {
+    /// Returns true when left at the defaults.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns true when the transition has something to play.
+    pub fn is_animated(&self) -> bool {
+        self.style != MenuTransitionStyle::None && self.duration > 0.0
+    }
+
+    /// Returns true once `elapsed` seconds have played the whole transition.
+    pub fn is_finished(&self, elapsed: f32) -> bool {
+        !self.is_animated() || elapsed >= self.duration
+    }
+
+    /// Eased share shown `elapsed` seconds into an entrance.
+    pub fn entering(&self, elapsed: f32) -> f32 {
+        if !self.is_animated() {
+            return 1.0;
+        }
+        Easing::OutCubic.apply(elapsed / self.duration)
+    }
+
+    /// Eased share still shown `elapsed` seconds into an exit.
+    pub fn exiting(&self, elapsed: f32) -> f32 {
+        if !self.is_animated() {
+            return 0.0;
+        }
+        Easing::OutCubic.apply(1.0 - elapsed / self.duration)
+    }
+}
+
+/// Animations authored on a menu template.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(default)]
+pub struct MenuAnimation {
+    /// Transition of the whole menu when it opens.
+    pub open: MenuTransition,
+    /// Transition of the whole menu when it closes. Closing actions wait for it.
+    pub close: MenuTransition,
+    /// Seconds between the enter animations of successive elements.
+    pub stagger: f32,
+    /// How far the focused element swells while pulsing, as a share of its size.
+    pub focus_pulse: f32,
+    /// Sprite that glides beside the focused element. `SpriteId(0)` draws none.
+    pub cursor_sprite: SpriteId,
+    /// Squash controls briefly when they are activated.
+    pub press_feedback: bool,
+}
+
+impl MenuAnimation {
+    /// Returns true when nothing is animated.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Seconds element number `order` waits before its enter animation.
+    pub fn enter_delay(&self, order: usize) -> f32 {
+        self.stagger.max(0.0) * order as f32
+    }
+
+    /// Scale of the focused element `time` seconds into its pulse.
+    pub fn focus_scale(&self, time: f32) -> f32 {
+        if self.focus_pulse <= 0.0 {
+            return 1.0;
+        }
+        let wave = 0.5 - 0.5 * (time * FOCUS_PULSE_RATE * 2.0 * PI).cos();
+        1.0 + self.focus_pulse * wave
+    }
+
+    /// Scale of a control `elapsed` seconds after it was pressed.
+    pub fn press_scale(&self, elapsed: f32) -> f32 {
+        if !self.press_feedback || !(0.0..PRESS_DURATION).contains(&elapsed) {
+            return 1.0;
+        }
+        1.0 - PRESS_DEPTH * (elapsed / PRESS_DURATION * PI).sin()
+    }
+}
+
+/// Moves `rect` by `dx`, `dy`.
+fn offset_rect(rect: Rect, dx: f32, dy: f32) -> Rect {
+    Rect::new(rect.x + dx, rect.y + dy, rect.w, rect.h)
+}
+
+/// Scales `rect` about its centre.
+pub fn scale_rect(rect: Rect, scale: f32) -> Rect {
+    let w = rect.w * scale;
+    let h = rect.h * scale;
+    Rect::new(
+        rect.x + (rect.w - w) / 2.0,
+        rect.y + (rect.h - h) / 2.0,
+        w,
+        h,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transitions_ease_in_and_out_of_place() {
+        let rect = Rect::new(0.0, 0.0, 100.0, 50.0);
+        let slide = MenuTransition {
+            style: MenuTransitionStyle::SlideFromRight,
+            duration: 0.5,
+        };
+
+        assert_eq!(slide.entering(0.0), 0.0);
+        assert_eq!(slide.entering(0.5), 1.0);
+        assert_eq!(slide.exiting(0.5), 0.0);
+        assert!(slide.is_finished(0.5) && !slide.is_finished(0.25));
+
+        let (start, opacity) = slide.style.apply(rect, slide.entering(0.0));
+        assert_eq!((start.x, opacity), (100.0, 1.0));
+        let (end, _) = slide.style.apply(rect, slide.entering(1.0));
+        assert_eq!(end, rect);
+
+        let (scaled, opacity) = MenuTransitionStyle::Scale.apply(rect, 0.0);
+        assert_eq!(scaled, Rect::new(10.0, 5.0, 80.0, 40.0));
+        assert_eq!(opacity, 0.0);
+
+        let none = MenuTransition::default();
+        assert_eq!(none.entering(0.0), 1.0);
+        assert!(none.is_finished(0.0));
+    }
+
+    #[test]
+    fn focus_pulse_and_press_feedback_return_to_rest() {
+        let animation = MenuAnimation {
+            stagger: 0.05,
+            focus_pulse: 0.1,
+            press_feedback: true,
+            ..Default::default()
+        };
+
+        assert_eq!(animation.enter_delay(3), 0.05 * 3.0);
+        assert_eq!(animation.focus_scale(0.0), 1.0);
+        assert!((animation.focus_scale(1.0 / 3.0) - 1.1).abs() < 0.001);
+        assert_eq!(animation.press_scale(0.0), 1.0);
+        assert!(animation.press_scale(PRESS_DURATION / 2.0) < 1.0);
+        assert_eq!(animation.press_scale(PRESS_DURATION), 1.0);
+        assert_eq!(MenuAnimation::default().press_scale(0.05), 1.0);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::menu::*;
+use crate::text::{TextValue, interpolate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Upper bound on cached bound texts before the cache is flushed.
+const BOUND_TEXT_CACHE_LIMIT: usize = 256;
+
+/// A value pushed from Lua that menu elements can bind to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuValue {
+    Number(f64),
+    Bool(bool),
+    Text(String),
+    /// Items for scroll lists, in order.
+    List(Vec<String>),
+}
+
+impl MenuValue {
+    /// Returns true for `true`, non-zero numbers and non-empty text and lists.
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            MenuValue::Number(value) => *value != 0.0,
+            MenuValue::Bool(value) => *value,
+            MenuValue::Text(text) => !text.is_empty(),
+            MenuValue::List(items) => !items.is_empty(),
+        }
+    }
+
+    /// Returns the value as a text value. Lists read as their items joined by commas.
+    pub fn text_value(&self) -> TextValue {
+        match self {
+            MenuValue::Number(value) => TextValue::Number(*value),
+            MenuValue::Bool(value) => TextValue::Bool(*value),
+            MenuValue::Text(text) => TextValue::Text(text.clone()),
+            MenuValue::List(items) => TextValue::Text(items.join(", ")),
+        }
+    }
+}
+
+/// Values pushed to one menu, by key.
+pub type MenuValues = HashMap<String, MenuValue>;
+
+/// Formats `text` with the bound values through [`interpolate`], so bound text
+/// supports the same placeholders, plurals and selects as other text.
+/// Placeholders without a value are left as written.
+pub fn bind_text(text: &str, language: &str, values: &MenuValues) -> String {
+    let variables: HashMap<String, TextValue> = values
+        .iter()
+        .map(|(key, value)| (key.clone(), value.text_value()))
+        .collect();
+    interpolate(text, language, &variables)
+}
+
+/// Reuses bound text while its source, language and values stay the same.
+#[derive(Debug, Default)]
+pub struct BoundTextCache {
+    entries: HashMap<String, BoundText>,
+}
+
+#[derive(Debug)]
+struct BoundText {
+    language: String,
+    values: MenuValues,
+    text: String,
+}
+
+impl BoundTextCache {
+    /// Returns `text` formatted with `values`, binding it again only when they changed.
+    pub fn get(&mut self, text: &str, language: &str, values: &MenuValues) -> String {
+        if let Some(bound) = self.entries.get(text)
+            && bound.language == language
+            && bound.values == *values
+        {
+            return bound.text.clone();
+        }
+
+        // Dynamic text would otherwise grow the cache forever
+        if self.entries.len() >= BOUND_TEXT_CACHE_LIMIT {
+            self.entries.clear();
+        }
+        let bound = bind_text(text, language, values);
+        self.entries.insert(
+            text.to_string(),
+            BoundText {
+                language: language.to_string(),
+                values: values.clone(),
+                text: bound.clone(),
+            },
+        );
+        bound
+    }
+}
+
+/// Conditions that show, hide, enable or disable an element from bound values.
+/// Each is a value key, optionally prefixed with `!` to negate it.
+/// An empty condition leaves the authored setting alone.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ElementBindings {
+    /// Element is only visible while this holds.
+    pub visible_if: String,
+    /// Element is only enabled while this holds.
+    pub enabled_if: String,
+}
+
+impl ElementBindings {
+    /// Returns true if no condition is set.
+    pub fn is_empty(&self) -> bool {
+        self.visible_if.is_empty() && self.enabled_if.is_empty()
+    }
+}
+
+/// Evaluates a condition such as `"has_save"` or `"!shop_closed"`.
+/// Missing values count as false.
+pub fn evaluate_condition(condition: &str, values: &MenuValues) -> bool {
+    let condition = condition.trim();
+    let (negate, key) = match condition.strip_prefix('!') {
+        Some(key) => (true, key.trim()),
+        None => (false, condition),
+    };
+    let holds = values.get(key).is_some_and(MenuValue::is_truthy);
+    holds != negate
+}
+
+/// Applies the visibility and enabled conditions of `element` and its layout children.
+pub fn apply_element_bindings(element: &mut MenuElement, values: &MenuValues) {
+    let bindings = &element.bindings;
+    if !bindings.visible_if.is_empty() {
+        element.visible &= evaluate_condition(&bindings.visible_if, values);
+    }
+    if !bindings.enabled_if.is_empty() {
+        element.enabled &= evaluate_condition(&bindings.enabled_if, values);
+    }
+    if let MenuElementKind::LayoutGroup(group) = &mut element.kind {
+        for child in &mut group.children {
+            apply_element_bindings(&mut child.element, values);
+        }
+    }
+}
+
+impl MenuTemplate {
+    /// Returns a copy of the template with its element conditions applied.
+    pub fn with_bindings(&self, values: &MenuValues) -> MenuTemplate {
+        let mut bound = self.clone();
+        for element in &mut bound.elements {
+            apply_element_bindings(element, values);
+        }
+        bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bishop::prelude::Rect;
+
+    fn values() -> MenuValues {
+        MenuValues::from([
+            ("player.coins".to_string(), MenuValue::Number(42.0)),
+            ("has_save".to_string(), MenuValue::Bool(true)),
+            ("speed".to_string(), MenuValue::Number(1.5)),
+        ])
+    }
+
+    #[test]
+    fn placeholders_are_replaced_with_values() {
+        let values = values();
+        assert_eq!(
+            bind_text("Coins: {{player.coins}}", "en", &values),
+            "Coins: 42"
+        );
+        assert_eq!(
+            bind_text("{{ speed }}x {{missing}}!", "en", &values),
+            "1.5x {{missing}}!"
+        );
+        assert_eq!(
+            bind_text(
+                "{player.coins, plural, one {# coin} other {# coins}}",
+                "en",
+                &values
+            ),
+            "42 coins"
+        );
+    }
+
+    #[test]
+    fn bound_text_is_reused_until_the_values_change() {
+        let mut cache = BoundTextCache::default();
+        let mut values = values();
+        assert_eq!(
+            cache.get("Coins: {{player.coins}}", "en", &values),
+            "Coins: 42"
+        );
+        assert_eq!(
+            cache.get("Coins: {{player.coins}}", "en", &values),
+            "Coins: 42"
+        );
+        assert_eq!(cache.entries.len(), 1);
+
+        values.insert("player.coins".to_string(), MenuValue::Number(7.0));
+        assert_eq!(
+            cache.get("Coins: {{player.coins}}", "en", &values),
+            "Coins: 7"
+        );
+        assert_eq!(cache.entries.len(), 1);
+    }
+
+    #[test]
+    fn conditions_hide_and_disable_elements() {
+        let values = values();
+        assert!(evaluate_condition("has_save", &values));
+        assert!(!evaluate_condition("!has_save", &values));
+        assert!(evaluate_condition("!shop_open", &values));
+
+        let mut template = MenuTemplate::new("shop".to_string());
+        let mut button = MenuElement::button(
+            "continue".to_string(),
+            MenuAction::Resume,
+            Rect::new(0.0, 0.0, 0.1, 0.1),
+        );
+        button.bindings.enabled_if = "shop_open".to_string();
+        button.bindings.visible_if = "has_save".to_string();
+        template.elements.push(button);
+
+        let bound = template.with_bindings(&values);
+        assert!(bound.elements[0].visible);
+        assert!(!bound.elements[0].enabled);
+        assert!(template.elements[0].enabled);
+    }
+}
@@ -31,6 +31,17 @@ impl MenuAction {
             MenuAction::Custom(_) => "Custom",
         }
     }
+
+    /// Returns true if the action takes the current menu off screen.
+    pub fn closes_menu(&self) -> bool {
+        matches!(
+            self,
+            MenuAction::Resume
+                | MenuAction::CloseMenu
+                | MenuAction::QuitToMainMenu
+                | MenuAction::QuitGame
+        )
+    }
 }
 
 /// Builder for composing menus with flexible layouts.
@@ -40,6 +51,7 @@ pub struct MenuBuilder {
     elements: Vec<MenuElement>,
     background: MenuBackground,
     mode: MenuMode,
+    animation: MenuAnimation,
     screen_width: f32,
     screen_height: f32,
 }
@@ -52,6 +64,7 @@ impl MenuBuilder {
             elements: Vec::new(),
             background: MenuBackground::default(),
             mode: MenuMode::Paused,
+            animation: MenuAnimation::default(),
             screen_width: 800.0,
             screen_height: 600.0,
         }
@@ -70,6 +83,12 @@ impl MenuBuilder {
         self
     }
 
+    /// Sets the menu's transitions and focus feedback.
+    pub fn animation(mut self, animation: MenuAnimation) -> Self {
+        self.animation = animation;
+        self
+    }
+
     /// Sets the background style.
     pub fn background(mut self, bg: MenuBackground) -> Self {
         self.background = bg;
@@ -101,6 +120,7 @@ impl MenuBuilder {
             background: self.background,
             elements: self.elements,
             mode: self.mode,
+            animation: self.animation,
         }
     }
 }
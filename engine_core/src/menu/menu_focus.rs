@@ -11,13 +11,13 @@ pub enum NavDirection {
 
 /// Tracks the current focus position in a menu.
 ///
-/// Uses a two-level scheme: `node` indexes a top-level element (button, slider, or layout group),
+/// Uses a two-level scheme: `node` indexes a top-level element (a control or a layout group),
 /// and `child` optionally indexes a focusable child within a layout group.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MenuFocus {
     /// Element index in the template.
     pub node: usize,
-    /// Child index within a layout group (None for standalone buttons or sliders).
+    /// Child index within a layout group (None for standalone controls).
     pub child: Option<usize>,
 }
 
@@ -37,7 +37,7 @@ impl MenuFocus {
                 continue;
             }
             match &element.kind {
-                MenuElementKind::Button(_) | MenuElementKind::Slider(_) => {
+                kind if kind.is_focusable() => {
                     self.node = i;
                     return;
                 }
@@ -59,30 +59,24 @@ impl MenuFocus {
             return;
         };
 
-        match &element.kind {
-            MenuElementKind::Button(button) => {
-                if let Some(target_idx) = Self::get_nav_target(&button.nav_targets, dir) {
-                    self.enter_element(target_idx, dir, template);
-                }
-            }
-            MenuElementKind::Slider(slider) => {
-                if let Some(target_idx) = Self::get_nav_target(&slider.nav_targets, dir) {
-                    self.enter_element(target_idx, dir, template);
-                }
+        if let Some(nav_targets) = element.kind.control_nav_targets() {
+            if let Some(target_idx) = Self::get_nav_target(nav_targets, dir) {
+                self.enter_element(target_idx, dir, template);
             }
-            MenuElementKind::LayoutGroup(group) => {
-                let is_along_axis = Self::direction_along_axis(dir, group.layout.direction);
+            return;
+        }
 
-                if is_along_axis {
-                    self.navigate_within_group(dir, group, template);
-                } else {
-                    let target = Self::group_nav_field(dir, group);
-                    if let Some(target_idx) = target {
-                        self.enter_element(target_idx, dir, template);
-                    }
+        if let MenuElementKind::LayoutGroup(group) = &element.kind {
+            let is_along_axis = Self::direction_along_axis(dir, group.layout.direction);
+
+            if is_along_axis {
+                self.navigate_within_group(dir, group, template);
+            } else {
+                let target = Self::group_nav_field(dir, group);
+                if let Some(target_idx) = target {
+                    self.enter_element(target_idx, dir, template);
                 }
             }
-            _ => {}
         }
     }
 
@@ -161,7 +155,7 @@ impl MenuFocus {
         }
 
         match &target_element.kind {
-            MenuElementKind::Button(_) | MenuElementKind::Slider(_) => {
+            kind if kind.is_focusable() => {
                 self.node = target_idx;
                 self.child = None;
             }
@@ -1,3 +1,4 @@
+use crate::text::dialogue::{parse_dialogue_choice_action, push_dialogue_choice};
 use std::cell::RefCell;
 
 /// Trait for handling custom menu actions.
@@ -30,6 +31,10 @@ pub struct GameMenuHandler;
 
 impl MenuActionHandler for GameMenuHandler {
     fn handle_action(&mut self, action: &str) -> bool {
+        if let Some(index) = parse_dialogue_choice_action(action) {
+            push_dialogue_choice(index);
+            return true;
+        }
         MENU_EVENTS.with(|events| {
             events.borrow_mut().push(action.to_string());
         });
@@ -57,3 +62,45 @@ pub fn push_slider_event(key: String, value: f32) {
 pub fn drain_slider_events() -> Vec<(String, f32)> {
     SLIDER_EVENTS.with(|events| events.borrow_mut().drain(..).collect())
 }
+
+thread_local! {
+    static TEXT_INPUT_EVENTS: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Queues a submitted text input event.
+pub fn push_text_input_event(key: String, text: String) {
+    TEXT_INPUT_EVENTS.with(|events| {
+        events.borrow_mut().push((key, text));
+    });
+}
+
+/// Drains all pending text input events and returns them.
+pub fn drain_text_input_events() -> Vec<(String, String)> {
+    TEXT_INPUT_EVENTS.with(|events| events.borrow_mut().drain(..).collect())
+}
+
+/// An item picked from a scroll list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListEvent {
+    /// Key of the list the item was picked from.
+    pub key: String,
+    /// Zero-based index of the item.
+    pub index: usize,
+    pub item: String,
+}
+
+thread_local! {
+    static LIST_EVENTS: RefCell<Vec<ListEvent>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Queues a scroll list pick event.
+pub fn push_list_event(event: ListEvent) {
+    LIST_EVENTS.with(|events| {
+        events.borrow_mut().push(event);
+    });
+}
+
+/// Drains all pending scroll list events and returns them.
+pub fn drain_list_events() -> Vec<ListEvent> {
+    LIST_EVENTS.with(|events| events.borrow_mut().drain(..).collect())
+}
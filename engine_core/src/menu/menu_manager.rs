@@ -1,8 +1,12 @@
+use crate::assets::asset_manager::AssetManager;
+use crate::assets::sprite::SpriteId;
+use crate::audio::{AudioCommand, push_audio_command};
 use crate::menu::runtime::*;
 use crate::menu::*;
 use crate::onscreen_error;
-use crate::storage::path_utils::menus_folder;
-use crate::text::TextManager;
+use crate::storage::path_utils::{menu_theme_path, menus_folder};
+use crate::storage::player_settings::*;
+use crate::text::{RichTextCache, TextManager};
 use bishop::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -13,8 +17,14 @@ use widgets::MouseButton;
 pub struct MenuManager {
     /// Registered menu templates by id.
     templates: HashMap<String, MenuTemplate>,
+    /// Templates with their element conditions applied to the current values.
+    bound_templates: HashMap<String, MenuTemplate>,
+    /// Values pushed from Lua, by menu id.
+    values: HashMap<String, MenuValues>,
     /// Stack of active menu ids (top = current).
     menu_stack: Vec<String>,
+    /// Shown HUD ids, drawn in order under any active menu.
+    huds: Vec<String>,
     /// Policy that determines how global menu shortcuts behave.
     input_policy: MenuInputPolicy,
     /// Navigation input bindings.
@@ -25,10 +35,18 @@ pub struct MenuManager {
     action_handler: Box<dyn MenuActionHandler>,
     /// The game viewport rect used to transform normalized menu coordinates to screen space.
     viewport: Rect,
-    /// Current values for slider elements, keyed by slider key.
-    slider_values: HashMap<String, f32>,
     /// Hold-to-repeat state for the currently focused slider.
     slider_repeat: SliderRepeatState,
+    /// Parsed label markup reused across frames.
+    markup_cache: RichTextCache,
+    /// Bound label text reused while its values stay the same.
+    bound_text_cache: BoundTextCache,
+    /// Text input values, scroll list items and the edit in progress.
+    controls: MenuControlState,
+    /// Transitions, focus feedback and deferred closing of the active menu.
+    animator: MenuAnimator,
+    /// Named styles shared by every template.
+    theme: MenuTheme,
 }
 
 impl Default for MenuManager {
@@ -64,6 +82,8 @@ pub enum MenuMode {
     Overlay,
     /// Full-screen front-end menu shown before gameplay begins.
     FrontEnd,
+    /// Heads-up display drawn over gameplay every frame. Never takes input.
+    Hud,
 }
 
 impl MenuMode {
@@ -78,14 +98,21 @@ impl MenuManager {
     pub fn new() -> Self {
         let mut manager = Self {
             templates: HashMap::new(),
+            bound_templates: HashMap::new(),
+            values: HashMap::new(),
             menu_stack: Vec::new(),
+            huds: Vec::new(),
             input_policy: MenuInputPolicy::default(),
             navigation: MenuNavigation::default(),
             focus: MenuFocus::new(0),
             action_handler: Box::new(NoOpActionHandler),
             viewport: Rect::new(0.0, 0.0, 1.0, 1.0),
-            slider_values: HashMap::new(),
             slider_repeat: SliderRepeatState::default(),
+            markup_cache: RichTextCache::default(),
+            bound_text_cache: BoundTextCache::default(),
+            controls: MenuControlState::default(),
+            animator: MenuAnimator::default(),
+            theme: MenuTheme::default(),
         };
         for template in default_menus() {
             manager.register_template(template);
@@ -113,16 +140,98 @@ impl MenuManager {
         &self.input_policy
     }
 
+    /// Replaces the theme every template is styled with.
+    pub fn set_theme(&mut self, theme: MenuTheme) {
+        self.theme = theme;
+    }
+
+    /// Returns the theme every template is styled with.
+    pub fn theme(&self) -> &MenuTheme {
+        &self.theme
+    }
+
     /// Registers a menu template.
     pub fn register_template(&mut self, template: MenuTemplate) {
-        self.templates.insert(template.id.clone(), template);
+        let id = template.id.clone();
+        self.templates.insert(id.clone(), template);
+        self.refresh_bindings(&id);
     }
 
-    /// Opens a menu by id.
+    /// Sets a value elements of `menu_id` can bind to, or clears it with `None`.
+    /// List values also fill the scroll lists with the same key in that menu only.
+    pub fn set_value(&mut self, menu_id: &str, key: &str, value: Option<MenuValue>) {
+        let values = self.values.entry(menu_id.to_string()).or_default();
+        match value {
+            Some(value) => {
+                if values.get(key) == Some(&value) {
+                    return;
+                }
+                match &value {
+                    MenuValue::List(items) => {
+                        self.controls
+                            .set_bound_list_items(menu_id, key, items.clone());
+                    }
+                    _ => self.controls.clear_bound_list(menu_id, key),
+                }
+                values.insert(key.to_string(), value);
+            }
+            None => {
+                if values.remove(key).is_none() {
+                    return;
+                }
+                self.controls.clear_bound_list(menu_id, key);
+            }
+        }
+        self.refresh_bindings(menu_id);
+    }
+
+    /// Returns the value bound to `key` in `menu_id`.
+    pub fn value(&self, menu_id: &str, key: &str) -> Option<&MenuValue> {
+        self.values.get(menu_id)?.get(key)
+    }
+
+    /// Re-applies element conditions for `menu_id` after its values changed,
+    /// moving focus off elements that were hidden or disabled.
+    fn refresh_bindings(&mut self, menu_id: &str) {
+        let Some(template) = self.templates.get(menu_id) else {
+            return;
+        };
+        let bound = match self.values.get(menu_id) {
+            Some(values) => template.with_bindings(values),
+            None => template.clone(),
+        };
+
+        if self.active_menu_id() == Some(menu_id) {
+            let focus_is_valid = bound
+                .get_element_at_focus(&self.focus)
+                .is_some_and(|el| el.kind.is_focusable() && el.enabled && el.visible);
+            if !focus_is_valid {
+                self.focus.reset(&bound);
+                self.slider_repeat.reset();
+            }
+        }
+        self.bound_templates.insert(menu_id.to_string(), bound);
+    }
+
+    /// Opens a menu by id. HUD templates are shown as HUDs instead.
     pub fn open_menu(&mut self, id: &str) {
-        if let Some(template) = self.templates.get(id) {
+        // A menu still closing finishes first so its action isn't lost
+        if self.bound_templates.contains_key(id)
+            && let Some(action) = self.animator.take_closing()
+        {
+            self.run_action(action);
+        }
+
+        if let Some(template) = self.bound_templates.get(id) {
+            if template.mode == MenuMode::Hud {
+                self.show_hud(id);
+                return;
+            }
             self.focus.reset(template);
             self.slider_repeat.reset();
+            self.controls.cancel_edit();
+            self.animator.opened(template.animation.open);
+            self.animator.reset_cursor();
             self.menu_stack.push(id.to_string());
         }
     }
@@ -130,24 +239,80 @@ impl MenuManager {
     /// Closes the current menu and returns to previous menu if any.
     pub fn close_menu(&mut self) {
         self.menu_stack.pop();
+        self.controls.cancel_edit();
         if let Some(parent_id) = self.menu_stack.last()
-            && let Some(template) = self.templates.get(parent_id)
+            && let Some(template) = self.bound_templates.get(parent_id)
         {
             self.focus.reset(template);
             self.slider_repeat.reset();
+            self.animator.opened(template.animation.open);
+            self.animator.reset_cursor();
             return;
         }
+        self.animator.take_closing();
         self.focus = MenuFocus::new(0);
         self.slider_repeat.reset();
     }
 
+    /// Closes the current menu after its close transition, like a close button.
+    pub fn close_menu_animated(&mut self) {
+        self.handle_action(MenuAction::CloseMenu);
+    }
+
+    /// Closes the menu `id` wherever it is in the stack. The top menu plays
+    /// its close transition; menus beneath it are removed straight away.
+    pub fn close_menu_by_id(&mut self, id: &str) {
+        if self.active_menu_id() == Some(id) {
+            self.close_menu_animated();
+        } else {
+            self.menu_stack.retain(|menu_id| menu_id != id);
+        }
+    }
+
     /// Closes all menus and returns to game.
     pub fn close_all(&mut self) {
         self.menu_stack.clear();
+        self.animator.take_closing();
+        self.controls.cancel_edit();
         self.focus = MenuFocus::new(0);
         self.slider_repeat.reset();
     }
 
+    /// Shows a HUD template over gameplay. Later HUDs draw on top of earlier ones.
+    pub fn show_hud(&mut self, id: &str) {
+        let Some(template) = self.templates.get(id) else {
+            onscreen_error!("Unknown HUD '{}'", id);
+            return;
+        };
+        if template.mode != MenuMode::Hud {
+            onscreen_error!("Menu '{}' is not a HUD", id);
+            return;
+        }
+        if !self.is_hud_visible(id) {
+            self.huds.push(id.to_string());
+        }
+    }
+
+    /// Hides a shown HUD.
+    pub fn hide_hud(&mut self, id: &str) {
+        self.huds.retain(|hud| hud != id);
+    }
+
+    /// Hides every HUD.
+    pub fn hide_all_huds(&mut self) {
+        self.huds.clear();
+    }
+
+    /// Returns true if the HUD with `id` is shown.
+    pub fn is_hud_visible(&self, id: &str) -> bool {
+        self.huds.iter().any(|hud| hud == id)
+    }
+
+    /// Returns true if any HUD is shown.
+    pub fn has_visible_hud(&self) -> bool {
+        !self.huds.is_empty()
+    }
+
     /// Returns the current menu mode based on active menu.
     pub fn mode(&self) -> Option<MenuMode> {
         if let Some(menu_id) = self.menu_stack.last()
@@ -183,11 +348,27 @@ impl MenuManager {
         self.menu_stack.last().map(String::as_str)
     }
 
+    /// Replaces the items of every scroll list with `key`, except in menus
+    /// that bind their own list value to it.
+    pub fn set_list_items(&mut self, key: &str, items: Vec<String>) {
+        self.controls.set_list_items(key, items);
+    }
+
+    /// Sets the text shown by every text input with `key`.
+    pub fn set_text_value(&mut self, key: &str, text: String) {
+        self.controls.set_text(key, text);
+    }
+
+    /// Returns the submitted text of the text inputs with `key`.
+    pub fn text_value(&self, key: &str) -> &str {
+        self.controls.text(key)
+    }
+
     fn apply_pause_shortcut(&mut self, pause_pressed: bool) -> bool {
         if pause_pressed && let MenuInputPolicy::GameplayPause { pause_menu_id } = &self.input_policy {
             let pause_menu_id = pause_menu_id.clone();
             if self.has_active_menu() {
-                self.close_menu();
+                self.handle_action(MenuAction::CloseMenu);
             } else {
                 self.open_menu(&pause_menu_id);
             }
@@ -199,10 +380,10 @@ impl MenuManager {
     fn apply_cancel_shortcut(&mut self, cancel_pressed: bool) {
         if cancel_pressed {
             match &self.input_policy {
-                MenuInputPolicy::GameplayPause { .. } => self.close_menu(),
+                MenuInputPolicy::GameplayPause { .. } => self.handle_action(MenuAction::CloseMenu),
                 MenuInputPolicy::FrontEnd => {
                     if self.menu_stack.len() > 1 {
-                        self.close_menu();
+                        self.handle_action(MenuAction::CloseMenu);
                     }
                 }
             }
@@ -220,6 +401,18 @@ impl MenuManager {
 
     /// Handles input for menu toggling and navigation.
     pub fn handle_input<C: BishopContext>(&mut self, ctx: &mut C) {
+        self.advance_animations(ctx.get_time());
+        // A closing menu is on its way out and takes no more input
+        if self.animator.is_closing() {
+            return;
+        }
+
+        // Typing owns the keyboard, so no shortcuts apply until the edit ends
+        if self.controls.is_editing() {
+            self.handle_text_entry(ctx);
+            return;
+        }
+
         let pause_pressed = self.navigation.pause_pressed(ctx);
         let cancel_pressed = self.navigation.cancel_pressed(ctx);
         if self.apply_pause_shortcut(pause_pressed) {
@@ -231,17 +424,31 @@ impl MenuManager {
         }
 
         if let Some(menu_id) = self.menu_stack.last().cloned()
-            && let Some(template) = self.templates.get(&menu_id).cloned()
+            && let Some(template) = self.bound_templates.get(&menu_id).cloned()
         {
             let focus_before_input = self.focus.clone();
 
-            if ctx.is_mouse_button_pressed(MouseButton::Left) {
-                let mouse = ctx.mouse_position();
-                let mouse = Vec2::new(mouse.0, mouse.1);
-                if let Some(focus) = focus_target_at(&template, self.viewport, mouse) {
-                    self.focus = focus;
-                    self.slider_repeat.reset();
-                }
+            let mouse = ctx.mouse_position();
+            let mouse = Vec2::new(mouse.0, mouse.1);
+            if ctx.is_mouse_button_pressed(MouseButton::Left)
+                && let Some(focus) =
+                    focus_target_at(&template, self.viewport, self.animator.pose(), mouse)
+            {
+                self.focus = focus;
+                self.slider_repeat.reset();
+                self.animator.press(&self.focus);
+                self.click_focused(&template, mouse);
+            }
+
+            let wheel = ctx.mouse_wheel().1;
+            if wheel != 0.0
+                && let Some(MenuElementKind::ScrollList(list)) = template
+                    .get_element_at_focus(&self.focus)
+                    .map(|el| &el.kind)
+            {
+                let rows = if wheel > 0.0 { -1 } else { 1 };
+                self.controls
+                    .scroll(&template.id, &list.key, rows, list.visible_rows);
             }
 
             let up_pressed = self.navigation.up_pressed(ctx);
@@ -251,15 +458,29 @@ impl MenuManager {
             let right_pressed = self.navigation.right_pressed(ctx);
             let right_down = self.navigation.right_down(ctx);
 
-            if up_pressed {
+            let focused_list = template.get_element_at_focus(&self.focus).and_then(|el| {
+                if let MenuElementKind::ScrollList(list) = &el.kind {
+                    Some((list.key.clone(), list.visible_rows))
+                } else {
+                    None
+                }
+            });
+            // Lists move their selection first and only hand focus on at either end
+            let moves_list = |controls: &mut MenuControlState, offset| {
+                focused_list.as_ref().is_some_and(|(key, rows)| {
+                    controls.move_selection(&template.id, key, offset, *rows)
+                })
+            };
+            if up_pressed && !moves_list(&mut self.controls, -1) {
                 self.focus.navigate(NavDirection::Up, &template);
             }
-            if down_pressed {
+            if down_pressed && !moves_list(&mut self.controls, 1) {
                 self.focus.navigate(NavDirection::Down, &template);
             }
 
             if self.focus != focus_before_input {
                 self.slider_repeat.reset();
+                self.play_focused_sound(|style| &style.focus_sound);
             }
 
             let focused_slider = template.get_element_at_focus(&self.focus).and_then(|el| {
@@ -284,38 +505,39 @@ impl MenuManager {
                     right_pressed,
                     right_down,
                 ) {
-                    let current = self
-                        .slider_values
-                        .get(&key)
-                        .copied()
-                        .unwrap_or(default_value);
+                    let current = player_setting_number(&key, default_value);
                     if let Some(new_value) = adjust_slider_value(current, step, min, max, direction)
                     {
-                        self.slider_values.insert(key.clone(), new_value);
+                        set_player_setting(&key, SettingValue::Number(new_value));
                         push_slider_event(key, new_value);
                     }
                 }
-            } else if left_pressed {
+            } else if let Some(MenuElementKind::Choice(choice)) = template
+                .get_element_at_focus(&self.focus)
+                .map(|el| &el.kind)
+                && (left_pressed || right_pressed)
+            {
                 self.slider_repeat.reset();
-                self.focus.navigate(NavDirection::Left, &template);
-            } else if right_pressed {
+                cycle_choice(choice, if left_pressed { -1 } else { 1 });
+            } else if left_pressed || right_pressed {
                 self.slider_repeat.reset();
-                self.focus.navigate(NavDirection::Right, &template);
+                let direction = if left_pressed {
+                    NavDirection::Left
+                } else {
+                    NavDirection::Right
+                };
+                let focus_before_move = self.focus.clone();
+                self.focus.navigate(direction, &template);
+                if self.focus != focus_before_move {
+                    self.play_focused_sound(|style| &style.focus_sound);
+                }
             } else {
                 self.slider_repeat.reset();
             }
 
             let confirm_pressed = self.navigation.confirm_pressed(ctx);
             let action_to_handle = if confirm_pressed {
-                template
-                    .get_element_at_focus(&self.focus)
-                    .and_then(|element| {
-                        if let MenuElementKind::Button(button) = &element.kind {
-                            Some(button.action.clone())
-                        } else {
-                            None
-                        }
-                    })
+                self.activate_focused(&template)
             } else {
                 None
             };
@@ -328,29 +550,222 @@ impl MenuManager {
         }
     }
 
-    /// Renders the active menu if any.
-    pub fn render<C: BishopContext>(&mut self, ctx: &mut C, text_manager: &TextManager) {
-        if !self.has_active_menu() {
+    /// Confirms the focused control. Returns the action of a focused button.
+    fn activate_focused(&mut self, template: &MenuTemplate) -> Option<MenuAction> {
+        let element = template.get_element_at_focus(&self.focus)?;
+        self.animator.press(&self.focus);
+        self.play_focused_sound(|style| &style.activate_sound);
+        match &element.kind {
+            MenuElementKind::Button(button) => return Some(button.action.clone()),
+            MenuElementKind::Toggle(toggle) => flip_toggle(toggle),
+            MenuElementKind::Choice(choice) => cycle_choice(choice, 1),
+            MenuElementKind::TextInput(input) => {
+                self.controls.begin_edit(&input.key, input.max_length);
+            }
+            MenuElementKind::ScrollList(list) => self.pick_list_item(&template.id, &list.key),
+            _ => {}
+        }
+        None
+    }
+
+    /// Plays the sound `pick` selects from the focused element's style, if it has one.
+    fn play_focused_sound(&self, pick: impl Fn(&MenuStyle) -> &String) {
+        let Some(style) = self
+            .active_menu_id()
+            .and_then(|id| self.bound_templates.get(id))
+            .and_then(|template| template.get_element_at_focus(&self.focus))
+            .and_then(|element| self.theme.resolve(element))
+        else {
             return;
+        };
+        let sound = pick(&style);
+        if !sound.is_empty() {
+            push_audio_command(AudioCommand::PlaySfx(sound.clone()));
         }
+    }
 
-        if let Some(menu_id) = self.menu_stack.last()
-            && let Some(template) = self.templates.get(menu_id)
-            && let Some(action) = render_active_menu(
-                ctx,
-                template,
-                menu_id,
-                self.viewport,
-                &self.focus,
-                &mut self.slider_values,
+    /// Applies a mouse click at `mouse` to the control that was just focused.
+    /// Buttons and sliders handle their own clicks while rendering.
+    fn click_focused(&mut self, template: &MenuTemplate, mouse: Vec2) {
+        let Some(element) = template.get_element_at_focus(&self.focus) else {
+            return;
+        };
+        let Some(rect) = focused_rect(template, self.viewport, self.animator.pose(), &self.focus)
+        else {
+            return;
+        };
+        match &element.kind {
+            MenuElementKind::Choice(choice) => {
+                // Clicks left of the value's centre step back
+                let value_centre = rect.x + rect.w * 0.7;
+                cycle_choice(choice, if mouse.x < value_centre { -1 } else { 1 });
+            }
+            MenuElementKind::ScrollList(list) => {
+                let visible_rows = list.visible_rows.max(1);
+                let row_height = rect.h / visible_rows as f32;
+                let scroll = self
+                    .controls
+                    .list(&template.id, &list.key)
+                    .map_or(0, |state| state.scroll);
+                let row = ((mouse.y - rect.y) / row_height).max(0.0) as usize;
+                if self
+                    .controls
+                    .select(&template.id, &list.key, scroll + row, visible_rows)
+                {
+                    self.pick_list_item(&template.id, &list.key);
+                }
+            }
+            MenuElementKind::Toggle(_) | MenuElementKind::TextInput(_) => {
+                self.activate_focused(template);
+            }
+            _ => {}
+        }
+    }
+
+    /// Reports the selected item of the list with `key` in `menu_id`.
+    fn pick_list_item(&self, menu_id: &str, key: &str) {
+        if let Some((index, item)) = self.controls.selected_item(menu_id, key) {
+            push_list_event(ListEvent {
+                key: key.to_string(),
+                index,
+                item,
+            });
+        }
+    }
+
+    /// Handles typing into the text input being edited.
+    /// The primary arrow keys step through characters for players without a keyboard.
+    fn handle_text_entry<C: BishopContext>(&mut self, ctx: &mut C) {
+        if self.navigation.cancel.is_primary_pressed(ctx) {
+            self.controls.cancel_edit();
+            return;
+        }
+        if self.navigation.confirm.is_primary_pressed(ctx) {
+            if let Some((key, text)) = self.controls.submit_edit() {
+                push_text_input_event(key, text);
+            }
+            return;
+        }
+
+        for c in ctx.chars_pressed() {
+            self.controls.type_char(c);
+        }
+        if ctx.is_key_pressed(KeyCode::Backspace) || self.navigation.left.is_primary_pressed(ctx) {
+            self.controls.delete_char();
+        }
+        if self.navigation.right.is_primary_pressed(ctx) {
+            self.controls.append_entry_char();
+        }
+        if self.navigation.up.is_primary_pressed(ctx) {
+            self.controls.cycle_last_char(1);
+        }
+        if self.navigation.down.is_primary_pressed(ctx) {
+            self.controls.cycle_last_char(-1);
+        }
+    }
+
+    /// Renders the shown HUDs and the active menu, if any.
+    pub fn render<C: BishopContext>(
+        &mut self,
+        ctx: &mut C,
+        text_manager: &TextManager,
+        asset_manager: &mut AssetManager,
+    ) {
+        let time = ctx.get_time();
+        self.advance_animations(time);
+
+        // HUDs belong to the scene, so they go with it behind front-end menus
+        if !self.is_hiding_game() {
+            for hud_id in &self.huds {
+                if let Some(template) = self.bound_templates.get(hud_id) {
+                    render_hud(
+                        ctx,
+                        template,
+                        self.theme.hud_area(self.viewport),
+                        MenuState {
+                            controls: &self.controls,
+                            values: self.values.get(hud_id),
+                            motion: MenuMotion::at_rest(&template.animation, time as f32),
+                            theme: &self.theme,
+                        },
+                        MenuTextResources {
+                            text_manager,
+                            markup_cache: &mut self.markup_cache,
+                            bound_text_cache: &mut self.bound_text_cache,
+                            asset_manager,
+                        },
+                    );
+                }
+            }
+        }
+
+        let Some(menu_id) = self.menu_stack.last() else {
+            return;
+        };
+        let Some(template) = self.bound_templates.get(menu_id) else {
+            return;
+        };
+        let cursor_target = if template.animation.cursor_sprite.0 != 0 {
+            focused_rect(template, self.viewport, self.animator.pose(), &self.focus)
+                .map(|rect| vec2(rect.x, rect.y + rect.h / 2.0))
+        } else {
+            None
+        };
+        let closing = self.animator.is_closing();
+        let action = render_active_menu(
+            ctx,
+            template,
+            menu_id,
+            self.viewport,
+            &self.focus,
+            MenuState {
+                controls: &self.controls,
+                values: self.values.get(menu_id),
+                motion: self.animator.frame(&template.animation, cursor_target),
+                theme: &self.theme,
+            },
+            MenuTextResources {
                 text_manager,
-            )
+                markup_cache: &mut self.markup_cache,
+                bound_text_cache: &mut self.bound_text_cache,
+                asset_manager,
+            },
+        );
+        // Clicks on a menu that is already closing are ignored
+        if let Some(action) = action
+            && !closing
         {
+            self.play_focused_sound(|style| &style.activate_sound);
             self.handle_action(action);
         }
     }
 
+    /// Advances menu animations and runs a closing action once its transition ends.
+    fn advance_animations(&mut self, time: f64) {
+        self.animator.set_time(time);
+        if let Some(action) = self.animator.finished_closing() {
+            self.run_action(action);
+        }
+    }
+
+    /// Runs `action`, first playing the active menu's close transition if it has one.
     fn handle_action(&mut self, action: MenuAction) {
+        if action.closes_menu()
+            && !self.animator.is_closing()
+            && let Some(close) = self
+                .active_menu_id()
+                .and_then(|id| self.bound_templates.get(id))
+                .map(|template| template.animation.close)
+            && close.is_animated()
+        {
+            self.controls.cancel_edit();
+            self.animator.begin_closing(close, action);
+            return;
+        }
+        self.run_action(action);
+    }
+
+    fn run_action(&mut self, action: MenuAction) {
         match action {
             MenuAction::Resume => self.close_all(),
             MenuAction::CloseMenu => self.close_menu(),
@@ -372,8 +787,19 @@ impl MenuManager {
         self.close_all();
     }
 
-    /// Loads all .ron menu templates from the menus folder and registers them.
+    /// Returns every sprite the theme and registered templates draw, once per use.
+    pub fn sprites(&self) -> Vec<SpriteId> {
+        let mut sprites = self.theme.sprites();
+        for template in self.templates.values() {
+            sprites.extend(template.sprites());
+        }
+        sprites
+    }
+
+    /// Loads the menu theme and all .ron menu templates from the menus folder and registers them.
     pub fn load_templates_from_disk(&mut self) {
+        self.theme = MenuTheme::load_from_path(&menu_theme_path());
+
         let dir = menus_folder();
         if !dir.exists() {
             return;
@@ -405,6 +831,21 @@ impl MenuManager {
     }
 }
 
+/// Flips the player setting behind a toggle.
+fn flip_toggle(toggle: &ToggleElement) {
+    let value = player_setting_bool(&toggle.key, toggle.default_value);
+    set_player_setting(&toggle.key, SettingValue::Bool(!value));
+}
+
+/// Steps the player setting behind a choice by `offset` options.
+fn cycle_choice(choice: &ChoiceElement, offset: isize) {
+    let current = player_setting(&choice.key);
+    let index = choice.index_of(current.as_ref().and_then(SettingValue::as_choice));
+    if let Some(next) = choice.cycle(index, offset) {
+        set_player_setting(&choice.key, SettingValue::Choice(next.to_string()));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -456,10 +897,160 @@ mod tests {
             background: MenuBackground::None,
             elements: Vec::new(),
             mode: MenuMode::FrontEnd,
+            animation: MenuAnimation::default(),
         });
 
         manager.open_menu("start");
 
         assert!(manager.is_hiding_game());
     }
+
+    #[test]
+    fn confirming_controls_updates_settings_and_queues_list_picks() {
+        let mut manager = MenuManager::new();
+        let toggle = MenuElement::new(
+            MenuElementKind::Toggle(ToggleElement {
+                key: "test_subtitles".to_string(),
+                ..Default::default()
+            }),
+            Rect::new(0.1, 0.1, 0.4, 0.1),
+        );
+        let list = MenuElement::new(
+            MenuElementKind::ScrollList(ScrollListElement {
+                key: "test_saves".to_string(),
+                ..Default::default()
+            }),
+            Rect::new(0.1, 0.3, 0.4, 0.4),
+        );
+        let template = MenuTemplate {
+            id: "controls".to_string(),
+            background: MenuBackground::None,
+            elements: vec![toggle, list],
+            mode: MenuMode::Paused,
+            animation: MenuAnimation::default(),
+        };
+
+        manager.focus.reset(&template);
+        assert_eq!(manager.activate_focused(&template), None);
+        assert!(player_setting_bool("test_subtitles", false));
+
+        manager.set_list_items(
+            "test_saves",
+            vec!["slot 1".to_string(), "slot 2".to_string()],
+        );
+        manager.focus = MenuFocus::new(1);
+        assert!(
+            manager
+                .controls
+                .move_selection("controls", "test_saves", 1, 5)
+        );
+        manager.activate_focused(&template);
+        assert_eq!(
+            drain_list_events(),
+            vec![ListEvent {
+                key: "test_saves".to_string(),
+                index: 1,
+                item: "slot 2".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn hud_templates_show_without_taking_the_menu_stack() {
+        let mut manager = MenuManager::new();
+        for id in ["hearts", "coins"] {
+            manager.register_template(MenuTemplate {
+                id: id.to_string(),
+                background: MenuBackground::None,
+                elements: Vec::new(),
+                mode: MenuMode::Hud,
+                animation: MenuAnimation::default(),
+            });
+        }
+
+        manager.open_menu("hearts");
+        manager.show_hud("coins");
+        manager.show_hud("coins");
+        assert!(!manager.has_active_menu());
+        assert!(!manager.is_pausing_game());
+        assert_eq!(manager.huds, vec!["hearts", "coins"]);
+
+        manager.show_hud("pause");
+        manager.hide_hud("hearts");
+        assert_eq!(manager.huds, vec!["coins"]);
+        assert!(manager.is_hud_visible("coins"));
+    }
+
+    #[test]
+    fn close_actions_wait_for_the_exit_transition() {
+        let mut manager = MenuManager::new();
+        let mut template = MenuTemplate::new("inventory".to_string());
+        template.animation.close = MenuTransition {
+            style: MenuTransitionStyle::SlideFromBottom,
+            duration: 0.2,
+        };
+        manager.register_template(template);
+
+        manager.advance_animations(1.0);
+        manager.open_menu("inventory");
+        manager.handle_action(MenuAction::CloseMenu);
+        assert_eq!(manager.active_menu_id(), Some("inventory"));
+
+        manager.advance_animations(1.1);
+        assert_eq!(manager.active_menu_id(), Some("inventory"));
+
+        manager.advance_animations(1.2);
+        assert_eq!(manager.active_menu_id(), None);
+    }
+
+    #[test]
+    fn menus_close_by_id_from_under_other_menus() {
+        let mut manager = MenuManager::new();
+        for id in ["choices", "pause"] {
+            manager.register_template(MenuTemplate::new(id.to_string()));
+        }
+
+        manager.open_menu("choices");
+        manager.open_menu("pause");
+        manager.close_menu_by_id("choices");
+        assert_eq!(manager.menu_stack, vec!["pause"]);
+
+        manager.close_menu_by_id("pause");
+        manager.advance_animations(1.0);
+        assert!(!manager.has_active_menu());
+    }
+
+    #[test]
+    fn values_hide_elements_and_move_focus_off_them() {
+        let mut manager = MenuManager::new();
+        let mut buy = MenuElement::button(
+            "buy".to_string(),
+            MenuAction::Custom("buy".to_string()),
+            Rect::new(0.1, 0.1, 0.2, 0.1),
+        );
+        buy.bindings.visible_if = "can_afford".to_string();
+        let leave = MenuElement::button(
+            "leave".to_string(),
+            MenuAction::CloseMenu,
+            Rect::new(0.1, 0.3, 0.2, 0.1),
+        );
+        manager.register_template(MenuTemplate {
+            id: "shop".to_string(),
+            background: MenuBackground::None,
+            elements: vec![buy, leave],
+            mode: MenuMode::Overlay,
+            animation: MenuAnimation::default(),
+        });
+
+        manager.set_value("shop", "can_afford", Some(MenuValue::Bool(true)));
+        manager.open_menu("shop");
+        assert_eq!(manager.focus, MenuFocus::new(0));
+
+        manager.set_value("shop", "can_afford", Some(MenuValue::Bool(false)));
+        assert_eq!(manager.focus, MenuFocus::new(1));
+        assert_eq!(
+            manager.value("shop", "can_afford"),
+            Some(&MenuValue::Bool(false))
+        );
+    }
 }
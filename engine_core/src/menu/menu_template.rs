@@ -1,4 +1,7 @@
+use crate::assets::asset_manager::AssetManager;
+use crate::assets::sprite::SpriteId;
 use crate::menu::*;
+use crate::text::rich_text::*;
 use bishop::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +12,9 @@ pub struct MenuTemplate {
     pub background: MenuBackground,
     pub elements: Vec<MenuElement>,
     pub mode: MenuMode,
+    /// Open and close transitions, enter stagger and focus feedback.
+    #[serde(default, skip_serializing_if = "MenuAnimation::is_default")]
+    pub animation: MenuAnimation,
 }
 
 impl MenuTemplate {
@@ -19,6 +25,7 @@ impl MenuTemplate {
             background: MenuBackground::default(),
             elements: Vec::new(),
             mode: MenuMode::Paused,
+            animation: MenuAnimation::default(),
         }
     }
 
@@ -41,6 +48,34 @@ impl MenuTemplate {
         }
     }
 
+    /// Returns every sprite the template draws, once per use.
+    pub fn sprites(&self) -> Vec<SpriteId> {
+        let mut sprites = Vec::new();
+        if self.animation.cursor_sprite.0 != 0 {
+            sprites.push(self.animation.cursor_sprite);
+        }
+        for element in &self.elements {
+            element.collect_sprites(&mut sprites);
+        }
+        sprites
+    }
+
+    /// Points every element styled `from`, including layout children, at style `to`.
+    pub fn rename_style(&mut self, from: &str, to: &str) {
+        for element in &mut self.elements {
+            if let MenuElementKind::LayoutGroup(group) = &mut element.kind {
+                for child in &mut group.children {
+                    if child.element.style == from {
+                        child.element.style = to.to_string();
+                    }
+                }
+            }
+            if element.style == from {
+                element.style = to.to_string();
+            }
+        }
+    }
+
     /// Returns element indices sorted by z_order (stable, ascending).
     pub fn sorted_element_indices(&self) -> Vec<usize> {
         let mut indices: Vec<usize> = (0..self.elements.len()).collect();
@@ -48,21 +83,25 @@ impl MenuTemplate {
         indices
     }
 
-    /// Renders menu labels transformed from normalized to screen-space using canvas origin/size.
+    /// Renders a label's markup inside a screen-space rect, wrapping at the rect width.
     pub(crate) fn render_label<C: BishopContext>(
         ctx: &mut C,
         label: &LabelElement,
         rect: Rect,
-        display_text: &str,
+        markup: &RichText,
+        asset_manager: &mut AssetManager,
     ) {
-        let txt_dims = ctx.measure_text(display_text, label.font_size);
-        let txt_x = match label.alignment {
-            HorizontalAlign::Left => rect.x,
-            HorizontalAlign::Center => rect.x + (rect.w - txt_dims.width) / 2.0,
-            HorizontalAlign::Right => rect.x + rect.w - txt_dims.width,
+        let font = asset_manager.font(ctx, &label.font);
+        let layout = layout_rich_text(ctx, markup, font, label.font_size, Some(rect.w));
+        let params = RichTextDrawParams {
+            origin: Vec2::new(rect.x, rect.y + (rect.h - layout.height()) / 2.0),
+            width: rect.w,
+            align: label.alignment,
+            color: label.color,
+            time: ctx.get_time() as f32,
+            visible_glyphs: None,
         };
-        let txt_y = rect.y + (rect.h - txt_dims.height) / 2.0 + txt_dims.offset_y;
-        ctx.draw_text(display_text, txt_x, txt_y, label.font_size, label.color);
+        draw_rich_text(ctx, &layout, &params, asset_manager);
     }
 
     /// Returns button elements in z_order with their data.
@@ -99,7 +138,7 @@ impl MenuTemplate {
         }
     }
 
-    /// Counts focusable children (buttons or sliders) in a layout group at the given element index.
+    /// Counts focusable children in a layout group at the given element index.
     pub fn focusable_child_count(&self, element_index: usize) -> usize {
         let Some(element) = self.elements.get(element_index) else {
             return 0;
@@ -111,16 +150,12 @@ impl MenuTemplate {
             .children
             .iter()
             .filter(|child| {
-                matches!(
-                    child.element.kind,
-                    MenuElementKind::Button(_) | MenuElementKind::Slider(_)
-                ) && child.element.enabled
-                    && child.element.visible
+                child.element.kind.is_focusable() && child.element.enabled && child.element.visible
             })
             .count()
     }
 
-    /// Gets the nth focusable child (button or slider) in a layout group.
+    /// Gets the nth focusable child in a layout group.
     pub fn get_focusable_child(
         &self,
         element_index: usize,
@@ -134,11 +169,7 @@ impl MenuTemplate {
             .children
             .iter()
             .filter(|child| {
-                matches!(
-                    child.element.kind,
-                    MenuElementKind::Button(_) | MenuElementKind::Slider(_)
-                ) && child.element.enabled
-                    && child.element.visible
+                child.element.kind.is_focusable() && child.element.enabled && child.element.visible
             })
             .nth(child_index)
             .map(|child| &child.element)
@@ -0,0 +1,413 @@
+use crate::assets::asset_manager::AssetManager;
+use crate::assets::sprite::SpriteId;
+use crate::constants::DESIGN_RESOLUTION_HEIGHT;
+use crate::menu::*;
+use crate::onscreen_error;
+use bishop::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Which look of a style an element is drawn with.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StyleState {
+    #[default]
+    Normal,
+    /// Focused by navigation or hovered by the mouse.
+    Focused,
+    Disabled,
+    /// Held down or just activated.
+    Pressed,
+}
+
+impl StyleState {
+    /// All style states.
+    pub fn all() -> &'static [StyleState] {
+        &[
+            StyleState::Normal,
+            StyleState::Focused,
+            StyleState::Disabled,
+            StyleState::Pressed,
+        ]
+    }
+
+    /// Display label for UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            StyleState::Normal => "Normal",
+            StyleState::Focused => "Focused",
+            StyleState::Disabled => "Disabled",
+            StyleState::Pressed => "Pressed",
+        }
+    }
+}
+
+impl std::fmt::Display for StyleState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// Colours and panel sprite of a style in one state.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct StyleLook {
+    pub background: Color,
+    pub text_color: Color,
+    /// Outline drawn around the element. Fully transparent draws none.
+    pub outline: Color,
+    /// Panel sprite drawn over the element instead of the background colour.
+    /// `SpriteId(0)` uses the colour.
+    pub sprite: SpriteId,
+    /// How the panel sprite is cut so its frame keeps its size.
+    pub slice: NineSlice,
+}
+
+impl Default for StyleLook {
+    fn default() -> Self {
+        Self {
+            background: Color::new(0.2, 0.2, 0.25, 1.0),
+            text_color: Color::WHITE,
+            outline: Color::new(0.5, 0.5, 0.5, 1.0),
+            sprite: SpriteId(0),
+            slice: NineSlice::default(),
+        }
+    }
+}
+
+/// Named look shared by menu elements across every template.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct MenuStyle {
+    pub name: String,
+    /// Font name from the fonts folder. Empty uses the default font.
+    pub font: String,
+    pub font_size: f32,
+    /// Space between the element's edges and its text.
+    pub padding: f32,
+    pub normal: StyleLook,
+    pub focused: StyleLook,
+    pub disabled: StyleLook,
+    pub pressed: StyleLook,
+    /// Sound effect played when an element of this style gains focus. Empty plays none.
+    pub focus_sound: String,
+    /// Sound effect played when an element of this style is activated. Empty plays none.
+    pub activate_sound: String,
+}
+
+impl Default for MenuStyle {
+    fn default() -> Self {
+        let normal = StyleLook::default();
+        Self {
+            name: String::new(),
+            font: String::new(),
+            font_size: 20.0,
+            padding: 4.0,
+            normal,
+            focused: StyleLook {
+                background: Color::new(0.35, 0.35, 0.45, 1.0),
+                outline: Color::WHITE,
+                ..normal
+            },
+            disabled: StyleLook {
+                text_color: Color::new(0.5, 0.5, 0.5, 1.0),
+                ..normal
+            },
+            pressed: StyleLook {
+                background: Color::new(0.15, 0.15, 0.2, 1.0),
+                outline: Color::WHITE,
+                ..normal
+            },
+            focus_sound: String::new(),
+            activate_sound: String::new(),
+        }
+    }
+}
+
+impl MenuStyle {
+    /// Creates a style with default looks.
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the look used in `state`.
+    pub fn look(&self, state: StyleState) -> &StyleLook {
+        match state {
+            StyleState::Normal => &self.normal,
+            StyleState::Focused => &self.focused,
+            StyleState::Disabled => &self.disabled,
+            StyleState::Pressed => &self.pressed,
+        }
+    }
+
+    /// Returns the look used in `state` for editing.
+    pub fn look_mut(&mut self, state: StyleState) -> &mut StyleLook {
+        match state {
+            StyleState::Normal => &mut self.normal,
+            StyleState::Focused => &mut self.focused,
+            StyleState::Disabled => &mut self.disabled,
+            StyleState::Pressed => &mut self.pressed,
+        }
+    }
+
+    /// Returns a copy of the style with an element's overrides applied.
+    pub fn with_overrides(&self, overrides: &StyleOverrides) -> MenuStyle {
+        let mut style = self.clone();
+        if let Some(font) = &overrides.font {
+            style.font = font.clone();
+        }
+        if let Some(font_size) = overrides.font_size {
+            style.font_size = font_size;
+        }
+        if let Some(padding) = overrides.padding {
+            style.padding = padding;
+        }
+        if let Some(text_color) = overrides.text_color {
+            style.normal.text_color = text_color;
+        }
+        if let Some(background) = overrides.background {
+            style.normal.background = background;
+        }
+        if let Some(sound) = &overrides.focus_sound {
+            style.focus_sound = sound.clone();
+        }
+        if let Some(sound) = &overrides.activate_sound {
+            style.activate_sound = sound.clone();
+        }
+        style
+    }
+
+    /// Returns `label` restyled with this style's font and the text colour of `state`.
+    pub fn label(&self, label: &LabelElement, state: StyleState) -> LabelElement {
+        LabelElement {
+            font_size: self.font_size,
+            color: self.look(state).text_color,
+            font: self.font.clone(),
+            ..label.clone()
+        }
+    }
+
+    /// Shrinks `rect` by the style's padding on every side.
+    pub fn content_rect(&self, rect: Rect) -> Rect {
+        let padding = self.padding.min(rect.w / 2.0).min(rect.h / 2.0).max(0.0);
+        Rect::new(
+            rect.x + padding,
+            rect.y + padding,
+            rect.w - padding * 2.0,
+            rect.h - padding * 2.0,
+        )
+    }
+}
+
+/// Style fields an element sets for itself instead of taking them from its style.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct StyleOverrides {
+    pub font: Option<String>,
+    pub font_size: Option<f32>,
+    pub padding: Option<f32>,
+    /// Text colour of the normal look.
+    pub text_color: Option<Color>,
+    /// Background colour of the normal look.
+    pub background: Option<Color>,
+    pub focus_sound: Option<String>,
+    pub activate_sound: Option<String>,
+}
+
+impl StyleOverrides {
+    /// Returns true when the element overrides nothing.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Per-game set of named menu styles, saved next to the menus folder.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct MenuTheme {
+    pub styles: Vec<MenuStyle>,
+    /// Inset HUDs keep clear of the screen edges, in design pixels.
+    pub hud_safe_area: Padding,
+}
+
+impl MenuTheme {
+    /// Loads the theme at `path`. A missing file gives an empty theme.
+    pub fn load_from_path(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(txt) => ron::from_str(&txt).unwrap_or_else(|e| {
+                onscreen_error!("Could not parse menu theme: {e}");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Returns the style called `name`.
+    pub fn style(&self, name: &str) -> Option<&MenuStyle> {
+        self.styles.iter().find(|style| style.name == name)
+    }
+
+    /// Returns the style of `element` with its overrides applied,
+    /// or `None` when the element has no style or it is missing from the theme.
+    pub fn resolve(&self, element: &MenuElement) -> Option<MenuStyle> {
+        if element.style.is_empty() {
+            return None;
+        }
+        self.style(&element.style)
+            .map(|style| style.with_overrides(&element.style_overrides))
+    }
+
+    /// Returns every panel sprite the styles draw, once per use.
+    pub fn sprites(&self) -> Vec<SpriteId> {
+        self.styles
+            .iter()
+            .flat_map(|style| {
+                StyleState::all()
+                    .iter()
+                    .map(|&state| style.look(state).sprite)
+            })
+            .filter(|sprite| sprite.0 != 0)
+            .collect()
+    }
+
+    /// Returns the part of `viewport` HUDs are laid out in, inside the safe-area inset.
+    pub fn hud_area(&self, viewport: Rect) -> Rect {
+        let scale = viewport.h / DESIGN_RESOLUTION_HEIGHT;
+        let inset = self.hud_safe_area;
+        Rect::new(
+            viewport.x + inset.left * scale,
+            viewport.y + inset.top * scale,
+            (viewport.w - inset.horizontal() * scale).max(0.0),
+            (viewport.h - inset.vertical() * scale).max(0.0),
+        )
+    }
+
+    /// Returns `base`, numbered if needed so no style already uses it.
+    pub fn unique_name(&self, base: &str) -> String {
+        if self.style(base).is_none() {
+            return base.to_string();
+        }
+        (2..)
+            .map(|n| format!("{base} {n}"))
+            .find(|name| self.style(name).is_none())
+            .unwrap_or_default()
+    }
+}
+
+/// Draws a style look over `rect`: its panel sprite or background, then its outline.
+/// `scale` sizes the borders of the panel sprite.
+pub fn draw_style_look<C: BishopContext>(
+    ctx: &mut C,
+    rect: Rect,
+    look: &StyleLook,
+    asset_manager: &mut AssetManager,
+    scale: f32,
+) {
+    if look.sprite.0 != 0 {
+        let texture = asset_manager.get_texture_from_id(ctx, look.sprite);
+        ctx.draw_nine_slice(texture, rect, &look.slice.scaled(scale), Color::WHITE);
+    } else {
+        ctx.draw_rectangle(rect.x, rect.y, rect.w, rect.h, look.background);
+    }
+    if look.outline.a > 0.0 {
+        ctx.draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, look.outline);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elements_take_their_style_and_keep_their_overrides() {
+        let mut title = MenuStyle::new("Title".to_string());
+        title.font = "serif".to_string();
+        title.font_size = 32.0;
+        let theme = MenuTheme {
+            styles: vec![title],
+            ..Default::default()
+        };
+
+        let mut element = MenuElement::label("title".to_string(), Rect::new(0.0, 0.0, 1.0, 1.0));
+        assert!(theme.resolve(&element).is_none());
+
+        element.style = "Title".to_string();
+        element.style_overrides.font_size = Some(40.0);
+        element.style_overrides.text_color = Some(Color::RED);
+        let style = theme.resolve(&element).unwrap();
+        assert_eq!(style.font, "serif");
+        assert_eq!(style.font_size, 40.0);
+        assert_eq!(style.look(StyleState::Normal).text_color, Color::RED);
+        assert_eq!(style.look(StyleState::Focused).text_color, Color::WHITE);
+
+        element.style = "Missing".to_string();
+        assert!(theme.resolve(&element).is_none());
+        assert_eq!(theme.unique_name("Title"), "Title 2");
+        assert_eq!(theme.unique_name("Body"), "Body");
+    }
+
+    #[test]
+    fn sprites_are_listed_once_per_use() {
+        let mut panel = MenuStyle::new("Panel".to_string());
+        panel.normal.sprite = SpriteId(3);
+        panel.focused.sprite = SpriteId(3);
+        let theme = MenuTheme {
+            styles: vec![panel],
+            ..Default::default()
+        };
+        assert_eq!(theme.sprites(), vec![SpriteId(3), SpriteId(3)]);
+
+        let image = |sprite| {
+            MenuElement::new(
+                MenuElementKind::Image(ImageElement {
+                    sprite: SpriteId(sprite),
+                    ..Default::default()
+                }),
+                Rect::new(0.0, 0.0, 0.1, 0.1),
+            )
+        };
+        let mut group = LayoutGroupElement {
+            background: Some(PanelBackground {
+                fill: PanelFill::Sprite {
+                    sprite: SpriteId(4),
+                    slice: NineSlice::default(),
+                },
+                opacity: 1.0,
+            }),
+            ..Default::default()
+        };
+        group.children.push(LayoutChild {
+            element: image(5),
+            managed: true,
+        });
+        let mut template = MenuTemplate::new("inventory".to_string());
+        template.animation.cursor_sprite = SpriteId(6);
+        template.elements = vec![
+            image(0),
+            MenuElement::new(
+                MenuElementKind::LayoutGroup(group),
+                Rect::new(0.0, 0.0, 1.0, 1.0),
+            ),
+        ];
+        assert_eq!(
+            template.sprites(),
+            vec![SpriteId(6), SpriteId(4), SpriteId(5)]
+        );
+    }
+
+    #[test]
+    fn hud_area_keeps_the_safe_area_clear_at_any_size() {
+        let theme = MenuTheme {
+            hud_safe_area: Padding::new(54.0, 96.0, 0.0, 96.0),
+            ..Default::default()
+        };
+        let half = Rect::new(10.0, 0.0, 960.0, 540.0);
+        assert_eq!(theme.hud_area(half), Rect::new(58.0, 27.0, 864.0, 513.0));
+
+        let none = MenuTheme::default();
+        assert_eq!(none.hud_area(half), half);
+    }
+}
@@ -1,6 +1,8 @@
 pub mod elements;
 mod input_binding;
 pub mod layout;
+mod menu_animation;
+mod menu_binding;
 mod menu_builder;
 mod menu_coordinates;
 mod menu_focus;
@@ -8,12 +10,15 @@ mod menu_handler;
 mod menu_manager;
 mod menu_navigation;
 mod menu_template;
+mod menu_theme;
 pub(crate) mod runtime;
 
 pub use bishop::input::*;
 pub use elements::*;
 pub use input_binding::*;
 pub use layout::*;
+pub use menu_animation::*;
+pub use menu_binding::*;
 pub use menu_builder::*;
 pub use menu_coordinates::*;
 pub use menu_focus::*;
@@ -21,3 +26,4 @@ pub use menu_handler::*;
 pub use menu_manager::*;
 pub use menu_navigation::*;
 pub use menu_template::*;
+pub use menu_theme::*;
@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+
+/// Characters cycled through when entering text without a keyboard.
+const ENTRY_CHARACTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789 ";
+
+/// Items and selection of a scroll list.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ListState {
+    pub(crate) items: Vec<String>,
+    pub(crate) selected: usize,
+    /// Index of the first visible row.
+    pub(crate) scroll: usize,
+}
+
+impl ListState {
+    /// Replaces the items, keeping the selection in range.
+    fn set_items(&mut self, items: Vec<String>) {
+        self.selected = self.selected.min(items.len().saturating_sub(1));
+        self.scroll = self.scroll.min(self.selected);
+        self.items = items;
+    }
+
+    /// Scrolls just enough to keep the selection within `visible_rows`.
+    fn reveal_selected(&mut self, visible_rows: usize) {
+        let visible_rows = visible_rows.max(1);
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + visible_rows {
+            self.scroll = self.selected + 1 - visible_rows;
+        }
+    }
+}
+
+/// Text input being edited.
+#[derive(Debug, Clone, PartialEq)]
+struct TextEdit {
+    key: String,
+    text: String,
+    max_length: usize,
+}
+
+/// Runtime values of text inputs and scroll lists, which are not player settings.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MenuControlState {
+    texts: HashMap<String, String>,
+    lists: HashMap<String, ListState>,
+    /// Lists filled from a menu's bound values, keyed by menu id. They take
+    /// precedence over `lists` within that menu.
+    bound_lists: HashMap<String, HashMap<String, ListState>>,
+    editing: Option<TextEdit>,
+}
+
+impl MenuControlState {
+    /// Returns the text shown by the input with `key`, including unsubmitted edits.
+    pub(crate) fn text(&self, key: &str) -> &str {
+        match &self.editing {
+            Some(edit) if edit.key == key => &edit.text,
+            _ => self.texts.get(key).map(String::as_str).unwrap_or_default(),
+        }
+    }
+
+    /// Sets the text of the input with `key`.
+    pub(crate) fn set_text(&mut self, key: &str, text: String) {
+        self.texts.insert(key.to_string(), text);
+    }
+
+    /// Returns true while the input with `key` is being edited.
+    pub(crate) fn is_editing_key(&self, key: &str) -> bool {
+        self.editing.as_ref().is_some_and(|edit| edit.key == key)
+    }
+
+    /// Returns true while any input is being edited.
+    pub(crate) fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    /// Starts editing the input with `key`, beginning from its current text.
+    pub(crate) fn begin_edit(&mut self, key: &str, max_length: usize) {
+        let text = self.text(key).chars().take(max_length).collect();
+        self.editing = Some(TextEdit {
+            key: key.to_string(),
+            text,
+            max_length,
+        });
+    }
+
+    /// Appends a typed character, ignoring control characters and overflow.
+    pub(crate) fn type_char(&mut self, c: char) {
+        if let Some(edit) = &mut self.editing
+            && !c.is_control()
+            && edit.text.chars().count() < edit.max_length
+        {
+            edit.text.push(c);
+        }
+    }
+
+    /// Removes the last character.
+    pub(crate) fn delete_char(&mut self) {
+        if let Some(edit) = &mut self.editing {
+            edit.text.pop();
+        }
+    }
+
+    /// Steps the last character through the entry characters, appending one if empty.
+    pub(crate) fn cycle_last_char(&mut self, offset: isize) {
+        let Some(edit) = &mut self.editing else {
+            return;
+        };
+        let characters: Vec<char> = ENTRY_CHARACTERS.chars().collect();
+        let Some(last) = edit.text.pop() else {
+            if edit.max_length > 0 {
+                edit.text.push(characters[0]);
+            }
+            return;
+        };
+        let index = characters.iter().position(|&c| c == last).unwrap_or(0) as isize;
+        let next = (index + offset).rem_euclid(characters.len() as isize) as usize;
+        edit.text.push(characters[next]);
+    }
+
+    /// Adds a new character to step through.
+    pub(crate) fn append_entry_char(&mut self) {
+        self.type_char(ENTRY_CHARACTERS.chars().next().unwrap_or('A'));
+    }
+
+    /// Stores the edited text. Returns the key and submitted text.
+    pub(crate) fn submit_edit(&mut self) -> Option<(String, String)> {
+        let edit = self.editing.take()?;
+        self.texts.insert(edit.key.clone(), edit.text.clone());
+        Some((edit.key, edit.text))
+    }
+
+    /// Drops the edit, keeping the previous text.
+    pub(crate) fn cancel_edit(&mut self) {
+        self.editing = None;
+    }
+
+    /// Returns the list with `key` as shown in `menu_id`.
+    pub(crate) fn list(&self, menu_id: &str, key: &str) -> Option<&ListState> {
+        self.bound_lists
+            .get(menu_id)
+            .and_then(|lists| lists.get(key))
+            .or_else(|| self.lists.get(key))
+    }
+
+    fn list_mut(&mut self, menu_id: &str, key: &str) -> Option<&mut ListState> {
+        let bound = self
+            .bound_lists
+            .get(menu_id)
+            .is_some_and(|lists| lists.contains_key(key));
+        if bound {
+            self.bound_lists.get_mut(menu_id)?.get_mut(key)
+        } else {
+            self.lists.get_mut(key)
+        }
+    }
+
+    /// Replaces the items of the list with `key` in every menu without its own items.
+    pub(crate) fn set_list_items(&mut self, key: &str, items: Vec<String>) {
+        self.lists
+            .entry(key.to_string())
+            .or_default()
+            .set_items(items);
+    }
+
+    /// Replaces the items of the list with `key` in `menu_id` only.
+    pub(crate) fn set_bound_list_items(&mut self, menu_id: &str, key: &str, items: Vec<String>) {
+        self.bound_lists
+            .entry(menu_id.to_string())
+            .or_default()
+            .entry(key.to_string())
+            .or_default()
+            .set_items(items);
+    }
+
+    /// Drops the items `menu_id` had for the list with `key`.
+    pub(crate) fn clear_bound_list(&mut self, menu_id: &str, key: &str) {
+        if let Some(lists) = self.bound_lists.get_mut(menu_id) {
+            lists.remove(key);
+        }
+    }
+
+    /// Moves the selection by `offset`. Returns false when it is already at that end.
+    pub(crate) fn move_selection(
+        &mut self,
+        menu_id: &str,
+        key: &str,
+        offset: isize,
+        visible_rows: usize,
+    ) -> bool {
+        let Some(list) = self.list_mut(menu_id, key) else {
+            return false;
+        };
+        let next = list.selected as isize + offset;
+        if next < 0 || next as usize >= list.items.len() {
+            return false;
+        }
+        list.selected = next as usize;
+        list.reveal_selected(visible_rows);
+        true
+    }
+
+    /// Selects the item at `index`. Returns false if there is none.
+    pub(crate) fn select(
+        &mut self,
+        menu_id: &str,
+        key: &str,
+        index: usize,
+        visible_rows: usize,
+    ) -> bool {
+        let Some(list) = self.list_mut(menu_id, key) else {
+            return false;
+        };
+        if index >= list.items.len() {
+            return false;
+        }
+        list.selected = index;
+        list.reveal_selected(visible_rows);
+        true
+    }
+
+    /// Scrolls the list by `rows` without moving the selection.
+    pub(crate) fn scroll(&mut self, menu_id: &str, key: &str, rows: isize, visible_rows: usize) {
+        if let Some(list) = self.list_mut(menu_id, key) {
+            let max_scroll = list.items.len().saturating_sub(visible_rows.max(1));
+            list.scroll = (list.scroll as isize + rows).clamp(0, max_scroll as isize) as usize;
+        }
+    }
+
+    /// Returns the selected index and item of the list with `key` in `menu_id`.
+    pub(crate) fn selected_item(&self, menu_id: &str, key: &str) -> Option<(usize, String)> {
+        let list = self.list(menu_id, key)?;
+        let item = list.items.get(list.selected)?;
+        Some((list.selected, item.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edits_respect_the_length_limit_and_only_apply_on_submit() {
+        let mut state = MenuControlState::default();
+        state.set_text("name", "Bo".to_string());
+        state.begin_edit("name", 3);
+        for c in ['b', '\u{8}', 'x', 'y'] {
+            state.type_char(c);
+        }
+        assert_eq!(state.text("name"), "Bob");
+
+        state.cancel_edit();
+        assert_eq!(state.text("name"), "Bo");
+
+        state.begin_edit("name", 3);
+        state.delete_char();
+        state.cycle_last_char(1);
+        state.append_entry_char();
+        state.cycle_last_char(-1);
+        assert_eq!(
+            state.submit_edit(),
+            Some(("name".to_string(), "C ".to_string()))
+        );
+        assert!(!state.is_editing());
+    }
+
+    #[test]
+    fn selection_stops_at_the_ends_and_scrolls_into_view() {
+        let mut state = MenuControlState::default();
+        let items = (0..5).map(|i| format!("item {i}")).collect();
+        state.set_list_items("saves", items);
+
+        assert!(!state.move_selection("load", "saves", -1, 2));
+        assert!(state.move_selection("load", "saves", 1, 2));
+        assert!(state.move_selection("load", "saves", 1, 2));
+        assert_eq!(
+            state.list("load", "saves").map(|l| (l.selected, l.scroll)),
+            Some((2, 1))
+        );
+
+        state.set_list_items("saves", vec!["only".to_string()]);
+        assert_eq!(
+            state.selected_item("load", "saves"),
+            Some((0, "only".to_string()))
+        );
+    }
+
+    #[test]
+    fn bound_lists_only_replace_items_in_their_menu() {
+        let mut state = MenuControlState::default();
+        state.set_list_items("items", vec!["global".to_string()]);
+        state.set_bound_list_items("shop", "items", vec!["a".to_string(), "b".to_string()]);
+
+        assert!(state.move_selection("shop", "items", 1, 2));
+        assert_eq!(
+            state.selected_item("shop", "items"),
+            Some((1, "b".to_string()))
+        );
+        assert_eq!(
+            state.selected_item("inventory", "items"),
+            Some((0, "global".to_string()))
+        );
+
+        state.clear_bound_list("shop", "items");
+        assert_eq!(
+            state.selected_item("shop", "items"),
+            Some((0, "global".to_string()))
+        );
+    }
+}
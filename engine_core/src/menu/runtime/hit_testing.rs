@@ -1,3 +1,4 @@
+use crate::menu::runtime::MenuPose;
 use crate::menu::*;
 use bishop::prelude::*;
 
@@ -8,40 +9,53 @@ struct FocusTarget {
     rect: Rect,
 }
 
-/// Resolves the focus target under the given mouse position.
+/// Resolves the focus target under the given mouse position, with the
+/// elements moved by `pose` as they are drawn.
 pub(crate) fn focus_target_at(
     template: &MenuTemplate,
     viewport: Rect,
+    pose: MenuPose,
     mouse: Vec2,
 ) -> Option<MenuFocus> {
-    let canvas_origin = Vec2::new(viewport.x, viewport.y);
-    let canvas_size = Vec2::new(viewport.w, viewport.h);
-
-    collect_focus_targets(template, canvas_origin, canvas_size)
+    collect_focus_targets(template, viewport, pose)
         .into_iter()
         .rev()
         .find(|target| target.rect.contains(mouse))
         .map(|target| target.focus)
 }
 
+/// Resolves the screen rect of the element holding `focus`, moved by `pose`.
+pub(crate) fn focused_rect(
+    template: &MenuTemplate,
+    viewport: Rect,
+    pose: MenuPose,
+    focus: &MenuFocus,
+) -> Option<Rect> {
+    collect_focus_targets(template, viewport, pose)
+        .into_iter()
+        .find(|target| &target.focus == focus)
+        .map(|target| target.rect)
+}
+
 fn collect_focus_targets(
     template: &MenuTemplate,
-    canvas_origin: Vec2,
-    canvas_size: Vec2,
+    viewport: Rect,
+    pose: MenuPose,
 ) -> Vec<FocusTarget> {
     let mut targets = Vec::new();
 
-    for element_index in template.sorted_element_indices() {
+    for (order, element_index) in template.sorted_element_indices().into_iter().enumerate() {
         let element = &template.elements[element_index];
         if !element.visible {
             continue;
         }
 
+        let screen_rect = pose.element_rect(&template.animation, element, order, viewport);
         match &element.kind {
-            MenuElementKind::Button(_) | MenuElementKind::Slider(_) if element.enabled => {
+            kind if kind.is_focusable() && element.enabled => {
                 targets.push(FocusTarget {
                     focus: MenuFocus::new(element_index),
-                    rect: normalized_rect_to_screen(element.rect, canvas_origin, canvas_size),
+                    rect: screen_rect,
                 });
             }
             MenuElementKind::LayoutGroup(group) => {
@@ -53,18 +67,13 @@ fn collect_focus_targets(
                         continue;
                     }
 
-                    let is_focusable = matches!(
-                        child.element.kind,
-                        MenuElementKind::Button(_) | MenuElementKind::Slider(_)
-                    );
-
-                    if is_focusable && child.element.enabled {
+                    if child.element.kind.is_focusable() && child.element.enabled {
                         targets.push(FocusTarget {
                             focus: MenuFocus {
                                 node: element_index,
                                 child: Some(child_focus_index),
                             },
-                            rect: normalized_rect_to_screen(*rect, canvas_origin, canvas_size),
+                            rect: child_screen_rect(*rect, element.rect, screen_rect),
                         });
                         child_focus_index += 1;
                     }
@@ -114,6 +123,13 @@ mod tests {
                         ),
                         managed: true,
                     },
+                    LayoutChild {
+                        element: MenuElement::new(
+                            MenuElementKind::Image(ImageElement::default()),
+                            Rect::new(0.0, 0.0, 0.0, 0.0),
+                        ),
+                        managed: true,
+                    },
                     LayoutChild {
                         element: MenuElement::slider(
                             "volume".to_string(),
@@ -126,15 +142,23 @@ mod tests {
                         ),
                         managed: true,
                     },
+                    LayoutChild {
+                        element: MenuElement::new(
+                            MenuElementKind::Toggle(ToggleElement::default()),
+                            Rect::new(0.0, 0.0, 0.0, 0.0),
+                        ),
+                        managed: true,
+                    },
                 ],
                 ..Default::default()
             },
             Rect::new(0.0, 0.3, 1.0, 0.6),
         ));
 
-        let targets = collect_focus_targets(&template, Vec2::ZERO, Vec2::new(1000.0, 500.0));
+        let viewport = Rect::new(0.0, 0.0, 1000.0, 500.0);
+        let targets = collect_focus_targets(&template, viewport, MenuPose::AT_REST);
 
-        assert_eq!(targets.len(), 3);
+        assert_eq!(targets.len(), 4);
         assert_eq!(targets[0].focus, MenuFocus::new(1));
         assert_eq!(
             targets[1].focus,
@@ -150,6 +174,16 @@ mod tests {
                 child: Some(1),
             }
         );
+
+        let toggle_focus = MenuFocus {
+            node: 2,
+            child: Some(2),
+        };
+        assert_eq!(targets[3].focus, toggle_focus);
+        assert_eq!(
+            focused_rect(&template, viewport, MenuPose::AT_REST, &toggle_focus),
+            Some(targets[3].rect)
+        );
     }
 
     #[test]
@@ -173,14 +207,40 @@ mod tests {
             background: MenuBackground::None,
             elements: vec![back, front],
             mode: MenuMode::Paused,
+            animation: MenuAnimation::default(),
         };
 
         let focus = focus_target_at(
             &template,
             Rect::new(0.0, 0.0, 1000.0, 500.0),
+            MenuPose::AT_REST,
             Vec2::new(200.0, 100.0),
         );
 
         assert_eq!(focus, Some(MenuFocus::new(1)));
     }
+
+    #[test]
+    fn targets_follow_the_open_transition() {
+        let mut template = MenuTemplate::new("slide".to_string());
+        template.elements.push(MenuElement::button(
+            "play".to_string(),
+            MenuAction::Resume,
+            Rect::new(0.0, 0.0, 0.2, 0.2),
+        ));
+        let viewport = Rect::new(0.0, 0.0, 1000.0, 500.0);
+        let halfway = MenuPose {
+            transition: MenuTransitionStyle::SlideFromBottom,
+            shown: 0.5,
+            age: 0.0,
+        };
+
+        let rest = focused_rect(&template, viewport, MenuPose::AT_REST, &MenuFocus::new(0));
+        let moving = focused_rect(&template, viewport, halfway, &MenuFocus::new(0));
+        assert_ne!(rest, moving);
+        assert_eq!(
+            focus_target_at(&template, viewport, halfway, rest.unwrap().center()),
+            None
+        );
+    }
 }
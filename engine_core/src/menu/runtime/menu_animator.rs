@@ -0,0 +1,209 @@
+use crate::menu::*;
+use bishop::prelude::*;
+
+/// How quickly the focus cursor catches up with the focused element, per second.
+const CURSOR_FOLLOW_RATE: f32 = 18.0;
+
+/// A closing transition that runs its action once it finishes.
+#[derive(Debug, Clone)]
+struct Closing {
+    started: f64,
+    transition: MenuTransition,
+    action: MenuAction,
+}
+
+/// Clock and timestamps driving the active menu's animations.
+#[derive(Debug, Default)]
+pub(crate) struct MenuAnimator {
+    /// Latest context time.
+    time: f64,
+    /// Time of the previous rendered frame.
+    last_frame: Option<f64>,
+    /// When the active menu opened.
+    opened_at: f64,
+    /// Transition the active menu opened with.
+    opening: MenuTransition,
+    closing: Option<Closing>,
+    /// The control pressed last and when.
+    pressed: Option<(MenuFocus, f64)>,
+    /// Screen position of the focus cursor.
+    cursor: Option<Vec2>,
+}
+
+/// How far the active menu's open or close transition has moved it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MenuPose {
+    /// Style of the open or close transition in progress.
+    pub transition: MenuTransitionStyle,
+    /// Share of the menu shown by that transition.
+    pub shown: f32,
+    /// Seconds since the menu opened.
+    pub age: f32,
+}
+
+impl MenuPose {
+    /// Pose of a menu whose transitions have all finished.
+    pub const AT_REST: MenuPose = MenuPose {
+        transition: MenuTransitionStyle::None,
+        shown: 1.0,
+        age: f32::MAX,
+    };
+
+    /// Moves `viewport` and an element's anchored rect the way rendering does.
+    /// `order` is the element's place in z order, which staggers its entrance.
+    pub fn element_rect(
+        &self,
+        animation: &MenuAnimation,
+        element: &MenuElement,
+        order: usize,
+        viewport: Rect,
+    ) -> Rect {
+        let (moved, _) = self.transition.apply(viewport, self.shown);
+        let anchored = resolve_anchored_rect(element.rect, element.anchor, moved);
+        let shown = element
+            .enter
+            .entering(self.age - animation.enter_delay(order));
+        element.enter.style.apply(anchored, shown).0
+    }
+}
+
+/// Animation state of the active menu for one frame.
+pub(crate) struct MenuMotion<'a> {
+    pub animation: &'a MenuAnimation,
+    /// Style of the open or close transition in progress.
+    pub transition: MenuTransitionStyle,
+    /// Share of the menu shown by that transition.
+    pub shown: f32,
+    /// Seconds since the menu opened.
+    pub age: f32,
+    pub time: f32,
+    /// The control pressed last and seconds since.
+    pub pressed: Option<(&'a MenuFocus, f32)>,
+    /// Where to draw the focus cursor.
+    pub cursor: Option<Vec2>,
+}
+
+impl MenuMotion<'_> {
+    /// Motion of a menu sitting still, used for HUDs.
+    pub fn at_rest(animation: &MenuAnimation, time: f32) -> MenuMotion<'_> {
+        let pose = MenuPose::AT_REST;
+        MenuMotion {
+            animation,
+            transition: pose.transition,
+            shown: pose.shown,
+            age: pose.age,
+            time,
+            pressed: None,
+            cursor: None,
+        }
+    }
+}
+
+impl MenuAnimator {
+    /// Advances the clock to the context time.
+    pub fn set_time(&mut self, time: f64) {
+        self.time = time;
+    }
+
+    /// Starts the enter animations of a menu that just became active.
+    pub fn opened(&mut self, transition: MenuTransition) {
+        self.opened_at = self.time;
+        self.opening = transition;
+        self.closing = None;
+        self.pressed = None;
+    }
+
+    /// Starts closing the active menu, running `action` once the transition ends.
+    pub fn begin_closing(&mut self, transition: MenuTransition, action: MenuAction) {
+        self.closing = Some(Closing {
+            started: self.time,
+            transition,
+            action,
+        });
+    }
+
+    /// Returns true while the active menu plays its close transition.
+    pub fn is_closing(&self) -> bool {
+        self.closing.is_some()
+    }
+
+    /// Returns the pending action once the close transition has finished.
+    pub fn finished_closing(&mut self) -> Option<MenuAction> {
+        let closing = self.closing.as_ref()?;
+        if closing
+            .transition
+            .is_finished((self.time - closing.started) as f32)
+        {
+            return self.closing.take().map(|closing| closing.action);
+        }
+        None
+    }
+
+    /// Drops any close in progress and returns its action.
+    pub fn take_closing(&mut self) -> Option<MenuAction> {
+        self.closing.take().map(|closing| closing.action)
+    }
+
+    /// Records that the control at `focus` was activated.
+    pub fn press(&mut self, focus: &MenuFocus) {
+        self.pressed = Some((focus.clone(), self.time));
+    }
+
+    /// Forgets the cursor so it appears straight on the next focused element.
+    pub fn reset_cursor(&mut self) {
+        self.cursor = None;
+    }
+
+    /// Returns where the open or close transition has moved the active menu.
+    pub fn pose(&self) -> MenuPose {
+        let age = (self.time - self.opened_at) as f32;
+        let (transition, shown) = match &self.closing {
+            Some(closing) => (
+                closing.transition.style,
+                closing
+                    .transition
+                    .exiting((self.time - closing.started) as f32),
+            ),
+            None => (self.opening.style, self.opening.entering(age)),
+        };
+        MenuPose {
+            transition,
+            shown,
+            age,
+        }
+    }
+
+    /// Glides the focus cursor toward `target` and returns the frame's motion.
+    pub fn frame<'a>(
+        &'a mut self,
+        animation: &'a MenuAnimation,
+        target: Option<Vec2>,
+    ) -> MenuMotion<'a> {
+        let dt = self
+            .last_frame
+            .map_or(0.0, |last| (self.time - last).max(0.0) as f32);
+        self.last_frame = Some(self.time);
+
+        self.cursor = target.map(|target| match self.cursor {
+            Some(cursor) => {
+                let follow = 1.0 - (-CURSOR_FOLLOW_RATE * dt).exp();
+                cursor + (target - cursor) * follow
+            }
+            None => target,
+        });
+
+        let pose = self.pose();
+        MenuMotion {
+            animation,
+            transition: pose.transition,
+            shown: pose.shown,
+            age: pose.age,
+            time: self.time as f32,
+            pressed: self
+                .pressed
+                .as_ref()
+                .map(|(focus, at)| (focus, (self.time - at) as f32)),
+            cursor: self.cursor,
+        }
+    }
+}
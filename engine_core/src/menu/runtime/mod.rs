@@ -1,9 +1,13 @@
+mod control_state;
 mod defaults;
 mod hit_testing;
+mod menu_animator;
 mod render;
 mod slider_runtime;
 
+pub(crate) use control_state::MenuControlState;
 pub(crate) use defaults::default_menus;
-pub(crate) use hit_testing::focus_target_at;
-pub(crate) use render::render_active_menu;
+pub(crate) use hit_testing::{focus_target_at, focused_rect};
+pub(crate) use menu_animator::{MenuAnimator, MenuMotion, MenuPose};
+pub(crate) use render::{MenuState, MenuTextResources, render_active_menu, render_hud};
 pub(crate) use slider_runtime::{SliderRepeatState, adjust_slider_value};
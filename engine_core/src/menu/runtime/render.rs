@@ -1,9 +1,28 @@
+use crate::assets::asset_manager::AssetManager;
+use crate::constants::DESIGN_RESOLUTION_HEIGHT;
+use crate::menu::runtime::{MenuControlState, MenuMotion};
 use crate::menu::*;
-use crate::text::TextManager;
+use crate::storage::player_settings::*;
+use crate::text::{RichTextCache, TextManager};
 use bishop::prelude::*;
-use std::collections::HashMap;
 use widgets::*;
 
+/// Text and asset sources used to draw menu labels.
+pub(crate) struct MenuTextResources<'a> {
+    pub text_manager: &'a TextManager,
+    pub markup_cache: &'a mut RichTextCache,
+    pub bound_text_cache: &'a mut BoundTextCache,
+    pub asset_manager: &'a mut AssetManager,
+}
+
+/// Runtime control state, bound values, animation and theme of the menu being drawn.
+pub(crate) struct MenuState<'a> {
+    pub controls: &'a MenuControlState,
+    pub values: Option<&'a MenuValues>,
+    pub motion: MenuMotion<'a>,
+    pub theme: &'a MenuTheme,
+}
+
 /// Renders the currently active menu and returns a triggered button action.
 pub(crate) fn render_active_menu<C: BishopContext>(
     ctx: &mut C,
@@ -11,106 +30,344 @@ pub(crate) fn render_active_menu<C: BishopContext>(
     menu_id: &str,
     viewport: Rect,
     focus: &MenuFocus,
-    slider_values: &mut HashMap<String, f32>,
-    text_manager: &TextManager,
+    state: MenuState<'_>,
+    resources: MenuTextResources<'_>,
 ) -> Option<MenuAction> {
     widgets_frame_start(ctx);
 
+    // The backdrop fades with the transition while the content moves
+    let shown = state.motion.shown;
+    let (moved, opacity) = state.motion.transition.apply(viewport, shown);
+    ctx.push_opacity(shown);
+    template.render_background(ctx, viewport);
+    ctx.pop_opacity();
+
     let text_id = format!("ui/{}", menu_id);
     let mut triggered_action = None;
     let mut env = RenderEnv {
+        menu_id,
         text_id: &text_id,
-        text_manager,
-        canvas_origin: Vec2::new(viewport.x, viewport.y),
-        canvas_size: Vec2::new(viewport.w, viewport.h),
+        text_manager: resources.text_manager,
+        viewport: moved,
         focus,
-        slider_values,
+        interactive: true,
+        controls: state.controls,
+        values: state.values,
+        motion: state.motion,
+        theme: state.theme,
+        markup_cache: resources.markup_cache,
+        bound_text_cache: resources.bound_text_cache,
+        asset_manager: resources.asset_manager,
         triggered_action: &mut triggered_action,
     };
 
-    template.render_background(ctx, viewport);
+    ctx.push_opacity(opacity);
+    render_elements(ctx, template, &mut env);
+    render_cursor(ctx, &mut env);
+    ctx.pop_opacity();
 
-    for element_index in template.sorted_element_indices() {
+    widgets_frame_end(ctx);
+    triggered_action
+}
+
+/// Renders a HUD over gameplay. HUDs draw no background, show no focus and ignore input.
+pub(crate) fn render_hud<C: BishopContext>(
+    ctx: &mut C,
+    template: &MenuTemplate,
+    viewport: Rect,
+    state: MenuState<'_>,
+    resources: MenuTextResources<'_>,
+) {
+    let text_id = format!("ui/{}", template.id);
+    let mut triggered_action = None;
+    let mut env = RenderEnv {
+        menu_id: &template.id,
+        text_id: &text_id,
+        text_manager: resources.text_manager,
+        viewport,
+        focus: &MenuFocus::new(0),
+        interactive: false,
+        controls: state.controls,
+        values: state.values,
+        motion: state.motion,
+        theme: state.theme,
+        markup_cache: resources.markup_cache,
+        bound_text_cache: resources.bound_text_cache,
+        asset_manager: resources.asset_manager,
+        triggered_action: &mut triggered_action,
+    };
+    render_elements(ctx, template, &mut env);
+}
+
+/// Draws the visible elements of a template in z order.
+fn render_elements<C: BishopContext>(
+    ctx: &mut C,
+    template: &MenuTemplate,
+    env: &mut RenderEnv<'_>,
+) {
+    for (order, element_index) in template.sorted_element_indices().into_iter().enumerate() {
         let element = &template.elements[element_index];
         if !element.visible {
             continue;
         }
 
-        render_element(ctx, template, element_index, element, &mut env);
+        let delay = env.motion.animation.enter_delay(order);
+        let shown = element.enter.entering(env.motion.age - delay);
+        render_element(ctx, template, element_index, element, shown, env);
     }
+}
 
-    widgets_frame_end(ctx);
-    triggered_action
+/// Draws the cursor sprite beside the focused element.
+fn render_cursor<C: BishopContext>(ctx: &mut C, env: &mut RenderEnv<'_>) {
+    let sprite = env.motion.animation.cursor_sprite;
+    let Some(anchor) = env.motion.cursor else {
+        return;
+    };
+    if sprite.0 == 0 {
+        return;
+    }
+    let scale = env.ui_scale();
+    let texture = env.asset_manager.get_texture_from_id(ctx, sprite);
+    let size = vec2(texture.width(), texture.height()) * scale;
+    ctx.draw_texture_ex(
+        texture,
+        anchor.x - size.x - WIDGET_PADDING * scale,
+        anchor.y - size.y / 2.0,
+        Color::WHITE,
+        DrawTextureParams {
+            dest_size: Some(size),
+            ..Default::default()
+        },
+    );
 }
 
 struct RenderEnv<'a> {
+    menu_id: &'a str,
     text_id: &'a str,
     text_manager: &'a TextManager,
-    canvas_origin: Vec2,
-    canvas_size: Vec2,
+    viewport: Rect,
     focus: &'a MenuFocus,
-    slider_values: &'a mut HashMap<String, f32>,
+    /// False for HUDs, which never take focus or clicks.
+    interactive: bool,
+    controls: &'a MenuControlState,
+    values: Option<&'a MenuValues>,
+    motion: MenuMotion<'a>,
+    theme: &'a MenuTheme,
+    markup_cache: &'a mut RichTextCache,
+    bound_text_cache: &'a mut BoundTextCache,
+    asset_manager: &'a mut AssetManager,
     triggered_action: &'a mut Option<MenuAction>,
 }
 
+impl RenderEnv<'_> {
+    /// Draws label text, parsing its markup through the cache.
+    fn render_label<C: BishopContext>(
+        &mut self,
+        ctx: &mut C,
+        label: &LabelElement,
+        rect: Rect,
+        display_text: &str,
+    ) {
+        let markup = self.markup_cache.get(display_text);
+        MenuTemplate::render_label(ctx, label, rect, &markup, self.asset_manager);
+    }
+
+    /// Applies the focus pulse and press squash to the element at `focus`.
+    fn feedback_rect(&self, rect: Rect, focus: &MenuFocus, is_focused: bool) -> Rect {
+        let animation = self.motion.animation;
+        let mut scale = 1.0;
+        if is_focused {
+            scale *= animation.focus_scale(self.motion.time);
+        }
+        if let Some((pressed, elapsed)) = self.motion.pressed
+            && pressed == focus
+        {
+            scale *= animation.press_scale(elapsed);
+        }
+        scale_rect(rect, scale)
+    }
+
+    /// Screen pixels per design pixel of the menu being drawn.
+    fn ui_scale(&self) -> f32 {
+        self.viewport.h / DESIGN_RESOLUTION_HEIGHT
+    }
+
+    /// Returns true while the element at `focus` shows its press.
+    fn is_pressed(&self, focus: &MenuFocus) -> bool {
+        self.motion
+            .pressed
+            .is_some_and(|(pressed, elapsed)| pressed == focus && elapsed < PRESS_DURATION)
+    }
+
+    /// Resolves a text key for the active menu and fills in its bound values.
+    fn resolve(&mut self, key: &str) -> String {
+        let text = self.text_manager.resolve_ui_text(self.text_id, key);
+        match self.values {
+            Some(values) => {
+                self.bound_text_cache
+                    .get(&text, self.text_manager.get_language(), values)
+            }
+            None => text,
+        }
+    }
+}
+
 fn render_element<C: BishopContext>(
     ctx: &mut C,
     template: &MenuTemplate,
     element_index: usize,
     element: &MenuElement,
+    shown: f32,
     env: &mut RenderEnv<'_>,
 ) {
+    let anchored = resolve_anchored_rect(element.rect, element.anchor, env.viewport);
+    let (screen_rect, opacity) = element.enter.style.apply(anchored, shown);
+    ctx.push_opacity(opacity);
+
+    if let MenuElementKind::LayoutGroup(group) = &element.kind {
+        render_layout_group(
+            ctx,
+            template,
+            group,
+            element_index,
+            element,
+            screen_rect,
+            env,
+        );
+    } else {
+        let focus = MenuFocus::new(element_index);
+        let is_focused =
+            env.interactive && env.focus.node == element_index && env.focus.child.is_none();
+        let screen_rect = env.feedback_rect(screen_rect, &focus, is_focused);
+        let is_pressed = env.is_pressed(&focus);
+        render_leaf(ctx, element, screen_rect, env, is_focused, is_pressed);
+    }
+
+    ctx.pop_opacity();
+}
+
+/// Theme style and state an element is drawn with this frame.
+struct ElementLook {
+    style: Option<MenuStyle>,
+    state: StyleState,
+    is_focused: bool,
+}
+
+impl ElementLook {
+    /// Returns `base` restyled by the element's style, if it has one.
+    fn label(&self, base: LabelElement) -> LabelElement {
+        match &self.style {
+            Some(style) => style.label(&base, self.state),
+            None => base,
+        }
+    }
+
+    /// Returns the look of the current state when the element is styled.
+    fn style_look(&self) -> Option<&StyleLook> {
+        self.style.as_ref().map(|style| style.look(self.state))
+    }
+}
+
+/// Draws any element other than a layout group at `screen_rect`.
+fn render_leaf<C: BishopContext>(
+    ctx: &mut C,
+    element: &MenuElement,
+    screen_rect: Rect,
+    env: &mut RenderEnv<'_>,
+    is_focused: bool,
+    is_pressed: bool,
+) {
+    let mouse: Vec2 = ctx.mouse_position().into();
+    let hovered = env.interactive && element.kind.is_focusable() && screen_rect.contains(mouse);
+    let held = hovered && ctx.is_mouse_button_down(MouseButton::Left);
+    let state = if !element.enabled {
+        StyleState::Disabled
+    } else if is_pressed || held {
+        StyleState::Pressed
+    } else if is_focused || hovered {
+        StyleState::Focused
+    } else {
+        StyleState::Normal
+    };
+    let look = ElementLook {
+        style: env.theme.resolve(element),
+        state,
+        is_focused,
+    };
+
     match &element.kind {
         MenuElementKind::Label(label) => {
-            let display_text = env
-                .text_manager
-                .resolve_ui_text(env.text_id, &label.text_key);
-            let screen_rect =
-                normalized_rect_to_screen(element.rect, env.canvas_origin, env.canvas_size);
-            MenuTemplate::render_label(ctx, label, screen_rect, &display_text);
+            let display_text = env.resolve(&label.text_key);
+            let rect = match &look.style {
+                Some(style) => style.content_rect(screen_rect),
+                None => screen_rect,
+            };
+            env.render_label(ctx, &look.label(label.clone()), rect, &display_text);
         }
         MenuElementKind::Button(button) => {
-            let display_text = env
-                .text_manager
-                .resolve_ui_text(env.text_id, &button.text_key);
-            let is_focused = env.focus.node == element_index && env.focus.child.is_none();
-            let screen_rect =
-                normalized_rect_to_screen(element.rect, env.canvas_origin, env.canvas_size);
-            let widget = Button::new(screen_rect, &display_text)
-                .blocked(!element.enabled)
+            let display_text = env.resolve(&button.text_key);
+            let mut widget = Button::new(screen_rect, &display_text)
+                .blocked(!element.enabled || !env.interactive)
                 .focused(is_focused);
+            widget = match &look.style {
+                Some(style) => {
+                    let style_look = style.look(state);
+                    draw_style_look(
+                        ctx,
+                        screen_rect,
+                        style_look,
+                        env.asset_manager,
+                        env.ui_scale(),
+                    );
+                    widget
+                        .plain()
+                        .hover_color(Color::TRANSPARENT)
+                        .text_color(style_look.text_color)
+                        .font(env.asset_manager.font(ctx, &style.font))
+                        .font_size(style.font_size)
+                }
+                None => widget.font(env.asset_manager.font(ctx, &button.font)),
+            };
             if widget.show(ctx) {
                 *env.triggered_action = Some(button.action.clone());
             }
         }
-        MenuElementKind::Panel(panel) => {
-            let screen_rect =
-                normalized_rect_to_screen(element.rect, env.canvas_origin, env.canvas_size);
-            ctx.draw_rectangle(
-                screen_rect.x,
-                screen_rect.y,
-                screen_rect.w,
-                screen_rect.h,
-                panel.background.render_color(),
-            );
+        MenuElementKind::Panel(panel) => match look.style_look() {
+            Some(style_look) => {
+                draw_style_look(
+                    ctx,
+                    screen_rect,
+                    style_look,
+                    env.asset_manager,
+                    env.ui_scale(),
+                );
+            }
+            None => {
+                let scale = env.ui_scale();
+                panel
+                    .background
+                    .draw(ctx, screen_rect, env.asset_manager, scale);
+            }
+        },
+        MenuElementKind::Slider(slider) => {
+            render_slider(ctx, slider, screen_rect, env, &look);
+        }
+        MenuElementKind::Toggle(toggle) => {
+            render_toggle(ctx, toggle, screen_rect, env, &look);
         }
-        MenuElementKind::LayoutGroup(group) => {
-            render_layout_group(ctx, template, group, element_index, element, env);
+        MenuElementKind::Choice(choice) => {
+            render_choice(ctx, choice, screen_rect, env, &look);
         }
-        MenuElementKind::Slider(slider) => {
-            let screen_rect =
-                normalized_rect_to_screen(element.rect, env.canvas_origin, env.canvas_size);
-            let is_focused = env.focus.node == element_index && env.focus.child.is_none();
-            render_slider(
-                ctx,
-                slider,
-                screen_rect,
-                env.text_manager,
-                env.text_id,
-                env.slider_values,
-                is_focused,
-            );
+        MenuElementKind::TextInput(input) => {
+            render_text_input(ctx, input, screen_rect, env, &look);
         }
+        MenuElementKind::Image(image) => {
+            render_image(ctx, image, screen_rect, env);
+        }
+        MenuElementKind::ScrollList(list) => {
+            render_scroll_list(ctx, list, screen_rect, env, &look);
+        }
+        MenuElementKind::LayoutGroup(_) => {}
     }
 }
 
@@ -120,109 +377,346 @@ fn render_layout_group<C: BishopContext>(
     group: &LayoutGroupElement,
     element_index: usize,
     element: &MenuElement,
+    group_screen: Rect,
     env: &mut RenderEnv<'_>,
 ) {
     if let Some(bg) = &group.background {
-        let screen_rect =
-            normalized_rect_to_screen(element.rect, env.canvas_origin, env.canvas_size);
-        ctx.draw_rectangle(
-            screen_rect.x,
-            screen_rect.y,
-            screen_rect.w,
-            screen_rect.h,
-            bg.render_color(),
-        );
+        let scale = env.ui_scale();
+        bg.draw(ctx, group_screen, env.asset_manager, scale);
     }
 
     let resolved = resolve_layout(group, element.rect);
     let mut focusable_idx = 0;
 
     for (child, rect) in group.children.iter().zip(resolved.iter()) {
-        if !child.element.visible {
+        if !child.element.visible || matches!(child.element.kind, MenuElementKind::LayoutGroup(_)) {
             continue;
         }
 
-        let screen_rect = normalized_rect_to_screen(*rect, env.canvas_origin, env.canvas_size);
-        match &child.element.kind {
-            MenuElementKind::Label(label) => {
-                let display_text = env
-                    .text_manager
-                    .resolve_ui_text(env.text_id, &label.text_key);
-                MenuTemplate::render_label(ctx, label, screen_rect, &display_text);
-            }
-            MenuElementKind::Button(button) => {
-                let display_text = env
-                    .text_manager
-                    .resolve_ui_text(env.text_id, &button.text_key);
-                let is_focused =
-                    env.focus.node == element_index && env.focus.child == Some(focusable_idx);
-                let widget = Button::new(screen_rect, &display_text)
-                    .blocked(!child.element.enabled)
-                    .focused(is_focused);
-                if widget.show(ctx) {
-                    *env.triggered_action = Some(button.action.clone());
-                }
-                if child.element.enabled {
-                    focusable_idx += 1;
-                }
-            }
-            MenuElementKind::Slider(slider) => {
-                let is_focused =
-                    env.focus.node == element_index && env.focus.child == Some(focusable_idx);
-                render_slider(
-                    ctx,
-                    slider,
-                    screen_rect,
-                    env.text_manager,
-                    env.text_id,
-                    env.slider_values,
-                    is_focused,
-                );
-                if child.element.enabled {
-                    focusable_idx += 1;
-                }
-            }
-            _ => {}
+        let screen_rect = child_screen_rect(*rect, element.rect, group_screen);
+        let takes_focus = child.element.kind.is_focusable() && child.element.enabled;
+        let is_focused = takes_focus
+            && env.interactive
+            && env.focus.node == element_index
+            && env.focus.child == Some(focusable_idx);
+        let child_focus = MenuFocus {
+            node: element_index,
+            child: Some(focusable_idx),
+        };
+        let screen_rect = env.feedback_rect(screen_rect, &child_focus, is_focused);
+        let is_pressed = env.is_pressed(&child_focus);
+        render_leaf(
+            ctx,
+            &child.element,
+            screen_rect,
+            env,
+            is_focused,
+            is_pressed,
+        );
+        if takes_focus {
+            focusable_idx += 1;
         }
     }
 }
 
-fn render_slider<C: BishopContext>(
+/// Splits a control into its label on the left and its value on the right.
+fn split_control(screen_rect: Rect) -> (Rect, Rect) {
+    let split = screen_rect.w * 0.4;
+    (
+        Rect::new(screen_rect.x, screen_rect.y, split, screen_rect.h),
+        Rect::new(
+            screen_rect.x + split,
+            screen_rect.y,
+            screen_rect.w - split,
+            screen_rect.h,
+        ),
+    )
+}
+
+/// Fills a control's background, highlighted while focused.
+/// Styled controls draw their whole look, outline included.
+fn draw_control_background<C: BishopContext>(
     ctx: &mut C,
-    slider: &SliderElement,
-    screen_rect: Rect,
-    text_manager: &TextManager,
-    text_id: &str,
-    slider_values: &mut HashMap<String, f32>,
-    is_focused: bool,
+    env: &mut RenderEnv<'_>,
+    rect: Rect,
+    look: &ElementLook,
 ) {
-    let value = slider_values
-        .get(&slider.key)
-        .copied()
-        .unwrap_or(slider.default_value);
-    let split = screen_rect.w * 0.4;
-    let label_rect = Rect::new(screen_rect.x, screen_rect.y, split, screen_rect.h);
-    let slider_rect = Rect::new(
-        screen_rect.x + split,
-        screen_rect.y,
-        screen_rect.w - split,
-        screen_rect.h,
-    );
-    let label_bg = if is_focused {
+    if let Some(style_look) = look.style_look() {
+        draw_style_look(ctx, rect, style_look, env.asset_manager, env.ui_scale());
+        return;
+    }
+    let background = if look.is_focused {
         HOVER_COLOR
     } else {
         FIELD_BACKGROUND_COLOR
     };
-    ctx.draw_rectangle(
-        label_rect.x,
-        label_rect.y,
-        label_rect.w,
-        label_rect.h,
-        label_bg,
+    ctx.draw_rectangle(rect.x, rect.y, rect.w, rect.h, background);
+}
+
+/// Outlines an unstyled control, brighter while focused.
+fn draw_control_outline<C: BishopContext>(ctx: &mut C, rect: Rect, look: &ElementLook) {
+    if look.style.is_some() {
+        return;
+    }
+    let outline_color = if look.is_focused {
+        Color::WHITE
+    } else {
+        Color::new(0.5, 0.5, 0.5, 1.0)
+    };
+    ctx.draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, outline_color);
+}
+
+/// Draws a control's label text on the left of its rect.
+fn draw_control_label<C: BishopContext>(
+    ctx: &mut C,
+    env: &mut RenderEnv<'_>,
+    text_key: &str,
+    rect: Rect,
+    look: &ElementLook,
+) {
+    let display_text = env.resolve(text_key);
+    let label = look.label(LabelElement::default());
+    env.render_label(ctx, &label, rect, &display_text);
+}
+
+fn render_toggle<C: BishopContext>(
+    ctx: &mut C,
+    toggle: &ToggleElement,
+    screen_rect: Rect,
+    env: &mut RenderEnv<'_>,
+    look: &ElementLook,
+) {
+    let (label_rect, value_rect) = split_control(screen_rect);
+    draw_control_background(ctx, env, screen_rect, look);
+    draw_control_label(ctx, env, &toggle.text_key, label_rect, look);
+
+    let size = (value_rect.h * 0.6).min(value_rect.w);
+    let x = value_rect.x + (value_rect.w - size) / 2.0;
+    let y = value_rect.y + (value_rect.h - size) / 2.0;
+    let mark_color = look
+        .style_look()
+        .map_or(OUTLINE_COLOR, |style_look| style_look.text_color);
+    ctx.draw_rectangle_lines(x, y, size, size, 2.0, mark_color);
+    if player_setting_bool(&toggle.key, toggle.default_value) {
+        let inset = size * 0.2;
+        ctx.draw_rectangle(
+            x + inset,
+            y + inset,
+            size - inset * 2.0,
+            size - inset * 2.0,
+            mark_color,
+        );
+    }
+
+    draw_control_outline(ctx, screen_rect, look);
+}
+
+fn render_choice<C: BishopContext>(
+    ctx: &mut C,
+    choice: &ChoiceElement,
+    screen_rect: Rect,
+    env: &mut RenderEnv<'_>,
+    look: &ElementLook,
+) {
+    let (label_rect, value_rect) = split_control(screen_rect);
+    draw_control_background(ctx, env, screen_rect, look);
+    draw_control_label(ctx, env, &choice.text_key, label_rect, look);
+
+    let current = player_setting(&choice.key);
+    let index = choice.index_of(current.as_ref().and_then(SettingValue::as_choice));
+    let option = choice
+        .options
+        .get(index)
+        .map(|option| env.resolve(option))
+        .unwrap_or_default();
+    env.render_label(
+        ctx,
+        &look.label(LabelElement::default()),
+        value_rect,
+        &format!("< {option} >"),
+    );
+
+    draw_control_outline(ctx, screen_rect, look);
+}
+
+fn render_text_input<C: BishopContext>(
+    ctx: &mut C,
+    input: &TextInputElement,
+    screen_rect: Rect,
+    env: &mut RenderEnv<'_>,
+    look: &ElementLook,
+) {
+    let (label_rect, value_rect) = split_control(screen_rect);
+    draw_control_background(ctx, env, screen_rect, look);
+    draw_control_label(ctx, env, &input.text_key, label_rect, look);
+
+    let is_editing = env.controls.is_editing_key(&input.key);
+    let text = env.controls.text(&input.key).to_string();
+    let mut field = look.label(LabelElement {
+        alignment: HorizontalAlign::Left,
+        ..Default::default()
+    });
+    let shown = if is_editing {
+        // Caret blinks twice a second
+        let caret = if ctx.get_time().fract() < 0.5 {
+            "_"
+        } else {
+            " "
+        };
+        format!("{text}{caret}")
+    } else if text.is_empty() {
+        field.color = Color::new(0.6, 0.6, 0.6, 1.0);
+        env.resolve(&input.placeholder_key)
+    } else {
+        text
+    };
+    let inset = Rect::new(
+        value_rect.x + WIDGET_PADDING,
+        value_rect.y,
+        (value_rect.w - WIDGET_PADDING * 2.0).max(0.0),
+        value_rect.h,
+    );
+    env.render_label(ctx, &field, inset, &shown);
+
+    let field_outline = if is_editing {
+        Color::WHITE
+    } else {
+        OUTLINE_COLOR
+    };
+    ctx.draw_rectangle_lines(
+        value_rect.x,
+        value_rect.y,
+        value_rect.w,
+        value_rect.h,
+        1.0,
+        field_outline,
+    );
+    draw_control_outline(ctx, screen_rect, look);
+}
+
+fn render_image<C: BishopContext>(
+    ctx: &mut C,
+    image: &ImageElement,
+    screen_rect: Rect,
+    env: &mut RenderEnv<'_>,
+) {
+    if image.sprite.0 == 0 {
+        return;
+    }
+    let time = ctx.get_time();
+    let texture = env.asset_manager.get_texture_from_id(ctx, image.sprite);
+    let source = image.frame_source(vec2(texture.width(), texture.height()), time);
+    let dest = image.fit(screen_rect, source.size());
+    ctx.draw_texture_ex(
+        texture,
+        dest.x,
+        dest.y,
+        image.tint,
+        DrawTextureParams {
+            dest_size: Some(dest.size()),
+            source: Some(source),
+            ..Default::default()
+        },
     );
-    let display_text = text_manager.resolve_ui_text(text_id, &slider.text_key);
-    let label = LabelElement::default();
-    MenuTemplate::render_label(ctx, &label, label_rect, &display_text);
+}
+
+fn render_scroll_list<C: BishopContext>(
+    ctx: &mut C,
+    list: &ScrollListElement,
+    screen_rect: Rect,
+    env: &mut RenderEnv<'_>,
+    look: &ElementLook,
+) {
+    match look.style_look() {
+        Some(style_look) => draw_style_look(
+            ctx,
+            screen_rect,
+            style_look,
+            env.asset_manager,
+            env.ui_scale(),
+        ),
+        None => ctx.draw_rectangle(
+            screen_rect.x,
+            screen_rect.y,
+            screen_rect.w,
+            screen_rect.h,
+            FIELD_BACKGROUND_COLOR,
+        ),
+    }
+
+    let Some(state) = env.controls.list(env.menu_id, &list.key).cloned() else {
+        draw_control_outline(ctx, screen_rect, look);
+        return;
+    };
+    let visible_rows = list.visible_rows.max(1);
+    let row_height = screen_rect.h / visible_rows as f32;
+    let label = look.label(LabelElement {
+        font_size: list.font_size,
+        font: list.font.clone(),
+        alignment: HorizontalAlign::Left,
+        ..Default::default()
+    });
+
+    for (row, (index, item)) in state
+        .items
+        .iter()
+        .enumerate()
+        .skip(state.scroll)
+        .take(visible_rows)
+        .enumerate()
+    {
+        let row_rect = Rect::new(
+            screen_rect.x,
+            screen_rect.y + row as f32 * row_height,
+            screen_rect.w,
+            row_height,
+        );
+        if index == state.selected {
+            let highlight = if look.is_focused {
+                HOVER_COLOR
+            } else {
+                Color::new(0.2, 0.2, 0.2, 0.4)
+            };
+            ctx.draw_rectangle(row_rect.x, row_rect.y, row_rect.w, row_rect.h, highlight);
+        }
+        let text_rect = Rect::new(
+            row_rect.x + WIDGET_PADDING,
+            row_rect.y,
+            (row_rect.w - WIDGET_PADDING * 2.0).max(0.0),
+            row_rect.h,
+        );
+        let display_text = env.resolve(item);
+        env.render_label(ctx, &label, text_rect, &display_text);
+    }
+
+    // Thumb showing which part of the list is in view
+    if state.items.len() > visible_rows {
+        let track_width = 4.0;
+        let share = visible_rows as f32 / state.items.len() as f32;
+        let offset = state.scroll as f32 / state.items.len() as f32;
+        ctx.draw_rectangle(
+            screen_rect.x + screen_rect.w - track_width,
+            screen_rect.y + screen_rect.h * offset,
+            track_width,
+            screen_rect.h * share,
+            OUTLINE_COLOR,
+        );
+    }
+
+    draw_control_outline(ctx, screen_rect, look);
+}
+
+fn render_slider<C: BishopContext>(
+    ctx: &mut C,
+    slider: &SliderElement,
+    screen_rect: Rect,
+    env: &mut RenderEnv<'_>,
+    look: &ElementLook,
+) {
+    let value = player_setting_number(&slider.key, slider.default_value);
+    let (label_rect, slider_rect) = split_control(screen_rect);
+    draw_control_background(ctx, env, label_rect, look);
+    draw_control_label(ctx, env, &slider.text_key, label_rect, look);
 
     let (new_value, state) = gui_slider(
         ctx,
@@ -232,22 +726,10 @@ fn render_slider<C: BishopContext>(
         slider.max,
         value,
     );
-    if !matches!(state, SliderState::Unchanged) {
-        slider_values.insert(slider.key.clone(), new_value);
+    if env.interactive && !matches!(state, SliderState::Unchanged) {
+        set_player_setting(&slider.key, SettingValue::Number(new_value));
         push_slider_event(slider.key.clone(), new_value);
     }
 
-    let outline_color = if is_focused {
-        Color::WHITE
-    } else {
-        Color::new(0.5, 0.5, 0.5, 1.0)
-    };
-    ctx.draw_rectangle_lines(
-        screen_rect.x,
-        screen_rect.y,
-        screen_rect.w,
-        screen_rect.h,
-        2.0,
-        outline_color,
-    );
+    draw_control_outline(ctx, screen_rect, look);
 }
@@ -0,0 +1,5 @@
+pub mod particle_emitter;
+pub mod particle_system;
+
+pub use particle_emitter::*;
+pub use particle_system::*;
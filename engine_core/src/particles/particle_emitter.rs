@@ -0,0 +1,151 @@
+// engine_core/src/particles/particle_emitter.rs
+use crate::assets::sprite::SpriteId;
+use crate::ecs::entity::Entity;
+use crate::game::GameCtxMut;
+use crate::inspector_module;
+use bishop::prelude::*;
+use ecs_component::ecs_component;
+use reflect_derive::Reflect;
+use serde::{Deserialize, Serialize};
+use serde_with::{FromInto, serde_as};
+
+/// Spawns and simulates short lived particles around the entity.
+#[ecs_component(post_create = post_create, post_remove = post_remove)]
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[serde(default)]
+pub struct ParticleEmitter {
+    /// Whether particles are emitted continuously at `rate`.
+    pub emitting: bool,
+    /// Particles per second while emitting.
+    pub rate: f32,
+    /// Particles emitted at once when the emitter starts.
+    pub burst: i32,
+    /// Upper bound of live particles.
+    pub max_particles: i32,
+    /// Seconds a particle lives.
+    pub lifetime: f32,
+    /// Random +/- seconds added to each lifetime.
+    pub lifetime_variance: f32,
+    /// Emission direction in degrees, clockwise from +x. `-90` points up.
+    pub angle: f32,
+    /// Width of the emission cone in degrees.
+    pub spread: f32,
+    /// Initial speed in pixels per second.
+    pub speed: f32,
+    /// Random +/- pixels per second added to each speed.
+    pub speed_variance: f32,
+    /// Acceleration in pixels per second squared.
+    #[serde_as(as = "FromInto<[f32; 2]>")]
+    pub gravity: Vec2,
+    /// Size of the box particles spawn in, centred on the entity.
+    #[serde_as(as = "FromInto<[f32; 2]>")]
+    pub spawn_area: Vec2,
+    #[serde_as(as = "FromInto<[f32; 3]>")]
+    pub start_color: Vec3,
+    #[serde_as(as = "FromInto<[f32; 3]>")]
+    pub end_color: Vec3,
+    pub start_alpha: f32,
+    pub end_alpha: f32,
+    /// Particle width in pixels at birth.
+    pub start_size: f32,
+    /// Particle width in pixels at death.
+    pub end_size: f32,
+    /// Texture drawn per particle. Unset draws solid squares.
+    #[widget("png")]
+    pub sprite: SpriteId,
+    /// Columns of the sprite sheet.
+    pub sheet_columns: i32,
+    /// Rows of the sprite sheet.
+    pub sheet_rows: i32,
+    /// Sheet frames per second. `0` plays the sheet once over the lifetime.
+    pub sheet_fps: f32,
+    /// Particles move with the entity instead of staying where they spawned.
+    pub local_space: bool,
+}
+
+inspector_module!(ParticleEmitter);
+
+impl Default for ParticleEmitter {
+    fn default() -> Self {
+        Self {
+            emitting: true,
+            rate: 10.0,
+            burst: 0,
+            max_particles: 100,
+            lifetime: 1.0,
+            lifetime_variance: 0.0,
+            angle: -90.0,
+            spread: 30.0,
+            speed: 40.0,
+            speed_variance: 0.0,
+            gravity: Vec2::ZERO,
+            spawn_area: Vec2::ZERO,
+            start_color: Vec3::ONE,
+            end_color: Vec3::ONE,
+            start_alpha: 1.0,
+            end_alpha: 0.0,
+            start_size: 4.0,
+            end_size: 4.0,
+            sprite: SpriteId(0),
+            sheet_columns: 1,
+            sheet_rows: 1,
+            sheet_fps: 0.0,
+            local_space: false,
+        }
+    }
+}
+
+impl ParticleEmitter {
+    /// Number of frames in the sprite sheet.
+    pub fn frame_count(&self) -> usize {
+        (self.sheet_columns.max(1) * self.sheet_rows.max(1)) as usize
+    }
+}
+
+fn post_create(emitter: &mut ParticleEmitter, _entity: &Entity, ctx: &mut GameCtxMut) {
+    ctx.asset_manager.increment_ref(emitter.sprite);
+}
+
+fn post_remove(emitter: &mut ParticleEmitter, _entity: &Entity, ctx: &mut GameCtxMut) {
+    ctx.asset_manager.decrement_ref(emitter.sprite);
+}
+
+/// One live particle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    /// World position, or offset from the entity in local space.
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub age: f32,
+    pub lifetime: f32,
+}
+
+impl Particle {
+    /// Normalised age in `0..=1`.
+    pub fn progress(&self) -> f32 {
+        if self.lifetime > 0.0 {
+            (self.age / self.lifetime).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Runtime state of an entity's particle emitter.
+#[ecs_component(runtime = true)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParticleState {
+    /// Live particles.
+    #[serde(skip)]
+    pub particles: Vec<Particle>,
+    /// Fractional particles carried between frames.
+    #[serde(skip)]
+    pub accumulator: f32,
+    /// Particles requested by bursts, spawned on the next update.
+    #[serde(skip)]
+    pub pending: u32,
+    /// Whether the emitter was emitting last update, used to fire the start burst.
+    #[serde(skip)]
+    pub was_emitting: bool,
+}
@@ -0,0 +1,268 @@
+// engine_core/src/particles/particle_system.rs
+use crate::assets::asset_manager::AssetManager;
+use crate::ecs::ecs::Ecs;
+use crate::ecs::entity::Entity;
+use crate::ecs::transform::Transform;
+use crate::particles::particle_emitter::*;
+use crate::rendering::renderable::tint_color;
+use crate::worlds::room::{RoomId, entities_in_room};
+use bishop::prelude::*;
+use rand::Rng;
+
+/// Advances every particle emitter in the room by `dt` seconds.
+pub fn update_particles(ecs: &mut Ecs, room_id: RoomId, dt: f32) {
+    let mut rng = rand::thread_rng();
+
+    for entity in entities_in_room(ecs, room_id) {
+        let Some(emitter) = ecs.get::<ParticleEmitter>(entity).cloned() else {
+            continue;
+        };
+        let (origin, rotation) = ecs
+            .get::<Transform>(entity)
+            .map(|t| (t.position, t.rotation))
+            .unwrap_or_default();
+
+        // Runtime state is created lazily, like the animator
+        if !ecs.has::<ParticleState>(entity) {
+            ecs.add_component_to_entity(entity, ParticleState::default());
+        }
+        if let Some(state) = ecs.get_mut::<ParticleState>(entity) {
+            step_emitter(&emitter, state, origin, rotation, dt, &mut rng);
+        }
+    }
+}
+
+/// Queues `count` particles on the entity's emitter, spawned on the next update.
+pub fn emit_particles(ecs: &mut Ecs, entity: Entity, count: u32) {
+    if !ecs.has::<ParticleEmitter>(entity) {
+        return;
+    }
+    if !ecs.has::<ParticleState>(entity) {
+        ecs.add_component_to_entity(entity, ParticleState::default());
+    }
+    if let Some(state) = ecs.get_mut::<ParticleState>(entity) {
+        state.pending = state.pending.saturating_add(count);
+    }
+}
+
+/// Ages, moves and spawns the particles of one emitter.
+/// `origin` and `rotation` come from the entity's transform.
+pub fn step_emitter(
+    emitter: &ParticleEmitter,
+    state: &mut ParticleState,
+    origin: Vec2,
+    rotation: f32,
+    dt: f32,
+    rng: &mut impl Rng,
+) {
+    state.particles.retain_mut(|particle| {
+        particle.age += dt;
+        if particle.age >= particle.lifetime {
+            return false;
+        }
+        particle.velocity += emitter.gravity * dt;
+        particle.position += particle.velocity * dt;
+        true
+    });
+
+    if emitter.emitting && !state.was_emitting {
+        state.pending = state.pending.saturating_add(emitter.burst.max(0) as u32);
+    }
+    state.was_emitting = emitter.emitting;
+
+    if emitter.emitting && emitter.rate > 0.0 {
+        state.accumulator += emitter.rate * dt;
+        let whole = state.accumulator.floor();
+        state.accumulator -= whole;
+        state.pending = state.pending.saturating_add(whole as u32);
+    } else {
+        state.accumulator = 0.0;
+    }
+
+    // Requests over the cap are dropped rather than queued
+    let max = emitter.max_particles.max(0) as usize;
+    state.particles.truncate(max);
+    let free = max - state.particles.len();
+    let count = (state.pending as usize).min(free);
+    state.pending = 0;
+
+    for _ in 0..count {
+        let angle = (emitter.angle + rotation + jitter(rng, emitter.spread / 2.0)).to_radians();
+        let speed = emitter.speed + jitter(rng, emitter.speed_variance);
+        let offset = vec2(
+            jitter(rng, emitter.spawn_area.x / 2.0),
+            jitter(rng, emitter.spawn_area.y / 2.0),
+        );
+        state.particles.push(Particle {
+            position: if emitter.local_space {
+                offset
+            } else {
+                origin + offset
+            },
+            velocity: vec2(angle.cos(), angle.sin()) * speed,
+            age: 0.0,
+            lifetime: (emitter.lifetime + jitter(rng, emitter.lifetime_variance)).max(0.0),
+        });
+    }
+}
+
+/// Random value in `-amount..=amount`, or zero for non-positive amounts.
+fn jitter(rng: &mut impl Rng, amount: f32) -> f32 {
+    if amount > 0.0 {
+        rng.gen_range(-amount..=amount)
+    } else {
+        0.0
+    }
+}
+
+/// Draws the entity's particles. `pos` is the entity's draw position.
+pub fn draw_particles<C: BishopContext>(
+    ctx: &mut C,
+    ecs: &Ecs,
+    asset_manager: &mut AssetManager,
+    entity: Entity,
+    pos: Vec2,
+) {
+    let (Some(emitter), Some(state)) = (
+        ecs.get::<ParticleEmitter>(entity),
+        ecs.get::<ParticleState>(entity),
+    ) else {
+        return;
+    };
+    if state.particles.is_empty() {
+        return;
+    }
+
+    let texture = if emitter.sprite.0 != 0 {
+        Some(asset_manager.get_texture_from_id(ctx, emitter.sprite))
+    } else {
+        None
+    };
+    let frames = emitter.frame_count();
+
+    for particle in &state.particles {
+        let t = particle.progress();
+        let center = if emitter.local_space {
+            pos + particle.position
+        } else {
+            particle.position
+        };
+        let color = tint_color(
+            emitter.start_color.lerp(emitter.end_color, t),
+            emitter.start_alpha + (emitter.end_alpha - emitter.start_alpha) * t,
+        );
+        let width = (emitter.start_size + (emitter.end_size - emitter.start_size) * t).max(0.0);
+
+        let Some(texture) = texture else {
+            ctx.draw_rectangle(
+                center.x - width / 2.0,
+                center.y - width / 2.0,
+                width,
+                width,
+                color,
+            );
+            continue;
+        };
+
+        let columns = emitter.sheet_columns.max(1) as usize;
+        let frame_size = vec2(
+            texture.width() / columns as f32,
+            texture.height() / emitter.sheet_rows.max(1) as f32,
+        );
+        let frame = if emitter.sheet_fps > 0.0 {
+            (particle.age * emitter.sheet_fps) as usize % frames
+        } else {
+            ((t * frames as f32) as usize).min(frames - 1)
+        };
+        let source = Rect::new(
+            (frame % columns) as f32 * frame_size.x,
+            (frame / columns) as f32 * frame_size.y,
+            frame_size.x,
+            frame_size.y,
+        );
+        let height = if frame_size.x > 0.0 {
+            width * frame_size.y / frame_size.x
+        } else {
+            width
+        };
+
+        ctx.draw_texture_ex(
+            texture,
+            center.x - width / 2.0,
+            center.y - height / 2.0,
+            color,
+            DrawTextureParams {
+                dest_size: Some(vec2(width, height)),
+                source: Some(source),
+                ..Default::default()
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn straight_emitter() -> ParticleEmitter {
+        ParticleEmitter {
+            rate: 0.0,
+            burst: 3,
+            max_particles: 2,
+            angle: 0.0,
+            spread: 0.0,
+            speed: 10.0,
+            gravity: vec2(0.0, 20.0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn start_burst_respects_the_particle_cap() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let emitter = straight_emitter();
+        let mut state = ParticleState::default();
+
+        step_emitter(&emitter, &mut state, vec2(5.0, 5.0), 0.0, 0.0, &mut rng);
+        assert_eq!(state.particles.len(), 2);
+        assert_eq!(state.particles[0].position, vec2(5.0, 5.0));
+
+        // The burst only fires when emission starts
+        step_emitter(&emitter, &mut state, vec2(5.0, 5.0), 0.0, 0.0, &mut rng);
+        assert_eq!(state.pending, 0);
+        assert_eq!(state.particles.len(), 2);
+    }
+
+    #[test]
+    fn particles_move_with_gravity_and_expire() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let emitter = straight_emitter();
+        let mut state = ParticleState::default();
+        step_emitter(&emitter, &mut state, Vec2::ZERO, 0.0, 0.0, &mut rng);
+
+        step_emitter(&emitter, &mut state, Vec2::ZERO, 0.0, 0.5, &mut rng);
+        let particle = state.particles[0];
+        assert!((particle.velocity - vec2(10.0, 10.0)).length() < 1e-4);
+        assert!((particle.position - vec2(5.0, 5.0)).length() < 1e-4);
+
+        step_emitter(&emitter, &mut state, Vec2::ZERO, 0.0, 0.6, &mut rng);
+        assert!(state.particles.is_empty());
+    }
+
+    #[test]
+    fn continuous_rate_carries_fractions_between_frames() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let emitter = ParticleEmitter {
+            rate: 10.0,
+            ..Default::default()
+        };
+        let mut state = ParticleState::default();
+
+        for _ in 0..3 {
+            step_emitter(&emitter, &mut state, Vec2::ZERO, 0.0, 0.0625, &mut rng);
+        }
+        assert_eq!(state.particles.len(), 1);
+    }
+}
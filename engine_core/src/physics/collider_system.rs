@@ -7,6 +7,8 @@ use crate::ecs::component::Collider;
 use crate::ecs::component::ComponentStore;
 use crate::ecs::ecs::Ecs;
 use crate::ecs::entity::Entity;
+use crate::ecs::transform::Transform;
+use bishop::prelude::*;
 
 /// Set the collider for every entity that has a sprite and an unset collider
 pub fn update_colliders_from_sprites(ecs: &mut Ecs, assets: &mut AssetManager) {
@@ -17,6 +19,7 @@ pub fn update_colliders_from_sprites(ecs: &mut Ecs, assets: &mut AssetManager) {
         let sprite_store = ecs.get_store::<Sprite>();
         let current_frame_store = ecs.get_store::<CurrentFrame>();
         let collider_store = ecs.get_store::<Collider>();
+        let transform_store = ecs.get_store::<Transform>();
 
         // Only update entities with colliders
         for (entity, collider) in collider_store.data.iter() {
@@ -24,20 +27,22 @@ pub fn update_colliders_from_sprites(ecs: &mut Ecs, assets: &mut AssetManager) {
                 continue;
             }
 
-            // Try animation components first
-            if let Some(col) =
-                collider_from_animation_component(current_frame_store, *entity, assets)
-            {
-                pending.push((*entity, col));
-                continue; // Found
-            }
+            // Try animation components first, then sprite components
+            let derived = collider_from_animation_component(current_frame_store, *entity, assets)
+                .or_else(|| {
+                    let sprite = sprite_store.get(*entity)?;
+                    collider_from_sprite(assets, sprite.sprite)
+                });
+            let Some(col) = derived else {
+                continue;
+            };
 
-            // Then try sprite components if not
-            for (entity, sprite) in sprite_store.data.iter() {
-                if let Some(col) = collider_from_sprite(assets, sprite.sprite) {
-                    pending.push((*entity, col));
-                }
-            }
+            // Cover the scaled and rotated visual
+            let col = match transform_store.get(*entity) {
+                Some(transform) => transformed_collider(transform, col),
+                None => col,
+            };
+            pending.push((*entity, col));
         }
     }
 
@@ -84,3 +89,12 @@ fn collider_from_animation_component(
             height: h,
         })
 }
+
+/// Returns a collider covering `collider` after the transform's scale and rotation.
+pub fn transformed_collider(transform: &Transform, collider: Collider) -> Collider {
+    let bounds = transform.world_bounds(Vec2::new(collider.width, collider.height));
+    Collider {
+        width: bounds.w,
+        height: bounds.h,
+    }
+}
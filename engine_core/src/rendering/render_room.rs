@@ -5,7 +5,7 @@ use crate::prelude::*;
 use bishop::prelude::*;
 use std::collections::{BTreeMap, HashMap};
 
-/// Draws everything needed for the given room.
+/// Draws everything needed for the current room.
 /// Currently uses simplified single-pass rendering.
 pub fn render_room<C: BishopContext>(
     ctx: &mut C,
@@ -15,47 +15,92 @@ pub fn render_room<C: BishopContext>(
     alpha: f32,
     prev_positions: Option<&HashMap<Entity, Vec2>>,
 ) {
-    let render_start = std::time::Instant::now();
     let Some(current_room) = game_ctx.cur_world.current_room() else {
         return;
     };
-
-    let grid_size = game_ctx.cur_world.grid_size;
-
-    // Organize entities by layer
-    let layer_map = collect_interpolated_layer_map(
-        game_ctx.ecs,
-        current_room,
-        game_ctx.asset_manager,
+    let rooms = [current_room.id];
+    render_rooms(
+        ctx,
+        game_ctx,
+        render_system,
+        render_cam,
         alpha,
         prev_positions,
-        grid_size,
+        &rooms,
     );
+}
+
+/// Draws `rooms` in order under one camera, such as both rooms while
+/// the camera scrolls from one into the other.
+pub fn render_rooms<C: BishopContext>(
+    ctx: &mut C,
+    game_ctx: &mut GameCtxMut<'_>,
+    render_system: &mut RenderSystem,
+    render_cam: &Camera2D,
+    alpha: f32,
+    prev_positions: Option<&HashMap<Entity, Vec2>>,
+    rooms: &[RoomId],
+) {
+    let render_start = std::time::Instant::now();
 
     // Set up camera and clear background
     ctx.set_camera(render_cam);
     ctx.clear_background(Color::BLACK);
 
-    // Draw tilemap first
-    let tilemap = &current_room.current_variant().tilemap;
-    tilemap.draw(
-        ctx,
-        game_ctx.asset_manager,
-        current_room.position,
-        grid_size,
-    );
+    let grid_size = game_ctx.cur_world.grid_size;
+    for &room_id in rooms {
+        let Some(room) = game_ctx.cur_world.get_room(room_id) else {
+            continue;
+        };
+
+        // Organize entities by layer
+        let layer_map = collect_interpolated_layer_map(
+            game_ctx.ecs,
+            room,
+            game_ctx.asset_manager,
+            alpha,
+            prev_positions,
+            grid_size,
+        );
+        draw_room(
+            ctx,
+            game_ctx.ecs,
+            game_ctx.asset_manager,
+            room,
+            layer_map,
+            render_cam,
+            grid_size,
+        );
+    }
+
+    // TODO: Re-enable multi-pass rendering
+    // let lights = collect_lights(ecs, room, alpha, prev_positions);
+    // render_system.run_spotlight_pass(ctx, render_cam, lights, room.darkness);
+    // render_system.run_final_pass(ctx);
+
+    render_system.render_time_ms = render_start.elapsed().as_secs_f32() * 1000.0;
+}
+
+/// Draws the background, tilemap and layered entities of one room.
+fn draw_room<C: BishopContext>(
+    ctx: &mut C,
+    ecs: &Ecs,
+    asset_manager: &mut AssetManager,
+    room: &Room,
+    layer_map: BTreeMap<i32, LayerData<'_>>,
+    render_cam: &Camera2D,
+    grid_size: f32,
+) {
+    // Draw the background and tilemap first
+    let tilemap = &room.current_variant().tilemap;
+    tilemap.draw_background(ctx, asset_manager, room.position, grid_size, render_cam);
+    tilemap.draw(ctx, asset_manager, room.position, grid_size);
 
     // Draw all entities sorted by layer
     for (_z, layer) in layer_map {
         for (entity, pos) in layer.entities {
-            draw_entity(
-                ctx,
-                game_ctx.ecs,
-                game_ctx.asset_manager,
-                entity,
-                pos,
-                grid_size,
-            );
+            draw_entity(ctx, ecs, asset_manager, entity, pos, grid_size);
+            draw_particles(ctx, ecs, asset_manager, entity, pos);
         }
 
         // TODO: Re-enable multi-pass rendering
@@ -64,13 +109,6 @@ pub fn render_room<C: BishopContext>(
         // render_system.run_undarkened_pass(ctx);
         // render_system.run_scene_pass(ctx);
     }
-
-    // TODO: Re-enable multi-pass rendering
-    // let lights = collect_lights(ecs, room, alpha, prev_positions);
-    // render_system.run_spotlight_pass(ctx, render_cam, lights, room.darkness);
-    // render_system.run_final_pass(ctx);
-
-    render_system.render_time_ms = render_start.elapsed().as_secs_f32() * 1000.0;
 }
 
 fn draw_entity<C: BishopContext>(
@@ -83,17 +121,14 @@ fn draw_entity<C: BishopContext>(
 ) {
     let visual_entity = resolve_visual_entity(ecs, entity);
 
-    let pivot = ecs
+    let transform = ecs
         .get_store::<Transform>()
         .get(entity)
-        .map(|t| t.pivot)
-        .unwrap_or(Pivot::BottomCenter);
+        .copied()
+        .unwrap_or_default();
+    let pivot = transform.pivot;
 
-    let params = EntityDrawParams {
-        pos,
-        pivot,
-        grid_size,
-    };
+    let params = EntityDrawParams::from_transform(&transform, pos, grid_size);
 
     if let Some(cf) = ecs.get_store::<CurrentFrame>().get(visual_entity)
         && cf.draw(ctx, asset_manager, &params)
@@ -107,7 +142,7 @@ fn draw_entity<C: BishopContext>(
         return;
     }
 
-    if ecs.has_any::<(Light, Glow)>(visual_entity) {
+    if ecs.has_any::<(Light, Glow, ParticleEmitter)>(visual_entity) {
         return;
     }
 
@@ -6,6 +6,9 @@ use bishop::prelude::*;
 /// Max lights per layer.
 pub const MAX_LIGHTS: usize = 10;
 
+/// Segments of the ring drawn around an iris.
+const IRIS_SEGMENTS: usize = 48;
+
 /// Render system that draws the game scene to an offscreen target and scales it to the window.
 /// Multi-pass lighting is temporarily disabled.
 pub struct RenderSystem {
@@ -15,8 +18,14 @@ pub struct RenderSystem {
     pub rt_height: f32,
     /// Time spent rendering last frame (ms)
     pub render_time_ms: f32,
+    /// Only scale the scene by whole multiples so pixels stay square.
+    pub integer_scaling: bool,
     /// Offscreen render target at virtual resolution for scene rendering.
     scene_rt: Option<BishopRenderTarget>,
+    /// Copy of the last scene before a room transition started.
+    snapshot_rt: Option<BishopRenderTarget>,
+    /// Target room transitions are composed in before presenting.
+    transition_rt: Option<BishopRenderTarget>,
 }
 
 impl RenderSystem {
@@ -26,7 +35,10 @@ impl RenderSystem {
             rt_width: width,
             rt_height: height,
             render_time_ms: 0.0,
+            integer_scaling: false,
             scene_rt: None,
+            snapshot_rt: None,
+            transition_rt: None,
         }
     }
 
@@ -76,7 +88,11 @@ impl RenderSystem {
         let win_w = ctx.screen_width();
         let win_h = ctx.screen_height();
 
-        let scale = (win_w / virt_w).min(win_h / virt_h);
+        let mut scale = (win_w / virt_w).min(win_h / virt_h);
+        // Windows smaller than the scene still shrink it smoothly
+        if self.integer_scaling && scale >= 1.0 {
+            scale = scale.floor();
+        }
         let scaled_w = virt_w * scale;
         let scaled_h = virt_h * scale;
 
@@ -100,6 +116,69 @@ impl RenderSystem {
         ctx.draw_render_target(rt, vp.x, vp.y, vp.w, vp.h);
     }
 
+    /// Copies the last rendered scene so a room transition can show it
+    /// while the next room renders into the scene target.
+    pub fn capture_snapshot<C: BishopContext>(&mut self, ctx: &mut C) {
+        let Some(scene) = &self.scene_rt else {
+            return;
+        };
+        let (w, h) = (scene.width(), scene.height());
+        let snapshot = ensure_target(ctx, &mut self.snapshot_rt, w, h);
+
+        ctx.begin_render_to_target(snapshot);
+        ctx.set_default_camera();
+        ctx.draw_render_target(scene, 0.0, 0.0, w as f32, h as f32);
+        ctx.end_render_to_target();
+    }
+
+    /// Presents the scene with a room transition drawn over it, letterboxed like `present_game`.
+    pub fn present_transition<C: BishopContext>(&mut self, ctx: &mut C, frame: &TransitionFrame) {
+        let (Some(scene), Some(snapshot)) = (&self.scene_rt, &self.snapshot_rt) else {
+            self.present_game(ctx);
+            return;
+        };
+        let (w, h) = (scene.width(), scene.height());
+        let size = vec2(w as f32, h as f32);
+        let vp = self.viewport_rect(ctx);
+        let composite = ensure_target(ctx, &mut self.transition_rt, w, h);
+
+        ctx.begin_render_to_target(composite);
+        ctx.set_default_camera();
+        ctx.draw_rectangle(0.0, 0.0, size.x, size.y, Color::BLACK);
+
+        let shown = if frame.shows_snapshot() {
+            snapshot
+        } else {
+            scene
+        };
+        match frame.style {
+            // Slides scroll the camera, so the scene already shows them
+            TransitionStyle::None | TransitionStyle::Slide => {
+                ctx.draw_render_target(scene, 0.0, 0.0, size.x, size.y);
+            }
+            TransitionStyle::Fade => {
+                ctx.draw_render_target(shown, 0.0, 0.0, size.x, size.y);
+                let mut color = frame.color;
+                color.a *= frame.fade_alpha();
+                ctx.draw_rectangle(0.0, 0.0, size.x, size.y, color);
+            }
+            TransitionStyle::Iris => {
+                ctx.draw_render_target(shown, 0.0, 0.0, size.x, size.y);
+                draw_iris(
+                    ctx,
+                    frame.focus * size,
+                    frame.iris_radius(),
+                    size,
+                    frame.color,
+                );
+            }
+        }
+        ctx.end_render_to_target();
+
+        ctx.set_default_camera();
+        ctx.draw_render_target(composite, vp.x, vp.y, vp.w, vp.h);
+    }
+
     /// Re-creates every render target with the supplied size.
     pub fn resize(&mut self, _width: u32, _height: u32) {
         // TODO: Re-implement
@@ -148,6 +227,45 @@ impl RenderSystem {
     }
 }
 
+/// Returns `target`, re-creating it when missing or not `w` by `h`.
+fn ensure_target<'a, C: BishopContext>(
+    ctx: &mut C,
+    target: &'a mut Option<BishopRenderTarget>,
+    w: u32,
+    h: u32,
+) -> &'a BishopRenderTarget {
+    if target
+        .as_ref()
+        .is_none_or(|rt| rt.width() != w || rt.height() != h)
+    {
+        *target = Some(ctx.create_drawable_render_target(w, h));
+    }
+    target.as_ref().expect("render target was just created")
+}
+
+/// Covers everything outside a circle at `centre` with `color`.
+/// `radius` is a share of the distance to the furthest corner of `size`.
+fn draw_iris<C: BishopContext>(ctx: &mut C, centre: Vec2, radius: f32, size: Vec2, color: Color) {
+    let corners = [Vec2::ZERO, vec2(size.x, 0.0), vec2(0.0, size.y), size];
+    let reach = corners
+        .iter()
+        .map(|corner| corner.distance(centre))
+        .fold(0.0, f32::max);
+    let inner = reach * radius;
+    // Past the corners so the ring's straight edges never show
+    let outer = reach * 1.5 + 1.0;
+
+    let point = |angle: f32, r: f32| centre + vec2(angle.cos(), angle.sin()) * r;
+    for i in 0..IRIS_SEGMENTS {
+        let a0 = i as f32 / IRIS_SEGMENTS as f32 * std::f32::consts::TAU;
+        let a1 = (i + 1) as f32 / IRIS_SEGMENTS as f32 * std::f32::consts::TAU;
+        let (p0, p1) = (point(a0, inner), point(a1, inner));
+        let (q0, q1) = (point(a0, outer), point(a1, outer));
+        ctx.draw_triangle(p0, q0, q1, color);
+        ctx.draw_triangle(p0, q1, p1, color);
+    }
+}
+
 impl Default for RenderSystem {
     fn default() -> Self {
         Self::with_default_grid_size()
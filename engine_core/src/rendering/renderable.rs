@@ -7,6 +7,48 @@ pub struct EntityDrawParams {
     pub pos: Vec2,
     pub pivot: Pivot,
     pub grid_size: f32,
+    /// Clockwise rotation in degrees around `pos`.
+    pub rotation: f32,
+    pub scale: Vec2,
+    pub flip_y: bool,
+}
+
+impl EntityDrawParams {
+    /// Builds draw params from an entity's transform, drawn at `pos`.
+    pub fn from_transform(transform: &Transform, pos: Vec2, grid_size: f32) -> Self {
+        Self {
+            pos,
+            pivot: transform.pivot,
+            grid_size,
+            rotation: transform.rotation,
+            scale: transform.scale,
+            flip_y: transform.flip_y,
+        }
+    }
+
+    /// Returns the top-left draw position and texture params for a texture of
+    /// `size`. `offset` is in unscaled texture pixels.
+    pub fn placement(&self, size: Vec2, offset: Vec2, flip_x: bool) -> (Vec2, DrawTextureParams) {
+        let dest_size = size * self.scale.abs();
+        let base =
+            pivot_adjusted_position(self.pos, dest_size, self.pivot) + offset * self.scale.abs();
+        let params = DrawTextureParams {
+            dest_size: Some(dest_size),
+            rotation: self.rotation.to_radians(),
+            flip_x: flip_x != (self.scale.x < 0.0),
+            flip_y: self.flip_y != (self.scale.y < 0.0),
+            // Rotate around the entity position rather than the texture center
+            pivot: Some(self.pos - base),
+            ..Default::default()
+        };
+        (base, params)
+    }
+}
+
+/// Returns the draw color for a tint and opacity.
+#[inline]
+pub fn tint_color(tint: Vec3, opacity: f32) -> Color {
+    Color::new(tint.x, tint.y, tint.z, opacity.clamp(0.0, 1.0))
 }
 
 /// Trait for visual components that can draw themselves.
@@ -53,8 +53,24 @@ pub const SET_FACING: &str = "set_facing";
 pub const SET_ANIM_SPEED: &str = "set_anim_speed";
 pub const GET_CURRENT_FRAME: &str = "get_current_frame";
 pub const IS_CLIP_FINISHED: &str = "is_clip_finished";
+pub const GET_SLICE: &str = "get_slice";
+pub const SET_ANIM_PARAM: &str = "set_anim_param";
+pub const GET_ANIM_STATE: &str = "get_anim_state";
+pub const ON_ANIMATION_EVENT: &str = "on_animation_event";
 pub const ON_CLIP_FINISHED: &str = "on_clip_finished";
 
+// Transform and tint methods
+pub const SET_ROTATION: &str = "set_rotation";
+pub const SET_SCALE: &str = "set_scale";
+pub const SET_FLIP_Y: &str = "set_flip_y";
+pub const SET_TINT: &str = "set_tint";
+pub const SET_OPACITY: &str = "set_opacity";
+
+// Particle methods
+pub const EMIT_PARTICLES: &str = "emit_particles";
+pub const START_PARTICLES: &str = "start_particles";
+pub const STOP_PARTICLES: &str = "stop_particles";
+
 // Entity fields
 pub const ID: &str = "id";
 
@@ -71,6 +87,8 @@ pub const GET_LANGUAGE: &str = "get_language";
 pub const GET_LANGUAGES: &str = "get_languages";
 pub const SET_LANGUAGE: &str = "set_language";
 pub const GET_CONFIG: &str = "get_config";
+pub const FORMAT_TEXT: &str = "format";
+pub const GET_TEXT: &str = "get";
 
 // Menu module
 pub const LUA_MENU: &str = "menu";
@@ -78,6 +96,11 @@ pub const MENU_FILE: &str = "menu.lua";
 pub const OPEN_MENU: &str = "open";
 pub const CLOSE_MENU: &str = "close";
 pub const IS_MENU_OPEN: &str = "is_open";
+pub const SET_MENU_LIST: &str = "set_list";
+pub const SET_MENU_TEXT: &str = "set_text";
+pub const SET_MENU_VALUE: &str = "set_value";
+pub const SHOW_HUD: &str = "show_hud";
+pub const HIDE_HUD: &str = "hide_hud";
 
 // Audio module
 pub const LUA_AUDIO: &str = "audio";
@@ -99,3 +122,43 @@ pub const AUDIO_PLAY_SFX_VARIED: &str = "play_sfx_varied";
 pub const ENTITY_PLAY_SOUND: &str = "play_sound";
 pub const ENTITY_STOP_SOUND: &str = "stop_sound";
 pub const ENTITY_SET_SOUND_VOLUME: &str = "set_sound_volume";
+
+// Dialogue module
+pub const LUA_DIALOGUE: &str = "dialogue";
+pub const DIALOGUE_FILE: &str = "dialogue.lua";
+pub const DIALOGUE_START: &str = "start";
+pub const DIALOGUE_STOP: &str = "stop";
+pub const DIALOGUE_IS_ACTIVE: &str = "is_active";
+pub const DIALOGUE_CHOOSE: &str = "choose";
+pub const DIALOGUE_GET_VAR: &str = "get_var";
+pub const DIALOGUE_SET_VAR: &str = "set_var";
+
+// Tween module
+pub const TWEEN_FILE: &str = "tween.lua";
+pub const ENGINE_TWEEN: &str = "tween";
+pub const ENGINE_CANCEL_TWEENS: &str = "cancel_tweens";
+pub const TWEEN_ON_DONE: &str = "on_done";
+pub const TWEEN_DELAY: &str = "delay";
+pub const TWEEN_EASE: &str = "ease";
+pub const TWEEN_FROM: &str = "from";
+pub const TWEEN_YOYO: &str = "yoyo";
+pub const TWEEN_LOOP: &str = "loop";
+pub const TWEEN_THEN_TO: &str = "then_to";
+pub const TWEEN_CANCEL: &str = "cancel";
+pub const TWEEN_IS_RUNNING: &str = "is_running";
+
+// Camera module
+pub const LUA_CAMERA: &str = "camera";
+pub const CAMERA_FILE: &str = "camera.lua";
+pub const CAMERA_SHAKE: &str = "shake";
+pub const CAMERA_ZOOM: &str = "zoom";
+pub const CAMERA_PAN_TO: &str = "pan_to";
+pub const CAMERA_FOCUS: &str = "focus";
+pub const CAMERA_RELEASE: &str = "release";
+pub const CAMERA_SET_BLEND: &str = "set_blend";
+
+// Settings module
+pub const LUA_SETTINGS: &str = "settings";
+pub const SETTINGS_FILE: &str = "settings.lua";
+pub const SETTINGS_GET: &str = "get";
+pub const SETTINGS_SET: &str = "set";
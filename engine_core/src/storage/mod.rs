@@ -2,8 +2,10 @@ pub mod core_storage;
 pub mod editor_config;
 pub mod ordered_map;
 pub mod path_utils;
+pub mod player_settings;
 
 pub use core_storage::*;
 pub use editor_config::*;
 pub use ordered_map::*;
 pub use path_utils::*;
+pub use player_settings::*;
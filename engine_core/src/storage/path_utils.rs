@@ -55,11 +55,47 @@ pub fn text_folder() -> PathBuf {
     resources_folder_current().join(TEXT_FOLDER)
 }
 
+/// Path to a language's text file under `text_root`, supporting subfolders
+/// (e.g. "dialogue/npcs/npc").
+pub fn text_file_path(text_root: &Path, language: &str, text_id: &str) -> PathBuf {
+    let mut path = text_root.join(language);
+    for component in text_id.replace('\\', "/").split('/') {
+        path = path.join(component);
+    }
+    path.set_extension("toml");
+    path
+}
+
 /// Returns the path to the menus folder for the current game.
 pub fn menus_folder() -> PathBuf {
     resources_folder_current().join(MENUS_FOLDER)
 }
 
+/// Returns the path to the menu theme file for the current game.
+pub fn menu_theme_path() -> PathBuf {
+    resources_folder_current().join(MENU_THEME_FILE)
+}
+
+/// Returns the path to the dialogue graph folder for the current game.
+pub fn dialogue_folder() -> PathBuf {
+    resources_folder_current().join(DIALOGUE_FOLDER)
+}
+
+/// Returns the path to the dialogue settings file for the current game.
+pub fn dialogue_config_path() -> PathBuf {
+    resources_folder_current().join(DIALOGUE_CONFIG_FILE)
+}
+
+/// Returns the path to the animation state machine folder for the current game.
+pub fn state_machines_folder() -> PathBuf {
+    resources_folder_current().join(STATE_MACHINES_FOLDER)
+}
+
+/// Path to the fonts folder inside the resources folder (Editor/Game).
+pub fn fonts_folder() -> PathBuf {
+    resources_folder_current().join(FONTS_FOLDER)
+}
+
 /// Path to the audio folder inside the resources folder (Editor/Game).
 pub fn audio_folder() -> PathBuf {
     resources_folder_current().join(AUDIO_FOLDER)
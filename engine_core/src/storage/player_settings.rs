@@ -0,0 +1,280 @@
+// engine_core/src/storage/player_settings.rs
+use crate::onscreen_error;
+use crate::storage::path_utils::sanitise_name;
+use directories_next::ProjectDirs;
+use ron::ser::{PrettyConfig, to_string_pretty};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File the player's settings are saved to inside the game's app-data dir.
+const PLAYER_SETTINGS_FILE: &str = "settings.ron";
+
+/// Overall volume from 0 to 1.
+pub const MASTER_VOLUME_SETTING: &str = "master_volume";
+/// Music volume from 0 to 1.
+pub const MUSIC_VOLUME_SETTING: &str = "music_volume";
+/// Sound effect volume from 0 to 1.
+pub const SFX_VOLUME_SETTING: &str = "sfx_volume";
+/// Whether the window is fullscreen.
+pub const FULLSCREEN_SETTING: &str = "fullscreen";
+/// Whether presentation waits for vertical sync.
+pub const VSYNC_SETTING: &str = "vsync";
+/// Whether the game is only scaled up by whole multiples.
+pub const INTEGER_SCALING_SETTING: &str = "integer_scaling";
+
+/// Value of a single player setting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SettingValue {
+    Number(f32),
+    Bool(bool),
+    /// One option out of a named set, e.g. `"hard"`.
+    Choice(String),
+}
+
+impl SettingValue {
+    /// Returns the number, if this is a number setting.
+    pub fn as_number(&self) -> Option<f32> {
+        match self {
+            SettingValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the flag, if this is a boolean setting.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            SettingValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the option, if this is a choice setting.
+    pub fn as_choice(&self) -> Option<&str> {
+        match self {
+            SettingValue::Choice(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Settings the player has changed, saved per game and shared by every save slot.
+/// Settings that were never set fall back to the default of whatever reads them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayerSettings {
+    values: BTreeMap<String, SettingValue>,
+    /// Where the settings are saved. `None` keeps them in memory only.
+    #[serde(skip)]
+    path: Option<PathBuf>,
+    /// Whether there are changes that have not been saved.
+    #[serde(skip)]
+    dirty: bool,
+    /// Keys changed since the last drain, in the order they changed.
+    #[serde(skip)]
+    changes: Vec<String>,
+}
+
+impl PlayerSettings {
+    /// Loads the settings saved at `path`, starting empty when there are none.
+    pub fn load_from_path(path: &Path) -> Self {
+        let mut settings = match fs::read_to_string(path) {
+            Ok(txt) => ron::from_str(&txt).unwrap_or_else(|e| {
+                onscreen_error!("Could not parse player settings: {e}");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        };
+        settings.path = Some(path.to_path_buf());
+        settings
+    }
+
+    /// Returns the value stored under `key`.
+    pub fn get(&self, key: &str) -> Option<&SettingValue> {
+        self.values.get(key)
+    }
+
+    /// Returns the number stored under `key`.
+    pub fn number(&self, key: &str) -> Option<f32> {
+        self.get(key).and_then(SettingValue::as_number)
+    }
+
+    /// Returns the flag stored under `key`.
+    pub fn bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(SettingValue::as_bool)
+    }
+
+    /// Returns the option stored under `key`.
+    pub fn choice(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(SettingValue::as_choice)
+    }
+
+    /// Stores `value` under `key`. Returns true if the setting changed.
+    pub fn set(&mut self, key: &str, value: SettingValue) -> bool {
+        if self.values.get(key) == Some(&value) {
+            return false;
+        }
+        self.values.insert(key.to_string(), value);
+        self.dirty = true;
+        if !self.changes.iter().any(|changed| changed == key) {
+            self.changes.push(key.to_string());
+        }
+        true
+    }
+
+    /// Returns true if there are changes that have not been saved.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Takes the settings changed since the last call with their current values.
+    pub fn drain_changes(&mut self) -> Vec<(String, SettingValue)> {
+        std::mem::take(&mut self.changes)
+            .into_iter()
+            .filter_map(|key| {
+                let value = self.values.get(&key)?.clone();
+                Some((key, value))
+            })
+            .collect()
+    }
+
+    /// Writes unsaved changes to disk.
+    pub fn save(&mut self) -> Result<(), Box<dyn Error>> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if !self.dirty {
+            return Ok(());
+        }
+        // A failed write is reported once and retried on the next change
+        self.dirty = false;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, to_string_pretty(self, PrettyConfig::default())?)?;
+        Ok(())
+    }
+}
+
+/// Path of `file` in the OS app-data dir of `game_name`, where player data is saved.
+pub fn player_data_path(game_name: &str, file: &str) -> Option<PathBuf> {
+    ProjectDirs::from("com", "bishop", &sanitise_name(game_name))
+        .map(|dirs| dirs.data_dir().join(file))
+}
+
+/// Path of the settings file for `game_name` in the OS app-data dir.
+pub fn player_settings_path(game_name: &str) -> Option<PathBuf> {
+    player_data_path(game_name, PLAYER_SETTINGS_FILE)
+}
+
+thread_local! {
+    static PLAYER_SETTINGS: RefCell<PlayerSettings> = RefCell::new(PlayerSettings::default());
+}
+
+/// Replaces the current settings with the ones saved for `game_name`.
+pub fn load_player_settings(game_name: &str) {
+    let settings = match player_settings_path(game_name) {
+        Some(path) => PlayerSettings::load_from_path(&path),
+        None => {
+            onscreen_error!("Could not resolve the player settings folder.");
+            PlayerSettings::default()
+        }
+    };
+    PLAYER_SETTINGS.with(|cell| *cell.borrow_mut() = settings);
+}
+
+/// Returns the player setting stored under `key`.
+pub fn player_setting(key: &str) -> Option<SettingValue> {
+    PLAYER_SETTINGS.with(|cell| cell.borrow().get(key).cloned())
+}
+
+/// Returns the number stored under `key`, or `default` when it is unset.
+pub fn player_setting_number(key: &str, default: f32) -> f32 {
+    PLAYER_SETTINGS.with(|cell| cell.borrow().number(key).unwrap_or(default))
+}
+
+/// Returns the flag stored under `key`, or `default` when it is unset.
+pub fn player_setting_bool(key: &str, default: bool) -> bool {
+    PLAYER_SETTINGS.with(|cell| cell.borrow().bool(key).unwrap_or(default))
+}
+
+/// Stores a player setting. Returns true if it changed.
+pub fn set_player_setting(key: &str, value: SettingValue) -> bool {
+    PLAYER_SETTINGS.with(|cell| cell.borrow_mut().set(key, value))
+}
+
+/// Takes the player settings changed since the last call.
+pub fn drain_player_setting_changes() -> Vec<(String, SettingValue)> {
+    PLAYER_SETTINGS.with(|cell| cell.borrow_mut().drain_changes())
+}
+
+/// Writes unsaved player settings to disk.
+pub fn save_player_settings() {
+    PLAYER_SETTINGS.with(|cell| {
+        if let Err(e) = cell.borrow_mut().save() {
+            onscreen_error!("Error saving player settings: {e}");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn changes_are_reported_once_and_only_when_the_value_differs() {
+        let mut settings = PlayerSettings::default();
+        assert!(settings.set(MUSIC_VOLUME_SETTING, SettingValue::Number(0.5)));
+        assert!(settings.set(FULLSCREEN_SETTING, SettingValue::Bool(true)));
+        assert!(settings.set(MUSIC_VOLUME_SETTING, SettingValue::Number(0.25)));
+        assert!(!settings.set(FULLSCREEN_SETTING, SettingValue::Bool(true)));
+
+        assert_eq!(
+            settings.drain_changes(),
+            vec![
+                (MUSIC_VOLUME_SETTING.to_string(), SettingValue::Number(0.25)),
+                (FULLSCREEN_SETTING.to_string(), SettingValue::Bool(true)),
+            ]
+        );
+        assert!(settings.drain_changes().is_empty());
+        assert_eq!(settings.number(FULLSCREEN_SETTING), None);
+    }
+
+    #[test]
+    fn settings_round_trip_through_disk() {
+        let path = std::env::temp_dir()
+            .join(format!("player_settings_{}", Uuid::new_v4()))
+            .join(PLAYER_SETTINGS_FILE);
+
+        let mut settings = PlayerSettings::load_from_path(&path);
+        settings.set("difficulty", SettingValue::Choice("hard".to_string()));
+        settings.set(VSYNC_SETTING, SettingValue::Bool(false));
+        settings.save().unwrap();
+        assert!(!settings.is_dirty());
+
+        let loaded = PlayerSettings::load_from_path(&path);
+        assert_eq!(loaded.choice("difficulty"), Some("hard"));
+        assert_eq!(loaded.bool(VSYNC_SETTING), Some(false));
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn failed_saves_are_reported_once_per_change() {
+        // A file where the settings folder should be makes every write fail
+        let blocker = std::env::temp_dir().join(format!("player_settings_{}", Uuid::new_v4()));
+        fs::write(&blocker, "").unwrap();
+        let mut settings = PlayerSettings::load_from_path(&blocker.join(PLAYER_SETTINGS_FILE));
+
+        settings.set(MUSIC_VOLUME_SETTING, SettingValue::Number(0.5));
+        assert!(settings.save().is_err());
+        assert!(settings.save().is_ok());
+        settings.set(MUSIC_VOLUME_SETTING, SettingValue::Number(0.25));
+        assert!(settings.save().is_err());
+
+        let _ = fs::remove_file(&blocker);
+    }
+}
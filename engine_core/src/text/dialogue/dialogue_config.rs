@@ -1,13 +1,23 @@
 // engine_core/src/text/dialogue/dialogue_config.rs
+use crate::assets::asset_manager::AssetManager;
+use crate::assets::sprite::SpriteId;
+use crate::menu::InputBinding;
+use crate::onscreen_error;
+use bishop::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
 
 /// Global configuration for the dialogue system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DialogueConfig {
     /// Default duration in seconds for speech bubbles.
     pub default_duration: f32,
     /// Default font size for speech text.
     pub font_size: f32,
+    /// Default font name for speech text. Empty uses the default font.
+    pub font: String,
     /// Default maximum width before word wrap (in pixels).
     pub max_width: f32,
     /// Default vertical offset from entity position (negative = above).
@@ -20,6 +30,20 @@ pub struct DialogueConfig {
     pub default_background_color: [f32; 4],
     /// Whether to show background by default.
     pub show_background: bool,
+    /// Sprite path drawn behind bubbles instead of the background color. Empty uses the color.
+    pub background_sprite: String,
+    /// How the background sprite is cut so its frame keeps its size as bubbles grow.
+    pub background_slice: NineSlice,
+    /// Default typewriter speed in characters per second. Zero shows text instantly.
+    pub reveal_speed: f32,
+    /// Whether bubbles stay open until the advance input instead of using a timer.
+    pub wait_for_input: bool,
+    /// Input that completes a reveal, or dismisses a bubble waiting for input.
+    pub advance_input: InputBinding,
+    /// Sprite loaded from `background_sprite`, `SpriteId(0)` when it failed to load.
+    /// `None` until first needed.
+    #[serde(skip)]
+    background_sprite_id: Option<SpriteId>,
 }
 
 impl Default for DialogueConfig {
@@ -27,12 +51,54 @@ impl Default for DialogueConfig {
         Self {
             default_duration: 3.0,
             font_size: 5.0,
+            font: String::new(),
             max_width: 50.0,
             default_offset_y: -5.0,
             padding: 2.0,
             default_color: [1.0, 1.0, 1.0, 1.0],
             default_background_color: [0.0, 0.0, 0.0, 0.7],
             show_background: false,
+            background_sprite: String::new(),
+            background_slice: NineSlice::default(),
+            reveal_speed: 0.0,
+            wait_for_input: false,
+            advance_input: InputBinding::keyboard_with_alt(KeyCode::Space, KeyCode::Enter),
+            background_sprite_id: None,
         }
     }
 }
+
+impl DialogueConfig {
+    /// Loads the config at `path`. A missing file gives the defaults.
+    pub fn load_from_path(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(txt) => ron::from_str(&txt).unwrap_or_else(|e| {
+                onscreen_error!("Could not parse dialogue config: {e}");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Sets the background sprite path. It is loaded again when next drawn.
+    pub fn set_background_sprite(&mut self, path: String) {
+        self.background_sprite = path;
+        self.background_sprite_id = None;
+    }
+
+    /// Returns the background sprite, loading it the first time it is needed.
+    /// A path that fails to load is reported once, then bubbles use the colour.
+    pub fn background_sprite_id(
+        &mut self,
+        loader: &impl TextureLoader,
+        asset_manager: &mut AssetManager,
+    ) -> Option<SpriteId> {
+        let path = &self.background_sprite;
+        let sprite = *self.background_sprite_id.get_or_insert_with(|| {
+            asset_manager
+                .get_or_load(loader, path)
+                .unwrap_or(SpriteId(0))
+        });
+        (sprite.0 != 0).then_some(sprite)
+    }
+}
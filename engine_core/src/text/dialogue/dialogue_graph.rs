@@ -0,0 +1,339 @@
+// engine_core/src/text/dialogue/dialogue_graph.rs
+use crate::storage::path_utils::dialogue_folder;
+use crate::text::dialogue::dialogue_variables::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Identifier of a node within a single dialogue graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DialogueNodeId(pub u32);
+
+/// A branching conversation stored under `Resources/dialogue/<id>.ron`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DialogueGraph {
+    /// Graph id, matching its file path relative to the dialogue folder.
+    pub id: String,
+    /// Text file (relative to `Resources/text/<lang>/`) that line and choice keys resolve against.
+    pub text_id: String,
+    /// Node the conversation starts at.
+    pub start: Option<DialogueNodeId>,
+    /// All nodes in the graph.
+    pub nodes: Vec<DialogueNode>,
+}
+
+/// A single node in a dialogue graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialogueNode {
+    pub id: DialogueNodeId,
+    pub kind: DialogueNodeKind,
+    /// Canvas position used by the editor.
+    #[serde(default)]
+    pub position: (f32, f32),
+}
+
+/// The behaviour of a dialogue node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DialogueNodeKind {
+    /// A line spoken by a participant.
+    Line {
+        /// Participant role that speaks the line (e.g. `"npc"`).
+        speaker: String,
+        /// Key into the graph's text file.
+        key: String,
+        next: Option<DialogueNodeId>,
+    },
+    /// A set of options for the player to pick from.
+    Choice { options: Vec<DialogueChoice> },
+    /// Branches on a variable comparison.
+    Condition {
+        condition: DialogueCondition,
+        if_true: Option<DialogueNodeId>,
+        if_false: Option<DialogueNodeId>,
+    },
+    /// Writes a value into the dialogue variable store.
+    SetVariable {
+        name: String,
+        op: DialogueVariableOp,
+        value: DialogueValue,
+        next: Option<DialogueNodeId>,
+    },
+    /// Emits a named event on the script event bus.
+    Event {
+        name: String,
+        next: Option<DialogueNodeId>,
+    },
+    /// Ends the conversation.
+    End,
+}
+
+impl DialogueNodeKind {
+    /// Returns a human-readable label for this node kind.
+    pub fn ui_label(&self) -> &'static str {
+        match self {
+            DialogueNodeKind::Line { .. } => "Line",
+            DialogueNodeKind::Choice { .. } => "Choice",
+            DialogueNodeKind::Condition { .. } => "Condition",
+            DialogueNodeKind::SetVariable { .. } => "Set Variable",
+            DialogueNodeKind::Event { .. } => "Event",
+            DialogueNodeKind::End => "End",
+        }
+    }
+
+    /// Returns every node this node can lead to.
+    pub fn successors(&self) -> Vec<DialogueNodeId> {
+        match self {
+            DialogueNodeKind::Line { next, .. }
+            | DialogueNodeKind::SetVariable { next, .. }
+            | DialogueNodeKind::Event { next, .. } => next.iter().copied().collect(),
+            DialogueNodeKind::Choice { options } => {
+                options.iter().filter_map(|option| option.next).collect()
+            }
+            DialogueNodeKind::Condition {
+                if_true, if_false, ..
+            } => if_true.iter().chain(if_false.iter()).copied().collect(),
+            DialogueNodeKind::End => Vec::new(),
+        }
+    }
+
+    /// Returns the outgoing edge slots of this node as `(label, target)` pairs.
+    pub fn outputs(&self) -> Vec<(String, Option<DialogueNodeId>)> {
+        match self {
+            DialogueNodeKind::Line { next, .. }
+            | DialogueNodeKind::SetVariable { next, .. }
+            | DialogueNodeKind::Event { next, .. } => vec![("next".to_string(), *next)],
+            DialogueNodeKind::Choice { options } => options
+                .iter()
+                .map(|option| (option.key.clone(), option.next))
+                .collect(),
+            DialogueNodeKind::Condition {
+                if_true, if_false, ..
+            } => vec![
+                ("true".to_string(), *if_true),
+                ("false".to_string(), *if_false),
+            ],
+            DialogueNodeKind::End => Vec::new(),
+        }
+    }
+
+    /// Points the given output slot at `target`. Returns false if the slot does not exist.
+    pub fn set_output(&mut self, slot: usize, target: Option<DialogueNodeId>) -> bool {
+        let edge = match self {
+            DialogueNodeKind::Line { next, .. }
+            | DialogueNodeKind::SetVariable { next, .. }
+            | DialogueNodeKind::Event { next, .. }
+                if slot == 0 =>
+            {
+                next
+            }
+            DialogueNodeKind::Choice { options } => match options.get_mut(slot) {
+                Some(option) => &mut option.next,
+                None => return false,
+            },
+            DialogueNodeKind::Condition { if_true, .. } if slot == 0 => if_true,
+            DialogueNodeKind::Condition { if_false, .. } if slot == 1 => if_false,
+            _ => return false,
+        };
+        *edge = target;
+        true
+    }
+
+    /// Returns the text keys this node resolves against the graph's text file.
+    pub fn text_keys(&self) -> Vec<&str> {
+        match self {
+            DialogueNodeKind::Line { key, .. } => vec![key.as_str()],
+            DialogueNodeKind::Choice { options } => {
+                options.iter().map(|option| option.key.as_str()).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A player option on a choice node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DialogueChoice {
+    /// Key into the graph's text file for the option label.
+    pub key: String,
+    pub next: Option<DialogueNodeId>,
+    /// Option is only offered when this condition holds.
+    #[serde(default)]
+    pub condition: Option<DialogueCondition>,
+}
+
+impl DialogueGraph {
+    /// Creates an empty graph.
+    pub fn new(id: &str, text_id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            text_id: text_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the node with the given id.
+    pub fn node(&self, id: DialogueNodeId) -> Option<&DialogueNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Returns the node with the given id mutably.
+    pub fn node_mut(&mut self, id: DialogueNodeId) -> Option<&mut DialogueNode> {
+        self.nodes.iter_mut().find(|node| node.id == id)
+    }
+
+    /// Returns an id not used by any node in the graph.
+    pub fn next_node_id(&self) -> DialogueNodeId {
+        let max = self.nodes.iter().map(|node| node.id.0).max();
+        DialogueNodeId(max.map_or(0, |id| id + 1))
+    }
+
+    /// Adds a node and returns its id. The first node added becomes the start node.
+    pub fn add_node(&mut self, kind: DialogueNodeKind, position: (f32, f32)) -> DialogueNodeId {
+        let id = self.next_node_id();
+        self.nodes.push(DialogueNode { id, kind, position });
+        if self.start.is_none() {
+            self.start = Some(id);
+        }
+        id
+    }
+
+    /// Removes a node, clearing every edge that pointed at it.
+    pub fn remove_node(&mut self, id: DialogueNodeId) -> Option<DialogueNode> {
+        let index = self.nodes.iter().position(|node| node.id == id)?;
+        let removed = self.nodes.remove(index);
+
+        for node in &mut self.nodes {
+            for (slot, (_, target)) in node.kind.outputs().into_iter().enumerate() {
+                if target == Some(id) {
+                    node.kind.set_output(slot, None);
+                }
+            }
+        }
+        if self.start == Some(id) {
+            self.start = None;
+        }
+        Some(removed)
+    }
+
+    /// Returns every node that can be reached from the start node.
+    pub fn reachable_nodes(&self) -> HashSet<DialogueNodeId> {
+        let mut reachable = HashSet::new();
+        let mut stack: Vec<DialogueNodeId> = self.start.into_iter().collect();
+
+        while let Some(id) = stack.pop() {
+            let Some(node) = self.node(id) else {
+                continue;
+            };
+            if reachable.insert(id) {
+                stack.extend(node.kind.successors());
+            }
+        }
+        reachable
+    }
+}
+
+/// Returns the on-disk path for a dialogue graph id.
+/// Ids that would leave the dialogue folder, such as ones containing `..`, are rejected.
+pub fn dialogue_path(id: &str) -> Result<PathBuf, String> {
+    let mut path = dialogue_folder();
+    for component in id.replace('\\', "/").split('/') {
+        let mut parts = Path::new(component).components();
+        let (Some(Component::Normal(part)), None) = (parts.next(), parts.next()) else {
+            return Err(format!("Invalid dialogue id '{id}'"));
+        };
+        path = path.join(part);
+    }
+    path.set_extension("ron");
+    Ok(path)
+}
+
+/// Loads a dialogue graph by id from the dialogue folder.
+pub fn load_dialogue_graph(id: &str) -> Result<DialogueGraph, String> {
+    let path = dialogue_path(id)?;
+    let ron_str = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read dialogue '{}': {e}", path.display()))?;
+    let mut graph: DialogueGraph = ron::de::from_str(&ron_str)
+        .map_err(|e| format!("Failed to parse dialogue '{}': {e}", path.display()))?;
+    graph.id = id.to_string();
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(next: Option<DialogueNodeId>) -> DialogueNodeKind {
+        DialogueNodeKind::Line {
+            speaker: "npc".to_string(),
+            key: "hello".to_string(),
+            next,
+        }
+    }
+
+    #[test]
+    fn set_output_targets_condition_branches_by_slot() {
+        let mut kind = DialogueNodeKind::Condition {
+            condition: DialogueCondition {
+                variable: "met".to_string(),
+                comparison: DialogueComparison::IsTrue,
+                value: DialogueValue::Bool(true),
+            },
+            if_true: None,
+            if_false: None,
+        };
+
+        assert!(kind.set_output(1, Some(DialogueNodeId(4))));
+        assert!(!kind.set_output(2, Some(DialogueNodeId(5))));
+        assert_eq!(
+            kind.outputs(),
+            vec![
+                ("true".to_string(), None),
+                ("false".to_string(), Some(DialogueNodeId(4))),
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_node_clears_incoming_edges_and_start() {
+        let mut graph = DialogueGraph::new("npc", "dialogue/npc");
+        let first = graph.add_node(line(None), (0.0, 0.0));
+        let second = graph.add_node(line(None), (0.0, 0.0));
+        graph
+            .node_mut(second)
+            .unwrap()
+            .kind
+            .set_output(0, Some(first));
+
+        assert!(graph.remove_node(first).is_some());
+
+        assert_eq!(graph.start, None);
+        assert_eq!(graph.node(second).unwrap().kind.successors(), Vec::new());
+    }
+
+    #[test]
+    fn reachable_nodes_follows_edges_from_start() {
+        let mut graph = DialogueGraph::new("npc", "dialogue/npc");
+        let first = graph.add_node(line(Some(DialogueNodeId(1))), (0.0, 0.0));
+        let second = graph.add_node(line(None), (0.0, 0.0));
+        let orphan = graph.add_node(line(Some(first)), (0.0, 0.0));
+
+        let reachable = graph.reachable_nodes();
+
+        assert!(reachable.contains(&first));
+        assert!(reachable.contains(&second));
+        assert!(!reachable.contains(&orphan));
+    }
+
+    #[test]
+    fn dialogue_ids_cannot_leave_the_dialogue_folder() {
+        let path = dialogue_path("town/npc").unwrap();
+        assert!(path.starts_with(dialogue_folder()));
+        assert!(path.ends_with("town/npc.ron"));
+
+        for id in ["../settings", "town/../../npc", "./npc", "", "town//npc"] {
+            assert!(dialogue_path(id).is_err(), "{id}");
+        }
+    }
+}
@@ -0,0 +1,408 @@
+// engine_core/src/text/dialogue/dialogue_runner.rs
+use crate::ecs::entity::Entity;
+use crate::text::dialogue::dialogue_graph::*;
+use crate::text::dialogue::dialogue_variables::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Prefix of the custom menu action emitted by dialogue choice buttons.
+pub const DIALOGUE_CHOICE_ACTION: &str = "dialogue_choice:";
+
+/// An option offered to the player by a choice node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DialogueChoiceView {
+    /// Index of the option within the choice node.
+    pub index: usize,
+    /// Key into the graph's text file for the option label.
+    pub key: String,
+}
+
+/// Something the presentation layer has to act on.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DialogueStep {
+    /// Show a line. `speaker` is `None` when the role has no participant.
+    Line {
+        speaker: Option<Entity>,
+        role: String,
+        key: String,
+    },
+    /// Offer the given options and wait for [`DialogueRunner::choose`].
+    Choices(Vec<DialogueChoiceView>),
+    /// Emit an event and keep going.
+    Event(String),
+    /// The conversation is over.
+    Finished,
+}
+
+/// Walks a [`DialogueGraph`], resolving conditions and variable writes internally
+/// and stopping at each node that needs presenting.
+pub struct DialogueRunner {
+    graph: DialogueGraph,
+    participants: HashMap<String, Entity>,
+    current: Option<DialogueNodeId>,
+    awaiting_choice: bool,
+}
+
+impl DialogueRunner {
+    /// Creates a runner positioned at the graph's start node.
+    pub fn new(graph: DialogueGraph, participants: HashMap<String, Entity>) -> Self {
+        let current = graph.start;
+        Self {
+            graph,
+            participants,
+            current,
+            awaiting_choice: false,
+        }
+    }
+
+    /// Returns the graph being run.
+    pub fn graph(&self) -> &DialogueGraph {
+        &self.graph
+    }
+
+    /// Returns the entity playing the given role.
+    pub fn participant(&self, role: &str) -> Option<Entity> {
+        self.participants.get(role).copied()
+    }
+
+    /// Returns whether the runner is waiting for a choice.
+    pub fn is_awaiting_choice(&self) -> bool {
+        self.awaiting_choice
+    }
+
+    /// Advances to the next node that needs presenting.
+    pub fn next_step(&mut self, variables: &mut DialogueVariables) -> DialogueStep {
+        if self.awaiting_choice {
+            return DialogueStep::Choices(self.visible_choices(variables));
+        }
+
+        // Bound the number of silent hops so a cycle of conditions cannot hang the game.
+        let mut hops = 0;
+        while let Some(id) = self.current {
+            hops += 1;
+            if hops > self.graph.nodes.len() + 1 {
+                log::warn!(
+                    "Dialogue '{}' looped without presenting anything",
+                    self.graph.id
+                );
+                break;
+            }
+
+            let Some(node) = self.graph.node(id) else {
+                log::warn!(
+                    "Dialogue '{}' references missing node {:?}",
+                    self.graph.id,
+                    id
+                );
+                break;
+            };
+
+            match node.kind.clone() {
+                DialogueNodeKind::Line { speaker, key, next } => {
+                    self.current = next;
+                    return DialogueStep::Line {
+                        speaker: self.participant(&speaker),
+                        role: speaker,
+                        key,
+                    };
+                }
+                DialogueNodeKind::Choice { .. } => {
+                    let choices = self.visible_choices(variables);
+                    if choices.is_empty() {
+                        break;
+                    }
+                    self.awaiting_choice = true;
+                    return DialogueStep::Choices(choices);
+                }
+                DialogueNodeKind::Condition {
+                    condition,
+                    if_true,
+                    if_false,
+                } => {
+                    self.current = if condition.evaluate(variables) {
+                        if_true
+                    } else {
+                        if_false
+                    };
+                }
+                DialogueNodeKind::SetVariable {
+                    name,
+                    op,
+                    value,
+                    next,
+                } => {
+                    variables.apply(&name, op, &value);
+                    self.current = next;
+                }
+                DialogueNodeKind::Event { name, next } => {
+                    self.current = next;
+                    return DialogueStep::Event(name);
+                }
+                DialogueNodeKind::End => break,
+            }
+        }
+
+        self.current = None;
+        DialogueStep::Finished
+    }
+
+    /// Picks an option on the current choice node. Returns false if the index
+    /// is not one of the offered options.
+    pub fn choose(&mut self, index: usize, variables: &DialogueVariables) -> bool {
+        if !self.awaiting_choice {
+            return false;
+        }
+
+        let Some(DialogueNodeKind::Choice { options }) = self
+            .current
+            .and_then(|id| self.graph.node(id))
+            .map(|node| &node.kind)
+        else {
+            return false;
+        };
+
+        let Some(option) = options.get(index) else {
+            return false;
+        };
+        if option
+            .condition
+            .as_ref()
+            .is_some_and(|condition| !condition.evaluate(variables))
+        {
+            return false;
+        }
+
+        self.current = option.next;
+        self.awaiting_choice = false;
+        true
+    }
+
+    /// Stops the conversation.
+    pub fn stop(&mut self) {
+        self.current = None;
+        self.awaiting_choice = false;
+    }
+
+    fn visible_choices(&self, variables: &DialogueVariables) -> Vec<DialogueChoiceView> {
+        let Some(DialogueNodeKind::Choice { options }) = self
+            .current
+            .and_then(|id| self.graph.node(id))
+            .map(|node| &node.kind)
+        else {
+            return Vec::new();
+        };
+
+        options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| {
+                option
+                    .condition
+                    .as_ref()
+                    .is_none_or(|condition| condition.evaluate(variables))
+            })
+            .map(|(index, option)| DialogueChoiceView {
+                index,
+                key: option.key.clone(),
+            })
+            .collect()
+    }
+}
+
+thread_local! {
+    static DIALOGUE_CHOICES: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Queues a choice pick for the active dialogue.
+pub fn push_dialogue_choice(index: usize) {
+    DIALOGUE_CHOICES.with(|choices| choices.borrow_mut().push(index));
+}
+
+/// Drains all pending dialogue choice picks.
+pub fn drain_dialogue_choices() -> Vec<usize> {
+    DIALOGUE_CHOICES.with(|choices| choices.borrow_mut().drain(..).collect())
+}
+
+/// Parses the option index out of a dialogue choice menu action.
+pub fn parse_dialogue_choice_action(action: &str) -> Option<usize> {
+    action.strip_prefix(DIALOGUE_CHOICE_ACTION)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(speaker: &str, key: &str, next: Option<u32>) -> DialogueNodeKind {
+        DialogueNodeKind::Line {
+            speaker: speaker.to_string(),
+            key: key.to_string(),
+            next: next.map(DialogueNodeId),
+        }
+    }
+
+    fn met_condition() -> DialogueCondition {
+        DialogueCondition {
+            variable: "met".to_string(),
+            comparison: DialogueComparison::IsTrue,
+            value: DialogueValue::Bool(true),
+        }
+    }
+
+    /// 0: condition(met) -> 1 / 2
+    /// 1: npc "again" -> end
+    /// 2: set met -> 3
+    /// 3: npc "hello" -> 4
+    /// 4: choice [bye -> 5, secret(if met) -> 6]
+    /// 5: event "waved" -> end
+    /// 6: player "secret" -> end
+    fn sample_graph() -> DialogueGraph {
+        let mut graph = DialogueGraph::new("npc", "dialogue/npc");
+        graph.add_node(
+            DialogueNodeKind::Condition {
+                condition: met_condition(),
+                if_true: Some(DialogueNodeId(1)),
+                if_false: Some(DialogueNodeId(2)),
+            },
+            (0.0, 0.0),
+        );
+        graph.add_node(line("npc", "again", None), (0.0, 0.0));
+        graph.add_node(
+            DialogueNodeKind::SetVariable {
+                name: "met".to_string(),
+                op: DialogueVariableOp::Set,
+                value: DialogueValue::Bool(true),
+                next: Some(DialogueNodeId(3)),
+            },
+            (0.0, 0.0),
+        );
+        graph.add_node(line("npc", "hello", Some(4)), (0.0, 0.0));
+        graph.add_node(
+            DialogueNodeKind::Choice {
+                options: vec![
+                    DialogueChoice {
+                        key: "bye".to_string(),
+                        next: Some(DialogueNodeId(5)),
+                        condition: None,
+                    },
+                    DialogueChoice {
+                        key: "secret".to_string(),
+                        next: Some(DialogueNodeId(6)),
+                        condition: Some(DialogueCondition {
+                            variable: "gold".to_string(),
+                            comparison: DialogueComparison::GreaterOrEqual,
+                            value: DialogueValue::Number(10.0),
+                        }),
+                    },
+                ],
+            },
+            (0.0, 0.0),
+        );
+        graph.add_node(
+            DialogueNodeKind::Event {
+                name: "waved".to_string(),
+                next: None,
+            },
+            (0.0, 0.0),
+        );
+        graph.add_node(line("player", "secret", None), (0.0, 0.0));
+        graph
+    }
+
+    fn participants() -> HashMap<String, Entity> {
+        HashMap::from([("npc".to_string(), Entity(7))])
+    }
+
+    #[test]
+    fn first_meeting_sets_variable_and_offers_unconditional_choices() {
+        let mut runner = DialogueRunner::new(sample_graph(), participants());
+        let mut vars = DialogueVariables::default();
+
+        assert_eq!(
+            runner.next_step(&mut vars),
+            DialogueStep::Line {
+                speaker: Some(Entity(7)),
+                role: "npc".to_string(),
+                key: "hello".to_string(),
+            }
+        );
+        assert_eq!(vars.get("met"), Some(&DialogueValue::Bool(true)));
+
+        let DialogueStep::Choices(choices) = runner.next_step(&mut vars) else {
+            panic!("expected choices");
+        };
+        assert_eq!(choices.len(), 1);
+        assert_eq!(choices[0].key, "bye");
+        assert!(runner.is_awaiting_choice());
+
+        assert!(!runner.choose(1, &vars));
+        assert!(runner.choose(0, &vars));
+        assert_eq!(
+            runner.next_step(&mut vars),
+            DialogueStep::Event("waved".to_string())
+        );
+        assert_eq!(runner.next_step(&mut vars), DialogueStep::Finished);
+    }
+
+    #[test]
+    fn later_meeting_takes_condition_branch() {
+        let mut runner = DialogueRunner::new(sample_graph(), participants());
+        let mut vars = DialogueVariables::default();
+        vars.set("met", DialogueValue::Bool(true));
+
+        assert!(matches!(
+            runner.next_step(&mut vars),
+            DialogueStep::Line { ref key, .. } if key == "again"
+        ));
+        assert_eq!(runner.next_step(&mut vars), DialogueStep::Finished);
+    }
+
+    #[test]
+    fn conditional_choice_is_offered_when_condition_holds() {
+        let mut graph = sample_graph();
+        graph.start = Some(DialogueNodeId(4));
+        let mut runner = DialogueRunner::new(graph, participants());
+        let mut vars = DialogueVariables::default();
+        vars.set("gold", DialogueValue::Number(12.0));
+
+        let DialogueStep::Choices(choices) = runner.next_step(&mut vars) else {
+            panic!("expected choices");
+        };
+        assert_eq!(choices.len(), 2);
+
+        assert!(runner.choose(1, &vars));
+        assert_eq!(
+            runner.next_step(&mut vars),
+            DialogueStep::Line {
+                speaker: None,
+                role: "player".to_string(),
+                key: "secret".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn condition_cycle_finishes_instead_of_hanging() {
+        let mut graph = DialogueGraph::new("loop", "dialogue/loop");
+        graph.add_node(
+            DialogueNodeKind::Condition {
+                condition: met_condition(),
+                if_true: Some(DialogueNodeId(0)),
+                if_false: Some(DialogueNodeId(0)),
+            },
+            (0.0, 0.0),
+        );
+        let mut runner = DialogueRunner::new(graph, HashMap::new());
+
+        assert_eq!(
+            runner.next_step(&mut DialogueVariables::default()),
+            DialogueStep::Finished
+        );
+    }
+
+    #[test]
+    fn parse_dialogue_choice_action_reads_index() {
+        assert_eq!(parse_dialogue_choice_action("dialogue_choice:3"), Some(3));
+        assert_eq!(parse_dialogue_choice_action("dialogue_choice:x"), None);
+        assert_eq!(parse_dialogue_choice_action("resume"), None);
+    }
+}
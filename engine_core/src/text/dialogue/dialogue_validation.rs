@@ -0,0 +1,152 @@
+// engine_core/src/text/dialogue/dialogue_validation.rs
+use crate::text::TextFile;
+use crate::text::dialogue::dialogue_graph::*;
+
+/// A problem found in a dialogue graph.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DialogueIssue {
+    /// The graph has nodes but no start node.
+    NoStartNode,
+    /// An edge points at a node that does not exist.
+    MissingTarget {
+        node: DialogueNodeId,
+        target: DialogueNodeId,
+    },
+    /// The node cannot be reached from the start node.
+    Unreachable(DialogueNodeId),
+    /// A line or choice key is not present in the graph's text file.
+    MissingTextKey { node: DialogueNodeId, key: String },
+}
+
+impl DialogueIssue {
+    /// Returns the node the issue is attached to, if any.
+    pub fn node(&self) -> Option<DialogueNodeId> {
+        match self {
+            DialogueIssue::NoStartNode => None,
+            DialogueIssue::MissingTarget { node, .. }
+            | DialogueIssue::MissingTextKey { node, .. } => Some(*node),
+            DialogueIssue::Unreachable(node) => Some(*node),
+        }
+    }
+
+    /// Returns a short description for display in the editor.
+    pub fn message(&self) -> String {
+        match self {
+            DialogueIssue::NoStartNode => "No start node".to_string(),
+            DialogueIssue::MissingTarget { node, target } => {
+                format!("Node {} links to missing node {}", node.0, target.0)
+            }
+            DialogueIssue::Unreachable(node) => format!("Node {} is unreachable", node.0),
+            DialogueIssue::MissingTextKey { node, key } => {
+                format!("Node {}: missing text key '{key}'", node.0)
+            }
+        }
+    }
+}
+
+/// Checks a graph for unreachable nodes, dangling edges and, when a text file
+/// is given, keys that have no entry in it.
+pub fn validate_dialogue_graph(
+    graph: &DialogueGraph,
+    text: Option<&TextFile>,
+) -> Vec<DialogueIssue> {
+    let mut issues = Vec::new();
+
+    if graph.start.is_none() && !graph.nodes.is_empty() {
+        issues.push(DialogueIssue::NoStartNode);
+    }
+
+    let reachable = graph.reachable_nodes();
+    for node in &graph.nodes {
+        for target in node.kind.successors() {
+            if graph.node(target).is_none() {
+                issues.push(DialogueIssue::MissingTarget {
+                    node: node.id,
+                    target,
+                });
+            }
+        }
+
+        if graph.start.is_some() && !reachable.contains(&node.id) {
+            issues.push(DialogueIssue::Unreachable(node.id));
+        }
+
+        if let Some(text) = text {
+            for key in node.kind.text_keys() {
+                if !text.entries.contains_key(key) {
+                    issues.push(DialogueIssue::MissingTextKey {
+                        node: node.id,
+                        key: key.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::TextEntry;
+
+    fn line(key: &str, next: Option<DialogueNodeId>) -> DialogueNodeKind {
+        DialogueNodeKind::Line {
+            speaker: "npc".to_string(),
+            key: key.to_string(),
+            next,
+        }
+    }
+
+    #[test]
+    fn flags_unreachable_nodes_and_dangling_edges() {
+        let mut graph = DialogueGraph::new("npc", "dialogue/npc");
+        let start = graph.add_node(line("hello", Some(DialogueNodeId(9))), (0.0, 0.0));
+        let orphan = graph.add_node(line("bye", None), (0.0, 0.0));
+
+        let issues = validate_dialogue_graph(&graph, None);
+
+        assert_eq!(
+            issues,
+            vec![
+                DialogueIssue::MissingTarget {
+                    node: start,
+                    target: DialogueNodeId(9),
+                },
+                DialogueIssue::Unreachable(orphan),
+            ]
+        );
+    }
+
+    #[test]
+    fn flags_keys_missing_from_text_file() {
+        let mut graph = DialogueGraph::new("npc", "dialogue/npc");
+        let start = graph.add_node(line("hello", None), (0.0, 0.0));
+        let mut text = TextFile::default();
+        text.entries
+            .insert("greeting".to_string(), TextEntry::default());
+
+        let issues = validate_dialogue_graph(&graph, Some(&text));
+
+        assert_eq!(
+            issues,
+            vec![DialogueIssue::MissingTextKey {
+                node: start,
+                key: "hello".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn graph_without_start_reports_missing_start_only() {
+        let mut graph = DialogueGraph::new("npc", "dialogue/npc");
+        graph.add_node(DialogueNodeKind::End, (0.0, 0.0));
+        graph.start = None;
+
+        assert_eq!(
+            validate_dialogue_graph(&graph, None),
+            vec![DialogueIssue::NoStartNode]
+        );
+    }
+}
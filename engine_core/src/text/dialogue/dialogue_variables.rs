@@ -0,0 +1,323 @@
+// engine_core/src/text/dialogue/dialogue_variables.rs
+use crate::onscreen_error;
+use crate::storage::player_settings::player_data_path;
+use crate::text::message_format::TextValue;
+use ron::ser::{PrettyConfig, to_string_pretty};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File the dialogue variables are saved to inside the game's app-data dir.
+const DIALOGUE_VARIABLES_FILE: &str = "dialogue_variables.ron";
+
+/// A value stored in the dialogue variable store.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DialogueValue {
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+impl Default for DialogueValue {
+    fn default() -> Self {
+        DialogueValue::Bool(false)
+    }
+}
+
+impl DialogueValue {
+    /// Returns whether the value counts as true in a condition.
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            DialogueValue::Bool(b) => *b,
+            DialogueValue::Number(n) => *n != 0.0,
+            DialogueValue::Text(s) => !s.is_empty(),
+        }
+    }
+
+    /// Returns the value formatted for text interpolation.
+    pub fn to_display_string(&self) -> String {
+        match self {
+            DialogueValue::Bool(b) => b.to_string(),
+            DialogueValue::Number(n) if n.fract() == 0.0 => format!("{}", *n as i64),
+            DialogueValue::Number(n) => n.to_string(),
+            DialogueValue::Text(s) => s.clone(),
+        }
+    }
+}
+
+impl From<&DialogueValue> for TextValue {
+    fn from(value: &DialogueValue) -> Self {
+        match value {
+            DialogueValue::Bool(b) => TextValue::Bool(*b),
+            DialogueValue::Number(n) => TextValue::Number(*n),
+            DialogueValue::Text(s) => TextValue::Text(s.clone()),
+        }
+    }
+}
+
+/// How a set-variable node combines its value with the stored one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DialogueVariableOp {
+    /// Replace the stored value.
+    #[default]
+    Set,
+    /// Add to a stored number (missing values count as zero).
+    Add,
+    /// Flip a stored bool (the node value is ignored).
+    Toggle,
+}
+
+/// Comparison used by condition nodes and conditional choices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DialogueComparison {
+    #[default]
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    /// True when the variable is truthy; the condition value is ignored.
+    IsTrue,
+}
+
+/// A check against a single dialogue variable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DialogueCondition {
+    pub variable: String,
+    pub comparison: DialogueComparison,
+    pub value: DialogueValue,
+}
+
+impl DialogueCondition {
+    /// Evaluates the condition against the variable store.
+    /// Missing variables only satisfy `NotEqual`.
+    pub fn evaluate(&self, variables: &DialogueVariables) -> bool {
+        let Some(current) = variables.get(&self.variable) else {
+            return self.comparison == DialogueComparison::NotEqual;
+        };
+
+        match self.comparison {
+            DialogueComparison::Equal => current == &self.value,
+            DialogueComparison::NotEqual => current != &self.value,
+            DialogueComparison::IsTrue => current.is_truthy(),
+            ordering => match (current, &self.value) {
+                (DialogueValue::Number(a), DialogueValue::Number(b)) => match ordering {
+                    DialogueComparison::Less => a < b,
+                    DialogueComparison::LessOrEqual => a <= b,
+                    DialogueComparison::Greater => a > b,
+                    _ => a >= b,
+                },
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Variables read and written by dialogue graphs. Saved with the player's data
+/// so choices made in one conversation can shape later ones, across sessions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DialogueVariables {
+    values: HashMap<String, DialogueValue>,
+    /// Where the variables are saved. `None` keeps them in memory only.
+    #[serde(skip)]
+    path: Option<PathBuf>,
+    /// Whether there are changes that have not been saved.
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl DialogueVariables {
+    /// Loads the variables saved at `path`, starting empty when there are none.
+    pub fn load_from_path(path: &Path) -> Self {
+        let mut variables = match fs::read_to_string(path) {
+            Ok(txt) => ron::from_str(&txt).unwrap_or_else(|e| {
+                onscreen_error!("Could not parse dialogue variables: {e}");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        };
+        variables.path = Some(path.to_path_buf());
+        variables
+    }
+
+    /// Writes unsaved changes to disk.
+    pub fn save(&mut self) -> Result<(), Box<dyn Error>> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if !self.dirty {
+            return Ok(());
+        }
+        // A failed write is reported once and retried on the next change
+        self.dirty = false;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, to_string_pretty(self, PrettyConfig::default())?)?;
+        Ok(())
+    }
+
+    /// Returns the value of a variable.
+    pub fn get(&self, name: &str) -> Option<&DialogueValue> {
+        self.values.get(name)
+    }
+
+    /// Sets the value of a variable.
+    pub fn set(&mut self, name: &str, value: DialogueValue) {
+        self.values.insert(name.to_string(), value);
+        self.dirty = true;
+    }
+
+    /// Removes a variable.
+    pub fn remove(&mut self, name: &str) -> Option<DialogueValue> {
+        let removed = self.values.remove(name);
+        self.dirty |= removed.is_some();
+        removed
+    }
+
+    /// Removes all variables.
+    pub fn clear(&mut self) {
+        self.dirty |= !self.values.is_empty();
+        self.values.clear();
+    }
+
+    /// Applies a set-variable operation.
+    pub fn apply(&mut self, name: &str, op: DialogueVariableOp, value: &DialogueValue) {
+        let new_value = match op {
+            DialogueVariableOp::Set => value.clone(),
+            DialogueVariableOp::Add => {
+                let current = match self.values.get(name) {
+                    Some(DialogueValue::Number(n)) => *n,
+                    _ => 0.0,
+                };
+                let delta = match value {
+                    DialogueValue::Number(n) => *n,
+                    _ => 0.0,
+                };
+                DialogueValue::Number(current + delta)
+            }
+            DialogueVariableOp::Toggle => {
+                let current = self.values.get(name).is_some_and(|v| v.is_truthy());
+                DialogueValue::Bool(!current)
+            }
+        };
+        self.set(name, new_value);
+    }
+
+    /// Returns all variables as typed values for text interpolation.
+    pub fn to_interpolation_map(&self) -> HashMap<String, TextValue> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.clone(), TextValue::from(value)))
+            .collect()
+    }
+}
+
+/// Path of the dialogue variables file for `game_name` in the OS app-data dir.
+pub fn dialogue_variables_path(game_name: &str) -> Option<PathBuf> {
+    player_data_path(game_name, DIALOGUE_VARIABLES_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition(comparison: DialogueComparison, value: DialogueValue) -> DialogueCondition {
+        DialogueCondition {
+            variable: "gold".to_string(),
+            comparison,
+            value,
+        }
+    }
+
+    #[test]
+    fn numeric_comparisons_use_stored_value() {
+        let mut vars = DialogueVariables::default();
+        vars.set("gold", DialogueValue::Number(10.0));
+
+        assert!(condition(DialogueComparison::Greater, DialogueValue::Number(5.0)).evaluate(&vars));
+        assert!(
+            condition(DialogueComparison::LessOrEqual, DialogueValue::Number(10.0)).evaluate(&vars)
+        );
+        assert!(!condition(DialogueComparison::Less, DialogueValue::Number(10.0)).evaluate(&vars));
+        assert!(
+            !condition(DialogueComparison::Greater, DialogueValue::Text("5".into()))
+                .evaluate(&vars)
+        );
+    }
+
+    #[test]
+    fn missing_variable_only_satisfies_not_equal() {
+        let vars = DialogueVariables::default();
+
+        assert!(condition(DialogueComparison::NotEqual, DialogueValue::Bool(true)).evaluate(&vars));
+        assert!(!condition(DialogueComparison::Equal, DialogueValue::Bool(false)).evaluate(&vars));
+        assert!(!condition(DialogueComparison::IsTrue, DialogueValue::Bool(true)).evaluate(&vars));
+    }
+
+    #[test]
+    fn saved_variables_load_back() {
+        let path = std::env::temp_dir()
+            .join(format!("bishop-dialogue-{}", uuid::Uuid::new_v4()))
+            .join(DIALOGUE_VARIABLES_FILE);
+
+        let mut vars = DialogueVariables::load_from_path(&path);
+        vars.set("met_guard", DialogueValue::Bool(true));
+        vars.apply("gold", DialogueVariableOp::Add, &DialogueValue::Number(4.0));
+        vars.save().unwrap();
+
+        let loaded = DialogueVariables::load_from_path(&path);
+        assert_eq!(loaded.get("met_guard"), Some(&DialogueValue::Bool(true)));
+        assert_eq!(loaded.get("gold"), Some(&DialogueValue::Number(4.0)));
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn failed_saves_are_reported_once_per_change() {
+        // A file where the save folder should be makes every write fail
+        let blocker =
+            std::env::temp_dir().join(format!("bishop-dialogue-{}", uuid::Uuid::new_v4()));
+        fs::write(&blocker, "").unwrap();
+        let mut vars = DialogueVariables::load_from_path(&blocker.join(DIALOGUE_VARIABLES_FILE));
+
+        vars.set("met_guard", DialogueValue::Bool(true));
+        assert!(vars.save().is_err());
+        assert!(vars.save().is_ok());
+        vars.set("met_guard", DialogueValue::Bool(false));
+        assert!(vars.save().is_err());
+
+        let _ = fs::remove_file(&blocker);
+    }
+
+    #[test]
+    fn apply_add_and_toggle_start_from_defaults() {
+        let mut vars = DialogueVariables::default();
+
+        vars.apply("gold", DialogueVariableOp::Add, &DialogueValue::Number(3.0));
+        vars.apply("gold", DialogueVariableOp::Add, &DialogueValue::Number(2.0));
+        vars.apply(
+            "met",
+            DialogueVariableOp::Toggle,
+            &DialogueValue::Bool(false),
+        );
+
+        assert_eq!(vars.get("gold"), Some(&DialogueValue::Number(5.0)));
+        assert_eq!(vars.get("met"), Some(&DialogueValue::Bool(true)));
+    }
+
+    #[test]
+    fn interpolation_map_formats_whole_numbers_without_fraction() {
+        let mut vars = DialogueVariables::default();
+        vars.set("gold", DialogueValue::Number(5.0));
+        vars.set("ratio", DialogueValue::Number(0.5));
+
+        let map = vars.to_interpolation_map();
+
+        assert_eq!(map["gold"].to_string(), "5");
+        assert_eq!(map["ratio"].to_string(), "0.5");
+    }
+}
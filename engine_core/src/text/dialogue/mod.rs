@@ -1,11 +1,21 @@
 // engine_core/src/text/dialogue/mod.rs
 
 pub mod dialogue_config;
+pub mod dialogue_graph;
+pub mod dialogue_runner;
+pub mod dialogue_validation;
+pub mod dialogue_variables;
 pub mod speech_bubble;
 pub mod speech_renderer;
 pub mod speech_system;
+pub mod speech_voice;
 
 pub use dialogue_config::*;
+pub use dialogue_graph::*;
+pub use dialogue_runner::*;
+pub use dialogue_validation::*;
+pub use dialogue_variables::*;
 pub use speech_bubble::*;
 pub use speech_renderer::*;
 pub use speech_system::*;
+pub use speech_voice::*;
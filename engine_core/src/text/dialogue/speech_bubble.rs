@@ -1,13 +1,15 @@
 // engine_core/src/text/dialogue/speech_bubble.rs
+use crate::text::rich_text::RichText;
 use ecs_component::ecs_component;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Component for displaying speech bubbles above entities.
 #[ecs_component]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct SpeechBubble {
-    /// The text to display.
+    /// The text to display. May contain rich text markup.
     pub text: String,
     /// Remaining time in seconds before the bubble disappears.
     pub timer: f32,
@@ -17,12 +19,36 @@ pub struct SpeechBubble {
     pub offset: (f32, f32),
     /// Font size override (uses config default if None).
     pub font_size: Option<f32>,
+    /// Font name override (uses config default if None).
+    pub font: Option<String>,
     /// Maximum width before word wrap (uses config default if None).
     pub max_width: Option<f32>,
     /// Whether to show a background behind the text.
     pub show_background: bool,
     /// Background color [r, g, b, a].
     pub background_color: [f32; 4],
+    /// Typewriter speed in characters per second. Zero shows the text instantly.
+    pub reveal_speed: f32,
+    /// Whether the bubble stays open until the advance input instead of timing out.
+    pub wait_for_input: bool,
+    /// Markup parsed from `text`, refreshed when the text changes.
+    #[serde(skip)]
+    pub markup: Option<Arc<RichText>>,
+    /// Seconds since the reveal started.
+    #[serde(skip)]
+    pub reveal_elapsed: f32,
+    /// Time at which each glyph of the markup appears.
+    #[serde(skip)]
+    pub reveal_times: Vec<f32>,
+    /// Whether every glyph has been revealed.
+    #[serde(skip)]
+    pub revealed: bool,
+    /// Number of revealed glyphs already checked for a voice blip.
+    #[serde(skip)]
+    pub voiced_glyphs: usize,
+    /// Seconds since the bubble was shown.
+    #[serde(skip)]
+    pub age: f32,
 }
 
 impl Default for SpeechBubble {
@@ -33,9 +59,18 @@ impl Default for SpeechBubble {
             color: [1.0, 1.0, 1.0, 1.0],
             offset: (0.0, 0.0),
             font_size: Some(2.5),
+            font: None,
             max_width: None,
             show_background: false,
             background_color: [0.0, 0.0, 0.0, 0.7],
+            reveal_speed: 0.0,
+            wait_for_input: false,
+            markup: None,
+            reveal_elapsed: 0.0,
+            reveal_times: Vec::new(),
+            revealed: false,
+            voiced_glyphs: 0,
+            age: 0.0,
         }
     }
 }
@@ -68,6 +103,12 @@ impl SpeechBubble {
         self
     }
 
+    /// Builder method to set the font by name.
+    pub fn with_font(mut self, font: impl Into<String>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
     /// Builder method to set the max width.
     pub fn with_max_width(mut self, width: f32) -> Self {
         self.max_width = Some(width);
@@ -85,4 +126,66 @@ impl SpeechBubble {
         self.background_color = color;
         self
     }
+
+    /// Builder method to set the typewriter speed in characters per second.
+    pub fn with_reveal_speed(mut self, chars_per_second: f32) -> Self {
+        self.reveal_speed = chars_per_second;
+        self
+    }
+
+    /// Builder method to keep the bubble open until the advance input.
+    pub fn with_wait_for_input(mut self, wait: bool) -> Self {
+        self.wait_for_input = wait;
+        self
+    }
+
+    /// Returns the parsed markup for the current text.
+    pub fn markup(&self) -> Arc<RichText> {
+        match &self.markup {
+            Some(markup) if markup.source == self.text => markup.clone(),
+            _ => Arc::new(RichText::parse(&self.text)),
+        }
+    }
+
+    /// Re-parses the markup if the text changed since it was last parsed,
+    /// restarting the reveal.
+    pub fn refresh_markup(&mut self) {
+        if self
+            .markup
+            .as_ref()
+            .is_none_or(|markup| markup.source != self.text)
+        {
+            let markup = RichText::parse(&self.text);
+            self.reveal_times = markup.reveal_times(self.reveal_speed);
+            self.reveal_elapsed = 0.0;
+            self.revealed = false;
+            self.voiced_glyphs = 0;
+            self.markup = Some(Arc::new(markup));
+        }
+    }
+
+    /// Returns the number of glyphs revealed so far.
+    pub fn revealed_glyphs(&self) -> usize {
+        self.reveal_times
+            .partition_point(|time| *time <= self.reveal_elapsed)
+    }
+
+    /// Returns how many glyphs to draw, or `None` once the reveal has finished.
+    pub fn visible_glyphs(&self) -> Option<usize> {
+        if self.revealed || self.reveal_speed <= 0.0 {
+            None
+        } else {
+            Some(self.revealed_glyphs())
+        }
+    }
+
+    /// Returns true while glyphs are still being typed out.
+    pub fn revealing(&self) -> bool {
+        !self.revealed && self.reveal_elapsed < self.reveal_duration()
+    }
+
+    /// Returns the time at which the last glyph appears.
+    pub fn reveal_duration(&self) -> f32 {
+        self.reveal_times.last().copied().unwrap_or(0.0)
+    }
 }
@@ -6,23 +6,27 @@ use crate::ecs::component::CurrentRoom;
 use crate::ecs::ecs::Ecs;
 use crate::ecs::entity::Entity;
 use crate::ecs::transform::Transform;
+use crate::menu::layout::HorizontalAlign;
 use crate::rendering::helpers::lerp_rounded;
 use crate::rendering::helpers::entity_dimensions;
 use crate::text::*;
-use crate::ui::text::*;
 use crate::worlds::room::RoomId;
 use bishop::prelude::*;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Collected data for rendering a speech bubble in screen space.
 pub struct SpeechBubbleRenderData {
-    pub text: String,
+    pub markup: Arc<RichText>,
+    /// Glyphs shown so far by the typewriter reveal, `None` once fully revealed.
+    pub visible_glyphs: Option<usize>,
     pub world_pos: Vec2,
     pub entity_size: Vec2,
     pub pivot: Pivot,
     pub color: [f32; 4],
     pub offset: (f32, f32),
     pub font_size: Option<f32>,
+    pub font: Option<String>,
     pub max_width: Option<f32>,
     pub show_background: bool,
     pub background_color: [f32; 4],
@@ -60,13 +64,15 @@ pub fn collect_speech_bubbles(
         let entity_size = entity_dimensions(ecs, asset_manager, *entity, grid_size);
 
         bubbles.push(SpeechBubbleRenderData {
-            text: bubble.text.clone(),
+            markup: bubble.markup(),
+            visible_glyphs: bubble.visible_glyphs(),
             world_pos,
             entity_size,
             pivot: transform.pivot,
             color: bubble.color,
             offset: bubble.offset,
             font_size: bubble.font_size,
+            font: bubble.font.clone(),
             max_width: bubble.max_width,
             show_background: bubble.show_background,
             background_color: bubble.background_color,
@@ -81,9 +87,10 @@ pub fn collect_speech_bubbles(
 pub fn render_speech_bubbles<C: BishopContext>(
     ctx: &mut C,
     bubbles: &[SpeechBubbleRenderData],
-    config: &DialogueConfig,
+    config: &mut DialogueConfig,
     render_cam: &Camera2D,
     grid_size: f32,
+    asset_manager: &mut AssetManager,
 ) {
     let virt_w = world_virtual_width(grid_size);
     let virt_h = world_virtual_height(grid_size);
@@ -108,7 +115,7 @@ pub fn render_speech_bubbles<C: BishopContext>(
     };
 
     for bubble in bubbles {
-        render_bubble_screen_space(ctx, bubble, config, &projection);
+        render_bubble_screen_space(ctx, bubble, config, &projection, asset_manager);
     }
 }
 
@@ -124,28 +131,22 @@ struct ScreenSpaceProjection<'a> {
 fn render_bubble_screen_space<C: BishopContext>(
     ctx: &mut C,
     bubble: &SpeechBubbleRenderData,
-    config: &DialogueConfig,
+    config: &mut DialogueConfig,
     projection: &ScreenSpaceProjection<'_>,
+    asset_manager: &mut AssetManager,
 ) {
     let font_size = bubble.font_size.unwrap_or(config.font_size) * projection.scale;
     let max_width = bubble.max_width.unwrap_or(config.max_width) * projection.scale;
     let padding = config.padding * projection.scale;
 
-    let lines = wrap_text(ctx, &bubble.text, max_width, font_size);
-    if lines.is_empty() {
+    let font = asset_manager.font(ctx, bubble.font.as_deref().unwrap_or(&config.font));
+    let layout = layout_rich_text(ctx, &bubble.markup, font, font_size, Some(max_width));
+    if layout.lines.is_empty() {
         return;
     }
 
-    let line_height = font_size * 1.2;
-    let total_text_height = lines.len() as f32 * line_height;
-
-    let max_line_width = lines
-        .iter()
-        .map(|line| measure_text(ctx, line, font_size).width)
-        .fold(0.0_f32, f32::max);
-
-    let bubble_width = max_line_width + padding * 2.0;
-    let bubble_height = total_text_height + padding * 2.0;
+    let bubble_width = layout.width + padding * 2.0;
+    let bubble_height = layout.height() + padding * 2.0;
 
     let pivot_offset = bubble.pivot.as_normalized();
     let entity_width_scaled = bubble.entity_size.x * projection.scale;
@@ -172,13 +173,24 @@ fn render_bubble_screen_space<C: BishopContext>(
     let bubble_y = entity_top_y + bubble.offset.1 * projection.scale - bubble_height;
 
     if bubble.show_background {
+        let bubble_rect = Rect::new(bubble_x, bubble_y, bubble_width, bubble_height);
         let bg_color = Color::new(
             bubble.background_color[0],
             bubble.background_color[1],
             bubble.background_color[2],
             bubble.background_color[3],
         );
-        ctx.draw_rectangle(bubble_x, bubble_y, bubble_width, bubble_height, bg_color);
+        // The sprite is tinted by the bubble colour, so white shows it as drawn
+        match config.background_sprite_id(ctx, asset_manager) {
+            Some(sprite) => {
+                let texture = asset_manager.get_texture_from_id(ctx, sprite);
+                let slice = config.background_slice.scaled(projection.scale);
+                ctx.draw_nine_slice(texture, bubble_rect, &slice, bg_color);
+            }
+            None => {
+                ctx.draw_rectangle(bubble_x, bubble_y, bubble_width, bubble_height, bg_color);
+            }
+        }
     }
 
     let text_color = Color::new(
@@ -188,51 +200,15 @@ fn render_bubble_screen_space<C: BishopContext>(
         bubble.color[3],
     );
 
-    for (i, line) in lines.iter().enumerate() {
-        let line_width = measure_text(ctx, line, font_size).width;
-        let text_x = bubble_x + (bubble_width - line_width) / 2.0;
-        let text_y = bubble_y + padding + (i as f32 + 1.0) * line_height - line_height * 0.2;
-
-        ctx.draw_text(line, text_x, text_y, font_size, text_color);
-    }
-}
-
-/// Wraps text to fit within a maximum width.
-fn wrap_text<C: BishopContext>(
-    ctx: &mut C,
-    text: &str,
-    max_width: f32,
-    font_size: f32,
-) -> Vec<String> {
-    let mut lines = Vec::new();
-    let mut current_line = String::new();
-
-    for word in text.split_whitespace() {
-        let test_line = if current_line.is_empty() {
-            word.to_string()
-        } else {
-            format!("{} {}", current_line, word)
-        };
-
-        let test_width = measure_text(ctx, &test_line, font_size).width;
-
-        if test_width <= max_width || current_line.is_empty() {
-            current_line = test_line;
-        } else {
-            lines.push(current_line);
-            current_line = word.to_string();
-        }
-    }
-
-    if !current_line.is_empty() {
-        lines.push(current_line);
-    }
-
-    if lines.is_empty() && !text.is_empty() {
-        lines.push(text.to_string());
-    }
-
-    lines
+    let params = RichTextDrawParams {
+        origin: Vec2::new(bubble_x + padding, bubble_y + padding),
+        width: layout.width,
+        align: HorizontalAlign::Center,
+        color: text_color,
+        time: ctx.get_time() as f32,
+        visible_glyphs: bubble.visible_glyphs,
+    };
+    draw_rich_text(ctx, &layout, &params, asset_manager);
 }
 
 /// Interpolates position for smooth rendering.
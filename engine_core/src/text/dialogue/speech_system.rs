@@ -1,36 +1,234 @@
 // engine_core/src/text/dialogue/speech_system.rs
+use crate::audio::{AudioCommand, push_audio_command};
 use crate::ecs::ecs::Ecs;
 use crate::ecs::entity::Entity;
-use crate::text::dialogue::SpeechBubble;
+use crate::text::dialogue::{SpeechBubble, SpeechVoice};
 
-/// Updates speech bubble timers and removes expired bubbles.
-pub fn update_speech_timers(ecs: &mut Ecs, dt: f32) {
-    let store = ecs.get_store_mut::<SpeechBubble>();
+/// Speech bubble state changes reported by `update_speech_bubbles`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeechEvent {
+    /// Every glyph of the entity's bubble is now visible.
+    Revealed(Entity),
+    /// The entity's bubble timed out or was dismissed.
+    Dismissed(Entity),
+}
 
-    let expired: Vec<Entity> = store
+/// Returns the bubble a press of the advance input goes to: `focus` when its
+/// bubble reacts to the press, otherwise the newest bubble that does.
+pub fn speech_advance_target(ecs: &Ecs, focus: Option<Entity>) -> Option<Entity> {
+    let store = ecs.get_store::<SpeechBubble>();
+    let reacts = |bubble: &SpeechBubble| bubble.revealing() || bubble.wait_for_input;
+    if let Some(focus) = focus
+        && store.get(focus).is_some_and(reacts)
+    {
+        return Some(focus);
+    }
+    store
         .data
-        .iter_mut()
-        .filter_map(|(entity, bubble)| {
+        .iter()
+        .filter(|(_, bubble)| reacts(bubble))
+        .min_by(|(a, a_bubble), (b, b_bubble)| a_bubble.age.total_cmp(&b_bubble.age).then(b.cmp(a)))
+        .map(|(entity, _)| *entity)
+}
+
+/// Advances typewriter reveals, plays voice blips, counts down timers and removes
+/// finished bubbles. The bubble of `advance` completes its running reveal, or is
+/// dismissed once revealed if it waits for input.
+pub fn update_speech_bubbles(ecs: &mut Ecs, dt: f32, advance: Option<Entity>) -> Vec<SpeechEvent> {
+    let mut events = Vec::new();
+    let mut blips = Vec::new();
+    let store = ecs.get_store_mut::<SpeechBubble>();
+
+    for (entity, bubble) in store.data.iter_mut() {
+        bubble.refresh_markup();
+        bubble.age += dt;
+        let advance_pressed = advance == Some(*entity);
+
+        if !bubble.revealed {
+            // Text shown all at once leaves the press to the input handling below
+            let typing = bubble.revealing();
+            if advance_pressed && typing {
+                bubble.reveal_elapsed = bubble.reveal_duration();
+            } else {
+                bubble.reveal_elapsed += dt;
+                if bubble.reveal_speed > 0.0 {
+                    blips.push((*entity, bubble.voiced_glyphs..bubble.revealed_glyphs()));
+                }
+            }
+            bubble.voiced_glyphs = bubble.revealed_glyphs();
+            if bubble.reveal_elapsed >= bubble.reveal_duration() {
+                bubble.revealed = true;
+                events.push(SpeechEvent::Revealed(*entity));
+            }
+            // The same press must not also dismiss the bubble it just revealed
+            if typing {
+                continue;
+            }
+        }
+
+        if bubble.wait_for_input {
+            if advance_pressed {
+                events.push(SpeechEvent::Dismissed(*entity));
+            }
+        } else {
             bubble.timer -= dt;
             if bubble.timer <= 0.0 {
-                Some(*entity)
-            } else {
-                None
+                events.push(SpeechEvent::Dismissed(*entity));
             }
-        })
-        .collect();
+        }
+    }
 
-    for entity in expired {
-        store.remove(entity);
+    for event in &events {
+        if let SpeechEvent::Dismissed(entity) = event {
+            store.remove(*entity);
+        }
     }
+
+    let voices = ecs.get_store::<SpeechVoice>();
+    for (entity, revealed) in blips {
+        if let Some(voice) = voices.get(entity)
+            && voice.blip_due(revealed)
+        {
+            push_audio_command(AudioCommand::PlayVariedSfx {
+                sounds: vec![voice.blip_sound.clone()],
+                volume: voice.volume,
+                pitch_variation: voice.pitch_variation,
+                volume_variation: 0.0,
+            });
+        }
+    }
+
+    events
 }
 
 /// Removes the speech bubble from an entity immediately.
-pub fn clear_speech(ecs: &mut Ecs, entity: Entity) {
-    ecs.get_store_mut::<SpeechBubble>().remove(entity);
+/// Returns the dismissal when the entity had a bubble.
+pub fn clear_speech(ecs: &mut Ecs, entity: Entity) -> Option<SpeechEvent> {
+    ecs.get_store_mut::<SpeechBubble>()
+        .data
+        .remove(&entity)
+        .map(|_| SpeechEvent::Dismissed(entity))
 }
 
 /// Checks if an entity currently has a speech bubble.
 pub fn is_speaking(ecs: &Ecs, entity: Entity) -> bool {
     ecs.get_store::<SpeechBubble>().contains(entity)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn speaker(ecs: &mut Ecs, bubble: SpeechBubble) -> Entity {
+        ecs.create_entity().with(bubble).finish()
+    }
+
+    #[test]
+    fn reveal_completes_before_timer_runs() {
+        let mut ecs = Ecs::default();
+        let entity = speaker(
+            &mut ecs,
+            SpeechBubble::new("abcd".into(), 1.0).with_reveal_speed(10.0),
+        );
+
+        assert!(update_speech_bubbles(&mut ecs, 0.25, None).is_empty());
+        assert_eq!(
+            ecs.get::<SpeechBubble>(entity).unwrap().visible_glyphs(),
+            Some(2)
+        );
+
+        let events = update_speech_bubbles(&mut ecs, 0.25, None);
+        assert_eq!(events, vec![SpeechEvent::Revealed(entity)]);
+        assert_eq!(ecs.get::<SpeechBubble>(entity).unwrap().timer, 1.0);
+
+        let events = update_speech_bubbles(&mut ecs, 1.0, None);
+        assert_eq!(events, vec![SpeechEvent::Dismissed(entity)]);
+        assert!(!is_speaking(&ecs, entity));
+    }
+
+    #[test]
+    fn advance_skips_reveal_then_dismisses() {
+        let mut ecs = Ecs::default();
+        let bubble = SpeechBubble::new("hello there".into(), 1.0)
+            .with_reveal_speed(5.0)
+            .with_wait_for_input(true);
+        let entity = speaker(&mut ecs, bubble);
+
+        update_speech_bubbles(&mut ecs, 0.1, None);
+        let events = update_speech_bubbles(&mut ecs, 0.1, Some(entity));
+        assert_eq!(events, vec![SpeechEvent::Revealed(entity)]);
+
+        assert!(update_speech_bubbles(&mut ecs, 10.0, None).is_empty());
+        let events = update_speech_bubbles(&mut ecs, 0.1, Some(entity));
+        assert_eq!(events, vec![SpeechEvent::Dismissed(entity)]);
+        assert_eq!(
+            clear_speech(&mut ecs, entity),
+            None,
+            "a dismissed bubble is only reported once"
+        );
+    }
+
+    #[test]
+    fn first_glyph_plays_a_blip() {
+        let mut ecs = Ecs::default();
+        let voice = SpeechVoice {
+            blip_sound: "blip".to_string(),
+            ..Default::default()
+        };
+        let bubble = SpeechBubble::new("abc".into(), 1.0).with_reveal_speed(10.0);
+        ecs.create_entity().with(bubble).with(voice).finish();
+        crate::audio::command_queue::drain_audio_commands();
+
+        update_speech_bubbles(&mut ecs, 0.1, None);
+        assert_eq!(crate::audio::command_queue::drain_audio_commands().len(), 1);
+        update_speech_bubbles(&mut ecs, 0.1, None);
+        assert!(crate::audio::command_queue::drain_audio_commands().is_empty());
+    }
+
+    #[test]
+    fn instant_text_leaves_the_first_press_to_dismiss() {
+        let mut ecs = Ecs::default();
+        let entity = speaker(
+            &mut ecs,
+            SpeechBubble::new("hi".into(), 1.0).with_wait_for_input(true),
+        );
+
+        let events = update_speech_bubbles(&mut ecs, 0.1, Some(entity));
+        assert_eq!(
+            events,
+            vec![
+                SpeechEvent::Revealed(entity),
+                SpeechEvent::Dismissed(entity)
+            ]
+        );
+
+        let timed = speaker(&mut ecs, SpeechBubble::new("hi".into(), 1.0));
+        assert_eq!(
+            speech_advance_target(&ecs, None),
+            None,
+            "a press is not taken by text that is already shown"
+        );
+        assert!(is_speaking(&ecs, timed));
+    }
+
+    #[test]
+    fn advance_goes_to_the_focus_or_the_newest_bubble() {
+        let mut ecs = Ecs::default();
+        let waiting = || SpeechBubble::new("hi".into(), 1.0).with_wait_for_input(true);
+        let older = speaker(&mut ecs, waiting());
+        update_speech_bubbles(&mut ecs, 0.5, None);
+        let newer = speaker(&mut ecs, waiting());
+        update_speech_bubbles(&mut ecs, 0.1, None);
+
+        assert_eq!(speech_advance_target(&ecs, None), Some(newer));
+        assert_eq!(speech_advance_target(&ecs, Some(older)), Some(older));
+
+        let events = update_speech_bubbles(&mut ecs, 0.1, Some(newer));
+        assert_eq!(events, vec![SpeechEvent::Dismissed(newer)]);
+        assert!(is_speaking(&ecs, older));
+        assert_eq!(
+            clear_speech(&mut ecs, older),
+            Some(SpeechEvent::Dismissed(older))
+        );
+    }
+}
@@ -0,0 +1,44 @@
+// engine_core/src/text/dialogue/speech_voice.rs
+use crate::inspector_module;
+use ecs_component::ecs_component;
+use reflect_derive::Reflect;
+use serde::{Deserialize, Serialize};
+
+/// Voice blip played while a speaker's bubble text is revealed.
+#[ecs_component]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[serde(default)]
+pub struct SpeechVoice {
+    /// Sound id played for the blip. Empty disables blips.
+    pub blip_sound: String,
+    /// Plays a blip every this many revealed characters.
+    pub blip_interval: i32,
+    /// Random pitch variation applied to each blip.
+    pub pitch_variation: f32,
+    /// Volume of each blip.
+    pub volume: f32,
+}
+
+inspector_module!(SpeechVoice);
+
+impl Default for SpeechVoice {
+    fn default() -> Self {
+        Self {
+            blip_sound: String::new(),
+            blip_interval: 2,
+            pitch_variation: 0.05,
+            volume: 1.0,
+        }
+    }
+}
+
+impl SpeechVoice {
+    /// Returns true if a blip is due for any glyph revealed in `revealed`.
+    pub fn blip_due(&self, revealed: std::ops::Range<usize>) -> bool {
+        if self.blip_sound.is_empty() {
+            return false;
+        }
+        let interval = self.blip_interval.max(1) as usize;
+        revealed.into_iter().any(|glyph| glyph % interval == 0)
+    }
+}
@@ -1,22 +1,29 @@
 // engine_core/src/text/interpolation.rs
+use crate::text::message_format::*;
 use std::collections::HashMap;
 
-/// Interpolates `{{variable}}` placeholders in text with provided values.
+/// Formats `{{variable}}` placeholders and ICU-style arguments in text with the
+/// provided values, using the plural and number rules of `language`.
+/// Malformed spans are logged and kept as written while the rest is formatted.
+/// See [`Message`] for the syntax.
 ///
 /// # Example
 /// ```
+/// use engine_core::text::*;
+/// use std::collections::HashMap;
+///
 /// let mut vars = HashMap::new();
-/// vars.insert("name".to_string(), "Hero".to_string());
-/// let result = interpolate("Hello {{name}}!", &vars);
-/// assert_eq!(result, "Hello Hero!");
+/// vars.insert("name".to_string(), TextValue::from("Hero"));
+/// vars.insert("gold".to_string(), TextValue::from(2.0));
+/// let result = interpolate("Hello {{name}}, {gold, plural, one {# coin} other {# coins}}!", "en", &vars);
+/// assert_eq!(result, "Hello Hero, 2 coins!");
 /// ```
-pub fn interpolate(text: &str, variables: &HashMap<String, String>) -> String {
-    let mut result = text.to_string();
-    for (key, value) in variables {
-        let placeholder = format!("{{{{{}}}}}", key);
-        result = result.replace(&placeholder, value);
+pub fn interpolate(text: &str, language: &str, variables: &HashMap<String, TextValue>) -> String {
+    let (message, errors) = Message::parse_lenient(text);
+    for e in errors {
+        log::warn!("Malformed text \"{text}\": {e}");
     }
-    result
+    message.format(language, variables)
 }
 
 #[cfg(test)]
@@ -26,17 +33,17 @@ mod tests {
     #[test]
     fn test_interpolate_single_variable() {
         let mut vars = HashMap::new();
-        vars.insert("name".to_string(), "Hero".to_string());
-        assert_eq!(interpolate("Hello {{name}}!", &vars), "Hello Hero!");
+        vars.insert("name".to_string(), "Hero".into());
+        assert_eq!(interpolate("Hello {{name}}!", "en", &vars), "Hello Hero!");
     }
 
     #[test]
     fn test_interpolate_multiple_variables() {
         let mut vars = HashMap::new();
-        vars.insert("player".to_string(), "Alice".to_string());
-        vars.insert("gold".to_string(), "100".to_string());
+        vars.insert("player".to_string(), "Alice".into());
+        vars.insert("gold".to_string(), 100.0.into());
         assert_eq!(
-            interpolate("{{player}} has {{gold}} gold.", &vars),
+            interpolate("{{player}} has {{gold}} gold.", "en", &vars),
             "Alice has 100 gold."
         );
     }
@@ -45,7 +52,7 @@ mod tests {
     fn test_interpolate_no_variables() {
         let vars = HashMap::new();
         assert_eq!(
-            interpolate("No placeholders here.", &vars),
+            interpolate("No placeholders here.", "en", &vars),
             "No placeholders here."
         );
     }
@@ -53,6 +60,20 @@ mod tests {
     #[test]
     fn test_interpolate_missing_variable() {
         let vars = HashMap::new();
-        assert_eq!(interpolate("Hello {{name}}!", &vars), "Hello {{name}}!");
+        assert_eq!(
+            interpolate("Hello {{name}}!", "en", &vars),
+            "Hello {{name}}!"
+        );
+    }
+
+    #[test]
+    fn test_interpolate_malformed_span_is_kept() {
+        let mut vars = HashMap::new();
+        vars.insert("name".to_string(), "Hero".into());
+        assert_eq!(interpolate("Hello {{name}", "en", &vars), "Hello {{name}");
+        assert_eq!(
+            interpolate("Hello {{name} and {{name}}!", "en", &vars),
+            "Hello {{name} and Hero!"
+        );
     }
 }
@@ -0,0 +1,179 @@
+// engine_core/src/text/localization/localization_report.rs
+use crate::text::TextManifest;
+use crate::text::localization::string_table::*;
+use std::path::Path;
+
+/// Entry whose variant count differs from the default language.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantMismatch {
+    /// Entry formatted as `file:key`.
+    pub entry: String,
+    pub expected: usize,
+    pub found: usize,
+}
+
+/// Translation status of one language compared to the default language.
+#[derive(Debug, Clone, Default)]
+pub struct LanguageReport {
+    pub language: String,
+    /// Number of strings in the default language.
+    pub total: usize,
+    /// Number of those strings with a non-empty translation.
+    pub translated: usize,
+    /// Entries present in the default language but not in this one, as `file:key`.
+    pub missing: Vec<String>,
+    /// Entries present in this language but not in the default one, as `file:key`.
+    pub extra: Vec<String>,
+    pub variant_mismatches: Vec<VariantMismatch>,
+}
+
+impl LanguageReport {
+    /// Returns the translated fraction of strings, from 0 to 1.
+    pub fn coverage(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        self.translated as f32 / self.total as f32
+    }
+
+    /// Returns true if nothing is missing, extra or mismatched.
+    pub fn is_complete(&self) -> bool {
+        self.translated == self.total
+            && self.missing.is_empty()
+            && self.extra.is_empty()
+            && self.variant_mismatches.is_empty()
+    }
+}
+
+/// Translation status of every language of a game.
+#[derive(Debug, Clone, Default)]
+pub struct LocalizationReport {
+    pub default_language: String,
+    /// One report per available language other than the default.
+    pub languages: Vec<LanguageReport>,
+}
+
+impl LocalizationReport {
+    /// Loads every language under `text_root` and compares it to the default.
+    pub fn build(text_root: &Path) -> Self {
+        let manifest = TextManifest::load(text_root).unwrap_or_default();
+        let default = StringTable::load(text_root, &manifest.default_language);
+
+        let languages = manifest
+            .available
+            .iter()
+            .filter(|language| **language != manifest.default_language)
+            .map(|language| {
+                let table = StringTable::load(text_root, language);
+                compare_string_tables(&default, &table, language)
+            })
+            .collect();
+
+        Self {
+            default_language: manifest.default_language,
+            languages,
+        }
+    }
+}
+
+/// Compares a language's strings against the default language's.
+pub fn compare_string_tables(
+    default: &StringTable,
+    table: &StringTable,
+    language: &str,
+) -> LanguageReport {
+    let mut report = LanguageReport {
+        language: language.to_string(),
+        ..Default::default()
+    };
+
+    for (id, _) in default.strings() {
+        report.total += 1;
+        if table.get(&id).is_some_and(|text| !text.is_empty()) {
+            report.translated += 1;
+        }
+    }
+
+    for (file, entries) in &default.files {
+        for (key, entry) in entries {
+            match table.entry(file, key) {
+                None => report.missing.push(format!("{file}:{key}")),
+                Some(translated) => {
+                    let (expected, found) = (entry.variant_count(), translated.variant_count());
+                    if expected != found {
+                        report.variant_mismatches.push(VariantMismatch {
+                            entry: format!("{file}:{key}"),
+                            expected,
+                            found,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    for (file, entries) in &table.files {
+        for key in entries.keys() {
+            if default.entry(file, key).is_none() {
+                report.extra.push(format!("{file}:{key}"));
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn table(files: &[(&str, &str)]) -> StringTable {
+        StringTable {
+            files: files
+                .iter()
+                .map(|(id, content)| (id.to_string(), parse_text_file(content).unwrap()))
+                .collect::<BTreeMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn reports_missing_extra_and_mismatched_entries() {
+        let default = table(&[
+            ("ui/start", "play = \"Play\"\nquit = \"Quit\""),
+            ("npc", "[hello]\nvariants = [\"Hi\", \"Hey\"]"),
+        ]);
+        let french = table(&[
+            ("ui/start", "play = \"Jouer\"\nback = \"Retour\""),
+            ("npc", "[hello]\nvariants = [\"Salut\"]"),
+        ]);
+
+        let report = compare_string_tables(&default, &french, "fr");
+
+        assert_eq!(report.total, 4);
+        assert_eq!(report.translated, 2);
+        assert_eq!(report.missing, vec!["ui/start:quit".to_string()]);
+        assert_eq!(report.extra, vec!["ui/start:back".to_string()]);
+        assert_eq!(
+            report.variant_mismatches,
+            vec![VariantMismatch {
+                entry: "npc:hello".to_string(),
+                expected: 2,
+                found: 1,
+            }]
+        );
+        assert!(!report.is_complete());
+        assert_eq!(report.coverage(), 0.5);
+    }
+
+    #[test]
+    fn empty_translations_do_not_count() {
+        let default = table(&[("ui", "play = \"Play\"")]);
+        let german = table(&[("ui", "play = \"\"")]);
+
+        let report = compare_string_tables(&default, &german, "de");
+
+        assert_eq!(report.translated, 0);
+        assert!(report.missing.is_empty());
+    }
+}
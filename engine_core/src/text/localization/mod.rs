@@ -0,0 +1,9 @@
+// engine_core/src/text/localization/mod.rs
+
+pub mod localization_report;
+pub mod string_table;
+pub mod string_table_csv;
+
+pub use localization_report::*;
+pub use string_table::*;
+pub use string_table_csv::*;
@@ -0,0 +1,358 @@
+// engine_core/src/text/localization/string_table.rs
+use crate::text::TextEntry;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A translatable entry in a text file.
+#[derive(Debug, Clone)]
+pub enum StringEntry {
+    /// A flat UI string.
+    Ui(String),
+    /// A dialogue entry with variants.
+    Dialogue(TextEntry),
+}
+
+impl StringEntry {
+    /// Returns the number of variants, counting a UI string as one.
+    pub fn variant_count(&self) -> usize {
+        match self {
+            StringEntry::Ui(_) => 1,
+            StringEntry::Dialogue(entry) => entry.variants.len(),
+        }
+    }
+
+    /// Returns every string in the entry with the part it belongs to.
+    pub fn parts(&self) -> Vec<(StringPart, &str)> {
+        match self {
+            StringEntry::Ui(text) => vec![(StringPart::Text, text.as_str())],
+            StringEntry::Dialogue(entry) => {
+                let mut parts: Vec<_> = entry
+                    .variants
+                    .iter()
+                    .enumerate()
+                    .map(|(i, text)| (StringPart::Variant(i), text.as_str()))
+                    .collect();
+                if let Some(exhausted) = &entry.exhausted {
+                    parts.push((StringPart::Exhausted, exhausted.as_str()));
+                }
+                parts
+            }
+        }
+    }
+
+    /// Returns the string stored in `part`, if any.
+    pub fn get(&self, part: StringPart) -> Option<&str> {
+        match (self, part) {
+            (StringEntry::Ui(text), StringPart::Text) => Some(text),
+            (StringEntry::Dialogue(entry), StringPart::Variant(i)) => {
+                entry.variants.get(i).map(String::as_str)
+            }
+            (StringEntry::Dialogue(entry), StringPart::Exhausted) => entry.exhausted.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Which string of an entry an id refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StringPart {
+    /// The value of a UI string.
+    Text,
+    /// A dialogue variant by index.
+    Variant(usize),
+    /// The text shown once a dialogue entry is exhausted.
+    Exhausted,
+}
+
+/// Identifies a single translatable string across languages.
+///
+/// Formatted as `key`, `key#<variant>` or `key#exhausted` inside its text file.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StringId {
+    /// Text file id relative to the language folder (e.g. "dialogue/npcs/npc").
+    pub file: String,
+    pub key: String,
+    pub part: StringPart,
+}
+
+impl StringId {
+    /// Returns the key column value, including the part suffix.
+    pub fn qualified_key(&self) -> String {
+        match self.part {
+            StringPart::Text => self.key.clone(),
+            StringPart::Variant(i) => format!("{}#{i}", self.key),
+            StringPart::Exhausted => format!("{}#exhausted", self.key),
+        }
+    }
+
+    /// Parses an id from its file and qualified key.
+    pub fn parse(file: &str, qualified_key: &str) -> Result<Self, String> {
+        let (key, part) = match qualified_key.rsplit_once('#') {
+            None => (qualified_key, StringPart::Text),
+            Some((key, "exhausted")) => (key, StringPart::Exhausted),
+            Some((key, index)) => {
+                let index = index
+                    .parse::<usize>()
+                    .map_err(|_| format!("invalid variant in key '{qualified_key}'"))?;
+                (key, StringPart::Variant(index))
+            }
+        };
+        if file.is_empty() || key.is_empty() {
+            return Err(format!("empty file or key in '{file}:{qualified_key}'"));
+        }
+        Ok(Self {
+            file: file.to_string(),
+            key: key.to_string(),
+            part,
+        })
+    }
+}
+
+impl fmt::Display for StringId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.qualified_key())
+    }
+}
+
+/// Every translatable string of one language, grouped by text file.
+#[derive(Debug, Clone, Default)]
+pub struct StringTable {
+    /// Entries by text file id, then by key.
+    pub files: BTreeMap<String, BTreeMap<String, StringEntry>>,
+}
+
+impl StringTable {
+    /// Loads all text files of `language` under `text_root`.
+    /// Files that fail to parse are skipped with a warning.
+    pub fn load(text_root: &Path, language: &str) -> Self {
+        let language_root = text_root.join(language);
+        let mut table = Self::default();
+        let mut paths = Vec::new();
+        collect_toml_files(&language_root, &mut paths);
+
+        for path in paths {
+            let Some(text_id) = text_id_for(&language_root, &path) else {
+                continue;
+            };
+            let parsed = fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| parse_text_file(&content));
+            match parsed {
+                Ok(entries) => {
+                    table.files.insert(text_id, entries);
+                }
+                Err(e) => log::warn!("Failed to read text file '{}': {e}", path.display()),
+            }
+        }
+        table
+    }
+
+    /// Writes one text file of `language` back to disk.
+    /// Comments and key order of the original file are not preserved.
+    pub fn save_file(&self, text_root: &Path, language: &str, text_id: &str) -> Result<(), String> {
+        let Some(entries) = self.files.get(text_id) else {
+            return Err(format!("no text file '{text_id}'"));
+        };
+
+        let mut path = text_root.join(language);
+        for component in text_id.split('/') {
+            path = path.join(component);
+        }
+        path.set_extension("toml");
+
+        let content = serialize_text_file(entries)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(&path, content).map_err(|e| e.to_string())
+    }
+
+    /// Returns the entry for `key` in `file`.
+    pub fn entry(&self, file: &str, key: &str) -> Option<&StringEntry> {
+        self.files.get(file)?.get(key)
+    }
+
+    /// Returns the string an id refers to.
+    pub fn get(&self, id: &StringId) -> Option<&str> {
+        self.entry(&id.file, &id.key)?.get(id.part)
+    }
+
+    /// Returns every string in the table in file and key order.
+    pub fn strings(&self) -> Vec<(StringId, &str)> {
+        let mut strings = Vec::new();
+        for (file, entries) in &self.files {
+            for (key, entry) in entries {
+                for (part, text) in entry.parts() {
+                    let id = StringId {
+                        file: file.clone(),
+                        key: key.clone(),
+                        part,
+                    };
+                    strings.push((id, text));
+                }
+            }
+        }
+        strings
+    }
+
+    /// Sets the string an id refers to. A missing entry is created from
+    /// `template`, usually the default language's entry, so it keeps the same
+    /// kind and selection mode.
+    pub fn set(
+        &mut self,
+        id: &StringId,
+        text: String,
+        template: Option<&StringEntry>,
+    ) -> Result<(), String> {
+        let entries = self.files.entry(id.file.clone()).or_default();
+        let entry = entries
+            .entry(id.key.clone())
+            .or_insert_with(|| match template {
+                Some(StringEntry::Ui(_)) => StringEntry::Ui(String::new()),
+                Some(StringEntry::Dialogue(entry)) => StringEntry::Dialogue(TextEntry {
+                    selection: entry.selection,
+                    exhausted: None,
+                    variants: Vec::new(),
+                }),
+                None if id.part == StringPart::Text => StringEntry::Ui(String::new()),
+                None => StringEntry::Dialogue(TextEntry::default()),
+            });
+
+        match (entry, id.part) {
+            (StringEntry::Ui(value), StringPart::Text) => *value = text,
+            (StringEntry::Dialogue(entry), StringPart::Variant(i)) => {
+                if entry.variants.len() <= i {
+                    entry.variants.resize(i + 1, String::new());
+                }
+                entry.variants[i] = text;
+            }
+            (StringEntry::Dialogue(entry), StringPart::Exhausted) => entry.exhausted = Some(text),
+            _ => return Err(format!("'{id}' does not match the kind of its entry")),
+        }
+        Ok(())
+    }
+}
+
+/// Parses a text file that may contain UI strings, dialogue entries or both.
+pub fn parse_text_file(content: &str) -> Result<BTreeMap<String, StringEntry>, String> {
+    let table: toml::Table = toml::from_str(content).map_err(|e| e.to_string())?;
+    let mut entries = BTreeMap::new();
+
+    for (key, value) in table {
+        let entry = match value {
+            toml::Value::String(text) => StringEntry::Ui(text),
+            value @ toml::Value::Table(_) => StringEntry::Dialogue(
+                value
+                    .try_into::<TextEntry>()
+                    .map_err(|e| format!("entry '{key}': {e}"))?,
+            ),
+            _ => return Err(format!("entry '{key}' is not a string or table")),
+        };
+        entries.insert(key, entry);
+    }
+    Ok(entries)
+}
+
+fn serialize_text_file(entries: &BTreeMap<String, StringEntry>) -> Result<String, String> {
+    let mut table = toml::Table::new();
+    for (key, entry) in entries {
+        let value = match entry {
+            StringEntry::Ui(text) => toml::Value::String(text.clone()),
+            StringEntry::Dialogue(entry) => {
+                toml::Value::try_from(entry).map_err(|e| format!("entry '{key}': {e}"))?
+            }
+        };
+        table.insert(key.clone(), value);
+    }
+    toml::to_string_pretty(&table).map_err(|e| e.to_string())
+}
+
+fn collect_toml_files(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_toml_files(&path, paths);
+        } else if path.extension().is_some_and(|ext| ext == "toml") {
+            paths.push(path);
+        }
+    }
+}
+
+/// Converts a file path inside a language folder to its text id.
+fn text_id_for(language_root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(language_root).ok()?.with_extension("");
+    let components: Vec<_> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    Some(components.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NPC: &str = r#"
+title = "Guard"
+
+[greeting]
+selection = "once"
+exhausted = "..."
+variants = ["Halt!", "Move along."]
+"#;
+
+    #[test]
+    fn parses_ui_and_dialogue_entries() {
+        let entries = parse_text_file(NPC).unwrap();
+
+        assert_eq!(entries["title"].variant_count(), 1);
+        assert_eq!(entries["greeting"].variant_count(), 2);
+        assert_eq!(entries["greeting"].parts().len(), 3);
+    }
+
+    #[test]
+    fn string_ids_round_trip() {
+        for key in ["title", "greeting#1", "greeting#exhausted"] {
+            let id = StringId::parse("npc", key).unwrap();
+            assert_eq!(id.qualified_key(), key);
+        }
+        assert!(StringId::parse("npc", "greeting#x").is_err());
+    }
+
+    #[test]
+    fn set_creates_entries_from_template() {
+        let source = StringTable {
+            files: BTreeMap::from([("npc".to_string(), parse_text_file(NPC).unwrap())]),
+        };
+        let mut table = StringTable::default();
+        let id = StringId::parse("npc", "greeting#1").unwrap();
+
+        table
+            .set(
+                &id,
+                "Circulez.".to_string(),
+                source.entry("npc", "greeting"),
+            )
+            .unwrap();
+
+        let Some(StringEntry::Dialogue(entry)) = table.entry("npc", "greeting") else {
+            panic!("expected a dialogue entry");
+        };
+        assert_eq!(entry.selection, crate::text::SelectionMode::Once);
+        assert_eq!(entry.variants, vec![String::new(), "Circulez.".to_string()]);
+        assert!(
+            table
+                .set(
+                    &StringId::parse("npc", "greeting").unwrap(),
+                    String::new(),
+                    None
+                )
+                .is_err()
+        );
+    }
+}
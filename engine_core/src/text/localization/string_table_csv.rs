@@ -0,0 +1,254 @@
+// engine_core/src/text/localization/string_table_csv.rs
+use crate::text::TextManifest;
+use crate::text::localization::string_table::*;
+use std::collections::{BTreeSet, HashSet};
+use std::fs;
+use std::path::Path;
+
+/// Writes every string of every language to a CSV file for translators.
+/// Returns the number of rows written.
+pub fn export_localization_csv(text_root: &Path, path: &Path) -> Result<usize, String> {
+    let languages = load_languages(text_root);
+    let (csv, rows) = string_tables_to_csv(&languages);
+    fs::write(path, csv).map_err(|e| e.to_string())?;
+    Ok(rows)
+}
+
+/// Reads translations from a CSV file produced by `export_localization_csv` and
+/// writes every changed text file back to disk. Returns the number of strings updated.
+pub fn import_localization_csv(text_root: &Path, path: &Path) -> Result<usize, String> {
+    let csv = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let mut languages = load_languages(text_root);
+    let changed = apply_csv(&csv, &mut languages)?;
+
+    let files: BTreeSet<_> = changed
+        .iter()
+        .map(|(language, id)| (language.as_str(), id.file.as_str()))
+        .collect();
+    for (language, file) in files {
+        let (_, table) = languages
+            .iter()
+            .find(|(name, _)| name == language)
+            .expect("changed language is loaded");
+        table.save_file(text_root, language, file)?;
+    }
+    Ok(changed.len())
+}
+
+/// Loads the string tables of every available language, default language first.
+fn load_languages(text_root: &Path) -> Vec<(String, StringTable)> {
+    let manifest = TextManifest::load(text_root).unwrap_or_default();
+    let mut names = vec![manifest.default_language.clone()];
+    names.extend(
+        manifest
+            .available
+            .into_iter()
+            .filter(|language| *language != manifest.default_language),
+    );
+    names
+        .into_iter()
+        .map(|language| {
+            let table = StringTable::load(text_root, &language);
+            (language, table)
+        })
+        .collect()
+}
+
+/// Builds a CSV with `file`, `key` and one column per language.
+/// Returns the CSV and its row count, excluding the header.
+pub fn string_tables_to_csv(languages: &[(String, StringTable)]) -> (String, usize) {
+    let ids: BTreeSet<StringId> = languages
+        .iter()
+        .flat_map(|(_, table)| table.strings().into_iter().map(|(id, _)| id))
+        .collect();
+
+    let mut csv = String::new();
+    let mut header = vec!["file".to_string(), "key".to_string()];
+    header.extend(languages.iter().map(|(language, _)| language.clone()));
+    push_csv_row(&mut csv, &header);
+
+    for id in &ids {
+        let mut row = vec![id.file.clone(), id.qualified_key()];
+        row.extend(
+            languages
+                .iter()
+                .map(|(_, table)| table.get(id).unwrap_or_default().to_string()),
+        );
+        push_csv_row(&mut csv, &row);
+    }
+    (csv, ids.len())
+}
+
+/// Applies translations from a CSV to the loaded languages. The first language is
+/// the default and provides templates for entries missing elsewhere.
+/// Empty cells are skipped. Returns the language and id of every changed string.
+pub fn apply_csv(
+    csv: &str,
+    languages: &mut [(String, StringTable)],
+) -> Result<Vec<(String, StringId)>, String> {
+    let mut rows = parse_csv(csv)?.into_iter();
+    let header = rows.next().ok_or("CSV is empty")?;
+    if header.len() < 3 || header[0] != "file" || header[1] != "key" {
+        return Err("CSV header must start with 'file,key' followed by languages".to_string());
+    }
+
+    let mut columns = Vec::new();
+    let mut seen = HashSet::new();
+    for (column, language) in header.iter().enumerate().skip(2) {
+        let Some(index) = languages.iter().position(|(name, _)| name == language) else {
+            return Err(format!("language '{language}' is not in the text manifest"));
+        };
+        if !seen.insert(index) {
+            return Err(format!("language '{language}' appears twice"));
+        }
+        columns.push((column, index));
+    }
+
+    let mut changed = Vec::new();
+    for (line, row) in rows.enumerate() {
+        if row.iter().all(|cell| cell.is_empty()) {
+            continue;
+        }
+        let id = StringId::parse(
+            row.first().map(String::as_str).unwrap_or_default(),
+            row.get(1).map(String::as_str).unwrap_or_default(),
+        )
+        .map_err(|e| format!("row {}: {e}", line + 2))?;
+        let template = languages
+            .first()
+            .and_then(|(_, table)| table.entry(&id.file, &id.key))
+            .cloned();
+
+        for &(column, index) in &columns {
+            let Some(text) = row.get(column).filter(|text| !text.is_empty()) else {
+                continue;
+            };
+            let (language, table) = &mut languages[index];
+            if table.get(&id) == Some(text.as_str()) {
+                continue;
+            }
+            table
+                .set(&id, text.clone(), template.as_ref())
+                .map_err(|e| format!("row {}: {e}", line + 2))?;
+            changed.push((language.clone(), id.clone()));
+        }
+    }
+    Ok(changed)
+}
+
+fn push_csv_row(csv: &mut String, cells: &[String]) {
+    let escaped: Vec<String> = cells
+        .iter()
+        .map(|cell| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.clone()
+            }
+        })
+        .collect();
+    csv.push_str(&escaped.join(","));
+    csv.push('\n');
+}
+
+/// Parses RFC 4180 CSV, allowing quoted fields with commas, quotes and newlines.
+fn parse_csv(csv: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = csv.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if quoted {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                c => field.push(c),
+            }
+            continue;
+        }
+        match ch {
+            '"' if field.is_empty() => quoted = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err("unterminated quoted field".to_string());
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn languages() -> Vec<(String, StringTable)> {
+        let english = StringTable {
+            files: BTreeMap::from([(
+                "npc".to_string(),
+                parse_text_file(
+                    "title = \"Guard, \\\"Bob\\\"\"\n[hello]\nselection = \"once\"\nvariants = [\"Hi\", \"Hey\"]",
+                )
+                .unwrap(),
+            )]),
+        };
+        vec![
+            ("en".to_string(), english),
+            ("fr".to_string(), StringTable::default()),
+        ]
+    }
+
+    #[test]
+    fn exports_one_row_per_string_with_escaping() {
+        let (csv, rows) = string_tables_to_csv(&languages());
+
+        assert_eq!(rows, 3);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "file,key,en,fr");
+        assert_eq!(lines[1], "npc,hello#0,Hi,");
+        assert_eq!(lines[3], "npc,title,\"Guard, \"\"Bob\"\"\",");
+    }
+
+    #[test]
+    fn import_round_trips_translations() {
+        let mut languages = languages();
+        let csv = "file,key,fr\r\nnpc,hello#1,\"Salut, toi\"\nnpc,title,Garde\nnpc,hello#0,\n";
+
+        let changed = apply_csv(csv, &mut languages).unwrap();
+
+        assert_eq!(changed.len(), 2);
+        let french = &languages[1].1;
+        assert_eq!(
+            french.get(&StringId::parse("npc", "title").unwrap()),
+            Some("Garde")
+        );
+        assert_eq!(
+            french.get(&StringId::parse("npc", "hello#1").unwrap()),
+            Some("Salut, toi")
+        );
+        let (exported, _) = string_tables_to_csv(&languages);
+        assert_eq!(parse_csv(&exported).unwrap()[2][3], "Salut, toi");
+    }
+
+    #[test]
+    fn rejects_unknown_languages() {
+        let mut languages = languages();
+        assert!(apply_csv("file,key,de\nnpc,title,Wache\n", &mut languages).is_err());
+    }
+}
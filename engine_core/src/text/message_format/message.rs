@@ -0,0 +1,604 @@
+// engine_core/src/text/message_format/message.rs
+use crate::text::message_format::number_format::*;
+use crate::text::message_format::plural_rules::*;
+use crate::text::message_format::text_value::*;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A malformed placeholder or argument in a message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageError {
+    /// Byte offset of the problem in the message source.
+    pub offset: usize,
+    pub message: String,
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+/// A message in a subset of ICU MessageFormat.
+///
+/// Supports `{{name}}` placeholders, `{name}`, `{name, number[, integer|percent|::.00]}`,
+/// `{name, plural, [offset:n] =0 {...} one {...} other {...}}` with `#` for the
+/// number, and `{name, select, a {...} other {...}}`. An apostrophe quotes
+/// literal braces (`'{'`) and `''` is a single apostrophe. A `#` inside a
+/// `[...]` markup tag, such as `[color=#f00]`, is kept as written.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Message {
+    parts: Vec<MessagePart>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum MessagePart {
+    Text(String),
+    /// `#` inside a plural branch.
+    PluralValue,
+    Argument {
+        name: String,
+        kind: ArgumentKind,
+        /// Original source, shown when the value is missing.
+        source: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ArgumentKind {
+    Simple,
+    Number(NumberStyle),
+    Plural {
+        offset: f64,
+        branches: Vec<(PluralKey, Message)>,
+    },
+    Select {
+        branches: Vec<(String, Message)>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PluralKey {
+    Exact(f64),
+    Category(PluralCategory),
+}
+
+impl Message {
+    /// Parses a message, reporting the first malformed placeholder.
+    pub fn parse(source: &str) -> Result<Self, MessageError> {
+        let mut parser = Parser::new(source, false);
+        let parts = parser.parse_parts(false, false)?;
+        Ok(Self { parts })
+    }
+
+    /// Parses a message, keeping each malformed span as literal text so the
+    /// rest still formats. Returns the message and the problems found.
+    pub fn parse_lenient(source: &str) -> (Self, Vec<MessageError>) {
+        let mut parser = Parser::new(source, true);
+        let parts = parser.parse_parts(false, false).unwrap_or_default();
+        (Self { parts }, parser.errors)
+    }
+
+    /// Formats the message for `language`. Arguments without a value are left as written.
+    pub fn format(&self, language: &str, values: &HashMap<String, TextValue>) -> String {
+        let mut out = String::new();
+        self.format_into(&mut out, language, values, None);
+        out
+    }
+
+    fn format_into(
+        &self,
+        out: &mut String,
+        language: &str,
+        values: &HashMap<String, TextValue>,
+        plural_value: Option<f64>,
+    ) {
+        for part in &self.parts {
+            match part {
+                MessagePart::Text(text) => out.push_str(text),
+                MessagePart::PluralValue => match plural_value {
+                    Some(n) => out.push_str(&format_number(n, NumberStyle::Default, language)),
+                    None => out.push('#'),
+                },
+                MessagePart::Argument { name, kind, source } => {
+                    let Some(value) = values.get(name) else {
+                        out.push_str(source);
+                        continue;
+                    };
+                    match kind {
+                        ArgumentKind::Simple => out.push_str(&value.to_string()),
+                        ArgumentKind::Number(style) => match value.as_number() {
+                            Some(n) => out.push_str(&format_number(n, *style, language)),
+                            None => out.push_str(&value.to_string()),
+                        },
+                        ArgumentKind::Plural { offset, branches } => {
+                            let Some(n) = value.as_number() else {
+                                out.push_str(source);
+                                continue;
+                            };
+                            let category = plural_category(language, n - offset);
+                            let branch = branches
+                                .iter()
+                                .find(|(key, _)| *key == PluralKey::Exact(n))
+                                .or_else(|| {
+                                    branches
+                                        .iter()
+                                        .find(|(key, _)| *key == PluralKey::Category(category))
+                                })
+                                .or_else(|| {
+                                    branches.iter().find(|(key, _)| {
+                                        *key == PluralKey::Category(PluralCategory::Other)
+                                    })
+                                });
+                            if let Some((_, message)) = branch {
+                                message.format_into(out, language, values, Some(n - offset));
+                            }
+                        }
+                        ArgumentKind::Select { branches } => {
+                            let key = value.to_string();
+                            let branch = branches
+                                .iter()
+                                .find(|(name, _)| *name == key)
+                                .or_else(|| branches.iter().find(|(name, _)| name == "other"));
+                            if let Some((_, message)) = branch {
+                                message.format_into(out, language, values, plural_value);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+    /// Whether top-level errors are kept as literal text instead of failing the parse.
+    lenient: bool,
+    errors: Vec<MessageError>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str, lenient: bool) -> Self {
+        Self {
+            source,
+            pos: 0,
+            lenient,
+            errors: Vec::new(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn error(&self, offset: usize, message: impl Into<String>) -> MessageError {
+        MessageError {
+            offset,
+            message: message.into(),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), MessageError> {
+        match self.peek() {
+            Some(ch) if ch == expected => {
+                self.bump();
+                Ok(())
+            }
+            Some(ch) => Err(self.error(self.pos, format!("expected '{expected}', found '{ch}'"))),
+            None => Err(self.error(self.pos, format!("expected '{expected}'"))),
+        }
+    }
+
+    /// Reads a run of characters that are not whitespace or syntax.
+    fn word(&mut self) -> &str {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && !matches!(c, '{' | '}' | ','))
+        {
+            self.bump();
+        }
+        &self.source[start..self.pos]
+    }
+
+    fn identifier(&mut self) -> Result<String, MessageError> {
+        let start = self.pos;
+        let word = self.word();
+        if word.is_empty()
+            || !word
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+        {
+            return Err(self.error(start, "expected an argument name"));
+        }
+        Ok(word.to_string())
+    }
+
+    /// Keeps the source from `start` to where parsing stopped as literal text
+    /// when lenient and at the top level, otherwise returns `error`.
+    fn recover(
+        &mut self,
+        start: usize,
+        nested: bool,
+        error: MessageError,
+        text: &mut String,
+    ) -> Result<(), MessageError> {
+        if !self.lenient || nested {
+            return Err(error);
+        }
+        // Every span starts with a single-byte brace or apostrophe
+        let end = self.pos.max(start + 1);
+        text.push_str(&self.source[start..end]);
+        self.pos = end;
+        self.errors.push(error);
+        Ok(())
+    }
+
+    /// Parses text and arguments until the end of input, or until an unmatched
+    /// `}` when `nested`.
+    fn parse_parts(
+        &mut self,
+        in_plural: bool,
+        nested: bool,
+    ) -> Result<Vec<MessagePart>, MessageError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        // Inside a rich text tag, where `#` starts a colour rather than the plural value
+        let mut in_tag = false;
+        let flush = |text: &mut String, parts: &mut Vec<MessagePart>| {
+            if !text.is_empty() {
+                parts.push(MessagePart::Text(std::mem::take(text)));
+            }
+        };
+
+        while let Some(ch) = self.peek() {
+            match ch {
+                '\'' => {
+                    let start = self.pos;
+                    self.bump();
+                    match self.peek() {
+                        Some('\'') => {
+                            self.bump();
+                            text.push('\'');
+                        }
+                        Some('{' | '}') => {
+                            if let Err(e) = self.quoted(&mut text, start) {
+                                self.recover(start, nested, e, &mut text)?;
+                            }
+                        }
+                        Some('#') if in_plural => self.quoted(&mut text, start)?,
+                        _ => text.push('\''),
+                    }
+                }
+                '{' => {
+                    let start = self.pos;
+                    match self.parse_argument(in_plural) {
+                        Ok(part) => {
+                            flush(&mut text, &mut parts);
+                            parts.push(part);
+                        }
+                        Err(e) => self.recover(start, nested, e, &mut text)?,
+                    }
+                }
+                '}' if nested => break,
+                '}' => {
+                    let error = self.error(self.pos, "unmatched '}'");
+                    self.recover(self.pos, nested, error, &mut text)?;
+                }
+                '[' => {
+                    self.bump();
+                    text.push('[');
+                    // `[[` is a literal bracket, not a tag
+                    if self.peek() == Some('[') {
+                        self.bump();
+                        text.push('[');
+                    } else {
+                        in_tag = true;
+                    }
+                }
+                ']' => {
+                    self.bump();
+                    text.push(']');
+                    in_tag = false;
+                }
+                '#' if in_plural && !in_tag => {
+                    self.bump();
+                    flush(&mut text, &mut parts);
+                    parts.push(MessagePart::PluralValue);
+                }
+                ch => {
+                    self.bump();
+                    text.push(ch);
+                }
+            }
+        }
+
+        flush(&mut text, &mut parts);
+        Ok(parts)
+    }
+
+    /// Reads quoted literal text up to the closing apostrophe.
+    fn quoted(&mut self, text: &mut String, start: usize) -> Result<(), MessageError> {
+        loop {
+            match self.bump() {
+                None => return Err(self.error(start, "unterminated quote")),
+                Some('\'') if self.peek() == Some('\'') => {
+                    self.bump();
+                    text.push('\'');
+                }
+                Some('\'') => return Ok(()),
+                Some(ch) => text.push(ch),
+            }
+        }
+    }
+
+    fn parse_argument(&mut self, in_plural: bool) -> Result<MessagePart, MessageError> {
+        let start = self.pos;
+        self.bump();
+
+        // Legacy `{{name}}` placeholder.
+        if self.peek() == Some('{') {
+            self.bump();
+            self.skip_whitespace();
+            let name = self.identifier()?;
+            self.skip_whitespace();
+            self.expect('}')?;
+            self.expect('}')?;
+            return Ok(MessagePart::Argument {
+                name,
+                kind: ArgumentKind::Simple,
+                source: self.source[start..self.pos].to_string(),
+            });
+        }
+
+        self.skip_whitespace();
+        let name = self.identifier()?;
+        self.skip_whitespace();
+
+        let kind = if self.peek() == Some(',') {
+            self.bump();
+            self.skip_whitespace();
+            let type_start = self.pos;
+            let kind_name = self.word().to_string();
+            self.skip_whitespace();
+            match kind_name.as_str() {
+                "number" => {
+                    let style = if self.peek() == Some(',') {
+                        self.bump();
+                        let style_start = self.pos;
+                        while self.peek().is_some_and(|c| c != '}') {
+                            self.bump();
+                        }
+                        let style = &self.source[style_start..self.pos];
+                        NumberStyle::parse(style).ok_or_else(|| {
+                            self.error(
+                                style_start,
+                                format!("unknown number style '{}'", style.trim()),
+                            )
+                        })?
+                    } else {
+                        NumberStyle::Default
+                    };
+                    ArgumentKind::Number(style)
+                }
+                "plural" => {
+                    self.expect(',')?;
+                    self.parse_plural()?
+                }
+                "select" => {
+                    self.expect(',')?;
+                    let branches = self
+                        .parse_branches(in_plural)?
+                        .into_iter()
+                        .map(|(key, _, message)| (key, message))
+                        .collect();
+                    ArgumentKind::Select { branches }
+                }
+                other => {
+                    return Err(self.error(type_start, format!("unknown argument type '{other}'")));
+                }
+            }
+        } else {
+            ArgumentKind::Simple
+        };
+
+        self.skip_whitespace();
+        self.expect('}')?;
+        Ok(MessagePart::Argument {
+            name,
+            kind,
+            source: self.source[start..self.pos].to_string(),
+        })
+    }
+
+    fn parse_plural(&mut self) -> Result<ArgumentKind, MessageError> {
+        self.skip_whitespace();
+        let mut offset = 0.0;
+        if self.source[self.pos..].starts_with("offset:") {
+            self.pos += "offset:".len();
+            let number_start = self.pos;
+            offset = self
+                .word()
+                .parse()
+                .map_err(|_| self.error(number_start, "invalid plural offset"))?;
+        }
+
+        let mut branches = Vec::new();
+        for (key, key_start, message) in self.parse_branches(true)? {
+            let plural_key =
+                match key.strip_prefix('=') {
+                    Some(exact) => PluralKey::Exact(exact.parse().map_err(|_| {
+                        self.error(key_start, format!("invalid plural key '{key}'"))
+                    })?),
+                    None => PluralKey::Category(PluralCategory::parse(&key).ok_or_else(|| {
+                        self.error(key_start, format!("unknown plural category '{key}'"))
+                    })?),
+                };
+            branches.push((plural_key, message));
+        }
+        Ok(ArgumentKind::Plural { offset, branches })
+    }
+
+    /// Parses `key {message}` branches up to the closing brace of the argument,
+    /// which is left unconsumed. An `other` branch is required.
+    fn parse_branches(
+        &mut self,
+        in_plural: bool,
+    ) -> Result<Vec<(String, usize, Message)>, MessageError> {
+        let start = self.pos;
+        let mut branches = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => break,
+                None => return Err(self.error(start, "unclosed argument")),
+                _ => {}
+            }
+
+            let key_start = self.pos;
+            let key = self.word().to_string();
+            if key.is_empty() {
+                return Err(self.error(key_start, "expected a branch key"));
+            }
+            self.skip_whitespace();
+            self.expect('{')?;
+            let parts = self.parse_parts(in_plural, true)?;
+            if self.peek() != Some('}') {
+                return Err(self.error(key_start, format!("unclosed branch '{key}'")));
+            }
+            self.bump();
+            branches.push((key, key_start, Message { parts }));
+        }
+
+        if !branches.iter().any(|(key, _, _)| key == "other") {
+            return Err(self.error(start, "missing 'other' branch"));
+        }
+        Ok(branches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(source: &str, language: &str, values: &[(&str, TextValue)]) -> String {
+        let values = values
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect();
+        Message::parse(source).unwrap().format(language, &values)
+    }
+
+    #[test]
+    fn formats_plural_branches_per_language() {
+        let source = "{count, plural, =0 {no coins} one {# coin} other {# coins}}";
+
+        assert_eq!(format(source, "en", &[("count", 0.0.into())]), "no coins");
+        assert_eq!(format(source, "en", &[("count", 1.0.into())]), "1 coin");
+        assert_eq!(
+            format(source, "en", &[("count", 1200.0.into())]),
+            "1,200 coins"
+        );
+        assert_eq!(format(source, "fr", &[("count", 1.5.into())]), "1,5 coin");
+    }
+
+    #[test]
+    fn formats_select_and_nested_arguments() {
+        let source = "{gender, select, female {She has} other {They have}} {n, plural, one {a {item}} other {# {item}s}}";
+        let values = [
+            ("gender", "female".into()),
+            ("n", 3.0.into()),
+            ("item", "key".into()),
+        ];
+
+        assert_eq!(format(source, "en", &values), "She has 3 keys");
+    }
+
+    #[test]
+    fn formats_numbers_and_legacy_placeholders() {
+        let source = "{{name}} scored {score, number} ({ratio, number, percent})";
+        let values = [
+            ("name", "Hero".into()),
+            ("score", 12345.0.into()),
+            ("ratio", 0.5.into()),
+        ];
+
+        assert_eq!(format(source, "en", &values), "Hero scored 12,345 (50%)");
+    }
+
+    #[test]
+    fn quotes_escape_braces() {
+        assert_eq!(
+            format(
+                "Use '{name}' or it''s {name}",
+                "en",
+                &[("name", "x".into())]
+            ),
+            "Use {name} or it's x"
+        );
+        assert_eq!(format("don't", "en", &[]), "don't");
+    }
+
+    #[test]
+    fn missing_values_keep_their_source() {
+        assert_eq!(
+            format("Hi {{name}} {n, plural, other {#}}", "en", &[]),
+            "Hi {{name}} {n, plural, other {#}}"
+        );
+    }
+
+    #[test]
+    fn plural_value_skips_markup_tags() {
+        let source = "{n, plural, one {[color=#f00]#[/color] coin} other {[color=#0f0]#[/color] [[#] coins}}";
+
+        assert_eq!(
+            format(source, "en", &[("n", 1.0.into())]),
+            "[color=#f00]1[/color] coin"
+        );
+        assert_eq!(
+            format(source, "en", &[("n", 5.0.into())]),
+            "[color=#0f0]5[/color] [[5] coins"
+        );
+    }
+
+    #[test]
+    fn reports_malformed_placeholders() {
+        let error = |source: &str| Message::parse(source).unwrap_err();
+
+        assert_eq!(error("Hello {name").offset, 11);
+        assert_eq!(error("Hello }").message, "unmatched '}'");
+        assert_eq!(
+            error("{n, plural, one {x}}").message,
+            "missing 'other' branch"
+        );
+        assert_eq!(error("{n, plural, lots {x} other {y}}").offset, 12);
+        assert!(error("{n, date}").message.contains("unknown argument type"));
+        assert!(error("{}").message.contains("argument name"));
+    }
+
+    #[test]
+    fn lenient_parsing_keeps_malformed_spans_as_text() {
+        let values = HashMap::from([("name".to_string(), TextValue::from("Hero"))]);
+        let (message, errors) = Message::parse_lenient("{{name} meets {{name}} }{n, date}");
+
+        assert_eq!(errors.len(), 4);
+        assert_eq!(
+            message.format("en", &values),
+            "{{name} meets Hero }{n, date}"
+        );
+    }
+}
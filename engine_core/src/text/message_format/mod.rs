@@ -0,0 +1,11 @@
+// engine_core/src/text/message_format/mod.rs
+
+pub mod message;
+pub mod number_format;
+pub mod plural_rules;
+pub mod text_value;
+
+pub use message::*;
+pub use number_format::*;
+pub use plural_rules::*;
+pub use text_value::*;
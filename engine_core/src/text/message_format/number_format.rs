@@ -0,0 +1,117 @@
+// engine_core/src/text/message_format/number_format.rs
+
+/// Maximum fraction digits shown by the default number style.
+const DEFAULT_FRACTION_DIGITS: usize = 3;
+
+/// How a `{name, number, style}` argument is formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberStyle {
+    /// Up to three fraction digits, trailing zeros removed.
+    #[default]
+    Default,
+    /// Rounded to a whole number.
+    Integer,
+    /// Multiplied by 100 and rounded, with a percent sign.
+    Percent,
+    /// A fixed number of fraction digits, written as `::.00`.
+    Fixed(usize),
+}
+
+impl NumberStyle {
+    /// Parses a number argument style. An empty style is the default.
+    pub fn parse(style: &str) -> Option<Self> {
+        match style.trim() {
+            "" => Some(NumberStyle::Default),
+            "integer" => Some(NumberStyle::Integer),
+            "percent" => Some(NumberStyle::Percent),
+            skeleton => {
+                let zeros = skeleton.strip_prefix("::.")?;
+                if zeros.chars().all(|c| c == '0') {
+                    Some(NumberStyle::Fixed(zeros.len()))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Returns the decimal and grouping separators used by `language`.
+fn separators(language: &str) -> (char, char) {
+    let primary = language.split(['-', '_']).next().unwrap_or_default();
+    match primary {
+        "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" => (',', '.'),
+        "fr" | "ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" => (',', '\u{a0}'),
+        _ => ('.', ','),
+    }
+}
+
+/// Formats a number with the separators of `language`.
+pub fn format_number(value: f64, style: NumberStyle, language: &str) -> String {
+    let (value, decimals, suffix) = match style {
+        NumberStyle::Default => (value, DEFAULT_FRACTION_DIGITS, ""),
+        NumberStyle::Integer => (value, 0, ""),
+        NumberStyle::Percent => (value * 100.0, 0, "%"),
+        NumberStyle::Fixed(digits) => (value, digits, ""),
+    };
+
+    let mut digits = format!("{:.*}", decimals, value.abs());
+    if style == NumberStyle::Default && digits.contains('.') {
+        digits = digits
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string();
+    }
+    let negative = value < 0.0 && digits.chars().any(|c| c.is_ascii_digit() && c != '0');
+
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (digits.as_str(), None),
+    };
+    let (decimal_separator, group_separator) = separators(language);
+
+    let mut out = String::new();
+    if negative {
+        out.push('-');
+    }
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            out.push(group_separator);
+        }
+        out.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        out.push(decimal_separator);
+        out.push_str(fraction);
+    }
+    out.push_str(suffix);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_thousands_per_language() {
+        assert_eq!(
+            format_number(1234567.5, NumberStyle::Default, "en"),
+            "1,234,567.5"
+        );
+        assert_eq!(format_number(1234.5, NumberStyle::Default, "de"), "1.234,5");
+        assert_eq!(format_number(-999.0, NumberStyle::Default, "en"), "-999");
+    }
+
+    #[test]
+    fn applies_styles() {
+        assert_eq!(
+            format_number(2.0 / 3.0, NumberStyle::Default, "en"),
+            "0.667"
+        );
+        assert_eq!(format_number(2.5, NumberStyle::Fixed(2), "en"), "2.50");
+        assert_eq!(format_number(0.256, NumberStyle::Percent, "en"), "26%");
+        assert_eq!(format_number(-0.2, NumberStyle::Integer, "en"), "0");
+        assert_eq!(NumberStyle::parse("::.000"), Some(NumberStyle::Fixed(3)));
+        assert_eq!(NumberStyle::parse("currency"), None);
+    }
+}
@@ -0,0 +1,138 @@
+// engine_core/src/text/message_format/plural_rules.rs
+
+/// CLDR plural category of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Parses a category keyword used in a plural branch.
+    pub fn parse(keyword: &str) -> Option<Self> {
+        match keyword {
+            "zero" => Some(PluralCategory::Zero),
+            "one" => Some(PluralCategory::One),
+            "two" => Some(PluralCategory::Two),
+            "few" => Some(PluralCategory::Few),
+            "many" => Some(PluralCategory::Many),
+            "other" => Some(PluralCategory::Other),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the cardinal plural category of `n` in `language`.
+///
+/// Covers the CLDR rules of common game languages. Unknown languages use the
+/// English rule. Region subtags (`pt-BR`, `zh_Hans`) are ignored.
+pub fn plural_category(language: &str, n: f64) -> PluralCategory {
+    let primary = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let n = n.abs();
+    let integer = n.fract() == 0.0;
+    let i = n.trunc() as u64;
+    let (i10, i100) = (i % 10, i % 100);
+
+    match primary.as_str() {
+        "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" => PluralCategory::Other,
+        "fr" | "pt" => {
+            if i <= 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        "ru" | "uk" | "be" => {
+            if !integer {
+                PluralCategory::Other
+            } else if i10 == 1 && i100 != 11 {
+                PluralCategory::One
+            } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "pl" => {
+            if !integer {
+                PluralCategory::Other
+            } else if i == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "cs" | "sk" => {
+            if !integer {
+                PluralCategory::Many
+            } else if i == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&i) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        "ar" => {
+            if !integer {
+                PluralCategory::Other
+            } else if i == 0 {
+                PluralCategory::Zero
+            } else if i == 1 {
+                PluralCategory::One
+            } else if i == 2 {
+                PluralCategory::Two
+            } else if (3..=10).contains(&i100) {
+                PluralCategory::Few
+            } else if (11..=99).contains(&i100) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        _ => {
+            if integer && i == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_distinguishes_one_from_other() {
+        assert_eq!(plural_category("en", 1.0), PluralCategory::One);
+        assert_eq!(plural_category("en-GB", 0.0), PluralCategory::Other);
+        assert_eq!(plural_category("en", 1.5), PluralCategory::Other);
+    }
+
+    #[test]
+    fn french_treats_zero_as_one() {
+        assert_eq!(plural_category("fr", 0.0), PluralCategory::One);
+        assert_eq!(plural_category("fr", 1.5), PluralCategory::One);
+        assert_eq!(plural_category("fr", 2.0), PluralCategory::Other);
+    }
+
+    #[test]
+    fn russian_uses_few_and_many() {
+        assert_eq!(plural_category("ru", 21.0), PluralCategory::One);
+        assert_eq!(plural_category("ru", 3.0), PluralCategory::Few);
+        assert_eq!(plural_category("ru", 12.0), PluralCategory::Many);
+        assert_eq!(plural_category("ru", 25.0), PluralCategory::Many);
+    }
+}
@@ -0,0 +1,62 @@
+// engine_core/src/text/message_format/text_value.rs
+use std::fmt;
+
+/// A typed value substituted into a text message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextValue {
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+impl TextValue {
+    /// Returns the value as a number, parsing text if needed.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            TextValue::Number(n) => Some(*n),
+            TextValue::Text(s) => s.trim().parse().ok(),
+            TextValue::Bool(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for TextValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TextValue::Bool(b) => write!(f, "{b}"),
+            TextValue::Number(n) if n.fract() == 0.0 => write!(f, "{}", *n as i64),
+            TextValue::Number(n) => write!(f, "{n}"),
+            TextValue::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+impl From<bool> for TextValue {
+    fn from(value: bool) -> Self {
+        TextValue::Bool(value)
+    }
+}
+
+impl From<f64> for TextValue {
+    fn from(value: f64) -> Self {
+        TextValue::Number(value)
+    }
+}
+
+impl From<i64> for TextValue {
+    fn from(value: i64) -> Self {
+        TextValue::Number(value as f64)
+    }
+}
+
+impl From<&str> for TextValue {
+    fn from(value: &str) -> Self {
+        TextValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for TextValue {
+    fn from(value: String) -> Self {
+        TextValue::Text(value)
+    }
+}
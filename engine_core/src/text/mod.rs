@@ -2,10 +2,16 @@
 
 pub mod dialogue;
 pub mod interpolation;
+pub mod localization;
+pub mod message_format;
+pub mod rich_text;
 pub mod text_data;
 pub mod text_manager;
 
 pub use dialogue::*;
 pub use interpolation::*;
+pub use localization::*;
+pub use message_format::*;
+pub use rich_text::*;
 pub use text_data::*;
 pub use text_manager::*;
@@ -0,0 +1,10 @@
+// engine_core/src/text/rich_text/mod.rs
+
+pub mod rich_text_layout;
+pub mod rich_text_markup;
+pub mod rich_text_render;
+pub mod rich_text_reveal;
+
+pub use rich_text_layout::*;
+pub use rich_text_markup::*;
+pub use rich_text_render::*;
@@ -0,0 +1,309 @@
+// engine_core/src/text/rich_text/rich_text_layout.rs
+use crate::text::rich_text::rich_text_markup::*;
+use bishop::prelude::*;
+
+/// Line height as a multiple of the font size.
+pub const RICH_TEXT_LINE_SPACING: f32 = 1.2;
+
+/// What a laid out fragment draws.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RichFragmentContent {
+    Text(String),
+    Icon(String),
+}
+
+/// A run of content on one line with a single style.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RichFragment {
+    pub content: RichFragmentContent,
+    pub style: RichTextStyle,
+    /// Offset from the start of the line.
+    pub x: f32,
+    pub width: f32,
+}
+
+/// A single wrapped line.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RichLine {
+    pub fragments: Vec<RichFragment>,
+    pub width: f32,
+}
+
+/// Rich text broken into positioned lines.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RichTextLayout {
+    pub lines: Vec<RichLine>,
+    /// Width of the widest line.
+    pub width: f32,
+    pub font: FontId,
+    pub font_size: f32,
+    pub line_height: f32,
+}
+
+impl RichTextLayout {
+    /// Returns the total height of all lines.
+    pub fn height(&self) -> f32 {
+        self.lines.len() as f32 * self.line_height
+    }
+}
+
+/// Lays out rich text, wrapping words that would exceed `max_width`.
+///
+/// Runs of whitespace collapse into a single space and `\n` forces a line break.
+/// A word wider than `max_width` gets its own line instead of being split.
+pub fn layout_rich_text<C: Text>(
+    ctx: &C,
+    text: &RichText,
+    font: FontId,
+    font_size: f32,
+    max_width: Option<f32>,
+) -> RichTextLayout {
+    let mut builder = LayoutBuilder {
+        ctx,
+        font,
+        font_size,
+        max_width,
+        space_width: ctx.measure_text_with_font(" ", font, font_size).width,
+        lines: Vec::new(),
+        line: RichLine::default(),
+        word: Vec::new(),
+        piece: String::new(),
+        pending_space: false,
+    };
+
+    for span in &text.spans {
+        match span {
+            RichSpan::Text { text, style } => builder.push_text(text, *style),
+            RichSpan::Variable { name, style } => {
+                builder.push_text(&variable_placeholder(name), *style)
+            }
+            RichSpan::Icon { name, style } => {
+                builder.flush_piece(*style);
+                builder.word.push(WordPiece {
+                    content: RichFragmentContent::Icon(name.clone()),
+                    style: *style,
+                    width: font_size,
+                });
+            }
+            RichSpan::Pause(_) => {}
+        }
+    }
+    builder.finish()
+}
+
+/// Part of a word that shares one style.
+struct WordPiece {
+    content: RichFragmentContent,
+    style: RichTextStyle,
+    width: f32,
+}
+
+struct LayoutBuilder<'a, C: Text> {
+    ctx: &'a C,
+    font: FontId,
+    font_size: f32,
+    max_width: Option<f32>,
+    space_width: f32,
+    lines: Vec<RichLine>,
+    line: RichLine,
+    /// Pieces of the word being collected. Words can span several styles.
+    word: Vec<WordPiece>,
+    /// Text of the current piece, not yet measured.
+    piece: String,
+    /// Whether whitespace was seen since the last placed word.
+    pending_space: bool,
+}
+
+impl<C: Text> LayoutBuilder<'_, C> {
+    fn measure(&self, text: &str) -> f32 {
+        self.ctx
+            .measure_text_with_font(text, self.font, self.font_size)
+            .width
+    }
+
+    fn push_text(&mut self, text: &str, style: RichTextStyle) {
+        for ch in text.chars() {
+            if ch.is_whitespace() {
+                self.flush_piece(style);
+                self.place_word();
+                if ch == '\n' {
+                    self.break_line();
+                } else {
+                    self.pending_space = true;
+                }
+            } else {
+                self.piece.push(ch);
+            }
+        }
+        self.flush_piece(style);
+    }
+
+    fn flush_piece(&mut self, style: RichTextStyle) {
+        if self.piece.is_empty() {
+            return;
+        }
+        let text = std::mem::take(&mut self.piece);
+        let width = self.measure(&text);
+        self.word.push(WordPiece {
+            content: RichFragmentContent::Text(text),
+            style,
+            width,
+        });
+    }
+
+    /// Places the collected word on the current line, wrapping first if it does not fit.
+    fn place_word(&mut self) {
+        if self.word.is_empty() {
+            return;
+        }
+
+        let word_width: f32 = self.word.iter().map(|piece| piece.width).sum();
+        let mut space = self.pending_space && !self.line.fragments.is_empty();
+        if let Some(max_width) = self.max_width {
+            let needed = self.line.width + if space { self.space_width } else { 0.0 } + word_width;
+            if space && needed > max_width {
+                self.break_line();
+                space = false;
+            }
+        }
+
+        for piece in std::mem::take(&mut self.word) {
+            self.place_piece(piece, space);
+            space = false;
+        }
+        self.pending_space = false;
+    }
+
+    fn place_piece(&mut self, piece: WordPiece, space: bool) {
+        let (ctx, font, font_size) = (self.ctx, self.font, self.font_size);
+        // Merge into the previous fragment when the style matches to keep draw calls low
+        if let Some(last) = self.line.fragments.last_mut()
+            && last.style == piece.style
+            && let (RichFragmentContent::Text(last_text), RichFragmentContent::Text(text)) =
+                (&mut last.content, &piece.content)
+        {
+            if space {
+                last_text.push(' ');
+            }
+            last_text.push_str(text);
+            last.width = ctx.measure_text_with_font(last_text, font, font_size).width;
+            self.line.width = last.x + last.width;
+            return;
+        }
+
+        let x = self.line.width + if space { self.space_width } else { 0.0 };
+        self.line.width = x + piece.width;
+        self.line.fragments.push(RichFragment {
+            content: piece.content,
+            style: piece.style,
+            x,
+            width: piece.width,
+        });
+    }
+
+    fn break_line(&mut self) {
+        self.lines.push(std::mem::take(&mut self.line));
+        self.pending_space = false;
+    }
+
+    fn finish(mut self) -> RichTextLayout {
+        self.place_word();
+        if !self.line.fragments.is_empty() {
+            self.lines.push(std::mem::take(&mut self.line));
+        }
+
+        let width = self
+            .lines
+            .iter()
+            .map(|line| line.width)
+            .fold(0.0_f32, f32::max);
+        RichTextLayout {
+            lines: self.lines,
+            width,
+            font: self.font,
+            font_size: self.font_size,
+            line_height: self.font_size * RICH_TEXT_LINE_SPACING,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Measures every character as half the font size wide.
+    struct MonospaceContext;
+
+    impl Text for MonospaceContext {
+        fn draw_text(
+            &mut self,
+            _text: &str,
+            _x: f32,
+            _y: f32,
+            _font_size: f32,
+            _color: Color,
+        ) -> TextDimensions {
+            TextDimensions::default()
+        }
+
+        fn draw_text_ex(
+            &mut self,
+            _text: &str,
+            _x: f32,
+            _y: f32,
+            _params: TextParams,
+        ) -> TextDimensions {
+            TextDimensions::default()
+        }
+
+        fn measure_text(&self, text: &str, font_size: f32) -> TextDimensions {
+            TextDimensions {
+                width: text.chars().count() as f32 * font_size / 2.0,
+                ..Default::default()
+            }
+        }
+    }
+
+    fn line_text(line: &RichLine) -> String {
+        line.fragments
+            .iter()
+            .map(|fragment| match &fragment.content {
+                RichFragmentContent::Text(text) => text.clone(),
+                RichFragmentContent::Icon(name) => format!("<{name}>"),
+            })
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+
+    #[test]
+    fn wraps_words_at_max_width() {
+        let rich = RichText::parse("one two three four");
+        // 10 units per character
+        let layout = layout_rich_text(&MonospaceContext, &rich, FontId::DEFAULT, 20.0, Some(90.0));
+
+        let lines: Vec<String> = layout.lines.iter().map(line_text).collect();
+        assert_eq!(lines, vec!["one two", "three", "four"]);
+        assert_eq!(layout.width, 70.0);
+        assert_eq!(layout.height(), 3.0 * 24.0);
+    }
+
+    #[test]
+    fn styled_pieces_stay_in_one_word() {
+        let rich = RichText::parse("ab[color=red]cd[/color] ef[icon=coin]");
+        let layout = layout_rich_text(&MonospaceContext, &rich, FontId::DEFAULT, 20.0, Some(45.0));
+
+        let lines: Vec<String> = layout.lines.iter().map(line_text).collect();
+        assert_eq!(lines, vec!["ab|cd", "ef|<coin>"]);
+        let icon = &layout.lines[1].fragments[1];
+        assert_eq!((icon.x, icon.width), (20.0, 20.0));
+    }
+
+    #[test]
+    fn newline_forces_a_break_and_whitespace_collapses() {
+        let rich = RichText::parse("a   b\n\nc");
+        let layout = layout_rich_text(&MonospaceContext, &rich, FontId::DEFAULT, 20.0, None);
+
+        let lines: Vec<String> = layout.lines.iter().map(line_text).collect();
+        assert_eq!(lines, vec!["a b", "", "c"]);
+    }
+}
@@ -0,0 +1,462 @@
+// engine_core/src/text/rich_text/rich_text_markup.rs
+use bishop::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Upper bound on cached entries before the cache is flushed.
+const RICH_TEXT_CACHE_LIMIT: usize = 256;
+
+/// Visual style applied to a run of rich text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RichTextStyle {
+    /// Color override. `None` uses the renderer's default color.
+    pub color: Option<Color>,
+    /// Draws the text with a faux-bold offset.
+    pub bold: bool,
+    /// Bobs each glyph up and down.
+    pub wave: bool,
+    /// Jitters each glyph randomly.
+    pub shake: bool,
+    /// Reveal speed multiplier used by typewriter effects.
+    pub speed: f32,
+}
+
+impl Default for RichTextStyle {
+    fn default() -> Self {
+        Self {
+            color: None,
+            bold: false,
+            wave: false,
+            shake: false,
+            speed: 1.0,
+        }
+    }
+}
+
+impl RichTextStyle {
+    /// Returns true if glyphs in this style move over time.
+    pub fn is_animated(&self) -> bool {
+        self.wave || self.shake
+    }
+}
+
+/// A single parsed piece of rich text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RichSpan {
+    /// Plain text drawn with a style.
+    Text { text: String, style: RichTextStyle },
+    /// Inline image loaded from the icons folder.
+    Icon { name: String, style: RichTextStyle },
+    /// `{{name}}` placeholder that was not interpolated before parsing.
+    Variable { name: String, style: RichTextStyle },
+    /// Pause in seconds used by typewriter effects.
+    Pause(f32),
+}
+
+/// Text parsed from inline markup into styled spans.
+///
+/// Supported tags are `[color=#rrggbb]`, `[b]`, `[wave]`, `[shake]`, `[speed=2]`,
+/// `[pause=0.5]`, `[icon=name]` and `{{variable}}`. Paired tags close with `[/tag]`,
+/// and `[[` produces a literal `[`. Anything that is not valid markup is kept as text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RichText {
+    /// The markup this text was parsed from.
+    pub source: String,
+    pub spans: Vec<RichSpan>,
+}
+
+/// Tags that change the style until their closing tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StyleTag {
+    Color,
+    Bold,
+    Wave,
+    Shake,
+    Speed,
+}
+
+impl StyleTag {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "color" => Some(Self::Color),
+            "b" => Some(Self::Bold),
+            "wave" => Some(Self::Wave),
+            "shake" => Some(Self::Shake),
+            "speed" => Some(Self::Speed),
+            _ => None,
+        }
+    }
+}
+
+/// Markup tag recognised by the parser.
+enum Tag {
+    Open(StyleTag, RichTextStyle),
+    Close(StyleTag),
+    Pause(f32),
+    Icon(String),
+}
+
+impl RichText {
+    /// Parses markup into styled spans.
+    pub fn parse(source: &str) -> Self {
+        let mut parser = Parser {
+            spans: Vec::new(),
+            buffer: String::new(),
+            style: RichTextStyle::default(),
+            open_tags: Vec::new(),
+        };
+
+        let mut rest = source;
+        while let Some(ch) = rest.chars().next() {
+            if let Some(after) = rest.strip_prefix("[[") {
+                parser.buffer.push('[');
+                rest = after;
+                continue;
+            }
+
+            if ch == '['
+                && let Some(end) = rest.find(']')
+                && let Some(tag) = parser.parse_tag(&rest[1..end])
+            {
+                parser.apply_tag(tag);
+                rest = &rest[end + 1..];
+                continue;
+            }
+
+            if let Some(after) = rest.strip_prefix("{{")
+                && let Some(end) = after.find("}}")
+                && is_variable_name(after[..end].trim())
+            {
+                parser.flush();
+                parser.spans.push(RichSpan::Variable {
+                    name: after[..end].trim().to_string(),
+                    style: parser.style,
+                });
+                rest = &after[end + 2..];
+                continue;
+            }
+
+            parser.buffer.push(ch);
+            rest = &rest[ch.len_utf8()..];
+        }
+        parser.flush();
+
+        Self {
+            source: source.to_string(),
+            spans: parser.spans,
+        }
+    }
+
+    /// Returns a copy with `{{name}}` spans replaced by their values, keeping their style.
+    /// Unknown variables are left in place.
+    pub fn resolve_variables(&self, variables: &HashMap<String, String>) -> Self {
+        let spans = self
+            .spans
+            .iter()
+            .map(|span| match span {
+                RichSpan::Variable { name, style } => match variables.get(name) {
+                    Some(value) => RichSpan::Text {
+                        text: value.clone(),
+                        style: *style,
+                    },
+                    None => span.clone(),
+                },
+                _ => span.clone(),
+            })
+            .collect();
+
+        Self {
+            source: self.source.clone(),
+            spans,
+        }
+    }
+
+    /// Returns the text without markup. Icons and pauses are omitted.
+    pub fn plain_text(&self) -> String {
+        let mut text = String::new();
+        for span in &self.spans {
+            match span {
+                RichSpan::Text { text: t, .. } => text.push_str(t),
+                RichSpan::Variable { name, .. } => text.push_str(&variable_placeholder(name)),
+                RichSpan::Icon { .. } | RichSpan::Pause(_) => {}
+            }
+        }
+        text
+    }
+
+    /// Returns true if any span needs redrawing every frame.
+    pub fn is_animated(&self) -> bool {
+        self.spans.iter().any(|span| match span {
+            RichSpan::Text { style, .. }
+            | RichSpan::Icon { style, .. }
+            | RichSpan::Variable { style, .. } => style.is_animated(),
+            RichSpan::Pause(_) => false,
+        })
+    }
+}
+
+/// Reuses parsed markup for strings that are drawn every frame.
+#[derive(Debug, Default)]
+pub struct RichTextCache {
+    entries: HashMap<String, Arc<RichText>>,
+}
+
+impl RichTextCache {
+    /// Returns the parsed markup for `source`, parsing it on first use.
+    pub fn get(&mut self, source: &str) -> Arc<RichText> {
+        if let Some(markup) = self.entries.get(source) {
+            return markup.clone();
+        }
+
+        // Dynamic text would otherwise grow the cache forever
+        if self.entries.len() >= RICH_TEXT_CACHE_LIMIT {
+            self.entries.clear();
+        }
+        let markup = Arc::new(RichText::parse(source));
+        self.entries.insert(source.to_string(), markup.clone());
+        markup
+    }
+}
+
+/// Returns how an unresolved variable is displayed.
+pub(crate) fn variable_placeholder(name: &str) -> String {
+    format!("{{{{{}}}}}", name)
+}
+
+struct Parser {
+    spans: Vec<RichSpan>,
+    buffer: String,
+    style: RichTextStyle,
+    /// Open style tags with the style that was active before each one.
+    open_tags: Vec<(StyleTag, RichTextStyle)>,
+}
+
+impl Parser {
+    /// Parses the contents of `[...]`, returning `None` if it is not a valid tag.
+    fn parse_tag(&self, body: &str) -> Option<Tag> {
+        if let Some(name) = body.strip_prefix('/') {
+            let tag = StyleTag::from_name(name.trim())?;
+            return self
+                .open_tags
+                .iter()
+                .any(|(open, _)| *open == tag)
+                .then_some(Tag::Close(tag));
+        }
+
+        let (name, value) = match body.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.trim())),
+            None => (body.trim(), None),
+        };
+
+        let mut style = self.style;
+        match (name, value) {
+            ("color", Some(value)) => style.color = Some(parse_color(value)?),
+            ("b", None) => style.bold = true,
+            ("wave", None) => style.wave = true,
+            ("shake", None) => style.shake = true,
+            ("speed", Some(value)) => {
+                let speed = value.parse::<f32>().ok().filter(|s| *s > 0.0)?;
+                style.speed = speed;
+            }
+            ("pause", Some(value)) => {
+                let seconds = value.parse::<f32>().ok().filter(|s| *s >= 0.0)?;
+                return Some(Tag::Pause(seconds));
+            }
+            ("icon", Some(value)) if !value.is_empty() => {
+                return Some(Tag::Icon(value.to_string()));
+            }
+            _ => return None,
+        }
+
+        Some(Tag::Open(StyleTag::from_name(name)?, style))
+    }
+
+    fn apply_tag(&mut self, tag: Tag) {
+        self.flush();
+        match tag {
+            Tag::Open(kind, style) => {
+                self.open_tags.push((kind, self.style));
+                self.style = style;
+            }
+            Tag::Close(kind) => {
+                // Closing a tag also closes anything opened inside it
+                if let Some(index) = self.open_tags.iter().rposition(|(open, _)| *open == kind) {
+                    self.style = self.open_tags[index].1;
+                    self.open_tags.truncate(index);
+                }
+            }
+            Tag::Pause(seconds) => self.spans.push(RichSpan::Pause(seconds)),
+            Tag::Icon(name) => self.spans.push(RichSpan::Icon {
+                name,
+                style: self.style,
+            }),
+        }
+    }
+
+    /// Moves buffered text into a span with the current style.
+    fn flush(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        self.spans.push(RichSpan::Text {
+            text: std::mem::take(&mut self.buffer),
+            style: self.style,
+        });
+    }
+}
+
+fn is_variable_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+/// Parses `#rgb`, `#rrggbb`, `#rrggbbaa` or a color name.
+pub fn parse_color(value: &str) -> Option<Color> {
+    if let Some(hex) = value.strip_prefix('#') {
+        let digits: Vec<u8> = hex
+            .chars()
+            .map(|c| c.to_digit(16).map(|d| d as u8))
+            .collect::<Option<_>>()?;
+        let channel = |hi: u8, lo: u8| (hi * 16 + lo) as f32 / 255.0;
+        return match digits.as_slice() {
+            [r, g, b] => Some(Color::new(
+                channel(*r, *r),
+                channel(*g, *g),
+                channel(*b, *b),
+                1.0,
+            )),
+            [r1, r2, g1, g2, b1, b2] => Some(Color::new(
+                channel(*r1, *r2),
+                channel(*g1, *g2),
+                channel(*b1, *b2),
+                1.0,
+            )),
+            [r1, r2, g1, g2, b1, b2, a1, a2] => Some(Color::new(
+                channel(*r1, *r2),
+                channel(*g1, *g2),
+                channel(*b1, *b2),
+                channel(*a1, *a2),
+            )),
+            _ => None,
+        };
+    }
+
+    match value.to_ascii_lowercase().as_str() {
+        "white" => Some(Color::WHITE),
+        "black" => Some(Color::BLACK),
+        "grey" | "gray" => Some(Color::GREY),
+        "red" => Some(Color::RED),
+        "green" => Some(Color::GREEN),
+        "blue" => Some(Color::BLUE),
+        "yellow" => Some(Color::YELLOW),
+        "gold" => Some(Color::GOLD),
+        "orange" => Some(Color::ORANGE),
+        "pink" => Some(Color::PINK),
+        "purple" => Some(Color::PURPLE),
+        "skyblue" => Some(Color::SKYBLUE),
+        "magenta" => Some(Color::MAGENTA),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_span(text: &str, style: RichTextStyle) -> RichSpan {
+        RichSpan::Text {
+            text: text.to_string(),
+            style,
+        }
+    }
+
+    #[test]
+    fn parses_nested_style_tags() {
+        let rich = RichText::parse("a [b]bold [wave]wavy[/wave][/b] c");
+        let bold = RichTextStyle {
+            bold: true,
+            ..Default::default()
+        };
+        let bold_wave = RichTextStyle { wave: true, ..bold };
+
+        assert_eq!(
+            rich.spans,
+            vec![
+                text_span("a ", RichTextStyle::default()),
+                text_span("bold ", bold),
+                text_span("wavy", bold_wave),
+                text_span(" c", RichTextStyle::default()),
+            ]
+        );
+        assert_eq!(rich.plain_text(), "a bold wavy c");
+    }
+
+    #[test]
+    fn parses_color_pause_speed_and_icon() {
+        let rich = RichText::parse("[color=#f00]Hi[/color][pause=0.5][speed=2]x[icon=coin]");
+        let red = RichTextStyle {
+            color: Some(Color::new(1.0, 0.0, 0.0, 1.0)),
+            ..Default::default()
+        };
+        let fast = RichTextStyle {
+            speed: 2.0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            rich.spans,
+            vec![
+                text_span("Hi", red),
+                RichSpan::Pause(0.5),
+                text_span("x", fast),
+                RichSpan::Icon {
+                    name: "coin".to_string(),
+                    style: fast,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn closing_outer_tag_closes_inner_tags() {
+        let rich = RichText::parse("[b][shake]x[/b]y");
+        assert_eq!(rich.spans[1], text_span("y", RichTextStyle::default()));
+    }
+
+    #[test]
+    fn invalid_markup_is_kept_as_text() {
+        let rich = RichText::parse("[[b] [unknown] [/wave] [color=nope] [speed=0] {{ }}");
+        assert_eq!(
+            rich.spans,
+            vec![text_span(
+                "[b] [unknown] [/wave] [color=nope] [speed=0] {{ }}",
+                RichTextStyle::default()
+            )]
+        );
+    }
+
+    #[test]
+    fn variables_keep_their_style_when_resolved() {
+        let rich = RichText::parse("[b]{{player.name}}[/b] has {{gold}}");
+        let mut vars = HashMap::new();
+        vars.insert("player.name".to_string(), "Hero".to_string());
+
+        let resolved = rich.resolve_variables(&vars);
+        let bold = RichTextStyle {
+            bold: true,
+            ..Default::default()
+        };
+        assert_eq!(resolved.spans[0], text_span("Hero", bold));
+        assert_eq!(resolved.plain_text(), "Hero has {{gold}}");
+    }
+
+    #[test]
+    fn parses_color_formats() {
+        assert_eq!(parse_color("#ffffff"), Some(Color::WHITE));
+        assert_eq!(parse_color("#00000080").map(|c| c.a), Some(128.0 / 255.0));
+        assert_eq!(parse_color("Gold"), Some(Color::GOLD));
+        assert_eq!(parse_color("#12"), None);
+    }
+}
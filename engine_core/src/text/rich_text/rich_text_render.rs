@@ -0,0 +1,190 @@
+// engine_core/src/text/rich_text/rich_text_render.rs
+use crate::assets::asset_manager::AssetManager;
+use crate::menu::layout::HorizontalAlign;
+use crate::text::rich_text::rich_text_layout::*;
+use bishop::prelude::*;
+
+/// Wave animation speed in radians per second.
+const WAVE_SPEED: f32 = 6.0;
+/// Phase offset between neighbouring glyphs in a wave.
+const WAVE_PHASE: f32 = 0.6;
+/// Wave height as a fraction of the font size.
+const WAVE_AMPLITUDE: f32 = 0.15;
+/// How many times per second shaking glyphs pick a new offset.
+const SHAKE_RATE: f32 = 20.0;
+/// Shake distance as a fraction of the font size.
+const SHAKE_AMPLITUDE: f32 = 0.06;
+
+/// Where and how a laid out rich text block is drawn.
+#[derive(Debug, Clone, Copy)]
+pub struct RichTextDrawParams {
+    /// Top-left corner of the block.
+    pub origin: Vec2,
+    /// Width lines are aligned within.
+    pub width: f32,
+    pub align: HorizontalAlign,
+    /// Color used for spans without a color tag.
+    pub color: Color,
+    /// Animation time in seconds.
+    pub time: f32,
+    /// Number of glyphs shown by a typewriter reveal. `None` draws everything.
+    pub visible_glyphs: Option<usize>,
+}
+
+/// Draws laid out rich text, animating wave and shake glyphs.
+/// Icons are loaded through the asset manager on first use.
+///
+/// Glyphs are counted the same way as `RichText::glyph_count`, so whitespace never
+/// consumes part of a reveal.
+pub fn draw_rich_text<C: BishopContext>(
+    ctx: &mut C,
+    layout: &RichTextLayout,
+    params: &RichTextDrawParams,
+    asset_manager: &mut AssetManager,
+) {
+    let font = layout.font;
+    let font_size = layout.font_size;
+    let mut glyph_index = 0;
+
+    for (i, line) in layout.lines.iter().enumerate() {
+        let line_x = params.origin.x
+            + match params.align {
+                HorizontalAlign::Left => 0.0,
+                HorizontalAlign::Center => (params.width - line.width) / 2.0,
+                HorizontalAlign::Right => params.width - line.width,
+            };
+        let line_top = params.origin.y + i as f32 * layout.line_height;
+        let baseline = line_top + layout.line_height * 0.8;
+
+        for fragment in &line.fragments {
+            let style = &fragment.style;
+            let color = style.color.unwrap_or(params.color);
+            let x = line_x + fragment.x;
+
+            match &fragment.content {
+                RichFragmentContent::Text(text) if style.is_animated() => {
+                    let mut glyph_x = x;
+                    let mut buf = [0; 4];
+                    for ch in text.chars() {
+                        let glyph = ch.encode_utf8(&mut buf);
+                        if !ch.is_whitespace() {
+                            if !is_visible(glyph_index, params.visible_glyphs) {
+                                return;
+                            }
+                            let offset =
+                                glyph_offset(style.wave, style.shake, glyph_index, params.time);
+                            draw_styled_text(
+                                ctx,
+                                layout,
+                                glyph,
+                                glyph_x + offset.x * font_size,
+                                baseline + offset.y * font_size,
+                                color,
+                                style.bold,
+                            );
+                            glyph_index += 1;
+                        }
+                        glyph_x += ctx.measure_text_with_font(glyph, font, font_size).width;
+                    }
+                }
+                RichFragmentContent::Text(text) => {
+                    let glyphs = text.chars().filter(|c| !c.is_whitespace()).count();
+                    let shown = match params.visible_glyphs {
+                        Some(visible) => visible.saturating_sub(glyph_index).min(glyphs),
+                        None => glyphs,
+                    };
+                    if shown == glyphs {
+                        draw_styled_text(ctx, layout, text, x, baseline, color, style.bold);
+                    } else {
+                        let prefix = glyph_prefix(text, shown);
+                        draw_styled_text(ctx, layout, prefix, x, baseline, color, style.bold);
+                        return;
+                    }
+                    glyph_index += glyphs;
+                }
+                RichFragmentContent::Icon(name) => {
+                    if !is_visible(glyph_index, params.visible_glyphs) {
+                        return;
+                    }
+                    let offset = glyph_offset(style.wave, style.shake, glyph_index, params.time);
+                    if let Some(texture) = asset_manager.icon_texture(&*ctx, name) {
+                        ctx.draw_texture_ex(
+                            &texture,
+                            x + offset.x * font_size,
+                            line_top
+                                + (layout.line_height - font_size) / 2.0
+                                + offset.y * font_size,
+                            Color::new(1.0, 1.0, 1.0, params.color.a),
+                            DrawTextureParams {
+                                dest_size: Some(Vec2::new(fragment.width, font_size)),
+                                ..Default::default()
+                            },
+                        );
+                    }
+                    glyph_index += 1;
+                }
+            }
+        }
+    }
+}
+
+fn is_visible(glyph_index: usize, visible_glyphs: Option<usize>) -> bool {
+    visible_glyphs.is_none_or(|visible| glyph_index < visible)
+}
+
+/// Returns the start of `text` up to and including its `count`th non-whitespace glyph.
+fn glyph_prefix(text: &str, count: usize) -> &str {
+    if count == 0 {
+        return "";
+    }
+    let end = text
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .nth(count - 1)
+        .map(|(i, c)| i + c.len_utf8())
+        .unwrap_or(text.len());
+    &text[..end]
+}
+
+/// Draws text in the layout's font, thickening it with a second pass when bold.
+fn draw_styled_text<C: BishopContext>(
+    ctx: &mut C,
+    layout: &RichTextLayout,
+    text: &str,
+    x: f32,
+    y: f32,
+    color: Color,
+    bold: bool,
+) {
+    let (font, font_size) = (layout.font, layout.font_size);
+    ctx.draw_text_with_font(text, x, y, font, font_size, color);
+    if bold {
+        let thickness = (font_size / 24.0).max(1.0);
+        ctx.draw_text_with_font(text, x + thickness, y, font, font_size, color);
+    }
+}
+
+/// Returns a glyph's animation offset as a fraction of the font size.
+fn glyph_offset(wave: bool, shake: bool, glyph_index: usize, time: f32) -> Vec2 {
+    let mut offset = Vec2::ZERO;
+    if wave {
+        offset.y += (time * WAVE_SPEED - glyph_index as f32 * WAVE_PHASE).sin() * WAVE_AMPLITUDE;
+    }
+    if shake {
+        let step = (time * SHAKE_RATE) as u32;
+        let hash = shake_hash(glyph_index as u32, step);
+        let unit = |bits: u32| (bits & 0xFFFF) as f32 / 65535.0 * 2.0 - 1.0;
+        offset.x += unit(hash) * SHAKE_AMPLITUDE;
+        offset.y += unit(hash >> 16) * SHAKE_AMPLITUDE;
+    }
+    offset
+}
+
+/// Cheap integer hash so each glyph shakes independently but deterministically.
+fn shake_hash(glyph: u32, step: u32) -> u32 {
+    let mut h = glyph.wrapping_mul(0x9E37_79B1) ^ step.wrapping_mul(0x85EB_CA6B);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2C1B_3C6D);
+    h ^= h >> 12;
+    h
+}
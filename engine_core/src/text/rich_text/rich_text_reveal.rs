@@ -0,0 +1,88 @@
+// engine_core/src/text/rich_text/rich_text_reveal.rs
+use crate::text::rich_text::rich_text_markup::*;
+
+impl RichText {
+    /// Returns the number of glyphs a typewriter reveals. Whitespace is not counted
+    /// and each icon counts as one glyph.
+    pub fn glyph_count(&self) -> usize {
+        self.spans
+            .iter()
+            .map(|span| match span {
+                RichSpan::Text { text, .. } => non_whitespace_count(text),
+                RichSpan::Variable { name, .. } => {
+                    non_whitespace_count(&variable_placeholder(name))
+                }
+                RichSpan::Icon { .. } => 1,
+                RichSpan::Pause(_) => 0,
+            })
+            .sum()
+    }
+
+    /// Returns the time in seconds at which each glyph appears when revealing at
+    /// `chars_per_second`, honouring `[speed]` and `[pause]` tags.
+    /// A rate of zero or less reveals everything immediately.
+    pub fn reveal_times(&self, chars_per_second: f32) -> Vec<f32> {
+        if chars_per_second <= 0.0 {
+            return vec![0.0; self.glyph_count()];
+        }
+
+        let mut times = Vec::with_capacity(self.glyph_count());
+        let mut time = 0.0;
+        let mut push = |count: usize, style: &RichTextStyle, time: &mut f32| {
+            let step = 1.0 / (chars_per_second * style.speed);
+            for _ in 0..count {
+                *time += step;
+                times.push(*time);
+            }
+        };
+
+        for span in &self.spans {
+            match span {
+                RichSpan::Text { text, style } => {
+                    push(non_whitespace_count(text), style, &mut time)
+                }
+                RichSpan::Variable { name, style } => push(
+                    non_whitespace_count(&variable_placeholder(name)),
+                    style,
+                    &mut time,
+                ),
+                RichSpan::Icon { style, .. } => push(1, style, &mut time),
+                RichSpan::Pause(seconds) => time += seconds,
+            }
+        }
+        times
+    }
+}
+
+fn non_whitespace_count(text: &str) -> usize {
+    text.chars().filter(|c| !c.is_whitespace()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyph_count_skips_whitespace_and_counts_icons() {
+        let rich = RichText::parse("a b[icon=coin] [pause=1]c");
+        assert_eq!(rich.glyph_count(), 4);
+    }
+
+    #[test]
+    fn reveal_times_apply_speed_and_pauses() {
+        let rich = RichText::parse("ab[pause=1][speed=2]cd");
+        let times = rich.reveal_times(10.0);
+        let expected = [0.1, 0.2, 1.25, 1.3];
+
+        assert_eq!(times.len(), expected.len());
+        for (time, expected) in times.iter().zip(expected) {
+            assert!((time - expected).abs() < 1e-5, "{time} != {expected}");
+        }
+    }
+
+    #[test]
+    fn zero_rate_reveals_instantly() {
+        let rich = RichText::parse("hi [pause=2]there");
+        assert!(rich.reveal_times(0.0).iter().all(|t| *t == 0.0));
+    }
+}
@@ -1,6 +1,8 @@
 // engine_core/src/text/text_data.rs
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
 /// How text variants are selected when displaying text.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
@@ -70,6 +72,14 @@ impl Default for TextManifest {
     }
 }
 
+impl TextManifest {
+    /// Loads `_manifest.toml` from a text root. Returns `None` if it is missing or invalid.
+    pub fn load(text_root: &Path) -> Option<Self> {
+        let content = fs::read_to_string(text_root.join("_manifest.toml")).ok()?;
+        toml::from_str(&content).ok()
+    }
+}
+
 /// State tracking for sequential and shuffle selection modes.
 #[derive(Debug, Clone, Default)]
 pub struct TextState {
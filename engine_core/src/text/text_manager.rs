@@ -1,9 +1,10 @@
 // engine_core/src/text/text_manager.rs
+use crate::storage::path_utils::text_file_path;
 use crate::text::*;
 use rand::Rng;
 use rand::seq::SliceRandom;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -13,12 +14,16 @@ pub struct TextManager {
     text_root: PathBuf,
     /// Current language code.
     current_language: String,
+    /// Language used for keys the current language has not translated.
+    default_language: String,
     /// Available languages from manifest.
     available_languages: Vec<String>,
-    /// Cached text files by text_id.
-    cache: RefCell<HashMap<String, TextFile>>,
-    /// Cached UI text files by text_id.
-    ui_cache: RefCell<HashMap<String, UiTextFile>>,
+    /// Cached text files by (language, text_id).
+    cache: RefCell<HashMap<(String, String), TextFile>>,
+    /// Cached UI text files by (language, text_id).
+    ui_cache: RefCell<HashMap<(String, String), UiTextFile>>,
+    /// Text files by (language, text_id) that could not be read, so they are not retried.
+    missing: RefCell<HashSet<(String, String)>>,
     /// State tracking for each (text_id, key) pair.
     state: RefCell<HashMap<(String, String), TextState>>,
     /// Global dialogue configuration.
@@ -30,9 +35,11 @@ impl Default for TextManager {
         Self {
             text_root: PathBuf::new(),
             current_language: "en".to_string(),
+            default_language: "en".to_string(),
             available_languages: vec!["en".to_string()],
             cache: RefCell::new(HashMap::new()),
             ui_cache: RefCell::new(HashMap::new()),
+            missing: RefCell::new(HashSet::new()),
             state: RefCell::new(HashMap::new()),
             config: DialogueConfig::default(),
         }
@@ -52,11 +59,9 @@ impl TextManager {
 
     /// Loads the manifest file to get available languages.
     fn load_manifest(&mut self) {
-        let manifest_path = self.text_root.join("_manifest.toml");
-        if let Ok(content) = fs::read_to_string(&manifest_path)
-            && let Ok(manifest) = toml::from_str::<TextManifest>(&content)
-        {
-            self.current_language = manifest.default_language;
+        if let Some(manifest) = TextManifest::load(&self.text_root) {
+            self.current_language = manifest.default_language.clone();
+            self.default_language = manifest.default_language;
             self.available_languages = manifest.available;
         }
     }
@@ -65,8 +70,6 @@ impl TextManager {
     pub fn set_language(&mut self, lang: &str) -> bool {
         if self.available_languages.contains(&lang.to_string()) {
             self.current_language = lang.to_string();
-            self.cache.borrow_mut().clear();
-            self.ui_cache.borrow_mut().clear();
             true
         } else {
             false
@@ -78,32 +81,59 @@ impl TextManager {
         &self.current_language
     }
 
+    /// Returns the default language code, used as a fallback for untranslated keys.
+    pub fn get_default_language(&self) -> &str {
+        &self.default_language
+    }
+
     /// Returns a list of available languages.
     pub fn get_languages(&self) -> &[String] {
         &self.available_languages
     }
 
-    /// Loads a text file by ID, supporting subfolders (e.g., "dialogue/npcs/npc" -> "dialogue/npcs/npc.toml").
-    fn load_text_file(&self, text_id: &str) -> bool {
-        if self.cache.borrow().contains_key(text_id) {
-            return true;
+    /// Returns the languages to search for a key, current language first.
+    fn lookup_languages(&self) -> Vec<String> {
+        let mut languages = vec![self.current_language.clone()];
+        if self.default_language != self.current_language {
+            languages.push(self.default_language.clone());
         }
+        languages
+    }
 
-        // Build path from text_id, supporting subfolders (e.g., "dialogue/npcs/npc")
-        let normalized_id = text_id.replace('\\', "/");
-        let mut file_path = self.text_root.join(&self.current_language);
-        for component in normalized_id.split('/') {
-            file_path = file_path.join(component);
+    /// Reads a text file. A file missing from a translation is only logged at
+    /// debug level because the default language is used instead. Files that
+    /// cannot be read are remembered until the cache is cleared.
+    fn read_text_file(&self, language: &str, text_id: &str) -> Option<String> {
+        let key = (language.to_string(), text_id.to_string());
+        if self.missing.borrow().contains(&key) {
+            return None;
         }
-        file_path.set_extension("toml");
 
-        let content = match fs::read_to_string(&file_path) {
-            Ok(c) => c,
+        let file_path = text_file_path(&self.text_root, language, text_id);
+        match fs::read_to_string(&file_path) {
+            Ok(content) => return Some(content),
+            Err(e) if language != self.default_language => {
+                log::debug!("Untranslated text file '{}': {e}", file_path.display());
+            }
             Err(e) => {
                 log::warn!("Failed to load text file '{}': {e}", file_path.display());
-                return false;
             }
+        }
+        self.missing.borrow_mut().insert(key);
+        None
+    }
+
+    /// Loads a text file by ID, supporting subfolders (e.g., "dialogue/npcs/npc" -> "dialogue/npcs/npc.toml").
+    fn load_text_file(&self, language: &str, text_id: &str) -> bool {
+        let cache_key = (language.to_string(), text_id.to_string());
+        if self.cache.borrow().contains_key(&cache_key) {
+            return true;
+        }
+
+        let Some(content) = self.read_text_file(language, text_id) else {
+            return false;
         };
+        let file_path = text_file_path(&self.text_root, language, text_id);
 
         let text_file: TextFile = match toml::from_str(&content) {
             Ok(f) => f,
@@ -113,22 +143,34 @@ impl TextManager {
             }
         };
 
-        self.cache
-            .borrow_mut()
-            .insert(text_id.to_string(), text_file);
+        self.cache.borrow_mut().insert(cache_key, text_file);
         true
     }
 
-    /// Selects and returns text for the given text_id and key.
-    pub fn select_text(&self, text_id: &str, key: &str) -> Option<String> {
-        if !self.load_text_file(text_id) {
-            return None;
+    /// Returns the entry for a key, falling back to the default language.
+    fn find_entry(&self, text_id: &str, key: &str) -> Option<TextEntry> {
+        for language in self.lookup_languages() {
+            if !self.load_text_file(&language, text_id) {
+                continue;
+            }
+            let cache = self.cache.borrow();
+            let entry = cache
+                .get(&(language.clone(), text_id.to_string()))
+                .and_then(|file| file.entries.get(key));
+            if let Some(entry) = entry {
+                if language != self.current_language {
+                    log::debug!("Using '{language}' text for untranslated key '{text_id}:{key}'");
+                }
+                return Some(entry.clone());
+            }
         }
+        None
+    }
 
-        let cache = self.cache.borrow();
-        let file = cache.get(text_id)?;
-        let entry = file.entries.get(key)?.clone();
-        drop(cache);
+    /// Selects and returns text for the given text_id and key.
+    /// Keys missing from the current language fall back to the default language.
+    pub fn select_text(&self, text_id: &str, key: &str) -> Option<String> {
+        let entry = self.find_entry(text_id, key)?;
 
         if entry.variants.is_empty() {
             return entry.exhausted.clone();
@@ -225,37 +267,28 @@ impl TextManager {
     pub fn clear_cache(&self) {
         self.cache.borrow_mut().clear();
         self.ui_cache.borrow_mut().clear();
+        self.missing.borrow_mut().clear();
     }
 
     /// Updates the text root path.
     pub fn set_text_root(&mut self, root: PathBuf) {
         self.text_root = root;
-        self.cache.borrow_mut().clear();
-        self.ui_cache.borrow_mut().clear();
+        self.clear_cache();
         self.state.borrow_mut().clear();
         self.load_manifest();
     }
 
     /// Loads a UI text file by ID into the cache.
-    fn load_ui_text_file(&self, text_id: &str) -> bool {
-        if self.ui_cache.borrow().contains_key(text_id) {
+    fn load_ui_text_file(&self, language: &str, text_id: &str) -> bool {
+        let cache_key = (language.to_string(), text_id.to_string());
+        if self.ui_cache.borrow().contains_key(&cache_key) {
             return true;
         }
 
-        let normalized_id = text_id.replace('\\', "/");
-        let mut file_path = self.text_root.join(&self.current_language);
-        for component in normalized_id.split('/') {
-            file_path = file_path.join(component);
-        }
-        file_path.set_extension("toml");
-
-        let content = match fs::read_to_string(&file_path) {
-            Ok(c) => c,
-            Err(e) => {
-                log::warn!("Failed to load UI text file '{}': {e}", file_path.display());
-                return false;
-            }
+        let Some(content) = self.read_text_file(language, text_id) else {
+            return false;
         };
+        let file_path = text_file_path(&self.text_root, language, text_id);
 
         let ui_file: UiTextFile = match toml::from_str(&content) {
             Ok(f) => f,
@@ -268,20 +301,26 @@ impl TextManager {
             }
         };
 
-        self.ui_cache
-            .borrow_mut()
-            .insert(text_id.to_string(), ui_file);
+        self.ui_cache.borrow_mut().insert(cache_key, ui_file);
         true
     }
 
     /// Returns UI text for the given text_id and key.
+    /// Keys missing from the current language fall back to the default language.
     pub fn get_ui_text(&self, text_id: &str, key: &str) -> Option<String> {
-        if !self.load_ui_text_file(text_id) {
-            return None;
+        for language in self.lookup_languages() {
+            if !self.load_ui_text_file(&language, text_id) {
+                continue;
+            }
+            let cache = self.ui_cache.borrow();
+            let text = cache
+                .get(&(language.clone(), text_id.to_string()))
+                .and_then(|file| file.get(key));
+            if let Some(text) = text {
+                return Some(text.clone());
+            }
         }
-
-        let cache = self.ui_cache.borrow();
-        cache.get(text_id)?.get(key).cloned()
+        None
     }
 
     /// Resolves UI text for the given text_id and key, falling back to the key itself.
@@ -295,3 +334,79 @@ impl TextManager {
         &self.text_root
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, relative: &str, content: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn untranslated_keys_fall_back_to_default_language() {
+        let root = std::env::temp_dir().join(format!("bishop-text-{}", uuid::Uuid::new_v4()));
+        write(
+            &root,
+            "_manifest.toml",
+            "default_language = \"en\"\navailable = [\"en\", \"fr\"]",
+        );
+        write(
+            &root,
+            "en/ui/start.toml",
+            "play = \"Play\"\nquit = \"Quit\"",
+        );
+        write(&root, "en/npc.toml", "[hello]\nvariants = [\"Hi\"]");
+        write(&root, "fr/ui/start.toml", "play = \"Jouer\"");
+
+        let mut manager = TextManager::new(root.clone());
+        assert!(manager.set_language("fr"));
+
+        assert_eq!(
+            manager.get_ui_text("ui/start", "play").as_deref(),
+            Some("Jouer")
+        );
+        assert_eq!(
+            manager.get_ui_text("ui/start", "quit").as_deref(),
+            Some("Quit")
+        );
+        assert_eq!(manager.select_text("npc", "hello").as_deref(), Some("Hi"));
+        assert_eq!(manager.get_ui_text("ui/start", "missing"), None);
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn missing_translation_files_are_not_read_again_until_the_cache_clears() {
+        let root = std::env::temp_dir().join(format!("bishop-text-{}", uuid::Uuid::new_v4()));
+        write(
+            &root,
+            "_manifest.toml",
+            "default_language = \"en\"\navailable = [\"en\", \"fr\"]",
+        );
+        write(&root, "en/ui/start.toml", "play = \"Play\"");
+
+        let mut manager = TextManager::new(root.clone());
+        assert!(manager.set_language("fr"));
+        assert_eq!(
+            manager.get_ui_text("ui/start", "play").as_deref(),
+            Some("Play")
+        );
+
+        write(&root, "fr/ui/start.toml", "play = \"Jouer\"");
+        assert_eq!(
+            manager.get_ui_text("ui/start", "play").as_deref(),
+            Some("Play")
+        );
+
+        manager.clear_cache();
+        assert_eq!(
+            manager.get_ui_text("ui/start", "play").as_deref(),
+            Some("Jouer")
+        );
+
+        let _ = fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,312 @@
+// engine_core/src/tiles/background.rs
+use crate::assets::asset_manager::AssetManager;
+use crate::assets::sprite::SpriteId;
+use bishop::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_with::{FromInto, serde_as};
+
+/// Number of horizontal bands a gradient is drawn with.
+const GRADIENT_BANDS: usize = 32;
+
+/// A vertical colour gradient spanning the height of the room.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct BackgroundGradient {
+    #[serde_as(as = "FromInto<[f32; 4]>")]
+    pub top: Color,
+    #[serde_as(as = "FromInto<[f32; 4]>")]
+    pub bottom: Color,
+}
+
+impl Default for BackgroundGradient {
+    fn default() -> Self {
+        Self {
+            top: Color::new(0.35, 0.55, 0.85, 1.0),
+            bottom: Color::new(0.75, 0.85, 0.95, 1.0),
+        }
+    }
+}
+
+/// One layer of a room background, drawn behind the tiles.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct BackgroundLayer {
+    pub name: String,
+    pub visible: bool,
+    /// Image drawn by the layer. Unset layers only draw their gradient.
+    pub sprite: SpriteId,
+    /// Optional gradient drawn underneath the image.
+    pub gradient: Option<BackgroundGradient>,
+    /// How far the layer moves with the camera. `1` moves with the world,
+    /// `0` stays fixed on screen.
+    #[serde_as(as = "FromInto<[f32; 2]>")]
+    pub parallax: Vec2,
+    /// Offset of the image from the room's top left corner.
+    #[serde_as(as = "FromInto<[f32; 2]>")]
+    pub offset: Vec2,
+    /// Scale applied to the image's pixel size.
+    pub scale: f32,
+    pub repeat_x: bool,
+    pub repeat_y: bool,
+    /// Automatic scrolling in pixels per second.
+    #[serde_as(as = "FromInto<[f32; 2]>")]
+    pub scroll_velocity: Vec2,
+    /// Columns of the sprite sheet.
+    pub sheet_columns: u32,
+    /// Rows of the sprite sheet.
+    pub sheet_rows: u32,
+    /// Sheet frames per second. `0` shows the first frame only.
+    pub sheet_fps: f32,
+}
+
+impl Default for BackgroundLayer {
+    fn default() -> Self {
+        Self {
+            name: "Layer".to_string(),
+            visible: true,
+            sprite: SpriteId(0),
+            gradient: None,
+            parallax: Vec2::ONE,
+            offset: Vec2::ZERO,
+            scale: 1.0,
+            repeat_x: false,
+            repeat_y: false,
+            scroll_velocity: Vec2::ZERO,
+            sheet_columns: 1,
+            sheet_rows: 1,
+            sheet_fps: 0.0,
+        }
+    }
+}
+
+impl BackgroundLayer {
+    /// Number of frames in the sprite sheet.
+    pub fn frame_count(&self) -> u32 {
+        self.sheet_columns.max(1) * self.sheet_rows.max(1)
+    }
+
+    /// Sheet frame shown at `time` seconds.
+    pub fn frame_at(&self, time: f64) -> u32 {
+        if self.sheet_fps <= 0.0 {
+            return 0;
+        }
+        ((time * self.sheet_fps as f64) as u64 % self.frame_count() as u64) as u32
+    }
+
+    /// World position of the layer's image for the given camera target.
+    /// `image_size` is used to wrap the auto scroll of repeating layers.
+    pub fn origin(
+        &self,
+        room_position: Vec2,
+        camera_target: Vec2,
+        image_size: Vec2,
+        time: f64,
+    ) -> Vec2 {
+        // Scroll in f64 so long running rooms don't lose precision
+        let scroll = |velocity: f32, size: f32, repeat: bool| {
+            let distance = velocity as f64 * time;
+            if repeat && size > 0.0 {
+                distance.rem_euclid(size as f64) as f32
+            } else {
+                distance as f32
+            }
+        };
+        let scroll = vec2(
+            scroll(self.scroll_velocity.x, image_size.x, self.repeat_x),
+            scroll(self.scroll_velocity.y, image_size.y, self.repeat_y),
+        );
+
+        room_position
+            + self.offset
+            + (camera_target - room_position) * (Vec2::ONE - self.parallax)
+            + scroll
+    }
+
+    /// Draws the layer into `area`, the visible part of the room.
+    pub fn draw<C: BishopContext>(
+        &self,
+        ctx: &mut C,
+        asset_manager: &mut AssetManager,
+        room_rect: Rect,
+        area: Rect,
+        camera_target: Vec2,
+        time: f64,
+    ) {
+        if !self.visible {
+            return;
+        }
+
+        if let Some(gradient) = self.gradient {
+            let origin = self.origin(room_rect.top_left(), camera_target, Vec2::ZERO, time);
+            draw_gradient(ctx, gradient, origin.y, room_rect.h, area);
+        }
+
+        if self.sprite.0 == 0 {
+            return;
+        }
+
+        let texture = asset_manager.get_texture_from_id(ctx, self.sprite);
+        let columns = self.sheet_columns.max(1);
+        let frame_size = vec2(
+            texture.width() / columns as f32,
+            texture.height() / self.sheet_rows.max(1) as f32,
+        );
+        let size = frame_size * self.scale.max(0.0);
+        if size.x <= 0.0 || size.y <= 0.0 {
+            return;
+        }
+
+        let frame = self.frame_at(time);
+        let frame_origin = vec2(
+            (frame % columns) as f32 * frame_size.x,
+            (frame / columns) as f32 * frame_size.y,
+        );
+        let origin = self.origin(room_rect.top_left(), camera_target, size, time);
+
+        for (dest, uv) in tile_rects(origin, size, self.repeat_x, self.repeat_y, area) {
+            ctx.draw_texture_ex(
+                texture,
+                dest.x,
+                dest.y,
+                Color::WHITE,
+                DrawTextureParams {
+                    dest_size: Some(vec2(dest.w, dest.h)),
+                    source: Some(Rect::new(
+                        frame_origin.x + uv.x * frame_size.x,
+                        frame_origin.y + uv.y * frame_size.y,
+                        uv.w * frame_size.x,
+                        uv.h * frame_size.y,
+                    )),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+}
+
+/// Fills `area` with a gradient that runs from `top` to `top + height`.
+/// Parts of `area` outside that span use the nearest end colour.
+fn draw_gradient<C: BishopContext>(
+    ctx: &mut C,
+    gradient: BackgroundGradient,
+    top: f32,
+    height: f32,
+    area: Rect,
+) {
+    let band = area.h / GRADIENT_BANDS as f32;
+    for i in 0..GRADIENT_BANDS {
+        let y = area.y + i as f32 * band;
+        let t = if height > 0.0 {
+            ((y + band / 2.0 - top) / height).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let color = Color::new(
+            gradient.top.r + (gradient.bottom.r - gradient.top.r) * t,
+            gradient.top.g + (gradient.bottom.g - gradient.top.g) * t,
+            gradient.top.b + (gradient.bottom.b - gradient.top.b) * t,
+            gradient.top.a + (gradient.bottom.a - gradient.top.a) * t,
+        );
+        ctx.draw_rectangle(area.x, y, area.w, band, color);
+    }
+}
+
+/// Lays out copies of a `size` sized image anchored at `origin` over `area`.
+/// Returns each copy's destination cropped to `area`, paired with the
+/// matching part of the image in normalised `0..=1` coordinates.
+pub fn tile_rects(
+    origin: Vec2,
+    size: Vec2,
+    repeat_x: bool,
+    repeat_y: bool,
+    area: Rect,
+) -> Vec<(Rect, Rect)> {
+    let columns = tile_starts(origin.x, size.x, repeat_x, area.x, area.w);
+    let rows = tile_starts(origin.y, size.y, repeat_y, area.y, area.h);
+
+    let mut rects = Vec::with_capacity(columns.len() * rows.len());
+    for &y in &rows {
+        for &x in &columns {
+            let left = x.max(area.x);
+            let top = y.max(area.y);
+            let right = (x + size.x).min(area.x + area.w);
+            let bottom = (y + size.y).min(area.y + area.h);
+            if right <= left || bottom <= top {
+                continue;
+            }
+            rects.push((
+                Rect::new(left, top, right - left, bottom - top),
+                Rect::new(
+                    (left - x) / size.x,
+                    (top - y) / size.y,
+                    (right - left) / size.x,
+                    (bottom - top) / size.y,
+                ),
+            ));
+        }
+    }
+    rects
+}
+
+/// Start positions along one axis of the copies overlapping `start..start + len`.
+fn tile_starts(origin: f32, size: f32, repeat: bool, start: f32, len: f32) -> Vec<f32> {
+    if !repeat {
+        return vec![origin];
+    }
+    let first = origin + ((start - origin) / size).floor() * size;
+    let count = ((start + len - first) / size).ceil().max(0.0) as usize;
+    (0..count).map(|i| first + i as f32 * size).collect()
+}
+
+/// Intersection of two rectangles, or `None` when they don't overlap.
+pub fn intersect_rects(a: Rect, b: Rect) -> Option<Rect> {
+    let left = a.x.max(b.x);
+    let top = a.y.max(b.y);
+    let right = (a.x + a.w).min(b.x + b.w);
+    let bottom = (a.y + a.h).min(b.y + b.h);
+    (right > left && bottom > top).then(|| Rect::new(left, top, right - left, bottom - top))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallax_and_scroll_offset_the_origin() {
+        let layer = BackgroundLayer {
+            parallax: vec2(0.5, 1.0),
+            scroll_velocity: vec2(10.0, 0.0),
+            repeat_x: true,
+            ..Default::default()
+        };
+        let origin = layer.origin(Vec2::ZERO, vec2(100.0, 40.0), vec2(32.0, 32.0), 5.0);
+        // Half the camera travel plus 50px of scroll wrapped to 18px
+        assert_eq!(origin, vec2(68.0, 0.0));
+    }
+
+    #[test]
+    fn repeating_tiles_cover_and_crop_to_the_area() {
+        let area = Rect::new(0.0, 0.0, 20.0, 8.0);
+        let rects = tile_rects(vec2(-4.0, 0.0), vec2(8.0, 8.0), true, false, area);
+
+        let xs: Vec<f32> = rects.iter().map(|(dest, _)| dest.x).collect();
+        assert_eq!(xs, vec![0.0, 4.0, 12.0]);
+        // The first copy is cut in half on the left
+        assert_eq!(rects[0].1, Rect::new(0.5, 0.0, 0.5, 1.0));
+        assert_eq!(rects[2].0.w, 8.0);
+    }
+
+    #[test]
+    fn animated_sheets_loop_through_frames() {
+        let layer = BackgroundLayer {
+            sheet_columns: 2,
+            sheet_rows: 2,
+            sheet_fps: 4.0,
+            ..Default::default()
+        };
+        assert_eq!(layer.frame_at(0.0), 0);
+        assert_eq!(layer.frame_at(0.5), 2);
+        assert_eq!(layer.frame_at(1.25), 1);
+    }
+}
@@ -1,7 +1,9 @@
+pub mod background;
 pub mod serialization;
 pub mod tile;
 pub mod tilemap;
 
+pub use background::*;
 pub use serialization::*;
 pub use tile::*;
 pub use tilemap::*;
@@ -1,5 +1,6 @@
 // engine_core/src/tiles/tilemap.rs
 use crate::assets::asset_manager::AssetManager;
+use crate::tiles::background::*;
 use crate::tiles::serialization::{deserialize_tiles, serialize_tiles};
 use crate::tiles::tile::TileDefId;
 use crate::worlds::world::GridPos;
@@ -20,8 +21,12 @@ pub struct TileMap {
         deserialize_with = "deserialize_tiles"
     )]
     pub tiles: HashMap<(usize, usize), TileDefId>,
+    /// Colour filling the room behind the background layers.
     #[serde_as(as = "FromInto<[f32; 4]>")]
     pub background: Color,
+    /// Background layers, drawn back to front over the background colour.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub background_layers: Vec<BackgroundLayer>,
 }
 
 impl TileMap {
@@ -31,26 +36,56 @@ impl TileMap {
             height,
             tiles: HashMap::new(),
             background: Color::LIGHTGREY,
+            background_layers: Vec::new(),
         }
     }
 
-    /// Draw the tilemap.
-    pub fn draw<C: BishopContext>(
+    /// Draw the background colour and layers for the part of the room
+    /// the camera can see.
+    pub fn draw_background<C: BishopContext>(
         &self,
         ctx: &mut C,
         asset_manager: &mut AssetManager,
         room_position: Vec2,
         grid_size: f32,
+        camera: &Camera2D,
     ) {
-        // Background
-        ctx.draw_rectangle(
+        let room_rect = Rect::new(
             room_position.x,
             room_position.y,
             self.width as f32 * grid_size,
             self.height as f32 * grid_size,
-            self.background,
         );
+        let half_view = vec2(
+            1.0 / camera.zoom.x.abs().max(f32::EPSILON),
+            1.0 / camera.zoom.y.abs().max(f32::EPSILON),
+        );
+        let view = Rect::new(
+            camera.target.x - half_view.x,
+            camera.target.y - half_view.y,
+            half_view.x * 2.0,
+            half_view.y * 2.0,
+        );
+        let Some(area) = intersect_rects(room_rect, view) else {
+            return;
+        };
+
+        ctx.draw_rectangle(area.x, area.y, area.w, area.h, self.background);
+
+        let time = ctx.get_time();
+        for layer in &self.background_layers {
+            layer.draw(ctx, asset_manager, room_rect, area, camera.target, time);
+        }
+    }
 
+    /// Draw the tiles of the tilemap.
+    pub fn draw<C: BishopContext>(
+        &self,
+        ctx: &mut C,
+        asset_manager: &mut AssetManager,
+        room_position: Vec2,
+        grid_size: f32,
+    ) {
         for ((x, y), tile_def_id) in &self.tiles {
             let tile_pos = Vec2::new(*x as f32 * grid_size, *y as f32 * grid_size) + room_position;
 
@@ -0,0 +1,168 @@
+// engine_core/src/tween/easing.rs
+use std::f32::consts::PI;
+
+/// Easing curve applied to a tween's normalised progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    InQuad,
+    OutQuad,
+    InOutQuad,
+    InCubic,
+    OutCubic,
+    InOutCubic,
+    InSine,
+    OutSine,
+    InOutSine,
+    InExpo,
+    OutExpo,
+    InOutExpo,
+    InBack,
+    OutBack,
+    InOutBack,
+    InElastic,
+    OutElastic,
+    InOutElastic,
+    InBounce,
+    OutBounce,
+    InOutBounce,
+}
+
+impl Easing {
+    /// Every easing with its script-facing name.
+    pub const ALL: [(&'static str, Easing); 22] = [
+        ("linear", Easing::Linear),
+        ("in_quad", Easing::InQuad),
+        ("out_quad", Easing::OutQuad),
+        ("in_out_quad", Easing::InOutQuad),
+        ("in_cubic", Easing::InCubic),
+        ("out_cubic", Easing::OutCubic),
+        ("in_out_cubic", Easing::InOutCubic),
+        ("in_sine", Easing::InSine),
+        ("out_sine", Easing::OutSine),
+        ("in_out_sine", Easing::InOutSine),
+        ("in_expo", Easing::InExpo),
+        ("out_expo", Easing::OutExpo),
+        ("in_out_expo", Easing::InOutExpo),
+        ("in_back", Easing::InBack),
+        ("out_back", Easing::OutBack),
+        ("in_out_back", Easing::InOutBack),
+        ("in_elastic", Easing::InElastic),
+        ("out_elastic", Easing::OutElastic),
+        ("in_out_elastic", Easing::InOutElastic),
+        ("in_bounce", Easing::InBounce),
+        ("out_bounce", Easing::OutBounce),
+        ("in_out_bounce", Easing::InOutBounce),
+    ];
+
+    /// Looks up an easing by its script-facing name, e.g. `"out_quad"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        Self::ALL
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, easing)| *easing)
+    }
+
+    /// Maps linear progress `t` in `0..=1` onto the curve.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::InQuad => t * t,
+            Easing::OutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::InOutQuad => in_out(t, |t| t * t),
+            Easing::InCubic => t * t * t,
+            Easing::OutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::InOutCubic => in_out(t, |t| t * t * t),
+            Easing::InSine => 1.0 - (t * PI / 2.0).cos(),
+            Easing::OutSine => (t * PI / 2.0).sin(),
+            Easing::InOutSine => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::InExpo => in_expo(t),
+            Easing::OutExpo => 1.0 - in_expo(1.0 - t),
+            Easing::InOutExpo => in_out(t, in_expo),
+            Easing::InBack => in_back(t),
+            Easing::OutBack => 1.0 - in_back(1.0 - t),
+            Easing::InOutBack => in_out(t, in_back),
+            Easing::InElastic => in_elastic(t),
+            Easing::OutElastic => 1.0 - in_elastic(1.0 - t),
+            Easing::InOutElastic => in_out(t, in_elastic),
+            Easing::InBounce => 1.0 - out_bounce(1.0 - t),
+            Easing::OutBounce => out_bounce(t),
+            Easing::InOutBounce => in_out(t, |t| 1.0 - out_bounce(1.0 - t)),
+        }
+    }
+}
+
+/// Builds an in-out curve from the "in" half.
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(t * 2.0) / 2.0
+    } else {
+        1.0 - ease_in((1.0 - t) * 2.0) / 2.0
+    }
+}
+
+fn in_expo(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        2f32.powf(10.0 * t - 10.0)
+    }
+}
+
+fn in_back(t: f32) -> f32 {
+    const C1: f32 = 1.70158;
+    const C3: f32 = C1 + 1.0;
+    C3 * t * t * t - C1 * t * t
+}
+
+fn in_elastic(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    let c4 = 2.0 * PI / 3.0;
+    -(2f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * c4).sin()
+}
+
+fn out_bounce(t: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+    if t < 1.0 / D1 {
+        N1 * t * t
+    } else if t < 2.0 / D1 {
+        let t = t - 1.5 / D1;
+        N1 * t * t + 0.75
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+        N1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D1;
+        N1 * t * t + 0.984375
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_easing_starts_at_zero_and_ends_at_one() {
+        for (name, easing) in Easing::ALL {
+            assert!(easing.apply(0.0).abs() < 1e-4, "{name} at 0");
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-4, "{name} at 1");
+        }
+    }
+
+    #[test]
+    fn names_round_trip() {
+        assert_eq!(Easing::from_name("out_quad"), Some(Easing::OutQuad));
+        assert_eq!(
+            Easing::from_name("In_Out_Bounce"),
+            Some(Easing::InOutBounce)
+        );
+        assert_eq!(Easing::from_name("wobble"), None);
+        assert!((Easing::OutQuad.apply(0.5) - 0.75).abs() < 1e-6);
+    }
+}
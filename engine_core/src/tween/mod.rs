@@ -0,0 +1,8 @@
+pub mod easing;
+#[allow(clippy::module_inception)]
+pub mod tween;
+pub mod tween_manager;
+
+pub use easing::*;
+pub use tween::*;
+pub use tween_manager::*;
@@ -0,0 +1,276 @@
+// engine_core/src/tween/tween.rs
+use crate::animation::animation_clip::Animation;
+use crate::assets::sprite::Sprite;
+use crate::audio::audio_source::AudioSource;
+use crate::camera::game_camera::{RoomCamera, world_virtual_width};
+use crate::ecs::component_registry::COMPONENTS;
+use crate::ecs::ecs::Ecs;
+use crate::ecs::entity::Entity;
+use crate::ecs::reflect_field::FieldValue;
+use crate::ecs::transform::*;
+use crate::lighting::light::Light;
+use crate::tween::easing::Easing;
+use bishop::prelude::*;
+
+/// A value a tween interpolates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TweenValue {
+    Float(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+}
+
+impl TweenValue {
+    /// Interpolates towards `to`. Returns `None` when the two values have different kinds.
+    pub fn lerp(self, to: TweenValue, t: f32) -> Option<TweenValue> {
+        match (self, to) {
+            (TweenValue::Float(a), TweenValue::Float(b)) => {
+                Some(TweenValue::Float(a + (b - a) * t))
+            }
+            (TweenValue::Vec2(a), TweenValue::Vec2(b)) => Some(TweenValue::Vec2(a + (b - a) * t)),
+            (TweenValue::Vec3(a), TweenValue::Vec3(b)) => Some(TweenValue::Vec3(a + (b - a) * t)),
+            _ => None,
+        }
+    }
+
+    /// Human readable name of the value kind, used in error messages.
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            TweenValue::Float(_) => "number",
+            TweenValue::Vec2(_) => "vec2",
+            TweenValue::Vec3(_) => "vec3",
+        }
+    }
+}
+
+/// What a tween animates on its entity.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TweenProperty {
+    /// `Transform.position`, moving children along.
+    Position,
+    /// `Transform.rotation` in degrees, rotating children along.
+    Rotation,
+    /// `Transform.scale`, scaling children along.
+    Scale,
+    /// Opacity of the entity's sprite and animation.
+    Opacity,
+    /// Camera zoom factor, where `1.0` frames the default virtual resolution.
+    CameraZoom,
+    /// `Light.intensity`.
+    LightIntensity,
+    /// `AudioSource.runtime_volume`.
+    Volume,
+    /// Any numeric or vector field of a component deriving `Reflect`.
+    Field { component: String, field: String },
+}
+
+impl TweenProperty {
+    /// Parses a property path such as `"Transform.position"`, `"alpha"` or `"Light.radius"`.
+    pub fn parse(path: &str) -> Result<Self, String> {
+        let path = path.trim();
+        let property = match path {
+            "position" | "Transform.position" => TweenProperty::Position,
+            "rotation" | "Transform.rotation" => TweenProperty::Rotation,
+            "scale" | "Transform.scale" => TweenProperty::Scale,
+            "alpha" | "opacity" => TweenProperty::Opacity,
+            "zoom" | "camera_zoom" => TweenProperty::CameraZoom,
+            "light_intensity" | "Light.intensity" => TweenProperty::LightIntensity,
+            "volume" | "AudioSource.runtime_volume" => TweenProperty::Volume,
+            _ => {
+                let Some((component, field)) = path.split_once('.') else {
+                    return Err(format!("Unknown tween property '{path}'"));
+                };
+                let Some(reg) = COMPONENTS.iter().find(|r| r.type_name == component) else {
+                    return Err(format!("Unknown component '{component}' in '{path}'"));
+                };
+                if reg.reflect.is_none() {
+                    return Err(format!(
+                        "Component '{component}' does not expose its fields"
+                    ));
+                }
+                TweenProperty::Field {
+                    component: component.to_string(),
+                    field: field.to_string(),
+                }
+            }
+        };
+        Ok(property)
+    }
+
+    /// Reads the current value, or `None` if the entity lacks the property.
+    pub fn read(&self, ecs: &mut Ecs, entity: Entity, grid_size: f32) -> Option<TweenValue> {
+        match self {
+            TweenProperty::Position => ecs
+                .get::<Transform>(entity)
+                .map(|t| TweenValue::Vec2(t.position)),
+            TweenProperty::Rotation => ecs
+                .get::<Transform>(entity)
+                .map(|t| TweenValue::Float(t.rotation)),
+            TweenProperty::Scale => ecs
+                .get::<Transform>(entity)
+                .map(|t| TweenValue::Vec2(t.scale)),
+            TweenProperty::Opacity => ecs
+                .get::<Sprite>(entity)
+                .map(|s| s.opacity)
+                .or_else(|| ecs.get::<Animation>(entity).map(|a| a.opacity))
+                .map(TweenValue::Float),
+            TweenProperty::CameraZoom => ecs
+                .get::<RoomCamera>(entity)
+                .map(|c| TweenValue::Float(c.zoom.x * world_virtual_width(grid_size) / 2.0)),
+            TweenProperty::LightIntensity => ecs
+                .get::<Light>(entity)
+                .map(|l| TweenValue::Float(l.intensity)),
+            TweenProperty::Volume => ecs
+                .get::<AudioSource>(entity)
+                .map(|a| TweenValue::Float(a.runtime_volume)),
+            TweenProperty::Field { component, field } => {
+                with_reflected_field(ecs, entity, component, field, |value| match value {
+                    FieldValue::Float(v) => Some(TweenValue::Float(*v)),
+                    FieldValue::Int(v) => Some(TweenValue::Float(*v as f32)),
+                    FieldValue::Vec2(v) => Some(TweenValue::Vec2(*v)),
+                    FieldValue::Vec3(v) => Some(TweenValue::Vec3(*v)),
+                    _ => None,
+                })
+                .flatten()
+            }
+        }
+    }
+
+    /// Writes `value` to the property. Values of the wrong kind are ignored.
+    pub fn write(&self, ecs: &mut Ecs, entity: Entity, grid_size: f32, value: TweenValue) {
+        match (self, value) {
+            (TweenProperty::Position, TweenValue::Vec2(pos)) => {
+                update_entity_position(ecs, entity, pos);
+            }
+            (TweenProperty::Rotation, TweenValue::Float(degrees)) => {
+                update_entity_rotation(ecs, entity, degrees);
+            }
+            (TweenProperty::Scale, TweenValue::Vec2(scale)) => {
+                update_entity_scale(ecs, entity, scale);
+            }
+            (TweenProperty::Opacity, TweenValue::Float(opacity)) => {
+                let opacity = opacity.clamp(0.0, 1.0);
+                if let Some(sprite) = ecs.get_mut::<Sprite>(entity) {
+                    sprite.opacity = opacity;
+                }
+                if let Some(animation) = ecs.get_mut::<Animation>(entity) {
+                    animation.opacity = opacity;
+                }
+            }
+            (TweenProperty::CameraZoom, TweenValue::Float(factor)) => {
+                if let Some(camera) = ecs.get_mut::<RoomCamera>(entity) {
+                    let current = camera.zoom.x * world_virtual_width(grid_size) / 2.0;
+                    if current > f32::EPSILON && factor > f32::EPSILON {
+                        camera.zoom *= factor / current;
+                    }
+                }
+            }
+            (TweenProperty::LightIntensity, TweenValue::Float(intensity)) => {
+                if let Some(light) = ecs.get_mut::<Light>(entity) {
+                    light.intensity = intensity.max(0.0);
+                }
+            }
+            (TweenProperty::Volume, TweenValue::Float(volume)) => {
+                if let Some(source) = ecs.get_mut::<AudioSource>(entity) {
+                    source.runtime_volume = volume.clamp(0.0, 1.0);
+                }
+            }
+            (TweenProperty::Field { component, field }, value) => {
+                with_reflected_field(ecs, entity, component, field, |target| {
+                    match (target, value) {
+                        (FieldValue::Float(v), TweenValue::Float(x)) => *v = x,
+                        (FieldValue::Int(v), TweenValue::Float(x)) => *v = x.round() as i32,
+                        (FieldValue::Vec2(v), TweenValue::Vec2(x)) => *v = x,
+                        (FieldValue::Vec3(v), TweenValue::Vec3(x)) => *v = x,
+                        _ => {}
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Runs `f` on the named field of a reflected component.
+fn with_reflected_field<R>(
+    ecs: &mut Ecs,
+    entity: Entity,
+    component: &str,
+    field: &str,
+    f: impl FnOnce(FieldValue<'_>) -> R,
+) -> Option<R> {
+    let reg = COMPONENTS.iter().find(|r| r.type_name == component)?;
+    let reflected = (reg.reflect?)(ecs, entity)?;
+    let info = reflected
+        .fields()
+        .into_iter()
+        .find(|info| info.name == field)?;
+    Some(f(info.value))
+}
+
+/// One step of a tween: animates a property from its current value to `to`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tween {
+    pub entity: Entity,
+    pub property: TweenProperty,
+    /// Explicit start value. `None` uses the property's value when the step starts.
+    pub from: Option<TweenValue>,
+    pub to: TweenValue,
+    /// Seconds per pass.
+    pub duration: f32,
+    /// Seconds to wait before the first pass.
+    pub delay: f32,
+    pub easing: Easing,
+    /// Number of passes to play. `None` loops forever.
+    pub cycles: Option<u32>,
+    /// Whether every other pass plays backwards.
+    pub yoyo: bool,
+}
+
+impl Tween {
+    /// Creates a single pass linear tween.
+    pub fn new(entity: Entity, property: TweenProperty, to: TweenValue, duration: f32) -> Self {
+        Self {
+            entity,
+            property,
+            from: None,
+            to,
+            duration: duration.max(0.0),
+            delay: 0.0,
+            easing: Easing::Linear,
+            cycles: Some(1),
+            yoyo: false,
+        }
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay.max(0.0);
+        self
+    }
+
+    pub fn with_from(mut self, from: TweenValue) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Plays back to the start after reaching the target.
+    /// A single pass tween becomes a there-and-back pair.
+    pub fn with_yoyo(mut self) -> Self {
+        self.yoyo = true;
+        if self.cycles == Some(1) {
+            self.cycles = Some(2);
+        }
+        self
+    }
+
+    /// Repeats the tween `cycles` times, or forever when `None`.
+    pub fn with_cycles(mut self, cycles: Option<u32>) -> Self {
+        self.cycles = cycles.map(|c| c.max(1));
+        self
+    }
+}
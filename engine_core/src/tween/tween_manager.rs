@@ -0,0 +1,337 @@
+// engine_core/src/tween/tween_manager.rs
+use crate::ecs::ecs::Ecs;
+use crate::ecs::entity::Entity;
+use crate::ecs::transform::Transform;
+use crate::onscreen_error;
+use crate::tween::tween::*;
+use mlua::Function;
+use std::collections::VecDeque;
+
+/// Identifier handed out for every started tween sequence.
+pub type TweenId = u64;
+
+/// Owns and advances all running tweens.
+#[derive(Default)]
+pub struct TweenManager {
+    sequences: Vec<TweenSequence>,
+    next_id: TweenId,
+}
+
+/// A queue of tween steps played one after another.
+struct TweenSequence {
+    id: TweenId,
+    steps: VecDeque<Tween>,
+    /// Runtime state of the front step, created when it becomes current.
+    active: Option<ActiveStep>,
+    /// Called once every step has finished.
+    on_done: Option<Function>,
+}
+
+struct ActiveStep {
+    delay_left: f32,
+    /// Start and end values, captured once the delay has elapsed.
+    values: Option<(TweenValue, TweenValue)>,
+    elapsed: f32,
+    /// Number of completed passes.
+    pass: u32,
+}
+
+enum SequenceProgress {
+    Running,
+    Finished,
+    Cancelled,
+}
+
+impl TweenManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new sequence with `tween` as its first step.
+    pub fn start(&mut self, tween: Tween) -> TweenId {
+        self.next_id += 1;
+        self.sequences.push(TweenSequence {
+            id: self.next_id,
+            steps: VecDeque::from([tween]),
+            active: None,
+            on_done: None,
+        });
+        self.next_id
+    }
+
+    /// Appends a step that plays after the current last step of `id`.
+    pub fn then(&mut self, id: TweenId, tween: Tween) -> bool {
+        let Some(sequence) = self.sequence_mut(id) else {
+            return false;
+        };
+        sequence.steps.push_back(tween);
+        true
+    }
+
+    /// Edits the last queued step of `id`.
+    pub fn modify_last(&mut self, id: TweenId, edit: impl FnOnce(&mut Tween)) -> bool {
+        let Some(sequence) = self.sequence_mut(id) else {
+            return false;
+        };
+        let Some(last) = sequence.steps.back_mut() else {
+            return false;
+        };
+        edit(last);
+
+        // A step that has not captured its values yet restarts with the new settings
+        if sequence.steps.len() == 1 && sequence.active.as_ref().is_some_and(|a| a.values.is_none())
+        {
+            sequence.active = None;
+        }
+        true
+    }
+
+    /// Sets the function called when the whole sequence finishes.
+    pub fn set_on_done(&mut self, id: TweenId, callback: Function) -> bool {
+        let Some(sequence) = self.sequence_mut(id) else {
+            return false;
+        };
+        sequence.on_done = Some(callback);
+        true
+    }
+
+    /// Stops a sequence without calling its `on_done` callback.
+    pub fn cancel(&mut self, id: TweenId) -> bool {
+        let count = self.sequences.len();
+        self.sequences.retain(|s| s.id != id);
+        self.sequences.len() != count
+    }
+
+    /// Stops every sequence that animates `entity`.
+    pub fn cancel_entity(&mut self, entity: Entity) {
+        self.sequences
+            .retain(|s| !s.steps.iter().any(|step| step.entity == entity));
+    }
+
+    /// Stops every sequence.
+    pub fn clear(&mut self) {
+        self.sequences.clear();
+    }
+
+    /// Returns true while the sequence still has steps to play.
+    pub fn is_running(&self, id: TweenId) -> bool {
+        self.sequences.iter().any(|s| s.id == id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.sequences.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sequences.is_empty()
+    }
+
+    /// Advances all tweens by `dt` seconds.
+    /// Sequences whose entity has been removed are dropped silently.
+    /// Returns the `on_done` callbacks of finished sequences; call them
+    /// once the game borrow has been released.
+    pub fn update(&mut self, ecs: &mut Ecs, grid_size: f32, dt: f32) -> Vec<Function> {
+        let mut finished = Vec::new();
+        self.sequences.retain_mut(|sequence| {
+            match advance_sequence(sequence, ecs, grid_size, dt) {
+                SequenceProgress::Running => true,
+                SequenceProgress::Finished => {
+                    finished.extend(sequence.on_done.take());
+                    false
+                }
+                SequenceProgress::Cancelled => false,
+            }
+        });
+        finished
+    }
+
+    fn sequence_mut(&mut self, id: TweenId) -> Option<&mut TweenSequence> {
+        self.sequences.iter_mut().find(|s| s.id == id)
+    }
+}
+
+fn advance_sequence(
+    sequence: &mut TweenSequence,
+    ecs: &mut Ecs,
+    grid_size: f32,
+    mut dt: f32,
+) -> SequenceProgress {
+    let TweenSequence { steps, active, .. } = sequence;
+
+    loop {
+        let Some(step) = steps.front() else {
+            return SequenceProgress::Finished;
+        };
+        // Every live entity owns a transform
+        if !ecs.has::<Transform>(step.entity) {
+            return SequenceProgress::Cancelled;
+        }
+
+        let state = active.get_or_insert_with(|| ActiveStep {
+            delay_left: step.delay,
+            values: None,
+            elapsed: 0.0,
+            pass: 0,
+        });
+
+        if state.delay_left > 0.0 {
+            let waited = dt.min(state.delay_left);
+            state.delay_left -= waited;
+            dt -= waited;
+            if state.delay_left > 0.0 {
+                return SequenceProgress::Running;
+            }
+        }
+
+        let (start, end) = match state.values {
+            Some(values) => values,
+            None => {
+                let Some(current) = step.property.read(ecs, step.entity, grid_size) else {
+                    onscreen_error!("Tween target {:?} has no {:?}", step.entity, step.property);
+                    return SequenceProgress::Cancelled;
+                };
+                let start = step.from.unwrap_or(current);
+                if current.lerp(step.to, 0.0).is_none() || start.lerp(step.to, 0.0).is_none() {
+                    onscreen_error!(
+                        "Tween of {:?} expects a {} value",
+                        step.property,
+                        current.kind_name()
+                    );
+                    return SequenceProgress::Cancelled;
+                }
+                state.values = Some((start, step.to));
+                (start, step.to)
+            }
+        };
+
+        state.elapsed += dt;
+        let t = if step.duration > 0.0 {
+            (state.elapsed / step.duration).min(1.0)
+        } else {
+            1.0
+        };
+        let reversed = step.yoyo && state.pass % 2 == 1;
+        let eased = step.easing.apply(if reversed { 1.0 - t } else { t });
+        if let Some(value) = start.lerp(end, eased) {
+            step.property.write(ecs, step.entity, grid_size, value);
+        }
+
+        if t < 1.0 {
+            return SequenceProgress::Running;
+        }
+
+        // Pass complete: carry the overshoot into whatever plays next
+        dt = (state.elapsed - step.duration).max(0.0);
+        state.elapsed = 0.0;
+        state.pass += 1;
+
+        if step.cycles.is_some_and(|cycles| state.pass >= cycles) {
+            steps.pop_front();
+            *active = None;
+        } else if dt <= 0.0 || step.duration <= 0.0 {
+            return SequenceProgress::Running;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tween::easing::Easing;
+    use bishop::prelude::*;
+    use mlua::Lua;
+
+    fn entity_at(ecs: &mut Ecs, position: Vec2) -> Entity {
+        ecs.create_entity()
+            .with(Transform {
+                position,
+                ..Default::default()
+            })
+            .finish()
+    }
+
+    fn position(ecs: &Ecs, entity: Entity) -> Vec2 {
+        ecs.get::<Transform>(entity).unwrap().position
+    }
+
+    #[test]
+    fn tween_reaches_target_and_reports_completion() {
+        let lua = Lua::new();
+        let mut ecs = Ecs::default();
+        let entity = entity_at(&mut ecs, Vec2::ZERO);
+        let mut tweens = TweenManager::new();
+
+        let tween = Tween::new(
+            entity,
+            TweenProperty::parse("Transform.position").unwrap(),
+            TweenValue::Vec2(vec2(10.0, 0.0)),
+            1.0,
+        )
+        .with_easing(Easing::OutQuad);
+        let id = tweens.start(tween);
+        tweens.set_on_done(id, lua.create_function(|_, ()| Ok(())).unwrap());
+
+        assert!(tweens.update(&mut ecs, 16.0, 0.5).is_empty());
+        assert!((position(&ecs, entity).x - 7.5).abs() < 1e-4);
+
+        let done = tweens.update(&mut ecs, 16.0, 0.6);
+        assert_eq!(done.len(), 1);
+        assert_eq!(position(&ecs, entity), vec2(10.0, 0.0));
+        assert!(!tweens.is_running(id));
+    }
+
+    #[test]
+    fn delay_yoyo_and_sequences_play_in_order() {
+        let mut ecs = Ecs::default();
+        let entity = entity_at(&mut ecs, Vec2::ZERO);
+        let mut tweens = TweenManager::new();
+
+        let there_and_back = Tween::new(
+            entity,
+            TweenProperty::Position,
+            TweenValue::Vec2(vec2(4.0, 0.0)),
+            1.0,
+        )
+        .with_delay(0.5)
+        .with_yoyo();
+        let id = tweens.start(there_and_back);
+        tweens.then(
+            id,
+            Tween::new(
+                entity,
+                TweenProperty::Rotation,
+                TweenValue::Float(90.0),
+                1.0,
+            ),
+        );
+
+        tweens.update(&mut ecs, 16.0, 0.5);
+        assert_eq!(position(&ecs, entity), Vec2::ZERO);
+        tweens.update(&mut ecs, 16.0, 1.0);
+        assert_eq!(position(&ecs, entity), vec2(4.0, 0.0));
+        tweens.update(&mut ecs, 16.0, 1.5);
+        assert_eq!(position(&ecs, entity), Vec2::ZERO);
+
+        // The overshoot carries into the rotation step
+        let rotation = ecs.get::<Transform>(entity).unwrap().rotation;
+        assert!((rotation - 45.0).abs() < 1e-3);
+        assert!(tweens.is_running(id));
+    }
+
+    #[test]
+    fn tweens_on_removed_entities_are_cancelled() {
+        let mut ecs = Ecs::default();
+        let entity = entity_at(&mut ecs, Vec2::ZERO);
+        let mut tweens = TweenManager::new();
+        tweens.start(Tween::new(
+            entity,
+            TweenProperty::Position,
+            TweenValue::Vec2(Vec2::ONE),
+            1.0,
+        ));
+
+        ecs.get_store_mut::<Transform>().remove(entity);
+        assert!(tweens.update(&mut ecs, 16.0, 0.1).is_empty());
+        assert!(tweens.is_empty());
+    }
+}
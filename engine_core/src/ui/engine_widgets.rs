@@ -9,6 +9,7 @@ use bishop::prelude::*;
 use std::borrow::Cow;
 use widgets::{Button, WIDGET_SPACING};
 
+/// Draws a sprite picker for `id`, moving its ref to the picked sprite.
 pub fn gui_sprite_picker<C: BishopContext>(
     ctx: &mut C,
     rect: Rect,
@@ -16,12 +17,30 @@ pub fn gui_sprite_picker<C: BishopContext>(
     asset_manager: &mut AssetManager,
     blocked: bool,
 ) -> bool {
+    match gui_sprite_field(ctx, rect, *id, asset_manager, blocked) {
+        Some(new_id) => {
+            asset_manager.change_sprite(id, new_id);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Draws a sprite picker for `id` without touching ref counts, for owners that
+/// count their own sprites. Returns the picked sprite, `SpriteId(0)` when cleared.
+pub fn gui_sprite_field<C: BishopContext>(
+    ctx: &mut C,
+    rect: Rect,
+    id: SpriteId,
+    asset_manager: &mut AssetManager,
+    blocked: bool,
+) -> Option<SpriteId> {
     let btn_label: Cow<str> = if id.0 == 0 {
         Cow::Borrowed("[Pick File]")
     } else {
         let filename = asset_manager
             .sprite_id_to_path
-            .get(id)
+            .get(&id)
             .and_then(|p| p.file_name())
             .map(|n| n.to_string_lossy().into_owned())
             .unwrap_or_else(|| "???".to_string());
@@ -35,7 +54,7 @@ pub fn gui_sprite_picker<C: BishopContext>(
     let picker_rect = Rect::new(rect.x, rect.y, picker_w, rect.h);
     let remove_rect = Rect::new(rect.x + rect.w - remove_w, rect.y, remove_w, rect.h);
 
-    let mut changed = false;
+    let mut picked = None;
 
     if Button::new(picker_rect, &btn_label)
         .blocked(blocked)
@@ -50,10 +69,8 @@ pub fn gui_sprite_picker<C: BishopContext>(
             {
                 let normalized = asset_manager.normalize_path(path);
                 match asset_manager.get_or_load(ctx, &normalized) {
-                    Some(new_id) => {
-                        asset_manager.change_sprite(id, new_id);
-                        changed = true;
-                    }
+                    Some(new_id) if new_id != id => picked = Some(new_id),
+                    Some(_) => {}
                     None => {
                         onscreen_error!("Failed to load sprite.");
                     }
@@ -63,12 +80,10 @@ pub fn gui_sprite_picker<C: BishopContext>(
     }
 
     if Button::new(remove_rect, "x").blocked(blocked).show(ctx) && id.0 != 0 {
-        asset_manager.decrement_ref(*id);
-        *id = SpriteId(0);
-        changed = true;
+        picked = Some(SpriteId(0));
     }
 
-    changed
+    picked
 }
 
 pub fn gui_script_picker<C: BishopContext>(
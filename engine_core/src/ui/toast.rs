@@ -198,6 +198,10 @@ mod tests {
         fn push_clip_rect(&mut self, _rect: Rect) {}
 
         fn pop_clip_rect(&mut self) {}
+
+        fn push_opacity(&mut self, _opacity: f32) {}
+
+        fn pop_opacity(&mut self) {}
     }
 
     impl Text for TestContext {
@@ -259,6 +263,10 @@ mod tests {
         fn is_fullscreen(&self) -> bool {
             false
         }
+        fn set_vsync(&mut self, _enabled: bool) {}
+        fn is_vsync(&self) -> bool {
+            true
+        }
         fn scale_factor(&self) -> f32 {
             1.0
         }
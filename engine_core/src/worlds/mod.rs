@@ -1,5 +1,7 @@
 pub mod room;
+pub mod room_transition;
 pub mod world;
 
 pub use room::*;
+pub use room_transition::*;
 pub use world::*;
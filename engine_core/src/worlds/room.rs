@@ -6,6 +6,7 @@ use crate::ecs::ecs::Ecs;
 use crate::ecs::entity::Entity;
 use crate::ecs::transform::*;
 use crate::tiles::tilemap::TileMap;
+use crate::worlds::room_transition::RoomTransition;
 use bishop::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_with::FromInto;
@@ -294,4 +295,7 @@ pub struct Exit {
     pub position: Vec2,
     pub direction: ExitDirection,
     pub target_room_id: Option<RoomId>,
+    /// Overrides the world's room transition for this exit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transition: Option<RoomTransition>,
 }
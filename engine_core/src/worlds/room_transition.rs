@@ -0,0 +1,196 @@
+// engine_core/src/worlds/room_transition.rs
+use crate::worlds::room::*;
+use crate::worlds::world::World;
+use bishop::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_with::{FromInto, serde_as};
+
+/// How the screen changes when the player walks into another room.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransitionStyle {
+    /// Cut straight to the new room.
+    #[default]
+    None,
+    /// Fade out to a colour and back in.
+    Fade,
+    /// Scroll the camera from the old room into the new one.
+    Slide,
+    /// Close a circle on the player, then open it in the new room.
+    Iris,
+}
+
+impl TransitionStyle {
+    /// All transition styles.
+    pub fn all() -> &'static [TransitionStyle] {
+        &[
+            TransitionStyle::None,
+            TransitionStyle::Fade,
+            TransitionStyle::Slide,
+            TransitionStyle::Iris,
+        ]
+    }
+
+    /// Display label for UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            TransitionStyle::None => "None",
+            TransitionStyle::Fade => "Fade",
+            TransitionStyle::Slide => "Slide",
+            TransitionStyle::Iris => "Iris",
+        }
+    }
+}
+
+impl std::fmt::Display for TransitionStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// Transition played when the player moves between rooms.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct RoomTransition {
+    pub style: TransitionStyle,
+    /// Length of the whole transition in seconds.
+    pub duration: f32,
+    /// Colour faded to and drawn outside the iris.
+    #[serde_as(as = "FromInto<[f32; 4]>")]
+    pub color: Color,
+}
+
+impl Default for RoomTransition {
+    fn default() -> Self {
+        Self {
+            style: TransitionStyle::None,
+            duration: 0.6,
+            color: Color::BLACK,
+        }
+    }
+}
+
+impl RoomTransition {
+    /// Returns true when the transition has something to play.
+    pub fn is_animated(&self) -> bool {
+        self.style != TransitionStyle::None && self.duration > 0.0
+    }
+}
+
+/// One frame of a running transition, handed to the renderer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransitionFrame {
+    pub style: TransitionStyle,
+    /// Progress through the transition in `0..=1`.
+    pub progress: f32,
+    pub color: Color,
+    /// Centre of the iris in normalised screen coordinates.
+    pub focus: Vec2,
+}
+
+impl TransitionFrame {
+    /// Returns true while the old room's snapshot is shown instead of the new room.
+    /// Slides scroll the camera over both rooms instead and never show it.
+    pub fn shows_snapshot(&self) -> bool {
+        self.progress < 0.5
+    }
+
+    /// Opacity of the fade colour, peaking halfway through.
+    pub fn fade_alpha(&self) -> f32 {
+        1.0 - (self.progress * 2.0 - 1.0).abs()
+    }
+
+    /// Iris radius as a share of its largest radius, closed halfway through.
+    pub fn iris_radius(&self) -> f32 {
+        (self.progress * 2.0 - 1.0).abs()
+    }
+}
+
+impl World {
+    /// Returns the transition for the player leaving `from` for `to` at `position`.
+    /// The nearest exit leading to `to` may override the world's transition.
+    pub fn transition_between(&self, from: RoomId, to: RoomId, position: Vec2) -> RoomTransition {
+        let Some(from_room) = self.get_room(from) else {
+            return self.room_transition;
+        };
+
+        from_room
+            .exits
+            .iter()
+            .filter(|exit| exit.target_room_id == Some(to))
+            .min_by(|a, b| {
+                let distance = |exit: &Exit| {
+                    (from_room.position + exit.position * self.grid_size).distance_squared(position)
+                };
+                distance(a).total_cmp(&distance(b))
+            })
+            .and_then(|exit| exit.transition)
+            .unwrap_or(self.room_transition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(progress: f32) -> TransitionFrame {
+        TransitionFrame {
+            style: TransitionStyle::Fade,
+            progress,
+            color: Color::BLACK,
+            focus: vec2(0.5, 0.5),
+        }
+    }
+
+    #[test]
+    fn fades_and_irises_peak_halfway() {
+        assert_eq!(frame(0.0).fade_alpha(), 0.0);
+        assert_eq!(frame(0.5).fade_alpha(), 1.0);
+        assert_eq!(frame(0.5).iris_radius(), 0.0);
+        assert_eq!(frame(1.0).iris_radius(), 1.0);
+        assert!(frame(0.25).shows_snapshot());
+        assert!(!frame(0.75).shows_snapshot());
+    }
+
+    #[test]
+    fn exits_override_the_world_transition() {
+        let fade = RoomTransition {
+            style: TransitionStyle::Fade,
+            ..Default::default()
+        };
+        let mut world = World {
+            grid_size: 16.0,
+            room_transition: fade,
+            ..Default::default()
+        };
+        world.rooms = vec![
+            Room {
+                id: RoomId(1),
+                size: vec2(10.0, 10.0),
+                exits: vec![Exit {
+                    position: vec2(10.0, 5.0),
+                    direction: ExitDirection::Right,
+                    target_room_id: Some(RoomId(2)),
+                    transition: Some(RoomTransition {
+                        style: TransitionStyle::Slide,
+                        ..Default::default()
+                    }),
+                }],
+                ..Default::default()
+            },
+            Room {
+                id: RoomId(2),
+                position: vec2(160.0, 0.0),
+                size: vec2(10.0, 10.0),
+                ..Default::default()
+            },
+        ];
+
+        let transition = world.transition_between(RoomId(1), RoomId(2), vec2(160.0, 80.0));
+        assert_eq!(transition.style, TransitionStyle::Slide);
+
+        // Without an exit the world default is used
+        let transition = world.transition_between(RoomId(2), RoomId(1), vec2(160.0, 80.0));
+        assert_eq!(transition, fade);
+    }
+}
@@ -3,6 +3,7 @@ use crate::assets::asset_manager::AssetManager;
 use crate::assets::sprite::SpriteId;
 use crate::tiles::tilemap::TileMap;
 use crate::worlds::room::*;
+use crate::worlds::room_transition::RoomTransition;
 use bishop::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_with::FromInto;
@@ -33,6 +34,9 @@ pub struct World {
     /// Grid size in pixels for this world.
     #[serde(default = "default_grid_size")]
     pub grid_size: f32,
+    /// Transition played between rooms, unless an exit overrides it.
+    #[serde(default)]
+    pub room_transition: RoomTransition,
 }
 
 #[serde_as]
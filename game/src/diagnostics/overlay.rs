@@ -52,6 +52,7 @@ pub struct DiagnosticsOverlay {
     cached_audio_matching_refs: usize,
     cached_audio_checked_refs: usize,
     cached_audio_rows: Vec<AudioDiagnosticsRow>,
+    cached_animator_lines: Vec<String>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -115,6 +116,7 @@ impl DiagnosticsOverlay {
             cached_audio_matching_refs: 0,
             cached_audio_checked_refs: 0,
             cached_audio_rows: Vec::new(),
+            cached_animator_lines: Vec::new(),
         }
     }
 
@@ -172,6 +174,7 @@ impl DiagnosticsOverlay {
         self.cached_audio_matching_refs = matching_refs;
         self.cached_audio_checked_refs = checked_refs;
         self.cached_audio_rows = audio_diagnostics_rows(&expected_audio_refs, &audio_snapshot);
+        self.cached_animator_lines = animator_lines(&game.ecs);
     }
 
     /// Handle input for toggling the overlay.
@@ -232,6 +235,7 @@ impl DiagnosticsOverlay {
                     .iter()
                     .map(AudioDiagnosticsRow::display_line),
             );
+            lines.extend(self.cached_animator_lines.iter().cloned());
         }
 
         // Calculate background size
@@ -347,6 +351,30 @@ fn audio_ref_summary(rows: &[AudioDiagnosticsRow]) -> (usize, usize) {
     (matching, relevant_rows.len())
 }
 
+/// One line per running animation state machine with its state and clip.
+fn animator_lines(ecs: &Ecs) -> Vec<String> {
+    let mut lines: Vec<(Entity, String)> = ecs
+        .get_store::<AnimatorState>()
+        .data
+        .iter()
+        .filter_map(|(entity, animator)| {
+            let state = animator.current.as_ref()?;
+            let name = ecs
+                .get::<Name>(*entity)
+                .map(|n| n.0.clone())
+                .unwrap_or_else(|| format!("#{}", entity.0));
+            let clip = ecs
+                .get::<Animation>(*entity)
+                .and_then(|a| a.current.as_ref())
+                .map(ClipId::ui_label)
+                .unwrap_or_default();
+            Some((*entity, format!("Animator {name}: {state} ({clip})")))
+        })
+        .collect();
+    lines.sort_by_key(|(entity, _)| entity.0);
+    lines.into_iter().map(|(_, line)| line).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,68 @@
+// game/src/engine/animation_events.rs
+use super::Engine;
+use crate::scripting::commands::lua_command::{CallEntityFnCmd, LuaCommand};
+use crate::scripting::modules::entity_module::lua_entity_handle;
+use crate::scripting::script_system::ScriptSystem;
+use engine_core::onscreen_error;
+use engine_core::prelude::*;
+use mlua::{IntoLua, Variadic};
+
+/// Emits `animation:event` (entity, name, clip, frame) and `animation:finished` (entity, clip),
+/// then calls `on_animation_event` or `on_clip_finished` on the entity's own script.
+pub(super) fn emit_animation_events(engine: &mut Engine, events: Vec<AnimationEvent>) {
+    if events.is_empty() {
+        return;
+    }
+
+    let event_bus = engine
+        .game_instance
+        .borrow()
+        .game
+        .script_manager
+        .event_bus
+        .clone();
+    for event in events {
+        let (name, fn_name, entity, args) = match event {
+            AnimationEvent::Frame {
+                entity,
+                clip_id,
+                event,
+            } => (
+                "animation:event",
+                ON_ANIMATION_EVENT,
+                entity,
+                vec![
+                    event.name.into_lua(&engine.lua),
+                    clip_id.ui_label().into_lua(&engine.lua),
+                    event.frame.into_lua(&engine.lua),
+                ],
+            ),
+            AnimationEvent::ClipFinished { entity, clip_id } => (
+                "animation:finished",
+                ON_CLIP_FINISHED,
+                entity,
+                vec![clip_id.ui_label().into_lua(&engine.lua)],
+            ),
+        };
+
+        let Ok(args) = args.into_iter().collect::<mlua::Result<Vec<_>>>() else {
+            onscreen_error!("Failed to create animation event arguments");
+            continue;
+        };
+        match lua_entity_handle(&engine.lua, entity) {
+            Ok(handle) => event_bus.emit(
+                name.to_string(),
+                Variadic::from_iter(std::iter::once(handle).chain(args.iter().cloned())),
+            ),
+            Err(e) => onscreen_error!("Failed to create animation event arguments: {}", e),
+        }
+
+        CallEntityFnCmd {
+            entity,
+            fn_name: fn_name.to_string(),
+            args,
+        }
+        .execute(engine);
+        ScriptSystem::process_commands(engine);
+    }
+}
@@ -0,0 +1,292 @@
+// game/src/engine/dialogue.rs
+use crate::engine::{Engine, GameInstance};
+use crate::game_global::set_dialogue_active;
+use bishop::prelude::*;
+use engine_core::prelude::*;
+use mlua::{Function, Value, Variadic};
+use std::collections::HashMap;
+
+/// Id of the overlay menu generated for dialogue choices.
+const DIALOGUE_CHOICE_MENU: &str = "__dialogue_choices";
+/// Prefix of the menu values holding the text of each dialogue choice.
+const DIALOGUE_CHOICE_VALUE: &str = "choice_";
+
+/// A dialogue graph being played by the engine.
+pub struct ActiveDialogue {
+    runner: DialogueRunner,
+    /// Called with the dialogue id once the graph finishes.
+    on_finished: Option<Function>,
+    /// Speaker whose bubble must clear before the next node runs.
+    waiting_on: Option<Entity>,
+    /// Remaining display time of a line that has no speaker entity.
+    line_timer: f32,
+}
+
+/// What the dialogue update needs to do after stepping the runner.
+enum DialogueOutcome {
+    Wait,
+    Emit(String, Vec<String>),
+    Finish,
+}
+
+impl Engine {
+    /// Replaces the dialogue variables with the ones saved for this game.
+    pub(super) fn load_dialogue_variables(&mut self) {
+        let mut game_instance = self.game_instance.borrow_mut();
+        game_instance.dialogue_variables = match dialogue_variables_path(&game_instance.game.name) {
+            Some(path) => DialogueVariables::load_from_path(&path),
+            None => {
+                onscreen_error!("Could not resolve the dialogue variables folder.");
+                DialogueVariables::default()
+            }
+        };
+    }
+
+    /// Saves the dialogue variables changed since the last save.
+    pub(super) fn save_dialogue_variables(&mut self) {
+        let mut game_instance = self.game_instance.borrow_mut();
+        if let Err(e) = game_instance.dialogue_variables.save() {
+            onscreen_error!("Could not save dialogue variables: {e}");
+        }
+    }
+
+    /// Speaker of the running dialogue line, whose bubble the advance input goes to first.
+    pub(super) fn speech_focus(&self) -> Option<Entity> {
+        self.dialogue.as_ref().and_then(|active| active.waiting_on)
+    }
+
+    /// Loads a dialogue graph and starts playing it, replacing any running dialogue.
+    pub fn start_dialogue(
+        &mut self,
+        id: &str,
+        participants: HashMap<String, Entity>,
+        on_finished: Option<Function>,
+    ) {
+        let graph = match load_dialogue_graph(id) {
+            Ok(graph) => graph,
+            Err(e) => {
+                onscreen_error!("{e}");
+                return;
+            }
+        };
+
+        self.stop_dialogue();
+        self.dialogue = Some(ActiveDialogue {
+            runner: DialogueRunner::new(graph, participants),
+            on_finished,
+            waiting_on: None,
+            line_timer: 0.0,
+        });
+        set_dialogue_active(true);
+    }
+
+    /// Stops the running dialogue without calling its completion callback.
+    pub fn stop_dialogue(&mut self) {
+        if self.dialogue.take().is_some() {
+            self.close_dialogue_choices();
+            set_dialogue_active(false);
+        }
+        drain_dialogue_choices();
+    }
+
+    /// Advances the running dialogue until it needs to wait on a line or a choice.
+    pub(super) fn update_dialogue(&mut self, dt: f32) {
+        let Some(mut active) = self.dialogue.take() else {
+            drain_dialogue_choices();
+            return;
+        };
+        active.line_timer = (active.line_timer - dt).max(0.0);
+
+        for index in drain_dialogue_choices() {
+            let game_instance = self.game_instance.borrow();
+            if active
+                .runner
+                .choose(index, &game_instance.dialogue_variables)
+            {
+                drop(game_instance);
+                self.close_dialogue_choices();
+                break;
+            }
+        }
+
+        loop {
+            match self.step_dialogue(&mut active) {
+                DialogueOutcome::Wait => {
+                    self.dialogue = Some(active);
+                    return;
+                }
+                DialogueOutcome::Emit(event, args) => {
+                    self.emit_dialogue_event(event, args);
+                }
+                DialogueOutcome::Finish => {
+                    self.finish_dialogue(active);
+                    return;
+                }
+            }
+        }
+    }
+
+    fn step_dialogue(&mut self, active: &mut ActiveDialogue) -> DialogueOutcome {
+        if active.runner.is_awaiting_choice() {
+            return DialogueOutcome::Wait;
+        }
+
+        if active.line_timer > 0.0 {
+            return DialogueOutcome::Wait;
+        }
+
+        let mut borrow = self.game_instance.borrow_mut();
+        let game_instance = &mut *borrow;
+        if let Some(speaker) = active.waiting_on {
+            if is_speaking(&game_instance.game.ecs, speaker) {
+                return DialogueOutcome::Wait;
+            }
+            active.waiting_on = None;
+        }
+
+        let text_id = active.runner.graph().text_id.clone();
+        match active
+            .runner
+            .next_step(&mut game_instance.dialogue_variables)
+        {
+            DialogueStep::Line { speaker, role, key } => {
+                let text = resolve_dialogue_text(game_instance, &text_id, &key);
+                match speaker {
+                    Some(speaker) => {
+                        show_dialogue_line(&mut game_instance.game, speaker, &text);
+                        active.waiting_on = Some(speaker);
+                    }
+                    // Lines without a participant are left to `dialogue:line` listeners
+                    None => {
+                        active.line_timer = game_instance.game.text_manager.config.default_duration
+                    }
+                }
+
+                DialogueOutcome::Emit("dialogue:line".to_string(), vec![role, text])
+            }
+            DialogueStep::Choices(choices) => {
+                let labels: Vec<(usize, String)> = choices
+                    .iter()
+                    .map(|choice| {
+                        let text = resolve_dialogue_text(game_instance, &text_id, &choice.key);
+                        (choice.index, text)
+                    })
+                    .collect();
+                drop(borrow);
+                self.open_dialogue_choices(&labels);
+                DialogueOutcome::Wait
+            }
+            DialogueStep::Event(name) => {
+                DialogueOutcome::Emit(name, vec![active.runner.graph().id.clone()])
+            }
+            DialogueStep::Finished => DialogueOutcome::Finish,
+        }
+    }
+
+    fn finish_dialogue(&mut self, active: ActiveDialogue) {
+        self.close_dialogue_choices();
+        set_dialogue_active(false);
+
+        let id = active.runner.graph().id.clone();
+        if let Some(callback) = active.on_finished {
+            if let Err(e) = callback.call::<()>(id.clone()) {
+                onscreen_error!("Dialogue '{}' callback error: {}", id, e);
+            }
+        }
+        self.emit_dialogue_event("dialogue:finished".to_string(), vec![id]);
+    }
+
+    fn emit_dialogue_event(&self, event: String, args: Vec<String>) {
+        let mut values = Vec::with_capacity(args.len());
+        for arg in args {
+            match self.lua.create_string(&arg) {
+                Ok(value) => values.push(Value::String(value)),
+                Err(e) => {
+                    onscreen_error!("Failed to create dialogue event argument: {}", e);
+                    return;
+                }
+            }
+        }
+
+        let event_bus = self
+            .game_instance
+            .borrow()
+            .game
+            .script_manager
+            .event_bus
+            .clone();
+        event_bus.emit(event, Variadic::from_iter(values));
+    }
+
+    fn open_dialogue_choices(&mut self, labels: &[(usize, String)]) {
+        let layout = LayoutConfig::vertical()
+            .with_item_size(360.0, 36.0)
+            .with_spacing(8.0)
+            .with_padding(Padding::uniform(24.0))
+            .with_alignment(Alignment::center());
+
+        let template = MenuBuilder::new(DIALOGUE_CHOICE_MENU)
+            .mode(MenuMode::Overlay)
+            .background(MenuBackground::None)
+            .layout_group(Rect::new(0.0, 0.55, 1.0, 0.45), layout, |group| {
+                // Choice text is already resolved, so bind it as a value rather
+                // than looking it up as a UI text key
+                labels.iter().fold(group, |group, (index, _)| {
+                    group.button(
+                        &format!("{{{DIALOGUE_CHOICE_VALUE}{index}}}"),
+                        MenuAction::Custom(format!("{DIALOGUE_CHOICE_ACTION}{index}")),
+                    )
+                })
+            })
+            .build();
+
+        self.close_dialogue_choices();
+        for (index, text) in labels {
+            self.menu_manager.set_value(
+                DIALOGUE_CHOICE_MENU,
+                &format!("{DIALOGUE_CHOICE_VALUE}{index}"),
+                Some(MenuValue::Text(text.clone())),
+            );
+        }
+        self.menu_manager.register_template(template);
+        self.menu_manager.open_menu(DIALOGUE_CHOICE_MENU);
+    }
+
+    fn close_dialogue_choices(&mut self) {
+        self.menu_manager.close_menu_by_id(DIALOGUE_CHOICE_MENU);
+    }
+}
+
+/// Resolves a dialogue text key, interpolating dialogue variables. Falls back to the key.
+fn resolve_dialogue_text(game_instance: &GameInstance, text_id: &str, key: &str) -> String {
+    match game_instance.game.text_manager.select_text(text_id, key) {
+        Some(text) => interpolate(
+            &text,
+            game_instance.game.text_manager.get_language(),
+            &game_instance.dialogue_variables.to_interpolation_map(),
+        ),
+        None => {
+            log::warn!("Dialogue text not found: {}:{}", text_id, key);
+            key.to_string()
+        }
+    }
+}
+
+/// Shows a line as a speech bubble using the dialogue config defaults.
+fn show_dialogue_line(game: &mut Game, speaker: Entity, text: &str) {
+    let config = &game.text_manager.config;
+    let bubble = SpeechBubble {
+        text: text.to_string(),
+        timer: config.default_duration,
+        color: config.default_color,
+        offset: (0.0, config.default_offset_y),
+        font_size: None,
+        max_width: None,
+        show_background: config.show_background,
+        background_color: config.default_background_color,
+        reveal_speed: config.reveal_speed,
+        wait_for_input: config.wait_for_input,
+        ..Default::default()
+    };
+    game.ecs.add_component_to_entity(speaker, bubble);
+}
@@ -12,6 +12,8 @@ pub struct GameInstance {
     pub game: Game,
     /// Holds the Transform of every entity rendered in the previous frame.
     pub prev_positions: HashMap<Entity, Vec2>,
+    /// Variables read and written by dialogue graphs during this session.
+    pub dialogue_variables: DialogueVariables,
 }
 
 impl GameInstance {
@@ -142,12 +144,15 @@ impl GameInstance {
         Self {
             game,
             prev_positions: HashMap::new(),
+            dialogue_variables: DialogueVariables::default(),
         }
     }
 
     /// Drains events generated during UI rendering and forwards them to the event bus.
-    pub fn drain_ui_events(&self) {
+    pub fn drain_ui_events(&self, lua: &Lua) {
         self.emit_slider_events();
+        self.emit_text_input_events(lua);
+        self.emit_list_events(lua);
         self.emit_menu_events();
     }
 
@@ -173,6 +178,41 @@ impl GameInstance {
         }
     }
 
+    /// Drains submitted text inputs and emits them to the Lua event bus.
+    fn emit_text_input_events(&self, lua: &Lua) {
+        for (key, text) in drain_text_input_events() {
+            let Ok(text) = lua.create_string(&text) else {
+                onscreen_error!("Failed to create text input event payload");
+                continue;
+            };
+            self.game.script_manager.event_bus.emit(
+                format!("text_input:{key}"),
+                Variadic::from_iter([Value::String(text)]),
+            );
+        }
+    }
+
+    /// Drains picked scroll list items and emits them to the Lua event bus.
+    fn emit_list_events(&self, lua: &Lua) {
+        for event in drain_list_events() {
+            let Ok(payload) = lua.create_table() else {
+                onscreen_error!("Failed to create list event payload table");
+                continue;
+            };
+            // Lua lists start at 1
+            if payload.set("index", event.index + 1).is_err()
+                || payload.set("item", event.item).is_err()
+            {
+                onscreen_error!("Failed to populate list event payload table");
+                continue;
+            }
+            self.game.script_manager.event_bus.emit(
+                format!("list:{}", event.key),
+                Variadic::from_iter([Value::Table(payload)]),
+            );
+        }
+    }
+
     /// Updates the previous position for all entities in the active room.
     pub fn store_previous_positions(&mut self, camera_manager: &mut CameraManager) {
         let ecs = &self.game.ecs;
@@ -180,7 +220,7 @@ impl GameInstance {
         let room_store = ecs.get_store::<CurrentRoom>();
 
         // Store the camera target
-        camera_manager.previous_position = Some(camera_manager.active.camera.target);
+        camera_manager.previous_position = Some(camera_manager.view_target());
 
         self.prev_positions.clear();
         self.prev_positions
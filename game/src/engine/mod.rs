@@ -1,22 +1,33 @@
 // game/src/engine/mod.rs
 // Keep `mod.rs` limited to frame orchestration. Feature-specific methods belong in focused
 // helper modules alongside the subsystem it serves, or in a new engine sub-module.
+mod animation_events;
 mod audio_events;
+mod dialogue;
 pub mod engine_builder;
 pub mod game_instance;
 mod render;
+mod room_transitions;
+mod settings_events;
+mod speech_events;
 #[cfg(test)]
 mod tests;
+mod tween_events;
+use animation_events::emit_animation_events;
 use audio_events::emit_pending_audio_events;
 use render::*;
+use settings_events::{apply_saved_settings, emit_setting_changes};
+use tween_events::run_tween_callbacks;
 
+pub use dialogue::ActiveDialogue;
 pub use engine_builder::EngineBuilder;
 pub use game_instance::GameInstance;
 
 use crate::diagnostics::DiagnosticsOverlay;
-use crate::game_global::set_menu_active;
+use crate::game_global::{clear_consumed_input, consume_key_press, set_menu_active};
 use crate::physics::physics_system::*;
 use crate::scripting::script_system::ScriptSystem;
+use crate::transitions::room_transition::ActiveRoomTransition;
 use crate::transitions::transition_manager::TransitionManager;
 use bishop::prelude::*;
 use bishop::BishopApp;
@@ -50,6 +61,10 @@ pub struct Engine {
     pub smoothed_dt: Option<f32>,
     /// Background audio service, polled once per frame.
     pub audio_manager: AudioManager,
+    /// Dialogue graph currently being played, if any.
+    pub dialogue: Option<ActiveDialogue>,
+    /// Room transition being played, freezing gameplay until it ends.
+    pub room_transition: Option<ActiveRoomTransition>,
 }
 
 /// Represents the current state of the active game.
@@ -88,10 +103,13 @@ impl BishopApp for Engine {
             self.diagnostics.handle_input(&mut *ctx.borrow_mut());
         }
 
-        if self.game_state == GameState::Playing {
+        if self.game_state == GameState::Playing && self.room_transition.is_some() {
+            self.update_room_transition(raw_dt);
+        } else if self.game_state == GameState::Playing {
             self.accumulator = (self.accumulator + dt).min(MAX_ACCUM);
 
-            while self.accumulator >= FIXED_DT {
+            // Stop stepping once a room transition freezes gameplay
+            while self.accumulator >= FIXED_DT && self.room_transition.is_none() {
                 self.accumulator -= FIXED_DT;
                 self.fixed_update(&mut *ctx.borrow_mut(), FIXED_DT);
             }
@@ -114,7 +132,12 @@ impl BishopApp for Engine {
         self.render(&ctx, alpha);
 
         // Process ui events and emit to Lua
-        self.game_instance.borrow().drain_ui_events();
+        self.game_instance.borrow().drain_ui_events(&self.lua);
+        emit_setting_changes(self);
+        self.save_dialogue_variables();
+
+        // Menu commands apply in every state so open menus update while gameplay is frozen
+        ScriptSystem::process_menu_commands(self);
     }
 }
 
@@ -131,11 +154,18 @@ impl Engine {
     ) -> Self {
         let mut menu_manager = MenuManager::new();
         menu_manager.load_templates_from_disk();
+        {
+            // Menu sprites stay loaded for the whole session
+            let asset_manager = &mut game_instance.borrow_mut().game.asset_manager;
+            for sprite in menu_manager.sprites() {
+                asset_manager.increment_ref(sprite);
+            }
+        }
         menu_manager.set_action_handler(GameMenuHandler);
 
         let game_state = apply_entry_mode(&mut menu_manager, entry_mode);
 
-        Self {
+        let mut engine = Self {
             game_instance,
             game_state,
             ctx,
@@ -148,7 +178,12 @@ impl Engine {
             accumulator: 0.0,
             smoothed_dt: None,
             audio_manager: AudioManager::new::<PlatformAudioBackend>(),
-        }
+            dialogue: None,
+            room_transition: None,
+        };
+        apply_saved_settings(&mut engine);
+        engine.load_dialogue_variables();
+        engine
     }
 
     pub fn fixed_update<C: BishopContext>(&mut self, ctx: &mut C, dt: f32) {
@@ -170,7 +205,7 @@ impl Engine {
         }
 
         // Resolve room transitions before updating the camera
-        TransitionManager::handle_transitions(&mut game_instance);
+        let room_change = TransitionManager::handle_transitions(&mut game_instance);
 
         let game_ctx = game_instance.game.ctx_mut();
         if let Some(current_room) = game_ctx.cur_world.current_room() {
@@ -179,25 +214,55 @@ impl Engine {
                 game_ctx.ecs,
                 current_room,
                 game_ctx.cur_world.grid_size,
+                dt,
             );
         }
+
+        drop(game_instance);
+        if let Some(change) = room_change {
+            self.begin_room_transition(ctx, change);
+        }
     }
 
     pub fn update(&mut self, dt: f32) {
+        let speech_events;
+        let mut animation_events = Vec::new();
+        let tween_callbacks;
         {
             // Keep borrow_mut in this scope
             let mut game_instance = self.game_instance.borrow_mut();
-            update_speech_timers(&mut game_instance.game.ecs, dt);
+            clear_consumed_input();
+            let advance_input = &game_instance.game.text_manager.config.advance_input;
+            let advance = if !self.menu_manager.has_active_menu()
+                && advance_input.is_pressed(&*self.ctx.borrow())
+            {
+                speech_advance_target(&game_instance.game.ecs, self.speech_focus())
+            } else {
+                None
+            };
+            // Advancing a bubble uses up the press so scripts don't also act on it
+            if advance.is_some() {
+                advance_input.keys().for_each(consume_key_press);
+            }
+            speech_events = update_speech_bubbles(&mut game_instance.game.ecs, dt, advance);
 
             let game_ctx = game_instance.game.ctx_mut();
             let asset_manager = game_ctx.asset_manager;
             let ecs = game_ctx.ecs;
+            let grid_size = game_ctx.cur_world.grid_size;
 
             if let Some(current_room) = game_ctx.cur_world.current_room() {
                 let loader = self.ctx.borrow();
-                update_animation_sytem(&*loader, ecs, asset_manager, dt, current_room.id);
+                update_state_machines(ecs, current_room.id);
+                update_particles(ecs, current_room.id, dt);
+                animation_events =
+                    update_animation_sytem(&*loader, ecs, asset_manager, dt, current_room.id);
+                play_animation_event_sounds(ecs, &animation_events);
             }
 
+            let game = &mut game_instance.game;
+            tween_callbacks = game.tweens.update(&mut game.ecs, grid_size, dt);
+
             // Load scripts in this scope TODO: make this part of run_scripts when scope is finalized
             let ctx = game_instance.game.ctx_mut();
             if let Err(e) = ScriptSystem::load_scripts(&self.lua, ctx.ecs, ctx.script_manager) {
@@ -205,12 +270,20 @@ impl Engine {
             }
         }
 
+        self.emit_speech_events(speech_events);
+        emit_animation_events(self, animation_events);
+        run_tween_callbacks(self, tween_callbacks);
+        self.update_dialogue(dt);
+
         // Sync menu state for Lua scripts
         set_menu_active(self.menu_manager.has_active_menu());
 
-        // Run scripts outside borrow_mut scope
-        if let Err(e) = ScriptSystem::run_scripts(dt, self) {
-            onscreen_error!("Error running scripts: {}", e);
+        // Run scripts outside borrow_mut scope. They stay paused while a room
+        // transition started during this frame's fixed update plays.
+        if self.room_transition.is_none() {
+            if let Err(e) = ScriptSystem::run_scripts(dt, self) {
+                onscreen_error!("Error running scripts: {}", e);
+            }
         }
     }
 
@@ -218,7 +291,13 @@ impl Engine {
         if !self.menu_manager.is_hiding_game() {
             let mut ctx_borrow = ctx.borrow_mut();
             let platform_ctx = &mut *ctx_borrow;
-            let render_cam = build_render_camera(&self.camera_manager, alpha);
+            let mut render_cam = build_render_camera(&self.camera_manager, alpha);
+            let mut leaving = None;
+            if let Some((camera, from)) = self.room_transition_camera(&render_cam) {
+                render_cam = camera;
+                leaving = Some(from);
+            }
+            let transition = self.room_transition_frame(&render_cam);
             let mut game_borrow = self.game_instance.borrow_mut();
             let game_instance = &mut *game_borrow;
 
@@ -228,9 +307,14 @@ impl Engine {
                 &mut self.render_system,
                 &render_cam,
                 alpha,
+                transition.as_ref(),
+                leaving,
             );
 
-            render_screen_space(platform_ctx, game_instance, &render_cam, alpha);
+            // Speech bubbles belong to the room, not the transition over it
+            if transition.is_none() {
+                render_screen_space(platform_ctx, game_instance, &render_cam, alpha);
+            }
 
             if self.is_playtest {
                 self.diagnostics.draw(platform_ctx);
@@ -270,4 +354,3 @@ fn resolve_game_state(current_state: GameState, menu_manager: &MenuManager) -> G
         GameState::Playing
     }
 }
-
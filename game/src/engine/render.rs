@@ -5,16 +5,20 @@ use engine_core::prelude::*;
 
 impl Engine {
     pub(crate) fn render_menus(&mut self, ctx: &PlatformContext) {
-        if !self.menu_manager.has_active_menu() {
+        if !self.menu_manager.has_active_menu() && !self.menu_manager.has_visible_hud() {
             return;
         }
 
         ctx.borrow_mut().flush_if_needed();
         let viewport = self.render_system.viewport_rect(&*ctx.borrow());
         self.menu_manager.set_viewport(viewport);
-        let game_instance = self.game_instance.borrow();
-        self.menu_manager
-            .render(&mut *ctx.borrow_mut(), &game_instance.game.text_manager);
+        let mut game_instance = self.game_instance.borrow_mut();
+        let game = &mut game_instance.game;
+        self.menu_manager.render(
+            &mut *ctx.borrow_mut(),
+            &game.text_manager,
+            &mut game.asset_manager,
+        );
     }
 }
 
@@ -22,18 +26,21 @@ impl Engine {
 pub(super) fn build_render_camera(camera_manager: &CameraManager, alpha: f32) -> Camera2D {
     Camera2D {
         target: camera_manager.interpolated_target(alpha),
-        zoom: camera_manager.active.camera.zoom,
+        zoom: camera_manager.view_zoom(),
         ..Default::default()
     }
 }
 
-/// Renders the game world for the current frame.
+/// Renders the game world for the current frame, with any room transition over it.
+/// `leaving` is the room a slide scrolls away from, drawn beside the current room.
 pub(super) fn render_scene<C: BishopContext>(
     ctx: &mut C,
     game_instance: &mut GameInstance,
     render_system: &mut RenderSystem,
     render_cam: &Camera2D,
     alpha: f32,
+    transition: Option<&TransitionFrame>,
+    leaving: Option<RoomId>,
 ) {
     let mut game_ctx = game_instance.game.ctx_mut();
     let prev_positions = &game_instance.prev_positions;
@@ -41,23 +48,29 @@ pub(super) fn render_scene<C: BishopContext>(
     render_system.resize_for_camera(render_cam.zoom);
     render_system.begin_scene(ctx);
 
-    render_room(
+    let current = game_ctx.cur_world.current_room().map(|room| room.id);
+    let rooms: Vec<RoomId> = leaving.into_iter().chain(current).collect();
+    render_rooms(
         ctx,
         &mut game_ctx,
         render_system,
         render_cam,
         alpha,
         Some(prev_positions),
+        &rooms,
     );
 
     render_system.end_scene(ctx);
-    render_system.present_game(ctx);
+    match transition {
+        Some(frame) => render_system.present_transition(ctx, frame),
+        None => render_system.present_game(ctx),
+    }
 }
 
 /// Renders all screen-space UI elements (speech bubbles, ui etc.).
 pub fn render_screen_space<C: BishopContext>(
     ctx: &mut C,
-    game_instance: &GameInstance,
+    game_instance: &mut GameInstance,
     render_cam: &Camera2D,
     alpha: f32,
 ) {
@@ -67,7 +80,7 @@ pub fn render_screen_space<C: BishopContext>(
 /// Renders speech bubbles in screen space above the game world.
 fn render_speech<C: BishopContext>(
     ctx: &mut C,
-    game_instance: &GameInstance,
+    game_instance: &mut GameInstance,
     render_cam: &Camera2D,
     alpha: f32,
 ) {
@@ -86,11 +99,13 @@ fn render_speech<C: BishopContext>(
         grid_size,
     );
 
+    let game = &mut game_instance.game;
     render_speech_bubbles(
         ctx,
         &bubbles,
-        &game_instance.game.text_manager.config,
+        &mut game.text_manager.config,
         render_cam,
         grid_size,
+        &mut game.asset_manager,
     );
 }
@@ -0,0 +1,118 @@
+// game/src/engine/room_transitions.rs
+use super::Engine;
+use crate::transitions::room_transition::ActiveRoomTransition;
+use crate::transitions::transition_manager::RoomChange;
+use bishop::prelude::*;
+use engine_core::onscreen_error;
+use engine_core::prelude::*;
+use mlua::{Value, Variadic};
+
+impl Engine {
+    /// Emits `on_room_exit` for a player room change and starts its transition.
+    /// Changes without an animated transition emit `on_room_enter` straight away.
+    /// Camera effects of the old room are stopped first.
+    pub(super) fn begin_room_transition<C: BishopContext>(
+        &mut self,
+        ctx: &mut C,
+        change: RoomChange,
+    ) {
+        let transition = self
+            .game_instance
+            .borrow()
+            .game
+            .current_world()
+            .transition_between(change.from, change.to, change.position);
+
+        // The last rendered frame still shows the old room
+        let exit_cam = Camera2D {
+            target: self
+                .camera_manager
+                .previous_position
+                .unwrap_or(self.camera_manager.view_target()),
+            zoom: self.camera_manager.view_zoom(),
+            ..Default::default()
+        };
+        // Shakes, pans and zooms belong to the room being left
+        self.camera_manager.effects.clear();
+
+        self.emit_room_event("on_room_exit", change.from, change.to);
+        if !transition.is_animated() {
+            self.emit_room_event("on_room_enter", change.from, change.to);
+            return;
+        }
+
+        // Slides scroll the camera over both rooms instead of showing the old frame
+        if transition.style != TransitionStyle::Slide {
+            self.render_system.capture_snapshot(ctx);
+        }
+        self.camera_manager
+            .cut(&self.game_instance.borrow().game.ecs);
+
+        self.room_transition = Some(
+            ActiveRoomTransition::new(transition, change.from, change.to)
+                .with_exit_focus(exit_cam.world_to_screen(change.position, 1.0, 1.0))
+                .with_exit_view(exit_cam.target, exit_cam.zoom),
+        );
+    }
+
+    /// Plays the running room transition and emits `on_room_enter` once it ends.
+    pub(super) fn update_room_transition(&mut self, dt: f32) {
+        let Some(active) = &mut self.room_transition else {
+            return;
+        };
+        if active.advance(dt) {
+            let (from, to) = (active.from, active.to);
+            self.room_transition = None;
+            self.emit_room_event("on_room_enter", from, to);
+        }
+    }
+
+    /// Camera of a running slide and the room it scrolls away from.
+    pub(super) fn room_transition_camera(
+        &self,
+        render_cam: &Camera2D,
+    ) -> Option<(Camera2D, RoomId)> {
+        let active = self.room_transition.as_ref()?;
+        Some((active.camera(render_cam)?, active.from))
+    }
+
+    /// Frame of the running room transition, with the iris opening on the player.
+    /// Slides only move the camera, so they have no frame.
+    pub(super) fn room_transition_frame(&self, render_cam: &Camera2D) -> Option<TransitionFrame> {
+        let active = self
+            .room_transition
+            .as_ref()
+            .filter(|active| active.transition.style != TransitionStyle::Slide)?;
+        let player_pos = self
+            .game_instance
+            .borrow()
+            .game
+            .ecs
+            .get_player_transform()
+            .map_or(render_cam.target, |t| t.position);
+        Some(active.frame(render_cam.world_to_screen(player_pos, 1.0, 1.0)))
+    }
+
+    /// Emits a room event with a `{ from, to }` table of room ids.
+    fn emit_room_event(&self, name: &str, from: RoomId, to: RoomId) {
+        let event_bus = self
+            .game_instance
+            .borrow()
+            .game
+            .script_manager
+            .event_bus
+            .clone();
+        let Ok(payload) = self.lua.create_table() else {
+            onscreen_error!("Failed to create room event payload table");
+            return;
+        };
+        if payload.set("from", *from).is_err() || payload.set("to", *to).is_err() {
+            onscreen_error!("Failed to populate room event payload table");
+            return;
+        }
+        event_bus.emit(
+            name.to_string(),
+            Variadic::from_iter([Value::Table(payload)]),
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use super::Engine;
+use crate::scripting::modules::settings_module::setting_to_lua;
+use engine_core::audio::command_queue::{push_audio_command, AudioCommand};
+use engine_core::onscreen_error;
+use engine_core::storage::player_settings::*;
+use mlua::{Value, Variadic};
+
+/// Settings the engine applies itself rather than leaving to scripts.
+const ENGINE_SETTINGS: [&str; 6] = [
+    MASTER_VOLUME_SETTING,
+    MUSIC_VOLUME_SETTING,
+    SFX_VOLUME_SETTING,
+    FULLSCREEN_SETTING,
+    VSYNC_SETTING,
+    INTEGER_SCALING_SETTING,
+];
+
+/// Loads the player's saved settings and applies the ones the engine owns.
+pub(super) fn apply_saved_settings(engine: &mut Engine) {
+    let game_name = engine.game_instance.borrow().game.name.clone();
+    load_player_settings(&game_name);
+
+    for key in ENGINE_SETTINGS {
+        if let Some(value) = player_setting(key) {
+            apply_setting(engine, key, &value);
+        }
+    }
+}
+
+/// Applies settings changed this frame, emits them to Lua and saves them
+/// once no menu is open.
+pub(super) fn emit_setting_changes(engine: &mut Engine) {
+    let changes = drain_player_setting_changes();
+    if !changes.is_empty() {
+        let event_bus = engine
+            .game_instance
+            .borrow()
+            .game
+            .script_manager
+            .event_bus
+            .clone();
+        for (key, value) in changes {
+            apply_setting(engine, &key, &value);
+
+            let Ok(payload) = engine.lua.create_table() else {
+                onscreen_error!("Failed to create settings event payload table");
+                continue;
+            };
+            let Ok(lua_value) = setting_to_lua(&engine.lua, &value) else {
+                onscreen_error!("Failed to convert setting '{key}' for Lua");
+                continue;
+            };
+            if payload.set("key", key.as_str()).is_err() || payload.set("value", lua_value).is_err()
+            {
+                onscreen_error!("Failed to populate settings event payload table");
+                continue;
+            }
+
+            event_bus.emit(
+                "settings:changed".to_string(),
+                Variadic::from_iter([Value::Table(payload)]),
+            );
+        }
+    }
+
+    // Dragging a slider changes its setting every frame, so wait for the menu to close
+    if !engine.menu_manager.has_active_menu() {
+        save_player_settings();
+    }
+}
+
+/// Pushes an engine owned setting to the audio, window or renderer.
+fn apply_setting(engine: &mut Engine, key: &str, value: &SettingValue) {
+    match (key, value) {
+        (MASTER_VOLUME_SETTING, SettingValue::Number(volume)) => {
+            push_audio_command(AudioCommand::SetMasterVolume(*volume));
+        }
+        (MUSIC_VOLUME_SETTING, SettingValue::Number(volume)) => {
+            push_audio_command(AudioCommand::SetMusicVolume(*volume));
+        }
+        (SFX_VOLUME_SETTING, SettingValue::Number(volume)) => {
+            push_audio_command(AudioCommand::SetSfxVolume(*volume));
+        }
+        (FULLSCREEN_SETTING, SettingValue::Bool(fullscreen)) => {
+            let mut ctx = engine.ctx.borrow_mut();
+            if ctx.is_fullscreen() != *fullscreen {
+                ctx.toggle_fullscreen();
+            }
+        }
+        (VSYNC_SETTING, SettingValue::Bool(vsync)) => {
+            engine.ctx.borrow_mut().set_vsync(*vsync);
+        }
+        (INTEGER_SCALING_SETTING, SettingValue::Bool(integer_scaling)) => {
+            engine.render_system.integer_scaling = *integer_scaling;
+        }
+        _ => {}
+    }
+}
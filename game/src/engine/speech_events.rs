@@ -0,0 +1,33 @@
+// game/src/engine/speech_events.rs
+use super::Engine;
+use crate::scripting::modules::entity_module::lua_entity_handle;
+use engine_core::onscreen_error;
+use engine_core::prelude::*;
+use mlua::Variadic;
+
+impl Engine {
+    /// Emits `speech:revealed` and `speech:dismissed` with the speaker entity.
+    pub(crate) fn emit_speech_events(&self, events: Vec<SpeechEvent>) {
+        if events.is_empty() {
+            return;
+        }
+
+        let event_bus = self
+            .game_instance
+            .borrow()
+            .game
+            .script_manager
+            .event_bus
+            .clone();
+        for event in events {
+            let (name, entity) = match event {
+                SpeechEvent::Revealed(entity) => ("speech:revealed", entity),
+                SpeechEvent::Dismissed(entity) => ("speech:dismissed", entity),
+            };
+            match lua_entity_handle(&self.lua, entity) {
+                Ok(handle) => event_bus.emit(name.to_string(), Variadic::from_iter([handle])),
+                Err(e) => onscreen_error!("Failed to create speech event entity: {}", e),
+            }
+        }
+    }
+}
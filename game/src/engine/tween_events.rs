@@ -0,0 +1,15 @@
+// game/src/engine/tween_events.rs
+use super::Engine;
+use crate::scripting::script_system::ScriptSystem;
+use engine_core::onscreen_error;
+use mlua::Function;
+
+/// Calls the `on_done` callbacks of tweens that finished this frame.
+pub(super) fn run_tween_callbacks(engine: &mut Engine, callbacks: Vec<Function>) {
+    for callback in callbacks {
+        if let Err(e) = callback.call::<()>(()) {
+            onscreen_error!("Tween on_done callback failed: {}", e);
+        }
+        ScriptSystem::process_commands(engine);
+    }
+}
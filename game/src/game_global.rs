@@ -3,6 +3,8 @@ use crate::input::input_snapshot::InputSnapshot;
 use crate::input::{focus_priority, InputFocusMap};
 use crate::scripting::commands::lua_command::LuaCommand;
 use crate::scripting::commands::lua_command_manager::LuaCommandManager;
+use bishop::prelude::*;
+use engine_core::input::input_table::KEY_TABLE;
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use std::vec::IntoIter;
@@ -11,8 +13,10 @@ use std::vec::IntoIter;
 #[derive(Default)]
 pub struct GameServices {
     pub command_manager: RefCell<LuaCommandManager>,
+    pub menu_command_manager: RefCell<LuaCommandManager>,
     pub input_snapshot: RefCell<InputSnapshot>,
     pub menu_active: Cell<bool>,
+    pub dialogue_active: Cell<bool>,
     pub input_focus: RefCell<InputFocusMap>,
 }
 
@@ -34,11 +38,40 @@ pub fn drain_commands() -> IntoIter<Box<dyn LuaCommand>> {
     })
 }
 
+/// Push a menu `LuaCommand`. Menu commands run every frame, even while
+/// a menu has gameplay frozen, so menus update while they are open.
+pub fn push_menu_command(cmd: Box<dyn LuaCommand>) {
+    GAME_SERVICES.with(|services| {
+        services.menu_command_manager.borrow_mut().push(cmd);
+    });
+}
+
+/// Consumes the current contents of the menu command queue and returns an iterator.
+pub fn drain_menu_commands() -> IntoIter<Box<dyn LuaCommand>> {
+    GAME_SERVICES.with(|services| services.menu_command_manager.borrow_mut().drain())
+}
+
 /// Returns a fresh copy of the current `InputSnapshot`.
 pub fn get_input_snapshot() -> InputSnapshot {
     GAME_SERVICES.with(|services| services.input_snapshot.borrow().clone())
 }
 
+/// Hides this frame's press of `key` from scripts, for input the engine already used.
+pub fn consume_key_press(key: KeyCode) {
+    GAME_SERVICES.with(|services| {
+        let names = KEY_TABLE
+            .iter()
+            .filter(|(_, code)| *code == key)
+            .map(|(name, _)| *name);
+        services.input_snapshot.borrow_mut().consumed.extend(names);
+    });
+}
+
+/// Stops hiding the presses consumed in an earlier frame.
+pub fn clear_consumed_input() {
+    GAME_SERVICES.with(|services| services.input_snapshot.borrow_mut().consumed.clear());
+}
+
 /// Sets whether a menu is currently active.
 pub fn set_menu_active(active: bool) {
     GAME_SERVICES.with(|services| {
@@ -78,3 +111,13 @@ pub fn in_input_control(name: &str) -> bool {
 pub fn is_menu_active() -> bool {
     GAME_SERVICES.with(|services| services.menu_active.get())
 }
+
+/// Sets whether a dialogue graph is currently running.
+pub fn set_dialogue_active(active: bool) {
+    GAME_SERVICES.with(|services| services.dialogue_active.set(active));
+}
+
+/// Returns true if a dialogue graph is currently running.
+pub fn is_dialogue_active() -> bool {
+    GAME_SERVICES.with(|services| services.dialogue_active.get())
+}
@@ -1,13 +1,15 @@
 // game/src/input/input_snapshot.rs
 use bishop::prelude::*;
 use engine_core::input::input_table::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, Default)]
 pub struct InputSnapshot {
     pub down: HashMap<&'static str, bool>,
     pub pressed: HashMap<&'static str, bool>,
     pub released: HashMap<&'static str, bool>,
+    /// Inputs whose press this frame the engine already used, reported as not pressed.
+    pub consumed: HashSet<&'static str>,
 }
 
 impl InputSnapshot {
@@ -26,6 +28,9 @@ impl InputSnapshot {
             self.pressed.insert(name, ctx.is_key_pressed(code));
             self.released.insert(name, ctx.is_key_released(code));
         }
+        for &name in &self.consumed {
+            self.pressed.insert(name, false);
+        }
 
         // Mouse
         for &(name, button) in MOUSE_TABLE {
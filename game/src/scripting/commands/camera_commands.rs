@@ -0,0 +1,75 @@
+// game/src/scripting/commands/camera_commands.rs
+use crate::engine::Engine;
+use crate::scripting::commands::lua_command::LuaCommand;
+use engine_core::prelude::*;
+
+/// Command to shake the camera.
+pub struct ShakeCameraCmd {
+    pub intensity: f32,
+    pub duration: f32,
+}
+
+impl LuaCommand for ShakeCameraCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine
+            .camera_manager
+            .effects
+            .shake(self.intensity, self.duration);
+    }
+}
+
+/// Command to zoom the camera by a factor of its own zoom.
+pub struct ZoomCameraCmd {
+    pub factor: f32,
+    pub duration: f32,
+    pub easing: Easing,
+}
+
+impl LuaCommand for ZoomCameraCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine
+            .camera_manager
+            .effects
+            .zoom_to(self.factor, self.duration, self.easing);
+    }
+}
+
+/// Command to pan the camera to a point or entity.
+pub struct PanCameraCmd {
+    pub target: PanTarget,
+    pub duration: f32,
+    /// Seconds to hold before returning, `None` to hold until released.
+    pub hold: Option<f32>,
+    pub easing: Easing,
+}
+
+impl LuaCommand for PanCameraCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine
+            .camera_manager
+            .effects
+            .pan_to(self.target, self.duration, self.hold, self.easing);
+    }
+}
+
+/// Command to return a panned camera to its own target.
+pub struct ReleaseCameraCmd {
+    pub duration: Option<f32>,
+}
+
+impl LuaCommand for ReleaseCameraCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine.camera_manager.effects.release(self.duration);
+    }
+}
+
+/// Command to set how long switches between room cameras blend for.
+pub struct SetCameraBlendCmd {
+    pub duration: f32,
+}
+
+impl LuaCommand for SetCameraBlendCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine.camera_manager.effects.blend_duration = self.duration.max(0.0);
+    }
+}
@@ -0,0 +1,48 @@
+// game/src/scripting/commands/dialogue_commands.rs
+use crate::engine::Engine;
+use crate::scripting::commands::lua_command::LuaCommand;
+use engine_core::prelude::*;
+use mlua::Function;
+use std::collections::HashMap;
+
+/// Command to start a dialogue graph.
+pub struct StartDialogueCmd {
+    pub id: String,
+    pub participants: HashMap<String, Entity>,
+    pub on_finished: Option<Function>,
+}
+
+impl LuaCommand for StartDialogueCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        let participants = std::mem::take(&mut self.participants);
+        engine.start_dialogue(&self.id, participants, self.on_finished.take());
+    }
+}
+
+/// Command to stop the running dialogue.
+pub struct StopDialogueCmd;
+
+impl LuaCommand for StopDialogueCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine.stop_dialogue();
+    }
+}
+
+/// Command to set or clear a dialogue variable.
+pub struct SetDialogueVarCmd {
+    pub name: String,
+    pub value: Option<DialogueValue>,
+}
+
+impl LuaCommand for SetDialogueVarCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        let mut game_instance = engine.game_instance.borrow_mut();
+        let variables = &mut game_instance.dialogue_variables;
+        match self.value.take() {
+            Some(value) => variables.set(&self.name, value),
+            None => {
+                variables.remove(&self.name);
+            }
+        }
+    }
+}
@@ -1,9 +1,16 @@
 // game/src/scripting/commands/lua_command.rs
 use crate::engine::Engine;
+use bishop::prelude::*;
 use engine_core::animation::animation_clip::*;
+use engine_core::animation::state_machine::{AnimParam, AnimatorState};
+use engine_core::assets::sprite::Sprite;
 use engine_core::ecs::component_registry::COMPONENTS;
 use engine_core::ecs::entity::Entity;
 use engine_core::ecs::facing_direction::*;
+use engine_core::ecs::transform::{
+    update_entity_flip_y, update_entity_rotation, update_entity_scale,
+};
+use engine_core::particles::*;
 use engine_core::scripting::script::Script;
 use engine_core::*;
 use mlua::Function;
@@ -126,13 +133,38 @@ impl LuaCommand for ResetClipCmd {
                     state.timer = 0.0;
                     state.col = 0;
                     state.row = 0;
+                    state.step = 0;
                     state.finished = false;
+                    state.started = false;
+                    state.loops = 0;
                 }
             }
         }
     }
 }
 
+/// Sets a parameter on an entity's animation state machine.
+pub struct SetAnimParamCmd {
+    pub entity: Entity,
+    pub name: String,
+    pub value: AnimParam,
+}
+
+impl LuaCommand for SetAnimParamCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        let mut game_instance = engine.game_instance.borrow_mut();
+        let ecs = &mut game_instance.game.ecs;
+
+        // The state machine system creates the runtime state lazily
+        if !ecs.has::<AnimatorState>(self.entity) {
+            ecs.add_component_to_entity(self.entity, AnimatorState::default());
+        }
+        if let Some(animator) = ecs.get_mut::<AnimatorState>(self.entity) {
+            animator.set_param(&self.name, self.value);
+        }
+    }
+}
+
 /// Sets the horizontal flip state on an entity's animation.
 pub struct SetFlipXCmd {
     pub entity: Entity,
@@ -176,6 +208,106 @@ impl LuaCommand for SetFacingCmd {
     }
 }
 
+/// Sets the rotation of an entity and its children.
+pub struct SetRotationCmd {
+    pub entity: Entity,
+    pub degrees: f32,
+}
+
+impl LuaCommand for SetRotationCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        let mut game_instance = engine.game_instance.borrow_mut();
+        update_entity_rotation(&mut game_instance.game.ecs, self.entity, self.degrees);
+    }
+}
+
+/// Sets the scale of an entity and its children.
+pub struct SetScaleCmd {
+    pub entity: Entity,
+    pub scale: Vec2,
+}
+
+impl LuaCommand for SetScaleCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        let mut game_instance = engine.game_instance.borrow_mut();
+        update_entity_scale(&mut game_instance.game.ecs, self.entity, self.scale);
+    }
+}
+
+/// Sets the vertical flip of an entity and its children.
+pub struct SetFlipYCmd {
+    pub entity: Entity,
+    pub flip_y: bool,
+}
+
+impl LuaCommand for SetFlipYCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        let mut game_instance = engine.game_instance.borrow_mut();
+        update_entity_flip_y(&mut game_instance.game.ecs, self.entity, self.flip_y);
+    }
+}
+
+/// Sets the tint and/or opacity on an entity's sprite and animation.
+pub struct SetTintCmd {
+    pub entity: Entity,
+    pub tint: Option<Vec3>,
+    pub opacity: Option<f32>,
+}
+
+impl LuaCommand for SetTintCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        let mut game_instance = engine.game_instance.borrow_mut();
+        let ecs = &mut game_instance.game.ecs;
+
+        if let Some(sprite) = ecs.get_mut::<Sprite>(self.entity) {
+            sprite.tint = self.tint.unwrap_or(sprite.tint);
+            sprite.opacity = self.opacity.unwrap_or(sprite.opacity);
+        }
+        if let Some(animation) = ecs.get_mut::<Animation>(self.entity) {
+            animation.tint = self.tint.unwrap_or(animation.tint);
+            animation.opacity = self.opacity.unwrap_or(animation.opacity);
+        }
+    }
+}
+
+/// Queues a burst of particles on an entity's emitter.
+pub struct EmitParticlesCmd {
+    pub entity: Entity,
+    pub count: u32,
+}
+
+impl LuaCommand for EmitParticlesCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        let mut game_instance = engine.game_instance.borrow_mut();
+        emit_particles(&mut game_instance.game.ecs, self.entity, self.count);
+    }
+}
+
+/// Starts or stops continuous emission on an entity's emitter.
+pub struct SetParticlesEmittingCmd {
+    pub entity: Entity,
+    pub emitting: bool,
+    /// Removes live particles as well.
+    pub clear: bool,
+}
+
+impl LuaCommand for SetParticlesEmittingCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        let mut game_instance = engine.game_instance.borrow_mut();
+        let ecs = &mut game_instance.game.ecs;
+
+        if let Some(emitter) = ecs.get_mut::<ParticleEmitter>(self.entity) {
+            emitter.emitting = self.emitting;
+        }
+        if self.clear {
+            if let Some(state) = ecs.get_mut::<ParticleState>(self.entity) {
+                state.particles.clear();
+                state.pending = 0;
+            }
+        }
+    }
+}
+
 /// Sets the animation playback speed multiplier.
 pub struct SetAnimSpeedCmd {
     pub entity: Entity,
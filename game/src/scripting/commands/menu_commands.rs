@@ -1,6 +1,7 @@
 // game/src/scripting/commands/menu_commands.rs
 use crate::engine::Engine;
 use crate::scripting::commands::lua_command::LuaCommand;
+use engine_core::menu::MenuValue;
 
 /// Command to open a menu by id.
 pub struct OpenMenuCmd {
@@ -18,6 +19,71 @@ pub struct CloseMenuCmd;
 
 impl LuaCommand for CloseMenuCmd {
     fn execute(&mut self, engine: &mut Engine) {
-        engine.menu_manager.close_menu();
+        engine.menu_manager.close_menu_animated();
+    }
+}
+
+/// Command to show a HUD over gameplay.
+pub struct ShowHudCmd {
+    pub hud_id: String,
+}
+
+impl LuaCommand for ShowHudCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine.menu_manager.show_hud(&self.hud_id);
+    }
+}
+
+/// Command to hide a shown HUD.
+pub struct HideHudCmd {
+    pub hud_id: String,
+}
+
+impl LuaCommand for HideHudCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine.menu_manager.hide_hud(&self.hud_id);
+    }
+}
+
+/// Command to fill the scroll lists with the given key.
+pub struct SetMenuListCmd {
+    pub key: String,
+    pub items: Vec<String>,
+}
+
+impl LuaCommand for SetMenuListCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine
+            .menu_manager
+            .set_list_items(&self.key, std::mem::take(&mut self.items));
+    }
+}
+
+/// Command to set the text of the text inputs with the given key.
+pub struct SetMenuTextCmd {
+    pub key: String,
+    pub text: String,
+}
+
+impl LuaCommand for SetMenuTextCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine
+            .menu_manager
+            .set_text_value(&self.key, std::mem::take(&mut self.text));
+    }
+}
+
+/// Command to set or clear a value menu elements bind to.
+pub struct SetMenuValueCmd {
+    pub menu_id: String,
+    pub key: String,
+    pub value: Option<MenuValue>,
+}
+
+impl LuaCommand for SetMenuValueCmd {
+    fn execute(&mut self, engine: &mut Engine) {
+        engine
+            .menu_manager
+            .set_value(&self.menu_id, &self.key, self.value.take());
     }
 }
@@ -1,3 +1,5 @@
+pub mod camera_commands;
+pub mod dialogue_commands;
 pub mod lua_command;
 pub mod lua_command_manager;
 pub mod menu_commands;
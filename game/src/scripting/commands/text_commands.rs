@@ -11,9 +11,12 @@ pub struct ShowSpeechCmd {
     pub color: Option<[f32; 4]>,
     pub offset: Option<(f32, f32)>,
     pub font_size: Option<f32>,
+    pub font: Option<String>,
     pub max_width: Option<f32>,
     pub show_background: Option<bool>,
     pub background_color: Option<[f32; 4]>,
+    pub reveal_speed: Option<f32>,
+    pub wait_for_input: Option<bool>,
 }
 
 impl LuaCommand for ShowSpeechCmd {
@@ -27,11 +30,15 @@ impl LuaCommand for ShowSpeechCmd {
             color: self.color.unwrap_or(config.default_color),
             offset: self.offset.unwrap_or((0.0, config.default_offset_y)),
             font_size: self.font_size,
+            font: self.font.clone(),
             max_width: self.max_width,
             show_background: self.show_background.unwrap_or(config.show_background),
             background_color: self
                 .background_color
                 .unwrap_or(config.default_background_color),
+            reveal_speed: self.reveal_speed.unwrap_or(config.reveal_speed),
+            wait_for_input: self.wait_for_input.unwrap_or(config.wait_for_input),
+            ..Default::default()
         };
 
         game_instance
@@ -48,9 +55,11 @@ pub struct ClearSpeechCmd {
 
 impl LuaCommand for ClearSpeechCmd {
     fn execute(&mut self, engine: &mut Engine) {
-        let mut game_instance = engine.game_instance.borrow_mut();
-        let ecs = &mut game_instance.game.ecs;
-        engine_core::text::clear_speech(ecs, self.entity);
+        let dismissed = {
+            let mut game_instance = engine.game_instance.borrow_mut();
+            engine_core::text::clear_speech(&mut game_instance.game.ecs, self.entity)
+        };
+        engine.emit_speech_events(dismissed.into_iter().collect());
     }
 }
 
@@ -0,0 +1,169 @@
+// game/src/scripting/modules/camera_module.rs
+use crate::game_global::push_command;
+use crate::scripting::commands::camera_commands::*;
+use crate::scripting::modules::tween_module::{entity_from_value, parse_easing};
+use bishop::prelude::vec2;
+use engine_core::prelude::*;
+use mlua::prelude::LuaResult;
+use mlua::Lua;
+use mlua::Table;
+use mlua::Value;
+
+/// Seconds `focus` takes to reach its entity when no duration is given.
+const DEFAULT_FOCUS_DURATION: f32 = 0.5;
+
+/// Lua module that exposes the camera effects API.
+#[derive(Default)]
+pub struct CameraModule;
+register_lua_module!(CameraModule);
+
+impl LuaModule for CameraModule {
+    fn register(&self, lua: &Lua) -> LuaResult<()> {
+        let engine_tbl: Table = lua.globals().get(ENGINE)?;
+        let camera_tbl = lua.create_table()?;
+
+        let shake_fn = lua.create_function(|_lua, (intensity, duration): (f32, f32)| {
+            push_command(Box::new(ShakeCameraCmd {
+                intensity,
+                duration,
+            }));
+            Ok(())
+        })?;
+        camera_tbl.set(CAMERA_SHAKE, shake_fn)?;
+
+        let zoom_fn = lua.create_function(
+            |_lua, (factor, duration, easing): (f32, f32, Option<String>)| {
+                push_command(Box::new(ZoomCameraCmd {
+                    factor,
+                    duration,
+                    easing: easing_or_default(easing)?,
+                }));
+                Ok(())
+            },
+        )?;
+        camera_tbl.set(CAMERA_ZOOM, zoom_fn)?;
+
+        let pan_fn = lua.create_function(
+            |_lua, (x, y, duration, hold, easing): (f32, f32, f32, Option<f32>, Option<String>)| {
+                push_command(Box::new(PanCameraCmd {
+                    target: PanTarget::Point(vec2(x, y)),
+                    duration,
+                    hold: Some(hold.unwrap_or(DEFAULT_PAN_HOLD)),
+                    easing: easing_or_default(easing)?,
+                }));
+                Ok(())
+            },
+        )?;
+        camera_tbl.set(CAMERA_PAN_TO, pan_fn)?;
+
+        let focus_fn = lua.create_function(
+            |_lua, (entity, duration, easing): (Value, Option<f32>, Option<String>)| {
+                push_command(Box::new(PanCameraCmd {
+                    target: PanTarget::Entity(entity_from_value(entity)?),
+                    duration: duration.unwrap_or(DEFAULT_FOCUS_DURATION),
+                    hold: None,
+                    easing: easing_or_default(easing)?,
+                }));
+                Ok(())
+            },
+        )?;
+        camera_tbl.set(CAMERA_FOCUS, focus_fn)?;
+
+        let release_fn = lua.create_function(|_lua, duration: Option<f32>| {
+            push_command(Box::new(ReleaseCameraCmd { duration }));
+            Ok(())
+        })?;
+        camera_tbl.set(CAMERA_RELEASE, release_fn)?;
+
+        let blend_fn = lua.create_function(|_lua, duration: f32| {
+            push_command(Box::new(SetCameraBlendCmd { duration }));
+            Ok(())
+        })?;
+        camera_tbl.set(CAMERA_SET_BLEND, blend_fn)?;
+
+        engine_tbl.set(LUA_CAMERA, camera_tbl)?;
+        Ok(())
+    }
+}
+
+/// Camera moves ease in and out unless told otherwise.
+fn easing_or_default(easing: Option<String>) -> LuaResult<Easing> {
+    match easing {
+        Some(name) => parse_easing(&name),
+        None => Ok(Easing::InOutSine),
+    }
+}
+
+register_lua_api!(CameraModule, CAMERA_FILE);
+
+impl LuaApi for CameraModule {
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Camera effects module");
+        out.line("---@class CameraApi");
+        out.line("engine.camera = {}");
+        out.line("");
+
+        out.line("--- Shakes the camera. Shakes add up and fade out over `duration`.");
+        out.line("---@param intensity number From 0 to 1");
+        out.line("---@param duration number Seconds");
+        out.line(&format!(
+            "function engine.camera.{}(intensity, duration) end",
+            CAMERA_SHAKE
+        ));
+        out.line("");
+
+        out.line("--- Zooms to `factor` times the camera's own zoom. Use 1 to zoom back.");
+        out.line("---@param factor number");
+        out.line("---@param duration number Seconds");
+        out.line("---@param easing? string e.g. \"out_quad\" (default \"in_out_sine\")");
+        out.line(&format!(
+            "function engine.camera.{}(factor, duration, easing) end",
+            CAMERA_ZOOM
+        ));
+        out.line("");
+
+        out.line("--- Pans to a world point, holds, then returns to the player on its own.");
+        out.line("---@param x number");
+        out.line("---@param y number");
+        out.line("---@param duration number Seconds to travel each way");
+        out.line(&format!(
+            "---@param hold? number Seconds to stay on the point (default {})",
+            DEFAULT_PAN_HOLD
+        ));
+        out.line("---@param easing? string (default \"in_out_sine\")");
+        out.line(&format!(
+            "function engine.camera.{}(x, y, duration, hold, easing) end",
+            CAMERA_PAN_TO
+        ));
+        out.line("");
+
+        out.line("--- Pans to an entity and follows it until `release` is called.");
+        out.line("---@param entity Entity|integer");
+        out.line(&format!(
+            "---@param duration? number Seconds to travel (default {})",
+            DEFAULT_FOCUS_DURATION
+        ));
+        out.line("---@param easing? string (default \"in_out_sine\")");
+        out.line(&format!(
+            "function engine.camera.{}(entity, duration, easing) end",
+            CAMERA_FOCUS
+        ));
+        out.line("");
+
+        out.line("--- Returns a panned or focused camera to the player.");
+        out.line("---@param duration? number Seconds (default: the pan's duration)");
+        out.line(&format!(
+            "function engine.camera.{}(duration) end",
+            CAMERA_RELEASE
+        ));
+        out.line("");
+
+        out.line("--- Sets how long switches between room cameras blend. 0 cuts.");
+        out.line("---@param duration number Seconds");
+        out.line(&format!(
+            "function engine.camera.{}(duration) end",
+            CAMERA_SET_BLEND
+        ));
+        out.line("");
+    }
+}
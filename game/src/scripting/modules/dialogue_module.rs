@@ -0,0 +1,162 @@
+// game/src/scripting/modules/dialogue_module.rs
+use crate::game_global::{is_dialogue_active, push_command};
+use crate::scripting::commands::dialogue_commands::*;
+use crate::scripting::lua_ctx::LuaGameCtx;
+use crate::scripting::modules::entity_module::EntityHandle;
+use engine_core::prelude::*;
+use mlua::prelude::LuaResult;
+use mlua::Function;
+use mlua::Lua;
+use mlua::Table;
+use mlua::Value;
+use std::collections::HashMap;
+
+/// Lua module that exposes branching dialogue graphs under `engine.dialogue`.
+#[derive(Default)]
+pub struct DialogueModule;
+register_lua_module!(DialogueModule);
+
+impl LuaModule for DialogueModule {
+    fn register(&self, lua: &Lua) -> LuaResult<()> {
+        let engine_tbl: Table = lua.globals().get(ENGINE)?;
+        let dialogue_tbl = lua.create_table()?;
+
+        let start_fn = lua.create_function(
+            |_lua, (id, participants, on_finished): (String, Option<Table>, Option<Function>)| {
+                let participants = match participants {
+                    Some(table) => participants_from_table(table)?,
+                    None => HashMap::new(),
+                };
+                push_command(Box::new(StartDialogueCmd {
+                    id,
+                    participants,
+                    on_finished,
+                }));
+                Ok(())
+            },
+        )?;
+        dialogue_tbl.set(DIALOGUE_START, start_fn)?;
+
+        let stop_fn = lua.create_function(|_lua, ()| {
+            push_command(Box::new(StopDialogueCmd));
+            Ok(())
+        })?;
+        dialogue_tbl.set(DIALOGUE_STOP, stop_fn)?;
+
+        let is_active_fn = lua.create_function(|_lua, ()| Ok(is_dialogue_active()))?;
+        dialogue_tbl.set(DIALOGUE_IS_ACTIVE, is_active_fn)?;
+
+        let choose_fn = lua.create_function(|_lua, index: usize| {
+            // Lua options are 1-based
+            if index > 0 {
+                push_dialogue_choice(index - 1);
+            }
+            Ok(())
+        })?;
+        dialogue_tbl.set(DIALOGUE_CHOOSE, choose_fn)?;
+
+        let get_var_fn = lua.create_function(|lua, name: String| {
+            let ctx = LuaGameCtx::borrow_ctx(lua)?;
+            let game_instance = ctx.game_instance.borrow();
+            let value = match game_instance.dialogue_variables.get(&name) {
+                Some(DialogueValue::Bool(b)) => Value::Boolean(*b),
+                Some(DialogueValue::Number(n)) => Value::Number(*n),
+                Some(DialogueValue::Text(s)) => Value::String(lua.create_string(s)?),
+                None => Value::Nil,
+            };
+            Ok(value)
+        })?;
+        dialogue_tbl.set(DIALOGUE_GET_VAR, get_var_fn)?;
+
+        let set_var_fn = lua.create_function(|_lua, (name, value): (String, Value)| {
+            let value = match value {
+                Value::Nil => None,
+                Value::Boolean(b) => Some(DialogueValue::Bool(b)),
+                Value::Integer(i) => Some(DialogueValue::Number(i as f64)),
+                Value::Number(n) => Some(DialogueValue::Number(n)),
+                Value::String(s) => Some(DialogueValue::Text(s.to_str()?.to_string())),
+                other => {
+                    return Err(mlua::Error::RuntimeError(format!(
+                        "dialogue variable '{name}' cannot hold a {}",
+                        other.type_name()
+                    )));
+                }
+            };
+            push_command(Box::new(SetDialogueVarCmd { name, value }));
+            Ok(())
+        })?;
+        dialogue_tbl.set(DIALOGUE_SET_VAR, set_var_fn)?;
+
+        engine_tbl.set(LUA_DIALOGUE, dialogue_tbl)?;
+        Ok(())
+    }
+}
+
+/// Reads a `role -> entity` table, accepting entity handles or raw entity ids.
+fn participants_from_table(table: Table) -> LuaResult<HashMap<String, Entity>> {
+    let mut participants = HashMap::new();
+    for pair in table.pairs::<String, Value>() {
+        let (role, value) = pair?;
+        let entity = match value {
+            Value::UserData(ud) => ud.borrow::<EntityHandle>()?.entity,
+            Value::Integer(id) => Entity(id as usize),
+            other => {
+                return Err(mlua::Error::RuntimeError(format!(
+                    "dialogue participant '{role}' must be an entity, got {}",
+                    other.type_name()
+                )));
+            }
+        };
+        participants.insert(role, entity);
+    }
+    Ok(participants)
+}
+
+register_lua_api!(DialogueModule, DIALOGUE_FILE);
+
+impl LuaApi for DialogueModule {
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Branching dialogue module");
+        out.line("---@class DialogueApi");
+        out.line("engine.dialogue = {}");
+        out.line("");
+
+        out.line(
+            "--- Starts a dialogue graph from Resources/dialogue, replacing any running dialogue.",
+        );
+        out.line("--- Emits `dialogue:line` (role, text) per line and `dialogue:finished` (id) at the end.");
+        out.line("---@param id string The dialogue graph id (e.g. \"merchant\")");
+        out.line(
+            "---@param participants? table<string, Entity|integer> Maps speaker roles to entities",
+        );
+        out.line("---@param on_finished? fun(id: string) Called when the dialogue ends");
+        out.line("function engine.dialogue.start(id, participants, on_finished) end");
+        out.line("");
+
+        out.line("--- Stops the running dialogue without calling its callback.");
+        out.line("function engine.dialogue.stop() end");
+        out.line("");
+
+        out.line("--- Returns true if a dialogue is running.");
+        out.line("---@return boolean");
+        out.line("function engine.dialogue.is_active() end");
+        out.line("");
+
+        out.line("--- Picks an option on the current choice node.");
+        out.line("---@param index integer The 1-based option index");
+        out.line("function engine.dialogue.choose(index) end");
+        out.line("");
+
+        out.line("--- Returns the value of a dialogue variable.");
+        out.line("---@param name string");
+        out.line("---@return boolean|number|string|nil");
+        out.line("function engine.dialogue.get_var(name) end");
+        out.line("");
+
+        out.line("--- Sets a dialogue variable. Passing nil removes it.");
+        out.line("---@param name string");
+        out.line("---@param value boolean|number|string|nil");
+        out.line("function engine.dialogue.set_var(name, value) end");
+        out.line("");
+    }
+}
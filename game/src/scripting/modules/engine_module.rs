@@ -333,6 +333,13 @@ impl LuaApi for EngineModule {
 
         // engine.on
         out.line("--- Register an event handler");
+        out.line(
+            "--- `on_room_exit` and `on_room_enter` receive `{ from, to }` room ids when the player",
+        );
+        out.line("--- changes room. `on_room_enter` waits for the room transition to finish.");
+        out.line("--- `settings:changed` receives `{ key, value }` when a player setting changes.");
+        out.line("--- `text_input:<key>` receives the submitted text and `list:<key>` receives");
+        out.line("--- `{ index, item }` when a menu scroll list item is picked.");
         out.line("--- @param event string The name of the event to listen for");
         out.line("--- @param handler function The Lua function that will be called");
         out.line("--- @return nil");
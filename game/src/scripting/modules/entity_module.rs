@@ -4,6 +4,8 @@ use crate::scripting::commands::lua_command::*;
 use crate::scripting::commands::text_commands::*;
 use crate::scripting::lua_ctx::LuaGameCtx;
 use crate::scripting::lua_helpers::*;
+use crate::scripting::modules::text_module::text_values_from_table;
+use bishop::prelude::{Vec2, Vec3};
 use engine_core::prelude::*;
 use mlua::prelude::LuaResult;
 use mlua::Lua;
@@ -13,7 +15,6 @@ use mlua::UserDataMethods;
 use mlua::UserDataRegistry;
 use mlua::Value;
 use mlua::Variadic;
-use std::collections::HashMap;
 
 /// Lua module that exposes a constructor for `EntityHandle`.
 #[derive(Default)]
@@ -93,6 +94,17 @@ pub enum EntityHandleMethod {
     SetAnimSpeed(SetAnimSpeedMethod),
     GetCurrentFrame(GetCurrentFrameMethod),
     IsClipFinished(IsClipFinishedMethod),
+    GetSlice(GetSliceMethod),
+    SetAnimParam(SetAnimParamMethod),
+    GetAnimState(GetAnimStateMethod),
+    SetRotation(SetRotationMethod),
+    SetScale(SetScaleMethod),
+    SetFlipY(SetFlipYMethod),
+    SetTint(SetTintMethod),
+    SetOpacity(SetOpacityMethod),
+    EmitParticles(EmitParticlesMethod),
+    StartParticles(StartParticlesMethod),
+    StopParticles(StopParticlesMethod),
     Say(SayMethod),
     ClearSpeech(ClearSpeechMethod),
     IsSpeaking(IsSpeakingMethod),
@@ -118,6 +130,17 @@ fn entity_handle_methods() -> Vec<EntityHandleMethod> {
         EntityHandleMethod::SetAnimSpeed(SetAnimSpeedMethod),
         EntityHandleMethod::GetCurrentFrame(GetCurrentFrameMethod),
         EntityHandleMethod::IsClipFinished(IsClipFinishedMethod),
+        EntityHandleMethod::GetSlice(GetSliceMethod),
+        EntityHandleMethod::SetAnimParam(SetAnimParamMethod),
+        EntityHandleMethod::GetAnimState(GetAnimStateMethod),
+        EntityHandleMethod::SetRotation(SetRotationMethod),
+        EntityHandleMethod::SetScale(SetScaleMethod),
+        EntityHandleMethod::SetFlipY(SetFlipYMethod),
+        EntityHandleMethod::SetTint(SetTintMethod),
+        EntityHandleMethod::SetOpacity(SetOpacityMethod),
+        EntityHandleMethod::EmitParticles(EmitParticlesMethod),
+        EntityHandleMethod::StartParticles(StartParticlesMethod),
+        EntityHandleMethod::StopParticles(StopParticlesMethod),
         EntityHandleMethod::Say(SayMethod),
         EntityHandleMethod::ClearSpeech(ClearSpeechMethod),
         EntityHandleMethod::IsSpeaking(IsSpeakingMethod),
@@ -144,6 +167,17 @@ impl LuaMethod<EntityHandle> for EntityHandleMethod {
             EntityHandleMethod::SetAnimSpeed(m) => m.register(methods),
             EntityHandleMethod::GetCurrentFrame(m) => m.register(methods),
             EntityHandleMethod::IsClipFinished(m) => m.register(methods),
+            EntityHandleMethod::GetSlice(m) => m.register(methods),
+            EntityHandleMethod::SetAnimParam(m) => m.register(methods),
+            EntityHandleMethod::GetAnimState(m) => m.register(methods),
+            EntityHandleMethod::SetRotation(m) => m.register(methods),
+            EntityHandleMethod::SetScale(m) => m.register(methods),
+            EntityHandleMethod::SetFlipY(m) => m.register(methods),
+            EntityHandleMethod::SetTint(m) => m.register(methods),
+            EntityHandleMethod::SetOpacity(m) => m.register(methods),
+            EntityHandleMethod::EmitParticles(m) => m.register(methods),
+            EntityHandleMethod::StartParticles(m) => m.register(methods),
+            EntityHandleMethod::StopParticles(m) => m.register(methods),
             EntityHandleMethod::Say(m) => m.register(methods),
             EntityHandleMethod::ClearSpeech(m) => m.register(methods),
             EntityHandleMethod::IsSpeaking(m) => m.register(methods),
@@ -169,6 +203,17 @@ impl LuaMethod<EntityHandle> for EntityHandleMethod {
             EntityHandleMethod::SetAnimSpeed(m) => m.emit_api(out),
             EntityHandleMethod::GetCurrentFrame(m) => m.emit_api(out),
             EntityHandleMethod::IsClipFinished(m) => m.emit_api(out),
+            EntityHandleMethod::GetSlice(m) => m.emit_api(out),
+            EntityHandleMethod::SetAnimParam(m) => m.emit_api(out),
+            EntityHandleMethod::GetAnimState(m) => m.emit_api(out),
+            EntityHandleMethod::SetRotation(m) => m.emit_api(out),
+            EntityHandleMethod::SetScale(m) => m.emit_api(out),
+            EntityHandleMethod::SetFlipY(m) => m.emit_api(out),
+            EntityHandleMethod::SetTint(m) => m.emit_api(out),
+            EntityHandleMethod::SetOpacity(m) => m.emit_api(out),
+            EntityHandleMethod::EmitParticles(m) => m.emit_api(out),
+            EntityHandleMethod::StartParticles(m) => m.emit_api(out),
+            EntityHandleMethod::StopParticles(m) => m.emit_api(out),
             EntityHandleMethod::Say(m) => m.emit_api(out),
             EntityHandleMethod::ClearSpeech(m) => m.emit_api(out),
             EntityHandleMethod::IsSpeaking(m) => m.emit_api(out),
@@ -210,7 +255,7 @@ impl LuaMethod<EntityHandle> for GetMethod {
 
     fn emit_api(&self, out: &mut LuaApiWriter) {
         out.line("-- Component getters");
-        for reg in COMPONENTS.iter() {
+        for reg in COMPONENTS.iter().filter(|r| !r.runtime) {
             out.line(&format!(
                 "---@overload fun(self: Entity, component: \"{}\"): {}",
                 reg.type_name, reg.type_name
@@ -237,7 +282,7 @@ impl LuaMethod<EntityHandle> for SetMethod {
         });
 
         // Typed setters
-        for reg in COMPONENTS.iter() {
+        for reg in COMPONENTS.iter().filter(|r| !r.runtime) {
             let comp_name = reg.type_name.to_string();
             let fn_name = format!("{}_{}", SET, to_snake_case(reg.type_name));
             methods.add_method(fn_name.as_str(), move |_lua, this, value: Value| {
@@ -260,7 +305,7 @@ impl LuaMethod<EntityHandle> for SetMethod {
         out.line("");
 
         out.line("-- Typed component setters");
-        for reg in COMPONENTS.iter() {
+        for reg in COMPONENTS.iter().filter(|r| !r.runtime) {
             let type_name = reg.type_name;
             let fn_name = to_snake_case(type_name);
             out.line("---@param self Entity");
@@ -402,6 +447,9 @@ impl LuaMethod<EntityHandle> for SetClipMethod {
 
     fn emit_api(&self, out: &mut LuaApiWriter) {
         out.line("--- Sets the active animation clip.");
+        out.line("--- Frame events emit `animation:event` (entity, name, clip, frame) when their frame is entered,");
+        out.line("--- and non-looping clips emit `animation:finished` (entity, clip) after their last frame.");
+        out.line("--- The entity's script also receives `on_animation_event(self, name, clip, frame)` and `on_clip_finished(self, clip)`.");
         out.line("---@param clip_name string The name of the clip (e.g. \"Walk\", \"Idle\")");
         out.line(&format!("function Entity:{}(clip_name) end", SET_CLIP));
         out.line("");
@@ -601,6 +649,291 @@ impl LuaMethod<EntityHandle> for IsClipFinishedMethod {
     }
 }
 
+/// Method: `entity:get_slice(name) -> table|nil`
+pub struct GetSliceMethod;
+impl LuaMethod<EntityHandle> for GetSliceMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(GET_SLICE, |lua, this, name: String| {
+            let ctx = LuaGameCtx::borrow_ctx(lua)?;
+            let game_instance = ctx.game_instance.borrow();
+            let ecs = &game_instance.game.ecs;
+
+            let (Some(animation), Some(frame)) = (
+                ecs.get::<Animation>(this.entity),
+                ecs.get::<CurrentFrame>(this.entity),
+            ) else {
+                return Ok(Value::Nil);
+            };
+            let Some(clip) = animation.clips.get(&frame.clip_id) else {
+                return Ok(Value::Nil);
+            };
+            let frame_index = frame.row * clip.cols + frame.col;
+            let Some(key) = clip.slice(&name, frame_index) else {
+                return Ok(Value::Nil);
+            };
+
+            let table = lua.create_table()?;
+            table.set("x", key.bounds.x)?;
+            table.set("y", key.bounds.y)?;
+            table.set("w", key.bounds.w)?;
+            table.set("h", key.bounds.h)?;
+            if let Some(pivot) = key.pivot {
+                table.set("pivot_x", pivot.x)?;
+                table.set("pivot_y", pivot.y)?;
+            }
+            Ok(Value::Table(table))
+        });
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Gets a named Aseprite slice for the current animation frame.");
+        out.line("--- Bounds and pivot are in frame pixels.");
+        out.line("---@param name string");
+        out.line("---@return {x: number, y: number, w: number, h: number, pivot_x: number?, pivot_y: number?}|nil");
+        out.line(&format!("function Entity:{}(name) end", GET_SLICE));
+        out.line("");
+    }
+}
+
+/// Method: `entity:set_anim_param(name, value)`
+pub struct SetAnimParamMethod;
+impl LuaMethod<EntityHandle> for SetAnimParamMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(
+            SET_ANIM_PARAM,
+            |_lua, this, (name, value): (String, Value)| {
+                let value = match value {
+                    Value::Boolean(b) => AnimParam::Bool(b),
+                    Value::Integer(i) => AnimParam::Float(i as f32),
+                    Value::Number(n) => AnimParam::Float(n as f32),
+                    other => {
+                        return Err(mlua::Error::RuntimeError(format!(
+                            "set_anim_param expects a boolean or number, got {}",
+                            other.type_name()
+                        )));
+                    }
+                };
+                push_command(Box::new(SetAnimParamCmd {
+                    entity: this.entity,
+                    name,
+                    value,
+                }));
+                Ok(())
+            },
+        );
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Sets a parameter on the entity's animation state machine.");
+        out.line("--- Setting a trigger parameter to true fires it until a transition uses it.");
+        out.line("---@param name string");
+        out.line("---@param value boolean|number");
+        out.line(&format!(
+            "function Entity:{}(name, value) end",
+            SET_ANIM_PARAM
+        ));
+        out.line("");
+    }
+}
+
+/// Method: `entity:get_anim_state() -> string|nil`
+pub struct GetAnimStateMethod;
+impl LuaMethod<EntityHandle> for GetAnimStateMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(GET_ANIM_STATE, |lua, this, ()| {
+            let ctx = LuaGameCtx::borrow_ctx(lua)?;
+            let game_instance = ctx.game_instance.borrow();
+            let state = game_instance
+                .game
+                .ecs
+                .get::<AnimatorState>(this.entity)
+                .and_then(|animator| animator.current.clone());
+            Ok(state)
+        });
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Gets the current state of the entity's animation state machine.");
+        out.line("---@return string|nil");
+        out.line(&format!("function Entity:{}() end", GET_ANIM_STATE));
+        out.line("");
+    }
+}
+
+/// Method: `entity:set_rotation(degrees)`
+pub struct SetRotationMethod;
+impl LuaMethod<EntityHandle> for SetRotationMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(SET_ROTATION, |_lua, this, degrees: f32| {
+            push_command(Box::new(SetRotationCmd {
+                entity: this.entity,
+                degrees,
+            }));
+            Ok(())
+        });
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Sets the clockwise rotation in degrees. Children rotate with the entity.");
+        out.line("---@param degrees number");
+        out.line(&format!("function Entity:{}(degrees) end", SET_ROTATION));
+        out.line("");
+    }
+}
+
+/// Method: `entity:set_scale(x, y)`
+pub struct SetScaleMethod;
+impl LuaMethod<EntityHandle> for SetScaleMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(SET_SCALE, |_lua, this, (x, y): (f32, Option<f32>)| {
+            push_command(Box::new(SetScaleCmd {
+                entity: this.entity,
+                scale: Vec2::new(x, y.unwrap_or(x)),
+            }));
+            Ok(())
+        });
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Sets the scale. A negative axis mirrors the entity. Children scale with it.");
+        out.line("---@param x number");
+        out.line("---@param y number? Defaults to x");
+        out.line(&format!("function Entity:{}(x, y) end", SET_SCALE));
+        out.line("");
+    }
+}
+
+/// Method: `entity:set_flip_y(true)`
+pub struct SetFlipYMethod;
+impl LuaMethod<EntityHandle> for SetFlipYMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(SET_FLIP_Y, |_lua, this, flip_y: bool| {
+            push_command(Box::new(SetFlipYCmd {
+                entity: this.entity,
+                flip_y,
+            }));
+            Ok(())
+        });
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Sets vertical flip. Children are mirrored with the entity.");
+        out.line("---@param flip_y boolean Whether to flip vertically");
+        out.line(&format!("function Entity:{}(flip_y) end", SET_FLIP_Y));
+        out.line("");
+    }
+}
+
+/// Method: `entity:set_tint(r, g, b)`
+pub struct SetTintMethod;
+impl LuaMethod<EntityHandle> for SetTintMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(SET_TINT, |_lua, this, (r, g, b): (f32, f32, f32)| {
+            push_command(Box::new(SetTintCmd {
+                entity: this.entity,
+                tint: Some(Vec3::new(r, g, b)),
+                opacity: None,
+            }));
+            Ok(())
+        });
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Sets the color multiplied with the sprite or animation (0-1 per channel).");
+        out.line("---@param r number");
+        out.line("---@param g number");
+        out.line("---@param b number");
+        out.line(&format!("function Entity:{}(r, g, b) end", SET_TINT));
+        out.line("");
+    }
+}
+
+/// Method: `entity:set_opacity(alpha)`
+pub struct SetOpacityMethod;
+impl LuaMethod<EntityHandle> for SetOpacityMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(SET_OPACITY, |_lua, this, opacity: f32| {
+            push_command(Box::new(SetTintCmd {
+                entity: this.entity,
+                tint: None,
+                opacity: Some(opacity),
+            }));
+            Ok(())
+        });
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Sets the opacity of the sprite or animation (0-1).");
+        out.line("---@param alpha number");
+        out.line(&format!("function Entity:{}(alpha) end", SET_OPACITY));
+        out.line("");
+    }
+}
+
+/// Method: `entity:emit_particles(count)`
+pub struct EmitParticlesMethod;
+impl LuaMethod<EntityHandle> for EmitParticlesMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(EMIT_PARTICLES, |_lua, this, count: u32| {
+            push_command(Box::new(EmitParticlesCmd {
+                entity: this.entity,
+                count,
+            }));
+            Ok(())
+        });
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Emits a burst of particles from this entity's ParticleEmitter.");
+        out.line("---@param count integer");
+        out.line(&format!("function Entity:{}(count) end", EMIT_PARTICLES));
+        out.line("");
+    }
+}
+
+/// Method: `entity:start_particles()`
+pub struct StartParticlesMethod;
+impl LuaMethod<EntityHandle> for StartParticlesMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(START_PARTICLES, |_lua, this, ()| {
+            push_command(Box::new(SetParticlesEmittingCmd {
+                entity: this.entity,
+                emitting: true,
+                clear: false,
+            }));
+            Ok(())
+        });
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Starts continuous emission and fires the emitter's start burst.");
+        out.line(&format!("function Entity:{}() end", START_PARTICLES));
+        out.line("");
+    }
+}
+
+/// Method: `entity:stop_particles(clear)`
+pub struct StopParticlesMethod;
+impl LuaMethod<EntityHandle> for StopParticlesMethod {
+    fn register<M: UserDataMethods<EntityHandle>>(&self, methods: &mut M) {
+        methods.add_method(STOP_PARTICLES, |_lua, this, clear: Option<bool>| {
+            push_command(Box::new(SetParticlesEmittingCmd {
+                entity: this.entity,
+                emitting: false,
+                clear: clear.unwrap_or(false),
+            }));
+            Ok(())
+        });
+    }
+
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Stops continuous emission. Live particles finish unless `clear` is true.");
+        out.line("---@param clear? boolean Remove live particles immediately");
+        out.line(&format!("function Entity:{}(clear) end", STOP_PARTICLES));
+        out.line("");
+    }
+}
+
 /// Method: `entity:say(dialogue_id, key, opts)`
 pub struct SayMethod;
 impl LuaMethod<EntityHandle> for SayMethod {
@@ -623,21 +956,13 @@ impl LuaMethod<EntityHandle> for SayMethod {
                         return Ok(());
                     }
                 };
+                let language = game_instance.game.text_manager.get_language().to_string();
                 drop(game_instance);
 
-                let text = if let Some(ref opts_table) = opts {
-                    if let Ok(vars_table) = opts_table.get::<Table>("vars") {
-                        let mut vars = HashMap::new();
-                        for (k, v) in vars_table.pairs::<String, String>().flatten() {
-                            vars.insert(k, v);
-                        }
-                        interpolate(&text, &vars)
-                    } else {
-                        text
-                    }
-                } else {
-                    text
-                };
+                let vars = text_values_from_table(
+                    opts.as_ref().and_then(|t| t.get::<Table>("vars").ok()),
+                )?;
+                let text = interpolate(&text, &language, &vars);
 
                 let duration = opts
                     .as_ref()
@@ -662,10 +987,17 @@ impl LuaMethod<EntityHandle> for SayMethod {
                 });
 
                 let font_size = opts.as_ref().and_then(|t| t.get::<f32>("font_size").ok());
+                let font = opts.as_ref().and_then(|t| t.get::<String>("font").ok());
                 let max_width = opts.as_ref().and_then(|t| t.get::<f32>("max_width").ok());
                 let show_background = opts
                     .as_ref()
                     .and_then(|t| t.get::<bool>("show_background").ok());
+                let reveal_speed = opts
+                    .as_ref()
+                    .and_then(|t| t.get::<f32>("reveal_speed").ok());
+                let wait_for_input = opts
+                    .as_ref()
+                    .and_then(|t| t.get::<bool>("wait_for_input").ok());
 
                 let background_color = opts.as_ref().and_then(|t| {
                     t.get::<Table>("background_color").ok().and_then(|c| {
@@ -685,9 +1017,12 @@ impl LuaMethod<EntityHandle> for SayMethod {
                     color,
                     offset,
                     font_size,
+                    font,
                     max_width,
                     show_background,
                     background_color,
+                    reveal_speed,
+                    wait_for_input,
                 }));
                 Ok(())
             },
@@ -698,7 +1033,8 @@ impl LuaMethod<EntityHandle> for SayMethod {
         out.line("--- Shows a speech bubble with text from a dialogue file.");
         out.line("---@param dialogue_id string The dialogue file ID (e.g. \"npc_merchant\")");
         out.line("---@param key string The dialogue key (e.g. \"greeting\")");
-        out.line("---@param opts? {vars?: table<string, string>, duration?: number, color?: number[], offset?: number[], font_size?: number, max_width?: number, show_background?: boolean, background_color?: number[]}");
+        out.line("---@param opts? {vars?: table<string, string|number|boolean>, duration?: number, color?: number[], offset?: number[], font_size?: number, font?: string, max_width?: number, show_background?: boolean, background_color?: number[], reveal_speed?: number, wait_for_input?: boolean}");
+        out.line("--- Emits `speech:revealed` (entity) when the text is fully shown and `speech:dismissed` (entity) when the bubble closes.");
         out.line(&format!(
             "function Entity:{}(dialogue_id, key, opts) end",
             SAY
@@ -720,7 +1056,7 @@ impl LuaMethod<EntityHandle> for ClearSpeechMethod {
     }
 
     fn emit_api(&self, out: &mut LuaApiWriter) {
-        out.line("--- Removes any speech bubble from the entity.");
+        out.line("--- Removes any speech bubble from the entity, emitting `speech:dismissed` (entity).");
         out.line(&format!("function Entity:{}() end", CLEAR_SPEECH));
         out.line("");
     }
@@ -759,31 +1095,12 @@ impl LuaMethod<EntityHandle> for PlaySoundMethod {
             };
 
             let group_id = SoundGroupId::Custom(group_name.clone());
-            let Some(group) = source.groups.get(&group_id) else {
+            if !source.play_group(&group_id, *this.entity as u64) {
                 log::warn!(
                     "Entity {:?} tried to play missing sound group '{}'",
                     this.entity,
                     group_name
                 );
-                return Ok(());
-            };
-            let volume = (group.volume * source.runtime_volume).clamp(0.0, 1.0);
-
-            if group.looping {
-                push_audio_command(AudioCommand::PlayLoop {
-                    handle: *this.entity as u64,
-                    sounds: group.sounds.clone(),
-                    volume,
-                    pitch_variation: group.pitch_variation,
-                    volume_variation: group.volume_variation,
-                });
-            } else {
-                push_audio_command(AudioCommand::PlayVariedSfx {
-                    sounds: group.sounds.clone(),
-                    volume,
-                    pitch_variation: group.pitch_variation,
-                    volume_variation: group.volume_variation,
-                });
             }
             Ok(())
         });
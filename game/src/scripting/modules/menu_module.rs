@@ -1,6 +1,10 @@
 // game/src/scripting/modules/menu_module.rs
-use crate::game_global::{is_menu_active, push_command};
-use crate::scripting::commands::menu_commands::{CloseMenuCmd, OpenMenuCmd};
+use crate::game_global::{is_menu_active, push_command, push_menu_command};
+use crate::scripting::commands::menu_commands::{
+    CloseMenuCmd, HideHudCmd, OpenMenuCmd, SetMenuListCmd, SetMenuTextCmd, SetMenuValueCmd,
+    ShowHudCmd,
+};
+use engine_core::menu::MenuValue;
 use engine_core::register_lua_api;
 use engine_core::register_lua_module;
 use engine_core::scripting::lua_constants::*;
@@ -8,6 +12,7 @@ use engine_core::scripting::modules::lua_module::*;
 use mlua::prelude::LuaResult;
 use mlua::Lua;
 use mlua::Table;
+use mlua::Value;
 
 /// Lua module that exposes the menu system API.
 #[derive(Default)]
@@ -34,11 +39,85 @@ impl LuaModule for MenuModule {
         let is_open_fn = lua.create_function(|_lua, ()| Ok(is_menu_active()))?;
         menu_tbl.set(IS_MENU_OPEN, is_open_fn)?;
 
+        let set_list_fn = lua.create_function(|_lua, (key, items): (String, Vec<String>)| {
+            push_menu_command(Box::new(SetMenuListCmd { key, items }));
+            Ok(())
+        })?;
+        menu_tbl.set(SET_MENU_LIST, set_list_fn)?;
+
+        let set_text_fn = lua.create_function(|_lua, (key, text): (String, String)| {
+            push_menu_command(Box::new(SetMenuTextCmd { key, text }));
+            Ok(())
+        })?;
+        menu_tbl.set(SET_MENU_TEXT, set_text_fn)?;
+
+        let set_value_fn =
+            lua.create_function(|_lua, (menu_id, key, value): (String, String, Value)| {
+                let value = menu_value_from_lua(value)?;
+                push_menu_command(Box::new(SetMenuValueCmd {
+                    menu_id,
+                    key,
+                    value,
+                }));
+                Ok(())
+            })?;
+        menu_tbl.set(SET_MENU_VALUE, set_value_fn)?;
+
+        let show_hud_fn = lua.create_function(|_lua, hud_id: String| {
+            push_command(Box::new(ShowHudCmd { hud_id }));
+            Ok(())
+        })?;
+        menu_tbl.set(SHOW_HUD, show_hud_fn)?;
+
+        let hide_hud_fn = lua.create_function(|_lua, hud_id: String| {
+            push_command(Box::new(HideHudCmd { hud_id }));
+            Ok(())
+        })?;
+        menu_tbl.set(HIDE_HUD, hide_hud_fn)?;
+
         engine_tbl.set(LUA_MENU, menu_tbl)?;
         Ok(())
     }
 }
 
+/// Converts a Lua value into a menu value. `nil` clears the value.
+fn menu_value_from_lua(value: Value) -> LuaResult<Option<MenuValue>> {
+    let value = match value {
+        Value::Nil => return Ok(None),
+        Value::Boolean(b) => MenuValue::Bool(b),
+        Value::Integer(i) => MenuValue::Number(i as f64),
+        Value::Number(n) => MenuValue::Number(n),
+        Value::String(s) => MenuValue::Text(s.to_str()?.to_string()),
+        Value::Table(items) => MenuValue::List(
+            items
+                .sequence_values::<Value>()
+                .map(|item| list_item_text(item?))
+                .collect::<LuaResult<_>>()?,
+        ),
+        other => {
+            return Err(mlua::Error::RuntimeError(format!(
+                "menu values must be numbers, booleans, strings or lists, got {}",
+                other.type_name()
+            )))
+        }
+    };
+    Ok(Some(value))
+}
+
+/// Formats one entry of a Lua list as scroll list text.
+fn list_item_text(item: Value) -> LuaResult<String> {
+    match item {
+        Value::String(s) => Ok(s.to_str()?.to_string()),
+        Value::Integer(i) => Ok(i.to_string()),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::Boolean(b) => Ok(b.to_string()),
+        other => Err(mlua::Error::RuntimeError(format!(
+            "menu list items must be strings or numbers, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
 register_lua_api!(MenuModule, MENU_FILE);
 
 impl LuaApi for MenuModule {
@@ -48,7 +127,7 @@ impl LuaApi for MenuModule {
         out.line("engine.menu = {}");
         out.line("");
 
-        out.line("--- Opens a menu by id.");
+        out.line("--- Opens a menu by id. HUD menus are shown as with `show_hud`.");
         out.line("---@param menu_id string The menu template id");
         out.line("function engine.menu.open(menu_id) end");
         out.line("");
@@ -61,5 +140,41 @@ impl LuaApi for MenuModule {
         out.line("---@return boolean");
         out.line("function engine.menu.is_open() end");
         out.line("");
+
+        out.line("--- Shows a HUD menu over gameplay. HUDs ignore input and stay up");
+        out.line("--- until hidden; several can be shown at once, later ones on top.");
+        out.line("--- Use `set_value` with the HUD's id to update what it shows.");
+        out.line("---@param hud_id string The id of a menu template in HUD mode");
+        out.line("function engine.menu.show_hud(hud_id) end");
+        out.line("");
+
+        out.line("--- Hides a shown HUD.");
+        out.line("---@param hud_id string The HUD menu id");
+        out.line("function engine.menu.hide_hud(hud_id) end");
+        out.line("");
+
+        out.line("--- Fills every scroll list with `key`. Picking an item emits");
+        out.line("--- `list:<key>` with `{ index, item }`, where `index` starts at 1.");
+        out.line("---@param key string The scroll list key");
+        out.line("---@param items string[] Item text or text keys, in order");
+        out.line("function engine.menu.set_list(key, items) end");
+        out.line("");
+
+        out.line("--- Sets the text of every text input with `key`. Submitting an input");
+        out.line("--- emits `text_input:<key>` with the entered text.");
+        out.line("---@param key string The text input key");
+        out.line("---@param text string");
+        out.line("function engine.menu.set_text(key, text) end");
+        out.line("");
+
+        out.line("--- Sets a value the elements of a menu bind to. Label text shows it");
+        out.line("--- through `{{key}}`, elements with a visible or enabled condition on");
+        out.line("--- `key` update, and lists fill the menu's scroll lists with that key.");
+        out.line("--- Passing nil clears the value.");
+        out.line("---@param menu_id string The menu template id");
+        out.line("---@param key string The value key, e.g. `\"player.coins\"`");
+        out.line("---@param value number|boolean|string|string[]|nil");
+        out.line("function engine.menu.set_value(menu_id, key, value) end");
+        out.line("");
     }
 }
@@ -1,7 +1,11 @@
 pub mod audio_module;
+pub mod camera_module;
+pub mod dialogue_module;
 pub mod engine_module;
 pub mod entity_module;
 pub mod input_module;
 pub mod logging_module;
 pub mod menu_module;
+pub mod settings_module;
 pub mod text_module;
+pub mod tween_module;
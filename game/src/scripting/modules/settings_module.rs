@@ -0,0 +1,102 @@
+// game/src/scripting/modules/settings_module.rs
+use engine_core::prelude::*;
+use mlua::prelude::LuaResult;
+use mlua::IntoLua;
+use mlua::Lua;
+use mlua::Table;
+use mlua::Value;
+
+/// Lua module that exposes the player settings store under `engine.settings`.
+#[derive(Default)]
+pub struct SettingsModule;
+register_lua_module!(SettingsModule);
+
+impl LuaModule for SettingsModule {
+    fn register(&self, lua: &Lua) -> LuaResult<()> {
+        let engine_tbl: Table = lua.globals().get(ENGINE)?;
+        let settings_tbl = lua.create_table()?;
+
+        let get_fn = lua.create_function(|lua, (key, default): (String, Value)| {
+            match player_setting(&key) {
+                Some(value) => setting_to_lua(lua, &value),
+                None => Ok(default),
+            }
+        })?;
+        settings_tbl.set(SETTINGS_GET, get_fn)?;
+
+        let set_fn = lua.create_function(|_lua, (key, value): (String, Value)| {
+            set_player_setting(&key, setting_from_lua(value)?);
+            Ok(())
+        })?;
+        settings_tbl.set(SETTINGS_SET, set_fn)?;
+
+        engine_tbl.set(LUA_SETTINGS, settings_tbl)?;
+        Ok(())
+    }
+}
+
+/// Converts a setting into the Lua value scripts see.
+pub(crate) fn setting_to_lua(lua: &Lua, value: &SettingValue) -> LuaResult<Value> {
+    match value {
+        SettingValue::Number(number) => Ok(Value::Number(*number as f64)),
+        SettingValue::Bool(flag) => Ok(Value::Boolean(*flag)),
+        SettingValue::Choice(choice) => choice.as_str().into_lua(lua),
+    }
+}
+
+/// Reads a number, boolean or string from Lua as a setting.
+fn setting_from_lua(value: Value) -> LuaResult<SettingValue> {
+    match value {
+        Value::Integer(number) => Ok(SettingValue::Number(number as f32)),
+        Value::Number(number) => Ok(SettingValue::Number(number as f32)),
+        Value::Boolean(flag) => Ok(SettingValue::Bool(flag)),
+        Value::String(choice) => Ok(SettingValue::Choice(choice.to_str()?.to_string())),
+        other => Err(mlua::Error::RuntimeError(format!(
+            "expected a number, boolean or string setting, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+register_lua_api!(SettingsModule, SETTINGS_FILE);
+
+impl LuaApi for SettingsModule {
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Player settings module. Settings are saved per game and shared by");
+        out.line("--- menu sliders with the same key.");
+        out.line("---");
+        out.line("--- The engine applies these keys itself:");
+        out.line(&format!(
+            "--- `{}`, `{}`, `{}` (0 to 1),",
+            MASTER_VOLUME_SETTING, MUSIC_VOLUME_SETTING, SFX_VOLUME_SETTING
+        ));
+        out.line(&format!(
+            "--- `{}`, `{}` and `{}` (booleans).",
+            FULLSCREEN_SETTING, VSYNC_SETTING, INTEGER_SCALING_SETTING
+        ));
+        out.line("---");
+        out.line("--- Changes emit `settings:changed` with `{ key, value }`.");
+        out.line("---@class SettingsApi");
+        out.line("engine.settings = {}");
+        out.line("");
+
+        out.line("--- Returns the saved value for `key`, or `default` when it was never set.");
+        out.line("---@param key string");
+        out.line("---@param default? number|boolean|string");
+        out.line("---@return number|boolean|string|nil");
+        out.line(&format!(
+            "function engine.settings.{}(key, default) end",
+            SETTINGS_GET
+        ));
+        out.line("");
+
+        out.line("--- Stores a setting. Strings hold one option out of a set, e.g. \"hard\".");
+        out.line("---@param key string");
+        out.line("---@param value number|boolean|string");
+        out.line(&format!(
+            "function engine.settings.{}(key, value) end",
+            SETTINGS_SET
+        ));
+        out.line("");
+    }
+}
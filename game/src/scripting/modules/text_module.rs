@@ -2,13 +2,14 @@
 use crate::game_global::push_command;
 use crate::scripting::commands::text_commands::SetLanguageCmd;
 use crate::scripting::lua_ctx::LuaGameCtx;
+use engine_core::prelude::*;
 use engine_core::register_lua_api;
 use engine_core::register_lua_module;
-use engine_core::scripting::lua_constants::*;
-use engine_core::scripting::modules::lua_module::*;
 use mlua::prelude::LuaResult;
 use mlua::Lua;
 use mlua::Table;
+use mlua::Value;
+use std::collections::HashMap;
 
 /// Lua module for onscreen text display.
 #[derive(Default)]
@@ -77,11 +78,59 @@ impl LuaModule for TextModule {
         })?;
         text_tbl.set(GET_CONFIG, get_config_fn)?;
 
+        let format_fn = lua.create_function(|lua, (message, vars): (String, Option<Table>)| {
+            let values = text_values_from_table(vars)?;
+            let ctx = LuaGameCtx::borrow_ctx(lua)?;
+            let game_instance = ctx.game_instance.borrow();
+            let language = game_instance.game.text_manager.get_language();
+            Ok(interpolate(&message, language, &values))
+        })?;
+        text_tbl.set(FORMAT_TEXT, format_fn)?;
+
+        let get_text_fn = lua.create_function(
+            |lua, (text_id, key, vars): (String, String, Option<Table>)| {
+                let values = text_values_from_table(vars)?;
+                let ctx = LuaGameCtx::borrow_ctx(lua)?;
+                let game_instance = ctx.game_instance.borrow();
+                let text_manager = &game_instance.game.text_manager;
+                let text = text_manager.resolve_ui_text(&text_id, &key);
+                Ok(interpolate(&text, text_manager.get_language(), &values))
+            },
+        )?;
+        text_tbl.set(GET_TEXT, get_text_fn)?;
+
         engine_tbl.set(TEXT, text_tbl)?;
         Ok(())
     }
 }
 
+/// Reads a `name -> value` table of text variables, keeping numbers and booleans typed.
+pub(crate) fn text_values_from_table(
+    table: Option<Table>,
+) -> LuaResult<HashMap<String, TextValue>> {
+    let mut values = HashMap::new();
+    let Some(table) = table else {
+        return Ok(values);
+    };
+    for pair in table.pairs::<String, Value>() {
+        let (name, value) = pair?;
+        let value = match value {
+            Value::Boolean(b) => TextValue::Bool(b),
+            Value::Integer(i) => TextValue::Number(i as f64),
+            Value::Number(n) => TextValue::Number(n),
+            Value::String(s) => TextValue::Text(s.to_str()?.to_string()),
+            other => {
+                return Err(mlua::Error::RuntimeError(format!(
+                    "text variable '{name}' cannot be a {}",
+                    other.type_name()
+                )));
+            }
+        };
+        values.insert(name, value);
+    }
+    Ok(values)
+}
+
 register_lua_api!(TextModule, TEXT_FILE);
 
 impl LuaApi for TextModule {
@@ -110,5 +159,26 @@ impl LuaApi for TextModule {
         out.line("---@return {default_duration: number, font_size: number, max_width: number, default_offset_y: number, padding: number, show_background: boolean, default_color: number[], default_background_color: number[]}");
         out.line("function engine.text.get_config() end");
         out.line("");
+
+        out.line("--- Formats a message with `{{name}}` placeholders and ICU-style arguments,");
+        out.line(
+            "--- e.g. `{count, plural, one {# coin} other {# coins}}`, in the current language.",
+        );
+        out.line("---@param message string");
+        out.line("---@param vars? table<string, string|number|boolean>");
+        out.line("---@return string");
+        out.line("function engine.text.format(message, vars) end");
+        out.line("");
+
+        out.line(
+            "--- Gets UI text by file and key in the current language, formatted with `vars`.",
+        );
+        out.line("--- Returns the key if the text is missing.");
+        out.line("---@param text_id string The text file id (e.g. \"ui/hud\")");
+        out.line("---@param key string");
+        out.line("---@param vars? table<string, string|number|boolean>");
+        out.line("---@return string");
+        out.line("function engine.text.get(text_id, key, vars) end");
+        out.line("");
     }
 }
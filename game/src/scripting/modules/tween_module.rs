@@ -0,0 +1,286 @@
+// game/src/scripting/modules/tween_module.rs
+use crate::scripting::lua_ctx::LuaGameCtx;
+use crate::scripting::modules::entity_module::EntityHandle;
+use bishop::prelude::{Vec2, Vec3};
+use engine_core::prelude::*;
+use mlua::prelude::LuaResult;
+use mlua::Function;
+use mlua::Lua;
+use mlua::Table;
+use mlua::UserData;
+use mlua::UserDataMethods;
+use mlua::Value;
+
+/// Lua module that exposes `engine.tween` and the tween handle returned by it.
+#[derive(Default)]
+pub struct TweenModule;
+register_lua_module!(TweenModule);
+
+/// Handle to a running tween sequence. Methods return the handle for chaining.
+#[derive(Clone, Copy)]
+pub struct TweenHandle {
+    pub id: TweenId,
+    pub entity: Entity,
+}
+
+impl LuaModule for TweenModule {
+    fn register(&self, lua: &Lua) -> LuaResult<()> {
+        let engine_tbl: Table = lua.globals().get(ENGINE)?;
+
+        let tween_fn = lua.create_function(
+            |lua,
+             (entity, property, to, duration, easing): (
+                Value,
+                String,
+                Value,
+                f32,
+                Option<String>,
+            )| {
+                let entity = entity_from_value(entity)?;
+                let tween = build_tween(entity, &property, &to, duration, easing)?;
+                let id = with_tweens(lua, |tweens| tweens.start(tween))?;
+                Ok(TweenHandle { id, entity })
+            },
+        )?;
+        engine_tbl.set(ENGINE_TWEEN, tween_fn)?;
+
+        let cancel_fn = lua.create_function(|lua, entity: Value| {
+            let entity = entity_from_value(entity)?;
+            with_tweens(lua, |tweens| tweens.cancel_entity(entity))
+        })?;
+        engine_tbl.set(ENGINE_CANCEL_TWEENS, cancel_fn)?;
+
+        Ok(())
+    }
+}
+
+impl UserData for TweenHandle {
+    fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method(TWEEN_ON_DONE, |lua, this, callback: Function| {
+            with_tweens(lua, |tweens| tweens.set_on_done(this.id, callback))?;
+            Ok(*this)
+        });
+
+        methods.add_method(TWEEN_DELAY, |lua, this, seconds: f32| {
+            with_tweens(lua, |tweens| {
+                tweens.modify_last(this.id, |t| t.delay = seconds.max(0.0))
+            })?;
+            Ok(*this)
+        });
+
+        methods.add_method(TWEEN_EASE, |lua, this, name: String| {
+            let easing = parse_easing(&name)?;
+            with_tweens(lua, |tweens| {
+                tweens.modify_last(this.id, |t| t.easing = easing)
+            })?;
+            Ok(*this)
+        });
+
+        methods.add_method(TWEEN_FROM, |lua, this, value: Value| {
+            let from = tween_value_from_lua(&value)?;
+            with_tweens(lua, |tweens| {
+                tweens.modify_last(this.id, |t| t.from = Some(from))
+            })?;
+            Ok(*this)
+        });
+
+        methods.add_method(TWEEN_YOYO, |lua, this, ()| {
+            with_tweens(lua, |tweens| {
+                tweens.modify_last(this.id, |t| *t = t.clone().with_yoyo())
+            })?;
+            Ok(*this)
+        });
+
+        methods.add_method(TWEEN_LOOP, |lua, this, count: Option<u32>| {
+            with_tweens(lua, |tweens| {
+                tweens.modify_last(this.id, |t| *t = t.clone().with_cycles(count))
+            })?;
+            Ok(*this)
+        });
+
+        methods.add_method(
+            TWEEN_THEN_TO,
+            |lua, this, (property, to, duration, easing): (String, Value, f32, Option<String>)| {
+                let tween = build_tween(this.entity, &property, &to, duration, easing)?;
+                with_tweens(lua, |tweens| tweens.then(this.id, tween))?;
+                Ok(*this)
+            },
+        );
+
+        methods.add_method(TWEEN_CANCEL, |lua, this, ()| {
+            with_tweens(lua, |tweens| tweens.cancel(this.id))
+        });
+
+        methods.add_method(TWEEN_IS_RUNNING, |lua, this, ()| {
+            with_tweens(lua, |tweens| tweens.is_running(this.id))
+        });
+    }
+}
+
+/// Runs `f` on the game's tween manager.
+fn with_tweens<R>(lua: &Lua, f: impl FnOnce(&mut TweenManager) -> R) -> LuaResult<R> {
+    let ctx = LuaGameCtx::borrow_ctx(lua)?;
+    let mut game_instance = ctx.game_instance.try_borrow_mut().map_err(|_| {
+        mlua::Error::RuntimeError("tweens cannot be changed while the game is busy".into())
+    })?;
+    Ok(f(&mut game_instance.game.tweens))
+}
+
+fn build_tween(
+    entity: Entity,
+    property: &str,
+    to: &Value,
+    duration: f32,
+    easing: Option<String>,
+) -> LuaResult<Tween> {
+    let property = TweenProperty::parse(property).map_err(mlua::Error::RuntimeError)?;
+    let to = tween_value_from_lua(to)?;
+    let easing = match easing {
+        Some(name) => parse_easing(&name)?,
+        None => Easing::Linear,
+    };
+    Ok(Tween::new(entity, property, to, duration).with_easing(easing))
+}
+
+pub(crate) fn parse_easing(name: &str) -> LuaResult<Easing> {
+    Easing::from_name(name)
+        .ok_or_else(|| mlua::Error::RuntimeError(format!("Unknown easing '{name}'")))
+}
+
+/// Accepts an entity handle or a raw entity id.
+pub(crate) fn entity_from_value(value: Value) -> LuaResult<Entity> {
+    match value {
+        Value::UserData(ud) => Ok(ud.borrow::<EntityHandle>()?.entity),
+        Value::Integer(id) => Ok(Entity(id as usize)),
+        other => Err(mlua::Error::RuntimeError(format!(
+            "expected an entity, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+/// Converts a number, `{x, y}`, `{x, y, z}` or `{r, g, b}` table into a tween value.
+fn tween_value_from_lua(value: &Value) -> LuaResult<TweenValue> {
+    match value {
+        Value::Integer(v) => Ok(TweenValue::Float(*v as f32)),
+        Value::Number(v) => Ok(TweenValue::Float(*v as f32)),
+        Value::Table(table) => {
+            let component = |key: &str, alt: &str, index: i64| -> LuaResult<Option<f32>> {
+                Ok(table
+                    .get::<Option<f32>>(key)?
+                    .or(table.get::<Option<f32>>(alt)?)
+                    .or(table.get::<Option<f32>>(index)?))
+            };
+            let (Some(x), Some(y)) = (component("x", "r", 1)?, component("y", "g", 2)?) else {
+                return Err(mlua::Error::RuntimeError(
+                    "tween value tables need x and y (or r, g, b) fields".into(),
+                ));
+            };
+            Ok(match component("z", "b", 3)? {
+                Some(z) => TweenValue::Vec3(Vec3::new(x, y, z)),
+                None => TweenValue::Vec2(Vec2::new(x, y)),
+            })
+        }
+        other => Err(mlua::Error::RuntimeError(format!(
+            "tween value must be a number or table, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+register_lua_api!(TweenModule, TWEEN_FILE);
+
+impl LuaApi for TweenModule {
+    fn emit_api(&self, out: &mut LuaApiWriter) {
+        out.line("--- Handle to a running tween. Every method returns the handle for chaining.");
+        out.line("---@class Tween");
+        out.line("local Tween = {}");
+        out.line("");
+
+        out.line("--- Animates a property from its current value to `to` over `duration` seconds.");
+        out.line(
+            "--- Properties: \"position\", \"rotation\", \"scale\", \"alpha\", \"camera_zoom\",",
+        );
+        out.line("--- \"light_intensity\", \"volume\" or any reflected \"Component.field\".");
+        out.line("--- Tweens stop silently when their entity is removed.");
+        out.line("---@param entity Entity|integer");
+        out.line("---@param property string e.g. \"Transform.position\"");
+        out.line("---@param to number|{x: number, y: number}|{r: number, g: number, b: number}");
+        out.line("---@param duration number Seconds");
+        out.line("---@param easing? string e.g. \"out_quad\" (default \"linear\")");
+        out.line("---@return Tween");
+        out.line(&format!(
+            "function engine.{}(entity, property, to, duration, easing) end",
+            ENGINE_TWEEN
+        ));
+        out.line("");
+
+        out.line("--- Stops every tween animating the entity, without calling callbacks.");
+        out.line("---@param entity Entity|integer");
+        out.line(&format!(
+            "function engine.{}(entity) end",
+            ENGINE_CANCEL_TWEENS
+        ));
+        out.line("");
+
+        out.line("--- Calls `fn` once every step of the tween has finished.");
+        out.line("---@param fn fun()");
+        out.line("---@return Tween");
+        out.line(&format!("function Tween:{}(fn) end", TWEEN_ON_DONE));
+        out.line("");
+
+        out.line("--- Waits before the last step starts.");
+        out.line("---@param seconds number");
+        out.line("---@return Tween");
+        out.line(&format!("function Tween:{}(seconds) end", TWEEN_DELAY));
+        out.line("");
+
+        out.line("--- Sets the easing of the last step.");
+        out.line("---@param easing string e.g. \"in_out_sine\"");
+        out.line("---@return Tween");
+        out.line(&format!("function Tween:{}(easing) end", TWEEN_EASE));
+        out.line("");
+
+        out.line("--- Starts the last step from `value` instead of the current value.");
+        out.line("---@param value number|table");
+        out.line("---@return Tween");
+        out.line(&format!("function Tween:{}(value) end", TWEEN_FROM));
+        out.line("");
+
+        out.line("--- Plays the last step back to its start after reaching the target.");
+        out.line("---@return Tween");
+        out.line(&format!("function Tween:{}() end", TWEEN_YOYO));
+        out.line("");
+
+        out.line("--- Plays the last step `count` times, forever if omitted.");
+        out.line("--- With yoyo, every other pass runs backwards.");
+        out.line("---@param count? integer");
+        out.line("---@return Tween");
+        out.line(&format!("function Tween:{}(count) end", TWEEN_LOOP));
+        out.line("");
+
+        out.line(
+            "--- Queues another step on the same entity that starts when the previous one ends.",
+        );
+        out.line("---@param property string");
+        out.line("---@param to number|table");
+        out.line("---@param duration number Seconds");
+        out.line("---@param easing? string");
+        out.line("---@return Tween");
+        out.line(&format!(
+            "function Tween:{}(property, to, duration, easing) end",
+            TWEEN_THEN_TO
+        ));
+        out.line("");
+
+        out.line("--- Stops the tween without calling `on_done`.");
+        out.line("---@return boolean stopped False if the tween had already finished");
+        out.line(&format!("function Tween:{}() end", TWEEN_CANCEL));
+        out.line("");
+
+        out.line("--- Returns true while the tween still has steps to play.");
+        out.line("---@return boolean");
+        out.line(&format!("function Tween:{}() end", TWEEN_IS_RUNNING));
+        out.line("");
+    }
+}
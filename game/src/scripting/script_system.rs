@@ -1,6 +1,6 @@
 // engine_core/src/script/script_system.rs
 use crate::engine::Engine;
-use crate::game_global::drain_commands;
+use crate::game_global::{drain_commands, drain_menu_commands};
 use crate::scripting::modules::entity_module::*;
 use engine_core::prelude::*;
 use mlua::prelude::LuaResult;
@@ -139,6 +139,13 @@ impl ScriptSystem {
         }
     }
 
+    /// Process the menu commands scripts pushed this frame.
+    pub fn process_menu_commands(engine: &mut Engine) {
+        for mut cmd in drain_menu_commands() {
+            cmd.execute(engine);
+        }
+    }
+
     /// Initializes all needed scripts in the game.
     /// Only creates entity handles and queues init for newly created instances.
     pub fn load_scripts(
@@ -1 +1,2 @@
+pub mod room_transition;
 pub mod transition_manager;
@@ -0,0 +1,128 @@
+// game/src/transitions/room_transition.rs
+use bishop::prelude::*;
+use engine_core::prelude::*;
+
+/// A room transition being played. Gameplay is frozen until it finishes.
+#[derive(Debug, Clone, Copy)]
+pub struct ActiveRoomTransition {
+    pub transition: RoomTransition,
+    pub from: RoomId,
+    pub to: RoomId,
+    /// Iris centre over the old room, in normalised screen coordinates.
+    exit_focus: Vec2,
+    /// Camera target and zoom over the old room, where a slide scrolls from.
+    exit_view: (Vec2, Vec2),
+    elapsed: f32,
+}
+
+impl ActiveRoomTransition {
+    pub fn new(transition: RoomTransition, from: RoomId, to: RoomId) -> Self {
+        Self {
+            transition,
+            from,
+            to,
+            exit_focus: vec2(0.5, 0.5),
+            exit_view: (Vec2::ZERO, Vec2::ONE),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Starts a slide from the camera target and zoom of the old room.
+    pub fn with_exit_view(mut self, target: Vec2, zoom: Vec2) -> Self {
+        self.exit_view = (target, zoom);
+        self
+    }
+
+    /// Centres the closing iris on `focus`, in normalised screen coordinates.
+    pub fn with_exit_focus(mut self, focus: Vec2) -> Self {
+        self.exit_focus = focus;
+        self
+    }
+
+    /// Progress through the transition in `0..=1`.
+    pub fn progress(&self) -> f32 {
+        if self.transition.duration > 0.0 {
+            (self.elapsed / self.transition.duration).min(1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Advances the transition. Returns true once it has finished.
+    pub fn advance(&mut self, dt: f32) -> bool {
+        self.elapsed += dt;
+        self.progress() >= 1.0
+    }
+
+    /// Camera of a slide, scrolled from the old room's view to `enter`, the
+    /// view of the new room. Other styles keep the camera where it is.
+    pub fn camera(&self, enter: &Camera2D) -> Option<Camera2D> {
+        if self.transition.style != TransitionStyle::Slide {
+            return None;
+        }
+        let t = Easing::InOutSine.apply(self.progress());
+        let (target, zoom) = self.exit_view;
+        Some(Camera2D {
+            target: target.lerp(enter.target, t),
+            zoom: zoom.lerp(enter.zoom, t),
+            ..enter.clone()
+        })
+    }
+
+    /// Frame handed to the renderer. `enter_focus` centres the opening iris
+    /// over the new room, in normalised screen coordinates.
+    pub fn frame(&self, enter_focus: Vec2) -> TransitionFrame {
+        let mut frame = TransitionFrame {
+            style: self.transition.style,
+            progress: self.progress(),
+            color: self.transition.color,
+            focus: enter_focus,
+        };
+        if frame.shows_snapshot() {
+            frame.focus = self.exit_focus;
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finishes_after_its_duration() {
+        let transition = RoomTransition {
+            style: TransitionStyle::Fade,
+            duration: 0.5,
+            ..Default::default()
+        };
+        let mut active = ActiveRoomTransition::new(transition, RoomId(1), RoomId(2));
+
+        assert!(!active.advance(0.25));
+        assert_eq!(active.progress(), 0.5);
+        assert!(active.camera(&Camera2D::default()).is_none());
+        assert!(active.advance(0.25));
+        assert_eq!(active.frame(Vec2::ZERO).progress, 1.0);
+    }
+
+    #[test]
+    fn slides_scroll_the_camera_between_rooms() {
+        let transition = RoomTransition {
+            style: TransitionStyle::Slide,
+            duration: 1.0,
+            ..Default::default()
+        };
+        let mut active = ActiveRoomTransition::new(transition, RoomId(1), RoomId(2))
+            .with_exit_view(vec2(100.0, 50.0), Vec2::ONE);
+        let enter = Camera2D {
+            target: vec2(300.0, 50.0),
+            ..Default::default()
+        };
+
+        assert_eq!(active.camera(&enter).unwrap().target, vec2(100.0, 50.0));
+        active.advance(0.5);
+        assert_eq!(active.camera(&enter).unwrap().target, vec2(200.0, 50.0));
+        active.advance(0.5);
+        assert_eq!(active.camera(&enter).unwrap().target, enter.target);
+    }
+}
@@ -16,6 +16,15 @@ pub enum TransitionState {
     Retreated,
 }
 
+/// The player moving from one room into another.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoomChange {
+    pub from: RoomId,
+    pub to: RoomId,
+    /// Player position when the change happened.
+    pub position: Vec2,
+}
+
 #[derive(Default)]
 pub struct TransitionManager {
     pub state: TransitionState,
@@ -52,7 +61,8 @@ impl TransitionManager {
     }
 
     /// Handles entity transitions between rooms.
-    pub fn handle_transitions(game_instance: &mut GameInstance) {
+    /// Returns the room change when the player entered another room.
+    pub fn handle_transitions(game_instance: &mut GameInstance) -> Option<RoomChange> {
         let grid_size = game_instance.game.current_world().grid_size;
         let rooms = game_instance.game.current_world().rooms.clone();
 
@@ -65,6 +75,7 @@ impl TransitionManager {
             .cloned()
             .collect();
 
+        let mut room_change = None;
         for entity in entities {
             let (pos, _coll) = {
                 let p = match game_instance.game.ecs.get::<Transform>(entity) {
@@ -94,10 +105,19 @@ impl TransitionManager {
 
             if game_instance.game.ecs.get_player_entity() == Some(entity) {
                 if let Some(new_room) = rooms.iter().find(|r| r.id == target_id) {
-                    game_instance.game.current_world_mut().current_room_id = Some(new_room.id);
+                    let world = game_instance.game.current_world_mut();
+                    let previous = world.current_room_id.replace(new_room.id);
+                    if let Some(from) = previous.filter(|&from| from != new_room.id) {
+                        room_change = Some(RoomChange {
+                            from,
+                            to: new_room.id,
+                            position: pos,
+                        });
+                    }
                 }
             }
         }
+        room_change
     }
 }
 
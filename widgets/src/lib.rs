@@ -17,7 +17,7 @@ pub use widget_id::*;
 pub use widgets::*;
 
 pub use bishop::BishopContext;
-pub use bishop::TextDimensions;
+pub use bishop::{FontId, TextDimensions};
 
 pub use bishop::{Color, DrawTextureParams, KeyCode, MouseButton, Rect, Texture2D, Vec2};
 
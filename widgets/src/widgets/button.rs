@@ -28,6 +28,7 @@ pub struct Button<'a> {
     rect: Rect,
     content: ButtonContent<'a>,
     style: ButtonStyle,
+    font: FontId,
     font_size: f32,
     text_color: Color,
     hover_color: Color,
@@ -47,6 +48,7 @@ impl<'a> Button<'a> {
             rect: rect.into(),
             content: ButtonContent::Text(label),
             style: ButtonStyle::Default,
+            font: FontId::DEFAULT,
             font_size: FIELD_TEXT_SIZE_16,
             text_color: FIELD_TEXT_COLOR,
             hover_color: HOVER_COLOR,
@@ -69,6 +71,7 @@ impl<'a> Button<'a> {
             rect: rect.into(),
             content: ButtonContent::Icon { texture, id },
             style: ButtonStyle::Default,
+            font: FontId::DEFAULT,
             font_size: FIELD_TEXT_SIZE_16,
             text_color: FIELD_TEXT_COLOR,
             hover_color: HOVER_COLOR,
@@ -107,6 +110,12 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Sets the font for the button label.
+    pub fn font(mut self, font: FontId) -> Self {
+        self.font = font;
+        self
+    }
+
     /// Sets the font size for the button label.
     pub fn font_size(mut self, size: f32) -> Self {
         self.font_size = size;
@@ -213,15 +222,15 @@ impl<'a> Button<'a> {
 
         match &self.content {
             ButtonContent::Text(label) => {
-                let txt_dims = measure_text_ui(ctx, label, self.font_size);
+                let txt_dims = ctx.measure_text_with_font(label, self.font, self.font_size);
                 let txt_y =
                     self.rect.y + (self.rect.h - txt_dims.height) / 2.0 + txt_dims.offset_y;
                 let txt_x = self.rect.x + (self.rect.w - txt_dims.width) / 2.0;
-                draw_text_ui(
-                    ctx,
+                ctx.draw_text_with_font(
                     label,
                     txt_x + self.text_offset.x,
                     txt_y + self.text_offset.y,
+                    self.font,
                     self.font_size,
                     self.text_color,
                 );
@@ -426,6 +435,10 @@ mod tests {
         fn push_clip_rect(&mut self, _rect: Rect) {}
 
         fn pop_clip_rect(&mut self) {}
+
+        fn push_opacity(&mut self, _opacity: f32) {}
+
+        fn pop_opacity(&mut self) {}
     }
 
     impl Text for TestContext {
@@ -501,6 +514,12 @@ mod tests {
             false
         }
 
+        fn set_vsync(&mut self, _enabled: bool) {}
+
+        fn is_vsync(&self) -> bool {
+            true
+        }
+
         fn scale_factor(&self) -> f32 {
             1.0
         }